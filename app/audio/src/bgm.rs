@@ -0,0 +1,291 @@
+//! Background music selection and playback.
+//!
+//! [`bgm_for_state`] is a pure function that decides which [`BgmTrack`]
+//! belongs to the current [`AppState`] (and, during a run, to the boss /
+//! late-game phase).  [`update_bgm`] runs every frame, switches the
+//! [`BgmChannel`] to the target track when it changes, and starts playback
+//! once the track's sound file has finished loading.
+//!
+//! Missing sound files never finish loading, so the channel simply stays
+//! silent for that track.
+
+use bevy::prelude::*;
+use bevy_kira_audio::{AudioChannel, AudioControl, AudioSource};
+use serde::Deserialize;
+use vs_core::resources::GameData;
+use vs_core::states::AppState;
+
+use crate::BgmChannel;
+use crate::config::AudioParams;
+use crate::handles::SoundHandles;
+use crate::linear_to_decibels;
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Every background music track the game can play.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum BgmTrack {
+    /// Title and all out-of-run menus.
+    Title,
+    /// Early part of a run.
+    Gameplay,
+    /// Later part of a run (after `gameplay_late_after_secs`).
+    GameplayLate,
+    /// Final boss fight.
+    Boss,
+    /// Game-over jingle.
+    GameOver,
+    /// Victory fanfare.
+    Victory,
+}
+
+impl BgmTrack {
+    /// All tracks, in declaration order.
+    pub const ALL: [BgmTrack; 6] = [
+        BgmTrack::Title,
+        BgmTrack::Gameplay,
+        BgmTrack::GameplayLate,
+        BgmTrack::Boss,
+        BgmTrack::GameOver,
+        BgmTrack::Victory,
+    ];
+
+    /// Whether the track loops.  Result jingles play once.
+    pub fn looped(self) -> bool {
+        !matches!(self, BgmTrack::GameOver | BgmTrack::Victory)
+    }
+}
+
+/// The track currently assigned to the [`BgmChannel`].
+///
+/// `started` stays `false` until the track's sound file is loaded and
+/// playback has actually been queued on the channel.
+#[derive(Resource, Debug, Default)]
+pub struct CurrentBgm {
+    pub track: Option<BgmTrack>,
+    pub started: bool,
+}
+
+// ---------------------------------------------------------------------------
+// Track selection
+// ---------------------------------------------------------------------------
+
+/// Returns the background track for `state`.
+///
+/// During a run (`Playing`, `LevelUp`, `Paused`) the boss track takes
+/// precedence once the boss has spawned; otherwise the late track replaces
+/// the early one after `late_after_secs`.  `Loading` has no music.
+pub fn bgm_for_state(
+    state: &AppState,
+    game_data: &GameData,
+    late_after_secs: f32,
+) -> Option<BgmTrack> {
+    match state {
+        AppState::Loading => None,
+        AppState::Title
+        | AppState::CharacterSelect
+        | AppState::StageSelect
+        | AppState::MetaShop
+        | AppState::Settings => Some(BgmTrack::Title),
+        AppState::Playing | AppState::LevelUp | AppState::Paused => {
+            if game_data.boss_spawned {
+                Some(BgmTrack::Boss)
+            } else if game_data.elapsed_time >= late_after_secs {
+                Some(BgmTrack::GameplayLate)
+            } else {
+                Some(BgmTrack::Gameplay)
+            }
+        }
+        AppState::GameOver => Some(BgmTrack::GameOver),
+        AppState::Victory => Some(BgmTrack::Victory),
+    }
+}
+
+// ---------------------------------------------------------------------------
+// System
+// ---------------------------------------------------------------------------
+
+/// Keeps the [`BgmChannel`] in sync with [`bgm_for_state`].
+///
+/// When the target track changes the channel is stopped and [`CurrentBgm`]
+/// is updated immediately.  Playback is queued as soon as the track's
+/// [`AudioSource`] is available; a track whose file is missing stays silent.
+pub fn update_bgm(
+    state: Res<State<AppState>>,
+    game_data: Res<GameData>,
+    audio_cfg: AudioParams,
+    handles: Res<SoundHandles>,
+    sources: Option<Res<Assets<AudioSource>>>,
+    channel: Option<Res<AudioChannel<BgmChannel>>>,
+    mut current: ResMut<CurrentBgm>,
+) {
+    let target = bgm_for_state(
+        state.get(),
+        &game_data,
+        audio_cfg.gameplay_late_after_secs(),
+    );
+
+    if target != current.track {
+        if let Some(channel) = channel.as_deref() {
+            channel.stop();
+        }
+        current.track = target;
+        current.started = false;
+    }
+
+    if current.started {
+        return;
+    }
+    let Some(track) = current.track else {
+        return;
+    };
+    let (Some(channel), Some(sources)) = (channel.as_deref(), sources.as_deref()) else {
+        return;
+    };
+    let Some(handle) = handles.loaded_bgm(track, sources) else {
+        return;
+    };
+
+    let mut play = channel.play(handle);
+    play.with_volume(linear_to_decibels(audio_cfg.bgm_volume(track)));
+    if track.looped() {
+        play.looped();
+    }
+    current.started = true;
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce as _;
+    use bevy::state::app::StatesPlugin;
+
+    use super::*;
+
+    fn build_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin));
+        app.init_state::<AppState>();
+        app.insert_resource(GameData::default());
+        app.init_resource::<SoundHandles>();
+        app.init_resource::<CurrentBgm>();
+        app
+    }
+
+    fn set_state(app: &mut App, state: AppState) {
+        app.world_mut()
+            .resource_mut::<NextState<AppState>>()
+            .set(state);
+        app.update();
+    }
+
+    fn run_update(app: &mut App) {
+        app.world_mut()
+            .run_system_once(update_bgm)
+            .expect("update_bgm should run");
+    }
+
+    /// Menus share the title track; loading is silent.
+    #[test]
+    fn menu_states_use_title_track() {
+        let gd = GameData::default();
+        assert_eq!(bgm_for_state(&AppState::Loading, &gd, 900.0), None);
+        for state in [
+            AppState::Title,
+            AppState::CharacterSelect,
+            AppState::StageSelect,
+            AppState::MetaShop,
+            AppState::Settings,
+        ] {
+            assert_eq!(bgm_for_state(&state, &gd, 900.0), Some(BgmTrack::Title));
+        }
+    }
+
+    /// Pausing or levelling up keeps the gameplay track.
+    #[test]
+    fn in_run_overlays_keep_gameplay_track() {
+        let gd = GameData::default();
+        for state in [AppState::Playing, AppState::LevelUp, AppState::Paused] {
+            assert_eq!(bgm_for_state(&state, &gd, 900.0), Some(BgmTrack::Gameplay));
+        }
+    }
+
+    /// The late track starts at the configured threshold.
+    #[test]
+    fn late_track_after_threshold() {
+        let mut gd = GameData {
+            elapsed_time: 899.0,
+            ..default()
+        };
+        assert_eq!(
+            bgm_for_state(&AppState::Playing, &gd, 900.0),
+            Some(BgmTrack::Gameplay)
+        );
+        gd.elapsed_time = 900.0;
+        assert_eq!(
+            bgm_for_state(&AppState::Playing, &gd, 900.0),
+            Some(BgmTrack::GameplayLate)
+        );
+    }
+
+    /// The boss track overrides the late track.
+    #[test]
+    fn boss_track_overrides_late_track() {
+        let gd = GameData {
+            elapsed_time: 1800.0,
+            boss_spawned: true,
+            ..default()
+        };
+        assert_eq!(
+            bgm_for_state(&AppState::Playing, &gd, 900.0),
+            Some(BgmTrack::Boss)
+        );
+    }
+
+    /// Result screens get their own non-looping jingles.
+    #[test]
+    fn result_states_use_jingles() {
+        let gd = GameData::default();
+        assert_eq!(
+            bgm_for_state(&AppState::GameOver, &gd, 900.0),
+            Some(BgmTrack::GameOver)
+        );
+        assert_eq!(
+            bgm_for_state(&AppState::Victory, &gd, 900.0),
+            Some(BgmTrack::Victory)
+        );
+        assert!(!BgmTrack::GameOver.looped());
+        assert!(!BgmTrack::Victory.looped());
+        assert!(BgmTrack::Gameplay.looped());
+    }
+
+    /// State transitions retarget `CurrentBgm` even without any sound files.
+    #[test]
+    fn update_bgm_follows_state_without_audio() {
+        let mut app = build_app();
+
+        run_update(&mut app);
+        assert_eq!(app.world().resource::<CurrentBgm>().track, None);
+
+        set_state(&mut app, AppState::Title);
+        run_update(&mut app);
+        assert_eq!(
+            app.world().resource::<CurrentBgm>().track,
+            Some(BgmTrack::Title)
+        );
+
+        set_state(&mut app, AppState::Playing);
+        run_update(&mut app);
+        let current = app.world().resource::<CurrentBgm>();
+        assert_eq!(current.track, Some(BgmTrack::Gameplay));
+        assert!(
+            !current.started,
+            "a track with no loaded sound must not be marked as started"
+        );
+    }
+}
@@ -0,0 +1,316 @@
+//! Audio configuration loaded from `assets/config/audio.ron`.
+//!
+//! Maps every [`BgmTrack`] and [`SfxCue`] to a sound file path and a linear
+//! volume, and holds the voice-pool limits used by [`crate::voices`].  Cues
+//! that are absent from the file — or whose file does not exist on disk —
+//! are simply silent, so the game runs without any audio assets at all.
+//!
+//! The file is watched by Bevy's asset server; edits are picked up while the
+//! game is running and the sound handles are reloaded by
+//! [`crate::handles::load_sound_handles`].
+
+use std::collections::HashMap;
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::bgm::BgmTrack;
+use crate::sfx::SfxCue;
+use crate::voices::{SfxGroup, SfxLimit, default_sfx_limit};
+
+// ---------------------------------------------------------------------------
+// Fallback constants (used while audio.ron is still loading)
+// ---------------------------------------------------------------------------
+
+/// Elapsed run time (seconds) after which the late-game BGM replaces the
+/// early-game track.
+pub const DEFAULT_GAMEPLAY_LATE_AFTER_SECS: f32 = 900.0;
+
+/// Maximum number of sound effects sounding at the same time.
+pub const DEFAULT_MAX_SFX_VOICES: usize = 24;
+
+// ---------------------------------------------------------------------------
+// Config asset
+// ---------------------------------------------------------------------------
+
+/// A single sound file reference with its playback volume.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct SoundEntry {
+    /// Asset path relative to `assets/`, e.g. `"sounds/bgm/bgm_title.ogg"`.
+    pub path: String,
+    /// Linear volume in `0.0..=1.0` (1.0 = unattenuated).
+    #[serde(default = "default_volume")]
+    pub volume: f32,
+}
+
+fn default_volume() -> f32 {
+    1.0
+}
+
+/// Deserialization mirror of [`AudioConfig`] — every field is `Option<T>` so
+/// RON files with missing fields still load and emit a `warn!` instead of failing.
+#[derive(Deserialize, Default)]
+#[serde(default, rename = "AudioConfig")]
+pub(crate) struct AudioConfigPartial {
+    pub gameplay_late_after_secs: Option<f32>,
+    pub max_sfx_voices: Option<usize>,
+    pub sfx_limits: Option<HashMap<SfxGroup, SfxLimit>>,
+    pub bgm: Option<HashMap<BgmTrack, SoundEntry>>,
+    pub sfx: Option<HashMap<SfxCue, SoundEntry>>,
+}
+
+/// Audio mapping config loaded from `config/audio.ron`.
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct AudioConfig {
+    /// Seconds into a run after which [`BgmTrack::GameplayLate`] replaces
+    /// [`BgmTrack::Gameplay`].
+    pub gameplay_late_after_secs: f32,
+    /// Size of the sound-effect voice pool.
+    pub max_sfx_voices: usize,
+    /// Throttling limits per SFX group.  Missing groups use
+    /// [`default_sfx_limit`].
+    pub sfx_limits: HashMap<SfxGroup, SfxLimit>,
+    /// Background music per track.  Missing tracks play nothing.
+    pub bgm: HashMap<BgmTrack, SoundEntry>,
+    /// Sound effect per cue.  Missing cues play nothing.
+    pub sfx: HashMap<SfxCue, SoundEntry>,
+}
+
+impl From<AudioConfigPartial> for AudioConfig {
+    fn from(p: AudioConfigPartial) -> Self {
+        AudioConfig {
+            gameplay_late_after_secs: p.gameplay_late_after_secs.unwrap_or_else(|| {
+                warn!(
+                    "audio.ron: `gameplay_late_after_secs` missing → using default {DEFAULT_GAMEPLAY_LATE_AFTER_SECS}"
+                );
+                DEFAULT_GAMEPLAY_LATE_AFTER_SECS
+            }),
+            max_sfx_voices: p.max_sfx_voices.unwrap_or_else(|| {
+                warn!(
+                    "audio.ron: `max_sfx_voices` missing → using default {DEFAULT_MAX_SFX_VOICES}"
+                );
+                DEFAULT_MAX_SFX_VOICES
+            }),
+            sfx_limits: p.sfx_limits.unwrap_or_else(|| {
+                warn!("audio.ron: `sfx_limits` missing → using built-in limits");
+                HashMap::new()
+            }),
+            bgm: p.bgm.unwrap_or_else(|| {
+                warn!("audio.ron: `bgm` missing → all background music is silent");
+                HashMap::new()
+            }),
+            sfx: p.sfx.unwrap_or_else(|| {
+                warn!("audio.ron: `sfx` missing → all sound effects are silent");
+                HashMap::new()
+            }),
+        }
+    }
+}
+
+/// Resource holding the handle to the loaded [`AudioConfig`].
+#[derive(Resource)]
+pub struct AudioConfigHandle(pub Handle<AudioConfig>);
+
+// ---------------------------------------------------------------------------
+// Asset loader
+// ---------------------------------------------------------------------------
+
+/// RON loader for [`AudioConfig`] (mirrors `ron_asset_loader!` in vs-core).
+#[derive(Default)]
+pub(crate) struct AudioConfigLoader;
+
+impl AssetLoader for AudioConfigLoader {
+    type Asset = AudioConfig;
+    type Settings = ();
+    type Error = std::io::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let options = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME);
+        let partial: AudioConfigPartial = options
+            .from_bytes(&bytes)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        Ok(AudioConfig::from(partial))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ron"]
+    }
+}
+
+// ---------------------------------------------------------------------------
+// SystemParam bundle
+// ---------------------------------------------------------------------------
+
+/// SystemParam bundle for accessing [`AudioConfig`].
+///
+/// Returns `None` while the asset is still loading or when
+/// [`crate::GameAudioPlugin`] has not been registered (e.g. in unit tests).
+#[derive(SystemParam)]
+pub struct AudioParams<'w> {
+    handle: Option<Res<'w, AudioConfigHandle>>,
+    assets: Option<Res<'w, Assets<AudioConfig>>>,
+}
+
+impl<'w> AudioParams<'w> {
+    /// Returns the currently loaded [`AudioConfig`], or `None`.
+    pub fn get(&self) -> Option<&AudioConfig> {
+        self.handle
+            .as_ref()
+            .and_then(|h| self.assets.as_ref().and_then(|a| a.get(&h.0)))
+    }
+
+    pub fn gameplay_late_after_secs(&self) -> f32 {
+        self.get()
+            .map(|c| c.gameplay_late_after_secs)
+            .unwrap_or(DEFAULT_GAMEPLAY_LATE_AFTER_SECS)
+    }
+
+    pub fn max_sfx_voices(&self) -> usize {
+        self.get()
+            .map(|c| c.max_sfx_voices)
+            .unwrap_or(DEFAULT_MAX_SFX_VOICES)
+    }
+
+    /// Throttling limit for `group`, or the built-in limit when the config is
+    /// not loaded or does not list the group.
+    pub fn sfx_limit(&self, group: SfxGroup) -> SfxLimit {
+        self.get()
+            .and_then(|c| c.sfx_limits.get(&group).copied())
+            .unwrap_or_else(|| default_sfx_limit(group))
+    }
+
+    /// Volume configured for `cue`, or `1.0` when the config is not loaded.
+    pub fn sfx_volume(&self, cue: SfxCue) -> f32 {
+        self.get()
+            .and_then(|c| c.sfx.get(&cue))
+            .map(|e| e.volume)
+            .unwrap_or(1.0)
+    }
+
+    /// Volume configured for `track`, or `1.0` when the config is not loaded.
+    pub fn bgm_volume(&self, track: BgmTrack) -> f32 {
+        self.get()
+            .and_then(|c| c.bgm.get(&track))
+            .map(|e| e.volume)
+            .unwrap_or(1.0)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Hot-reload system
+// ---------------------------------------------------------------------------
+
+/// Logs when `config/audio.ron` is loaded or hot-reloaded.
+pub fn hot_reload_audio_config(mut events: MessageReader<AssetEvent<AudioConfig>>) {
+    for event in events.read() {
+        match event {
+            AssetEvent::Added { .. } => {
+                info!("✅ Audio config loaded");
+            }
+            AssetEvent::Modified { .. } => {
+                info!("🔥 Audio config hot-reloaded");
+            }
+            AssetEvent::Removed { .. } => {
+                warn!("⚠️ Audio config removed");
+            }
+            _ => {}
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(ron_data: &str) -> AudioConfig {
+        let partial: AudioConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(ron_data)
+            .expect("RON parse must succeed");
+        AudioConfig::from(partial)
+    }
+
+    #[test]
+    fn audio_config_deserialization() {
+        let cfg = parse(
+            r#"
+AudioConfig(
+    gameplay_late_after_secs: 600.0,
+    max_sfx_voices: 16,
+    sfx_limits: {
+        EnemyDeath: (max_voices: 3, min_interval: 0.05, voice_duration: 0.25),
+    },
+    bgm: {
+        Title: (path: "sounds/bgm/bgm_title.ogg", volume: 0.7),
+    },
+    sfx: {
+        LevelUp: (path: "sounds/sfx/events/sfx_level_up.ogg", volume: 0.8),
+        EnemyDieSmall: (path: "sounds/sfx/enemies/sfx_enemy_die_small.ogg"),
+    },
+)
+"#,
+        );
+
+        assert_eq!(cfg.gameplay_late_after_secs, 600.0);
+        assert_eq!(cfg.max_sfx_voices, 16);
+        assert_eq!(cfg.sfx_limits[&SfxGroup::EnemyDeath].max_voices, 3);
+        assert_eq!(cfg.bgm[&BgmTrack::Title].path, "sounds/bgm/bgm_title.ogg");
+        assert!((cfg.bgm[&BgmTrack::Title].volume - 0.7).abs() < 1e-6);
+        assert!((cfg.sfx[&SfxCue::LevelUp].volume - 0.8).abs() < 1e-6);
+        assert_eq!(
+            cfg.sfx[&SfxCue::EnemyDieSmall].volume,
+            1.0,
+            "omitted volume defaults to 1.0"
+        );
+    }
+
+    #[test]
+    fn audio_config_missing_fields_fall_back() {
+        let cfg = parse("AudioConfig()");
+
+        assert_eq!(
+            cfg.gameplay_late_after_secs,
+            DEFAULT_GAMEPLAY_LATE_AFTER_SECS
+        );
+        assert_eq!(cfg.max_sfx_voices, DEFAULT_MAX_SFX_VOICES);
+        assert!(cfg.sfx_limits.is_empty());
+        assert!(cfg.bgm.is_empty());
+        assert!(cfg.sfx.is_empty());
+    }
+
+    /// The shipped `audio.ron` must parse and map every BGM track.
+    #[test]
+    fn shipped_audio_ron_maps_every_track() {
+        let cfg = parse(include_str!(
+            "../../vampire-survivors/assets/config/audio.ron"
+        ));
+
+        for track in BgmTrack::ALL {
+            assert!(cfg.bgm.contains_key(&track), "{track:?} must be mapped");
+        }
+        for cue in SfxCue::ALL {
+            assert!(cfg.sfx.contains_key(&cue), "{cue:?} must be mapped");
+        }
+        for group in SfxGroup::ALL {
+            assert!(
+                cfg.sfx_limits.contains_key(&group),
+                "{group:?} must have a limit"
+            );
+        }
+    }
+}
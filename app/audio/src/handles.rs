@@ -0,0 +1,194 @@
+//! Sound file handles resolved from [`AudioConfig`].
+//!
+//! [`load_sound_handles`] (re)builds [`SoundHandles`] whenever `audio.ron`
+//! is loaded or hot-reloaded.  A handle for a file that does not exist never
+//! reaches [`Assets<AudioSource>`], so the `loaded_*` lookups return `None`
+//! and the cue stays silent instead of queueing a play command that would
+//! retry forever.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_kira_audio::{AudioChannel, AudioControl, AudioSource};
+
+use crate::BgmChannel;
+use crate::bgm::{BgmTrack, CurrentBgm};
+use crate::config::{AudioConfig, AudioConfigHandle};
+use crate::sfx::SfxCue;
+
+// ---------------------------------------------------------------------------
+// Resource
+// ---------------------------------------------------------------------------
+
+/// Audio source handles for every mapped BGM track and SFX cue.
+///
+/// Empty until `audio.ron` finishes loading.
+#[derive(Resource, Debug, Default)]
+pub struct SoundHandles {
+    pub bgm: HashMap<BgmTrack, Handle<AudioSource>>,
+    pub sfx: HashMap<SfxCue, Handle<AudioSource>>,
+}
+
+impl SoundHandles {
+    /// Returns the handle for `track` only if its file has finished loading.
+    pub fn loaded_bgm(
+        &self,
+        track: BgmTrack,
+        sources: &Assets<AudioSource>,
+    ) -> Option<Handle<AudioSource>> {
+        self.bgm
+            .get(&track)
+            .filter(|h| sources.contains(h.id()))
+            .cloned()
+    }
+
+    /// Returns the handle for `cue` only if its file has finished loading.
+    pub fn loaded_sfx(
+        &self,
+        cue: SfxCue,
+        sources: &Assets<AudioSource>,
+    ) -> Option<Handle<AudioSource>> {
+        self.sfx
+            .get(&cue)
+            .filter(|h| sources.contains(h.id()))
+            .cloned()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// System
+// ---------------------------------------------------------------------------
+
+/// Requests every sound file listed in `audio.ron` from the asset server.
+///
+/// Runs on `Added` and `Modified` events for the audio config.  On a
+/// hot-reload the [`BgmChannel`] is also stopped and [`CurrentBgm`] marked
+/// as not started, so [`crate::bgm::update_bgm`] restarts the current track
+/// from its (possibly new) file once it loads; sound effects pick up new
+/// paths on their next cue.
+pub fn load_sound_handles(
+    mut events: MessageReader<AssetEvent<AudioConfig>>,
+    config_handle: Option<Res<AudioConfigHandle>>,
+    configs: Res<Assets<AudioConfig>>,
+    asset_server: Res<AssetServer>,
+    channel: Option<Res<AudioChannel<BgmChannel>>>,
+    mut handles: ResMut<SoundHandles>,
+    mut current: ResMut<CurrentBgm>,
+) {
+    let Some(config_handle) = config_handle else {
+        return;
+    };
+    let (mut changed, mut modified) = (false, false);
+    for event in events.read() {
+        match event {
+            AssetEvent::Added { id } if *id == config_handle.0.id() => changed = true,
+            AssetEvent::Modified { id } if *id == config_handle.0.id() => {
+                changed = true;
+                modified = true;
+            }
+            _ => {}
+        }
+    }
+    if !changed {
+        return;
+    }
+    let Some(config) = configs.get(&config_handle.0) else {
+        return;
+    };
+
+    if modified {
+        if let Some(channel) = channel.as_deref() {
+            channel.stop();
+        }
+        current.started = false;
+    }
+
+    handles.bgm = config
+        .bgm
+        .iter()
+        .map(|(track, entry)| (*track, asset_server.load(entry.path.clone())))
+        .collect();
+    handles.sfx = config
+        .sfx
+        .iter()
+        .map(|(cue, entry)| (*cue, asset_server.load(entry.path.clone())))
+        .collect();
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce as _;
+
+    use super::*;
+
+    /// Builds an app with an empty `audio.ron` loaded and the title track
+    /// already playing.
+    fn build_app() -> (App, Handle<AudioConfig>) {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, bevy::asset::AssetPlugin::default()));
+        app.init_asset::<AudioConfig>();
+        app.init_resource::<SoundHandles>();
+        app.insert_resource(CurrentBgm {
+            track: Some(BgmTrack::Title),
+            started: true,
+        });
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<AudioConfig>>()
+            .add(AudioConfig {
+                gameplay_late_after_secs: 900.0,
+                max_sfx_voices: 24,
+                sfx_limits: HashMap::new(),
+                bgm: HashMap::new(),
+                sfx: HashMap::new(),
+            });
+        app.insert_resource(AudioConfigHandle(handle.clone()));
+        (app, handle)
+    }
+
+    /// Hot-reloading `audio.ron` restarts the current track so an edited
+    /// path is heard; the initial load leaves playback alone.
+    #[test]
+    fn hot_reload_restarts_current_bgm() {
+        let (mut app, handle) = build_app();
+
+        app.world_mut()
+            .write_message(AssetEvent::Added { id: handle.id() });
+        app.world_mut()
+            .run_system_once(load_sound_handles)
+            .expect("load_sound_handles should run");
+        assert!(app.world().resource::<CurrentBgm>().started);
+
+        app.world_mut()
+            .write_message(AssetEvent::Modified { id: handle.id() });
+        app.world_mut()
+            .run_system_once(load_sound_handles)
+            .expect("load_sound_handles should run");
+        let current = app.world().resource::<CurrentBgm>();
+        assert_eq!(current.track, Some(BgmTrack::Title));
+        assert!(
+            !current.started,
+            "a reloaded config must make update_bgm queue the track again"
+        );
+    }
+
+    /// Handles that never finished loading are reported as unavailable.
+    #[test]
+    fn unloaded_handles_are_not_returned() {
+        let mut handles = SoundHandles::default();
+        handles.bgm.insert(BgmTrack::Title, Handle::default());
+        handles.sfx.insert(SfxCue::LevelUp, Handle::default());
+        let sources = Assets::<AudioSource>::default();
+
+        assert!(handles.loaded_bgm(BgmTrack::Title, &sources).is_none());
+        assert!(handles.loaded_sfx(SfxCue::LevelUp, &sources).is_none());
+        assert!(
+            handles.loaded_sfx(SfxCue::BossDie, &sources).is_none(),
+            "unmapped cues must be silent"
+        );
+    }
+}
@@ -1,10 +1,111 @@
+//! # vs-audio
+//!
+//! Background music and sound effects for the Vampire Survivors clone.
+//!
+//! ## Modules
+//!
+//! - [`config`]: `audio.ron` mapping of tracks / cues to sound files
+//! - [`handles`]: sound file handles resolved from the config
+//! - [`bgm`]: per-state background music on the [`BgmChannel`]
+//! - [`sfx`]: gameplay-event → sound-effect routing on the [`SfxChannel`]
+//! - [`voices`]: per-category throttling and voice stealing for SFX
+//! - [`volume`]: player volume / mute settings applied to both channels
+//!
+//! Every sound is optional: a missing file (or a missing mapping) simply
+//! plays nothing, so the game and headless tests run without audio assets.
+
 use bevy::prelude::*;
-use bevy_kira_audio::AudioPlugin;
+use bevy_kira_audio::prelude::{AudioApp, AudioPlugin, Decibels};
+
+pub mod bgm;
+pub mod config;
+pub mod handles;
+pub mod sfx;
+pub mod voices;
+pub mod volume;
+
+use config::{AudioConfig, AudioConfigHandle, AudioConfigLoader};
+
+/// Kira channel marker for background music.
+#[derive(Resource)]
+pub struct BgmChannel;
+
+/// Kira channel marker for sound effects.
+#[derive(Resource)]
+pub struct SfxChannel;
 
+/// Converts a linear volume (`0.0..=1.0`) to kira [`Decibels`].
+///
+/// Zero and negative volumes map to [`Decibels::SILENCE`].
+pub fn linear_to_decibels(volume: f32) -> Decibels {
+    if volume <= 0.0 {
+        return Decibels::SILENCE;
+    }
+    Decibels((20.0 * volume.log10()).max(Decibels::SILENCE.0))
+}
+
+/// Audio plugin.
+///
+/// Adds the kira backend, the BGM / SFX channels, loads `config/audio.ron`,
+/// and wires the music and sound-effect systems.
+/// Add this plugin to the app after [`vs_core::GameCorePlugin`] so that the
+/// gameplay messages it listens to are already registered.
 pub struct GameAudioPlugin;
 
 impl Plugin for GameAudioPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(AudioPlugin);
+        app.add_plugins(AudioPlugin)
+            .add_audio_channel::<BgmChannel>()
+            .add_audio_channel::<SfxChannel>()
+            .add_message::<sfx::PlaySfx>()
+            .add_message::<voices::ScheduledSfx>()
+            .init_asset::<AudioConfig>()
+            .register_asset_loader(AudioConfigLoader)
+            .init_resource::<handles::SoundHandles>()
+            .init_resource::<bgm::CurrentBgm>()
+            .init_resource::<voices::SfxVoicePool>();
+
+        let audio_handle: Handle<AudioConfig> = app
+            .world_mut()
+            .resource::<AssetServer>()
+            .load("config/audio.ron");
+        app.insert_resource(AudioConfigHandle(audio_handle));
+
+        app.add_systems(
+            Update,
+            (
+                config::hot_reload_audio_config,
+                volume::apply_volume_settings,
+                handles::load_sound_handles,
+                bgm::update_bgm.after(handles::load_sound_handles),
+                (sfx::route_sfx, voices::schedule_sfx, sfx::play_sfx).chain(),
+            ),
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_volume_is_zero_decibels() {
+        assert_eq!(linear_to_decibels(1.0), Decibels::IDENTITY);
+    }
+
+    #[test]
+    fn zero_volume_is_silence() {
+        assert_eq!(linear_to_decibels(0.0), Decibels::SILENCE);
+        assert_eq!(linear_to_decibels(-1.0), Decibels::SILENCE);
+        assert_eq!(linear_to_decibels(1e-9), Decibels::SILENCE);
+    }
+
+    #[test]
+    fn half_volume_is_about_minus_six_decibels() {
+        assert!((linear_to_decibels(0.5).0 + 6.02).abs() < 0.01);
     }
 }
@@ -0,0 +1,434 @@
+//! Sound-effect routing and playback.
+//!
+//! Routing is split in three stages so it can be tested without an audio
+//! device:
+//!
+//! 1. [`route_sfx`] reads the vs-core gameplay messages and translates each
+//!    into zero or more [`PlaySfx`] requests using the pure mapping functions
+//!    [`cue_for_weapon_sound`] and [`cue_for_enemy_death`].  A weapon's
+//!    [`WeaponSound`] comes from its spec in the [`WeaponRegistry`].
+//! 2. [`schedule_sfx`](crate::voices::schedule_sfx) throttles the requests
+//!    through the voice pool and writes a [`ScheduledSfx`] for each one that
+//!    may play.
+//! 3. [`play_sfx`] drains [`ScheduledSfx`] and queues each cue on the
+//!    [`SfxChannel`] — but only when the cue's sound file is loaded.  Cues
+//!    with a missing file are dropped silently.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_kira_audio::{AudioChannel, AudioControl, AudioInstance, AudioSource, AudioTween};
+use serde::Deserialize;
+use vs_core::events::{
+    BossSpawnedEvent, EnemyDiedEvent, GameOverEvent, GemCollectedEvent, LevelUpEvent,
+    PlayerDamagedEvent, TreasureOpenedEvent, VictoryEvent, WeaponFiredEvent,
+};
+use vs_core::systems::weapons::registry::WeaponRegistry;
+use vs_core::types::{EnemyType, WeaponSound, WeaponType};
+
+use crate::SfxChannel;
+use crate::config::AudioParams;
+use crate::handles::SoundHandles;
+use crate::linear_to_decibels;
+use crate::voices::{ScheduledSfx, SfxCategory, SfxVoicePool};
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// Every sound effect the game can play.
+///
+/// Cues are deliberately coarser than the gameplay events: several weapons
+/// share a projectile sound, and enemy deaths are bucketed by size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum SfxCue {
+    WeaponWhip,
+    ProjectileSmall,
+    ProjectileLarge,
+    Thunder,
+    AuraTick,
+    EnemyDieSmall,
+    EnemyDieLarge,
+    BossSpawn,
+    BossDie,
+    PlayerHit,
+    PlayerDie,
+    GemPickup,
+    LevelUp,
+    TreasureOpen,
+    Victory,
+}
+
+impl SfxCue {
+    /// All cues, in declaration order.
+    pub const ALL: [SfxCue; 15] = [
+        SfxCue::WeaponWhip,
+        SfxCue::ProjectileSmall,
+        SfxCue::ProjectileLarge,
+        SfxCue::Thunder,
+        SfxCue::AuraTick,
+        SfxCue::EnemyDieSmall,
+        SfxCue::EnemyDieLarge,
+        SfxCue::BossSpawn,
+        SfxCue::BossDie,
+        SfxCue::PlayerHit,
+        SfxCue::PlayerDie,
+        SfxCue::GemPickup,
+        SfxCue::LevelUp,
+        SfxCue::TreasureOpen,
+        SfxCue::Victory,
+    ];
+}
+
+/// Request to play a sound effect this frame.
+///
+/// Written by [`route_sfx`] and consumed by
+/// [`schedule_sfx`](crate::voices::schedule_sfx), which may drop it when its
+/// [`SfxCategory`] is throttled.  Other crates may also write it directly
+/// (e.g. UI click sounds).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlaySfx {
+    pub cue: SfxCue,
+    pub category: SfxCategory,
+}
+
+impl PlaySfx {
+    /// Request for `cue`, throttled under its default category.
+    pub fn new(cue: SfxCue) -> Self {
+        Self {
+            cue,
+            category: SfxCategory::for_cue(cue),
+        }
+    }
+
+    /// Request for a weapon firing sound, throttled per `weapon_type`.
+    pub fn weapon(cue: SfxCue, weapon_type: WeaponType) -> Self {
+        Self {
+            cue,
+            category: SfxCategory::WeaponFire(weapon_type),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Cue mapping
+// ---------------------------------------------------------------------------
+
+/// Returns the cue that plays a weapon's [`WeaponSound`].
+pub fn cue_for_weapon_sound(sound: WeaponSound) -> SfxCue {
+    match sound {
+        WeaponSound::Whip => SfxCue::WeaponWhip,
+        WeaponSound::ProjectileSmall => SfxCue::ProjectileSmall,
+        WeaponSound::ProjectileLarge => SfxCue::ProjectileLarge,
+        WeaponSound::Thunder => SfxCue::Thunder,
+        WeaponSound::AuraTick => SfxCue::AuraTick,
+    }
+}
+
+/// Returns the death sound for `enemy_type`.
+pub fn cue_for_enemy_death(enemy_type: EnemyType) -> SfxCue {
+    match enemy_type {
+        EnemyType::BossDeath => SfxCue::BossDie,
+        EnemyType::MiniBoss | EnemyType::Dragon | EnemyType::Demon => SfxCue::EnemyDieLarge,
+        EnemyType::Bat
+        | EnemyType::Skeleton
+        | EnemyType::Zombie
+        | EnemyType::Ghost
+        | EnemyType::Medusa
+        | EnemyType::MiniDeath => SfxCue::EnemyDieSmall,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Message readers for every gameplay event that produces a sound.
+#[derive(SystemParam)]
+pub struct SfxSourceEvents<'w, 's> {
+    weapon_fired: MessageReader<'w, 's, WeaponFiredEvent>,
+    enemy_died: MessageReader<'w, 's, EnemyDiedEvent>,
+    player_damaged: MessageReader<'w, 's, PlayerDamagedEvent>,
+    level_up: MessageReader<'w, 's, LevelUpEvent>,
+    boss_spawned: MessageReader<'w, 's, BossSpawnedEvent>,
+    treasure_opened: MessageReader<'w, 's, TreasureOpenedEvent>,
+    game_over: MessageReader<'w, 's, GameOverEvent>,
+    victory: MessageReader<'w, 's, VictoryEvent>,
+    gem_collected: MessageReader<'w, 's, GemCollectedEvent>,
+}
+
+/// Translates gameplay messages into [`PlaySfx`] requests.
+///
+/// Runs in every state so that messages written on the frame of a state
+/// change (e.g. `GameOverEvent`) are never missed.  Weapons without a
+/// registered firing sound stay silent.
+pub fn route_sfx(
+    mut events: SfxSourceEvents,
+    registry: Res<WeaponRegistry>,
+    mut sfx: MessageWriter<PlaySfx>,
+) {
+    for event in events.weapon_fired.read() {
+        if let Some(sound) = registry.fire_sound(event.weapon_type) {
+            sfx.write(PlaySfx::weapon(
+                cue_for_weapon_sound(sound),
+                event.weapon_type,
+            ));
+        }
+    }
+    for event in events.enemy_died.read() {
+        sfx.write(PlaySfx::new(cue_for_enemy_death(event.enemy_type)));
+    }
+    for _ in events.player_damaged.read() {
+        sfx.write(PlaySfx::new(SfxCue::PlayerHit));
+    }
+    for _ in events.level_up.read() {
+        sfx.write(PlaySfx::new(SfxCue::LevelUp));
+    }
+    for _ in events.boss_spawned.read() {
+        sfx.write(PlaySfx::new(SfxCue::BossSpawn));
+    }
+    for _ in events.treasure_opened.read() {
+        sfx.write(PlaySfx::new(SfxCue::TreasureOpen));
+    }
+    for _ in events.game_over.read() {
+        sfx.write(PlaySfx::new(SfxCue::PlayerDie));
+    }
+    for _ in events.victory.read() {
+        sfx.write(PlaySfx::new(SfxCue::Victory));
+    }
+    for _ in events.gem_collected.read() {
+        sfx.write(PlaySfx::new(SfxCue::GemPickup));
+    }
+}
+
+/// Stops voices stolen by the pool, then queues every [`ScheduledSfx`] on
+/// the [`SfxChannel`].
+///
+/// Requests are consumed even when they cannot be played (no audio backend,
+/// file missing or still loading) so that nothing piles up.
+pub fn play_sfx(
+    mut requests: MessageReader<ScheduledSfx>,
+    mut pool: ResMut<SfxVoicePool>,
+    audio_cfg: AudioParams,
+    handles: Res<SoundHandles>,
+    sources: Option<Res<Assets<AudioSource>>>,
+    instances: Option<ResMut<Assets<AudioInstance>>>,
+    channel: Option<Res<AudioChannel<SfxChannel>>>,
+) {
+    let stolen = pool.take_stolen();
+    if let Some(mut instances) = instances {
+        for handle in stolen {
+            if let Some(instance) = instances.get_mut(&handle) {
+                instance.stop(AudioTween::default());
+            }
+        }
+    }
+
+    let (Some(channel), Some(sources)) = (channel.as_deref(), sources.as_deref()) else {
+        requests.clear();
+        return;
+    };
+
+    for request in requests.read() {
+        let Some(handle) = handles.loaded_sfx(request.cue, sources) else {
+            continue;
+        };
+        let instance = channel
+            .play(handle)
+            .with_volume(linear_to_decibels(audio_cfg.sfx_volume(request.cue)))
+            .handle();
+        pool.attach(request.voice, instance);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce as _;
+
+    use super::*;
+
+    fn build_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<WeaponFiredEvent>()
+            .add_message::<EnemyDiedEvent>()
+            .add_message::<PlayerDamagedEvent>()
+            .add_message::<LevelUpEvent>()
+            .add_message::<BossSpawnedEvent>()
+            .add_message::<TreasureOpenedEvent>()
+            .add_message::<GameOverEvent>()
+            .add_message::<VictoryEvent>()
+            .add_message::<GemCollectedEvent>()
+            .add_message::<PlaySfx>()
+            .add_message::<ScheduledSfx>();
+        app.init_resource::<SoundHandles>()
+            .init_resource::<SfxVoicePool>()
+            .insert_resource(WeaponRegistry::with_builtin_weapons());
+        app
+    }
+
+    fn run_route(app: &mut App) {
+        app.world_mut()
+            .run_system_once(route_sfx)
+            .expect("route_sfx should run");
+    }
+
+    fn requested(app: &App) -> Vec<SfxCue> {
+        let messages = app.world().resource::<Messages<PlaySfx>>();
+        let mut cursor = messages.get_cursor();
+        cursor.read(messages).map(|m| m.cue).collect()
+    }
+
+    fn died(enemy_type: EnemyType) -> EnemyDiedEvent {
+        EnemyDiedEvent {
+            entity: Entity::PLACEHOLDER,
+            position: Vec2::ZERO,
+            enemy_type,
+            xp_value: 1,
+            gold_chance: 0.0,
+            elite: None,
+            mini_boss: None,
+        }
+    }
+
+    /// Every weapon sound has its own cue.
+    #[test]
+    fn weapon_sounds_map_to_distinct_cues() {
+        let cues: std::collections::HashSet<SfxCue> = [
+            WeaponSound::Whip,
+            WeaponSound::ProjectileSmall,
+            WeaponSound::ProjectileLarge,
+            WeaponSound::Thunder,
+            WeaponSound::AuraTick,
+        ]
+        .into_iter()
+        .map(cue_for_weapon_sound)
+        .collect();
+        assert_eq!(cues.len(), 5);
+    }
+
+    /// Enemy deaths are bucketed into small / large / boss.
+    #[test]
+    fn enemy_death_cue_buckets() {
+        assert_eq!(cue_for_enemy_death(EnemyType::Bat), SfxCue::EnemyDieSmall);
+        assert_eq!(
+            cue_for_enemy_death(EnemyType::Dragon),
+            SfxCue::EnemyDieLarge
+        );
+        assert_eq!(cue_for_enemy_death(EnemyType::BossDeath), SfxCue::BossDie);
+    }
+
+    /// Each gameplay message produces the matching cue.
+    #[test]
+    fn route_sfx_maps_every_event() {
+        let mut app = build_app();
+        let world = app.world_mut();
+        world.write_message(WeaponFiredEvent {
+            player: Entity::PLACEHOLDER,
+            weapon_type: WeaponType::Whip,
+            level: 1,
+        });
+        world.write_message(died(EnemyType::Bat));
+        world.write_message(PlayerDamagedEvent {
+            player: Entity::PLACEHOLDER,
+            damage: 1.0,
+        });
+        world.write_message(LevelUpEvent { new_level: 2 });
+        world.write_message(BossSpawnedEvent);
+        world.write_message(TreasureOpenedEvent {
+            position: Vec2::ZERO,
+        });
+        world.write_message(GameOverEvent);
+        world.write_message(VictoryEvent);
+        world.write_message(GemCollectedEvent {
+            position: Vec2::ZERO,
+            value: 1,
+        });
+
+        run_route(&mut app);
+
+        assert_eq!(
+            requested(&app),
+            vec![
+                SfxCue::WeaponWhip,
+                SfxCue::EnemyDieSmall,
+                SfxCue::PlayerHit,
+                SfxCue::LevelUp,
+                SfxCue::BossSpawn,
+                SfxCue::TreasureOpen,
+                SfxCue::PlayerDie,
+                SfxCue::Victory,
+                SfxCue::GemPickup,
+            ]
+        );
+    }
+
+    /// Weapon cues are throttled per weapon type; other cues use their
+    /// default category.
+    #[test]
+    fn route_sfx_tags_weapon_category() {
+        let mut app = build_app();
+        app.world_mut().write_message(WeaponFiredEvent {
+            player: Entity::PLACEHOLDER,
+            weapon_type: WeaponType::HolyWand,
+            level: 1,
+        });
+        app.world_mut().write_message(died(EnemyType::Bat));
+
+        run_route(&mut app);
+
+        let messages = app.world().resource::<Messages<PlaySfx>>();
+        let mut cursor = messages.get_cursor();
+        let categories: Vec<SfxCategory> = cursor.read(messages).map(|m| m.category).collect();
+        assert_eq!(
+            categories,
+            vec![
+                SfxCategory::WeaponFire(WeaponType::HolyWand),
+                SfxCategory::EnemyDeath,
+            ]
+        );
+    }
+
+    /// Silent weapons produce no request.
+    #[test]
+    fn route_sfx_skips_silent_weapons() {
+        let mut app = build_app();
+        app.world_mut().write_message(WeaponFiredEvent {
+            player: Entity::PLACEHOLDER,
+            weapon_type: WeaponType::Bible,
+            level: 1,
+        });
+
+        run_route(&mut app);
+
+        assert!(requested(&app).is_empty());
+    }
+
+    /// Without an audio backend or loaded files, playback is a silent no-op.
+    #[test]
+    fn play_sfx_without_audio_is_silent() {
+        let mut app = build_app();
+        let mut pool = SfxVoicePool::default();
+        let voice = pool
+            .try_acquire(
+                SfxCategory::Event,
+                SfxCue::LevelUp.priority(),
+                crate::voices::default_sfx_limit(crate::voices::SfxGroup::Event),
+                8,
+                0.0,
+            )
+            .expect("empty pool grants a voice");
+        app.insert_resource(pool);
+        app.world_mut().write_message(ScheduledSfx {
+            cue: SfxCue::LevelUp,
+            voice,
+        });
+
+        app.world_mut()
+            .run_system_once(play_sfx)
+            .expect("play_sfx should run without an audio backend");
+    }
+}
@@ -0,0 +1,560 @@
+//! Sound-effect voice limiting.
+//!
+//! A Garlic or Holy Wand sweep can kill hundreds of enemies in a single
+//! frame; playing one death sound per [`EnemyDiedEvent`] would schedule
+//! hundreds of identical sounds at once.  [`schedule_sfx`] sits between
+//! [`route_sfx`] and [`play_sfx`] and decides, for every [`PlaySfx`]
+//! request, whether it gets a voice:
+//!
+//! 1. **Retrigger interval** — a category that started a voice less than
+//!    `min_interval` seconds ago is dropped.
+//! 2. **Category cap** — a category that already has `max_voices` voices
+//!    sounding is dropped.
+//! 3. **Voice pool** — when all `max_sfx_voices` voices are busy, the request
+//!    steals the oldest voice of strictly lower [`SfxPriority`], or is
+//!    dropped if there is none.
+//!
+//! [`SfxPriority::Critical`] cues (boss, level-up and result cues) skip
+//! steps 1 and 2 and always play, stealing a voice when the pool is full.
+//!
+//! Voices are tracked by an estimated duration (`voice_duration`) instead of
+//! polling kira, so the pool behaves identically with and without an audio
+//! backend and can be tested by counting [`ScheduledSfx`] messages.
+//!
+//! [`EnemyDiedEvent`]: vs_core::events::EnemyDiedEvent
+//! [`route_sfx`]: crate::sfx::route_sfx
+//! [`play_sfx`]: crate::sfx::play_sfx
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_kira_audio::AudioInstance;
+use serde::Deserialize;
+use vs_core::types::WeaponType;
+
+use crate::config::AudioParams;
+use crate::sfx::{PlaySfx, SfxCue};
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/// How important a cue is when the voice pool is full.
+///
+/// Ordered from lowest to highest; a request may only steal a voice of
+/// strictly lower priority.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SfxPriority {
+    /// High-volume, interchangeable sounds (weapon fire, small deaths, gems).
+    Low,
+    /// Noticeable one-off sounds (player hit, large deaths, chests).
+    Normal,
+    /// Cues that must always be heard (boss, level-up, result cues).
+    Critical,
+}
+
+/// Throttling bucket a sound effect is counted against.
+///
+/// Weapon fire is throttled per [`WeaponType`] so that a fast Knife does not
+/// starve the Whip of voices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SfxCategory {
+    EnemyDeath,
+    GemPickup,
+    WeaponFire(WeaponType),
+    Player,
+    Event,
+    Other,
+}
+
+/// Config key for a family of [`SfxCategory`] buckets.
+///
+/// All `WeaponFire(_)` categories share the [`SfxGroup::WeaponFire`] limit,
+/// but each weapon type gets its own voice count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum SfxGroup {
+    EnemyDeath,
+    GemPickup,
+    WeaponFire,
+    Player,
+    Event,
+    Other,
+}
+
+impl SfxGroup {
+    /// All groups, in declaration order.
+    pub const ALL: [SfxGroup; 6] = [
+        SfxGroup::EnemyDeath,
+        SfxGroup::GemPickup,
+        SfxGroup::WeaponFire,
+        SfxGroup::Player,
+        SfxGroup::Event,
+        SfxGroup::Other,
+    ];
+}
+
+impl SfxCategory {
+    /// Default category for `cue` when no weapon type is known.
+    ///
+    /// Weapon cues requested without a weapon (e.g. from UI code) fall into
+    /// [`SfxCategory::Other`]; [`route_sfx`](crate::sfx::route_sfx) uses
+    /// [`PlaySfx::weapon`] instead.
+    pub fn for_cue(cue: SfxCue) -> Self {
+        match cue {
+            SfxCue::EnemyDieSmall | SfxCue::EnemyDieLarge => SfxCategory::EnemyDeath,
+            SfxCue::GemPickup => SfxCategory::GemPickup,
+            SfxCue::PlayerHit | SfxCue::PlayerDie => SfxCategory::Player,
+            SfxCue::BossSpawn
+            | SfxCue::BossDie
+            | SfxCue::LevelUp
+            | SfxCue::TreasureOpen
+            | SfxCue::Victory => SfxCategory::Event,
+            SfxCue::WeaponWhip
+            | SfxCue::ProjectileSmall
+            | SfxCue::ProjectileLarge
+            | SfxCue::Thunder
+            | SfxCue::AuraTick => SfxCategory::Other,
+        }
+    }
+
+    /// The config group whose [`SfxLimit`] applies to this category.
+    pub fn group(self) -> SfxGroup {
+        match self {
+            SfxCategory::EnemyDeath => SfxGroup::EnemyDeath,
+            SfxCategory::GemPickup => SfxGroup::GemPickup,
+            SfxCategory::WeaponFire(_) => SfxGroup::WeaponFire,
+            SfxCategory::Player => SfxGroup::Player,
+            SfxCategory::Event => SfxGroup::Event,
+            SfxCategory::Other => SfxGroup::Other,
+        }
+    }
+}
+
+impl SfxCue {
+    /// Voice-stealing priority of this cue.
+    pub fn priority(self) -> SfxPriority {
+        match self {
+            SfxCue::BossSpawn
+            | SfxCue::BossDie
+            | SfxCue::PlayerDie
+            | SfxCue::LevelUp
+            | SfxCue::Victory => SfxPriority::Critical,
+            SfxCue::EnemyDieLarge | SfxCue::PlayerHit | SfxCue::TreasureOpen => SfxPriority::Normal,
+            SfxCue::WeaponWhip
+            | SfxCue::ProjectileSmall
+            | SfxCue::ProjectileLarge
+            | SfxCue::Thunder
+            | SfxCue::AuraTick
+            | SfxCue::EnemyDieSmall
+            | SfxCue::GemPickup => SfxPriority::Low,
+        }
+    }
+}
+
+/// Throttling limits for one [`SfxGroup`], loaded from `audio.ron`.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct SfxLimit {
+    /// Maximum voices of one category sounding at the same time.
+    pub max_voices: usize,
+    /// Minimum seconds between two voices of the same category.
+    pub min_interval: f32,
+    /// Seconds a voice is considered busy after it starts.
+    pub voice_duration: f32,
+}
+
+/// Built-in limit for `group`, used when `audio.ron` omits it.
+pub fn default_sfx_limit(group: SfxGroup) -> SfxLimit {
+    match group {
+        SfxGroup::EnemyDeath => SfxLimit {
+            max_voices: 6,
+            min_interval: 0.03,
+            voice_duration: 0.4,
+        },
+        SfxGroup::GemPickup => SfxLimit {
+            max_voices: 4,
+            min_interval: 0.04,
+            voice_duration: 0.2,
+        },
+        SfxGroup::WeaponFire => SfxLimit {
+            max_voices: 2,
+            min_interval: 0.05,
+            voice_duration: 0.3,
+        },
+        SfxGroup::Player => SfxLimit {
+            max_voices: 2,
+            min_interval: 0.1,
+            voice_duration: 0.4,
+        },
+        SfxGroup::Event => SfxLimit {
+            max_voices: 4,
+            min_interval: 0.0,
+            voice_duration: 1.5,
+        },
+        SfxGroup::Other => SfxLimit {
+            max_voices: 4,
+            min_interval: 0.03,
+            voice_duration: 0.3,
+        },
+    }
+}
+
+/// Identifier of a voice slot handed out by [`SfxVoicePool::try_acquire`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SfxVoiceId(u64);
+
+/// A cue that has been granted a voice and should be played this frame.
+///
+/// Written by [`schedule_sfx`] and consumed by
+/// [`play_sfx`](crate::sfx::play_sfx).
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScheduledSfx {
+    pub cue: SfxCue,
+    pub voice: SfxVoiceId,
+}
+
+// ---------------------------------------------------------------------------
+// Voice pool
+// ---------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct Voice {
+    id: SfxVoiceId,
+    category: SfxCategory,
+    priority: SfxPriority,
+    started_at: f32,
+    ends_at: f32,
+    instance: Option<Handle<AudioInstance>>,
+}
+
+/// Voices currently considered busy, plus per-category retrigger times.
+#[derive(Resource, Debug, Default)]
+pub struct SfxVoicePool {
+    voices: Vec<Voice>,
+    last_started: HashMap<SfxCategory, f32>,
+    next_id: u64,
+    stolen: Vec<Handle<AudioInstance>>,
+}
+
+impl SfxVoicePool {
+    /// Number of voices busy at the last [`try_acquire`](Self::try_acquire).
+    pub fn active_voices(&self) -> usize {
+        self.voices.len()
+    }
+
+    /// Number of busy voices belonging to `category`.
+    pub fn active_in(&self, category: SfxCategory) -> usize {
+        self.voices
+            .iter()
+            .filter(|v| v.category == category)
+            .count()
+    }
+
+    /// Tries to reserve a voice for a cue of `category` and `priority` at
+    /// time `now` (seconds).
+    ///
+    /// Returns `None` when the request is throttled.  A stolen voice's kira
+    /// instance (if any) is queued for [`take_stolen`](Self::take_stolen).
+    pub fn try_acquire(
+        &mut self,
+        category: SfxCategory,
+        priority: SfxPriority,
+        limit: SfxLimit,
+        max_voices: usize,
+        now: f32,
+    ) -> Option<SfxVoiceId> {
+        self.voices.retain(|v| v.ends_at > now);
+
+        if priority < SfxPriority::Critical {
+            let retriggered_too_soon = self
+                .last_started
+                .get(&category)
+                .is_some_and(|&last| now - last < limit.min_interval);
+            if retriggered_too_soon || self.active_in(category) >= limit.max_voices {
+                return None;
+            }
+        }
+
+        if self.voices.len() >= max_voices {
+            let victim = self
+                .voices
+                .iter()
+                .enumerate()
+                .filter(|(_, v)| v.priority < priority)
+                .min_by(|(_, a), (_, b)| {
+                    a.priority
+                        .cmp(&b.priority)
+                        .then(a.started_at.total_cmp(&b.started_at))
+                })
+                .map(|(i, _)| i);
+            match victim {
+                Some(i) => {
+                    let voice = self.voices.swap_remove(i);
+                    self.stolen.extend(voice.instance);
+                }
+                // Critical cues always play, even past the pool size.
+                None if priority == SfxPriority::Critical => {}
+                None => return None,
+            }
+        }
+
+        let id = SfxVoiceId(self.next_id);
+        self.next_id += 1;
+        self.voices.push(Voice {
+            id,
+            category,
+            priority,
+            started_at: now,
+            ends_at: now + limit.voice_duration,
+            instance: None,
+        });
+        self.last_started.insert(category, now);
+        Some(id)
+    }
+
+    /// Associates the kira instance that is playing `voice`, so it can be
+    /// stopped if the voice is stolen later.
+    pub fn attach(&mut self, voice: SfxVoiceId, instance: Handle<AudioInstance>) {
+        if let Some(v) = self.voices.iter_mut().find(|v| v.id == voice) {
+            v.instance = Some(instance);
+        }
+    }
+
+    /// Drains the instances of voices stolen since the last call.
+    pub fn take_stolen(&mut self) -> Vec<Handle<AudioInstance>> {
+        std::mem::take(&mut self.stolen)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// System
+// ---------------------------------------------------------------------------
+
+/// Filters [`PlaySfx`] requests through the [`SfxVoicePool`] and writes a
+/// [`ScheduledSfx`] for every request that was granted a voice.
+pub fn schedule_sfx(
+    mut requests: MessageReader<PlaySfx>,
+    mut scheduled: MessageWriter<ScheduledSfx>,
+    mut pool: ResMut<SfxVoicePool>,
+    audio_cfg: AudioParams,
+    time: Res<Time>,
+) {
+    let now = time.elapsed_secs();
+    let max_voices = audio_cfg.max_sfx_voices();
+
+    for request in requests.read() {
+        let limit = audio_cfg.sfx_limit(request.category.group());
+        if let Some(voice) = pool.try_acquire(
+            request.category,
+            request.cue.priority(),
+            limit,
+            max_voices,
+            now,
+        ) {
+            scheduled.write(ScheduledSfx {
+                cue: request.cue,
+                voice,
+            });
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::system::RunSystemOnce as _;
+
+    use super::*;
+
+    const NO_INTERVAL: SfxLimit = SfxLimit {
+        max_voices: 8,
+        min_interval: 0.0,
+        voice_duration: 1.0,
+    };
+
+    fn build_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<PlaySfx>()
+            .add_message::<ScheduledSfx>()
+            .init_resource::<SfxVoicePool>();
+        app
+    }
+
+    fn run_schedule(app: &mut App) {
+        app.world_mut()
+            .run_system_once(schedule_sfx)
+            .expect("schedule_sfx should run");
+    }
+
+    fn advance(app: &mut App, secs: f32) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(secs));
+    }
+
+    fn scheduled(app: &App) -> Vec<SfxCue> {
+        let messages = app.world().resource::<Messages<ScheduledSfx>>();
+        let mut cursor = messages.get_cursor();
+        cursor.read(messages).map(|m| m.cue).collect()
+    }
+
+    /// A mass kill in one frame schedules a single death sound, while the
+    /// level-up cue written in the same frame still plays.
+    #[test]
+    fn mass_kill_is_throttled_but_level_up_plays() {
+        let mut app = build_app();
+        for _ in 0..500 {
+            app.world_mut()
+                .write_message(PlaySfx::new(SfxCue::EnemyDieSmall));
+        }
+        app.world_mut().write_message(PlaySfx::new(SfxCue::LevelUp));
+
+        run_schedule(&mut app);
+
+        assert_eq!(
+            scheduled(&app),
+            vec![SfxCue::EnemyDieSmall, SfxCue::LevelUp]
+        );
+    }
+
+    /// The same category may retrigger once `min_interval` has elapsed.
+    #[test]
+    fn category_retriggers_after_interval() {
+        let mut app = build_app();
+        let interval = default_sfx_limit(SfxGroup::EnemyDeath).min_interval;
+
+        app.world_mut()
+            .write_message(PlaySfx::new(SfxCue::EnemyDieSmall));
+        run_schedule(&mut app);
+        app.world_mut()
+            .write_message(PlaySfx::new(SfxCue::EnemyDieSmall));
+        run_schedule(&mut app);
+        assert_eq!(scheduled(&app).len(), 1, "retrigger within interval");
+
+        advance(&mut app, interval * 2.0);
+        app.world_mut()
+            .write_message(PlaySfx::new(SfxCue::EnemyDieSmall));
+        run_schedule(&mut app);
+        assert_eq!(scheduled(&app).len(), 2, "retrigger after interval");
+    }
+
+    /// Sustained kills never exceed the category cap while voices are busy.
+    #[test]
+    fn category_cap_holds_over_many_frames() {
+        let mut app = build_app();
+        let limit = default_sfx_limit(SfxGroup::EnemyDeath);
+        let frames = (limit.voice_duration / (limit.min_interval * 1.5)) as usize;
+        assert!(frames > limit.max_voices, "test must outlast the cap");
+
+        for _ in 0..frames {
+            for _ in 0..50 {
+                app.world_mut()
+                    .write_message(PlaySfx::new(SfxCue::EnemyDieSmall));
+            }
+            run_schedule(&mut app);
+            advance(&mut app, limit.min_interval * 1.5);
+        }
+
+        assert_eq!(scheduled(&app).len(), limit.max_voices);
+    }
+
+    /// Weapon fire is capped per weapon type, not across all weapons.
+    #[test]
+    fn weapon_fire_is_throttled_per_weapon_type() {
+        let mut app = build_app();
+        for weapon_type in [WeaponType::Whip, WeaponType::Knife, WeaponType::Whip] {
+            app.world_mut()
+                .write_message(PlaySfx::weapon(SfxCue::WeaponWhip, weapon_type));
+        }
+
+        run_schedule(&mut app);
+
+        assert_eq!(scheduled(&app).len(), 2, "second Whip is throttled");
+        let pool = app.world().resource::<SfxVoicePool>();
+        assert_eq!(pool.active_in(SfxCategory::WeaponFire(WeaponType::Whip)), 1);
+        assert_eq!(
+            pool.active_in(SfxCategory::WeaponFire(WeaponType::Knife)),
+            1
+        );
+    }
+
+    /// A full pool steals the oldest lower-priority voice.
+    #[test]
+    fn full_pool_steals_lower_priority_voice() {
+        let mut pool = SfxVoicePool::default();
+        let death = SfxCategory::EnemyDeath;
+        let first = pool.try_acquire(death, SfxPriority::Low, NO_INTERVAL, 2, 0.0);
+        pool.try_acquire(death, SfxPriority::Low, NO_INTERVAL, 2, 0.1);
+        assert!(first.is_some());
+
+        assert!(
+            pool.try_acquire(death, SfxPriority::Low, NO_INTERVAL, 2, 0.2)
+                .is_none(),
+            "equal priority must not steal"
+        );
+        assert!(
+            pool.try_acquire(
+                SfxCategory::Player,
+                SfxPriority::Normal,
+                NO_INTERVAL,
+                2,
+                0.2
+            )
+            .is_some(),
+            "higher priority steals"
+        );
+        assert_eq!(pool.active_voices(), 2);
+        assert_eq!(pool.active_in(death), 1, "oldest low voice was stolen");
+    }
+
+    /// Critical cues play even when every voice is critical.
+    #[test]
+    fn critical_cues_always_play() {
+        let mut pool = SfxVoicePool::default();
+        for i in 0..3 {
+            assert!(
+                pool.try_acquire(
+                    SfxCategory::Event,
+                    SfxPriority::Critical,
+                    SfxLimit {
+                        max_voices: 1,
+                        min_interval: 10.0,
+                        ..NO_INTERVAL
+                    },
+                    1,
+                    i as f32 * 0.01,
+                )
+                .is_some()
+            );
+        }
+        assert_eq!(pool.active_voices(), 3);
+    }
+
+    /// Voices free up once their duration has elapsed.
+    #[test]
+    fn voices_expire_after_duration() {
+        let mut pool = SfxVoicePool::default();
+        let cat = SfxCategory::GemPickup;
+        let limit = SfxLimit {
+            max_voices: 1,
+            ..NO_INTERVAL
+        };
+        assert!(
+            pool.try_acquire(cat, SfxPriority::Low, limit, 8, 0.0)
+                .is_some()
+        );
+        assert!(
+            pool.try_acquire(cat, SfxPriority::Low, limit, 8, 0.5)
+                .is_none()
+        );
+        assert!(
+            pool.try_acquire(cat, SfxPriority::Low, limit, 8, 1.0)
+                .is_some()
+        );
+    }
+}
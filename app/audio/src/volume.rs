@@ -0,0 +1,62 @@
+//! Applies the player's volume settings to the kira channels.
+//!
+//! [`GameSettings`] holds master / BGM / SFX levels and a mute flag edited on
+//! the settings screen.  [`apply_volume_settings`] pushes the combined level
+//! to each channel whenever the resource changes, so slider edits are heard
+//! immediately.  Per-track and per-cue volumes from `audio.ron` are applied
+//! on each individual play and stack on top of the channel volume.
+
+use bevy::prelude::*;
+use bevy_kira_audio::{AudioChannel, AudioControl};
+use vs_core::resources::GameSettings;
+
+use crate::{BgmChannel, SfxChannel, linear_to_decibels};
+
+/// Sets the [`BgmChannel`] and [`SfxChannel`] volumes from [`GameSettings`].
+///
+/// Runs every frame but only does work when the settings changed (including
+/// the first frame, so saved volumes apply at startup).
+pub fn apply_volume_settings(
+    settings: Option<Res<GameSettings>>,
+    bgm: Option<Res<AudioChannel<BgmChannel>>>,
+    sfx: Option<Res<AudioChannel<SfxChannel>>>,
+) {
+    let Some(settings) = settings else {
+        return;
+    };
+    if !settings.is_changed() {
+        return;
+    }
+    if let Some(bgm) = bgm {
+        bgm.set_volume(linear_to_decibels(settings.effective_bgm_volume()));
+    }
+    if let Some(sfx) = sfx {
+        sfx.set_volume(linear_to_decibels(settings.effective_sfx_volume()));
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce as _;
+
+    use super::*;
+
+    /// Without an audio backend the system is a silent no-op.
+    #[test]
+    fn apply_volume_settings_without_audio_is_silent() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(GameSettings {
+            muted: true,
+            ..default()
+        });
+
+        app.world_mut()
+            .run_system_once(apply_volume_settings)
+            .expect("apply_volume_settings should run without an audio backend");
+    }
+}
@@ -0,0 +1,145 @@
+//! Enemy separation performance benchmarks.
+//!
+//! Measures the per-frame cost of the crowd-separation pass
+//! ([`separation_pushes`]) including the [`SpatialGrid`] rebuild it depends
+//! on.  Budget:
+//!
+//! - 500 enemies packed into a tight horde: grid build + separation < 1 ms
+//!   (well under the ~16.7 ms frame at 60 fps)
+//!
+//! Two layouts are measured at 300 and 500 enemies:
+//!
+//! - `spread` — enemies evenly spread over a 1920×1080 area (typical mid-run)
+//! - `horde`  — enemies packed into a disc around the player, every body
+//!   overlapping several neighbours (worst case for the pass)
+//!
+//! # Running
+//!
+//! ```bash
+//! cargo bench -p vs-core --bench enemy_separation
+//! ```
+//!
+//! HTML reports are written to `target/criterion/`.
+
+use std::collections::HashMap;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+
+use bevy::math::Vec2;
+use bevy::prelude::{Entity, World};
+use vs_core::resources::SpatialGrid;
+use vs_core::systems::enemies::separation::{SeparationBody, separation_pushes};
+
+// ---------------------------------------------------------------------------
+// Parameters
+// ---------------------------------------------------------------------------
+
+/// Skeleton-sized collider radius used for every body.
+const ENEMY_RADIUS: f32 = 12.0;
+/// Matches the `enemy.ron` default.
+const SEPARATION_STRENGTH: f32 = 0.5;
+/// Radius of the disc the `horde` layout packs enemies into (pixels).
+const HORDE_RADIUS: f32 = 200.0;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Create `n` unique [`Entity`] values without spawning any components.
+fn make_entities(n: usize) -> Vec<Entity> {
+    let mut world = World::new();
+    (0..n).map(|_| world.spawn_empty().id()).collect()
+}
+
+/// `n` positions on a uniform grid over a 1920×1080 area.
+fn spread_positions(n: usize) -> Vec<Vec2> {
+    let cols = (n as f32).sqrt().ceil() as usize;
+    let rows = n.div_ceil(cols);
+    let x_spacing = 1920.0 / cols as f32;
+    let y_spacing = 1080.0 / rows as f32;
+    (0..n)
+        .map(|i| {
+            Vec2::new(
+                (i % cols) as f32 * x_spacing - 960.0,
+                (i / cols) as f32 * y_spacing - 540.0,
+            )
+        })
+        .collect()
+}
+
+/// `n` positions packed into a disc of [`HORDE_RADIUS`] on a golden-angle
+/// spiral — deterministic and evenly dense, so every body overlaps its
+/// neighbours.
+fn horde_positions(n: usize) -> Vec<Vec2> {
+    let golden_angle = std::f32::consts::PI * (3.0 - 5.0_f32.sqrt());
+    (0..n)
+        .map(|i| {
+            let r = HORDE_RADIUS * ((i as f32 + 0.5) / n as f32).sqrt();
+            Vec2::from_angle(i as f32 * golden_angle) * r
+        })
+        .collect()
+}
+
+fn build_bodies(entities: &[Entity], positions: &[Vec2]) -> HashMap<Entity, SeparationBody> {
+    entities
+        .iter()
+        .zip(positions)
+        .map(|(&entity, &pos)| {
+            (
+                entity,
+                SeparationBody {
+                    pos,
+                    radius: ENEMY_RADIUS,
+                },
+            )
+        })
+        .collect()
+}
+
+/// One full separation frame: rebuild the grid, then compute all pushes.
+fn separation_frame(
+    entities: &[Entity],
+    bodies: &HashMap<Entity, SeparationBody>,
+) -> Vec<(Entity, Vec2)> {
+    let mut grid = SpatialGrid::default();
+    for entity in entities {
+        grid.insert(bodies[entity].pos, *entity);
+    }
+    separation_pushes(&grid, bodies, SEPARATION_STRENGTH)
+}
+
+// ---------------------------------------------------------------------------
+// Benchmarks
+// ---------------------------------------------------------------------------
+
+fn bench_layout(c: &mut Criterion, name: &str, layout: fn(usize) -> Vec<Vec2>) {
+    let mut group = c.benchmark_group(name);
+
+    for &n in &[300usize, 500] {
+        let entities = make_entities(n);
+        let bodies = build_bodies(&entities, &layout(n));
+
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, _| {
+            b.iter(|| separation_frame(&entities, &bodies));
+        });
+    }
+
+    group.finish();
+}
+
+/// Enemies spread across the play area; few overlaps.
+fn bench_spread(c: &mut Criterion) {
+    bench_layout(c, "separation_spread", spread_positions);
+}
+
+/// Enemies packed around the player; every body overlaps its neighbours.
+fn bench_horde(c: &mut Criterion) {
+    bench_layout(c, "separation_horde", horde_positions);
+}
+
+// ---------------------------------------------------------------------------
+// Registration
+// ---------------------------------------------------------------------------
+
+criterion_group!(benches, bench_spread, bench_horde);
+criterion_main!(benches);
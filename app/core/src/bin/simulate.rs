@@ -0,0 +1,83 @@
+//! `vs-sim` — headless run simulator for balance testing.
+//!
+//! ```text
+//! cargo run -p vs-core --release --bin vs-sim -- \
+//!     --minutes 30 --seed 42 --character Magician --stage MadForest \
+//!     --policy kite --out report.json
+//! ```
+//!
+//! | Flag              | Default                       |
+//! |-------------------|-------------------------------|
+//! | `--minutes`       | 30                            |
+//! | `--boss-minutes`  | 5 (boss fight, from its spawn) |
+//! | `--seed`          | random                        |
+//! | `--character`     | `DefaultCharacter`            |
+//! | `--stage`         | `MadForest`                   |
+//! | `--policy`        | `kite` (`kite`/`evade`/`idle`) |
+//! | `--sample-every`  | 10 (simulated seconds)        |
+//! | `--assets`        | `../vampire-survivors/assets` |
+//! | `--out`           | stdout                        |
+
+use std::process::ExitCode;
+
+use serde::de::DeserializeOwned;
+use vs_core::cli::{flag_value, parse_flag};
+use vs_core::simulation::{SimSettings, policy_from_name, run_simulation};
+
+/// Parses a RON enum variant name such as `Magician` or `DairyPlant`.
+fn parse_variant<T: DeserializeOwned>(args: &[String], flag: &str) -> Result<Option<T>, String> {
+    flag_value(args, flag)
+        .map(|name| ron::from_str(&name).map_err(|_| format!("unknown {flag} value: {name}")))
+        .transpose()
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let defaults = SimSettings::default();
+    let settings = SimSettings {
+        seed: parse_flag(args, "--seed")?.unwrap_or_else(rand::random),
+        character: parse_variant(args, "--character")?.unwrap_or(defaults.character),
+        stage: parse_variant(args, "--stage")?.unwrap_or(defaults.stage),
+        minutes: parse_flag(args, "--minutes")?.unwrap_or(defaults.minutes),
+        boss_fight_minutes: parse_flag(args, "--boss-minutes")?
+            .unwrap_or(defaults.boss_fight_minutes),
+        sample_interval: parse_flag(args, "--sample-every")?.unwrap_or(defaults.sample_interval),
+        assets_dir: flag_value(args, "--assets").unwrap_or(defaults.assets_dir),
+        ..defaults
+    };
+    let policy_name = flag_value(args, "--policy").unwrap_or_else(|| "kite".to_string());
+    let bot =
+        policy_from_name(&policy_name).ok_or_else(|| format!("unknown --policy: {policy_name}"))?;
+
+    let report = run_simulation(&settings, bot)?;
+    eprintln!(
+        "{:?} on {:?} (seed {}): {:?} at {:.0}s, level {}, {} kills — simulated in {:.1}s",
+        report.character,
+        report.stage,
+        report.seed,
+        report.outcome,
+        report.elapsed_time,
+        report.samples.last().map_or(1, |s| s.level),
+        report.samples.last().map_or(0, |s| s.kill_count),
+        report.wall_time
+    );
+
+    let json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+    match flag_value(args, "--out") {
+        Some(path) => std::fs::write(&path, json).map_err(|e| format!("{path}: {e}")),
+        None => {
+            println!("{json}");
+            Ok(())
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("vs-sim: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! `vs-sweep` — runs every character on every stage across a list of seeds
+//! and writes a balance table.
+//!
+//! ```text
+//! cargo run -p vs-core --release --bin vs-sweep -- \
+//!     --seeds 0..10 --minutes 30 --out sweep.csv
+//! ```
+//!
+//! | Flag           | Default                                  |
+//! |----------------|------------------------------------------|
+//! | `--characters` | all (comma-separated, e.g. `Magician,Thief`) |
+//! | `--stages`     | all (comma-separated, e.g. `MadForest`)  |
+//! | `--seeds`      | `0..5` (list and/or `start..end` ranges) |
+//! | `--minutes`    | 30                                       |
+//! | `--boss-minutes` | 5 (boss fight, from its spawn)         |
+//! | `--policy`     | `kite` (`kite`/`evade`/`idle`)           |
+//! | `--jobs`       | available CPU cores                      |
+//! | `--format`     | `json` when `--out` ends in `.json`, else `csv` |
+//! | `--assets`     | `../vampire-survivors/assets`            |
+//! | `--out`        | stdout                                   |
+
+use std::process::ExitCode;
+
+use serde::de::DeserializeOwned;
+use vs_core::cli::{flag_value, parse_flag};
+use vs_core::simulation::{SimSettings, SweepSettings, parse_seed_list, run_sweep, sweep_to_csv};
+use vs_core::types::{CharacterType, StageType};
+
+/// Parses a comma-separated list of RON enum variant names.
+fn parse_variants<T: DeserializeOwned>(
+    args: &[String],
+    flag: &str,
+) -> Result<Option<Vec<T>>, String> {
+    flag_value(args, flag)
+        .map(|list| {
+            list.split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(|name| {
+                    ron::from_str(name).map_err(|_| format!("unknown {flag} value: {name}"))
+                })
+                .collect()
+        })
+        .transpose()
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let base = SimSettings::default();
+    let seeds = match flag_value(args, "--seeds") {
+        Some(spec) => parse_seed_list(&spec).ok_or_else(|| format!("invalid --seeds: {spec}"))?,
+        None => (0..5).collect(),
+    };
+    let settings = SweepSettings {
+        characters: parse_variants(args, "--characters")?
+            .unwrap_or_else(|| CharacterType::ALL.to_vec()),
+        stages: parse_variants(args, "--stages")?.unwrap_or_else(|| StageType::ALL.to_vec()),
+        seeds,
+        policy: flag_value(args, "--policy").unwrap_or_else(|| "kite".to_string()),
+        jobs: parse_flag(args, "--jobs")?
+            .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |n| n.get())),
+        base: SimSettings {
+            minutes: parse_flag(args, "--minutes")?.unwrap_or(base.minutes),
+            boss_fight_minutes: parse_flag(args, "--boss-minutes")?
+                .unwrap_or(base.boss_fight_minutes),
+            assets_dir: flag_value(args, "--assets").unwrap_or_else(|| base.assets_dir.clone()),
+            ..base
+        },
+    };
+
+    let rows = run_sweep(&settings, |completed, total, row| {
+        eprintln!(
+            "[{completed}/{total}] {:?} on {:?} (seed {}): {:?} at {:.0}s",
+            row.character, row.stage, row.seed, row.outcome, row.survival_time
+        );
+    })?;
+
+    let out = flag_value(args, "--out");
+    let json = match flag_value(args, "--format").as_deref() {
+        Some("json") => true,
+        Some("csv") => false,
+        Some(other) => return Err(format!("unknown --format: {other}")),
+        None => out.as_deref().is_some_and(|path| path.ends_with(".json")),
+    };
+    let table = if json {
+        serde_json::to_string_pretty(&rows).map_err(|e| e.to_string())?
+    } else {
+        sweep_to_csv(&rows)
+    };
+    match out {
+        Some(path) => std::fs::write(&path, table).map_err(|e| format!("{path}: {e}")),
+        None => {
+            print!("{table}");
+            Ok(())
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("vs-sweep: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! `vs-validate-configs` — checks every RON config under an assets directory
+//! without starting the game.
+//!
+//! ```text
+//! cargo run -p vs-core --bin vs-validate-configs -- --assets ../vampire-survivors/assets
+//! ```
+//!
+//! | Flag       | Default                       |
+//! |------------|-------------------------------|
+//! | `--assets` | `../vampire-survivors/assets` |
+//!
+//! A relative `--assets` resolves against the crate directory when run via
+//! `cargo run` (as Bevy's asset server does), else the working directory.
+//!
+//! Prints one line per problem and exits with a failure status when any
+//! file is unreadable, is not valid RON, or breaks an invariant.
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use vs_core::cli::flag_value;
+use vs_core::config::validate::{config_file_paths, validate_config_dir};
+use vs_core::simulation::DEFAULT_SIM_ASSETS_DIR;
+
+/// `dir` relative to `CARGO_MANIFEST_DIR` when set, else as given.
+fn resolve(dir: &str) -> PathBuf {
+    match std::env::var_os("CARGO_MANIFEST_DIR") {
+        Some(manifest_dir) => Path::new(&manifest_dir).join(dir),
+        None => PathBuf::from(dir),
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let assets_dir =
+        flag_value(&args, "--assets").unwrap_or_else(|| DEFAULT_SIM_ASSETS_DIR.to_string());
+
+    let issues = validate_config_dir(&resolve(&assets_dir));
+    let file_count = config_file_paths().count();
+    if issues.is_empty() {
+        println!("{file_count} config files in {assets_dir} are valid");
+        return ExitCode::SUCCESS;
+    }
+
+    for issue in &issues {
+        eprintln!("{issue}");
+    }
+    eprintln!(
+        "vs-validate-configs: {} problem(s) in {file_count} config files under {assets_dir}",
+        issues.len()
+    );
+    ExitCode::FAILURE
+}
@@ -0,0 +1,87 @@
+//! Minimal command-line flag parsing shared by the game binaries.
+//!
+//! Flags are accepted as either `--flag value` or `--flag=value`.  Only the
+//! handful of developer / QA flags the binaries need are supported, so a
+//! full argument-parsing crate is not warranted.
+
+use std::str::FromStr;
+
+/// Returns the value passed to `flag`, if present.
+///
+/// The first occurrence wins.  A trailing `--flag` with no value yields
+/// `None`.
+pub fn flag_value<I, S>(args: I, flag: &str) -> Option<String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let arg = arg.as_ref();
+        if let Some(value) = arg
+            .strip_prefix(flag)
+            .and_then(|rest| rest.strip_prefix('='))
+        {
+            return Some(value.to_string());
+        }
+        if arg == flag {
+            return args.next().map(|v| v.as_ref().to_string());
+        }
+    }
+    None
+}
+
+/// Parses the value passed to `flag` as `T`.
+///
+/// Returns `Ok(None)` when the flag is absent, and an error naming the flag
+/// when its value does not parse.
+pub fn parse_flag<T, I, S>(args: I, flag: &str) -> Result<Option<T>, String>
+where
+    T: FromStr,
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    flag_value(args, flag)
+        .map(|v| v.parse().map_err(|_| format!("invalid {flag} value: {v}")))
+        .transpose()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flag_value_accepts_both_forms() {
+        assert_eq!(
+            flag_value(["--replay", "a.json"], "--replay").as_deref(),
+            Some("a.json")
+        );
+        assert_eq!(
+            flag_value(["--replay=b.json"], "--replay").as_deref(),
+            Some("b.json")
+        );
+    }
+
+    #[test]
+    fn flag_value_ignores_other_flags() {
+        assert_eq!(flag_value(["--replays", "x"], "--replay"), None);
+        assert_eq!(flag_value(["--replay"], "--replay"), None);
+    }
+
+    #[test]
+    fn parse_flag_rejects_invalid_values() {
+        assert_eq!(
+            parse_flag::<u64, _, _>(["--seed", "99"], "--seed"),
+            Ok(Some(99))
+        );
+        assert_eq!(
+            parse_flag::<u64, _, _>(["--seed", "abc"], "--seed"),
+            Err("invalid --seed value: abc".to_string())
+        );
+        assert_eq!(parse_flag::<u64, _, _>(["game"], "--seed"), Ok(None));
+    }
+}
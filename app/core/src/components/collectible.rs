@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::types::GemTier;
+use crate::types::{CoinTier, GemTier, PickupType};
 
 /// An XP gem dropped by a defeated enemy.
 #[derive(Component, Debug)]
@@ -13,11 +13,33 @@ pub struct ExperienceGem {
 #[derive(Component, Debug)]
 pub struct GoldCoin {
     pub value: u32,
+    pub tier: CoinTier,
 }
 
-/// A treasure chest spawned on the map.
+/// A floor item (chicken, vacuum, rosary, orologion) that takes effect when
+/// the player touches it.
 #[derive(Component, Debug)]
-pub struct Treasure;
+pub struct FloorPickup {
+    pub kind: PickupType,
+}
+
+/// A breakable light-source prop.  Shatters on player contact and drops one
+/// [`FloorPickup`] from the `pickup.ron` drop table.
+#[derive(Component, Debug)]
+pub struct LightSource;
+
+/// A treasure chest spawned on the map.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Treasure {
+    /// Rewards granted when the chest is opened (1, 3 or 5).
+    pub items: u32,
+}
+
+impl Default for Treasure {
+    fn default() -> Self {
+        Self { items: 1 }
+    }
+}
 
 /// Drives the white-flash-to-yellow transition played when a chest first spawns.
 ///
@@ -1,6 +1,9 @@
 use bevy::prelude::*;
 
-use crate::types::{AIType, EnemyType};
+use crate::{
+    config::{BossPhaseSpec, MiniBossArchetype, StageBoss},
+    types::{AIType, EliteModifier, EnemyType, MiniBossKind},
+};
 
 // ---------------------------------------------------------------------------
 // Fallback constants — (base_hp, speed px/s, contact_damage, xp_value, gold_drop_chance)
@@ -20,6 +23,19 @@ const DEFAULT_ENEMY_STATS_MINI_DEATH: (f32, f32, f32, u32, f32) = (800.0, 80.0,
 /// gold_chance = 0.0 because it always drops a treasure chest instead.
 const DEFAULT_ENEMY_STATS_MINI_BOSS: (f32, f32, f32, u32, f32) = (400.0, 70.0, 20.0, 30, 0.0);
 
+/// Fallback knockback resistance (0.0–1.0) per enemy type; mirrors `enemy.ron`.
+const fn default_knockback_resistance(enemy_type: EnemyType) -> f32 {
+    match enemy_type {
+        EnemyType::Bat | EnemyType::Ghost => 0.0,
+        EnemyType::Skeleton => 0.1,
+        EnemyType::Zombie => 0.3,
+        EnemyType::Demon | EnemyType::Medusa => 0.2,
+        EnemyType::Dragon => 0.5,
+        EnemyType::BossDeath => 0.95,
+        EnemyType::MiniDeath | EnemyType::MiniBoss => 0.9,
+    }
+}
+
 /// Core enemy stats. Attached to every enemy entity.
 #[derive(Component, Debug, Clone)]
 pub struct Enemy {
@@ -34,6 +50,8 @@ pub struct Enemy {
     pub xp_value: u32,
     /// Probability (0.0–1.0) of dropping a gold coin on death.
     pub gold_chance: f32,
+    /// Fraction (0.0–1.0) of weapon knockback and hit-stun ignored.
+    pub knockback_resistance: f32,
 }
 
 impl Enemy {
@@ -56,6 +74,7 @@ impl Enemy {
             damage: stats.damage,
             xp_value: stats.xp_value,
             gold_chance: stats.gold_chance,
+            knockback_resistance: stats.knockback_resistance.clamp(0.0, 1.0),
         }
     }
 
@@ -87,6 +106,7 @@ impl Enemy {
             damage,
             xp_value: xp,
             gold_chance: gold,
+            knockback_resistance: default_knockback_resistance(enemy_type),
         }
     }
 
@@ -108,12 +128,47 @@ impl Enemy {
 #[derive(Component, Debug)]
 pub struct EnemyAI {
     pub ai_type: AIType,
-    /// Timer between ranged attacks (used by KeepDistance AI).
+    /// Timer between ranged attacks (enemies listed in `ranged_attacks`).
     pub attack_timer: f32,
     /// Maximum distance at which this enemy will attack.
     pub attack_range: f32,
 }
 
+/// Movement override for enemies spawned by a formation event.
+///
+/// Only read while [`EnemyAI::ai_type`] is [`AIType::Formation`].
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub enum FormationMotion {
+    /// Close in on `center` (the player's position when the ring spawned);
+    /// the enemy reverts to its normal AI once it gets near.
+    Converge { center: Vec2 },
+    /// Travel along `direction` (unit vector) regardless of the player until
+    /// culled.
+    Sweep { direction: Vec2 },
+}
+
+/// Short stun applied by a knockback hit.
+///
+/// While present the enemy's AI movement is skipped so the knockback is not
+/// immediately walked back.  Removed by
+/// [`tick_hit_stun`](crate::systems::damage::tick_hit_stun) when it expires.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct HitStun {
+    /// Remaining stun duration in seconds.
+    pub remaining: f32,
+}
+
+/// Marks an elite enemy and its modifier.
+///
+/// Inserted at spawn by
+/// [`spawn_enemies`](crate::systems::enemies::spawn::spawn_enemies) on a
+/// successful elite roll; [`init_elites`](crate::systems::enemies::elite::init_elites)
+/// then scales the [`Enemy`] stats, size and tint once.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elite {
+    pub modifier: EliteModifier,
+}
+
 /// Brief color flash applied when an enemy takes damage.
 #[derive(Component, Debug)]
 pub struct DamageFlash {
@@ -121,56 +176,103 @@ pub struct DamageFlash {
     pub timer: f32,
 }
 
-/// Petrification projectile fired by the Medusa enemy.
+/// Projectile fired at the player by an enemy — Medusa and Dragon shots,
+/// Boss Death's scythes and stage-boss bullets alike.
 ///
-/// Moves in a straight line toward the player's position at fire time.
-/// Despawns when [`lifetime`](MedusaProjectile::lifetime) reaches zero or
-/// when it hits the player.
-#[derive(Component, Debug)]
-pub struct MedusaProjectile {
+/// Moved, expired and collided by the shared systems in
+/// [`hostile_projectile`](crate::systems::enemies::hostile_projectile); spawn
+/// one through [`HostileProjectiles`](crate::systems::enemies::hostile_projectile::HostileProjectiles)
+/// so finished projectiles are recycled instead of despawned.
+#[derive(Component, Debug, Clone)]
+pub struct HostileProjectile {
+    /// Enemy type that fired the projectile.
+    pub source: EnemyType,
     /// Contact damage dealt to the player on hit.
     pub damage: f32,
     /// Velocity vector (pixels/second).
     pub velocity: Vec2,
-    /// Remaining lifetime before the projectile despawns (seconds).
+    /// Remaining lifetime before the projectile is released (seconds).
     pub lifetime: f32,
+    /// Max turn rate toward the player (radians/second); 0 flies straight.
+    pub homing: f32,
+    /// Speed change (pixels/second²); a negative value slows to a stop.
+    pub acceleration: f32,
+    /// Sprite rotation speed (radians/second), visual only.
+    pub spin: f32,
 }
 
-/// Fireball projectile fired by the Dragon enemy.
-///
-/// Moves in a straight line toward the player's position at fire time.
-/// Despawns when [`lifetime`](DragonFireball::lifetime) reaches zero or
-/// when it hits the player.
-#[derive(Component, Debug)]
-pub struct DragonFireball {
-    /// Contact damage dealt to the player on hit.
-    pub damage: f32,
-    /// Velocity vector (pixels/second).
-    pub velocity: Vec2,
-    /// Remaining lifetime before the projectile despawns (seconds).
-    pub lifetime: f32,
+impl HostileProjectile {
+    /// A straight-flying projectile with no homing, acceleration or spin.
+    pub fn straight(source: EnemyType, damage: f32, velocity: Vec2, lifetime: f32) -> Self {
+        Self {
+            source,
+            damage,
+            velocity,
+            lifetime,
+            homing: 0.0,
+            acceleration: 0.0,
+            spin: 0.0,
+        }
+    }
 }
 
-/// Scythe projectile fired by Boss Death during [`BossPhase::Phase3`].
+/// Marks a [`HostileProjectile`] parked in the
+/// [`HostileProjectilePool`](crate::resources::HostileProjectilePool): hidden
+/// and skipped by every hostile-projectile system until fired again.
 ///
-/// Moves in a straight line toward the player's position at fire time.
-/// Despawns when [`lifetime`](BossScythe::lifetime) reaches zero or
-/// when it hits the player.
+/// Sparse-set storage keeps the frequent insert/remove cheap.
 #[derive(Component, Debug)]
-pub struct BossScythe {
-    /// Contact damage dealt to the player on hit.
-    pub damage: f32,
-    /// Velocity vector (pixels/second).
-    pub velocity: Vec2,
-    /// Remaining lifetime before the projectile despawns (seconds).
-    pub lifetime: f32,
+#[component(storage = "SparseSet")]
+pub struct PooledProjectile;
+
+/// Runtime state of a pattern-driven stage boss.
+///
+/// Inserted by [`check_boss_spawn`](crate::systems::enemies::boss_spawn::check_boss_spawn)
+/// instead of [`BossPhase`](crate::types::BossPhase) when the selected stage
+/// names its own [`StageBoss`]; the definition is copied so a config reload
+/// does not change a fight in progress.  Driven by the systems in
+/// [`boss_pattern`](crate::systems::enemies::boss_pattern).
+#[derive(Component, Debug, Clone)]
+pub struct BossPatterns {
+    pub boss: StageBoss,
+    /// Index of the active phase in `boss.phases`.
+    pub phase: usize,
+    /// Index of the next pattern in the active phase.
+    pub next_pattern: usize,
+    /// Seconds until the next pattern runs.
+    pub timer: f32,
+    /// Velocity of a charge in progress.
+    pub charge_velocity: Vec2,
+    /// Seconds left of a charge in progress (0.0 when not charging).
+    pub charge_remaining: f32,
+}
+
+impl BossPatterns {
+    /// Starts `boss` in its first phase, with the first pattern one interval
+    /// away.
+    pub fn new(boss: StageBoss) -> Self {
+        let timer = boss.phases.first().map_or(0.0, |p| p.interval);
+        Self {
+            boss,
+            phase: 0,
+            next_pattern: 0,
+            timer,
+            charge_velocity: Vec2::ZERO,
+            charge_remaining: 0.0,
+        }
+    }
+
+    /// The active phase, or `None` for a boss defined without phases.
+    pub fn current_phase(&self) -> Option<&BossPhaseSpec> {
+        self.boss.phases.get(self.phase)
+    }
 }
 
 /// Marker component: this enemy phases through other enemy entities.
 ///
-/// Currently informational — no enemy-to-enemy collision is implemented.
-/// When enemy separation is added in the future, systems must skip entities
-/// carrying this component.
+/// [`separate_enemies`](crate::systems::enemies::separation::separate_enemies)
+/// skips entities carrying this component, so they neither push nor get
+/// pushed by other enemies.
 #[derive(Component, Debug, Default)]
 pub struct PhaseThrough;
 
@@ -266,3 +368,34 @@ mod tests {
         assert_eq!(e.current_hp, before);
     }
 }
+
+/// Runtime state of a [`EnemyType::MiniBoss`].
+///
+/// Inserted by [`spawn_mini_boss`](crate::systems::enemies::mini_boss_spawn::spawn_mini_boss);
+/// the archetype is copied so a config reload does not change a mini-boss
+/// already on the field.  Driven by the systems in
+/// [`mini_boss`](crate::systems::enemies::mini_boss).
+#[derive(Component, Debug, Clone)]
+pub struct MiniBoss {
+    pub kind: MiniBossKind,
+    pub archetype: MiniBossArchetype,
+    /// Seconds until the next use of the archetype's ability.
+    pub ability_timer: f32,
+    /// Velocity of a charge in progress.
+    pub charge_velocity: Vec2,
+    /// Seconds left of a charge in progress (0.0 when not charging).
+    pub charge_remaining: f32,
+}
+
+impl MiniBoss {
+    /// A fresh mini-boss of `kind`, with its first ability one interval away.
+    pub fn new(kind: MiniBossKind, archetype: MiniBossArchetype) -> Self {
+        Self {
+            kind,
+            ability_timer: archetype.ability_interval,
+            archetype,
+            charge_velocity: Vec2::ZERO,
+            charge_remaining: 0.0,
+        }
+    }
+}
@@ -2,12 +2,14 @@ pub mod collectible;
 pub mod enemy;
 pub mod physics;
 pub mod player;
+pub mod status;
 pub mod weapon;
 
 pub use collectible::*;
 pub use enemy::*;
 pub use physics::*;
 pub use player::*;
+pub use status::*;
 pub use weapon::*;
 
 use bevy::prelude::*;
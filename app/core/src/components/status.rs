@@ -0,0 +1,233 @@
+use bevy::prelude::*;
+
+use crate::types::WeaponType;
+
+/// Maximum number of Poison stacks an enemy can carry at once.
+pub const MAX_POISON_STACKS: u32 = 5;
+
+/// Kind of status effect a weapon can inflict on an enemy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatusKind {
+    /// Fire damage over time; a fresh application refreshes the duration and
+    /// keeps the stronger burn.
+    Burn,
+    /// Damage over time that stacks up to [`MAX_POISON_STACKS`] times.
+    Poison,
+    /// Reduces move speed by a fraction; the strongest slow wins.
+    Slow,
+    /// Stops movement entirely.
+    Freeze,
+}
+
+/// One status effect — either carried by a
+/// [`DamageEnemyEvent`](crate::events::DamageEnemyEvent) or active on an enemy.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StatusEffect {
+    pub kind: StatusKind,
+    /// Remaining duration in seconds.
+    pub remaining: f32,
+    /// Burn / Poison: damage per second (per stack).  Slow: fraction of move
+    /// speed removed (0.0–1.0).  Freeze: unused.
+    pub magnitude: f32,
+    /// Number of applications stacked (only Poison goes above 1).
+    pub stacks: u32,
+    /// Weapon that inflicted the effect; tick damage uses its damage type.
+    pub source: WeaponType,
+}
+
+impl StatusEffect {
+    /// Burn dealing `dps` damage per second for `duration` seconds.
+    pub fn burn(dps: f32, duration: f32, source: WeaponType) -> Self {
+        Self::new(StatusKind::Burn, duration, dps, source)
+    }
+
+    /// One Poison stack dealing `dps` damage per second for `duration` seconds.
+    pub fn poison(dps: f32, duration: f32, source: WeaponType) -> Self {
+        Self::new(StatusKind::Poison, duration, dps, source)
+    }
+
+    /// Slow removing `fraction` of move speed for `duration` seconds.
+    pub fn slow(fraction: f32, duration: f32, source: WeaponType) -> Self {
+        Self::new(StatusKind::Slow, duration, fraction.clamp(0.0, 1.0), source)
+    }
+
+    /// Freeze stopping movement for `duration` seconds.
+    pub fn freeze(duration: f32, source: WeaponType) -> Self {
+        Self::new(StatusKind::Freeze, duration, 0.0, source)
+    }
+
+    fn new(kind: StatusKind, duration: f32, magnitude: f32, source: WeaponType) -> Self {
+        Self {
+            kind,
+            remaining: duration,
+            magnitude,
+            stacks: 1,
+            source,
+        }
+    }
+
+    /// `true` for effects that deal damage over time (Burn, Poison).
+    pub fn is_damage_over_time(&self) -> bool {
+        matches!(self.kind, StatusKind::Burn | StatusKind::Poison)
+    }
+
+    /// `true` for crowd-control effects (Slow, Freeze), whose duration is
+    /// scaled down by the target's knockback resistance.
+    pub fn is_crowd_control(&self) -> bool {
+        matches!(self.kind, StatusKind::Slow | StatusKind::Freeze)
+    }
+
+    /// Damage per second across all stacks (0 for crowd control).
+    pub fn damage_per_second(&self) -> f32 {
+        if self.is_damage_over_time() {
+            self.magnitude * self.stacks as f32
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Status effects active on an enemy, at most one entry per [`StatusKind`].
+///
+/// Inserted by
+/// [`apply_damage_to_enemies`](crate::systems::damage::apply_damage_to_enemies)
+/// on the first hit that inflicts an effect and kept afterwards; an empty
+/// list means no effect is active.  Ticked by
+/// [`tick_status_effects`](crate::systems::enemies::status::tick_status_effects).
+#[derive(Component, Debug, Clone, Default)]
+pub struct StatusEffects {
+    pub effects: Vec<StatusEffect>,
+    /// Seconds until the next burn / poison damage tick.
+    pub tick_timer: f32,
+}
+
+impl StatusEffects {
+    /// Merges `effect` into the active list following the stacking rules:
+    ///
+    /// - Burn: duration refreshed, the higher damage per second kept.
+    /// - Poison: one more stack (up to [`MAX_POISON_STACKS`]), duration
+    ///   refreshed.
+    /// - Slow: the stronger slow and the longer duration kept.
+    /// - Freeze: the longer duration kept.
+    pub fn apply(&mut self, effect: StatusEffect) {
+        let Some(active) = self.effects.iter_mut().find(|e| e.kind == effect.kind) else {
+            self.effects.push(effect);
+            return;
+        };
+        active.remaining = active.remaining.max(effect.remaining);
+        if effect.magnitude > active.magnitude {
+            active.magnitude = effect.magnitude;
+            active.source = effect.source;
+        }
+        if effect.kind == StatusKind::Poison {
+            active.stacks = (active.stacks + effect.stacks).min(MAX_POISON_STACKS);
+        }
+    }
+
+    /// The active effect of `kind`, if any.
+    pub fn get(&self, kind: StatusKind) -> Option<&StatusEffect> {
+        self.effects.iter().find(|e| e.kind == kind)
+    }
+
+    /// `true` while a Freeze is active.
+    pub fn is_frozen(&self) -> bool {
+        self.get(StatusKind::Freeze).is_some()
+    }
+
+    /// Factor applied to the enemy's move speed: 0 while frozen, otherwise
+    /// `1 − slow fraction`.
+    pub fn speed_multiplier(&self) -> f32 {
+        if self.is_frozen() {
+            return 0.0;
+        }
+        self.get(StatusKind::Slow)
+            .map_or(1.0, |slow| 1.0 - slow.magnitude)
+    }
+
+    /// Sprite tint for the most visible active effect (Freeze, then Burn,
+    /// Poison, Slow), or `None` when no effect is active.
+    pub fn tint(&self) -> Option<Color> {
+        [
+            (StatusKind::Freeze, Color::srgb(0.6, 0.85, 1.0)),
+            (StatusKind::Burn, Color::srgb(1.0, 0.45, 0.1)),
+            (StatusKind::Poison, Color::srgb(0.45, 0.9, 0.3)),
+            (StatusKind::Slow, Color::srgb(0.55, 0.6, 0.95)),
+        ]
+        .into_iter()
+        .find_map(|(kind, color)| self.get(kind).map(|_| color))
+    }
+}
+
+/// The enemy's own sprite colour, saved while a status tint is shown so it
+/// can be restored once every effect has expired.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct StatusTint {
+    pub base_color: Color,
+}
+
+/// Status effect inflicted by a projectile on every enemy it hits.
+///
+/// Inserted alongside [`Projectile`](crate::components::Projectile) by weapons
+/// whose shots carry an effect (e.g. Holy Wand freeze).
+#[derive(Component, Debug, Clone, Copy)]
+pub struct InflictsStatus(pub StatusEffect);
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn burn_refreshes_duration_and_keeps_stronger_dps() {
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusEffect::burn(10.0, 1.0, WeaponType::FireWand));
+        effects.apply(StatusEffect::burn(4.0, 3.0, WeaponType::FireWand));
+
+        let burn = effects.get(StatusKind::Burn).unwrap();
+        assert_eq!(burn.magnitude, 10.0);
+        assert_eq!(burn.remaining, 3.0);
+        assert_eq!(burn.stacks, 1);
+    }
+
+    #[test]
+    fn poison_stacks_up_to_cap() {
+        let mut effects = StatusEffects::default();
+        for _ in 0..MAX_POISON_STACKS + 3 {
+            effects.apply(StatusEffect::poison(2.0, 4.0, WeaponType::Knife));
+        }
+
+        let poison = effects.get(StatusKind::Poison).unwrap();
+        assert_eq!(poison.stacks, MAX_POISON_STACKS);
+        assert_eq!(poison.damage_per_second(), 2.0 * MAX_POISON_STACKS as f32);
+    }
+
+    #[test]
+    fn strongest_slow_wins() {
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusEffect::slow(0.5, 1.0, WeaponType::SoulEater));
+        effects.apply(StatusEffect::slow(0.2, 2.0, WeaponType::Garlic));
+
+        assert!((effects.speed_multiplier() - 0.5).abs() < 1e-6);
+        assert_eq!(effects.get(StatusKind::Slow).unwrap().remaining, 2.0);
+    }
+
+    #[test]
+    fn freeze_overrides_slow_and_tint() {
+        let mut effects = StatusEffects::default();
+        effects.apply(StatusEffect::slow(0.3, 1.0, WeaponType::Garlic));
+        effects.apply(StatusEffect::freeze(1.0, WeaponType::HolyWand));
+
+        assert_eq!(effects.speed_multiplier(), 0.0);
+        assert_eq!(effects.tint(), Some(Color::srgb(0.6, 0.85, 1.0)));
+    }
+
+    #[test]
+    fn no_effects_means_full_speed_and_no_tint() {
+        let effects = StatusEffects::default();
+        assert_eq!(effects.speed_multiplier(), 1.0);
+        assert!(effects.tint().is_none());
+    }
+}
@@ -15,6 +15,8 @@ pub struct Projectile {
     /// Remaining lifetime in seconds.
     pub lifetime: f32,
     pub weapon_type: WeaponType,
+    /// Knockback distance (pixels) applied along the travel direction on hit.
+    pub knockback: f32,
 }
 
 /// Linear velocity of a projectile entity (pixels/second).
@@ -4,6 +4,7 @@ use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use serde::Deserialize;
 
+use crate::config::validate::{ConfigValidator, ValidateConfig};
 use crate::types::{CharacterBaseStats, CharacterType, WeaponType};
 
 // ---------------------------------------------------------------------------
@@ -140,6 +141,28 @@ impl CharacterConfig {
 #[derive(Resource)]
 pub struct CharacterConfigHandle(pub Handle<CharacterConfig>);
 
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+impl ValidateConfig for CharacterConfig {
+    fn validate(&self, v: &mut ConfigValidator) {
+        for (name, stats) in [
+            ("default_character", &self.default_character),
+            ("magician", &self.magician),
+            ("thief", &self.thief),
+            ("knight", &self.knight),
+        ] {
+            v.scope(name, |v| {
+                v.positive("max_hp", stats.max_hp);
+                v.positive("move_speed", stats.move_speed);
+                v.non_negative("damage_multiplier", stats.damage_multiplier);
+                v.fraction("cooldown_reduction", stats.cooldown_reduction);
+            });
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // SystemParam bundle
 // ---------------------------------------------------------------------------
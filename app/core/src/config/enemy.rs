@@ -1,10 +1,13 @@
 //! Enemy configuration loaded from `assets/config/enemy.ron`.
 
+use std::collections::HashMap;
+
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use serde::Deserialize;
 
-use crate::types::EnemyType;
+use crate::config::validate::{ConfigValidator, ValidateConfig};
+use crate::types::{DamageType, EnemyType, MiniBossKind};
 
 // ---------------------------------------------------------------------------
 // Fallback constants (used while enemy.ron is still loading)
@@ -25,6 +28,20 @@ const DEFAULT_CULL_DISTANCE: f32 = 2000.0;
 /// Extra pixels beyond the viewport edge at which enemies spawn.
 const DEFAULT_SPAWN_MARGIN: f32 = 60.0;
 
+/// Fraction of enemy-enemy overlap resolved per frame (0.0 disables separation).
+const DEFAULT_SEPARATION_STRENGTH: f32 = 0.5;
+
+/// Seconds an enemy with zero knockback resistance stops moving after a
+/// knockback hit.
+const DEFAULT_HIT_STUN_DURATION: f32 = 0.15;
+
+/// Seconds between damage-over-time ticks of burn and poison.
+const DEFAULT_STATUS_TICK_INTERVAL: f32 = 0.5;
+
+/// Shortest accepted DoT tick interval; guards against a zero in the RON
+/// flooding the damage queue.
+const MIN_STATUS_TICK_INTERVAL: f32 = 0.05;
+
 /// Seconds before Zombies appear in the spawn table.
 const DEFAULT_ZOMBIE_UNLOCK_SECS: f32 = 300.0;
 
@@ -43,19 +60,38 @@ const DEFAULT_DRAGON_UNLOCK_SECS: f32 = 1500.0;
 /// Seconds between mini-boss spawns.
 const DEFAULT_MINI_BOSS_INTERVAL: f32 = 180.0;
 
+/// Built-in incoming-damage multiplier per enemy and damage type; mirrors the
+/// `damage_multipliers` maps in `enemy.ron`.  Unlisted pairs take full damage.
+fn default_damage_multiplier(enemy_type: EnemyType, damage_type: DamageType) -> f32 {
+    use DamageType::*;
+    match (enemy_type, damage_type) {
+        (EnemyType::Skeleton, Holy) => 1.25,
+        (EnemyType::Zombie, Fire) => 1.5,
+        (EnemyType::Ghost, Physical) => 0.5,
+        (EnemyType::Ghost, Holy) => 1.5,
+        (EnemyType::Demon, Fire) => 0.5,
+        (EnemyType::Demon, Holy) => 1.5,
+        (EnemyType::Medusa, Lightning) => 0.75,
+        (EnemyType::Dragon, Fire) => 0.25,
+        (EnemyType::Dragon, Lightning) => 1.25,
+        (EnemyType::BossDeath, Physical) => 0.8,
+        (EnemyType::BossDeath | EnemyType::MiniDeath, Holy) => 1.25,
+        _ => 1.0,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Medusa AI behavior config
 // ---------------------------------------------------------------------------
 
-/// Behavior parameters for the Medusa ranged enemy, deserialized from RON.
+/// Keep-distance movement parameters for the Medusa, deserialized from RON.
+///
+/// Its projectile attack lives in the `ranged_attacks` map like every other
+/// ranged enemy's.
 #[derive(Debug, Clone)]
 pub struct MedusaBehaviorConfig {
     pub keep_min_dist: f32,
     pub keep_max_dist: f32,
-    pub attack_interval: f32,
-    pub projectile_speed: f32,
-    pub projectile_lifetime: f32,
-    pub projectile_radius: f32,
 }
 
 /// Deserialization mirror of [`MedusaBehaviorConfig`].
@@ -64,84 +100,342 @@ pub struct MedusaBehaviorConfig {
 pub(super) struct MedusaBehaviorConfigPartial {
     pub keep_min_dist: Option<f32>,
     pub keep_max_dist: Option<f32>,
-    pub attack_interval: Option<f32>,
-    pub projectile_speed: Option<f32>,
-    pub projectile_lifetime: Option<f32>,
-    pub projectile_radius: Option<f32>,
 }
 
 impl From<MedusaBehaviorConfigPartial> for MedusaBehaviorConfig {
     fn from(p: MedusaBehaviorConfigPartial) -> Self {
         MedusaBehaviorConfig {
             keep_min_dist: p.keep_min_dist.unwrap_or_else(|| {
-                warn!("enemy.ron: `medusa_behavior.keep_min_dist` missing → using built-in baseline");
+                warn!(
+                    "enemy.ron: `medusa_behavior.keep_min_dist` missing → using built-in baseline"
+                );
                 150.0
             }),
             keep_max_dist: p.keep_max_dist.unwrap_or_else(|| {
-                warn!("enemy.ron: `medusa_behavior.keep_max_dist` missing → using built-in baseline");
+                warn!(
+                    "enemy.ron: `medusa_behavior.keep_max_dist` missing → using built-in baseline"
+                );
                 250.0
             }),
-            attack_interval: p.attack_interval.unwrap_or_else(|| {
-                warn!("enemy.ron: `medusa_behavior.attack_interval` missing → using built-in baseline");
-                2.0
-            }),
-            projectile_speed: p.projectile_speed.unwrap_or_else(|| {
-                warn!("enemy.ron: `medusa_behavior.projectile_speed` missing → using built-in baseline");
-                180.0
-            }),
-            projectile_lifetime: p.projectile_lifetime.unwrap_or_else(|| {
-                warn!("enemy.ron: `medusa_behavior.projectile_lifetime` missing → using built-in baseline");
-                5.0
-            }),
-            projectile_radius: p.projectile_radius.unwrap_or_else(|| {
-                warn!("enemy.ron: `medusa_behavior.projectile_radius` missing → using built-in baseline");
-                5.0
-            }),
         }
     }
 }
 
 // ---------------------------------------------------------------------------
-// Dragon AI behavior config
+// Ranged attack config
 // ---------------------------------------------------------------------------
 
-/// Behavior parameters for the Dragon enemy, deserialized from RON.
-#[derive(Debug, Clone)]
-pub struct DragonBehaviorConfig {
+/// Hostile projectiles kept for reuse after they expire or hit.
+const DEFAULT_HOSTILE_PROJECTILE_POOL_SIZE: usize = 512;
+
+/// Projectile attack of one ranged enemy type, deserialized from the
+/// `ranged_attacks` map.
+///
+/// Every enemy type listed in the map shoots at the player while it chases
+/// or keeps its distance; each shot deals the enemy's contact `damage`.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct RangedAttackConfig {
+    /// Seconds between shots.
     pub attack_interval: f32,
-    pub fireball_speed: f32,
-    pub fireball_lifetime: f32,
-    pub fireball_radius: f32,
+    /// Launch speed in pixels/second.
+    pub projectile_speed: f32,
+    /// Seconds before the projectile expires.
+    pub projectile_lifetime: f32,
+    /// Projectile collider radius in pixels.
+    pub projectile_radius: f32,
+    /// Sprite colour as `(r, g, b)`.
+    pub color: (f32, f32, f32),
+    /// Max turn rate toward the player (radians/second); 0 flies straight.
+    #[serde(default)]
+    pub homing: f32,
+    /// Speed change in pixels/second²; negative values slow down to a stop.
+    #[serde(default)]
+    pub acceleration: f32,
+    /// Sprite rotation speed (radians/second), visual only.
+    #[serde(default)]
+    pub spin: f32,
+}
+
+/// Built-in ranged attacks; mirror the `ranged_attacks` map in `enemy.ron`.
+fn default_ranged_attack(enemy_type: EnemyType) -> Option<RangedAttackConfig> {
+    match enemy_type {
+        EnemyType::Medusa => Some(RangedAttackConfig {
+            attack_interval: 2.0,
+            projectile_speed: 180.0,
+            projectile_lifetime: 5.0,
+            projectile_radius: 5.0,
+            color: (0.9, 0.75, 0.0),
+            homing: 0.0,
+            acceleration: 0.0,
+            spin: 0.0,
+        }),
+        EnemyType::Dragon => Some(RangedAttackConfig {
+            attack_interval: 3.0,
+            projectile_speed: 200.0,
+            projectile_lifetime: 6.0,
+            projectile_radius: 7.0,
+            color: (1.0, 0.4, 0.0),
+            homing: 0.0,
+            acceleration: 0.0,
+            spin: 0.0,
+        }),
+        _ => None,
+    }
+}
+
+fn default_ranged_attacks() -> HashMap<EnemyType, RangedAttackConfig> {
+    [EnemyType::Medusa, EnemyType::Dragon]
+        .into_iter()
+        .filter_map(|t| default_ranged_attack(t).map(|attack| (t, attack)))
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Mini-boss archetypes config
+// ---------------------------------------------------------------------------
+
+/// Special move of a mini-boss archetype, written as a struct-like enum
+/// variant in RON, e.g. `Charge(speed: 420.0, duration: 0.8)`.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum MiniBossAbility {
+    /// Dash toward the player's current position at `speed` px/s for
+    /// `duration` seconds.
+    Charge { speed: f32, duration: f32 },
+    /// `count` regular enemies of type `enemy` spawned in a ring of `radius`
+    /// pixels around the mini-boss.
+    Summon {
+        enemy: EnemyType,
+        count: u32,
+        radius: f32,
+    },
+    /// `count` projectiles fanned across `spread_deg` degrees, centred on
+    /// the player.  Each deals `damage`.
+    Volley {
+        count: u32,
+        spread_deg: f32,
+        speed: f32,
+        damage: f32,
+        lifetime: f32,
+        radius: f32,
+    },
+}
+
+/// One mini-boss archetype, deserialized from the `mini_boss_archetypes` map.
+///
+/// Multipliers apply on top of the shared `mini_boss` stat block.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct MiniBossArchetype {
+    /// Max-HP multiplier.
+    pub hp_multiplier: f32,
+    /// Move-speed multiplier.
+    pub speed_multiplier: f32,
+    /// Sprite and collider scale.
+    pub size_multiplier: f32,
+    /// Placeholder sprite colour as `(r, g, b)`.
+    pub color: (f32, f32, f32),
+    /// Items in the treasure chest dropped on defeat (1, 3 or 5).
+    pub chest_items: u32,
+    /// Seconds between two uses of `ability`.
+    pub ability_interval: f32,
+    pub ability: MiniBossAbility,
+}
+
+/// Built-in archetypes; mirror the `mini_boss_archetypes` map in `enemy.ron`.
+pub(crate) fn default_mini_boss_archetype(kind: MiniBossKind) -> MiniBossArchetype {
+    match kind {
+        MiniBossKind::Giant => MiniBossArchetype {
+            hp_multiplier: 2.0,
+            speed_multiplier: 0.8,
+            size_multiplier: 1.5,
+            color: (0.6, 0.35, 0.15),
+            chest_items: 1,
+            ability_interval: 4.0,
+            ability: MiniBossAbility::Charge {
+                speed: 420.0,
+                duration: 0.8,
+            },
+        },
+        MiniBossKind::Summoner => MiniBossArchetype {
+            hp_multiplier: 1.2,
+            speed_multiplier: 0.9,
+            size_multiplier: 1.0,
+            color: (0.3, 0.8, 0.4),
+            chest_items: 3,
+            ability_interval: 6.0,
+            ability: MiniBossAbility::Summon {
+                enemy: EnemyType::Bat,
+                count: 6,
+                radius: 60.0,
+            },
+        },
+        MiniBossKind::Mage => MiniBossArchetype {
+            hp_multiplier: 1.0,
+            speed_multiplier: 1.0,
+            size_multiplier: 0.9,
+            color: (0.35, 0.45, 1.0),
+            chest_items: 5,
+            ability_interval: 2.5,
+            ability: MiniBossAbility::Volley {
+                count: 5,
+                spread_deg: 40.0,
+                speed: 220.0,
+                damage: 15.0,
+                lifetime: 4.0,
+                radius: 6.0,
+            },
+        },
+    }
+}
+
+fn default_mini_boss_archetypes() -> HashMap<MiniBossKind, MiniBossArchetype> {
+    MiniBossKind::ALL
+        .into_iter()
+        .map(|kind| (kind, default_mini_boss_archetype(kind)))
+        .collect()
 }
 
-/// Deserialization mirror of [`DragonBehaviorConfig`].
+// ---------------------------------------------------------------------------
+// Elite variants config
+// ---------------------------------------------------------------------------
+
+const DEFAULT_ELITE_BASE_CHANCE: f32 = 0.02;
+const DEFAULT_ELITE_CHANCE_PER_DIFFICULTY: f32 = 0.01;
+const DEFAULT_ELITE_MAX_CHANCE: f32 = 0.15;
+const DEFAULT_ELITE_HP_MULTIPLIER: f32 = 3.0;
+const DEFAULT_ELITE_XP_MULTIPLIER: f32 = 5.0;
+const DEFAULT_ELITE_SIZE_MULTIPLIER: f32 = 1.25;
+const DEFAULT_ELITE_SWIFT_SPEED_MULTIPLIER: f32 = 1.6;
+const DEFAULT_ELITE_ARMORED_DAMAGE_TAKEN: f32 = 0.5;
+const DEFAULT_ELITE_SPLIT_COUNT: u32 = 2;
+const DEFAULT_ELITE_SPLIT_HP_FRACTION: f32 = 0.4;
+const DEFAULT_ELITE_SPLIT_SIZE_MULTIPLIER: f32 = 0.7;
+const DEFAULT_ELITE_VAMPIRIC_HEAL_FRACTION: f32 = 0.2;
+
+/// Elite-roll and modifier parameters, deserialized from the `elite` block.
+#[derive(Debug, Clone)]
+pub struct EliteConfig {
+    /// Elite chance at difficulty 1.0.
+    pub base_chance: f32,
+    /// Extra elite chance per point of difficulty above 1.0.
+    pub chance_per_difficulty: f32,
+    /// Upper bound on the elite chance.
+    pub max_chance: f32,
+    /// Max-HP multiplier applied to every elite.
+    pub hp_multiplier: f32,
+    /// XP multiplier; the larger value yields a bigger gem tier.
+    pub xp_multiplier: f32,
+    /// Sprite and collider scale of an elite.
+    pub size_multiplier: f32,
+    /// Move-speed multiplier of Swift elites.
+    pub swift_speed_multiplier: f32,
+    /// Fraction of incoming damage Armored elites take.
+    pub armored_damage_taken: f32,
+    /// Copies a Splitting elite leaves behind on death.
+    pub split_count: u32,
+    /// HP of each split copy relative to a normal enemy of its type.
+    pub split_hp_fraction: f32,
+    /// Sprite and collider scale of each split copy.
+    pub split_size_multiplier: f32,
+    /// Fraction of max HP a Vampiric elite heals per hit on the player.
+    pub vampiric_heal_fraction: f32,
+}
+
+impl Default for EliteConfig {
+    fn default() -> Self {
+        EliteConfig {
+            base_chance: DEFAULT_ELITE_BASE_CHANCE,
+            chance_per_difficulty: DEFAULT_ELITE_CHANCE_PER_DIFFICULTY,
+            max_chance: DEFAULT_ELITE_MAX_CHANCE,
+            hp_multiplier: DEFAULT_ELITE_HP_MULTIPLIER,
+            xp_multiplier: DEFAULT_ELITE_XP_MULTIPLIER,
+            size_multiplier: DEFAULT_ELITE_SIZE_MULTIPLIER,
+            swift_speed_multiplier: DEFAULT_ELITE_SWIFT_SPEED_MULTIPLIER,
+            armored_damage_taken: DEFAULT_ELITE_ARMORED_DAMAGE_TAKEN,
+            split_count: DEFAULT_ELITE_SPLIT_COUNT,
+            split_hp_fraction: DEFAULT_ELITE_SPLIT_HP_FRACTION,
+            split_size_multiplier: DEFAULT_ELITE_SPLIT_SIZE_MULTIPLIER,
+            vampiric_heal_fraction: DEFAULT_ELITE_VAMPIRIC_HEAL_FRACTION,
+        }
+    }
+}
+
+impl EliteConfig {
+    /// Chance (0.0–`max_chance`) that a regular spawn at `difficulty` is an
+    /// elite.
+    pub fn chance_at(&self, difficulty: f32) -> f32 {
+        (self.base_chance + self.chance_per_difficulty * (difficulty - 1.0).max(0.0))
+            .clamp(0.0, self.max_chance.max(0.0))
+    }
+}
+
+/// Deserialization mirror of [`EliteConfig`].
 #[derive(Deserialize, Default)]
 #[serde(default)]
-pub(super) struct DragonBehaviorConfigPartial {
-    pub attack_interval: Option<f32>,
-    pub fireball_speed: Option<f32>,
-    pub fireball_lifetime: Option<f32>,
-    pub fireball_radius: Option<f32>,
+pub(super) struct EliteConfigPartial {
+    pub base_chance: Option<f32>,
+    pub chance_per_difficulty: Option<f32>,
+    pub max_chance: Option<f32>,
+    pub hp_multiplier: Option<f32>,
+    pub xp_multiplier: Option<f32>,
+    pub size_multiplier: Option<f32>,
+    pub swift_speed_multiplier: Option<f32>,
+    pub armored_damage_taken: Option<f32>,
+    pub split_count: Option<u32>,
+    pub split_hp_fraction: Option<f32>,
+    pub split_size_multiplier: Option<f32>,
+    pub vampiric_heal_fraction: Option<f32>,
 }
 
-impl From<DragonBehaviorConfigPartial> for DragonBehaviorConfig {
-    fn from(p: DragonBehaviorConfigPartial) -> Self {
-        DragonBehaviorConfig {
-            attack_interval: p.attack_interval.unwrap_or_else(|| {
-                warn!("enemy.ron: `dragon_behavior.attack_interval` missing → using built-in baseline");
-                3.0
+impl From<EliteConfigPartial> for EliteConfig {
+    fn from(p: EliteConfigPartial) -> Self {
+        EliteConfig {
+            base_chance: p.base_chance.unwrap_or_else(|| {
+                warn!("enemy.ron: `elite.base_chance` missing → using default {DEFAULT_ELITE_BASE_CHANCE}");
+                DEFAULT_ELITE_BASE_CHANCE
+            }),
+            chance_per_difficulty: p.chance_per_difficulty.unwrap_or_else(|| {
+                warn!("enemy.ron: `elite.chance_per_difficulty` missing → using default {DEFAULT_ELITE_CHANCE_PER_DIFFICULTY}");
+                DEFAULT_ELITE_CHANCE_PER_DIFFICULTY
             }),
-            fireball_speed: p.fireball_speed.unwrap_or_else(|| {
-                warn!("enemy.ron: `dragon_behavior.fireball_speed` missing → using built-in baseline");
-                200.0
+            max_chance: p.max_chance.unwrap_or_else(|| {
+                warn!("enemy.ron: `elite.max_chance` missing → using default {DEFAULT_ELITE_MAX_CHANCE}");
+                DEFAULT_ELITE_MAX_CHANCE
             }),
-            fireball_lifetime: p.fireball_lifetime.unwrap_or_else(|| {
-                warn!("enemy.ron: `dragon_behavior.fireball_lifetime` missing → using built-in baseline");
-                6.0
+            hp_multiplier: p.hp_multiplier.unwrap_or_else(|| {
+                warn!("enemy.ron: `elite.hp_multiplier` missing → using default {DEFAULT_ELITE_HP_MULTIPLIER}");
+                DEFAULT_ELITE_HP_MULTIPLIER
             }),
-            fireball_radius: p.fireball_radius.unwrap_or_else(|| {
-                warn!("enemy.ron: `dragon_behavior.fireball_radius` missing → using built-in baseline");
-                7.0
+            xp_multiplier: p.xp_multiplier.unwrap_or_else(|| {
+                warn!("enemy.ron: `elite.xp_multiplier` missing → using default {DEFAULT_ELITE_XP_MULTIPLIER}");
+                DEFAULT_ELITE_XP_MULTIPLIER
+            }),
+            size_multiplier: p.size_multiplier.unwrap_or_else(|| {
+                warn!("enemy.ron: `elite.size_multiplier` missing → using default {DEFAULT_ELITE_SIZE_MULTIPLIER}");
+                DEFAULT_ELITE_SIZE_MULTIPLIER
+            }),
+            swift_speed_multiplier: p.swift_speed_multiplier.unwrap_or_else(|| {
+                warn!("enemy.ron: `elite.swift_speed_multiplier` missing → using default {DEFAULT_ELITE_SWIFT_SPEED_MULTIPLIER}");
+                DEFAULT_ELITE_SWIFT_SPEED_MULTIPLIER
+            }),
+            armored_damage_taken: p.armored_damage_taken.unwrap_or_else(|| {
+                warn!("enemy.ron: `elite.armored_damage_taken` missing → using default {DEFAULT_ELITE_ARMORED_DAMAGE_TAKEN}");
+                DEFAULT_ELITE_ARMORED_DAMAGE_TAKEN
+            }),
+            split_count: p.split_count.unwrap_or_else(|| {
+                warn!("enemy.ron: `elite.split_count` missing → using default {DEFAULT_ELITE_SPLIT_COUNT}");
+                DEFAULT_ELITE_SPLIT_COUNT
+            }),
+            split_hp_fraction: p.split_hp_fraction.unwrap_or_else(|| {
+                warn!("enemy.ron: `elite.split_hp_fraction` missing → using default {DEFAULT_ELITE_SPLIT_HP_FRACTION}");
+                DEFAULT_ELITE_SPLIT_HP_FRACTION
+            }),
+            split_size_multiplier: p.split_size_multiplier.unwrap_or_else(|| {
+                warn!("enemy.ron: `elite.split_size_multiplier` missing → using default {DEFAULT_ELITE_SPLIT_SIZE_MULTIPLIER}");
+                DEFAULT_ELITE_SPLIT_SIZE_MULTIPLIER
+            }),
+            vampiric_heal_fraction: p.vampiric_heal_fraction.unwrap_or_else(|| {
+                warn!("enemy.ron: `elite.vampiric_heal_fraction` missing → using default {DEFAULT_ELITE_VAMPIRIC_HEAL_FRACTION}");
+                DEFAULT_ELITE_VAMPIRIC_HEAL_FRACTION
             }),
         }
     }
@@ -165,6 +459,24 @@ pub struct EnemyStatsEntry {
     /// Higher values = more frequent.  Does not affect unlock timing.
     /// Typical range: 0.3 (Dragon) – 1.0 (Bat/Skeleton).
     pub spawn_weight: f32,
+    /// Fraction (0.0–1.0) of weapon knockback and hit-stun this enemy ignores.
+    ///
+    /// 0.0 takes the full push; bosses sit near 1.0.
+    pub knockback_resistance: f32,
+    /// Incoming-damage multiplier per [`DamageType`]: below 1.0 is a
+    /// resistance, above 1.0 a weakness.  Types not listed take full damage.
+    pub damage_multipliers: HashMap<DamageType, f32>,
+}
+
+impl EnemyStatsEntry {
+    /// Multiplier applied to incoming damage of `damage_type` (1.0 if unlisted).
+    pub fn damage_multiplier(&self, damage_type: DamageType) -> f32 {
+        self.damage_multipliers
+            .get(&damage_type)
+            .copied()
+            .unwrap_or(1.0)
+            .max(0.0)
+    }
 }
 
 /// Deserialization mirror of [`EnemyStatsEntry`].
@@ -178,6 +490,8 @@ pub(super) struct EnemyStatsEntryPartial {
     pub gold_chance: Option<f32>,
     pub collider_radius: Option<f32>,
     pub spawn_weight: Option<f32>,
+    pub knockback_resistance: Option<f32>,
+    pub damage_multipliers: Option<HashMap<DamageType, f32>>,
 }
 
 impl EnemyStatsEntryPartial {
@@ -213,6 +527,18 @@ impl EnemyStatsEntryPartial {
                 warn!("enemy.ron: `{field_prefix}.spawn_weight` missing → using built-in baseline");
                 0.0
             }),
+            knockback_resistance: self.knockback_resistance.unwrap_or_else(|| {
+                warn!(
+                    "enemy.ron: `{field_prefix}.knockback_resistance` missing → using built-in baseline"
+                );
+                0.0
+            }),
+            damage_multipliers: self.damage_multipliers.unwrap_or_else(|| {
+                warn!(
+                    "enemy.ron: `{field_prefix}.damage_multipliers` missing → no resistances or weaknesses"
+                );
+                HashMap::new()
+            }),
         }
     }
 }
@@ -241,14 +567,20 @@ pub(super) struct EnemyConfigPartial {
     pub cull_distance: Option<f32>,
     pub difficulty_max: Option<f32>,
     pub spawn_margin: Option<f32>,
+    pub separation_strength: Option<f32>,
+    pub hit_stun_duration: Option<f32>,
+    pub status_tick_interval: Option<f32>,
     pub zombie_unlock_secs: Option<f32>,
     pub ghost_unlock_secs: Option<f32>,
     pub demon_unlock_secs: Option<f32>,
     pub medusa_unlock_secs: Option<f32>,
     pub dragon_unlock_secs: Option<f32>,
     pub mini_boss_interval: Option<f32>,
+    pub hostile_projectile_pool_size: Option<usize>,
     pub medusa_behavior: Option<MedusaBehaviorConfigPartial>,
-    pub dragon_behavior: Option<DragonBehaviorConfigPartial>,
+    pub ranged_attacks: Option<HashMap<EnemyType, RangedAttackConfig>>,
+    pub mini_boss_archetypes: Option<HashMap<MiniBossKind, MiniBossArchetype>>,
+    pub elite: Option<EliteConfigPartial>,
 }
 
 /// Full enemy configuration, loaded from `assets/config/enemy.ron`.
@@ -267,7 +599,8 @@ pub struct EnemyConfig {
     pub dragon: EnemyStatsEntry,
     pub boss_death: EnemyStatsEntry,
     pub mini_death: EnemyStatsEntry,
-    /// Mini-boss that spawns every 3 minutes and drops a treasure chest on defeat.
+    /// Stats shared by every mini-boss archetype; each drops a treasure chest
+    /// on defeat.
     pub mini_boss: EnemyStatsEntry,
     // Spawning parameters
     pub spawn_base_interval: f32,
@@ -276,6 +609,14 @@ pub struct EnemyConfig {
     pub difficulty_max: f32,
     /// Extra pixels beyond the half-viewport edge at which enemies appear.
     pub spawn_margin: f32,
+    /// Fraction of the overlap between two enemies resolved each frame
+    /// (0.0 disables separation, 1.0 resolves it fully in one frame).
+    pub separation_strength: f32,
+    /// Seconds an enemy stops moving after a knockback hit, before
+    /// `knockback_resistance` scaling.
+    pub hit_stun_duration: f32,
+    /// Seconds between damage-over-time ticks of burn and poison.
+    pub status_tick_interval: f32,
     /// Seconds into the run before Zombies are added to the spawn table.
     pub zombie_unlock_secs: f32,
     /// Seconds into the run before Ghosts are added to the spawn table.
@@ -286,12 +627,19 @@ pub struct EnemyConfig {
     pub medusa_unlock_secs: f32,
     /// Seconds into the run before Dragons are added to the spawn table.
     pub dragon_unlock_secs: f32,
-    /// Seconds between each mini-boss spawn (default 180 = 3 minutes).
+    /// Seconds between mini-boss spawns when no stage schedule is loaded
+    /// (default 180 = 3 minutes).
     pub mini_boss_interval: f32,
-    /// Medusa-specific AI and projectile behavior parameters.
+    /// Finished hostile projectiles kept for reuse instead of despawned.
+    pub hostile_projectile_pool_size: usize,
+    /// Medusa-specific keep-distance parameters.
     pub medusa_behavior: MedusaBehaviorConfig,
-    /// Dragon-specific fireball behavior parameters.
-    pub dragon_behavior: DragonBehaviorConfig,
+    /// Projectile attack per ranged enemy type; unlisted types only melee.
+    pub ranged_attacks: HashMap<EnemyType, RangedAttackConfig>,
+    /// Behaviour, look and chest size per mini-boss archetype.
+    pub mini_boss_archetypes: HashMap<MiniBossKind, MiniBossArchetype>,
+    /// Elite roll chance and modifier strengths.
+    pub elite: EliteConfig,
 }
 
 impl From<EnemyConfigPartial> for EnemyConfig {
@@ -367,6 +715,24 @@ impl From<EnemyConfigPartial> for EnemyConfig {
                 );
                 DEFAULT_SPAWN_MARGIN
             }),
+            separation_strength: p.separation_strength.unwrap_or_else(|| {
+                warn!(
+                    "enemy.ron: `separation_strength` missing → using default {DEFAULT_SEPARATION_STRENGTH}"
+                );
+                DEFAULT_SEPARATION_STRENGTH
+            }),
+            hit_stun_duration: p.hit_stun_duration.unwrap_or_else(|| {
+                warn!(
+                    "enemy.ron: `hit_stun_duration` missing → using default {DEFAULT_HIT_STUN_DURATION}"
+                );
+                DEFAULT_HIT_STUN_DURATION
+            }),
+            status_tick_interval: p.status_tick_interval.unwrap_or_else(|| {
+                warn!(
+                    "enemy.ron: `status_tick_interval` missing → using default {DEFAULT_STATUS_TICK_INTERVAL}"
+                );
+                DEFAULT_STATUS_TICK_INTERVAL
+            }),
             zombie_unlock_secs: p.zombie_unlock_secs.unwrap_or_else(|| {
                 warn!(
                     "enemy.ron: `zombie_unlock_secs` missing → using default {DEFAULT_ZOMBIE_UNLOCK_SECS}"
@@ -403,18 +769,30 @@ impl From<EnemyConfigPartial> for EnemyConfig {
                 );
                 DEFAULT_MINI_BOSS_INTERVAL
             }),
+            hostile_projectile_pool_size: p.hostile_projectile_pool_size.unwrap_or_else(|| {
+                warn!(
+                    "enemy.ron: `hostile_projectile_pool_size` missing → using default {DEFAULT_HOSTILE_PROJECTILE_POOL_SIZE}"
+                );
+                DEFAULT_HOSTILE_PROJECTILE_POOL_SIZE
+            }),
             medusa_behavior: MedusaBehaviorConfig::from(
                 p.medusa_behavior.unwrap_or_else(|| {
                     warn!("enemy.ron: `medusa_behavior` block missing → using built-in baseline");
                     MedusaBehaviorConfigPartial::default()
                 }),
             ),
-            dragon_behavior: DragonBehaviorConfig::from(
-                p.dragon_behavior.unwrap_or_else(|| {
-                    warn!("enemy.ron: `dragon_behavior` block missing → using built-in baseline");
-                    DragonBehaviorConfigPartial::default()
-                }),
-            ),
+            ranged_attacks: p.ranged_attacks.unwrap_or_else(|| {
+                warn!("enemy.ron: `ranged_attacks` missing → using built-in baseline");
+                default_ranged_attacks()
+            }),
+            mini_boss_archetypes: p.mini_boss_archetypes.unwrap_or_else(|| {
+                warn!("enemy.ron: `mini_boss_archetypes` missing → using built-in baseline");
+                default_mini_boss_archetypes()
+            }),
+            elite: EliteConfig::from(p.elite.unwrap_or_else(|| {
+                warn!("enemy.ron: `elite` block missing → using defaults");
+                EliteConfigPartial::default()
+            })),
         }
     }
 }
@@ -441,6 +819,146 @@ impl EnemyConfig {
 #[derive(Resource)]
 pub struct EnemyConfigHandle(pub Handle<EnemyConfig>);
 
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+impl ValidateConfig for EnemyStatsEntry {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.positive("base_hp", self.base_hp);
+        v.non_negative("speed", self.speed);
+        v.non_negative("damage", self.damage);
+        v.fraction("gold_chance", self.gold_chance);
+        v.positive("collider_radius", self.collider_radius);
+        v.non_negative("spawn_weight", self.spawn_weight);
+        v.fraction("knockback_resistance", self.knockback_resistance);
+        v.scope("damage_multipliers", |v| {
+            for (damage_type, &mult) in sorted_by_name(&self.damage_multipliers) {
+                v.non_negative(&format!("[{damage_type:?}]"), mult);
+            }
+        });
+    }
+}
+
+impl ValidateConfig for RangedAttackConfig {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.positive("attack_interval", self.attack_interval);
+        v.non_negative("projectile_speed", self.projectile_speed);
+        v.positive("projectile_lifetime", self.projectile_lifetime);
+        v.positive("projectile_radius", self.projectile_radius);
+        v.non_negative("homing", self.homing);
+    }
+}
+
+impl ValidateConfig for MiniBossArchetype {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.positive("hp_multiplier", self.hp_multiplier);
+        v.non_negative("speed_multiplier", self.speed_multiplier);
+        v.positive("size_multiplier", self.size_multiplier);
+        v.non_zero("chest_items", self.chest_items.into());
+        v.positive("ability_interval", self.ability_interval);
+        v.scope("ability", |v| match self.ability {
+            MiniBossAbility::Charge { speed, duration } => {
+                v.positive("speed", speed);
+                v.positive("duration", duration);
+            }
+            MiniBossAbility::Summon { count, radius, .. } => {
+                v.non_zero("count", count.into());
+                v.non_negative("radius", radius);
+            }
+            MiniBossAbility::Volley {
+                count,
+                spread_deg,
+                speed,
+                damage,
+                lifetime,
+                radius,
+            } => {
+                v.non_zero("count", count.into());
+                v.non_negative("spread_deg", spread_deg);
+                v.positive("speed", speed);
+                v.non_negative("damage", damage);
+                v.positive("lifetime", lifetime);
+                v.positive("radius", radius);
+            }
+        });
+    }
+}
+
+impl ValidateConfig for EliteConfig {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.fraction("base_chance", self.base_chance);
+        v.non_negative("chance_per_difficulty", self.chance_per_difficulty);
+        v.fraction("max_chance", self.max_chance);
+        v.positive("hp_multiplier", self.hp_multiplier);
+        v.non_negative("xp_multiplier", self.xp_multiplier);
+        v.positive("size_multiplier", self.size_multiplier);
+        v.non_negative("swift_speed_multiplier", self.swift_speed_multiplier);
+        v.non_negative("armored_damage_taken", self.armored_damage_taken);
+        v.fraction("split_hp_fraction", self.split_hp_fraction);
+        v.positive("split_size_multiplier", self.split_size_multiplier);
+        v.fraction("vampiric_heal_fraction", self.vampiric_heal_fraction);
+    }
+}
+
+impl ValidateConfig for EnemyConfig {
+    fn validate(&self, v: &mut ConfigValidator) {
+        for (name, stats) in [
+            ("bat", &self.bat),
+            ("skeleton", &self.skeleton),
+            ("zombie", &self.zombie),
+            ("ghost", &self.ghost),
+            ("demon", &self.demon),
+            ("medusa", &self.medusa),
+            ("dragon", &self.dragon),
+            ("boss_death", &self.boss_death),
+            ("mini_death", &self.mini_death),
+            ("mini_boss", &self.mini_boss),
+        ] {
+            v.scope(name, |v| stats.validate(v));
+        }
+
+        v.positive("spawn_base_interval", self.spawn_base_interval);
+        v.non_zero("max_count", self.max_count as u64);
+        v.positive("cull_distance", self.cull_distance);
+        v.positive("difficulty_max", self.difficulty_max);
+        v.non_negative("spawn_margin", self.spawn_margin);
+        v.non_negative("separation_strength", self.separation_strength);
+        v.non_negative("hit_stun_duration", self.hit_stun_duration);
+        v.positive("status_tick_interval", self.status_tick_interval);
+        v.positive("mini_boss_interval", self.mini_boss_interval);
+
+        v.scope("medusa_behavior", |v| {
+            let m = &self.medusa_behavior;
+            v.non_negative("keep_min_dist", m.keep_min_dist);
+            v.less_than(
+                "keep_min_dist",
+                m.keep_min_dist,
+                "keep_max_dist",
+                m.keep_max_dist,
+            );
+        });
+        v.scope("ranged_attacks", |v| {
+            for (enemy_type, attack) in sorted_by_name(&self.ranged_attacks) {
+                v.scope(format!("[{enemy_type:?}]"), |v| attack.validate(v));
+            }
+        });
+        v.scope("mini_boss_archetypes", |v| {
+            for (kind, archetype) in sorted_by_name(&self.mini_boss_archetypes) {
+                v.scope(format!("[{kind:?}]"), |v| archetype.validate(v));
+            }
+        });
+        v.scope("elite", |v| self.elite.validate(v));
+    }
+}
+
+/// Map entries ordered by key name, so issues are reported in a stable order.
+fn sorted_by_name<K: std::fmt::Debug, V>(map: &HashMap<K, V>) -> Vec<(&K, &V)> {
+    let mut entries: Vec<_> = map.iter().collect();
+    entries.sort_by_cached_key(|(k, _)| format!("{k:?}"));
+    entries
+}
+
 // ---------------------------------------------------------------------------
 // SystemParam bundle
 // ---------------------------------------------------------------------------
@@ -497,6 +1015,71 @@ impl<'w> EnemyParams<'w> {
             .unwrap_or(DEFAULT_SPAWN_MARGIN)
     }
 
+    /// Overlap fraction resolved per frame, clamped to `0.0..=1.0`.
+    pub fn separation_strength(&self) -> f32 {
+        self.get()
+            .map(|c| c.separation_strength)
+            .unwrap_or(DEFAULT_SEPARATION_STRENGTH)
+            .clamp(0.0, 1.0)
+    }
+
+    /// Incoming-damage multiplier for `enemy_type` hit by `damage_type`.
+    ///
+    /// Falls back to the built-in resistance table while loading.
+    pub fn damage_multiplier(&self, enemy_type: EnemyType, damage_type: DamageType) -> f32 {
+        self.get()
+            .map(|c| c.stats_for(enemy_type).damage_multiplier(damage_type))
+            .unwrap_or_else(|| default_damage_multiplier(enemy_type, damage_type))
+    }
+
+    /// Hit-stun seconds for an enemy with zero knockback resistance.
+    pub fn hit_stun_duration(&self) -> f32 {
+        self.get()
+            .map(|c| c.hit_stun_duration)
+            .unwrap_or(DEFAULT_HIT_STUN_DURATION)
+            .max(0.0)
+    }
+
+    /// Seconds between burn/poison damage ticks, never below 0.05 s.
+    pub fn status_tick_interval(&self) -> f32 {
+        self.get()
+            .map(|c| c.status_tick_interval)
+            .unwrap_or(DEFAULT_STATUS_TICK_INTERVAL)
+            .max(MIN_STATUS_TICK_INTERVAL)
+    }
+
+    /// Projectile attack of `enemy_type`, or `None` for a melee-only type.
+    ///
+    /// Falls back to the built-in Medusa and Dragon attacks while loading.
+    pub fn ranged_attack(&self, enemy_type: EnemyType) -> Option<RangedAttackConfig> {
+        match self.get() {
+            Some(c) => c.ranged_attacks.get(&enemy_type).cloned(),
+            None => default_ranged_attack(enemy_type),
+        }
+    }
+
+    /// Maximum number of idle hostile projectiles kept for reuse.
+    pub fn hostile_projectile_pool_size(&self) -> usize {
+        self.get()
+            .map(|c| c.hostile_projectile_pool_size)
+            .unwrap_or(DEFAULT_HOSTILE_PROJECTILE_POOL_SIZE)
+    }
+
+    /// Archetype parameters of `kind`.
+    ///
+    /// Falls back to the built-in archetype while loading or when `kind` is
+    /// missing from the map.
+    pub fn mini_boss_archetype(&self, kind: MiniBossKind) -> MiniBossArchetype {
+        self.get()
+            .and_then(|c| c.mini_boss_archetypes.get(&kind).cloned())
+            .unwrap_or_else(|| default_mini_boss_archetype(kind))
+    }
+
+    /// Elite parameters, or the built-in defaults while loading.
+    pub fn elite(&self) -> EliteConfig {
+        self.get().map(|c| c.elite.clone()).unwrap_or_default()
+    }
+
     pub fn zombie_unlock_secs(&self) -> f32 {
         self.get()
             .map(|c| c.zombie_unlock_secs)
@@ -571,40 +1154,56 @@ mod tests {
     fn enemy_config_deserialization() {
         let ron_data = r#"
 EnemyConfig(
-    bat: (base_hp: 10.0, speed: 150.0, damage: 5.0, xp_value: 3, gold_chance: 0.05, collider_radius: 8.0, spawn_weight: 1.0),
-    skeleton: (base_hp: 30.0, speed: 80.0, damage: 8.0, xp_value: 5, gold_chance: 0.08, collider_radius: 12.0, spawn_weight: 1.0),
-    zombie: (base_hp: 60.0, speed: 60.0, damage: 12.0, xp_value: 8, gold_chance: 0.10, collider_radius: 14.0, spawn_weight: 0.8),
-    ghost: (base_hp: 25.0, speed: 100.0, damage: 10.0, xp_value: 6, gold_chance: 0.08, collider_radius: 10.0, spawn_weight: 0.6),
-    demon: (base_hp: 80.0, speed: 130.0, damage: 15.0, xp_value: 10, gold_chance: 0.12, collider_radius: 14.0, spawn_weight: 0.5),
-    medusa: (base_hp: 60.0, speed: 60.0, damage: 12.0, xp_value: 8, gold_chance: 0.10, collider_radius: 12.0, spawn_weight: 0.4),
-    dragon: (base_hp: 150.0, speed: 90.0, damage: 25.0, xp_value: 15, gold_chance: 0.15, collider_radius: 20.0, spawn_weight: 0.3),
-    boss_death: (base_hp: 5000.0, speed: 30.0, damage: 50.0, xp_value: 500, gold_chance: 1.0, collider_radius: 30.0, spawn_weight: 0.0),
-    mini_death: (base_hp: 800.0, speed: 80.0, damage: 30.0, xp_value: 50, gold_chance: 0.5, collider_radius: 20.0, spawn_weight: 0.0),
-    mini_boss: (base_hp: 400.0, speed: 70.0, damage: 20.0, xp_value: 30, gold_chance: 0.0, collider_radius: 22.0, spawn_weight: 0.0),
+    bat: (base_hp: 10.0, speed: 150.0, damage: 5.0, xp_value: 3, gold_chance: 0.05, collider_radius: 8.0, spawn_weight: 1.0, knockback_resistance: 0.0),
+    skeleton: (base_hp: 30.0, speed: 80.0, damage: 8.0, xp_value: 5, gold_chance: 0.08, collider_radius: 12.0, spawn_weight: 1.0, knockback_resistance: 0.1),
+    zombie: (base_hp: 60.0, speed: 60.0, damage: 12.0, xp_value: 8, gold_chance: 0.10, collider_radius: 14.0, spawn_weight: 0.8, knockback_resistance: 0.3),
+    ghost: (base_hp: 25.0, speed: 100.0, damage: 10.0, xp_value: 6, gold_chance: 0.08, collider_radius: 10.0, spawn_weight: 0.6, knockback_resistance: 0.0, damage_multipliers: {Physical: 0.5, Holy: 1.5}),
+    demon: (base_hp: 80.0, speed: 130.0, damage: 15.0, xp_value: 10, gold_chance: 0.12, collider_radius: 14.0, spawn_weight: 0.5, knockback_resistance: 0.2),
+    medusa: (base_hp: 60.0, speed: 60.0, damage: 12.0, xp_value: 8, gold_chance: 0.10, collider_radius: 12.0, spawn_weight: 0.4, knockback_resistance: 0.2),
+    dragon: (base_hp: 150.0, speed: 90.0, damage: 25.0, xp_value: 15, gold_chance: 0.15, collider_radius: 20.0, spawn_weight: 0.3, knockback_resistance: 0.5),
+    boss_death: (base_hp: 5000.0, speed: 30.0, damage: 50.0, xp_value: 500, gold_chance: 1.0, collider_radius: 30.0, spawn_weight: 0.0, knockback_resistance: 0.95),
+    mini_death: (base_hp: 800.0, speed: 80.0, damage: 30.0, xp_value: 50, gold_chance: 0.5, collider_radius: 20.0, spawn_weight: 0.0, knockback_resistance: 0.9),
+    mini_boss: (base_hp: 400.0, speed: 70.0, damage: 20.0, xp_value: 30, gold_chance: 0.0, collider_radius: 22.0, spawn_weight: 0.0, knockback_resistance: 0.9),
     spawn_base_interval: 0.5,
     max_count: 500,
     cull_distance: 2000.0,
     difficulty_max: 10.0,
     spawn_margin: 60.0,
+    separation_strength: 0.5,
+    hit_stun_duration: 0.15,
+    status_tick_interval: 0.5,
     zombie_unlock_secs: 300.0,
     ghost_unlock_secs: 600.0,
     demon_unlock_secs: 900.0,
     medusa_unlock_secs: 1200.0,
     dragon_unlock_secs: 1500.0,
     mini_boss_interval: 180.0,
+    hostile_projectile_pool_size: 256,
     medusa_behavior: (
         keep_min_dist: 150.0,
         keep_max_dist: 250.0,
-        attack_interval: 2.0,
-        projectile_speed: 180.0,
-        projectile_lifetime: 5.0,
-        projectile_radius: 5.0,
     ),
-    dragon_behavior: (
-        attack_interval: 3.0,
-        fireball_speed: 200.0,
-        fireball_lifetime: 6.0,
-        fireball_radius: 7.0,
+    ranged_attacks: {
+        Medusa: (attack_interval: 2.0, projectile_speed: 180.0, projectile_lifetime: 5.0, projectile_radius: 5.0, color: (0.9, 0.75, 0.0)),
+        Dragon: (attack_interval: 3.0, projectile_speed: 200.0, projectile_lifetime: 6.0, projectile_radius: 7.0, color: (1.0, 0.4, 0.0), homing: 0.5, spin: 3.0),
+    },
+    mini_boss_archetypes: {
+        Giant: (hp_multiplier: 2.0, speed_multiplier: 0.8, size_multiplier: 1.5, color: (0.6, 0.35, 0.15), chest_items: 1, ability_interval: 4.0, ability: Charge(speed: 420.0, duration: 0.8)),
+        Summoner: (hp_multiplier: 1.2, speed_multiplier: 0.9, size_multiplier: 1.0, color: (0.3, 0.8, 0.4), chest_items: 3, ability_interval: 6.0, ability: Summon(enemy: Skeleton, count: 4, radius: 50.0)),
+    },
+    elite: (
+        base_chance: 0.02,
+        chance_per_difficulty: 0.01,
+        max_chance: 0.15,
+        hp_multiplier: 3.0,
+        xp_multiplier: 5.0,
+        size_multiplier: 1.25,
+        swift_speed_multiplier: 1.6,
+        armored_damage_taken: 0.5,
+        split_count: 2,
+        split_hp_fraction: 0.4,
+        split_size_multiplier: 0.7,
+        vampiric_heal_fraction: 0.2,
     ),
 )
 "#;
@@ -626,6 +1225,14 @@ EnemyConfig(
         assert_eq!(config.cull_distance, 2000.0);
         assert_eq!(config.difficulty_max, 10.0);
         assert_eq!(config.spawn_margin, 60.0);
+        assert_eq!(config.separation_strength, 0.5);
+        assert_eq!(config.hit_stun_duration, 0.15);
+        assert_eq!(config.status_tick_interval, 0.5);
+        assert_eq!(config.bat.knockback_resistance, 0.0);
+        assert_eq!(config.boss_death.knockback_resistance, 0.95);
+        assert_eq!(config.ghost.damage_multiplier(DamageType::Physical), 0.5);
+        assert_eq!(config.ghost.damage_multiplier(DamageType::Holy), 1.5);
+        assert_eq!(config.ghost.damage_multiplier(DamageType::Fire), 1.0);
         assert_eq!(config.zombie_unlock_secs, 300.0);
         assert_eq!(config.ghost_unlock_secs, 600.0);
         assert_eq!(config.demon_unlock_secs, 900.0);
@@ -634,51 +1241,103 @@ EnemyConfig(
         assert_eq!(config.mini_boss_interval, 180.0);
         assert_eq!(config.medusa_behavior.keep_min_dist, 150.0);
         assert_eq!(config.medusa_behavior.keep_max_dist, 250.0);
-        assert_eq!(config.medusa_behavior.attack_interval, 2.0);
-        assert_eq!(config.dragon_behavior.attack_interval, 3.0);
-        assert_eq!(config.dragon_behavior.fireball_speed, 200.0);
+        assert_eq!(config.hostile_projectile_pool_size, 256);
+        assert_eq!(
+            config.ranged_attacks[&EnemyType::Medusa].attack_interval,
+            2.0
+        );
+        let dragon = &config.ranged_attacks[&EnemyType::Dragon];
+        assert_eq!(dragon.projectile_speed, 200.0);
+        assert_eq!(dragon.homing, 0.5);
+        assert_eq!(dragon.acceleration, 0.0, "omitted modifiers default to 0");
+        assert!(!config.ranged_attacks.contains_key(&EnemyType::Bat));
+        let giant = &config.mini_boss_archetypes[&MiniBossKind::Giant];
+        assert_eq!(giant.chest_items, 1);
+        assert_eq!(
+            giant.ability,
+            MiniBossAbility::Charge {
+                speed: 420.0,
+                duration: 0.8
+            }
+        );
+        assert_eq!(
+            config.mini_boss_archetypes[&MiniBossKind::Summoner].ability,
+            MiniBossAbility::Summon {
+                enemy: EnemyType::Skeleton,
+                count: 4,
+                radius: 50.0
+            }
+        );
+        assert!(
+            !config
+                .mini_boss_archetypes
+                .contains_key(&MiniBossKind::Mage)
+        );
+        assert_eq!(config.elite.hp_multiplier, 3.0);
+        assert_eq!(config.elite.split_count, 2);
+        assert_eq!(config.elite.armored_damage_taken, 0.5);
         assert_eq!(config.bat.spawn_weight, 1.0);
         assert_eq!(config.dragon.spawn_weight, 0.3);
     }
 
+    #[test]
+    fn elite_chance_rises_with_difficulty_up_to_cap() {
+        let cfg = EliteConfig::default();
+        assert!((cfg.chance_at(1.0) - 0.02).abs() < 1e-6);
+        assert!((cfg.chance_at(5.0) - 0.06).abs() < 1e-6);
+        assert_eq!(cfg.chance_at(100.0), cfg.max_chance);
+    }
+
     #[test]
     fn stats_for_returns_correct_entry() {
         let ron_data = r#"
 EnemyConfig(
-    bat: (base_hp: 10.0, speed: 150.0, damage: 5.0, xp_value: 3, gold_chance: 0.05, collider_radius: 8.0, spawn_weight: 1.0),
-    skeleton: (base_hp: 30.0, speed: 80.0, damage: 8.0, xp_value: 5, gold_chance: 0.08, collider_radius: 12.0, spawn_weight: 1.0),
-    zombie: (base_hp: 60.0, speed: 60.0, damage: 12.0, xp_value: 8, gold_chance: 0.10, collider_radius: 14.0, spawn_weight: 0.8),
-    ghost: (base_hp: 25.0, speed: 100.0, damage: 10.0, xp_value: 6, gold_chance: 0.08, collider_radius: 10.0, spawn_weight: 0.6),
-    demon: (base_hp: 80.0, speed: 130.0, damage: 15.0, xp_value: 10, gold_chance: 0.12, collider_radius: 14.0, spawn_weight: 0.5),
-    medusa: (base_hp: 60.0, speed: 60.0, damage: 12.0, xp_value: 8, gold_chance: 0.10, collider_radius: 12.0, spawn_weight: 0.4),
-    dragon: (base_hp: 150.0, speed: 90.0, damage: 25.0, xp_value: 15, gold_chance: 0.15, collider_radius: 20.0, spawn_weight: 0.3),
-    boss_death: (base_hp: 5000.0, speed: 30.0, damage: 50.0, xp_value: 500, gold_chance: 1.0, collider_radius: 30.0, spawn_weight: 0.0),
-    mini_death: (base_hp: 800.0, speed: 80.0, damage: 30.0, xp_value: 50, gold_chance: 0.5, collider_radius: 20.0, spawn_weight: 0.0),
-    mini_boss: (base_hp: 400.0, speed: 70.0, damage: 20.0, xp_value: 30, gold_chance: 0.0, collider_radius: 22.0, spawn_weight: 0.0),
+    bat: (base_hp: 10.0, speed: 150.0, damage: 5.0, xp_value: 3, gold_chance: 0.05, collider_radius: 8.0, spawn_weight: 1.0, knockback_resistance: 0.0),
+    skeleton: (base_hp: 30.0, speed: 80.0, damage: 8.0, xp_value: 5, gold_chance: 0.08, collider_radius: 12.0, spawn_weight: 1.0, knockback_resistance: 0.1),
+    zombie: (base_hp: 60.0, speed: 60.0, damage: 12.0, xp_value: 8, gold_chance: 0.10, collider_radius: 14.0, spawn_weight: 0.8, knockback_resistance: 0.3),
+    ghost: (base_hp: 25.0, speed: 100.0, damage: 10.0, xp_value: 6, gold_chance: 0.08, collider_radius: 10.0, spawn_weight: 0.6, knockback_resistance: 0.0),
+    demon: (base_hp: 80.0, speed: 130.0, damage: 15.0, xp_value: 10, gold_chance: 0.12, collider_radius: 14.0, spawn_weight: 0.5, knockback_resistance: 0.2),
+    medusa: (base_hp: 60.0, speed: 60.0, damage: 12.0, xp_value: 8, gold_chance: 0.10, collider_radius: 12.0, spawn_weight: 0.4, knockback_resistance: 0.2),
+    dragon: (base_hp: 150.0, speed: 90.0, damage: 25.0, xp_value: 15, gold_chance: 0.15, collider_radius: 20.0, spawn_weight: 0.3, knockback_resistance: 0.5),
+    boss_death: (base_hp: 5000.0, speed: 30.0, damage: 50.0, xp_value: 500, gold_chance: 1.0, collider_radius: 30.0, spawn_weight: 0.0, knockback_resistance: 0.95),
+    mini_death: (base_hp: 800.0, speed: 80.0, damage: 30.0, xp_value: 50, gold_chance: 0.5, collider_radius: 20.0, spawn_weight: 0.0, knockback_resistance: 0.9),
+    mini_boss: (base_hp: 400.0, speed: 70.0, damage: 20.0, xp_value: 30, gold_chance: 0.0, collider_radius: 22.0, spawn_weight: 0.0, knockback_resistance: 0.9),
     spawn_base_interval: 0.5,
     max_count: 500,
     cull_distance: 2000.0,
     difficulty_max: 10.0,
     spawn_margin: 60.0,
+    separation_strength: 0.5,
+    hit_stun_duration: 0.15,
+    status_tick_interval: 0.5,
     zombie_unlock_secs: 300.0,
     ghost_unlock_secs: 600.0,
     demon_unlock_secs: 900.0,
     medusa_unlock_secs: 1200.0,
     dragon_unlock_secs: 1500.0,
     mini_boss_interval: 180.0,
+    hostile_projectile_pool_size: 256,
     medusa_behavior: (
         keep_min_dist: 150.0,
         keep_max_dist: 250.0,
-        attack_interval: 2.0,
-        projectile_speed: 180.0,
-        projectile_lifetime: 5.0,
-        projectile_radius: 5.0,
     ),
-    dragon_behavior: (
-        attack_interval: 3.0,
-        fireball_speed: 200.0,
-        fireball_lifetime: 6.0,
-        fireball_radius: 7.0,
+    ranged_attacks: {
+        Medusa: (attack_interval: 2.0, projectile_speed: 180.0, projectile_lifetime: 5.0, projectile_radius: 5.0, color: (0.9, 0.75, 0.0)),
+        Dragon: (attack_interval: 3.0, projectile_speed: 200.0, projectile_lifetime: 6.0, projectile_radius: 7.0, color: (1.0, 0.4, 0.0), homing: 0.5, spin: 3.0),
+    },
+    elite: (
+        base_chance: 0.02,
+        chance_per_difficulty: 0.01,
+        max_chance: 0.15,
+        hp_multiplier: 3.0,
+        xp_multiplier: 5.0,
+        size_multiplier: 1.25,
+        swift_speed_multiplier: 1.6,
+        armored_damage_taken: 0.5,
+        split_count: 2,
+        split_hp_fraction: 0.4,
+        split_size_multiplier: 0.7,
+        vampiric_heal_fraction: 0.2,
     ),
 )
 "#;
@@ -696,4 +1355,104 @@ EnemyConfig(
         assert_eq!(config.stats_for(EnemyType::MiniBoss).base_hp, 400.0);
         assert_eq!(config.stats_for(EnemyType::MiniBoss).gold_chance, 0.0);
     }
+
+    #[test]
+    fn enemy_ron_damage_multipliers_match_built_in_table() {
+        let partial: EnemyConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(include_str!(
+                "../../../vampire-survivors/assets/config/enemy.ron"
+            ))
+            .unwrap();
+        let config = EnemyConfig::from(partial);
+
+        let enemy_types = [
+            EnemyType::Bat,
+            EnemyType::Skeleton,
+            EnemyType::MiniBoss,
+            EnemyType::Zombie,
+            EnemyType::Ghost,
+            EnemyType::Demon,
+            EnemyType::Medusa,
+            EnemyType::Dragon,
+            EnemyType::BossDeath,
+            EnemyType::MiniDeath,
+        ];
+        let damage_types = [
+            DamageType::Physical,
+            DamageType::Magic,
+            DamageType::Holy,
+            DamageType::Lightning,
+            DamageType::Fire,
+        ];
+        for enemy_type in enemy_types {
+            for damage_type in damage_types {
+                assert_eq!(
+                    config.stats_for(enemy_type).damage_multiplier(damage_type),
+                    default_damage_multiplier(enemy_type, damage_type),
+                    "{enemy_type:?} vs {damage_type:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn enemy_ron_ranged_attacks_match_built_in_table() {
+        let partial: EnemyConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(include_str!(
+                "../../../vampire-survivors/assets/config/enemy.ron"
+            ))
+            .unwrap();
+        let config = EnemyConfig::from(partial);
+
+        assert_eq!(config.ranged_attacks, default_ranged_attacks());
+    }
+
+    #[test]
+    fn enemy_ron_mini_boss_archetypes_match_built_in_table() {
+        let partial: EnemyConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(include_str!(
+                "../../../vampire-survivors/assets/config/enemy.ron"
+            ))
+            .unwrap();
+        let config = EnemyConfig::from(partial);
+
+        assert_eq!(config.mini_boss_archetypes, default_mini_boss_archetypes());
+    }
+
+    fn issue_fields(config: &EnemyConfig) -> Vec<String> {
+        let mut v = ConfigValidator::new("config/enemy.ron");
+        config.validate(&mut v);
+        v.into_issues().into_iter().map(|i| i.field).collect()
+    }
+
+    #[test]
+    fn built_in_defaults_are_valid() {
+        let config = EnemyConfig::from(EnemyConfigPartial::default());
+        assert_eq!(issue_fields(&config), Vec::<String>::new());
+    }
+
+    /// Medusa's keep-away band must be non-empty, and per-enemy chances
+    /// stay within 0–1.
+    #[test]
+    fn invalid_enemy_values_are_reported() {
+        let mut config = EnemyConfig::from(EnemyConfigPartial::default());
+        config.medusa_behavior.keep_min_dist = config.medusa_behavior.keep_max_dist;
+        config.bat.gold_chance = 1.5;
+        config
+            .ghost
+            .damage_multipliers
+            .insert(DamageType::Fire, -1.0);
+
+        assert_eq!(
+            issue_fields(&config),
+            [
+                "bat.gold_chance",
+                "ghost.damage_multipliers[Fire]",
+                "medusa_behavior.keep_min_dist",
+            ]
+        );
+    }
 }
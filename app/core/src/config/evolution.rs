@@ -0,0 +1,268 @@
+//! Weapon evolution table loaded from `assets/config/evolution.ron`.
+//!
+//! Each row maps a base weapon and the passive item it requires to the
+//! evolved weapon it becomes.  Adding a new evolution only needs a new row
+//! here (plus the evolved weapon's behaviour) — the lookup functions in
+//! [`systems::xp::evolution`](crate::systems::xp::evolution) read the table
+//! instead of matching on weapon types.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::config::validate::{ConfigValidator, ValidateConfig};
+use crate::types::{PassiveItemType, WeaponType};
+
+// ---------------------------------------------------------------------------
+// Fallback constants (used while evolution.ron is still loading)
+// ---------------------------------------------------------------------------
+
+/// Built-in evolution table: `base weapon + passive → evolved weapon`.
+pub const DEFAULT_EVOLUTION_TABLE: [EvolutionEntry; 9] = [
+    EvolutionEntry::new(
+        WeaponType::Whip,
+        PassiveItemType::HollowHeart,
+        WeaponType::BloodyTear,
+    ),
+    EvolutionEntry::new(
+        WeaponType::MagicWand,
+        PassiveItemType::EmptyTome,
+        WeaponType::HolyWand,
+    ),
+    EvolutionEntry::new(
+        WeaponType::Knife,
+        PassiveItemType::Bracer,
+        WeaponType::ThousandEdge,
+    ),
+    EvolutionEntry::new(
+        WeaponType::Garlic,
+        PassiveItemType::Pummarola,
+        WeaponType::SoulEater,
+    ),
+    EvolutionEntry::new(
+        WeaponType::Bible,
+        PassiveItemType::Spellbinder,
+        WeaponType::UnholyVespers,
+    ),
+    EvolutionEntry::new(
+        WeaponType::ThunderRing,
+        PassiveItemType::Duplicator,
+        WeaponType::LightningRing,
+    ),
+    EvolutionEntry::new(
+        WeaponType::Cross,
+        PassiveItemType::Clover,
+        WeaponType::HeavenSword,
+    ),
+    EvolutionEntry::new(
+        WeaponType::FireWand,
+        PassiveItemType::Spinach,
+        WeaponType::Hellfire,
+    ),
+    EvolutionEntry::new(
+        WeaponType::Runetracer,
+        PassiveItemType::Wings,
+        WeaponType::NoFuture,
+    ),
+];
+
+// ---------------------------------------------------------------------------
+// Asset type
+// ---------------------------------------------------------------------------
+
+/// One row of [`EvolutionConfig::evolutions`].
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EvolutionEntry {
+    /// Base weapon that must be at max level.
+    pub weapon: WeaponType,
+    /// Passive item the player must own (any level).
+    pub passive: PassiveItemType,
+    /// Weapon the base weapon is replaced with.
+    pub evolved: WeaponType,
+}
+
+impl EvolutionEntry {
+    pub const fn new(weapon: WeaponType, passive: PassiveItemType, evolved: WeaponType) -> Self {
+        Self {
+            weapon,
+            passive,
+            evolved,
+        }
+    }
+}
+
+/// Deserialization mirror of [`EvolutionConfig`] — every field is `Option<T>`
+/// so RON files with missing fields still load and emit a `warn!` instead of
+/// failing.
+#[derive(Deserialize, Default)]
+#[serde(default, rename = "EvolutionConfig")]
+pub(super) struct EvolutionConfigPartial {
+    pub evolutions: Option<Vec<EvolutionEntry>>,
+}
+
+/// Weapon evolution recipes.
+///
+/// Loaded from `assets/config/evolution.ron` and hot-reloaded while the game
+/// is running; the next treasure chest uses the new table.
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct EvolutionConfig {
+    /// Evolution recipes, checked in order.
+    pub evolutions: Vec<EvolutionEntry>,
+}
+
+impl From<EvolutionConfigPartial> for EvolutionConfig {
+    fn from(p: EvolutionConfigPartial) -> Self {
+        EvolutionConfig {
+            evolutions: p.evolutions.unwrap_or_else(|| {
+                warn!("evolution.ron: `evolutions` missing → using built-in table");
+                DEFAULT_EVOLUTION_TABLE.to_vec()
+            }),
+        }
+    }
+}
+
+/// Resource holding the handle to the loaded evolution configuration.
+#[derive(Resource)]
+pub struct EvolutionConfigHandle(pub Handle<EvolutionConfig>);
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+impl ValidateConfig for EvolutionConfig {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.scope("evolutions", |v| {
+            for (i, entry) in self.evolutions.iter().enumerate() {
+                if entry.weapon == entry.evolved {
+                    v.error(&format!("[{i}].evolved"), "must differ from `weapon`");
+                }
+                // Recipes are checked in order, so a later row for the same
+                // base weapon would never fire.
+                if self.evolutions[..i]
+                    .iter()
+                    .any(|e| e.weapon == entry.weapon)
+                {
+                    v.error(
+                        &format!("[{i}].weapon"),
+                        format!("{:?} already has an earlier recipe", entry.weapon),
+                    );
+                }
+            }
+        });
+    }
+}
+
+// ---------------------------------------------------------------------------
+// SystemParam bundle
+// ---------------------------------------------------------------------------
+
+/// SystemParam bundle for accessing [`EvolutionConfig`].
+///
+/// Falls back to [`DEFAULT_EVOLUTION_TABLE`] while the asset is still loading
+/// or when [`GameConfigPlugin`](crate::config::GameConfigPlugin) has not been
+/// registered (e.g. in unit tests).
+#[derive(SystemParam)]
+pub struct EvolutionParams<'w> {
+    handle: Option<Res<'w, EvolutionConfigHandle>>,
+    assets: Option<Res<'w, Assets<EvolutionConfig>>>,
+}
+
+impl<'w> EvolutionParams<'w> {
+    /// Returns the currently loaded [`EvolutionConfig`], or `None`.
+    pub fn get(&self) -> Option<&EvolutionConfig> {
+        self.handle
+            .as_ref()
+            .and_then(|h| self.assets.as_ref().and_then(|a| a.get(&h.0)))
+    }
+
+    /// Evolution recipes in table order.
+    pub fn table(&self) -> &[EvolutionEntry] {
+        match self.get() {
+            Some(c) => &c.evolutions,
+            None => &DEFAULT_EVOLUTION_TABLE,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Hot-reload system
+// ---------------------------------------------------------------------------
+
+/// Handles hot-reloading of the evolution table.
+pub fn hot_reload_evolution_config(mut events: MessageReader<AssetEvent<EvolutionConfig>>) {
+    for event in events.read() {
+        match event {
+            AssetEvent::Added { id: _ } => {
+                info!("✅ Evolution config loaded");
+            }
+            AssetEvent::Modified { id: _ } => {
+                info!("🔥 Hot-reloading evolution config!");
+            }
+            AssetEvent::Removed { id: _ } => {
+                warn!("⚠️ Evolution config removed");
+            }
+            _ => {}
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(ron_data: &str) -> EvolutionConfig {
+        let partial: EvolutionConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(ron_data)
+            .expect("RON parse must succeed");
+        EvolutionConfig::from(partial)
+    }
+
+    #[test]
+    fn evolution_config_deserialization() {
+        let cfg = parse(
+            r#"
+EvolutionConfig(
+    evolutions: [
+        (weapon: Cross,    passive: Clover,  evolved: HeavenSword),
+        (weapon: FireWand, passive: Spinach, evolved: Hellfire),
+    ],
+)
+"#,
+        );
+        assert_eq!(
+            cfg.evolutions,
+            vec![
+                EvolutionEntry::new(
+                    WeaponType::Cross,
+                    PassiveItemType::Clover,
+                    WeaponType::HeavenSword
+                ),
+                EvolutionEntry::new(
+                    WeaponType::FireWand,
+                    PassiveItemType::Spinach,
+                    WeaponType::Hellfire
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_table_falls_back_to_built_in() {
+        let cfg = parse("EvolutionConfig()");
+        assert_eq!(cfg.evolutions, DEFAULT_EVOLUTION_TABLE.to_vec());
+    }
+
+    /// The shipped `evolution.ron` must match the built-in fallback table.
+    #[test]
+    fn evolution_ron_matches_built_in_table() {
+        let cfg = parse(include_str!(
+            "../../../vampire-survivors/assets/config/evolution.ron"
+        ));
+        assert_eq!(cfg.evolutions, DEFAULT_EVOLUTION_TABLE.to_vec());
+    }
+}
@@ -4,7 +4,8 @@ use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use serde::Deserialize;
 
-use crate::types::MetaUpgradeType;
+use crate::config::validate::{ConfigValidator, LEVEL_TABLE_LEN, ValidateConfig};
+use crate::types::{CoinTier, MetaUpgradeType};
 
 // ---------------------------------------------------------------------------
 // Fallback constants (used while game.ron is still loading)
@@ -36,6 +37,13 @@ const DEFAULT_TREASURE_HP_RECOVERY_PCT: f32 = 0.3;
 const DEFAULT_TREASURE_GLOW_DISTANCE: f32 = 150.0;
 const DEFAULT_TREASURE_SPAWN_FLASH_DURATION: f32 = 0.35;
 
+// --- gold coin drops ---
+const DEFAULT_GOLD_COIN_VALUE: u32 = 1;
+const DEFAULT_GOLD_BAG_VALUE: u32 = 10;
+const DEFAULT_GOLD_RICH_BAG_VALUE: u32 = 100;
+const DEFAULT_GOLD_BAG_CHANCE: f32 = 0.1;
+const DEFAULT_GOLD_RICH_BAG_CHANCE: f32 = 0.02;
+
 // --- boss and game timing ---
 const DEFAULT_BOSS_SPAWN_TIME: f32 = 1800.0;
 const DEFAULT_TREASURE_SPAWN_INTERVAL: f32 = 180.0;
@@ -100,6 +108,11 @@ pub(super) struct GameConfigPartial {
     pub treasure_hp_recovery_pct: Option<f32>,
     pub treasure_glow_distance: Option<f32>,
     pub treasure_spawn_flash_duration: Option<f32>,
+    pub gold_coin_value: Option<u32>,
+    pub gold_bag_value: Option<u32>,
+    pub gold_rich_bag_value: Option<u32>,
+    pub gold_bag_chance: Option<f32>,
+    pub gold_rich_bag_chance: Option<f32>,
     pub xp_level_base: Option<u32>,
     pub xp_level_multiplier: Option<f32>,
     pub level_up_choice_count: Option<usize>,
@@ -181,6 +194,17 @@ pub struct GameConfig {
     pub treasure_glow_distance: f32,
     /// Duration in seconds of the white-flash-to-yellow spawn animation.
     pub treasure_spawn_flash_duration: f32,
+    // Gold coin drops
+    /// Gold credited by a plain [`CoinTier::Coin`].
+    pub gold_coin_value: u32,
+    /// Gold credited by a [`CoinTier::Bag`].
+    pub gold_bag_value: u32,
+    /// Gold credited by a [`CoinTier::RichBag`].
+    pub gold_rich_bag_value: u32,
+    /// Chance (0.0–1.0) that a dropped coin is upgraded to a bag.
+    pub gold_bag_chance: f32,
+    /// Chance (0.0–1.0) that a dropped coin is upgraded to a rich bag.
+    pub gold_rich_bag_chance: f32,
     // XP / levelling
     pub xp_level_base: u32,
     pub xp_level_multiplier: f32,
@@ -326,6 +350,36 @@ impl From<GameConfigPartial> for GameConfig {
                 );
                 DEFAULT_TREASURE_SPAWN_FLASH_DURATION
             }),
+            gold_coin_value: p.gold_coin_value.unwrap_or_else(|| {
+                warn!(
+                    "game.ron: `gold_coin_value` missing → using default {DEFAULT_GOLD_COIN_VALUE}"
+                );
+                DEFAULT_GOLD_COIN_VALUE
+            }),
+            gold_bag_value: p.gold_bag_value.unwrap_or_else(|| {
+                warn!(
+                    "game.ron: `gold_bag_value` missing → using default {DEFAULT_GOLD_BAG_VALUE}"
+                );
+                DEFAULT_GOLD_BAG_VALUE
+            }),
+            gold_rich_bag_value: p.gold_rich_bag_value.unwrap_or_else(|| {
+                warn!(
+                    "game.ron: `gold_rich_bag_value` missing → using default {DEFAULT_GOLD_RICH_BAG_VALUE}"
+                );
+                DEFAULT_GOLD_RICH_BAG_VALUE
+            }),
+            gold_bag_chance: p.gold_bag_chance.unwrap_or_else(|| {
+                warn!(
+                    "game.ron: `gold_bag_chance` missing → using default {DEFAULT_GOLD_BAG_CHANCE}"
+                );
+                DEFAULT_GOLD_BAG_CHANCE
+            }),
+            gold_rich_bag_chance: p.gold_rich_bag_chance.unwrap_or_else(|| {
+                warn!(
+                    "game.ron: `gold_rich_bag_chance` missing → using default {DEFAULT_GOLD_RICH_BAG_CHANCE}"
+                );
+                DEFAULT_GOLD_RICH_BAG_CHANCE
+            }),
             xp_level_base: p.xp_level_base.unwrap_or_else(|| {
                 warn!(
                     "game.ron: `xp_level_base` missing → using default {DEFAULT_XP_LEVEL_BASE}"
@@ -537,6 +591,63 @@ impl GameConfig {
 #[derive(Resource)]
 pub struct GameConfigHandle(pub Handle<GameConfig>);
 
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+impl ValidateConfig for GameConfig {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.non_zero("window_width", self.window_width.into());
+        v.non_zero("window_height", self.window_height.into());
+        v.non_zero("max_weapons", self.max_weapons as u64);
+        v.non_zero("max_passives", self.max_passives as u64);
+        if !(1..=LEVEL_TABLE_LEN).contains(&usize::from(self.max_weapon_level)) {
+            v.error(
+                "max_weapon_level",
+                format!(
+                    "must be within 1–{LEVEL_TABLE_LEN} (weapon level tables), got {}",
+                    self.max_weapon_level
+                ),
+            );
+        }
+        v.non_zero("max_passive_level", self.max_passive_level.into());
+        v.non_negative("boss_spawn_time", self.boss_spawn_time);
+        v.positive("treasure_spawn_interval", self.treasure_spawn_interval);
+        v.positive("treasure_radius", self.treasure_radius);
+        v.fraction("treasure_hp_recovery_pct", self.treasure_hp_recovery_pct);
+        v.fraction("gold_bag_chance", self.gold_bag_chance);
+        v.fraction("gold_rich_bag_chance", self.gold_rich_bag_chance);
+        v.non_zero("xp_level_base", self.xp_level_base.into());
+        v.positive("xp_level_multiplier", self.xp_level_multiplier);
+        v.non_zero("level_up_choice_count", self.level_up_choice_count as u64);
+        v.non_negative("camera_lerp_speed", self.camera_lerp_speed);
+        v.positive("spatial_grid_cell_size", self.spatial_grid_cell_size);
+        v.positive("base_projectile_speed", self.base_projectile_speed);
+        v.positive("base_projectile_lifetime", self.base_projectile_lifetime);
+        v.fraction("boss_phase2_hp_threshold", self.boss_phase2_hp_threshold);
+        v.fraction("boss_phase3_hp_threshold", self.boss_phase3_hp_threshold);
+        v.less_than(
+            "boss_phase3_hp_threshold",
+            self.boss_phase3_hp_threshold,
+            "boss_phase2_hp_threshold",
+            self.boss_phase2_hp_threshold,
+        );
+        v.positive(
+            "boss_phase2_speed_multiplier",
+            self.boss_phase2_speed_multiplier,
+        );
+        v.positive(
+            "boss_phase3_speed_multiplier",
+            self.boss_phase3_speed_multiplier,
+        );
+        v.positive("boss_scythe_interval", self.boss_scythe_interval);
+        v.non_negative("boss_scythe_speed", self.boss_scythe_speed);
+        v.positive("boss_scythe_lifetime", self.boss_scythe_lifetime);
+        v.non_negative("boss_scythe_damage", self.boss_scythe_damage);
+        v.positive("boss_scythe_radius", self.boss_scythe_radius);
+    }
+}
+
 // ---------------------------------------------------------------------------
 // SystemParam bundle
 // ---------------------------------------------------------------------------
@@ -582,6 +693,18 @@ impl<'w> GameParams<'w> {
             .unwrap_or_else(|| default_upgrade_stat_bonus(upgrade))
     }
 
+    // --- Viewport ---
+
+    /// Viewport size in pixels (`window_width` × `window_height`).
+    pub fn window_size(&self) -> Vec2 {
+        self.get()
+            .map(|c| Vec2::new(c.window_width as f32, c.window_height as f32))
+            .unwrap_or(Vec2::new(
+                DEFAULT_WINDOW_WIDTH as f32,
+                DEFAULT_WINDOW_HEIGHT as f32,
+            ))
+    }
+
     // --- Inventory caps ---
 
     pub fn max_weapon_level(&self) -> u8 {
@@ -666,6 +789,36 @@ impl<'w> GameParams<'w> {
             .unwrap_or(DEFAULT_TREASURE_SPAWN_FLASH_DURATION)
     }
 
+    // --- Gold coin drops ---
+
+    /// Returns the gold credited by a coin of the given tier.
+    pub fn gold_value(&self, tier: CoinTier) -> u32 {
+        match self.get() {
+            Some(c) => match tier {
+                CoinTier::Coin => c.gold_coin_value,
+                CoinTier::Bag => c.gold_bag_value,
+                CoinTier::RichBag => c.gold_rich_bag_value,
+            },
+            None => match tier {
+                CoinTier::Coin => DEFAULT_GOLD_COIN_VALUE,
+                CoinTier::Bag => DEFAULT_GOLD_BAG_VALUE,
+                CoinTier::RichBag => DEFAULT_GOLD_RICH_BAG_VALUE,
+            },
+        }
+    }
+
+    pub fn gold_bag_chance(&self) -> f32 {
+        self.get()
+            .map(|c| c.gold_bag_chance)
+            .unwrap_or(DEFAULT_GOLD_BAG_CHANCE)
+    }
+
+    pub fn gold_rich_bag_chance(&self) -> f32 {
+        self.get()
+            .map(|c| c.gold_rich_bag_chance)
+            .unwrap_or(DEFAULT_GOLD_RICH_BAG_CHANCE)
+    }
+
     pub fn spatial_grid_cell_size(&self) -> f32 {
         self.get()
             .map(|c| c.spatial_grid_cell_size)
@@ -741,6 +894,11 @@ GameConfig(
     treasure_hp_recovery_pct: 0.3,
     treasure_glow_distance: 150.0,
     treasure_spawn_flash_duration: 0.35,
+    gold_coin_value: 1,
+    gold_bag_value: 10,
+    gold_rich_bag_value: 100,
+    gold_bag_chance: 0.1,
+    gold_rich_bag_chance: 0.02,
     xp_level_base: 20,
     xp_level_multiplier: 1.2,
     level_up_choice_count: 3,
@@ -790,6 +948,11 @@ GameConfig(
         assert_eq!(config.treasure_hp_recovery_pct, 0.3);
         assert_eq!(config.treasure_glow_distance, 150.0);
         assert_eq!(config.treasure_spawn_flash_duration, 0.35);
+        assert_eq!(config.gold_coin_value, 1);
+        assert_eq!(config.gold_bag_value, 10);
+        assert_eq!(config.gold_rich_bag_value, 100);
+        assert_eq!(config.gold_bag_chance, 0.1);
+        assert_eq!(config.gold_rich_bag_chance, 0.02);
         assert_eq!(config.xp_level_base, 20);
         assert_eq!(config.level_up_choice_count, 3);
         assert_eq!(config.spatial_grid_cell_size, 64.0);
@@ -823,4 +986,19 @@ GameConfig(
         );
         assert_eq!(config.meta_upgrade_xp_bonus, DEFAULT_META_UPGRADE_XP_BONUS);
     }
+
+    #[test]
+    fn boss_phase_thresholds_must_fall() {
+        let mut config = GameConfig::from(GameConfigPartial::default());
+        let mut v = ConfigValidator::new("config/game.ron");
+        config.validate(&mut v);
+        assert!(v.into_issues().is_empty(), "built-in defaults are valid");
+
+        config.boss_phase3_hp_threshold = config.boss_phase2_hp_threshold;
+        config.max_weapon_level = 9;
+        let mut v = ConfigValidator::new("config/game.ron");
+        config.validate(&mut v);
+        let fields: Vec<String> = v.into_issues().into_iter().map(|i| i.field).collect();
+        assert_eq!(fields, ["max_weapon_level", "boss_phase3_hp_threshold"]);
+    }
 }
@@ -4,7 +4,8 @@
 //! from RON (Rusty Object Notation) files in the assets directory.
 //!
 //! Supports hot-reloading: edit config files while the game is running
-//! and changes will be applied automatically.
+//! and changes will be applied automatically.  Every file is checked by
+//! [`validate`] as it loads; a file that fails keeps its previous values.
 //!
 //! # Sub-modules
 //!
@@ -16,29 +17,9 @@
 //! | [`weapon`]    | Per-weapon configs (`WhipConfig`, `MagicWandConfig`, `KnifeConfig`, …) |
 //! | [`passive`]   | `PassiveConfig` + `PassiveParams` SystemParam bundle |
 //! | [`character`] | `CharacterConfig`, `CharacterBaseStats` + `CharacterParams` SystemParam bundle |
-
-pub mod character;
-pub mod enemy;
-pub mod game;
-pub mod passive;
-pub mod player;
-pub mod stage;
-pub mod weapon;
-
-pub use character::*;
-pub use enemy::*;
-pub use game::*;
-pub use passive::*;
-pub use player::*;
-pub use stage::*;
-pub use weapon::*;
-
-use bevy::asset::io::Reader;
-use bevy::asset::{AssetLoader, LoadContext};
-use bevy::ecs::system::SystemParam;
-use bevy::prelude::*;
-
-use crate::states::AppState;
+//! | [`pickup`]    | `PickupConfig`, `PickupDropEntry` + `PickupParams` SystemParam bundle |
+//! | [`evolution`] | `EvolutionConfig`, `EvolutionEntry` + `EvolutionParams` SystemParam bundle |
+//! | [`validate`]  | `ValidateConfig` checks, `ConfigLoadError`, headless `validate_config_dir` |
 
 // ---------------------------------------------------------------------------
 // RON asset loader macro
@@ -46,47 +27,88 @@ use crate::states::AppState;
 
 /// Generates a RON-based `AssetLoader` implementation for a config type.
 ///
-/// All game config assets use identical loading logic (read bytes → `ron::de::from_bytes`),
-/// so this macro eliminates the repetition while keeping each loader a distinct type.
+/// All game config assets use identical loading logic (read bytes → parse →
+/// [`validate::parse_config`]), so this macro eliminates the repetition while
+/// keeping each loader a distinct type.  `$asset` must implement
+/// [`ValidateConfig`]; a file that fails to parse or validate fails to load
+/// with a [`ConfigLoadError`].
+///
+/// Declared before the sub-modules so weapon config modules can define their
+/// own loaders; an optional visibility makes the loader reachable from the
+/// weapon plugin that registers it.
 ///
 /// # Usage (two-step form: deserialize as `$partial`, then convert to `$asset` via `From`)
 /// ```ignore
 /// ron_asset_loader!(MyConfigLoader, MyConfigPartial => MyConfig);
+/// ron_asset_loader!(pub(crate) MyWeaponConfigLoader, MyWeaponConfigPartial => MyWeaponConfig);
 /// ```
 macro_rules! ron_asset_loader {
     // Two-step form: deserialize as $partial, then convert to $asset via From
-    ($loader:ident, $partial:ty => $asset:ty) => {
+    ($vis:vis $loader:ident, $partial:ty => $asset:ty) => {
         #[derive(Default)]
-        struct $loader;
+        $vis struct $loader;
 
-        impl AssetLoader for $loader {
+        impl bevy::asset::AssetLoader for $loader {
             type Asset = $asset;
             type Settings = ();
-            type Error = std::io::Error;
+            type Error = $crate::config::validate::ConfigLoadError;
 
             async fn load(
                 &self,
-                reader: &mut dyn Reader,
+                reader: &mut dyn bevy::asset::io::Reader,
                 _settings: &Self::Settings,
-                _load_context: &mut LoadContext<'_>,
+                load_context: &mut bevy::asset::LoadContext<'_>,
             ) -> Result<Self::Asset, Self::Error> {
                 let mut bytes = Vec::new();
                 reader.read_to_end(&mut bytes).await?;
-                let options = ron::Options::default()
-                    .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME);
-                let partial: $partial = options
-                    .from_bytes(&bytes)
-                    .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
-                Ok(<$asset>::from(partial))
+                let file = load_context.path().display().to_string();
+                $crate::config::validate::parse_config::<$partial, $asset>(&file, &bytes)
             }
 
             fn extensions(&self) -> &[&str] {
                 &["ron"]
             }
         }
+
+        impl $crate::config::validate::CheckConfigFile for $loader {
+            fn check(
+                file: &str,
+                bytes: &[u8],
+            ) -> Result<(), $crate::config::validate::ConfigLoadError> {
+                $crate::config::validate::parse_config::<$partial, $asset>(file, bytes).map(drop)
+            }
+        }
     };
 }
 
+pub mod character;
+pub mod enemy;
+pub mod evolution;
+pub mod game;
+pub mod passive;
+pub mod pickup;
+pub mod player;
+pub mod stage;
+pub mod validate;
+pub mod weapon;
+
+pub use character::*;
+pub use enemy::*;
+pub use evolution::*;
+pub use game::*;
+pub use passive::*;
+pub use pickup::*;
+pub use player::*;
+pub use stage::*;
+pub use validate::{ConfigIssue, ConfigLoadError, ConfigLoadErrors, ValidateConfig};
+pub use weapon::*;
+
+use bevy::asset::{AssetLoader, LoadState, UntypedAssetId};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::states::AppState;
+
 // ---------------------------------------------------------------------------
 // Font asset paths and preload resource
 // ---------------------------------------------------------------------------
@@ -114,16 +136,62 @@ ron_asset_loader!(GameConfigLoader, GameConfigPartial => GameConfig);
 ron_asset_loader!(PassiveConfigLoader, PassiveConfigPartial => PassiveConfig);
 ron_asset_loader!(CharacterConfigLoader, CharacterConfigPartial => CharacterConfig);
 ron_asset_loader!(StageConfigLoader, StageConfigPartial => StageConfig);
+ron_asset_loader!(PickupConfigLoader, PickupConfigPartial => PickupConfig);
+ron_asset_loader!(EvolutionConfigLoader, EvolutionConfigPartial => EvolutionConfig);
 
-// Per-weapon config loaders
-ron_asset_loader!(WhipConfigLoader, WhipConfigPartial => WhipConfig);
-ron_asset_loader!(MagicWandConfigLoader, MagicWandConfigPartial => MagicWandConfig);
-ron_asset_loader!(KnifeConfigLoader, KnifeConfigPartial => KnifeConfig);
-ron_asset_loader!(GarlicConfigLoader, GarlicConfigPartial => GarlicConfig);
-ron_asset_loader!(BibleConfigLoader, BibleConfigPartial => BibleConfig);
-ron_asset_loader!(ThunderRingConfigLoader, ThunderRingConfigPartial => ThunderRingConfig);
-ron_asset_loader!(CrossConfigLoader, CrossConfigPartial => CrossConfig);
-ron_asset_loader!(FireWandConfigLoader, FireWandConfigPartial => FireWandConfig);
+// ---------------------------------------------------------------------------
+// Plugin-registered configs
+// ---------------------------------------------------------------------------
+
+/// Configs loaded by other plugins through [`register_weapon_config`];
+/// [`wait_for_configs`] waits for every entry before leaving `Loading`.
+#[derive(Resource, Default)]
+struct RegisteredConfigs(Vec<UntypedAssetId>);
+
+/// Registers a weapon's RON config asset and loader, starts loading `path`,
+/// and inserts the handle resource built by `handle`.
+///
+/// Called from each weapon plugin so the weapon owns its config.  The asset
+/// joins the set [`wait_for_configs`] waits for, keeping the Loading screen
+/// up until it is ready, and gets a hot-reload logger.  Weapon systems read
+/// the config every frame, so edits apply immediately; weapons with
+/// long-lived entities re-derive them in their own hot-reload systems.
+///
+/// Without an [`AssetServer`] (headless unit tests) nothing is registered
+/// and the weapon falls back to its `DEFAULT_*` values.  The headless
+/// config validator checks the file through the spec's `config_check`.
+pub(crate) fn register_weapon_config<A, H, L>(
+    app: &mut App,
+    path: &'static str,
+    loader: L,
+    handle: impl FnOnce(Handle<A>) -> H,
+) where
+    A: Asset,
+    H: Resource,
+    L: AssetLoader<Asset = A>,
+{
+    if !app.world().contains_resource::<AssetServer>() {
+        return;
+    }
+    app.init_asset::<A>().register_asset_loader(loader);
+    let asset: Handle<A> = app.world().resource::<AssetServer>().load(path);
+    app.world_mut()
+        .get_resource_or_init::<RegisteredConfigs>()
+        .0
+        .push(asset.id().untyped());
+    app.insert_resource(handle(asset));
+    // Runs in all states so live-editing always works.
+    app.add_systems(Update, move |mut events: MessageReader<AssetEvent<A>>| {
+        for event in events.read() {
+            match event {
+                AssetEvent::Added { id: _ } => info!("✅ {path} loaded"),
+                AssetEvent::Modified { id: _ } => info!("🔥 Hot-reloading {path}!"),
+                AssetEvent::Removed { id: _ } => warn!("⚠️ {path} removed"),
+                _ => {}
+            }
+        }
+    });
+}
 
 // ---------------------------------------------------------------------------
 // AllConfigs — private SystemParam for wait_for_configs
@@ -141,28 +209,39 @@ struct AllConfigs<'w> {
     game_assets: Res<'w, Assets<GameConfig>>,
     passive_handle: Res<'w, PassiveConfigHandle>,
     passive_assets: Res<'w, Assets<PassiveConfig>>,
-    whip_handle: Res<'w, WhipConfigHandle>,
-    whip_assets: Res<'w, Assets<WhipConfig>>,
-    magic_wand_handle: Res<'w, MagicWandConfigHandle>,
-    magic_wand_assets: Res<'w, Assets<MagicWandConfig>>,
-    knife_handle: Res<'w, KnifeConfigHandle>,
-    knife_assets: Res<'w, Assets<KnifeConfig>>,
-    garlic_handle: Res<'w, GarlicConfigHandle>,
-    garlic_assets: Res<'w, Assets<GarlicConfig>>,
-    bible_handle: Res<'w, BibleConfigHandle>,
-    bible_assets: Res<'w, Assets<BibleConfig>>,
-    thunder_ring_handle: Res<'w, ThunderRingConfigHandle>,
-    thunder_ring_assets: Res<'w, Assets<ThunderRingConfig>>,
-    cross_handle: Res<'w, CrossConfigHandle>,
-    cross_assets: Res<'w, Assets<CrossConfig>>,
-    fire_wand_handle: Res<'w, FireWandConfigHandle>,
-    fire_wand_assets: Res<'w, Assets<FireWandConfig>>,
     character_handle: Res<'w, CharacterConfigHandle>,
     character_assets: Res<'w, Assets<CharacterConfig>>,
     stage_handle: Res<'w, StageConfigHandle>,
     stage_assets: Res<'w, Assets<StageConfig>>,
+    pickup_handle: Res<'w, PickupConfigHandle>,
+    pickup_assets: Res<'w, Assets<PickupConfig>>,
+    evolution_handle: Res<'w, EvolutionConfigHandle>,
+    evolution_assets: Res<'w, Assets<EvolutionConfig>>,
     font_handles: Res<'w, FontLoadHandles>,
     font_assets: Res<'w, Assets<Font>>,
+    registered: Option<Res<'w, RegisteredConfigs>>,
+    asset_server: Res<'w, AssetServer>,
+}
+
+impl AllConfigs<'_> {
+    /// Every asset the Loading screen waits for.
+    fn ids(&self) -> Vec<UntypedAssetId> {
+        let mut ids = vec![
+            self.player_handle.0.id().untyped(),
+            self.enemy_handle.0.id().untyped(),
+            self.game_handle.0.id().untyped(),
+            self.passive_handle.0.id().untyped(),
+            self.character_handle.0.id().untyped(),
+            self.stage_handle.0.id().untyped(),
+            self.pickup_handle.0.id().untyped(),
+            self.evolution_handle.0.id().untyped(),
+            self.font_handles.game.id().untyped(),
+        ];
+        if let Some(registered) = &self.registered {
+            ids.extend(registered.0.iter().copied());
+        }
+        ids
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -171,9 +250,11 @@ struct AllConfigs<'w> {
 
 /// Plugin for game configuration management.
 ///
-/// Registers all RON asset loaders, loads the config files from
+/// Registers the shared RON asset loaders, loads the config files from
 /// `assets/config/`, inserts handles as resources, and wires hot-reload
-/// systems. Transitions `Loading → Title` once all configs are ready.
+/// systems. Weapon configs are registered by their weapon plugins via
+/// [`register_weapon_config`]. Transitions `Loading → Title` once all configs
+/// are ready, or reports failed files in [`ConfigLoadErrors`].
 ///
 /// **Must be registered in the binary** (`main.rs`), after `DefaultPlugins`
 /// and before `GameCorePlugin`.
@@ -183,7 +264,7 @@ impl Plugin for GameConfigPlugin {
     fn build(&self, app: &mut App) {
         info!("🔧 Initializing GameConfigPlugin...");
 
-        // Register non-weapon asset types and loaders.
+        // Register shared asset types and loaders.
         app.init_asset::<PlayerConfig>()
             .register_asset_loader(PlayerConfigLoader)
             .init_asset::<EnemyConfig>()
@@ -195,25 +276,11 @@ impl Plugin for GameConfigPlugin {
             .init_asset::<CharacterConfig>()
             .register_asset_loader(CharacterConfigLoader)
             .init_asset::<StageConfig>()
-            .register_asset_loader(StageConfigLoader);
-
-        // Register per-weapon asset types and loaders.
-        app.init_asset::<WhipConfig>()
-            .register_asset_loader(WhipConfigLoader)
-            .init_asset::<MagicWandConfig>()
-            .register_asset_loader(MagicWandConfigLoader)
-            .init_asset::<KnifeConfig>()
-            .register_asset_loader(KnifeConfigLoader)
-            .init_asset::<GarlicConfig>()
-            .register_asset_loader(GarlicConfigLoader)
-            .init_asset::<BibleConfig>()
-            .register_asset_loader(BibleConfigLoader)
-            .init_asset::<ThunderRingConfig>()
-            .register_asset_loader(ThunderRingConfigLoader)
-            .init_asset::<CrossConfig>()
-            .register_asset_loader(CrossConfigLoader)
-            .init_asset::<FireWandConfig>()
-            .register_asset_loader(FireWandConfigLoader);
+            .register_asset_loader(StageConfigLoader)
+            .init_asset::<PickupConfig>()
+            .register_asset_loader(PickupConfigLoader)
+            .init_asset::<EvolutionConfig>()
+            .register_asset_loader(EvolutionConfigLoader);
 
         // Load all config files and insert handles as resources.
         let asset_server = app.world_mut().resource::<AssetServer>();
@@ -221,19 +288,10 @@ impl Plugin for GameConfigPlugin {
         let enemy_handle: Handle<EnemyConfig> = asset_server.load("config/enemy.ron");
         let game_handle: Handle<GameConfig> = asset_server.load("config/game.ron");
         let passive_handle: Handle<PassiveConfig> = asset_server.load("config/passive.ron");
-        let whip_handle: Handle<WhipConfig> = asset_server.load("config/weapons/whip.ron");
-        let magic_wand_handle: Handle<MagicWandConfig> =
-            asset_server.load("config/weapons/magic_wand.ron");
-        let knife_handle: Handle<KnifeConfig> = asset_server.load("config/weapons/knife.ron");
-        let garlic_handle: Handle<GarlicConfig> = asset_server.load("config/weapons/garlic.ron");
-        let bible_handle: Handle<BibleConfig> = asset_server.load("config/weapons/bible.ron");
-        let thunder_ring_handle: Handle<ThunderRingConfig> =
-            asset_server.load("config/weapons/thunder_ring.ron");
-        let cross_handle: Handle<CrossConfig> = asset_server.load("config/weapons/cross.ron");
-        let fire_wand_handle: Handle<FireWandConfig> =
-            asset_server.load("config/weapons/fire_wand.ron");
         let character_handle: Handle<CharacterConfig> = asset_server.load("config/character.ron");
         let stage_handle: Handle<StageConfig> = asset_server.load("config/stage.ron");
+        let pickup_handle: Handle<PickupConfig> = asset_server.load("config/pickup.ron");
+        let evolution_handle: Handle<EvolutionConfig> = asset_server.load("config/evolution.ron");
 
         let font_text: Handle<Font> = asset_server.load(FONT_TEXT);
 
@@ -241,16 +299,10 @@ impl Plugin for GameConfigPlugin {
             .insert_resource(EnemyConfigHandle(enemy_handle))
             .insert_resource(GameConfigHandle(game_handle))
             .insert_resource(PassiveConfigHandle(passive_handle))
-            .insert_resource(WhipConfigHandle(whip_handle))
-            .insert_resource(MagicWandConfigHandle(magic_wand_handle))
-            .insert_resource(KnifeConfigHandle(knife_handle))
-            .insert_resource(GarlicConfigHandle(garlic_handle))
-            .insert_resource(BibleConfigHandle(bible_handle))
-            .insert_resource(ThunderRingConfigHandle(thunder_ring_handle))
-            .insert_resource(CrossConfigHandle(cross_handle))
-            .insert_resource(FireWandConfigHandle(fire_wand_handle))
             .insert_resource(CharacterConfigHandle(character_handle))
             .insert_resource(StageConfigHandle(stage_handle))
+            .insert_resource(PickupConfigHandle(pickup_handle))
+            .insert_resource(EvolutionConfigHandle(evolution_handle))
             .insert_resource(FontLoadHandles { game: font_text });
 
         // Hot-reload systems run in all states so live-editing always works.
@@ -260,16 +312,20 @@ impl Plugin for GameConfigPlugin {
                 hot_reload_player_config,
                 hot_reload_enemy_config,
                 hot_reload_game_config,
+                hot_reload_passive_config,
                 hot_reload_character_config,
                 hot_reload_stage_config,
+                hot_reload_pickup_config,
+                hot_reload_evolution_config,
             ),
         );
 
         // Transition Loading → Title once all required configs are ready.
-        app.add_systems(Update, wait_for_configs.run_if(in_state(AppState::Loading)));
+        app.init_resource::<ConfigLoadErrors>()
+            .add_systems(Update, wait_for_configs.run_if(in_state(AppState::Loading)));
 
         info!(
-            "✅ GameConfigPlugin initialized (player, enemy, game, passive, character, stage, whip, magic_wand, knife, garlic, bible, thunder_ring, cross, fire_wand configs loading)"
+            "✅ GameConfigPlugin initialized (player, enemy, game, passive, character, stage, pickup, evolution configs loading; weapon configs load with their plugins)"
         );
     }
 }
@@ -278,8 +334,30 @@ impl Plugin for GameConfigPlugin {
 // wait_for_configs
 // ---------------------------------------------------------------------------
 
-/// Transitions from `Loading` → `Title` once all required RON configs are ready.
-fn wait_for_configs(configs: AllConfigs, mut next_state: ResMut<NextState<AppState>>) {
+/// Transitions from `Loading` → `Title` once all required RON configs —
+/// including those added through [`register_weapon_config`] — are ready.
+///
+/// Files that failed to load (unreadable, bad RON or failed validation) are
+/// listed in [`ConfigLoadErrors`] and keep the game in `Loading`; once the
+/// file is fixed it hot-reloads and the list clears.
+fn wait_for_configs(
+    configs: AllConfigs,
+    mut errors: ResMut<ConfigLoadErrors>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    let failures: Vec<String> = configs
+        .ids()
+        .into_iter()
+        .filter_map(|id| match configs.asset_server.get_load_state(id) {
+            Some(LoadState::Failed(err)) => Some(err.to_string()),
+            _ => None,
+        })
+        .collect();
+    errors.set_if_neq(ConfigLoadErrors(failures));
+    if !errors.0.is_empty() {
+        return;
+    }
+
     let all_ready = configs
         .player_assets
         .get(&configs.player_handle.0)
@@ -290,35 +368,29 @@ fn wait_for_configs(configs: AllConfigs, mut next_state: ResMut<NextState<AppSta
             .passive_assets
             .get(&configs.passive_handle.0)
             .is_some()
-        && configs.whip_assets.get(&configs.whip_handle.0).is_some()
-        && configs
-            .magic_wand_assets
-            .get(&configs.magic_wand_handle.0)
-            .is_some()
-        && configs.knife_assets.get(&configs.knife_handle.0).is_some()
         && configs
-            .garlic_assets
-            .get(&configs.garlic_handle.0)
+            .character_assets
+            .get(&configs.character_handle.0)
             .is_some()
-        && configs.bible_assets.get(&configs.bible_handle.0).is_some()
+        && configs.stage_assets.get(&configs.stage_handle.0).is_some()
         && configs
-            .thunder_ring_assets
-            .get(&configs.thunder_ring_handle.0)
+            .pickup_assets
+            .get(&configs.pickup_handle.0)
             .is_some()
-        && configs.cross_assets.get(&configs.cross_handle.0).is_some()
         && configs
-            .fire_wand_assets
-            .get(&configs.fire_wand_handle.0)
+            .evolution_assets
+            .get(&configs.evolution_handle.0)
             .is_some()
-        && configs
-            .character_assets
-            .get(&configs.character_handle.0)
-            .is_some()
-        && configs.stage_assets.get(&configs.stage_handle.0).is_some()
         && configs
             .font_assets
             .get(&configs.font_handles.game)
-            .is_some();
+            .is_some()
+        && configs.registered.as_ref().is_none_or(|registered| {
+            registered
+                .0
+                .iter()
+                .all(|&id| configs.asset_server.is_loaded_with_dependencies(id))
+        });
 
     if all_ready {
         info!("✅ All configs loaded, transitioning to Title");
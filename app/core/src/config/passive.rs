@@ -4,6 +4,9 @@ use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use serde::Deserialize;
 
+use crate::components::{PassiveInventory, Player};
+use crate::config::validate::{ConfigValidator, ValidateConfig};
+
 // ---------------------------------------------------------------------------
 // Fallback constants (used while passive.ron is still loading)
 // ---------------------------------------------------------------------------
@@ -42,8 +45,8 @@ pub(super) struct PassiveConfigPartial {
 ///
 /// Loaded from `assets/config/passive.ron` and hot-reloaded while the game
 /// is running. Systems that read via [`PassiveParams`] pick up new values
-/// immediately — however, already-applied bonuses on live `PlayerStats` are
-/// not retroactively adjusted; changes take effect on the next upgrade.
+/// immediately, and [`hot_reload_passive_config`] has the live `PlayerStats`
+/// rebuilt with the new bonuses.
 #[derive(Asset, TypePath, Debug, Clone)]
 pub struct PassiveConfig {
     /// Damage multiplier bonus added per Spinach level.
@@ -135,6 +138,29 @@ impl From<PassiveConfigPartial> for PassiveConfig {
 #[derive(Resource)]
 pub struct PassiveConfigHandle(pub Handle<PassiveConfig>);
 
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+impl ValidateConfig for PassiveConfig {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.non_negative("spinach_damage_per_level", self.spinach_damage_per_level);
+        v.non_negative("wings_speed_per_level", self.wings_speed_per_level);
+        v.non_negative("hollow_heart_hp_per_level", self.hollow_heart_hp_per_level);
+        v.non_negative("clover_luck_per_level", self.clover_luck_per_level);
+        v.fraction("empty_tome_cdr_per_level", self.empty_tome_cdr_per_level);
+        v.non_negative(
+            "bracer_proj_speed_per_level",
+            self.bracer_proj_speed_per_level,
+        );
+        v.non_negative(
+            "spellbinder_duration_per_level",
+            self.spellbinder_duration_per_level,
+        );
+        v.non_negative("pummarola_regen_per_level", self.pummarola_regen_per_level);
+    }
+}
+
 // ---------------------------------------------------------------------------
 // SystemParam bundle
 // ---------------------------------------------------------------------------
@@ -214,6 +240,40 @@ impl<'w> PassiveParams<'w> {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Hot-reload system
+// ---------------------------------------------------------------------------
+
+/// Handles hot-reloading of passive item bonuses.
+///
+/// On `Modified`, flags the player's [`PassiveInventory`] as changed so
+/// [`recalculate_player_stats`] rebuilds `PlayerStats` from the base stats
+/// with the new per-level bonuses on its next run.
+///
+/// [`recalculate_player_stats`]: crate::systems::xp::apply::recalculate_player_stats
+pub fn hot_reload_passive_config(
+    mut events: MessageReader<AssetEvent<PassiveConfig>>,
+    mut player_q: Query<&mut PassiveInventory, With<Player>>,
+) {
+    for event in events.read() {
+        match event {
+            AssetEvent::Added { id: _ } => {
+                info!("✅ Passive config loaded");
+            }
+            AssetEvent::Modified { id: _ } => {
+                info!("🔥 Hot-reloading passive config!");
+                for mut inventory in player_q.iter_mut() {
+                    inventory.set_changed();
+                }
+            }
+            AssetEvent::Removed { id: _ } => {
+                warn!("⚠️ Passive config removed");
+            }
+            _ => {}
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -0,0 +1,345 @@
+//! Floor pickup configuration loaded from `assets/config/pickup.ron`.
+//!
+//! Holds the drop table shared by enemy deaths and broken light sources,
+//! the strength of each pickup's effect, and light-source spawn tuning.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::config::validate::{ConfigValidator, ValidateConfig};
+use crate::types::PickupType;
+
+// ---------------------------------------------------------------------------
+// Fallback constants (used while pickup.ron is still loading)
+// ---------------------------------------------------------------------------
+
+const DEFAULT_ENEMY_DROP_CHANCE: f32 = 0.005;
+const DEFAULT_PICKUP_RADIUS: f32 = 10.0;
+const DEFAULT_CHICKEN_HEAL: f32 = 30.0;
+const DEFAULT_VACUUM_SPEED_MULTIPLIER: f32 = 2.0;
+const DEFAULT_OROLOGION_DURATION: f32 = 10.0;
+const DEFAULT_LIGHT_SOURCE_INTERVAL: f32 = 15.0;
+const DEFAULT_LIGHT_SOURCE_MAX: usize = 4;
+const DEFAULT_LIGHT_SOURCE_RADIUS: f32 = 12.0;
+
+/// Built-in drop table: `(pickup, weight)`.
+const DEFAULT_DROP_TABLE: [(PickupType, f32); 4] = [
+    (PickupType::FloorChicken, 1.0),
+    (PickupType::Vacuum, 0.3),
+    (PickupType::Rosary, 0.15),
+    (PickupType::Orologion, 0.2),
+];
+
+// ---------------------------------------------------------------------------
+// Asset type
+// ---------------------------------------------------------------------------
+
+/// One weighted row of [`PickupConfig::drop_table`].
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct PickupDropEntry {
+    pub pickup: PickupType,
+    /// Relative weight; the chance of this row is `weight / total_weight`.
+    pub weight: f32,
+}
+
+/// Deserialization mirror of [`PickupConfig`] — every field is `Option<T>` so
+/// RON files with missing fields still load and emit a `warn!` instead of failing.
+#[derive(Deserialize, Default)]
+#[serde(default, rename = "PickupConfig")]
+pub(super) struct PickupConfigPartial {
+    pub enemy_drop_chance: Option<f32>,
+    pub drop_table: Option<Vec<PickupDropEntry>>,
+    pub pickup_radius: Option<f32>,
+    pub chicken_heal: Option<f32>,
+    pub vacuum_speed_multiplier: Option<f32>,
+    pub orologion_duration: Option<f32>,
+    pub light_source_interval: Option<f32>,
+    pub light_source_max: Option<usize>,
+    pub light_source_radius: Option<f32>,
+}
+
+/// Floor pickup drop rates and effect strengths.
+///
+/// Loaded from `assets/config/pickup.ron` and hot-reloaded while the game
+/// is running; systems reading via [`PickupParams`] see new values on the
+/// next frame.
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct PickupConfig {
+    /// Chance (before luck) that a dying enemy drops a floor pickup.
+    pub enemy_drop_chance: f32,
+    /// Weighted table a dropped pickup is chosen from.
+    pub drop_table: Vec<PickupDropEntry>,
+    /// Pickup collision radius; collected when it overlaps the player collider.
+    pub pickup_radius: f32,
+    /// HP restored by a floor chicken (clamped to max HP).
+    pub chicken_heal: f32,
+    /// Vacuum gem pull speed as a multiple of the player's gem attraction speed.
+    pub vacuum_speed_multiplier: f32,
+    /// Seconds the orologion freezes enemies for.
+    pub orologion_duration: f32,
+    /// Seconds between light-source spawns.
+    pub light_source_interval: f32,
+    /// Maximum light sources on the map at once.
+    pub light_source_max: usize,
+    /// Light-source collision radius (pixels).
+    pub light_source_radius: f32,
+}
+
+fn default_drop_table() -> Vec<PickupDropEntry> {
+    DEFAULT_DROP_TABLE
+        .iter()
+        .map(|&(pickup, weight)| PickupDropEntry { pickup, weight })
+        .collect()
+}
+
+impl From<PickupConfigPartial> for PickupConfig {
+    fn from(p: PickupConfigPartial) -> Self {
+        PickupConfig {
+            enemy_drop_chance: p.enemy_drop_chance.unwrap_or_else(|| {
+                warn!(
+                    "pickup.ron: `enemy_drop_chance` missing → using default {DEFAULT_ENEMY_DROP_CHANCE}"
+                );
+                DEFAULT_ENEMY_DROP_CHANCE
+            }),
+            drop_table: p.drop_table.unwrap_or_else(|| {
+                warn!("pickup.ron: `drop_table` missing → using built-in table");
+                default_drop_table()
+            }),
+            pickup_radius: p.pickup_radius.unwrap_or_else(|| {
+                warn!("pickup.ron: `pickup_radius` missing → using default {DEFAULT_PICKUP_RADIUS}");
+                DEFAULT_PICKUP_RADIUS
+            }),
+            chicken_heal: p.chicken_heal.unwrap_or_else(|| {
+                warn!("pickup.ron: `chicken_heal` missing → using default {DEFAULT_CHICKEN_HEAL}");
+                DEFAULT_CHICKEN_HEAL
+            }),
+            vacuum_speed_multiplier: p.vacuum_speed_multiplier.unwrap_or_else(|| {
+                warn!(
+                    "pickup.ron: `vacuum_speed_multiplier` missing → using default {DEFAULT_VACUUM_SPEED_MULTIPLIER}"
+                );
+                DEFAULT_VACUUM_SPEED_MULTIPLIER
+            }),
+            orologion_duration: p.orologion_duration.unwrap_or_else(|| {
+                warn!(
+                    "pickup.ron: `orologion_duration` missing → using default {DEFAULT_OROLOGION_DURATION}"
+                );
+                DEFAULT_OROLOGION_DURATION
+            }),
+            light_source_interval: p.light_source_interval.unwrap_or_else(|| {
+                warn!(
+                    "pickup.ron: `light_source_interval` missing → using default {DEFAULT_LIGHT_SOURCE_INTERVAL}"
+                );
+                DEFAULT_LIGHT_SOURCE_INTERVAL
+            }),
+            light_source_max: p.light_source_max.unwrap_or_else(|| {
+                warn!(
+                    "pickup.ron: `light_source_max` missing → using default {DEFAULT_LIGHT_SOURCE_MAX}"
+                );
+                DEFAULT_LIGHT_SOURCE_MAX
+            }),
+            light_source_radius: p.light_source_radius.unwrap_or_else(|| {
+                warn!(
+                    "pickup.ron: `light_source_radius` missing → using default {DEFAULT_LIGHT_SOURCE_RADIUS}"
+                );
+                DEFAULT_LIGHT_SOURCE_RADIUS
+            }),
+        }
+    }
+}
+
+/// Resource holding the handle to the loaded pickup configuration.
+#[derive(Resource)]
+pub struct PickupConfigHandle(pub Handle<PickupConfig>);
+
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+impl ValidateConfig for PickupConfig {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.fraction("enemy_drop_chance", self.enemy_drop_chance);
+        v.scope("drop_table", |v| {
+            for (i, entry) in self.drop_table.iter().enumerate() {
+                v.scope(format!("[{i}]"), |v| v.non_negative("weight", entry.weight));
+            }
+        });
+        if !self.drop_table.is_empty() && !self.drop_table.iter().any(|e| e.weight > 0.0) {
+            v.error(
+                "drop_table",
+                "needs at least one entry with a positive weight",
+            );
+        }
+        v.positive("pickup_radius", self.pickup_radius);
+        v.non_negative("chicken_heal", self.chicken_heal);
+        v.positive("vacuum_speed_multiplier", self.vacuum_speed_multiplier);
+        v.non_negative("orologion_duration", self.orologion_duration);
+        v.positive("light_source_interval", self.light_source_interval);
+        v.positive("light_source_radius", self.light_source_radius);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// SystemParam bundle
+// ---------------------------------------------------------------------------
+
+/// SystemParam bundle for accessing [`PickupConfig`].
+///
+/// Getters fall back to built-in defaults while the asset is still loading
+/// or when [`GameConfigPlugin`](crate::config::GameConfigPlugin) has not been
+/// registered (e.g. in unit tests).
+#[derive(SystemParam)]
+pub struct PickupParams<'w> {
+    handle: Option<Res<'w, PickupConfigHandle>>,
+    assets: Option<Res<'w, Assets<PickupConfig>>>,
+}
+
+impl<'w> PickupParams<'w> {
+    /// Returns the currently loaded [`PickupConfig`], or `None`.
+    pub fn get(&self) -> Option<&PickupConfig> {
+        self.handle
+            .as_ref()
+            .and_then(|h| self.assets.as_ref().and_then(|a| a.get(&h.0)))
+    }
+
+    pub fn enemy_drop_chance(&self) -> f32 {
+        self.get()
+            .map(|c| c.enemy_drop_chance)
+            .unwrap_or(DEFAULT_ENEMY_DROP_CHANCE)
+    }
+
+    /// Drop table as `(pickup, weight)` pairs.
+    pub fn drop_table(&self) -> Vec<(PickupType, f32)> {
+        match self.get() {
+            Some(c) => c.drop_table.iter().map(|e| (e.pickup, e.weight)).collect(),
+            None => DEFAULT_DROP_TABLE.to_vec(),
+        }
+    }
+
+    pub fn pickup_radius(&self) -> f32 {
+        self.get()
+            .map(|c| c.pickup_radius)
+            .unwrap_or(DEFAULT_PICKUP_RADIUS)
+    }
+
+    pub fn chicken_heal(&self) -> f32 {
+        self.get()
+            .map(|c| c.chicken_heal)
+            .unwrap_or(DEFAULT_CHICKEN_HEAL)
+    }
+
+    pub fn vacuum_speed_multiplier(&self) -> f32 {
+        self.get()
+            .map(|c| c.vacuum_speed_multiplier)
+            .unwrap_or(DEFAULT_VACUUM_SPEED_MULTIPLIER)
+    }
+
+    pub fn orologion_duration(&self) -> f32 {
+        self.get()
+            .map(|c| c.orologion_duration)
+            .unwrap_or(DEFAULT_OROLOGION_DURATION)
+    }
+
+    pub fn light_source_interval(&self) -> f32 {
+        self.get()
+            .map(|c| c.light_source_interval)
+            .unwrap_or(DEFAULT_LIGHT_SOURCE_INTERVAL)
+    }
+
+    pub fn light_source_max(&self) -> usize {
+        self.get()
+            .map(|c| c.light_source_max)
+            .unwrap_or(DEFAULT_LIGHT_SOURCE_MAX)
+    }
+
+    pub fn light_source_radius(&self) -> f32 {
+        self.get()
+            .map(|c| c.light_source_radius)
+            .unwrap_or(DEFAULT_LIGHT_SOURCE_RADIUS)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Hot-reload system
+// ---------------------------------------------------------------------------
+
+/// Handles hot-reloading of pickup configuration.
+pub fn hot_reload_pickup_config(mut events: MessageReader<AssetEvent<PickupConfig>>) {
+    for event in events.read() {
+        match event {
+            AssetEvent::Added { id: _ } => {
+                info!("✅ Pickup config loaded");
+            }
+            AssetEvent::Modified { id: _ } => {
+                info!("🔥 Hot-reloading pickup config!");
+            }
+            AssetEvent::Removed { id: _ } => {
+                warn!("⚠️ Pickup config removed");
+            }
+            _ => {}
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(ron_data: &str) -> PickupConfig {
+        let partial: PickupConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(ron_data)
+            .expect("RON parse must succeed");
+        PickupConfig::from(partial)
+    }
+
+    #[test]
+    fn pickup_config_deserialization() {
+        let cfg = parse(
+            r#"
+PickupConfig(
+    enemy_drop_chance: 0.01,
+    drop_table: [
+        (pickup: FloorChicken, weight: 1.0),
+        (pickup: Rosary,       weight: 0.1),
+    ],
+    pickup_radius: 8.0,
+    chicken_heal: 30.0,
+    vacuum_speed_multiplier: 2.5,
+    orologion_duration: 10.0,
+    light_source_interval: 20.0,
+    light_source_max: 3,
+    light_source_radius: 12.0,
+)
+"#,
+        );
+        assert!((cfg.enemy_drop_chance - 0.01).abs() < 1e-6);
+        assert_eq!(
+            cfg.drop_table,
+            vec![
+                PickupDropEntry {
+                    pickup: PickupType::FloorChicken,
+                    weight: 1.0
+                },
+                PickupDropEntry {
+                    pickup: PickupType::Rosary,
+                    weight: 0.1
+                },
+            ]
+        );
+        assert_eq!(cfg.light_source_max, 3);
+        assert!((cfg.vacuum_speed_multiplier - 2.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn missing_fields_fall_back_to_defaults() {
+        let cfg = parse("PickupConfig()");
+        assert_eq!(cfg.drop_table, default_drop_table());
+        assert_eq!(cfg.light_source_max, DEFAULT_LIGHT_SOURCE_MAX);
+        assert!((cfg.chicken_heal - DEFAULT_CHICKEN_HEAL).abs() < 1e-6);
+    }
+}
@@ -4,6 +4,7 @@ use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use serde::Deserialize;
 
+use crate::config::validate::{ConfigValidator, ValidateConfig};
 use crate::{resources::MetaProgress, types::MetaUpgradeType};
 
 // ---------------------------------------------------------------------------
@@ -219,6 +220,39 @@ impl From<PlayerConfigPartial> for PlayerConfig {
 #[derive(Resource)]
 pub struct PlayerConfigHandle(pub Handle<PlayerConfig>);
 
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+impl ValidateConfig for PlayerConfig {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.positive("base_hp", self.base_hp);
+        v.positive("base_speed", self.base_speed);
+        v.non_negative("base_damage_mult", self.base_damage_mult);
+        v.fraction("base_cooldown_reduction", self.base_cooldown_reduction);
+        v.positive("base_projectile_speed", self.base_projectile_speed);
+        v.positive("base_duration_mult", self.base_duration_mult);
+        v.positive("base_area_mult", self.base_area_mult);
+        v.non_negative("base_luck", self.base_luck);
+        v.non_negative("base_hp_regen", self.base_hp_regen);
+        v.non_negative("base_xp_mult", self.base_xp_mult);
+        v.non_negative("pickup_radius", self.pickup_radius);
+        v.non_negative("invincibility_time", self.invincibility_time);
+        for (field, radius) in [
+            ("collider_radius", self.collider_radius),
+            ("collider_projectile_small", self.collider_projectile_small),
+            ("collider_projectile_large", self.collider_projectile_large),
+            ("collider_xp_gem", self.collider_xp_gem),
+            ("collider_gold_coin", self.collider_gold_coin),
+            ("collider_treasure", self.collider_treasure),
+        ] {
+            v.positive(field, radius);
+        }
+        v.non_negative("gem_attraction_speed", self.gem_attraction_speed);
+        v.non_negative("gem_absorption_radius", self.gem_absorption_radius);
+    }
+}
+
 // ---------------------------------------------------------------------------
 // SystemParam bundle
 // ---------------------------------------------------------------------------
@@ -1,14 +1,56 @@
 //! Stage configuration loaded from `assets/config/stage.ron`.
 //!
 //! Each stage defines the enemy types that may spawn, multipliers for
-//! HP/speed/spawn-rate, and boss strength.  `StageParams` exposes these
-//! values to any system that needs them.
+//! HP/speed/spawn-rate, boss strength, and a wave timeline.  `StageParams`
+//! exposes these values to any system that needs them.
+//!
+//! ## Wave timeline
+//!
+//! `waves` is a list of [`StageWave`]s, each covering a minute range of the
+//! run.  While a wave is active, [`spawn_enemies`] picks enemies from the
+//! wave's own weighted table, spawns `burst` enemies every `spawn_interval`
+//! seconds, and immediately tops the field back up to `min_count` whenever
+//! the player thins it out.  The wave also sets the difficulty multiplier,
+//! ramping from `difficulty_start` to `difficulty_end` across its minutes.
+//! Outside every wave the spawner falls back to the `enemy.ron` unlock
+//! timers filtered by `enemy_types` and the fixed per-minute difficulty
+//! ramp.
+//!
+//! ## Formation events
+//!
+//! `formations` schedules one-off [`FormationEvent`]s — a closing ring, a
+//! bat swarm or a sweeping wall — that [`spawn_formations`] fires once the
+//! run reaches `at_min`.
+//!
+//! ## Mini-boss schedule
+//!
+//! `mini_bosses` lists [`MiniBossEvent`]s — which mini-boss archetype
+//! (Giant, Summoner or Mage) appears at which run minute.
+//! [`spawn_mini_boss`] spawns each one once the run reaches `at_min`, until
+//! the final boss appears.  The archetype's behaviour and chest size live in
+//! `enemy.ron`.
+//!
+//! ## Stage boss
+//!
+//! `boss` optionally names the stage's own final boss as a [`StageBoss`]:
+//! stats, colour and a list of [`BossPhaseSpec`]s, each cycling through RON
+//! [`BossPattern`]s (radial bursts, aimed volleys, summons, charges,
+//! teleports).  [`check_boss_spawn`] spawns it in place of Boss Death and the
+//! generic interpreter in [`boss_pattern`] runs it.  Stages without a `boss`
+//! block keep the hand-coded Boss Death.
+//!
+//! [`check_boss_spawn`]: crate::systems::enemies::boss_spawn::check_boss_spawn
+//! [`boss_pattern`]: crate::systems::enemies::boss_pattern
+//! [`spawn_enemies`]: crate::systems::enemies::spawn::spawn_enemies
+//! [`spawn_formations`]: crate::systems::enemies::formation::spawn_formations
+//! [`spawn_mini_boss`]: crate::systems::enemies::mini_boss_spawn::spawn_mini_boss
 
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use serde::Deserialize;
 
-use crate::types::{EnemyType, StageType};
+use crate::config::validate::{ConfigValidator, ValidateConfig};
+use crate::types::{EnemyType, FormationKind, MiniBossKind, StageType};
 
 // ---------------------------------------------------------------------------
 // Fallback constants — Mad Forest (baseline, ×1.0)
@@ -20,6 +62,68 @@ const DEFAULT_MAD_FOREST_SPAWN_INTERVAL_MULT: f32 = 1.0;
 const DEFAULT_MAD_FOREST_MAX_ENEMIES_MULT: f32 = 1.0;
 const DEFAULT_MAD_FOREST_BOSS_HP_MULT: f32 = 1.0;
 const DEFAULT_MAD_FOREST_BOSS_SPEED_MULT: f32 = 1.0;
+/// Steady ramp: bats first, skeletons take over, then a dense mixed swarm.
+const DEFAULT_MAD_FOREST_WAVES: [DefaultWave; 5] = [
+    (0.0, 2.0, 8, 1, 0.5, (1.0, 1.2), &[(EnemyType::Bat, 1.0)]),
+    (
+        2.0,
+        5.0,
+        15,
+        2,
+        0.5,
+        (1.2, 1.5),
+        &[(EnemyType::Bat, 1.0), (EnemyType::Skeleton, 0.5)],
+    ),
+    (
+        5.0,
+        10.0,
+        25,
+        2,
+        0.5,
+        (1.5, 2.0),
+        &[(EnemyType::Bat, 0.6), (EnemyType::Skeleton, 1.0)],
+    ),
+    (
+        10.0,
+        20.0,
+        40,
+        3,
+        0.5,
+        (2.0, 3.0),
+        &[(EnemyType::Bat, 1.0), (EnemyType::Skeleton, 1.0)],
+    ),
+    (
+        20.0,
+        30.0,
+        60,
+        4,
+        0.5,
+        (3.0, 4.0),
+        &[(EnemyType::Bat, 1.0), (EnemyType::Skeleton, 1.2)],
+    ),
+];
+
+/// Swarms early, a skeleton ring mid-run, then denser swarms and rings.
+const DEFAULT_MAD_FOREST_FORMATIONS: [FormationEvent; 5] = [
+    FormationEvent::new(3.0, FormationKind::Swarm, EnemyType::Bat, 20, 2.5),
+    FormationEvent::new(7.0, FormationKind::Ring, EnemyType::Skeleton, 30, 1.0),
+    FormationEvent::new(12.0, FormationKind::Swarm, EnemyType::Bat, 30, 2.5),
+    FormationEvent::new(16.0, FormationKind::Ring, EnemyType::Bat, 40, 1.0),
+    FormationEvent::new(22.0, FormationKind::Swarm, EnemyType::Bat, 40, 2.5),
+];
+
+/// A Giant every 9 minutes, with a Summoner and a Mage in between.
+const DEFAULT_MAD_FOREST_MINI_BOSSES: [MiniBossEvent; 9] = [
+    MiniBossEvent::new(3.0, MiniBossKind::Giant),
+    MiniBossEvent::new(6.0, MiniBossKind::Summoner),
+    MiniBossEvent::new(9.0, MiniBossKind::Mage),
+    MiniBossEvent::new(12.0, MiniBossKind::Giant),
+    MiniBossEvent::new(15.0, MiniBossKind::Summoner),
+    MiniBossEvent::new(18.0, MiniBossKind::Mage),
+    MiniBossEvent::new(21.0, MiniBossKind::Giant),
+    MiniBossEvent::new(24.0, MiniBossKind::Summoner),
+    MiniBossEvent::new(27.0, MiniBossKind::Mage),
+];
 
 // ---------------------------------------------------------------------------
 // Fallback constants — Inlaid Library (medium, HP ×1.2, speed ×1.1)
@@ -31,6 +135,75 @@ const DEFAULT_INLAID_LIBRARY_SPAWN_INTERVAL_MULT: f32 = 0.9;
 const DEFAULT_INLAID_LIBRARY_MAX_ENEMIES_MULT: f32 = 1.1;
 const DEFAULT_INLAID_LIBRARY_BOSS_HP_MULT: f32 = 1.2;
 const DEFAULT_INLAID_LIBRARY_BOSS_SPEED_MULT: f32 = 1.1;
+/// Slow zombie build-up broken by a pure ghost swarm at minutes 8–12.
+const DEFAULT_INLAID_LIBRARY_WAVES: [DefaultWave; 5] = [
+    (
+        0.0,
+        3.0,
+        10,
+        1,
+        0.7,
+        (1.0, 1.2),
+        &[(EnemyType::Zombie, 1.0)],
+    ),
+    (
+        3.0,
+        8.0,
+        20,
+        1,
+        0.6,
+        (1.2, 1.6),
+        &[(EnemyType::Zombie, 1.0), (EnemyType::Ghost, 0.3)],
+    ),
+    (
+        8.0,
+        12.0,
+        30,
+        5,
+        0.35,
+        (1.6, 2.0),
+        &[(EnemyType::Ghost, 1.0)],
+    ),
+    (
+        12.0,
+        20.0,
+        40,
+        3,
+        0.5,
+        (2.0, 2.8),
+        &[(EnemyType::Zombie, 1.0), (EnemyType::Ghost, 0.8)],
+    ),
+    (
+        20.0,
+        30.0,
+        60,
+        5,
+        0.45,
+        (2.8, 4.0),
+        &[(EnemyType::Zombie, 0.8), (EnemyType::Ghost, 1.0)],
+    ),
+];
+
+/// Zombie walls bracketing a ghost ring.
+const DEFAULT_INLAID_LIBRARY_FORMATIONS: [FormationEvent; 4] = [
+    FormationEvent::new(4.0, FormationKind::Wall, EnemyType::Zombie, 20, 1.0),
+    FormationEvent::new(9.0, FormationKind::Ring, EnemyType::Ghost, 30, 1.0),
+    FormationEvent::new(15.0, FormationKind::Wall, EnemyType::Zombie, 30, 1.0),
+    FormationEvent::new(21.0, FormationKind::Ring, EnemyType::Zombie, 40, 1.0),
+];
+
+/// Summoners lead; the Giant and the Mage both arrive twice late in the run.
+const DEFAULT_INLAID_LIBRARY_MINI_BOSSES: [MiniBossEvent; 9] = [
+    MiniBossEvent::new(3.0, MiniBossKind::Summoner),
+    MiniBossEvent::new(6.0, MiniBossKind::Giant),
+    MiniBossEvent::new(9.0, MiniBossKind::Summoner),
+    MiniBossEvent::new(12.0, MiniBossKind::Mage),
+    MiniBossEvent::new(15.0, MiniBossKind::Summoner),
+    MiniBossEvent::new(18.0, MiniBossKind::Giant),
+    MiniBossEvent::new(21.0, MiniBossKind::Mage),
+    MiniBossEvent::new(24.0, MiniBossKind::Summoner),
+    MiniBossEvent::new(27.0, MiniBossKind::Mage),
+];
 
 // ---------------------------------------------------------------------------
 // Fallback constants — Dairy Plant (hard, HP ×1.5, speed ×1.2)
@@ -42,6 +215,114 @@ const DEFAULT_DAIRY_PLANT_SPAWN_INTERVAL_MULT: f32 = 0.8;
 const DEFAULT_DAIRY_PLANT_MAX_ENEMIES_MULT: f32 = 1.2;
 const DEFAULT_DAIRY_PLANT_BOSS_HP_MULT: f32 = 1.5;
 const DEFAULT_DAIRY_PLANT_BOSS_SPEED_MULT: f32 = 1.2;
+/// Aggressive from the first second; a medusa-only siege, then a dense,
+/// fast demon finale.
+const DEFAULT_DAIRY_PLANT_WAVES: [DefaultWave; 5] = [
+    (0.0, 5.0, 15, 2, 0.4, (1.3, 1.8), &[(EnemyType::Demon, 1.0)]),
+    (
+        5.0,
+        10.0,
+        25,
+        2,
+        0.4,
+        (1.8, 2.3),
+        &[(EnemyType::Demon, 1.0), (EnemyType::Medusa, 0.4)],
+    ),
+    (
+        10.0,
+        15.0,
+        20,
+        4,
+        0.6,
+        (2.3, 2.8),
+        &[(EnemyType::Medusa, 1.0)],
+    ),
+    (
+        15.0,
+        20.0,
+        50,
+        4,
+        0.35,
+        (2.8, 3.5),
+        &[(EnemyType::Demon, 1.0), (EnemyType::Medusa, 0.6)],
+    ),
+    (
+        20.0,
+        30.0,
+        60,
+        4,
+        0.3,
+        (3.5, 4.5),
+        &[(EnemyType::Demon, 1.0), (EnemyType::Medusa, 0.6)],
+    ),
+];
+
+/// Every formation kind, starting with a demon ring at minute 3.
+const DEFAULT_DAIRY_PLANT_FORMATIONS: [FormationEvent; 5] = [
+    FormationEvent::new(3.0, FormationKind::Ring, EnemyType::Demon, 24, 1.0),
+    FormationEvent::new(8.0, FormationKind::Swarm, EnemyType::Bat, 30, 2.5),
+    FormationEvent::new(13.0, FormationKind::Wall, EnemyType::Zombie, 30, 1.0),
+    FormationEvent::new(18.0, FormationKind::Ring, EnemyType::Demon, 40, 1.0),
+    FormationEvent::new(24.0, FormationKind::Swarm, EnemyType::Bat, 40, 3.0),
+];
+
+/// Mages from minute 2, and two mini-bosses at once near the end.
+const DEFAULT_DAIRY_PLANT_MINI_BOSSES: [MiniBossEvent; 10] = [
+    MiniBossEvent::new(2.0, MiniBossKind::Mage),
+    MiniBossEvent::new(5.0, MiniBossKind::Giant),
+    MiniBossEvent::new(8.0, MiniBossKind::Mage),
+    MiniBossEvent::new(11.0, MiniBossKind::Summoner),
+    MiniBossEvent::new(14.0, MiniBossKind::Mage),
+    MiniBossEvent::new(17.0, MiniBossKind::Giant),
+    MiniBossEvent::new(20.0, MiniBossKind::Mage),
+    MiniBossEvent::new(23.0, MiniBossKind::Summoner),
+    MiniBossEvent::new(26.0, MiniBossKind::Giant),
+    MiniBossEvent::new(26.0, MiniBossKind::Mage),
+];
+
+// ---------------------------------------------------------------------------
+// Fallback wave timelines
+// ---------------------------------------------------------------------------
+
+/// Built-in wave row: `(start_min, end_min, min_count, burst, spawn_interval,
+/// (difficulty_start, difficulty_end), [(enemy, weight)])`.
+type DefaultWave = (
+    f32,
+    f32,
+    usize,
+    u32,
+    f32,
+    (f32, f32),
+    &'static [(EnemyType, f32)],
+);
+
+fn default_waves(rows: &[DefaultWave]) -> Vec<StageWave> {
+    rows.iter()
+        .map(
+            |&(
+                start_min,
+                end_min,
+                min_count,
+                burst,
+                spawn_interval,
+                (difficulty_start, difficulty_end),
+                enemies,
+            )| StageWave {
+                start_min,
+                end_min,
+                enemies: enemies
+                    .iter()
+                    .map(|&(enemy, weight)| WaveEnemy { enemy, weight })
+                    .collect(),
+                min_count,
+                burst,
+                spawn_interval,
+                difficulty_start,
+                difficulty_end,
+            },
+        )
+        .collect()
+}
 
 // ---------------------------------------------------------------------------
 // Partial structs for deserialization
@@ -60,6 +341,10 @@ pub(super) struct StageEntryConfigPartial {
     pub max_enemies_multiplier: Option<f32>,
     pub boss_hp_multiplier: Option<f32>,
     pub boss_speed_multiplier: Option<f32>,
+    pub waves: Option<Vec<StageWave>>,
+    pub formations: Option<Vec<FormationEvent>>,
+    pub mini_bosses: Option<Vec<MiniBossEvent>>,
+    pub boss: Option<StageBoss>,
 }
 
 /// Deserialization mirror of [`StageConfig`] — every field is `Option<T>`.
@@ -75,6 +360,179 @@ pub(super) struct StageConfigPartial {
 // Full config types
 // ---------------------------------------------------------------------------
 
+/// One weighted row of [`StageWave::enemies`].
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct WaveEnemy {
+    pub enemy: EnemyType,
+    /// Relative weight; the chance of this row is `weight / total_weight`.
+    pub weight: f32,
+}
+
+/// One segment of a stage's wave timeline.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct StageWave {
+    /// Run minute at which this wave starts (inclusive).
+    pub start_min: f32,
+    /// Run minute at which this wave ends (exclusive).
+    pub end_min: f32,
+    /// Weighted enemy table used while the wave is active.
+    pub enemies: Vec<WaveEnemy>,
+    /// Enemies kept on screen; the spawner tops up to this count every frame.
+    pub min_count: usize,
+    /// Enemies spawned together each time the spawn interval elapses.
+    pub burst: u32,
+    /// Seconds between bursts at difficulty 1.0, before the stage's
+    /// `spawn_interval_multiplier`.
+    pub spawn_interval: f32,
+    /// Difficulty multiplier at `start_min`.
+    pub difficulty_start: f32,
+    /// Difficulty multiplier at `end_min`; interpolated linearly in between.
+    pub difficulty_end: f32,
+}
+
+impl StageWave {
+    /// Returns `true` when `elapsed_secs` falls inside `[start_min, end_min)`.
+    pub fn contains(&self, elapsed_secs: f32) -> bool {
+        let minutes = elapsed_secs / 60.0;
+        minutes >= self.start_min && minutes < self.end_min
+    }
+
+    /// Difficulty multiplier at `elapsed_secs`, interpolated linearly from
+    /// `difficulty_start` to `difficulty_end` across the wave's minutes.
+    pub fn difficulty_at(&self, elapsed_secs: f32) -> f32 {
+        let span = self.end_min - self.start_min;
+        let t = if span > 0.0 {
+            ((elapsed_secs / 60.0 - self.start_min) / span).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        self.difficulty_start + (self.difficulty_end - self.difficulty_start) * t
+    }
+
+    /// The wave's enemies as a `(EnemyType, weight)` table for weighted picks.
+    pub fn spawn_table(&self) -> Vec<(EnemyType, f32)> {
+        self.enemies
+            .iter()
+            .map(|e| (e.enemy, e.weight.max(0.0)))
+            .collect()
+    }
+}
+
+/// One scheduled formation spawn.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct FormationEvent {
+    /// Run minute at which the formation appears.
+    pub at_min: f32,
+    /// Shape and movement of the formation.
+    pub kind: FormationKind,
+    /// Enemy type every member of the formation spawns as.
+    pub enemy: EnemyType,
+    /// Number of enemies in the formation.
+    pub count: u32,
+    /// Multiplier on the members' movement speed (stacks with the stage's).
+    pub speed_multiplier: f32,
+}
+
+impl FormationEvent {
+    pub const fn new(
+        at_min: f32,
+        kind: FormationKind,
+        enemy: EnemyType,
+        count: u32,
+        speed_multiplier: f32,
+    ) -> Self {
+        Self {
+            at_min,
+            kind,
+            enemy,
+            count,
+            speed_multiplier,
+        }
+    }
+}
+
+/// One scheduled mini-boss spawn.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct MiniBossEvent {
+    /// Run minute at which the mini-boss appears.
+    pub at_min: f32,
+    /// Archetype to spawn.
+    pub kind: MiniBossKind,
+}
+
+impl MiniBossEvent {
+    pub const fn new(at_min: f32, kind: MiniBossKind) -> Self {
+        Self { at_min, kind }
+    }
+}
+
+/// One attack in a boss phase's pattern list.
+///
+/// Written as a struct-like enum variant in RON, e.g.
+/// `RadialBurst(count: 12, speed: 160.0, damage: 20.0)`.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum BossPattern {
+    /// `count` bullets fired evenly in every direction.
+    RadialBurst { count: u32, speed: f32, damage: f32 },
+    /// `count` bullets fanned across `spread_deg` degrees, centred on the
+    /// player.
+    AimedVolley {
+        count: u32,
+        spread_deg: f32,
+        speed: f32,
+        damage: f32,
+    },
+    /// `count` regular enemies of type `enemy` spawned in a ring of `radius`
+    /// pixels around the boss.
+    Summon {
+        enemy: EnemyType,
+        count: u32,
+        radius: f32,
+    },
+    /// Dash toward the player's current position at `speed` px/s for
+    /// `duration` seconds.
+    Charge { speed: f32, duration: f32 },
+    /// Reappear `distance` pixels from the player in a random direction.
+    Teleport { distance: f32 },
+}
+
+/// One phase of a [`StageBoss`] fight.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct BossPhaseSpec {
+    /// HP fraction (0.0–1.0) at or below which this phase starts.  Ignored
+    /// for the first phase, which is active from spawn.
+    pub hp_threshold: f32,
+    /// Multiplier on the boss's chase speed during this phase.
+    pub speed_multiplier: f32,
+    /// Seconds between two patterns.
+    pub interval: f32,
+    /// Patterns run in order, looping back to the first.
+    pub patterns: Vec<BossPattern>,
+}
+
+/// A stage's own final boss, run by the boss-pattern interpreter.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct StageBoss {
+    /// Name shown above the boss HP bar.
+    pub name: String,
+    /// Max HP before the stage's `boss_hp_multiplier`.
+    pub base_hp: f32,
+    /// Chase speed (px/s) before the stage's `boss_speed_multiplier`.
+    pub speed: f32,
+    /// Contact damage dealt to the player.
+    pub damage: f32,
+    /// Body collider radius in pixels (at most 60).
+    pub collider_radius: f32,
+    /// Placeholder sprite colour as `(r, g, b)`.
+    pub color: (f32, f32, f32),
+    /// Collider radius of the boss's bullets in pixels.
+    pub projectile_radius: f32,
+    /// Seconds a bullet lives before despawning.
+    pub projectile_lifetime: f32,
+    /// Phases in order of decreasing `hp_threshold`.
+    pub phases: Vec<BossPhaseSpec>,
+}
+
 /// Per-stage gameplay configuration.
 #[derive(Debug, Clone)]
 pub struct StageEntryConfig {
@@ -94,6 +552,22 @@ pub struct StageEntryConfig {
     pub boss_hp_multiplier: f32,
     /// Multiplier applied to boss movement speed.
     pub boss_speed_multiplier: f32,
+    /// Wave timeline, in run order.
+    pub waves: Vec<StageWave>,
+    /// Scheduled formation events (any order).
+    pub formations: Vec<FormationEvent>,
+    /// Scheduled mini-boss spawns (any order).
+    pub mini_bosses: Vec<MiniBossEvent>,
+    /// The stage's own final boss, or `None` for Boss Death.
+    pub boss: Option<StageBoss>,
+}
+
+impl StageEntryConfig {
+    /// Returns the first wave covering `elapsed_secs`, or `None` when the
+    /// timeline has a gap (or has ended) at that time.
+    pub fn wave_at(&self, elapsed_secs: f32) -> Option<&StageWave> {
+        self.waves.iter().find(|w| w.contains(elapsed_secs))
+    }
 }
 
 /// Full stage configuration loaded from `assets/config/stage.ron`.
@@ -133,6 +607,9 @@ fn entry_from_partial(
     default_max_enemies: f32,
     default_boss_hp: f32,
     default_boss_speed: f32,
+    default_stage_waves: &[DefaultWave],
+    default_formations: &[FormationEvent],
+    default_mini_bosses: &[MiniBossEvent],
     field_prefix: &str,
 ) -> StageEntryConfig {
     StageEntryConfig {
@@ -172,6 +649,20 @@ fn entry_from_partial(
             warn!("stage.ron: `{field_prefix}.boss_speed_multiplier` missing → using built-in baseline");
             default_boss_speed
         }),
+        waves: partial.waves.unwrap_or_else(|| {
+            warn!("stage.ron: `{field_prefix}.waves` missing → using built-in timeline");
+            default_waves(default_stage_waves)
+        }),
+        formations: partial.formations.unwrap_or_else(|| {
+            warn!("stage.ron: `{field_prefix}.formations` missing → using built-in schedule");
+            default_formations.to_vec()
+        }),
+        mini_bosses: partial.mini_bosses.unwrap_or_else(|| {
+            warn!("stage.ron: `{field_prefix}.mini_bosses` missing → using built-in schedule");
+            default_mini_bosses.to_vec()
+        }),
+        // Optional: stages without a boss block fight Boss Death.
+        boss: partial.boss,
     }
 }
 
@@ -188,6 +679,9 @@ impl From<StageConfigPartial> for StageConfig {
                 DEFAULT_MAD_FOREST_MAX_ENEMIES_MULT,
                 DEFAULT_MAD_FOREST_BOSS_HP_MULT,
                 DEFAULT_MAD_FOREST_BOSS_SPEED_MULT,
+                &DEFAULT_MAD_FOREST_WAVES,
+                &DEFAULT_MAD_FOREST_FORMATIONS,
+                &DEFAULT_MAD_FOREST_MINI_BOSSES,
                 "mad_forest",
             ),
             inlaid_library: entry_from_partial(
@@ -200,6 +694,9 @@ impl From<StageConfigPartial> for StageConfig {
                 DEFAULT_INLAID_LIBRARY_MAX_ENEMIES_MULT,
                 DEFAULT_INLAID_LIBRARY_BOSS_HP_MULT,
                 DEFAULT_INLAID_LIBRARY_BOSS_SPEED_MULT,
+                &DEFAULT_INLAID_LIBRARY_WAVES,
+                &DEFAULT_INLAID_LIBRARY_FORMATIONS,
+                &DEFAULT_INLAID_LIBRARY_MINI_BOSSES,
                 "inlaid_library",
             ),
             dairy_plant: entry_from_partial(
@@ -212,12 +709,154 @@ impl From<StageConfigPartial> for StageConfig {
                 DEFAULT_DAIRY_PLANT_MAX_ENEMIES_MULT,
                 DEFAULT_DAIRY_PLANT_BOSS_HP_MULT,
                 DEFAULT_DAIRY_PLANT_BOSS_SPEED_MULT,
+                &DEFAULT_DAIRY_PLANT_WAVES,
+                &DEFAULT_DAIRY_PLANT_FORMATIONS,
+                &DEFAULT_DAIRY_PLANT_MINI_BOSSES,
                 "dairy_plant",
             ),
         }
     }
 }
 
+// ---------------------------------------------------------------------------
+// Validation
+// ---------------------------------------------------------------------------
+
+impl ValidateConfig for StageWave {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.non_negative("start_min", self.start_min);
+        v.less_than("start_min", self.start_min, "end_min", self.end_min);
+        v.positive("spawn_interval", self.spawn_interval);
+        v.positive("difficulty_start", self.difficulty_start);
+        v.positive("difficulty_end", self.difficulty_end);
+        v.scope("enemies", |v| {
+            for (i, row) in self.enemies.iter().enumerate() {
+                v.scope(format!("[{i}]"), |v| v.non_negative("weight", row.weight));
+            }
+        });
+    }
+}
+
+impl ValidateConfig for BossPattern {
+    fn validate(&self, v: &mut ConfigValidator) {
+        match *self {
+            BossPattern::RadialBurst {
+                count,
+                speed,
+                damage,
+            } => {
+                v.non_zero("count", count.into());
+                v.positive("speed", speed);
+                v.non_negative("damage", damage);
+            }
+            BossPattern::AimedVolley {
+                count,
+                spread_deg,
+                speed,
+                damage,
+            } => {
+                v.non_zero("count", count.into());
+                v.non_negative("spread_deg", spread_deg);
+                v.positive("speed", speed);
+                v.non_negative("damage", damage);
+            }
+            BossPattern::Summon { count, radius, .. } => {
+                v.non_zero("count", count.into());
+                v.non_negative("radius", radius);
+            }
+            BossPattern::Charge { speed, duration } => {
+                v.positive("speed", speed);
+                v.positive("duration", duration);
+            }
+            BossPattern::Teleport { distance } => v.non_negative("distance", distance),
+        }
+    }
+}
+
+impl ValidateConfig for StageBoss {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.positive("base_hp", self.base_hp);
+        v.non_negative("speed", self.speed);
+        v.non_negative("damage", self.damage);
+        v.positive("collider_radius", self.collider_radius);
+        v.positive("projectile_radius", self.projectile_radius);
+        v.positive("projectile_lifetime", self.projectile_lifetime);
+        v.non_empty("phases", self.phases.len());
+        v.scope("phases", |v| {
+            for (i, phase) in self.phases.iter().enumerate() {
+                v.scope(format!("[{i}]"), |v| {
+                    v.fraction("hp_threshold", phase.hp_threshold);
+                    // The first phase is active from spawn; later ones must
+                    // trigger in order as HP falls.
+                    if i > 0 {
+                        let prev = self.phases[i - 1].hp_threshold;
+                        let prev_field = format!("phases[{}].hp_threshold", i - 1);
+                        v.less_than("hp_threshold", phase.hp_threshold, &prev_field, prev);
+                    }
+                    v.non_negative("speed_multiplier", phase.speed_multiplier);
+                    v.positive("interval", phase.interval);
+                    v.non_empty("patterns", phase.patterns.len());
+                    v.scope("patterns", |v| {
+                        for (j, pattern) in phase.patterns.iter().enumerate() {
+                            v.scope(format!("[{j}]"), |v| pattern.validate(v));
+                        }
+                    });
+                });
+            }
+        });
+    }
+}
+
+impl ValidateConfig for StageEntryConfig {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.non_empty("enemy_types", self.enemy_types.len());
+        for (field, mult) in [
+            ("enemy_hp_multiplier", self.enemy_hp_multiplier),
+            ("enemy_speed_multiplier", self.enemy_speed_multiplier),
+            ("spawn_interval_multiplier", self.spawn_interval_multiplier),
+            ("max_enemies_multiplier", self.max_enemies_multiplier),
+            ("boss_hp_multiplier", self.boss_hp_multiplier),
+            ("boss_speed_multiplier", self.boss_speed_multiplier),
+        ] {
+            v.positive(field, mult);
+        }
+        v.scope("waves", |v| {
+            for (i, wave) in self.waves.iter().enumerate() {
+                v.scope(format!("[{i}]"), |v| wave.validate(v));
+            }
+        });
+        v.scope("formations", |v| {
+            for (i, formation) in self.formations.iter().enumerate() {
+                v.scope(format!("[{i}]"), |v| {
+                    v.non_negative("at_min", formation.at_min);
+                    v.non_zero("count", formation.count.into());
+                    v.positive("speed_multiplier", formation.speed_multiplier);
+                });
+            }
+        });
+        v.scope("mini_bosses", |v| {
+            for (i, event) in self.mini_bosses.iter().enumerate() {
+                v.scope(format!("[{i}]"), |v| v.non_negative("at_min", event.at_min));
+            }
+        });
+        if let Some(boss) = &self.boss {
+            v.scope("boss", |v| boss.validate(v));
+        }
+    }
+}
+
+impl ValidateConfig for StageConfig {
+    fn validate(&self, v: &mut ConfigValidator) {
+        for (name, entry) in [
+            ("mad_forest", &self.mad_forest),
+            ("inlaid_library", &self.inlaid_library),
+            ("dairy_plant", &self.dairy_plant),
+        ] {
+            v.scope(name, |v| entry.validate(v));
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Resource + SystemParam
 // ---------------------------------------------------------------------------
@@ -420,5 +1059,307 @@ StageConfig(
             config.mad_forest.enemy_speed_multiplier,
             DEFAULT_MAD_FOREST_SPEED_MULT
         );
+        assert_eq!(
+            config.dairy_plant.waves,
+            default_waves(&DEFAULT_DAIRY_PLANT_WAVES)
+        );
+    }
+
+    #[test]
+    fn waves_deserialize() {
+        let partial: StageConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(
+                r#"
+StageConfig(
+    mad_forest: (
+        waves: [
+            (start_min: 0.0, end_min: 5.0, enemies: [(enemy: Bat, weight: 2.0)], min_count: 12, burst: 3,
+             spawn_interval: 0.4, difficulty_start: 1.0, difficulty_end: 1.5),
+        ],
+    ),
+)
+"#,
+            )
+            .unwrap();
+        let config = StageConfig::from(partial);
+
+        assert_eq!(
+            config.mad_forest.waves,
+            vec![StageWave {
+                start_min: 0.0,
+                end_min: 5.0,
+                enemies: vec![WaveEnemy {
+                    enemy: EnemyType::Bat,
+                    weight: 2.0,
+                }],
+                min_count: 12,
+                burst: 3,
+                spawn_interval: 0.4,
+                difficulty_start: 1.0,
+                difficulty_end: 1.5,
+            }]
+        );
+    }
+
+    #[test]
+    fn formations_deserialize() {
+        let partial: StageConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(
+                r#"
+StageConfig(
+    inlaid_library: (
+        formations: [
+            (at_min: 2.5, kind: Wall, enemy: Zombie, count: 12, speed_multiplier: 1.5),
+        ],
+    ),
+)
+"#,
+            )
+            .unwrap();
+        let config = StageConfig::from(partial);
+
+        assert_eq!(
+            config.inlaid_library.formations,
+            vec![FormationEvent::new(
+                2.5,
+                FormationKind::Wall,
+                EnemyType::Zombie,
+                12,
+                1.5
+            )]
+        );
+        // Other stages keep their built-in schedule.
+        assert_eq!(
+            config.mad_forest.formations,
+            DEFAULT_MAD_FOREST_FORMATIONS.to_vec()
+        );
+    }
+
+    #[test]
+    fn mini_bosses_deserialize() {
+        let partial: StageConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(
+                r#"
+StageConfig(
+    dairy_plant: (
+        mini_bosses: [
+            (at_min: 1.5, kind: Mage),
+            (at_min: 4.0, kind: Giant),
+        ],
+    ),
+)
+"#,
+            )
+            .unwrap();
+        let config = StageConfig::from(partial);
+
+        assert_eq!(
+            config.dairy_plant.mini_bosses,
+            vec![
+                MiniBossEvent::new(1.5, MiniBossKind::Mage),
+                MiniBossEvent::new(4.0, MiniBossKind::Giant),
+            ]
+        );
+        // Other stages keep their built-in schedule.
+        assert_eq!(
+            config.mad_forest.mini_bosses,
+            DEFAULT_MAD_FOREST_MINI_BOSSES.to_vec()
+        );
+    }
+
+    #[test]
+    fn wave_at_picks_covering_wave_and_none_past_the_end() {
+        let entry = StageConfig::from(StageConfigPartial::default()).mad_forest;
+
+        let first = entry.wave_at(0.0).expect("minute 0 is covered");
+        assert_eq!(first.start_min, 0.0);
+        // End is exclusive: exactly 2:00 belongs to the next wave.
+        let second = entry.wave_at(120.0).expect("minute 2 is covered");
+        assert_eq!(second.start_min, 2.0);
+        assert!(entry.wave_at(30.0 * 60.0).is_none());
+    }
+
+    /// Every built-in stage has a gap-free timeline from minute 0 to 30.
+    #[test]
+    fn default_timelines_are_contiguous() {
+        let config = StageConfig::from(StageConfigPartial::default());
+        for stage in StageType::ALL {
+            let waves = &config.entry_for(stage).waves;
+            assert_eq!(waves.first().unwrap().start_min, 0.0, "{stage:?}");
+            assert_eq!(waves.last().unwrap().end_min, 30.0, "{stage:?}");
+            for pair in waves.windows(2) {
+                assert_eq!(pair[0].end_min, pair[1].start_min, "{stage:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn stage_boss_deserializes() {
+        let partial: StageConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(
+                r#"
+StageConfig(
+    dairy_plant: (
+        boss: (
+            name: "Test Boss",
+            base_hp: 100.0,
+            speed: 20.0,
+            damage: 10.0,
+            collider_radius: 30.0,
+            color: (1.0, 0.5, 0.0),
+            projectile_radius: 6.0,
+            projectile_lifetime: 4.0,
+            phases: [
+                (hp_threshold: 1.0, speed_multiplier: 1.0, interval: 2.0, patterns: [
+                    RadialBurst(count: 8, speed: 100.0, damage: 5.0),
+                    Summon(enemy: Bat, count: 3, radius: 50.0),
+                ]),
+                (hp_threshold: 0.5, speed_multiplier: 1.5, interval: 1.0, patterns: [
+                    Charge(speed: 300.0, duration: 0.5),
+                    Teleport(distance: 200.0),
+                ]),
+            ],
+        ),
+    ),
+)
+"#,
+            )
+            .unwrap();
+        let config = StageConfig::from(partial);
+
+        let boss = config.dairy_plant.boss.expect("boss block parsed");
+        assert_eq!(boss.name, "Test Boss");
+        assert_eq!(boss.phases.len(), 2);
+        assert_eq!(
+            boss.phases[0].patterns[1],
+            BossPattern::Summon {
+                enemy: EnemyType::Bat,
+                count: 3,
+                radius: 50.0,
+            }
+        );
+        assert_eq!(boss.phases[1].hp_threshold, 0.5);
+        // Stages without a boss block fight Boss Death.
+        assert!(config.mad_forest.boss.is_none());
+    }
+
+    /// The shipped `stage.ron` gives the later stages their own bosses and
+    /// leaves Mad Forest to Boss Death.
+    #[test]
+    fn stage_ron_names_stage_bosses() {
+        let partial: StageConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(include_str!(
+                "../../../vampire-survivors/assets/config/stage.ron"
+            ))
+            .unwrap();
+        let config = StageConfig::from(partial);
+
+        assert!(config.mad_forest.boss.is_none());
+        for (entry, name) in [
+            (&config.inlaid_library, "Library Lich"),
+            (&config.dairy_plant, "Dairy Demon Lord"),
+        ] {
+            let boss = entry.boss.as_ref().expect("stage boss missing");
+            assert_eq!(boss.name, name);
+            assert_eq!(boss.phases[0].hp_threshold, 1.0, "{name}");
+            assert!(boss.phases.iter().all(|p| !p.patterns.is_empty()), "{name}");
+        }
+    }
+
+    /// The shipped `stage.ron` timelines must match the built-in fallbacks.
+    #[test]
+    fn stage_ron_waves_match_built_in_timelines() {
+        let partial: StageConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(include_str!(
+                "../../../vampire-survivors/assets/config/stage.ron"
+            ))
+            .unwrap();
+        let config = StageConfig::from(partial);
+        let defaults = StageConfig::from(StageConfigPartial::default());
+
+        for stage in StageType::ALL {
+            assert_eq!(
+                config.entry_for(stage).waves,
+                defaults.entry_for(stage).waves,
+                "{stage:?}"
+            );
+            assert_eq!(
+                config.entry_for(stage).enemy_types,
+                defaults.entry_for(stage).enemy_types,
+                "{stage:?}"
+            );
+            assert_eq!(
+                config.entry_for(stage).formations,
+                defaults.entry_for(stage).formations,
+                "{stage:?}"
+            );
+            assert_eq!(
+                config.entry_for(stage).mini_bosses,
+                defaults.entry_for(stage).mini_bosses,
+                "{stage:?}"
+            );
+        }
+    }
+
+    /// Boss phases must trigger in order of falling HP, and every stage
+    /// needs at least one enemy type.
+    #[test]
+    fn invalid_stage_values_are_reported() {
+        use crate::config::validate::{ConfigLoadError, parse_config};
+
+        let err = parse_config::<StageConfigPartial, StageConfig>(
+            "config/stage.ron",
+            br#"
+StageConfig(
+    inlaid_library: (enemy_types: []),
+    dairy_plant: (
+        waves: [
+            (start_min: 5.0, end_min: 5.0, enemies: [(enemy: Bat, weight: 1.0)], min_count: 10, burst: 1,
+             spawn_interval: 0.0, difficulty_start: 1.0, difficulty_end: 1.0),
+        ],
+        boss: (
+            name: "Test Boss",
+            base_hp: 100.0,
+            speed: 20.0,
+            damage: 10.0,
+            collider_radius: 30.0,
+            color: (1.0, 0.5, 0.0),
+            projectile_radius: 6.0,
+            projectile_lifetime: 4.0,
+            phases: [
+                (hp_threshold: 1.0, speed_multiplier: 1.0, interval: 2.0, patterns: [
+                    Teleport(distance: 200.0),
+                ]),
+                (hp_threshold: 0.3, speed_multiplier: 1.0, interval: 2.0, patterns: [
+                    Teleport(distance: 200.0),
+                ]),
+                (hp_threshold: 0.6, speed_multiplier: 1.0, interval: 2.0, patterns: []),
+            ],
+        ),
+    ),
+)
+"#,
+        )
+        .unwrap_err();
+        let ConfigLoadError::Invalid(issues) = err else {
+            panic!("expected validation issues, got {err}");
+        };
+        let fields: Vec<&str> = issues.iter().map(|i| i.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            [
+                "inlaid_library.enemy_types",
+                "dairy_plant.waves[0].start_min",
+                "dairy_plant.waves[0].spawn_interval",
+                "dairy_plant.boss.phases[2].hp_threshold",
+                "dairy_plant.boss.phases[2].patterns",
+            ]
+        );
     }
 }
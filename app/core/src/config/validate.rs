@@ -0,0 +1,486 @@
+//! Config validation — range and invariant checks for every RON config.
+//!
+//! Each config type implements [`ValidateConfig`].  The shared RON loader
+//! (`ron_asset_loader!`) parses a file, converts it, then runs the check via
+//! [`parse_config`]; a file that breaks an invariant fails to load with a
+//! [`ConfigLoadError::Invalid`] listing every problem instead of silently
+//! misbehaving mid-run.
+//!
+//! Failures surface in two places:
+//!
+//! - In-game, `wait_for_configs` collects failed loads into
+//!   [`ConfigLoadErrors`] and stays in `Loading`; the UI shows them on the
+//!   config error screen.  Fixing the file hot-reloads it and the game
+//!   continues to the title.
+//! - Headless, [`validate_config_dir`] checks every file under an assets
+//!   directory without starting the engine (`vs-validate-configs`).
+//!
+//! Issues name the file and the field path, e.g.
+//! `config/stage.ron: dairy_plant.boss.phases[2].hp_threshold: …`.
+
+use std::fmt;
+use std::path::Path;
+
+use bevy::prelude::*;
+use serde::de::DeserializeOwned;
+
+use super::*;
+use crate::systems::weapons::registry::builtin_specs;
+
+/// Number of entries every per-level table must have (levels 1–8).
+pub const LEVEL_TABLE_LEN: usize = 8;
+
+// ---------------------------------------------------------------------------
+// Issues and errors
+// ---------------------------------------------------------------------------
+
+/// One problem found in a config file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigIssue {
+    /// Path relative to the assets directory, e.g. `config/enemy.ron`.
+    pub file: String,
+    /// Field path inside the file, e.g. `mad_forest.waves[2].enemies[0].weight`.
+    /// Empty when the problem concerns the whole file (unreadable, bad RON).
+    pub field: String,
+    pub message: String,
+}
+
+impl fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.field.is_empty() {
+            write!(f, "{}: {}", self.file, self.message)
+        } else {
+            write!(f, "{}: {}: {}", self.file, self.field, self.message)
+        }
+    }
+}
+
+/// Why a config file failed to load.
+#[derive(Debug)]
+pub enum ConfigLoadError {
+    /// The file could not be read.
+    Io(std::io::Error),
+    /// The file is not valid RON for its config type.
+    Parse(ron::error::SpannedError),
+    /// The file parsed but broke one or more invariants.
+    Invalid(Vec<ConfigIssue>),
+}
+
+impl ConfigLoadError {
+    /// The error as a list of issues against `file`.
+    pub fn into_issues(self, file: &str) -> Vec<ConfigIssue> {
+        let whole_file = |message: String| {
+            vec![ConfigIssue {
+                file: file.to_string(),
+                field: String::new(),
+                message,
+            }]
+        };
+        match self {
+            Self::Io(e) => whole_file(e.to_string()),
+            Self::Parse(e) => whole_file(format!("RON parse error at {e}")),
+            Self::Invalid(issues) => issues,
+        }
+    }
+}
+
+impl fmt::Display for ConfigLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{e}"),
+            Self::Parse(e) => write!(f, "RON parse error at {e}"),
+            Self::Invalid(issues) => {
+                write!(f, "{} invalid value(s)", issues.len())?;
+                for issue in issues {
+                    write!(f, "\n  {}: {}", issue.field, issue.message)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigLoadError {}
+
+impl From<std::io::Error> for ConfigLoadError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// Configs that failed to load, one message per file.
+///
+/// Refreshed every frame in `Loading` by `wait_for_configs`; the game stays
+/// in `Loading` while this is non-empty.
+#[derive(Resource, Debug, Default, PartialEq)]
+pub struct ConfigLoadErrors(pub Vec<String>);
+
+// ---------------------------------------------------------------------------
+// Validator
+// ---------------------------------------------------------------------------
+
+/// Range and invariant checks for a config type.
+pub trait ValidateConfig {
+    /// Reports every problem in `self` to `v`.
+    fn validate(&self, v: &mut ConfigValidator);
+}
+
+/// Collects the issues of one config file while tracking the field path.
+pub struct ConfigValidator {
+    file: String,
+    path: Vec<String>,
+    issues: Vec<ConfigIssue>,
+}
+
+impl ConfigValidator {
+    pub fn new(file: impl Into<String>) -> Self {
+        Self {
+            file: file.into(),
+            path: Vec::new(),
+            issues: Vec::new(),
+        }
+    }
+
+    /// Every issue reported so far.
+    pub fn into_issues(self) -> Vec<ConfigIssue> {
+        self.issues
+    }
+
+    /// Runs `f` with `name` (a field, or an index like `[2]`) appended to the
+    /// current field path.
+    pub fn scope(&mut self, name: impl fmt::Display, f: impl FnOnce(&mut Self)) {
+        self.path.push(name.to_string());
+        f(self);
+        self.path.pop();
+    }
+
+    /// Reports `message` against `field` in the current scope.
+    pub fn error(&mut self, field: &str, message: impl Into<String>) {
+        let mut full = String::new();
+        for part in self
+            .path
+            .iter()
+            .map(String::as_str)
+            .chain([field])
+            .filter(|p| !p.is_empty())
+        {
+            if !full.is_empty() && !part.starts_with('[') {
+                full.push('.');
+            }
+            full.push_str(part);
+        }
+        self.issues.push(ConfigIssue {
+            file: self.file.clone(),
+            field: full,
+            message: message.into(),
+        });
+    }
+
+    /// `value` must be zero or more (rejects NaN).
+    pub fn non_negative(&mut self, field: &str, value: f32) {
+        if value.is_nan() || value < 0.0 {
+            self.error(field, format!("must be ≥ 0, got {value}"));
+        }
+    }
+
+    /// `value` must be strictly positive (rejects NaN).
+    pub fn positive(&mut self, field: &str, value: f32) {
+        if value.is_nan() || value <= 0.0 {
+            self.error(field, format!("must be > 0, got {value}"));
+        }
+    }
+
+    /// `value` must lie within `0.0..=1.0`.
+    pub fn fraction(&mut self, field: &str, value: f32) {
+        if !(0.0..=1.0).contains(&value) {
+            self.error(field, format!("must be within 0.0–1.0, got {value}"));
+        }
+    }
+
+    /// A count that must be at least one.
+    pub fn non_zero(&mut self, field: &str, value: u64) {
+        if value == 0 {
+            self.error(field, "must be at least 1");
+        }
+    }
+
+    /// A list that must have at least one entry.
+    pub fn non_empty(&mut self, field: &str, len: usize) {
+        if len == 0 {
+            self.error(field, "must not be empty");
+        }
+    }
+
+    /// `lo` (named `lo_field`) must be strictly less than `hi`.
+    pub fn less_than(&mut self, lo_field: &str, lo: f32, hi_field: &str, hi: f32) {
+        if lo.is_nan() || hi.is_nan() || lo >= hi {
+            self.error(
+                lo_field,
+                format!("must be less than `{hi_field}` ({hi}), got {lo}"),
+            );
+        }
+    }
+
+    /// A per-level table: exactly [`LEVEL_TABLE_LEN`] non-negative values.
+    pub fn level_table(&mut self, field: &str, values: &[f32]) {
+        self.level_table_len(field, values.len());
+        for (i, &value) in values.iter().enumerate() {
+            self.non_negative(&format!("{field}[{i}]"), value);
+        }
+    }
+
+    /// A per-level count table: exactly [`LEVEL_TABLE_LEN`] values of at least 1.
+    pub fn level_counts(&mut self, field: &str, values: &[u32]) {
+        self.level_table_len(field, values.len());
+        for (i, &value) in values.iter().enumerate() {
+            self.non_zero(&format!("{field}[{i}]"), value.into());
+        }
+    }
+
+    fn level_table_len(&mut self, field: &str, len: usize) {
+        if len != LEVEL_TABLE_LEN {
+            self.error(
+                field,
+                format!("needs {LEVEL_TABLE_LEN} entries (one per level), got {len}"),
+            );
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Parsing
+// ---------------------------------------------------------------------------
+
+/// Parses `bytes` as the RON partial `P`, converts it to `A` and validates
+/// the result.  Shared by the asset loaders and [`validate_config_dir`].
+pub(crate) fn parse_config<P, A>(file: &str, bytes: &[u8]) -> Result<A, ConfigLoadError>
+where
+    P: DeserializeOwned,
+    A: From<P> + ValidateConfig,
+{
+    let options =
+        ron::Options::default().with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME);
+    let partial: P = options.from_bytes(bytes).map_err(ConfigLoadError::Parse)?;
+    let config = A::from(partial);
+
+    let mut validator = ConfigValidator::new(file);
+    config.validate(&mut validator);
+    let issues = validator.into_issues();
+    if issues.is_empty() {
+        Ok(config)
+    } else {
+        Err(ConfigLoadError::Invalid(issues))
+    }
+}
+
+/// Type-erased check of one config file.
+pub type FileCheck = fn(&str, &[u8]) -> Result<(), ConfigLoadError>;
+
+/// Checks a config file the way its loader would load it.
+///
+/// Implemented for every `ron_asset_loader!` loader, so each
+/// [`WeaponSpec`](crate::systems::weapons::registry::WeaponSpec) can carry
+/// its loader's check as `config_check`.
+pub(crate) trait CheckConfigFile {
+    fn check(file: &str, bytes: &[u8]) -> Result<(), ConfigLoadError>;
+}
+
+fn check<P, A>(file: &str, bytes: &[u8]) -> Result<(), ConfigLoadError>
+where
+    P: DeserializeOwned,
+    A: From<P> + ValidateConfig,
+{
+    parse_config::<P, A>(file, bytes).map(drop)
+}
+
+/// Config files loaded by [`GameConfigPlugin`], relative to the assets
+/// directory.  Weapon configs come from their specs instead.
+const CORE_CONFIG_FILES: &[(&str, FileCheck)] = &[
+    (
+        "config/player.ron",
+        check::<PlayerConfigPartial, PlayerConfig>,
+    ),
+    ("config/enemy.ron", check::<EnemyConfigPartial, EnemyConfig>),
+    ("config/game.ron", check::<GameConfigPartial, GameConfig>),
+    (
+        "config/passive.ron",
+        check::<PassiveConfigPartial, PassiveConfig>,
+    ),
+    (
+        "config/character.ron",
+        check::<CharacterConfigPartial, CharacterConfig>,
+    ),
+    ("config/stage.ron", check::<StageConfigPartial, StageConfig>),
+    (
+        "config/pickup.ron",
+        check::<PickupConfigPartial, PickupConfig>,
+    ),
+    (
+        "config/evolution.ron",
+        check::<EvolutionConfigPartial, EvolutionConfig>,
+    ),
+];
+
+/// The config file of every built-in weapon with its check, once per file
+/// (an evolution shares its base weapon's file).
+fn weapon_config_files() -> impl Iterator<Item = (&'static str, FileCheck)> {
+    let specs = builtin_specs();
+    specs.iter().enumerate().filter_map(|(i, spec)| {
+        let first = !specs[..i].iter().any(|s| s.config_path == spec.config_path);
+        first.then_some((spec.config_path, spec.config_check))
+    })
+}
+
+/// Every config file [`validate_config_dir`] checks, with its check.
+fn config_files() -> impl Iterator<Item = (&'static str, FileCheck)> {
+    CORE_CONFIG_FILES
+        .iter()
+        .copied()
+        .chain(weapon_config_files())
+}
+
+/// Paths of every config file [`validate_config_dir`] checks.
+pub fn config_file_paths() -> impl Iterator<Item = &'static str> {
+    config_files().map(|(file, _)| file)
+}
+
+/// Reads, parses and validates every core config under `assets_dir`,
+/// returning all issues found (empty when everything is valid).
+pub fn validate_config_dir(assets_dir: &Path) -> Vec<ConfigIssue> {
+    let mut issues = Vec::new();
+    for (file, check) in config_files() {
+        let result = std::fs::read(assets_dir.join(file))
+            .map_err(ConfigLoadError::Io)
+            .and_then(|bytes| check(file, &bytes));
+        if let Err(e) = result {
+            issues.extend(e.into_issues(file));
+        }
+    }
+    issues
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shipped_assets_dir() -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../vampire-survivors/assets")
+    }
+
+    /// Nested scopes join with dots; index scopes attach without one.
+    #[test]
+    fn field_paths_follow_scopes() {
+        let mut v = ConfigValidator::new("config/stage.ron");
+        v.scope("mad_forest", |v| {
+            v.scope("waves", |v| {
+                v.scope("[2]", |v| v.non_negative("min_count", -1.0));
+            });
+        });
+        v.positive("top_level", 0.0);
+
+        let issues = v.into_issues();
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].field, "mad_forest.waves[2].min_count");
+        assert_eq!(issues[1].field, "top_level");
+        assert!(
+            issues[0]
+                .to_string()
+                .starts_with("config/stage.ron: mad_forest.waves[2].min_count: ")
+        );
+    }
+
+    #[test]
+    fn level_table_checks_length_and_entries() {
+        let mut v = ConfigValidator::new("w.ron");
+        v.level_table("damage_by_level", &[1.0, -2.0, 3.0]);
+        v.level_counts("count_by_level", &[1, 0, 1, 1, 1, 1, 1, 1]);
+
+        let fields: Vec<String> = v.into_issues().into_iter().map(|i| i.field).collect();
+        assert_eq!(
+            fields,
+            ["damage_by_level", "damage_by_level[1]", "count_by_level[1]"]
+        );
+    }
+
+    #[test]
+    fn nan_is_rejected() {
+        let mut v = ConfigValidator::new("w.ron");
+        v.non_negative("a", f32::NAN);
+        v.fraction("b", f32::NAN);
+        v.less_than("lo", f32::NAN, "hi", 1.0);
+        assert_eq!(v.into_issues().len(), 3);
+    }
+
+    #[test]
+    fn parse_errors_are_reported_against_the_file() {
+        let err = parse_config::<GarlicConfigPartial, GarlicConfig>("garlic.ron", b"GarlicConfig(")
+            .unwrap_err();
+        let issues = err.into_issues("garlic.ron");
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].field.is_empty());
+        assert!(issues[0].message.starts_with("RON parse error"));
+    }
+
+    #[test]
+    fn invalid_values_fail_the_load() {
+        let err = parse_config::<GarlicConfigPartial, GarlicConfig>(
+            "garlic.ron",
+            b"GarlicConfig(radius_by_level: [80.0], slow_fraction: 1.5)",
+        )
+        .unwrap_err();
+        let ConfigLoadError::Invalid(issues) = err else {
+            panic!("expected validation issues, got {err}");
+        };
+        let fields: Vec<&str> = issues.iter().map(|i| i.field.as_str()).collect();
+        assert_eq!(fields, ["radius_by_level", "slow_fraction"]);
+    }
+
+    /// Every weapon config is checked, once, through its spec.
+    #[test]
+    fn weapon_configs_are_checked() {
+        let mut paths: Vec<&str> = config_file_paths()
+            .filter(|path| path.starts_with("config/weapons/"))
+            .collect();
+        paths.sort_unstable();
+        assert_eq!(
+            paths,
+            [
+                "config/weapons/bible.ron",
+                "config/weapons/cross.ron",
+                "config/weapons/fire_wand.ron",
+                "config/weapons/garlic.ron",
+                "config/weapons/knife.ron",
+                "config/weapons/magic_wand.ron",
+                "config/weapons/runetracer.ron",
+                "config/weapons/santa_water.ron",
+                "config/weapons/thunder_ring.ron",
+                "config/weapons/whip.ron",
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_file_is_reported() {
+        let issues = validate_config_dir(Path::new("/nonexistent-assets"));
+        assert_eq!(issues.len(), config_file_paths().count());
+    }
+
+    /// The configs shipped with the game pass every check.
+    #[test]
+    fn shipped_configs_are_valid() {
+        let issues = validate_config_dir(&shipped_assets_dir());
+        assert!(
+            issues.is_empty(),
+            "shipped configs have problems:\n{}",
+            issues
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+    }
+}
@@ -6,16 +6,21 @@ use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use serde::Deserialize;
 
+use crate::config::validate::{ConfigValidator, ValidateConfig};
+
 // ---------------------------------------------------------------------------
 // Fallback constants (used while bible.ron is still loading)
 // ---------------------------------------------------------------------------
 
 const DEFAULT_ORB_COLLISION_RADIUS: f32 = 12.0;
 const DEFAULT_HIT_COOLDOWN_SECS: f32 = 1.5;
-const DEFAULT_DAMAGE_BY_LEVEL: &[f32] = &[20.0, 25.0, 30.0, 35.0, 40.0, 50.0, 60.0, 80.0];
-const DEFAULT_ORBIT_RADIUS_BY_LEVEL: &[f32] = &[80.0, 80.0, 80.0, 90.0, 90.0, 100.0, 100.0, 110.0];
-const DEFAULT_ORBIT_SPEED_BY_LEVEL: &[f32] = &[2.0, 2.0, 2.3, 2.3, 2.5, 2.5, 2.8, 3.0];
-const DEFAULT_COUNT_BY_LEVEL: &[u32] = &[1, 1, 2, 2, 3, 3, 3, 3];
+pub(crate) const DEFAULT_DAMAGE_BY_LEVEL: [f32; 8] =
+    [20.0, 25.0, 30.0, 35.0, 40.0, 50.0, 60.0, 80.0];
+pub(crate) const DEFAULT_ORBIT_RADIUS_BY_LEVEL: [f32; 8] =
+    [80.0, 80.0, 80.0, 90.0, 90.0, 100.0, 100.0, 110.0];
+pub(crate) const DEFAULT_ORBIT_SPEED_BY_LEVEL: [f32; 8] = [2.0, 2.0, 2.3, 2.3, 2.5, 2.5, 2.8, 3.0];
+pub(crate) const DEFAULT_COUNT_BY_LEVEL: [u32; 8] = [1, 1, 2, 2, 3, 3, 3, 3];
+const DEFAULT_KNOCKBACK: f32 = 25.0;
 
 /// Deserialization mirror of [`BibleConfig`] — every field is `Option<T>` so
 /// RON files with missing fields still load and emit a `warn!` instead of failing.
@@ -28,6 +33,7 @@ pub(crate) struct BibleConfigPartial {
     pub count_by_level: Option<Vec<u32>>,
     pub orb_collision_radius: Option<f32>,
     pub hit_cooldown_secs: Option<f32>,
+    pub knockback: Option<f32>,
 }
 
 /// Tunable parameters for the Bible and its evolution UnholyVespers.
@@ -45,6 +51,9 @@ pub struct BibleConfig {
     pub orb_collision_radius: f32,
     /// Seconds before the same enemy can be hit again by the same orb.
     pub hit_cooldown_secs: f32,
+    /// Knockback distance (pixels) pushing each enemy hit away from the orb,
+    /// before the enemy's knockback resistance.
+    pub knockback: f32,
 }
 
 impl From<BibleConfigPartial> for BibleConfig {
@@ -78,10 +87,29 @@ impl From<BibleConfigPartial> for BibleConfig {
                 );
                 DEFAULT_HIT_COOLDOWN_SECS
             }),
+            knockback: p.knockback.unwrap_or_else(|| {
+                warn!("bible.ron: `knockback` missing → using default {DEFAULT_KNOCKBACK}");
+                DEFAULT_KNOCKBACK
+            }),
         }
     }
 }
 
+impl ValidateConfig for BibleConfig {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.level_table("damage_by_level", &self.damage_by_level);
+        v.level_table("orbit_radius_by_level", &self.orbit_radius_by_level);
+        v.level_table("orbit_speed_by_level", &self.orbit_speed_by_level);
+        v.level_counts("count_by_level", &self.count_by_level);
+        v.positive("orb_collision_radius", self.orb_collision_radius);
+        v.positive("hit_cooldown_secs", self.hit_cooldown_secs);
+        v.non_negative("knockback", self.knockback);
+    }
+}
+
+// Registered by `BiblePlugin` via `register_weapon_config`.
+ron_asset_loader!(pub(crate) BibleConfigLoader, BibleConfigPartial => BibleConfig);
+
 /// Resource holding the handle to the loaded [`BibleConfig`].
 #[derive(Resource)]
 pub struct BibleConfigHandle(pub Handle<BibleConfig>);
@@ -122,6 +150,7 @@ BibleConfig(
     count_by_level: [1, 1, 2, 2, 3, 3, 3, 3],
     orb_collision_radius: 12.0,
     hit_cooldown_secs: 1.5,
+    knockback: 25.0,
 )
 "#
     }
@@ -140,6 +169,7 @@ BibleConfig(
         assert_eq!(cfg.count_by_level, vec![1, 1, 2, 2, 3, 3, 3, 3]);
         assert_eq!(cfg.orb_collision_radius, 12.0);
         assert_eq!(cfg.hit_cooldown_secs, 1.5);
+        assert_eq!(cfg.knockback, 25.0);
     }
 
     #[test]
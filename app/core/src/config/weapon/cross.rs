@@ -1,4 +1,4 @@
-//! Cross weapon configuration.
+//! Cross / HeavenSword weapon configuration.
 //!
 //! Loaded from `assets/config/weapons/cross.ron`.
 
@@ -6,17 +6,26 @@ use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use serde::Deserialize;
 
+use crate::config::validate::{ConfigValidator, ValidateConfig};
+
 // ---------------------------------------------------------------------------
 // Fallback constants (used while cross.ron is still loading)
 // ---------------------------------------------------------------------------
 
 const DEFAULT_SPREAD_ANGLE_DEG: f32 = 30.0;
 const DEFAULT_COLLIDER_RADIUS: f32 = 8.0;
-const DEFAULT_DAMAGE_BY_LEVEL: &[f32] = &[50.0, 60.0, 70.0, 80.0, 90.0, 110.0, 130.0, 160.0];
-const DEFAULT_SPEED_BY_LEVEL: &[f32] = &[300.0, 320.0, 340.0, 360.0, 380.0, 400.0, 430.0, 460.0];
-const DEFAULT_MAX_RANGE_BY_LEVEL: &[f32] =
-    &[150.0, 160.0, 175.0, 190.0, 205.0, 220.0, 235.0, 250.0];
-const DEFAULT_COUNT_BY_LEVEL: &[u32] = &[1, 1, 1, 1, 2, 2, 2, 2];
+pub(crate) const DEFAULT_DAMAGE_BY_LEVEL: [f32; 8] =
+    [50.0, 60.0, 70.0, 80.0, 90.0, 110.0, 130.0, 160.0];
+pub(crate) const DEFAULT_SPEED_BY_LEVEL: [f32; 8] =
+    [300.0, 320.0, 340.0, 360.0, 380.0, 400.0, 430.0, 460.0];
+pub(crate) const DEFAULT_MAX_RANGE_BY_LEVEL: [f32; 8] =
+    [150.0, 160.0, 175.0, 190.0, 205.0, 220.0, 235.0, 250.0];
+pub(crate) const DEFAULT_COUNT_BY_LEVEL: [u32; 8] = [1, 1, 1, 1, 2, 2, 2, 2];
+pub(crate) const DEFAULT_HEAVEN_SWORD_DAMAGE: f32 = 200.0;
+const DEFAULT_HEAVEN_SWORD_SPEED: f32 = 500.0;
+const DEFAULT_HEAVEN_SWORD_MAX_RANGE: f32 = 320.0;
+pub(crate) const DEFAULT_HEAVEN_SWORD_COUNT: u32 = 4;
+const DEFAULT_KNOCKBACK: f32 = 15.0;
 
 /// Deserialization mirror of [`CrossConfig`] — every field is `Option<T>` so
 /// RON files with missing fields still load and emit a `warn!` instead of failing.
@@ -29,9 +38,15 @@ pub(crate) struct CrossConfigPartial {
     pub count_by_level: Option<Vec<u32>>,
     pub spread_angle_deg: Option<f32>,
     pub collider_radius: Option<f32>,
+    pub heaven_sword_damage: Option<f32>,
+    pub heaven_sword_speed: Option<f32>,
+    pub heaven_sword_max_range: Option<f32>,
+    pub heaven_sword_count: Option<u32>,
+    pub knockback: Option<f32>,
 }
 
-/// Tunable parameters for the Cross boomerang weapon.
+/// Tunable parameters for the Cross boomerang weapon and its evolution
+/// HeavenSword.
 #[derive(Asset, TypePath, Debug, Clone)]
 pub struct CrossConfig {
     /// Damage per hit at each weapon level (index 0 = level 1).
@@ -46,6 +61,17 @@ pub struct CrossConfig {
     pub spread_angle_deg: f32,
     /// Circle collider radius for hit detection (pixels).
     pub collider_radius: f32,
+    /// Damage per hit of each HeavenSword boomerang.
+    pub heaven_sword_damage: f32,
+    /// HeavenSword projectile speed in pixels/second.
+    pub heaven_sword_speed: f32,
+    /// HeavenSword travel distance before reversing, in pixels.
+    pub heaven_sword_max_range: f32,
+    /// Number of HeavenSword boomerangs, evenly spread over a full circle.
+    pub heaven_sword_count: u32,
+    /// Knockback distance (pixels) pushing each enemy hit along the boomerang's travel direction,
+    /// before the enemy's knockback resistance.
+    pub knockback: f32,
 }
 
 impl From<CrossConfigPartial> for CrossConfig {
@@ -79,10 +105,57 @@ impl From<CrossConfigPartial> for CrossConfig {
                 );
                 DEFAULT_COLLIDER_RADIUS
             }),
+            heaven_sword_damage: p.heaven_sword_damage.unwrap_or_else(|| {
+                warn!(
+                    "cross.ron: `heaven_sword_damage` missing → using default {DEFAULT_HEAVEN_SWORD_DAMAGE}"
+                );
+                DEFAULT_HEAVEN_SWORD_DAMAGE
+            }),
+            heaven_sword_speed: p.heaven_sword_speed.unwrap_or_else(|| {
+                warn!(
+                    "cross.ron: `heaven_sword_speed` missing → using default {DEFAULT_HEAVEN_SWORD_SPEED}"
+                );
+                DEFAULT_HEAVEN_SWORD_SPEED
+            }),
+            heaven_sword_max_range: p.heaven_sword_max_range.unwrap_or_else(|| {
+                warn!(
+                    "cross.ron: `heaven_sword_max_range` missing → using default {DEFAULT_HEAVEN_SWORD_MAX_RANGE}"
+                );
+                DEFAULT_HEAVEN_SWORD_MAX_RANGE
+            }),
+            heaven_sword_count: p.heaven_sword_count.unwrap_or_else(|| {
+                warn!(
+                    "cross.ron: `heaven_sword_count` missing → using default {DEFAULT_HEAVEN_SWORD_COUNT}"
+                );
+                DEFAULT_HEAVEN_SWORD_COUNT
+            }),
+            knockback: p.knockback.unwrap_or_else(|| {
+                warn!("cross.ron: `knockback` missing → using default {DEFAULT_KNOCKBACK}");
+                DEFAULT_KNOCKBACK
+            }),
         }
     }
 }
 
+impl ValidateConfig for CrossConfig {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.level_table("damage_by_level", &self.damage_by_level);
+        v.level_table("speed_by_level", &self.speed_by_level);
+        v.level_table("max_range_by_level", &self.max_range_by_level);
+        v.level_counts("count_by_level", &self.count_by_level);
+        v.non_negative("spread_angle_deg", self.spread_angle_deg);
+        v.positive("collider_radius", self.collider_radius);
+        v.non_negative("heaven_sword_damage", self.heaven_sword_damage);
+        v.positive("heaven_sword_speed", self.heaven_sword_speed);
+        v.positive("heaven_sword_max_range", self.heaven_sword_max_range);
+        v.non_zero("heaven_sword_count", self.heaven_sword_count.into());
+        v.non_negative("knockback", self.knockback);
+    }
+}
+
+// Registered by `CrossPlugin` via `register_weapon_config`.
+ron_asset_loader!(pub(crate) CrossConfigLoader, CrossConfigPartial => CrossConfig);
+
 /// Resource holding the handle to the loaded [`CrossConfig`].
 #[derive(Resource)]
 pub struct CrossConfigHandle(pub Handle<CrossConfig>);
@@ -123,6 +196,11 @@ CrossConfig(
     count_by_level:     [1, 1, 1, 1, 2, 2, 2, 2],
     spread_angle_deg:   30.0,
     collider_radius:    8.0,
+    heaven_sword_damage:    200.0,
+    heaven_sword_speed:     500.0,
+    heaven_sword_max_range: 320.0,
+    heaven_sword_count:     4,
+    knockback: 15.0,
 )
 "#
     }
@@ -141,6 +219,9 @@ CrossConfig(
         assert_eq!(cfg.count_by_level, vec![1, 1, 1, 1, 2, 2, 2, 2]);
         assert_eq!(cfg.spread_angle_deg, 30.0);
         assert_eq!(cfg.collider_radius, 8.0);
+        assert_eq!(cfg.heaven_sword_damage, 200.0);
+        assert_eq!(cfg.heaven_sword_count, 4);
+        assert_eq!(cfg.knockback, 15.0);
     }
 
     #[test]
@@ -1,4 +1,4 @@
-//! Fire Wand weapon configuration.
+//! Fire Wand / Hellfire weapon configuration.
 //!
 //! Loaded from `assets/config/weapons/fire_wand.ron`.
 
@@ -6,6 +6,8 @@ use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use serde::Deserialize;
 
+use crate::config::validate::{ConfigValidator, ValidateConfig};
+
 // ---------------------------------------------------------------------------
 // Fallback constants (used while fire_wand.ron is still loading)
 // ---------------------------------------------------------------------------
@@ -15,10 +17,21 @@ const DEFAULT_LIFETIME: f32 = 3.0;
 const DEFAULT_COLLIDER_RADIUS: f32 = 12.0;
 const DEFAULT_EXPLOSION_DURATION: f32 = 0.3;
 const DEFAULT_EXPLOSION_Z: f32 = 7.0;
-const DEFAULT_DAMAGE_BY_LEVEL: [f32; 8] = [80.0, 100.0, 120.0, 150.0, 180.0, 220.0, 270.0, 330.0];
-const DEFAULT_AOE_DAMAGE_BY_LEVEL: [f32; 8] = [40.0, 50.0, 60.0, 75.0, 90.0, 110.0, 135.0, 165.0];
-const DEFAULT_AOE_RADIUS_BY_LEVEL: [f32; 8] =
+pub(crate) const DEFAULT_DAMAGE_BY_LEVEL: [f32; 8] =
+    [80.0, 100.0, 120.0, 150.0, 180.0, 220.0, 270.0, 330.0];
+pub(crate) const DEFAULT_AOE_DAMAGE_BY_LEVEL: [f32; 8] =
+    [40.0, 50.0, 60.0, 75.0, 90.0, 110.0, 135.0, 165.0];
+pub(crate) const DEFAULT_AOE_RADIUS_BY_LEVEL: [f32; 8] =
     [80.0, 90.0, 100.0, 110.0, 120.0, 130.0, 140.0, 150.0];
+pub(crate) const DEFAULT_HELLFIRE_DAMAGE: f32 = 400.0;
+const DEFAULT_HELLFIRE_AOE_DAMAGE: f32 = 200.0;
+const DEFAULT_HELLFIRE_AOE_RADIUS: f32 = 200.0;
+pub(crate) const DEFAULT_HELLFIRE_COUNT: u32 = 3;
+const DEFAULT_HELLFIRE_SPREAD_ANGLE_DEG: f32 = 20.0;
+const DEFAULT_HELLFIRE_COLLIDER_RADIUS: f32 = 20.0;
+const DEFAULT_KNOCKBACK: f32 = 35.0;
+const DEFAULT_BURN_DPS: f32 = 15.0;
+const DEFAULT_BURN_DURATION: f32 = 3.0;
 
 /// Deserialization mirror of [`FireWandConfig`] — every field is `Option<T>` so
 /// RON files with missing fields still load and emit a `warn!` instead of failing.
@@ -34,9 +47,18 @@ pub(crate) struct FireWandConfigPartial {
     pub explosion_duration: Option<f32>,
     pub explosion_color: Option<(f32, f32, f32, f32)>,
     pub explosion_z: Option<f32>,
+    pub hellfire_damage: Option<f32>,
+    pub hellfire_aoe_damage: Option<f32>,
+    pub hellfire_aoe_radius: Option<f32>,
+    pub hellfire_count: Option<u32>,
+    pub hellfire_spread_angle_deg: Option<f32>,
+    pub hellfire_collider_radius: Option<f32>,
+    pub knockback: Option<f32>,
+    pub burn_dps: Option<f32>,
+    pub burn_duration: Option<f32>,
 }
 
-/// Tunable parameters for the Fire Wand weapon.
+/// Tunable parameters for the Fire Wand weapon and its evolution Hellfire.
 #[derive(Asset, TypePath, Debug, Clone)]
 pub struct FireWandConfig {
     /// Direct-hit damage at each weapon level (index 0 = level 1).
@@ -57,6 +79,25 @@ pub struct FireWandConfig {
     pub explosion_color: (f32, f32, f32, f32),
     /// Z-depth of spawned explosion visual entities.
     pub explosion_z: f32,
+    /// Direct-hit damage of each Hellfire fireball.
+    pub hellfire_damage: f32,
+    /// Explosion AoE damage of each Hellfire fireball.
+    pub hellfire_aoe_damage: f32,
+    /// Hellfire explosion radius in pixels.
+    pub hellfire_aoe_radius: f32,
+    /// Hellfire fireballs fired per activation.
+    pub hellfire_count: u32,
+    /// Angular gap between adjacent Hellfire fireballs (degrees).
+    pub hellfire_spread_angle_deg: f32,
+    /// Hellfire fireball collider radius (pixels).
+    pub hellfire_collider_radius: f32,
+    /// Knockback distance (pixels) pushing each enemy hit away from the impact point,
+    /// before the enemy's knockback resistance.
+    pub knockback: f32,
+    /// Burn damage per second inflicted on every enemy hit (direct or AoE).
+    pub burn_dps: f32,
+    /// Seconds the burn lasts; a fresh hit refreshes it.
+    pub burn_duration: f32,
 }
 
 impl From<FireWandConfigPartial> for FireWandConfig {
@@ -104,10 +145,82 @@ impl From<FireWandConfigPartial> for FireWandConfig {
                 );
                 DEFAULT_EXPLOSION_Z
             }),
+            hellfire_damage: p.hellfire_damage.unwrap_or_else(|| {
+                warn!(
+                    "fire_wand.ron: `hellfire_damage` missing → using default {DEFAULT_HELLFIRE_DAMAGE}"
+                );
+                DEFAULT_HELLFIRE_DAMAGE
+            }),
+            hellfire_aoe_damage: p.hellfire_aoe_damage.unwrap_or_else(|| {
+                warn!(
+                    "fire_wand.ron: `hellfire_aoe_damage` missing → using default {DEFAULT_HELLFIRE_AOE_DAMAGE}"
+                );
+                DEFAULT_HELLFIRE_AOE_DAMAGE
+            }),
+            hellfire_aoe_radius: p.hellfire_aoe_radius.unwrap_or_else(|| {
+                warn!(
+                    "fire_wand.ron: `hellfire_aoe_radius` missing → using default {DEFAULT_HELLFIRE_AOE_RADIUS}"
+                );
+                DEFAULT_HELLFIRE_AOE_RADIUS
+            }),
+            hellfire_count: p.hellfire_count.unwrap_or_else(|| {
+                warn!(
+                    "fire_wand.ron: `hellfire_count` missing → using default {DEFAULT_HELLFIRE_COUNT}"
+                );
+                DEFAULT_HELLFIRE_COUNT
+            }),
+            hellfire_spread_angle_deg: p.hellfire_spread_angle_deg.unwrap_or_else(|| {
+                warn!(
+                    "fire_wand.ron: `hellfire_spread_angle_deg` missing → using default {DEFAULT_HELLFIRE_SPREAD_ANGLE_DEG}"
+                );
+                DEFAULT_HELLFIRE_SPREAD_ANGLE_DEG
+            }),
+            hellfire_collider_radius: p.hellfire_collider_radius.unwrap_or_else(|| {
+                warn!(
+                    "fire_wand.ron: `hellfire_collider_radius` missing → using default {DEFAULT_HELLFIRE_COLLIDER_RADIUS}"
+                );
+                DEFAULT_HELLFIRE_COLLIDER_RADIUS
+            }),
+            knockback: p.knockback.unwrap_or_else(|| {
+                warn!("fire_wand.ron: `knockback` missing → using default {DEFAULT_KNOCKBACK}");
+                DEFAULT_KNOCKBACK
+            }),
+            burn_dps: p.burn_dps.unwrap_or_else(|| {
+                warn!("fire_wand.ron: `burn_dps` missing → using default {DEFAULT_BURN_DPS}");
+                DEFAULT_BURN_DPS
+            }),
+            burn_duration: p.burn_duration.unwrap_or_else(|| {
+                warn!("fire_wand.ron: `burn_duration` missing → using default {DEFAULT_BURN_DURATION}");
+                DEFAULT_BURN_DURATION
+            }),
         }
     }
 }
 
+impl ValidateConfig for FireWandConfig {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.level_table("damage_by_level", &self.damage_by_level);
+        v.level_table("aoe_damage_by_level", &self.aoe_damage_by_level);
+        v.level_table("aoe_radius_by_level", &self.aoe_radius_by_level);
+        v.positive("speed", self.speed);
+        v.positive("lifetime", self.lifetime);
+        v.positive("collider_radius", self.collider_radius);
+        v.positive("explosion_duration", self.explosion_duration);
+        v.non_negative("hellfire_damage", self.hellfire_damage);
+        v.non_negative("hellfire_aoe_damage", self.hellfire_aoe_damage);
+        v.non_negative("hellfire_aoe_radius", self.hellfire_aoe_radius);
+        v.non_zero("hellfire_count", self.hellfire_count.into());
+        v.non_negative("hellfire_spread_angle_deg", self.hellfire_spread_angle_deg);
+        v.positive("hellfire_collider_radius", self.hellfire_collider_radius);
+        v.non_negative("knockback", self.knockback);
+        v.non_negative("burn_dps", self.burn_dps);
+        v.non_negative("burn_duration", self.burn_duration);
+    }
+}
+
+// Registered by `FireWandPlugin` via `register_weapon_config`.
+ron_asset_loader!(pub(crate) FireWandConfigLoader, FireWandConfigPartial => FireWandConfig);
+
 /// Resource holding the handle to the loaded [`FireWandConfig`].
 #[derive(Resource)]
 pub struct FireWandConfigHandle(pub Handle<FireWandConfig>);
@@ -151,6 +264,15 @@ FireWandConfig(
     explosion_duration: 0.3,
     explosion_color:    (1.0, 0.4, 0.1, 0.8),
     explosion_z:        7.0,
+    hellfire_damage:           400.0,
+    hellfire_aoe_damage:       200.0,
+    hellfire_aoe_radius:       200.0,
+    hellfire_count:            3,
+    hellfire_spread_angle_deg: 20.0,
+    hellfire_collider_radius:  20.0,
+    knockback: 35.0,
+    burn_dps: 15.0,
+    burn_duration: 3.0,
 )
 "#
     }
@@ -172,6 +294,11 @@ FireWandConfig(
         assert_eq!(cfg.explosion_duration, 0.3);
         assert_eq!(cfg.explosion_color, (1.0, 0.4, 0.1, 0.8));
         assert_eq!(cfg.explosion_z, 7.0);
+        assert_eq!(cfg.hellfire_damage, 400.0);
+        assert_eq!(cfg.hellfire_count, 3);
+        assert_eq!(cfg.knockback, 35.0);
+        assert_eq!(cfg.burn_dps, 15.0);
+        assert_eq!(cfg.burn_duration, 3.0);
     }
 
     #[test]
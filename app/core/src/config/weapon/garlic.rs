@@ -6,12 +6,18 @@ use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use serde::Deserialize;
 
+use crate::config::validate::{ConfigValidator, ValidateConfig};
+
 // ---------------------------------------------------------------------------
 // Fallback constants (used while garlic.ron is still loading)
 // ---------------------------------------------------------------------------
 
-const DEFAULT_DAMAGE_BY_LEVEL: &[f32] = &[5.0, 5.0, 8.0, 8.0, 10.0, 12.0, 15.0, 20.0];
-const DEFAULT_RADIUS_BY_LEVEL: &[f32] = &[80.0, 90.0, 90.0, 100.0, 110.0, 120.0, 130.0, 150.0];
+pub(crate) const DEFAULT_DAMAGE_BY_LEVEL: [f32; 8] = [5.0, 5.0, 8.0, 8.0, 10.0, 12.0, 15.0, 20.0];
+pub(crate) const DEFAULT_RADIUS_BY_LEVEL: [f32; 8] =
+    [80.0, 90.0, 90.0, 100.0, 110.0, 120.0, 130.0, 150.0];
+const DEFAULT_KNOCKBACK: f32 = 12.0;
+const DEFAULT_SLOW_FRACTION: f32 = 0.3;
+const DEFAULT_SLOW_DURATION: f32 = 0.6;
 
 /// Deserialization mirror of [`GarlicConfig`] — every field is `Option<T>` so
 /// RON files with missing fields still load and emit a `warn!` instead of failing.
@@ -20,6 +26,9 @@ const DEFAULT_RADIUS_BY_LEVEL: &[f32] = &[80.0, 90.0, 90.0, 100.0, 110.0, 120.0,
 pub(crate) struct GarlicConfigPartial {
     pub damage_by_level: Option<Vec<f32>>,
     pub radius_by_level: Option<Vec<f32>>,
+    pub knockback: Option<f32>,
+    pub slow_fraction: Option<f32>,
+    pub slow_duration: Option<f32>,
 }
 
 /// Tunable parameters for Garlic and its evolution SoulEater.
@@ -29,6 +38,14 @@ pub struct GarlicConfig {
     pub damage_by_level: Vec<f32>,
     /// Aura radius in pixels, indexed by level (index 0 = level 1).
     pub radius_by_level: Vec<f32>,
+    /// Knockback distance (pixels) pushing each enemy hit away from the player,
+    /// before the enemy's knockback resistance.
+    pub knockback: f32,
+    /// Fraction (0.0–1.0) of move speed removed from enemies inside the aura.
+    pub slow_fraction: f32,
+    /// Seconds the slow lingers after each aura tick, before the enemy's
+    /// knockback resistance.
+    pub slow_duration: f32,
 }
 
 impl From<GarlicConfigPartial> for GarlicConfig {
@@ -42,10 +59,39 @@ impl From<GarlicConfigPartial> for GarlicConfig {
                 warn!("garlic.ron: `radius_by_level` missing → using default");
                 DEFAULT_RADIUS_BY_LEVEL.to_vec()
             }),
+            knockback: p.knockback.unwrap_or_else(|| {
+                warn!("garlic.ron: `knockback` missing → using default {DEFAULT_KNOCKBACK}");
+                DEFAULT_KNOCKBACK
+            }),
+            slow_fraction: p.slow_fraction.unwrap_or_else(|| {
+                warn!(
+                    "garlic.ron: `slow_fraction` missing → using default {DEFAULT_SLOW_FRACTION}"
+                );
+                DEFAULT_SLOW_FRACTION
+            }),
+            slow_duration: p.slow_duration.unwrap_or_else(|| {
+                warn!(
+                    "garlic.ron: `slow_duration` missing → using default {DEFAULT_SLOW_DURATION}"
+                );
+                DEFAULT_SLOW_DURATION
+            }),
         }
     }
 }
 
+impl ValidateConfig for GarlicConfig {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.level_table("damage_by_level", &self.damage_by_level);
+        v.level_table("radius_by_level", &self.radius_by_level);
+        v.non_negative("knockback", self.knockback);
+        v.fraction("slow_fraction", self.slow_fraction);
+        v.non_negative("slow_duration", self.slow_duration);
+    }
+}
+
+// Registered by `GarlicPlugin` via `register_weapon_config`.
+ron_asset_loader!(pub(crate) GarlicConfigLoader, GarlicConfigPartial => GarlicConfig);
+
 /// Resource holding the handle to the loaded [`GarlicConfig`].
 #[derive(Resource)]
 pub struct GarlicConfigHandle(pub Handle<GarlicConfig>);
@@ -83,6 +129,9 @@ mod tests {
 GarlicConfig(
     damage_by_level: [5.0, 5.0, 8.0, 8.0, 10.0, 12.0, 15.0, 20.0],
     radius_by_level: [80.0, 90.0, 90.0, 100.0, 110.0, 120.0, 130.0, 150.0],
+    knockback: 12.0,
+    slow_fraction: 0.3,
+    slow_duration: 0.6,
 )
 "#;
         let partial: GarlicConfigPartial = ron::Options::default()
@@ -96,5 +145,8 @@ GarlicConfig(
         );
         assert_eq!(cfg.radius_by_level[0], 80.0);
         assert_eq!(cfg.radius_by_level[7], 150.0);
+        assert_eq!(cfg.knockback, 12.0);
+        assert_eq!(cfg.slow_fraction, 0.3);
+        assert_eq!(cfg.slow_duration, 0.6);
     }
 }
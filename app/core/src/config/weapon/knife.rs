@@ -6,18 +6,21 @@ use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use serde::Deserialize;
 
+use crate::config::validate::{ConfigValidator, ValidateConfig};
+
 // ---------------------------------------------------------------------------
 // Fallback constants (used while knife.ron is still loading)
 // ---------------------------------------------------------------------------
 
 const DEFAULT_BASE_SPEED: f32 = 600.0;
 const DEFAULT_SPEED_PER_TWO_LEVELS: f32 = 100.0;
-const DEFAULT_BASE_DAMAGE: f32 = 15.0;
-const DEFAULT_DAMAGE_PER_TWO_LEVELS: f32 = 5.0;
+pub(crate) const DEFAULT_BASE_DAMAGE: f32 = 15.0;
+pub(crate) const DEFAULT_DAMAGE_PER_TWO_LEVELS: f32 = 5.0;
 const DEFAULT_LIFETIME: f32 = 5.0;
 const DEFAULT_COLLIDER_RADIUS: f32 = 6.0;
 const DEFAULT_SPREAD_ANGLE_DEG: f32 = 15.0;
-const DEFAULT_COUNT_BY_LEVEL: &[u32] = &[1, 1, 2, 2, 3, 3, 4, 5];
+pub(crate) const DEFAULT_COUNT_BY_LEVEL: [u32; 8] = [1, 1, 2, 2, 3, 3, 4, 5];
+const DEFAULT_KNOCKBACK: f32 = 8.0;
 
 /// Deserialization mirror of [`KnifeConfig`] — every field is `Option<T>` so
 /// RON files with missing fields still load and emit a `warn!` instead of failing.
@@ -32,6 +35,7 @@ pub(crate) struct KnifeConfigPartial {
     pub collider_radius: Option<f32>,
     pub spread_angle_deg: Option<f32>,
     pub count_by_level: Option<Vec<u32>>,
+    pub knockback: Option<f32>,
 }
 
 /// Tunable parameters for the Knife and its evolution ThousandEdge.
@@ -53,6 +57,9 @@ pub struct KnifeConfig {
     pub spread_angle_deg: f32,
     /// Number of projectiles fired per activation, indexed by level (index 0 = level 1).
     pub count_by_level: Vec<u32>,
+    /// Knockback distance (pixels) pushing each enemy hit along the projectile's travel direction,
+    /// before the enemy's knockback resistance.
+    pub knockback: f32,
 }
 
 impl From<KnifeConfigPartial> for KnifeConfig {
@@ -98,10 +105,31 @@ impl From<KnifeConfigPartial> for KnifeConfig {
                 warn!("knife.ron: `count_by_level` missing → using default");
                 DEFAULT_COUNT_BY_LEVEL.to_vec()
             }),
+            knockback: p.knockback.unwrap_or_else(|| {
+                warn!("knife.ron: `knockback` missing → using default {DEFAULT_KNOCKBACK}");
+                DEFAULT_KNOCKBACK
+            }),
         }
     }
 }
 
+impl ValidateConfig for KnifeConfig {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.positive("base_speed", self.base_speed);
+        v.non_negative("speed_per_two_levels", self.speed_per_two_levels);
+        v.non_negative("base_damage", self.base_damage);
+        v.non_negative("damage_per_two_levels", self.damage_per_two_levels);
+        v.positive("lifetime", self.lifetime);
+        v.positive("collider_radius", self.collider_radius);
+        v.non_negative("spread_angle_deg", self.spread_angle_deg);
+        v.level_counts("count_by_level", &self.count_by_level);
+        v.non_negative("knockback", self.knockback);
+    }
+}
+
+// Registered by `KnifePlugin` via `register_weapon_config`.
+ron_asset_loader!(pub(crate) KnifeConfigLoader, KnifeConfigPartial => KnifeConfig);
+
 /// Resource holding the handle to the loaded [`KnifeConfig`].
 #[derive(Resource)]
 pub struct KnifeConfigHandle(pub Handle<KnifeConfig>);
@@ -145,6 +173,7 @@ KnifeConfig(
     collider_radius: 6.0,
     spread_angle_deg: 15.0,
     count_by_level: [1, 1, 2, 2, 3, 3, 4, 5],
+    knockback: 8.0,
 )
 "#;
         let partial: KnifeConfigPartial = ron::Options::default()
@@ -156,5 +185,6 @@ KnifeConfig(
         assert_eq!(cfg.base_damage, 15.0);
         assert_eq!(cfg.spread_angle_deg, 15.0);
         assert_eq!(cfg.count_by_level, vec![1, 1, 2, 2, 3, 3, 4, 5]);
+        assert_eq!(cfg.knockback, 8.0);
     }
 }
@@ -6,17 +6,21 @@ use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use serde::Deserialize;
 
+use crate::config::validate::{ConfigValidator, ValidateConfig};
+
 // ---------------------------------------------------------------------------
 // Fallback constants (used while magic_wand.ron is still loading)
 // ---------------------------------------------------------------------------
 
 const DEFAULT_SPEED: f32 = 600.0;
-const DEFAULT_BASE_DAMAGE: f32 = 20.0;
-const DEFAULT_DAMAGE_PER_LEVEL: f32 = 10.0;
+pub(crate) const DEFAULT_BASE_DAMAGE: f32 = 20.0;
+pub(crate) const DEFAULT_DAMAGE_PER_LEVEL: f32 = 10.0;
 const DEFAULT_LIFETIME: f32 = 5.0;
 const DEFAULT_COLLIDER_RADIUS: f32 = 8.0;
 const DEFAULT_HOLY_WAND_DIRECTION_COUNT: u32 = 8;
 const DEFAULT_HOLY_WAND_PIERCING: u32 = u32::MAX;
+const DEFAULT_KNOCKBACK: f32 = 10.0;
+const DEFAULT_HOLY_WAND_FREEZE_DURATION: f32 = 0.4;
 
 /// Deserialization mirror of [`MagicWandConfig`] — every field is `Option<T>` so
 /// RON files with missing fields still load and emit a `warn!` instead of failing.
@@ -30,6 +34,8 @@ pub(crate) struct MagicWandConfigPartial {
     pub collider_radius: Option<f32>,
     pub holy_wand_direction_count: Option<u32>,
     pub holy_wand_piercing: Option<u32>,
+    pub knockback: Option<f32>,
+    pub holy_wand_freeze_duration: Option<f32>,
 }
 
 /// Tunable parameters for the Magic Wand and its evolution HolyWand.
@@ -49,6 +55,11 @@ pub struct MagicWandConfig {
     pub holy_wand_direction_count: u32,
     /// Piercing value for HolyWand projectiles; `u32::MAX` (4294967295) means infinite pierce.
     pub holy_wand_piercing: u32,
+    /// Knockback distance (pixels) pushing each enemy hit along the projectile's travel direction,
+    /// before the enemy's knockback resistance.
+    pub knockback: f32,
+    /// Seconds each HolyWand hit freezes the enemy, before its knockback resistance.
+    pub holy_wand_freeze_duration: f32,
 }
 
 impl From<MagicWandConfigPartial> for MagicWandConfig {
@@ -92,10 +103,38 @@ impl From<MagicWandConfigPartial> for MagicWandConfig {
                 );
                 DEFAULT_HOLY_WAND_PIERCING
             }),
+            knockback: p.knockback.unwrap_or_else(|| {
+                warn!("magic_wand.ron: `knockback` missing → using default {DEFAULT_KNOCKBACK}");
+                DEFAULT_KNOCKBACK
+            }),
+            holy_wand_freeze_duration: p.holy_wand_freeze_duration.unwrap_or_else(|| {
+                warn!("magic_wand.ron: `holy_wand_freeze_duration` missing → using default {DEFAULT_HOLY_WAND_FREEZE_DURATION}");
+                DEFAULT_HOLY_WAND_FREEZE_DURATION
+            }),
         }
     }
 }
 
+impl ValidateConfig for MagicWandConfig {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.positive("speed", self.speed);
+        v.non_negative("base_damage", self.base_damage);
+        v.non_negative("damage_per_level", self.damage_per_level);
+        v.positive("lifetime", self.lifetime);
+        v.positive("collider_radius", self.collider_radius);
+        v.non_zero(
+            "holy_wand_direction_count",
+            self.holy_wand_direction_count.into(),
+        );
+        v.non_zero("holy_wand_piercing", self.holy_wand_piercing.into());
+        v.non_negative("knockback", self.knockback);
+        v.non_negative("holy_wand_freeze_duration", self.holy_wand_freeze_duration);
+    }
+}
+
+// Registered by `MagicWandPlugin` via `register_weapon_config`.
+ron_asset_loader!(pub(crate) MagicWandConfigLoader, MagicWandConfigPartial => MagicWandConfig);
+
 /// Resource holding the handle to the loaded [`MagicWandConfig`].
 #[derive(Resource)]
 pub struct MagicWandConfigHandle(pub Handle<MagicWandConfig>);
@@ -138,6 +177,8 @@ MagicWandConfig(
     collider_radius: 8.0,
     holy_wand_direction_count: 8,
     holy_wand_piercing: 4294967295,
+    knockback: 10.0,
+    holy_wand_freeze_duration: 0.4,
 )
 "#;
         let partial: MagicWandConfigPartial = ron::Options::default()
@@ -152,5 +193,7 @@ MagicWandConfig(
         assert_eq!(cfg.collider_radius, 8.0);
         assert_eq!(cfg.holy_wand_direction_count, 8);
         assert_eq!(cfg.holy_wand_piercing, u32::MAX);
+        assert_eq!(cfg.knockback, 10.0);
+        assert_eq!(cfg.holy_wand_freeze_duration, 0.4);
     }
 }
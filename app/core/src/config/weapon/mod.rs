@@ -1,7 +1,14 @@
 //! Per-weapon configuration modules.
 //!
 //! Each weapon has its own RON file and Rust module so that adding a new
-//! weapon only requires a new file — no shared struct needs to grow.
+//! weapon only requires a new file — no shared struct needs to grow.  Each
+//! module also defines its RON loader; the weapon's plugin registers it with
+//! [`register_weapon_config`](super::register_weapon_config).
+//!
+//! A module's `pub(crate)` `DEFAULT_*` constants are the single copy of those
+//! defaults: the weapon's systems fall back to them while the RON file loads,
+//! and its [`WeaponSpec`](crate::systems::weapons::registry::WeaponSpec)
+//! builds its per-level damage and count tables from them.
 //!
 //! | RON file                            | Config type           | Weapon                       |
 //! |-------------------------------------|-----------------------|------------------------------|
@@ -11,8 +18,10 @@
 //! | `config/weapons/garlic.ron`         | [`GarlicConfig`]      | Garlic / SoulEater           |
 //! | `config/weapons/bible.ron`          | [`BibleConfig`]       | Bible / UnholyVespers        |
 //! | `config/weapons/thunder_ring.ron`   | [`ThunderRingConfig`] | Thunder Ring / LightningRing |
-//! | `config/weapons/cross.ron`          | [`CrossConfig`]       | Cross                        |
-//! | `config/weapons/fire_wand.ron`      | [`FireWandConfig`]    | Fire Wand                    |
+//! | `config/weapons/cross.ron`          | [`CrossConfig`]       | Cross / HeavenSword          |
+//! | `config/weapons/fire_wand.ron`      | [`FireWandConfig`]    | Fire Wand / Hellfire         |
+//! | `config/weapons/runetracer.ron`     | [`RunetracerConfig`]  | Runetracer / NoFuture        |
+//! | `config/weapons/santa_water.ron`    | [`SantaWaterConfig`]  | Santa Water                  |
 
 pub mod bible;
 pub mod cross;
@@ -20,6 +29,8 @@ pub mod fire_wand;
 pub mod garlic;
 pub mod knife;
 pub mod magic_wand;
+pub mod runetracer;
+pub mod santa_water;
 pub mod thunder_ring;
 pub mod whip;
 
@@ -29,15 +40,19 @@ pub use fire_wand::{FireWandConfig, FireWandConfigHandle, FireWandParams};
 pub use garlic::{GarlicConfig, GarlicConfigHandle, GarlicParams};
 pub use knife::{KnifeConfig, KnifeConfigHandle, KnifeParams};
 pub use magic_wand::{MagicWandConfig, MagicWandConfigHandle, MagicWandParams};
+pub use runetracer::{RunetracerConfig, RunetracerConfigHandle, RunetracerParams};
+pub use santa_water::{SantaWaterConfig, SantaWaterConfigHandle, SantaWaterParams};
 pub use thunder_ring::{ThunderRingConfig, ThunderRingConfigHandle, ThunderRingParams};
 pub use whip::{WhipConfig, WhipConfigHandle, WhipParams};
 
-// Re-export Partial types so config/mod.rs loaders can reference them.
-pub(crate) use bible::BibleConfigPartial;
-pub(crate) use cross::CrossConfigPartial;
-pub(crate) use fire_wand::FireWandConfigPartial;
-pub(crate) use garlic::GarlicConfigPartial;
-pub(crate) use knife::KnifeConfigPartial;
-pub(crate) use magic_wand::MagicWandConfigPartial;
-pub(crate) use thunder_ring::ThunderRingConfigPartial;
-pub(crate) use whip::WhipConfigPartial;
+// Re-export loaders so weapon plugins can register their configs.
+pub(crate) use bible::BibleConfigLoader;
+pub(crate) use cross::CrossConfigLoader;
+pub(crate) use fire_wand::FireWandConfigLoader;
+pub(crate) use garlic::GarlicConfigLoader;
+pub(crate) use knife::KnifeConfigLoader;
+pub(crate) use magic_wand::MagicWandConfigLoader;
+pub(crate) use runetracer::RunetracerConfigLoader;
+pub(crate) use santa_water::SantaWaterConfigLoader;
+pub(crate) use thunder_ring::ThunderRingConfigLoader;
+pub(crate) use whip::WhipConfigLoader;
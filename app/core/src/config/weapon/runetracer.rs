@@ -0,0 +1,239 @@
+//! Runetracer / NoFuture weapon configuration.
+//!
+//! Loaded from `assets/config/weapons/runetracer.ron`.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::config::validate::{ConfigValidator, ValidateConfig};
+
+// ---------------------------------------------------------------------------
+// Fallback constants (used while runetracer.ron is still loading)
+// ---------------------------------------------------------------------------
+
+const DEFAULT_COLLIDER_RADIUS: f32 = 7.0;
+pub(crate) const DEFAULT_DAMAGE_BY_LEVEL: [f32; 8] =
+    [10.0, 15.0, 15.0, 20.0, 25.0, 25.0, 30.0, 35.0];
+pub(crate) const DEFAULT_SPEED_BY_LEVEL: [f32; 8] =
+    [250.0, 250.0, 275.0, 275.0, 300.0, 300.0, 325.0, 350.0];
+pub(crate) const DEFAULT_DURATION_BY_LEVEL: [f32; 8] = [2.25, 2.5, 2.75, 3.0, 3.25, 3.5, 3.75, 4.0];
+pub(crate) const DEFAULT_COUNT_BY_LEVEL: [u32; 8] = [1, 1, 1, 2, 2, 2, 3, 3];
+pub(crate) const DEFAULT_NO_FUTURE_DAMAGE: f32 = 50.0;
+const DEFAULT_NO_FUTURE_SPEED: f32 = 400.0;
+const DEFAULT_NO_FUTURE_DURATION: f32 = 4.5;
+pub(crate) const DEFAULT_NO_FUTURE_COUNT: u32 = 3;
+const DEFAULT_KNOCKBACK: f32 = 5.0;
+
+/// Deserialization mirror of [`RunetracerConfig`] — every field is `Option<T>`
+/// so RON files with missing fields still load and emit a `warn!` instead of
+/// failing.
+#[derive(Deserialize, Default)]
+#[serde(default, rename = "RunetracerConfig")]
+pub(crate) struct RunetracerConfigPartial {
+    pub damage_by_level: Option<Vec<f32>>,
+    pub speed_by_level: Option<Vec<f32>>,
+    pub duration_by_level: Option<Vec<f32>>,
+    pub count_by_level: Option<Vec<u32>>,
+    pub collider_radius: Option<f32>,
+    pub no_future_damage: Option<f32>,
+    pub no_future_speed: Option<f32>,
+    pub no_future_duration: Option<f32>,
+    pub no_future_count: Option<u32>,
+    pub knockback: Option<f32>,
+}
+
+/// Tunable parameters for the Runetracer bouncing projectile and its
+/// evolution NoFuture.
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct RunetracerConfig {
+    /// Damage per hit at each weapon level (index 0 = level 1).
+    pub damage_by_level: Vec<f32>,
+    /// Projectile speed in pixels/second at each weapon level.
+    pub speed_by_level: Vec<f32>,
+    /// Seconds each projectile keeps bouncing at each weapon level, before
+    /// `PlayerStats::duration_multiplier`.
+    pub duration_by_level: Vec<f32>,
+    /// Number of projectiles fired per activation at each weapon level.
+    pub count_by_level: Vec<u32>,
+    /// Circle collider radius for hit detection (pixels).
+    pub collider_radius: f32,
+    /// Damage per hit of each NoFuture projectile.
+    pub no_future_damage: f32,
+    /// NoFuture projectile speed in pixels/second.
+    pub no_future_speed: f32,
+    /// Seconds each NoFuture projectile keeps bouncing, before
+    /// `PlayerStats::duration_multiplier`.
+    pub no_future_duration: f32,
+    /// Number of NoFuture projectiles per activation.
+    pub no_future_count: u32,
+    /// Knockback distance (pixels) pushing each enemy hit along the
+    /// projectile's travel direction, before the enemy's knockback resistance.
+    pub knockback: f32,
+}
+
+impl From<RunetracerConfigPartial> for RunetracerConfig {
+    fn from(p: RunetracerConfigPartial) -> Self {
+        RunetracerConfig {
+            damage_by_level: p.damage_by_level.unwrap_or_else(|| {
+                warn!("runetracer.ron: `damage_by_level` missing → using default");
+                DEFAULT_DAMAGE_BY_LEVEL.to_vec()
+            }),
+            speed_by_level: p.speed_by_level.unwrap_or_else(|| {
+                warn!("runetracer.ron: `speed_by_level` missing → using default");
+                DEFAULT_SPEED_BY_LEVEL.to_vec()
+            }),
+            duration_by_level: p.duration_by_level.unwrap_or_else(|| {
+                warn!("runetracer.ron: `duration_by_level` missing → using default");
+                DEFAULT_DURATION_BY_LEVEL.to_vec()
+            }),
+            count_by_level: p.count_by_level.unwrap_or_else(|| {
+                warn!("runetracer.ron: `count_by_level` missing → using default");
+                DEFAULT_COUNT_BY_LEVEL.to_vec()
+            }),
+            collider_radius: p.collider_radius.unwrap_or_else(|| {
+                warn!(
+                    "runetracer.ron: `collider_radius` missing → using default {DEFAULT_COLLIDER_RADIUS}"
+                );
+                DEFAULT_COLLIDER_RADIUS
+            }),
+            no_future_damage: p.no_future_damage.unwrap_or_else(|| {
+                warn!(
+                    "runetracer.ron: `no_future_damage` missing → using default {DEFAULT_NO_FUTURE_DAMAGE}"
+                );
+                DEFAULT_NO_FUTURE_DAMAGE
+            }),
+            no_future_speed: p.no_future_speed.unwrap_or_else(|| {
+                warn!(
+                    "runetracer.ron: `no_future_speed` missing → using default {DEFAULT_NO_FUTURE_SPEED}"
+                );
+                DEFAULT_NO_FUTURE_SPEED
+            }),
+            no_future_duration: p.no_future_duration.unwrap_or_else(|| {
+                warn!(
+                    "runetracer.ron: `no_future_duration` missing → using default {DEFAULT_NO_FUTURE_DURATION}"
+                );
+                DEFAULT_NO_FUTURE_DURATION
+            }),
+            no_future_count: p.no_future_count.unwrap_or_else(|| {
+                warn!(
+                    "runetracer.ron: `no_future_count` missing → using default {DEFAULT_NO_FUTURE_COUNT}"
+                );
+                DEFAULT_NO_FUTURE_COUNT
+            }),
+            knockback: p.knockback.unwrap_or_else(|| {
+                warn!("runetracer.ron: `knockback` missing → using default {DEFAULT_KNOCKBACK}");
+                DEFAULT_KNOCKBACK
+            }),
+        }
+    }
+}
+
+impl ValidateConfig for RunetracerConfig {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.level_table("damage_by_level", &self.damage_by_level);
+        v.level_table("speed_by_level", &self.speed_by_level);
+        v.level_table("duration_by_level", &self.duration_by_level);
+        v.level_counts("count_by_level", &self.count_by_level);
+        v.positive("collider_radius", self.collider_radius);
+        v.non_negative("no_future_damage", self.no_future_damage);
+        v.positive("no_future_speed", self.no_future_speed);
+        v.positive("no_future_duration", self.no_future_duration);
+        v.non_zero("no_future_count", self.no_future_count.into());
+        v.non_negative("knockback", self.knockback);
+    }
+}
+
+// Registered by `RunetracerPlugin` via `register_weapon_config`.
+ron_asset_loader!(pub(crate) RunetracerConfigLoader, RunetracerConfigPartial => RunetracerConfig);
+
+/// Resource holding the handle to the loaded [`RunetracerConfig`].
+#[derive(Resource)]
+pub struct RunetracerConfigHandle(pub Handle<RunetracerConfig>);
+
+/// SystemParam bundle for accessing [`RunetracerConfig`].
+///
+/// Returns `None` while the asset is still loading. Call `.get()` to obtain
+/// `Option<&RunetracerConfig>`.
+#[derive(SystemParam)]
+pub struct RunetracerParams<'w> {
+    handle: Option<Res<'w, RunetracerConfigHandle>>,
+    assets: Option<Res<'w, Assets<RunetracerConfig>>>,
+}
+
+impl<'w> RunetracerParams<'w> {
+    /// Returns the currently loaded [`RunetracerConfig`], or `None` while loading.
+    pub fn get(&self) -> Option<&RunetracerConfig> {
+        self.handle
+            .as_ref()
+            .and_then(|h| self.assets.as_ref().and_then(|a| a.get(&h.0)))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(ron_data: &str) -> RunetracerConfig {
+        let partial: RunetracerConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(ron_data)
+            .unwrap();
+        RunetracerConfig::from(partial)
+    }
+
+    #[test]
+    fn runetracer_config_deserialization() {
+        let cfg = parse(
+            r#"
+RunetracerConfig(
+    damage_by_level:   [10.0, 15.0, 15.0, 20.0, 25.0, 25.0, 30.0, 35.0],
+    speed_by_level:    [250.0, 250.0, 275.0, 275.0, 300.0, 300.0, 325.0, 350.0],
+    duration_by_level: [2.25, 2.5, 2.75, 3.0, 3.25, 3.5, 3.75, 4.0],
+    count_by_level:    [1, 1, 1, 2, 2, 2, 3, 3],
+    collider_radius:   7.0,
+    no_future_damage:   50.0,
+    no_future_speed:    400.0,
+    no_future_duration: 4.5,
+    no_future_count:    3,
+    knockback: 5.0,
+)
+"#,
+        );
+        assert_eq!(cfg.damage_by_level[0], 10.0);
+        assert_eq!(cfg.damage_by_level[7], 35.0);
+        assert_eq!(cfg.speed_by_level[0], 250.0);
+        assert_eq!(cfg.duration_by_level[7], 4.0);
+        assert_eq!(cfg.count_by_level, vec![1, 1, 1, 2, 2, 2, 3, 3]);
+        assert_eq!(cfg.collider_radius, 7.0);
+        assert_eq!(cfg.no_future_damage, 50.0);
+        assert_eq!(cfg.no_future_duration, 4.5);
+        assert_eq!(cfg.no_future_count, 3);
+        assert_eq!(cfg.knockback, 5.0);
+    }
+
+    #[test]
+    fn missing_fields_fall_back_to_defaults() {
+        let cfg = parse("RunetracerConfig()");
+        assert_eq!(cfg.duration_by_level, DEFAULT_DURATION_BY_LEVEL.to_vec());
+        assert_eq!(cfg.count_by_level, DEFAULT_COUNT_BY_LEVEL.to_vec());
+        assert_eq!(cfg.no_future_speed, DEFAULT_NO_FUTURE_SPEED);
+    }
+
+    #[test]
+    fn runetracer_config_duration_increases_with_level() {
+        let cfg = parse("RunetracerConfig()");
+        for i in 1..cfg.duration_by_level.len() {
+            assert!(
+                cfg.duration_by_level[i] >= cfg.duration_by_level[i - 1],
+                "duration at level {} should be >= level {}",
+                i + 1,
+                i
+            );
+        }
+    }
+}
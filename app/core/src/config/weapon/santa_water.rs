@@ -0,0 +1,199 @@
+//! Santa Water weapon configuration.
+//!
+//! Loaded from `assets/config/weapons/santa_water.ron`.
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::config::validate::{ConfigValidator, ValidateConfig};
+
+// ---------------------------------------------------------------------------
+// Fallback constants (used while santa_water.ron is still loading)
+// ---------------------------------------------------------------------------
+
+pub(crate) const DEFAULT_DAMAGE_BY_LEVEL: [f32; 8] =
+    [10.0, 10.0, 15.0, 15.0, 20.0, 20.0, 25.0, 30.0];
+pub(crate) const DEFAULT_RADIUS_BY_LEVEL: [f32; 8] =
+    [40.0, 44.0, 48.0, 52.0, 56.0, 60.0, 64.0, 70.0];
+pub(crate) const DEFAULT_DURATION_BY_LEVEL: [f32; 8] = [2.0, 2.0, 2.25, 2.5, 2.5, 2.75, 3.0, 3.0];
+pub(crate) const DEFAULT_COUNT_BY_LEVEL: [u32; 8] = [1, 1, 2, 2, 2, 3, 3, 4];
+const DEFAULT_HIT_COOLDOWN_SECS: f32 = 0.5;
+const DEFAULT_TARGET_RANGE: f32 = 350.0;
+const DEFAULT_SCATTER_RADIUS: f32 = 40.0;
+const DEFAULT_KNOCKBACK: f32 = 4.0;
+
+/// Deserialization mirror of [`SantaWaterConfig`] — every field is
+/// `Option<T>` so RON files with missing fields still load and emit a
+/// `warn!` instead of failing.
+#[derive(Deserialize, Default)]
+#[serde(default, rename = "SantaWaterConfig")]
+pub(crate) struct SantaWaterConfigPartial {
+    pub damage_by_level: Option<Vec<f32>>,
+    pub radius_by_level: Option<Vec<f32>>,
+    pub duration_by_level: Option<Vec<f32>>,
+    pub count_by_level: Option<Vec<u32>>,
+    pub hit_cooldown_secs: Option<f32>,
+    pub target_range: Option<f32>,
+    pub scatter_radius: Option<f32>,
+    pub knockback: Option<f32>,
+}
+
+/// Tunable parameters for the Santa Water ground pools.
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct SantaWaterConfig {
+    /// Damage per tick to each enemy in a pool, at each weapon level
+    /// (index 0 = level 1).
+    pub damage_by_level: Vec<f32>,
+    /// Pool radius in pixels at each weapon level, before
+    /// `PlayerStats::area_multiplier`.
+    pub radius_by_level: Vec<f32>,
+    /// Seconds each pool lasts at each weapon level, before
+    /// `PlayerStats::duration_multiplier`.
+    pub duration_by_level: Vec<f32>,
+    /// Number of flasks dropped per activation at each weapon level.
+    pub count_by_level: Vec<u32>,
+    /// Seconds before the same pool can damage the same enemy again.
+    pub hit_cooldown_secs: f32,
+    /// Enemies within this distance of the player (pixels) are flask targets.
+    pub target_range: f32,
+    /// Maximum random offset (pixels) of a flask from its target.
+    pub scatter_radius: f32,
+    /// Knockback distance (pixels) per tick, pushing enemies away from the
+    /// pool centre, before the enemy's knockback resistance.
+    pub knockback: f32,
+}
+
+impl From<SantaWaterConfigPartial> for SantaWaterConfig {
+    fn from(p: SantaWaterConfigPartial) -> Self {
+        SantaWaterConfig {
+            damage_by_level: p.damage_by_level.unwrap_or_else(|| {
+                warn!("santa_water.ron: `damage_by_level` missing → using default");
+                DEFAULT_DAMAGE_BY_LEVEL.to_vec()
+            }),
+            radius_by_level: p.radius_by_level.unwrap_or_else(|| {
+                warn!("santa_water.ron: `radius_by_level` missing → using default");
+                DEFAULT_RADIUS_BY_LEVEL.to_vec()
+            }),
+            duration_by_level: p.duration_by_level.unwrap_or_else(|| {
+                warn!("santa_water.ron: `duration_by_level` missing → using default");
+                DEFAULT_DURATION_BY_LEVEL.to_vec()
+            }),
+            count_by_level: p.count_by_level.unwrap_or_else(|| {
+                warn!("santa_water.ron: `count_by_level` missing → using default");
+                DEFAULT_COUNT_BY_LEVEL.to_vec()
+            }),
+            hit_cooldown_secs: p.hit_cooldown_secs.unwrap_or_else(|| {
+                warn!(
+                    "santa_water.ron: `hit_cooldown_secs` missing → using default {DEFAULT_HIT_COOLDOWN_SECS}"
+                );
+                DEFAULT_HIT_COOLDOWN_SECS
+            }),
+            target_range: p.target_range.unwrap_or_else(|| {
+                warn!(
+                    "santa_water.ron: `target_range` missing → using default {DEFAULT_TARGET_RANGE}"
+                );
+                DEFAULT_TARGET_RANGE
+            }),
+            scatter_radius: p.scatter_radius.unwrap_or_else(|| {
+                warn!(
+                    "santa_water.ron: `scatter_radius` missing → using default {DEFAULT_SCATTER_RADIUS}"
+                );
+                DEFAULT_SCATTER_RADIUS
+            }),
+            knockback: p.knockback.unwrap_or_else(|| {
+                warn!("santa_water.ron: `knockback` missing → using default {DEFAULT_KNOCKBACK}");
+                DEFAULT_KNOCKBACK
+            }),
+        }
+    }
+}
+
+impl ValidateConfig for SantaWaterConfig {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.level_table("damage_by_level", &self.damage_by_level);
+        v.level_table("radius_by_level", &self.radius_by_level);
+        v.level_table("duration_by_level", &self.duration_by_level);
+        v.level_counts("count_by_level", &self.count_by_level);
+        v.positive("hit_cooldown_secs", self.hit_cooldown_secs);
+        v.positive("target_range", self.target_range);
+        v.non_negative("scatter_radius", self.scatter_radius);
+        v.non_negative("knockback", self.knockback);
+    }
+}
+
+// Registered by `SantaWaterPlugin` via `register_weapon_config`.
+ron_asset_loader!(pub(crate) SantaWaterConfigLoader, SantaWaterConfigPartial => SantaWaterConfig);
+
+/// Resource holding the handle to the loaded [`SantaWaterConfig`].
+#[derive(Resource)]
+pub struct SantaWaterConfigHandle(pub Handle<SantaWaterConfig>);
+
+/// SystemParam bundle for accessing [`SantaWaterConfig`].
+///
+/// Returns `None` while the asset is still loading. Call `.get()` to obtain
+/// `Option<&SantaWaterConfig>`.
+#[derive(SystemParam)]
+pub struct SantaWaterParams<'w> {
+    handle: Option<Res<'w, SantaWaterConfigHandle>>,
+    assets: Option<Res<'w, Assets<SantaWaterConfig>>>,
+}
+
+impl<'w> SantaWaterParams<'w> {
+    /// Returns the currently loaded [`SantaWaterConfig`], or `None` while loading.
+    pub fn get(&self) -> Option<&SantaWaterConfig> {
+        self.handle
+            .as_ref()
+            .and_then(|h| self.assets.as_ref().and_then(|a| a.get(&h.0)))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(ron_data: &str) -> SantaWaterConfig {
+        let partial: SantaWaterConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(ron_data)
+            .unwrap();
+        SantaWaterConfig::from(partial)
+    }
+
+    #[test]
+    fn santa_water_config_deserialization() {
+        let cfg = parse(
+            r#"
+SantaWaterConfig(
+    damage_by_level:   [10.0, 10.0, 15.0, 15.0, 20.0, 20.0, 25.0, 30.0],
+    radius_by_level:   [40.0, 44.0, 48.0, 52.0, 56.0, 60.0, 64.0, 70.0],
+    duration_by_level: [2.0, 2.0, 2.25, 2.5, 2.5, 2.75, 3.0, 3.0],
+    count_by_level:    [1, 1, 2, 2, 2, 3, 3, 4],
+    hit_cooldown_secs: 0.5,
+    target_range:      350.0,
+    scatter_radius:    40.0,
+    knockback:         4.0,
+)
+"#,
+        );
+        assert_eq!(cfg.damage_by_level[7], 30.0);
+        assert_eq!(cfg.radius_by_level[0], 40.0);
+        assert_eq!(cfg.duration_by_level[2], 2.25);
+        assert_eq!(cfg.count_by_level, vec![1, 1, 2, 2, 2, 3, 3, 4]);
+        assert_eq!(cfg.hit_cooldown_secs, 0.5);
+        assert_eq!(cfg.target_range, 350.0);
+        assert_eq!(cfg.scatter_radius, 40.0);
+        assert_eq!(cfg.knockback, 4.0);
+    }
+
+    #[test]
+    fn missing_fields_fall_back_to_defaults() {
+        let cfg = parse("SantaWaterConfig()");
+        assert_eq!(cfg.radius_by_level, DEFAULT_RADIUS_BY_LEVEL.to_vec());
+        assert_eq!(cfg.hit_cooldown_secs, DEFAULT_HIT_COOLDOWN_SECS);
+    }
+}
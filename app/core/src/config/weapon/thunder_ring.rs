@@ -6,6 +6,8 @@ use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use serde::Deserialize;
 
+use crate::config::validate::{ConfigValidator, ValidateConfig};
+
 // ---------------------------------------------------------------------------
 // Fallback constants (used while thunder_ring.ron is still loading)
 // ---------------------------------------------------------------------------
@@ -14,8 +16,10 @@ const DEFAULT_EFFECT_DURATION: f32 = 0.2;
 const DEFAULT_VISUAL_SIZE: f32 = 24.0;
 const DEFAULT_STRIKE_Z: f32 = 6.0;
 const DEFAULT_TARGET_RANGE: f32 = 800.0;
-const DEFAULT_DAMAGE_BY_LEVEL: &[f32] = &[40.0, 50.0, 60.0, 60.0, 70.0, 80.0, 90.0, 100.0];
-const DEFAULT_COUNT_BY_LEVEL: &[u32] = &[1, 1, 2, 2, 3, 3, 3, 4];
+pub(crate) const DEFAULT_DAMAGE_BY_LEVEL: [f32; 8] =
+    [40.0, 50.0, 60.0, 60.0, 70.0, 80.0, 90.0, 100.0];
+pub(crate) const DEFAULT_COUNT_BY_LEVEL: [u32; 8] = [1, 1, 2, 2, 3, 3, 3, 4];
+const DEFAULT_KNOCKBACK: f32 = 0.0;
 
 /// Deserialization mirror of [`ThunderRingConfig`] — every field is `Option<T>` so
 /// RON files with missing fields still load and emit a `warn!` instead of failing.
@@ -29,6 +33,7 @@ pub(crate) struct ThunderRingConfigPartial {
     pub visual_color: Option<(f32, f32, f32, f32)>,
     pub strike_z: Option<f32>,
     pub target_range: Option<f32>,
+    pub knockback: Option<f32>,
 }
 
 /// Tunable parameters for the Thunder Ring and its evolution LightningRing.
@@ -49,6 +54,9 @@ pub struct ThunderRingConfig {
     /// Maximum distance from the player (pixels) within which enemies can be targeted.
     /// Approximates the visible screen radius; enemies culled beyond this range are not struck.
     pub target_range: f32,
+    /// Knockback distance (pixels) pushing each enemy hit away from the player,
+    /// before the enemy's knockback resistance.
+    pub knockback: f32,
 }
 
 impl From<ThunderRingConfigPartial> for ThunderRingConfig {
@@ -90,10 +98,28 @@ impl From<ThunderRingConfigPartial> for ThunderRingConfig {
                 );
                 DEFAULT_TARGET_RANGE
             }),
+            knockback: p.knockback.unwrap_or_else(|| {
+                warn!("thunder_ring.ron: `knockback` missing → using default {DEFAULT_KNOCKBACK}");
+                DEFAULT_KNOCKBACK
+            }),
         }
     }
 }
 
+impl ValidateConfig for ThunderRingConfig {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.level_table("damage_by_level", &self.damage_by_level);
+        v.level_counts("count_by_level", &self.count_by_level);
+        v.positive("effect_duration", self.effect_duration);
+        v.positive("visual_size", self.visual_size);
+        v.positive("target_range", self.target_range);
+        v.non_negative("knockback", self.knockback);
+    }
+}
+
+// Registered by `ThunderRingPlugin` via `register_weapon_config`.
+ron_asset_loader!(pub(crate) ThunderRingConfigLoader, ThunderRingConfigPartial => ThunderRingConfig);
+
 /// Resource holding the handle to the loaded [`ThunderRingConfig`].
 #[derive(Resource)]
 pub struct ThunderRingConfigHandle(pub Handle<ThunderRingConfig>);
@@ -135,6 +161,7 @@ ThunderRingConfig(
     visual_color: (0.9, 1.0, 0.2, 0.85),
     strike_z: 6.0,
     target_range: 800.0,
+    knockback: 0.0,
 )
 "#
     }
@@ -154,6 +181,7 @@ ThunderRingConfig(
         assert_eq!(cfg.visual_color, (0.9, 1.0, 0.2, 0.85));
         assert_eq!(cfg.strike_z, 6.0);
         assert_eq!(cfg.target_range, 800.0);
+        assert_eq!(cfg.knockback, 0.0);
     }
 
     #[test]
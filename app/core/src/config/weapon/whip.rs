@@ -6,15 +6,18 @@ use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use serde::Deserialize;
 
+use crate::config::validate::{ConfigValidator, ValidateConfig};
+
 // ---------------------------------------------------------------------------
 // Fallback constants (used while whip.ron is still loading)
 // ---------------------------------------------------------------------------
 
 const DEFAULT_RANGE: f32 = 160.0;
-const DEFAULT_BASE_DAMAGE: f32 = 20.0;
-const DEFAULT_DAMAGE_PER_LEVEL: f32 = 10.0;
+pub(crate) const DEFAULT_BASE_DAMAGE: f32 = 20.0;
+pub(crate) const DEFAULT_DAMAGE_PER_LEVEL: f32 = 10.0;
 const DEFAULT_EFFECT_DURATION: f32 = 0.15;
 const DEFAULT_SPREAD_FACTOR: f32 = 0.6;
+const DEFAULT_KNOCKBACK: f32 = 30.0;
 
 /// Deserialization mirror of [`WhipConfig`] — every field is `Option<T>` so
 /// RON files with missing fields still load and emit a `warn!` instead of failing.
@@ -26,6 +29,7 @@ pub(crate) struct WhipConfigPartial {
     pub damage_per_level: Option<f32>,
     pub effect_duration: Option<f32>,
     pub spread_factor: Option<f32>,
+    pub knockback: Option<f32>,
 }
 
 /// Tunable parameters for the Whip and its evolution BloodyTear.
@@ -41,6 +45,9 @@ pub struct WhipConfig {
     pub effect_duration: f32,
     /// Vertical spread factor: enemy passes when `rel.y.abs() < range * factor`.
     pub spread_factor: f32,
+    /// Knockback distance (pixels) pushing each enemy hit in the lash direction,
+    /// before the enemy's knockback resistance.
+    pub knockback: f32,
 }
 
 impl From<WhipConfigPartial> for WhipConfig {
@@ -72,10 +79,28 @@ impl From<WhipConfigPartial> for WhipConfig {
                 );
                 DEFAULT_SPREAD_FACTOR
             }),
+            knockback: p.knockback.unwrap_or_else(|| {
+                warn!("whip.ron: `knockback` missing → using default {DEFAULT_KNOCKBACK}");
+                DEFAULT_KNOCKBACK
+            }),
         }
     }
 }
 
+impl ValidateConfig for WhipConfig {
+    fn validate(&self, v: &mut ConfigValidator) {
+        v.positive("range", self.range);
+        v.non_negative("base_damage", self.base_damage);
+        v.non_negative("damage_per_level", self.damage_per_level);
+        v.positive("effect_duration", self.effect_duration);
+        v.non_negative("spread_factor", self.spread_factor);
+        v.non_negative("knockback", self.knockback);
+    }
+}
+
+// Registered by `WhipPlugin` via `register_weapon_config`.
+ron_asset_loader!(pub(crate) WhipConfigLoader, WhipConfigPartial => WhipConfig);
+
 /// Resource holding the handle to the loaded [`WhipConfig`].
 #[derive(Resource)]
 pub struct WhipConfigHandle(pub Handle<WhipConfig>);
@@ -116,6 +141,7 @@ WhipConfig(
     damage_per_level: 10.0,
     effect_duration: 0.15,
     spread_factor: 0.6,
+    knockback: 30.0,
 )
 "#;
         let partial: WhipConfigPartial = ron::Options::default()
@@ -128,5 +154,6 @@ WhipConfig(
         assert_eq!(cfg.damage_per_level, 10.0);
         assert_eq!(cfg.effect_duration, 0.15);
         assert_eq!(cfg.spread_factor, 0.6);
+        assert_eq!(cfg.knockback, 30.0);
     }
 }
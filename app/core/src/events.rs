@@ -5,7 +5,10 @@
 
 use bevy::prelude::*;
 
-use crate::types::{EnemyType, WeaponType};
+use crate::{
+    components::StatusEffect,
+    types::{EliteModifier, EnemyType, MiniBossKind, PickupType, WeaponType},
+};
 
 // ---------------------------------------------------------------------------
 // Weapon events
@@ -39,10 +42,18 @@ pub struct WeaponFiredEvent {
 pub struct DamageEnemyEvent {
     /// The enemy entity to damage.
     pub entity: Entity,
-    /// Raw damage amount (before resistances — none implemented yet).
+    /// Raw damage amount, before the target's resistance to the weapon's
+    /// [`DamageType`](crate::types::DamageType).
     pub damage: f32,
-    /// Which weapon type dealt this damage (for future effect routing).
+    /// Which weapon type dealt this damage; determines its damage type.
     pub weapon_type: WeaponType,
+    /// Knockback displacement in pixels (push direction × weapon strength)
+    /// before the target's knockback resistance.  `Vec2::ZERO` = no push and
+    /// no hit-stun.
+    pub knockback: Vec2,
+    /// Status effect inflicted on the target if it survives the hit, merged
+    /// into its [`StatusEffects`](crate::components::StatusEffects).
+    pub status: Option<StatusEffect>,
 }
 
 /// Fired when an enemy's HP reaches zero and it is removed from the world.
@@ -61,6 +72,15 @@ pub struct EnemyDiedEvent {
     /// of death.  Sourced from `enemy.ron` config at enemy spawn time;
     /// consumers do not need a separate config lookup.
     pub xp_value: u32,
+    /// Gold drop probability carried from [`Enemy::gold_chance`], before the
+    /// player's luck is applied.
+    pub gold_chance: f32,
+    /// Modifier of an elite enemy, or `None` for a regular one.  Elites
+    /// always drop gold, and Splitting elites leave copies behind.
+    pub elite: Option<EliteModifier>,
+    /// Archetype of a mini-boss, or `None` for any other enemy.  Decides how
+    /// many items the dropped treasure chest holds.
+    pub mini_boss: Option<MiniBossKind>,
 }
 
 /// Fired when the player takes damage from an enemy or hazard.
@@ -130,3 +150,30 @@ pub struct TreasureOpenedEvent {
     /// World-space position of the chest at the moment it was opened.
     pub position: Vec2,
 }
+
+/// Fired when an attracted XP gem reaches the player and is absorbed.
+///
+/// Emitted by
+/// [`move_attracted_gems`](crate::systems::xp::attraction::move_attracted_gems)
+/// once per gem, after its XP has been credited to
+/// [`GameData`](crate::resources::GameData).
+#[derive(Message, Debug, Clone)]
+pub struct GemCollectedEvent {
+    /// World-space position of the gem at the moment it was absorbed.
+    pub position: Vec2,
+    /// Raw gem value (before the player's XP multiplier).
+    pub value: u32,
+}
+
+/// Fired when the player touches a [`FloorPickup`](crate::components::FloorPickup).
+///
+/// Emitted by
+/// [`collect_floor_pickups`](crate::systems::pickups::effects::collect_floor_pickups);
+/// one effect system per [`PickupType`] reads it and applies the effect.
+#[derive(Message, Debug, Clone)]
+pub struct PickupCollectedEvent {
+    /// Which pickup was collected.
+    pub kind: PickupType,
+    /// World-space position of the pickup when it was collected.
+    pub position: Vec2,
+}
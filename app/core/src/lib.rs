@@ -1,8 +1,10 @@
+pub mod cli;
 pub mod components;
 pub mod config;
 pub mod events;
 pub mod materials;
 pub mod resources;
+pub mod simulation;
 pub mod states;
 pub mod systems;
 pub mod types;
@@ -10,13 +12,16 @@ pub mod types;
 use bevy::prelude::*;
 
 use events::{
-    BossSpawnedEvent, DamageEnemyEvent, EnemyDiedEvent, GameOverEvent, LevelUpEvent,
-    PlayerDamagedEvent, TreasureOpenedEvent, VictoryEvent, WeaponFiredEvent,
+    BossSpawnedEvent, DamageEnemyEvent, EnemyDiedEvent, GameOverEvent, GemCollectedEvent,
+    LevelUpEvent, PickupCollectedEvent, PlayerDamagedEvent, TreasureOpenedEvent, VictoryEvent,
+    WeaponFiredEvent,
 };
 use materials::GlowMaterialPlugin;
 use resources::{
-    EnemySpawner, GameData, GameSettings, LevelUpChoices, MetaProgress, PendingUpgradeIndex,
-    SelectedCharacter, SelectedStage, SpatialGrid, TreasureSpawner,
+    EnemyFreeze, EnemySpawner, FormationScheduler, GameData, GameRng, GameSettings,
+    HostileProjectileGrid, HostileProjectilePool, LevelUpChoices, LightSourceSpawner, MetaProgress,
+    MoveInput, PendingUpgradeIndex, SeedOverride, SelectedCharacter, SelectedStage, SpatialGrid,
+    TreasureSpawner,
 };
 use states::AppState;
 use systems::{
@@ -29,8 +34,11 @@ use systems::{
         accrue_gold_on_game_over, accrue_gold_on_victory, save_meta_on_game_over,
         save_meta_on_shop_exit, save_meta_on_victory, save_settings_on_exit,
     },
+    pickups::PickupPlugin,
     player::PlayerPlugin,
     projectiles::ProjectilesPlugin,
+    replay::ReplayPlugin,
+    rng::GameRngPlugin,
     spatial::SpatialPlugin,
     victory::VictoryPlugin,
     weapons::WeaponsPlugin,
@@ -46,18 +54,34 @@ use systems::{
 ///
 /// [`MetaProgress`] and [`SelectedCharacter`] are intentionally excluded
 /// because they persist across runs.
+///
+/// [`GameRng`] is re-seeded from [`SeedOverride`] (or a fresh random seed
+/// when no override is set) so every run is reproducible from its seed.
+#[allow(clippy::too_many_arguments)]
 fn reset_per_run_resources(
     mut game_data: ResMut<GameData>,
     mut enemy_spawner: ResMut<EnemySpawner>,
+    mut formation_scheduler: ResMut<FormationScheduler>,
     mut treasure_spawner: ResMut<TreasureSpawner>,
+    mut light_source_spawner: ResMut<LightSourceSpawner>,
+    mut enemy_freeze: ResMut<EnemyFreeze>,
+    mut projectile_pool: ResMut<HostileProjectilePool>,
     mut level_up_choices: ResMut<LevelUpChoices>,
     mut pending_upgrade: ResMut<PendingUpgradeIndex>,
+    mut game_rng: ResMut<GameRng>,
+    seed_override: Res<SeedOverride>,
 ) {
     *game_data = GameData::default();
     *enemy_spawner = EnemySpawner::default();
+    *formation_scheduler = FormationScheduler::default();
     *treasure_spawner = TreasureSpawner::default();
+    *light_source_spawner = LightSourceSpawner::default();
+    *enemy_freeze = EnemyFreeze::default();
+    *projectile_pool = HostileProjectilePool::default();
     *level_up_choices = LevelUpChoices::default();
     *pending_upgrade = PendingUpgradeIndex::default();
+    *game_rng = GameRng::from_seed(seed_override.next_seed());
+    info!("Run seed: {}", game_rng.seed());
 }
 
 /// Core game plugin. Registers states, inserts default resources, and wires up
@@ -76,18 +100,26 @@ impl Plugin for GameCorePlugin {
             // ---------------------------------------------------------------
             .insert_resource(GameData::default())
             .insert_resource(EnemySpawner::default())
+            .insert_resource(FormationScheduler::default())
             .insert_resource(TreasureSpawner::default())
+            .insert_resource(LightSourceSpawner::default())
+            .insert_resource(EnemyFreeze::default())
             .insert_resource(SpatialGrid::default())
+            .init_resource::<HostileProjectilePool>()
+            .init_resource::<HostileProjectileGrid>()
             .insert_resource(LevelUpChoices::default())
             .insert_resource(PendingUpgradeIndex::default())
             .insert_resource(SelectedCharacter::default())
             .insert_resource(SelectedStage::default())
+            .insert_resource(GameRng::default())
+            .init_resource::<SeedOverride>()
+            .init_resource::<MoveInput>()
             // ---------------------------------------------------------------
             // Persistent meta-progression (loaded from save/meta.json)
             // ---------------------------------------------------------------
             .insert_resource(MetaProgress::load())
             // ---------------------------------------------------------------
-            // User settings (language, future: volume, etc.)
+            // User settings (language, audio volume)
             // ---------------------------------------------------------------
             .insert_resource(GameSettings::load())
             // ---------------------------------------------------------------
@@ -102,6 +134,8 @@ impl Plugin for GameCorePlugin {
             .add_message::<LevelUpEvent>()
             .add_message::<BossSpawnedEvent>()
             .add_message::<TreasureOpenedEvent>()
+            .add_message::<GemCollectedEvent>()
+            .add_message::<PickupCollectedEvent>()
             // ---------------------------------------------------------------
             // Per-run reset: fires only when a brand-new run begins.
             // Covers both entry paths — Title → Playing (when CharacterSelect
@@ -166,8 +200,11 @@ impl Plugin for GameCorePlugin {
                 WeaponsPlugin,
                 ProjectilesPlugin,
                 XpPlugin,
+                PickupPlugin,
                 GameOverPlugin,
                 VictoryPlugin,
+                ReplayPlugin,
+                GameRngPlugin,
             ));
     }
 }
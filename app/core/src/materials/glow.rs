@@ -21,6 +21,7 @@
 
 use bevy::math::{Vec2, Vec4};
 use bevy::prelude::*;
+use bevy::render::RenderApp;
 use bevy::render::render_resource::{AsBindGroup, ShaderType};
 use bevy::shader::ShaderRef;
 use bevy::sprite_render::{AlphaMode2d, Material2d, Material2dPlugin};
@@ -118,11 +119,17 @@ impl Material2d for GlowMaterial {
 
 /// Registers [`GlowMaterial`] with Bevy's render pipeline.
 ///
-/// Add this to your [`App`] once (via [`GameCorePlugin`]).
+/// Add this to your [`App`] once (via [`GameCorePlugin`]).  Headless apps
+/// without a [`RenderApp`] (e.g. the balance simulator) only get the asset
+/// type, so gameplay code can still create glow materials.
 pub struct GlowMaterialPlugin;
 
 impl Plugin for GlowMaterialPlugin {
     fn build(&self, app: &mut App) {
+        if app.get_sub_app(RenderApp).is_none() {
+            app.init_asset::<GlowMaterial>();
+            return;
+        }
         app.add_plugins(Material2dPlugin::<GlowMaterial>::default());
     }
 }
@@ -3,7 +3,7 @@ use bevy::prelude::*;
 const DEFAULT_XP_LEVEL_BASE: u32 = 20;
 
 /// Global game-session data. Reset at the start of each run.
-#[derive(Resource, Debug)]
+#[derive(Resource, Debug, Clone, PartialEq)]
 pub struct GameData {
     /// Seconds elapsed since the run started (paused during LevelUp/Paused).
     pub elapsed_time: f32,
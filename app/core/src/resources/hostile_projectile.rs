@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+
+use super::SpatialGrid;
+
+/// Idle [`HostileProjectile`](crate::components::HostileProjectile) entities
+/// kept for reuse, so heavy bullet patterns do not spawn and despawn an
+/// entity per shot.
+///
+/// A released projectile is parked in `released` first: its
+/// [`PooledProjectile`](crate::components::PooledProjectile) marker is still a
+/// queued command, so it only becomes available to [`take`](Self::take) after
+/// [`recycle`](Self::recycle) runs on a later frame.  Cleared with the other
+/// per-run resources because the session cleanup despawns parked entities too.
+#[derive(Resource, Debug, Default)]
+pub struct HostileProjectilePool {
+    /// Parked on an earlier frame and ready to fire again.
+    free: Vec<Entity>,
+    /// Parked this frame.
+    released: Vec<Entity>,
+}
+
+impl HostileProjectilePool {
+    /// Number of parked projectiles, ready or not.
+    pub fn len(&self) -> usize {
+        self.free.len() + self.released.len()
+    }
+
+    /// `true` when no projectile is parked.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Parks `entity`; it can be taken again after the next [`recycle`](Self::recycle).
+    pub fn release(&mut self, entity: Entity) {
+        self.released.push(entity);
+    }
+
+    /// Makes every projectile released before this call available to
+    /// [`take`](Self::take).  Call once per frame, before any release.
+    pub fn recycle(&mut self) {
+        self.free.append(&mut self.released);
+    }
+
+    /// Removes and returns a ready projectile, if any.
+    pub fn take(&mut self) -> Option<Entity> {
+        self.free.pop()
+    }
+}
+
+/// Positions of live hostile projectiles, rebuilt every frame so the player
+/// collision check only looks at projectiles in nearby cells.
+#[derive(Resource, Debug, Default)]
+pub struct HostileProjectileGrid {
+    pub grid: SpatialGrid,
+    /// Largest projectile collider radius inserted this frame; widens the
+    /// query so a big projectile centred in a neighbouring cell is not missed.
+    pub max_radius: f32,
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn released_projectile_is_reusable_only_after_recycle() {
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+        let mut pool = HostileProjectilePool::default();
+
+        pool.release(entity);
+        assert_eq!(pool.len(), 1);
+        assert_eq!(pool.take(), None, "marker insert may still be queued");
+
+        pool.recycle();
+        assert_eq!(pool.take(), Some(entity));
+        assert!(pool.is_empty());
+    }
+}
@@ -64,6 +64,19 @@ impl Default for MetaProgress {
 }
 
 impl MetaProgress {
+    /// In-memory progress used by replay playback and the headless simulator.
+    ///
+    /// Carries only the given permanent upgrades (they shape the player's
+    /// base stats) and suppresses autosave so these runs never touch the real
+    /// `save/meta.json`.
+    pub fn in_memory(purchased_upgrades: Vec<MetaUpgradeType>) -> Self {
+        Self {
+            purchased_upgrades,
+            load_failed: true,
+            ..Self::default()
+        }
+    }
+
     /// Load meta-progression from `save/meta.json`.
     ///
     /// Returns `Self::default()` when:
@@ -1,15 +1,21 @@
 pub mod game_data;
+pub mod hostile_projectile;
 pub mod level_up;
 pub mod meta;
 pub mod pending_upgrade;
+pub mod replay;
+pub mod rng;
 pub mod settings;
 pub mod spatial;
 pub mod spawner;
 
 pub use game_data::*;
+pub use hostile_projectile::*;
 pub use level_up::*;
 pub use meta::*;
 pub use pending_upgrade::*;
-pub use settings::{GameSettings, Language};
+pub use replay::*;
+pub use rng::*;
+pub use settings::{GameSettings, Language, VOLUME_STEP, VolumeChannel};
 pub use spatial::*;
 pub use spawner::*;
@@ -0,0 +1,364 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::resources::{GameData, MetaProgress, SeedOverride};
+use crate::types::{CharacterType, MetaUpgradeType, StageType};
+
+/// Directory that finished runs are written to, relative to the working
+/// directory.
+const DEFAULT_REPLAY_DIR: &str = "save/replays";
+
+/// Bumped whenever the on-disk replay layout changes incompatibly.
+pub const REPLAY_FORMAT_VERSION: u32 = 1;
+
+// ---------------------------------------------------------------------------
+// Movement input
+// ---------------------------------------------------------------------------
+
+/// Movement direction requested for the current frame.
+///
+/// Each axis is `-1`, `0`, or `1`.  Filled from the keyboard by
+/// `read_movement_input`, overwritten by the replay player during playback,
+/// and consumed by `player_movement`.  Keeping the input in a resource (rather
+/// than reading [`ButtonInput`] directly) is what makes runs recordable.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MoveInput(pub IVec2);
+
+impl MoveInput {
+    /// Packs the two axes into a single byte in `0..9`.
+    pub fn encode(self) -> u8 {
+        let x = self.0.x.clamp(-1, 1) + 1;
+        let y = self.0.y.clamp(-1, 1) + 1;
+        (x * 3 + y) as u8
+    }
+
+    /// Inverse of [`Self::encode`].  Out-of-range codes decode to no input.
+    pub fn decode(code: u8) -> Self {
+        if code >= 9 {
+            return Self::default();
+        }
+        let code = code as i32;
+        Self(IVec2::new(code / 3 - 1, code % 3 - 1))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Replay file
+// ---------------------------------------------------------------------------
+
+/// A run of consecutive gameplay frames sharing the same input and time step.
+///
+/// Serialised as a `[input, delta_nanos, frames]` triple to keep replay files
+/// small: a player holding one direction at a steady frame rate collapses
+/// into a single entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplaySegment(
+    /// [`MoveInput::encode`]d movement.
+    pub u8,
+    /// Frame delta in nanoseconds.
+    pub u64,
+    /// Number of consecutive frames.
+    pub u32,
+);
+
+/// Final run statistics, compared against the replayed run to detect desyncs.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ReplaySummary {
+    pub elapsed_time: f32,
+    pub current_level: u32,
+    pub kill_count: u32,
+    pub gold_earned: u32,
+}
+
+impl From<&GameData> for ReplaySummary {
+    fn from(data: &GameData) -> Self {
+        Self {
+            elapsed_time: data.elapsed_time,
+            current_level: data.current_level,
+            kill_count: data.kill_count,
+            gold_earned: data.gold_earned,
+        }
+    }
+}
+
+/// Everything needed to reproduce a run frame-for-frame.
+///
+/// Gameplay frames are those where [`crate::states::AppState::Playing`] is
+/// active; menus, pause, and the level-up overlay do not advance the
+/// simulation and are not recorded.  Level-up choices are stored in the
+/// order the level-ups happened (`None` when the overlay was left without
+/// picking a card).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Replay {
+    pub version: u32,
+    pub seed: u64,
+    pub character: CharacterType,
+    pub stage: StageType,
+    /// Permanent upgrades owned when the run started (they change base stats).
+    pub meta_upgrades: Vec<MetaUpgradeType>,
+    pub frames: Vec<ReplaySegment>,
+    pub upgrade_choices: Vec<Option<usize>>,
+    /// Filled in when the run ends; `None` for runs that were abandoned.
+    pub summary: Option<ReplaySummary>,
+}
+
+impl Replay {
+    /// Starts an empty replay for a run with the given parameters.
+    pub fn new(
+        seed: u64,
+        character: CharacterType,
+        stage: StageType,
+        meta_upgrades: Vec<MetaUpgradeType>,
+    ) -> Self {
+        Self {
+            version: REPLAY_FORMAT_VERSION,
+            seed,
+            character,
+            stage,
+            meta_upgrades,
+            frames: Vec::new(),
+            upgrade_choices: Vec::new(),
+            summary: None,
+        }
+    }
+
+    /// Appends one gameplay frame, extending the last segment when both the
+    /// input and the time step match.
+    pub fn push_frame(&mut self, input: MoveInput, delta: Duration) {
+        let code = input.encode();
+        let nanos = delta.as_nanos() as u64;
+        if let Some(last) = self.frames.last_mut()
+            && last.0 == code
+            && last.1 == nanos
+        {
+            last.2 += 1;
+            return;
+        }
+        self.frames.push(ReplaySegment(code, nanos, 1));
+    }
+
+    /// Total number of recorded gameplay frames.
+    pub fn frame_count(&self) -> u64 {
+        self.frames.iter().map(|s| s.2 as u64).sum()
+    }
+
+    /// Reads a replay from `path`.
+    pub fn load_from(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let json = fs::read_to_string(path)?;
+        let replay: Self = serde_json::from_str(&json)?;
+        if replay.version != REPLAY_FORMAT_VERSION {
+            return Err(format!(
+                "unsupported replay version {} (expected {REPLAY_FORMAT_VERSION})",
+                replay.version
+            )
+            .into());
+        }
+        Ok(replay)
+    }
+
+    /// Writes the replay to `save/replays/` and returns the file path.
+    ///
+    /// Logs a warning and returns `None` if the save fails (non-fatal).
+    pub fn save(&self) -> Option<PathBuf> {
+        let secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        let filename = format!("run-{secs}-{}.json", self.seed);
+        match self.try_save_to(Path::new(DEFAULT_REPLAY_DIR), &filename) {
+            Ok(path) => Some(path),
+            Err(e) => {
+                warn!("Failed to save replay: {e}");
+                None
+            }
+        }
+    }
+
+    /// Writes the replay to `{dir}/{filename}` as compact JSON.
+    ///
+    /// Separated from [`Self::save`] so tests can use a temporary directory.
+    pub fn try_save_to(
+        &self,
+        dir: &Path,
+        filename: &str,
+    ) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        fs::create_dir_all(dir)?;
+        let json = serde_json::to_string(self)?;
+        let tmp_path = dir.join(format!("{filename}.tmp"));
+        fs::write(&tmp_path, &json)?;
+        let path = dir.join(filename);
+        fs::rename(&tmp_path, &path)?;
+        Ok(path)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Runtime resources
+// ---------------------------------------------------------------------------
+
+/// Replay being recorded for the current run, if any.
+///
+/// Started when a run begins, saved when it ends in game over or victory,
+/// and discarded when the player quits to the title.  Stays empty while a
+/// replay is being played back.
+#[derive(Resource, Debug, Default)]
+pub struct ReplayRecorder {
+    pub replay: Option<Replay>,
+    /// Skips recording entirely (set by the headless simulator, whose runs
+    /// would otherwise fill `save/replays/`).
+    pub disabled: bool,
+}
+
+/// Replay being played back instead of live input.
+///
+/// Inserted before the app runs (e.g. from the `--replay <path>` flag).  The
+/// replay systems start the recorded run from the title screen, feed the
+/// recorded movement and time step into every gameplay frame, pick the
+/// recorded level-up cards, and compare the final [`GameData`] with the
+/// recorded summary.  The player's own [`MetaProgress`] and [`SeedOverride`]
+/// are put back when playback ends.
+#[derive(Resource, Debug)]
+pub struct ReplayPlayback {
+    pub replay: Replay,
+    /// Segment holding the next gameplay frame to feed.
+    segment: usize,
+    /// Frames of `segment` already fed.
+    offset: u32,
+    /// Index of the next level-up choice to apply.
+    pub upgrade: usize,
+    /// Set once the recorded run has been started from the title screen.
+    pub started: bool,
+    /// Progress replaced by the replay's in-memory one while it plays.
+    pub(crate) saved_meta: Option<MetaProgress>,
+    /// Seed override replaced by the replay's seed while it plays.
+    pub(crate) saved_seed: SeedOverride,
+}
+
+impl ReplayPlayback {
+    pub fn new(replay: Replay) -> Self {
+        Self {
+            replay,
+            segment: 0,
+            offset: 0,
+            upgrade: 0,
+            started: false,
+            saved_meta: None,
+            saved_seed: SeedOverride::default(),
+        }
+    }
+
+    /// Returns the next gameplay frame without consuming it.
+    pub fn peek_frame(&self) -> Option<(MoveInput, Duration)> {
+        self.replay
+            .frames
+            .get(self.segment)
+            .map(|seg| (MoveInput::decode(seg.0), Duration::from_nanos(seg.1)))
+    }
+
+    /// Returns the next gameplay frame and advances past it.
+    pub fn next_frame(&mut self) -> Option<(MoveInput, Duration)> {
+        let frame = self.peek_frame()?;
+        self.offset += 1;
+        if self.offset >= self.replay.frames[self.segment].2 {
+            self.segment += 1;
+            self.offset = 0;
+        }
+        Some(frame)
+    }
+
+    /// Returns the next recorded level-up choice and advances past it.
+    ///
+    /// `None` once every recorded level-up has been replayed.
+    pub fn next_upgrade_choice(&mut self) -> Option<Option<usize>> {
+        let choice = self.replay.upgrade_choices.get(self.upgrade).copied()?;
+        self.upgrade += 1;
+        Some(choice)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn replay() -> Replay {
+        Replay::new(
+            7,
+            CharacterType::DefaultCharacter,
+            StageType::MadForest,
+            vec![],
+        )
+    }
+
+    #[test]
+    fn move_input_round_trips_every_direction() {
+        for x in -1..=1 {
+            for y in -1..=1 {
+                let input = MoveInput(IVec2::new(x, y));
+                assert_eq!(MoveInput::decode(input.encode()), input);
+            }
+        }
+    }
+
+    #[test]
+    fn identical_frames_are_run_length_encoded() {
+        let mut r = replay();
+        let dt = Duration::from_micros(16_667);
+        let right = MoveInput(IVec2::X);
+        for _ in 0..100 {
+            r.push_frame(right, dt);
+        }
+        r.push_frame(MoveInput::default(), dt);
+
+        assert_eq!(r.frames.len(), 2);
+        assert_eq!(r.frame_count(), 101);
+    }
+
+    #[test]
+    fn playback_yields_recorded_frames_in_order() {
+        let mut r = replay();
+        let dt = Duration::from_micros(16_667);
+        r.push_frame(MoveInput(IVec2::X), dt);
+        r.push_frame(MoveInput(IVec2::X), dt);
+        r.push_frame(MoveInput(IVec2::Y), dt * 2);
+        r.upgrade_choices = vec![Some(1)];
+
+        let mut playback = ReplayPlayback::new(r);
+        assert_eq!(playback.next_frame(), Some((MoveInput(IVec2::X), dt)));
+        assert_eq!(playback.next_frame(), Some((MoveInput(IVec2::X), dt)));
+        assert_eq!(playback.peek_frame(), Some((MoveInput(IVec2::Y), dt * 2)));
+        assert_eq!(playback.next_frame(), Some((MoveInput(IVec2::Y), dt * 2)));
+        assert_eq!(playback.next_frame(), None);
+
+        assert_eq!(playback.next_upgrade_choice(), Some(Some(1)));
+        assert_eq!(playback.next_upgrade_choice(), None);
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let dir = TempDir::new().unwrap();
+        let mut r = replay();
+        r.push_frame(MoveInput(IVec2::NEG_Y), Duration::from_millis(16));
+        r.upgrade_choices = vec![Some(2), None];
+        r.summary = Some(ReplaySummary::from(&GameData::default()));
+
+        let path = r.try_save_to(dir.path(), "run.json").unwrap();
+        assert_eq!(Replay::load_from(&path).unwrap(), r);
+    }
+
+    #[test]
+    fn load_rejects_unknown_version() {
+        let dir = TempDir::new().unwrap();
+        let mut r = replay();
+        r.version = REPLAY_FORMAT_VERSION + 1;
+        let path = r.try_save_to(dir.path(), "run.json").unwrap();
+        assert!(Replay::load_from(&path).is_err());
+    }
+}
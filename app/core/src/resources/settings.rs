@@ -15,6 +15,11 @@ const DEFAULT_SAVE_PATH: &str = "save/settings.json";
 /// Directory that contains the settings file.
 const DEFAULT_SAVE_DIR: &str = "save";
 
+/// Volume applied to every channel when no setting has been saved yet.
+const DEFAULT_VOLUME: f32 = 1.0;
+/// Amount one press of a volume stepper changes a level by.
+pub const VOLUME_STEP: f32 = 0.1;
+
 // ---------------------------------------------------------------------------
 // Language
 // ---------------------------------------------------------------------------
@@ -39,6 +44,30 @@ impl Language {
     }
 }
 
+// ---------------------------------------------------------------------------
+// VolumeChannel
+// ---------------------------------------------------------------------------
+
+/// One of the user-adjustable volume levels in [`GameSettings`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VolumeChannel {
+    /// Scales both music and sound effects.
+    Master,
+    /// Background music.
+    Bgm,
+    /// Sound effects.
+    Sfx,
+}
+
+impl VolumeChannel {
+    /// All channels, in settings-screen order.
+    pub const ALL: [VolumeChannel; 3] = [
+        VolumeChannel::Master,
+        VolumeChannel::Bgm,
+        VolumeChannel::Sfx,
+    ];
+}
+
 // ---------------------------------------------------------------------------
 // GameSettings
 // ---------------------------------------------------------------------------
@@ -48,14 +77,77 @@ impl Language {
 /// Loaded from `save/settings.json` at startup via [`GameSettings::load`] and
 /// saved automatically when the player leaves the settings screen via
 /// [`save_settings_on_exit`].
-#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+///
+/// `#[serde(default)]` lets settings files written before a field existed
+/// (e.g. language-only files from older builds) load with defaults for the
+/// missing fields.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct GameSettings {
     /// UI and text language.
     pub language: Language,
+    /// Overall volume in `0.0..=1.0`, multiplied into both channels.
+    pub master_volume: f32,
+    /// Background music volume in `0.0..=1.0`.
+    pub bgm_volume: f32,
+    /// Sound effect volume in `0.0..=1.0`.
+    pub sfx_volume: f32,
+    /// Silences all audio without losing the volume levels.
+    pub muted: bool,
+}
+
+impl Default for GameSettings {
+    fn default() -> Self {
+        Self {
+            language: Language::default(),
+            master_volume: DEFAULT_VOLUME,
+            bgm_volume: DEFAULT_VOLUME,
+            sfx_volume: DEFAULT_VOLUME,
+            muted: false,
+        }
+    }
 }
 
 impl GameSettings {
+    /// Returns the stored level for `channel`.
+    pub fn volume(&self, channel: VolumeChannel) -> f32 {
+        match channel {
+            VolumeChannel::Master => self.master_volume,
+            VolumeChannel::Bgm => self.bgm_volume,
+            VolumeChannel::Sfx => self.sfx_volume,
+        }
+    }
+
+    /// Changes the level for `channel` by `steps` × [`VOLUME_STEP`],
+    /// clamped to `0.0..=1.0` and snapped to the step grid.
+    pub fn step_volume(&mut self, channel: VolumeChannel, steps: i32) {
+        let slot = match channel {
+            VolumeChannel::Master => &mut self.master_volume,
+            VolumeChannel::Bgm => &mut self.bgm_volume,
+            VolumeChannel::Sfx => &mut self.sfx_volume,
+        };
+        let stepped = (*slot / VOLUME_STEP).round() + steps as f32;
+        *slot = (stepped * VOLUME_STEP).clamp(0.0, 1.0);
+    }
+
+    /// Final linear volume for background music (`0.0` when muted).
+    pub fn effective_bgm_volume(&self) -> f32 {
+        self.effective(self.bgm_volume)
+    }
+
+    /// Final linear volume for sound effects (`0.0` when muted).
+    pub fn effective_sfx_volume(&self) -> f32 {
+        self.effective(self.sfx_volume)
+    }
+
+    fn effective(&self, channel_volume: f32) -> f32 {
+        if self.muted {
+            0.0
+        } else {
+            (self.master_volume * channel_volume).clamp(0.0, 1.0)
+        }
+    }
+
     /// Load settings from `save/settings.json`.
     ///
     /// Returns `Self::default()` when:
@@ -134,16 +226,64 @@ mod tests {
     fn game_settings_default() {
         let s = GameSettings::default();
         assert_eq!(s.language, Language::Japanese);
+        assert_eq!(s.master_volume, 1.0);
+        assert_eq!(s.bgm_volume, 1.0);
+        assert_eq!(s.sfx_volume, 1.0);
+        assert!(!s.muted);
     }
 
     #[test]
     fn game_settings_serde_roundtrip() {
         let original = GameSettings {
             language: Language::English,
+            bgm_volume: 0.4,
+            muted: true,
+            ..default()
         };
         let json = serde_json::to_string(&original).unwrap();
         let back: GameSettings = serde_json::from_str(&json).unwrap();
         assert_eq!(back.language, Language::English);
+        assert_eq!(back.bgm_volume, 0.4);
+        assert!(back.muted);
+    }
+
+    /// Settings files saved before volume existed still load, with full volume.
+    #[test]
+    fn language_only_settings_file_loads_with_default_volume() {
+        let back: GameSettings = serde_json::from_str(r#"{ "language": "English" }"#).unwrap();
+        assert_eq!(back.language, Language::English);
+        assert_eq!(back.master_volume, 1.0);
+        assert_eq!(back.sfx_volume, 1.0);
+        assert!(!back.muted);
+    }
+
+    #[test]
+    fn step_volume_clamps_to_unit_range() {
+        let mut s = GameSettings::default();
+        s.step_volume(VolumeChannel::Bgm, 1);
+        assert_eq!(s.bgm_volume, 1.0);
+        for _ in 0..15 {
+            s.step_volume(VolumeChannel::Bgm, -1);
+        }
+        assert_eq!(s.bgm_volume, 0.0);
+        s.step_volume(VolumeChannel::Bgm, 3);
+        assert!((s.bgm_volume - 0.3).abs() < 1e-6);
+        assert_eq!(s.master_volume, 1.0, "other channels are untouched");
+    }
+
+    #[test]
+    fn effective_volume_combines_master_and_mute() {
+        let mut s = GameSettings {
+            master_volume: 0.5,
+            bgm_volume: 0.8,
+            sfx_volume: 0.6,
+            ..default()
+        };
+        assert!((s.effective_bgm_volume() - 0.4).abs() < 1e-6);
+        assert!((s.effective_sfx_volume() - 0.3).abs() < 1e-6);
+        s.muted = true;
+        assert_eq!(s.effective_bgm_volume(), 0.0);
+        assert_eq!(s.effective_sfx_volume(), 0.0);
     }
 
     // -----------------------------------------------------------------------
@@ -172,6 +312,7 @@ mod tests {
         let dir = TempDir::new().unwrap();
         let original = GameSettings {
             language: Language::English,
+            ..default()
         };
         original.save_to(dir.path(), "settings.json");
         let path = dir.path().join("settings.json");
@@ -193,6 +334,7 @@ mod tests {
         let dir = TempDir::new().unwrap();
         let s = GameSettings {
             language: Language::English,
+            ..default()
         };
         s.save_to(dir.path(), "settings.json");
         let content = fs::read_to_string(dir.path().join("settings.json")).unwrap();
@@ -205,10 +347,12 @@ mod tests {
         let dir = TempDir::new().unwrap();
         GameSettings {
             language: Language::Japanese,
+            ..default()
         }
         .save_to(dir.path(), "settings.json");
         GameSettings {
             language: Language::English,
+            ..default()
         }
         .save_to(dir.path(), "settings.json");
         let path = dir.path().join("settings.json");
@@ -29,16 +29,77 @@ impl Default for EnemySpawner {
 /// Controls treasure chest spawn timing.
 #[derive(Resource, Debug)]
 pub struct TreasureSpawner {
-    /// Accumulated time since the last chest spawn.
+    /// Accumulated time since the last chest spawn.  Only used when no stage
+    /// schedule is loaded.
     pub spawn_timer: f32,
+    /// Run time (seconds) up to which the stage's `mini_bosses` have been
+    /// fired.  Events with `at_min × 60` in `(last_checked_secs, now]` fire
+    /// next.
+    pub last_checked_secs: f32,
+    /// Mini-bosses spawned this run; picks the next archetype when spawning
+    /// on the interval fallback.
+    pub spawned: u32,
 }
 
 impl Default for TreasureSpawner {
     fn default() -> Self {
-        Self { spawn_timer: 0.0 }
+        // Negative so an event scheduled at minute 0 still fires.
+        Self {
+            spawn_timer: 0.0,
+            last_checked_secs: -1.0,
+            spawned: 0,
+        }
+    }
+}
+
+/// Tracks which scheduled formation events have already fired this run.
+#[derive(Resource, Debug)]
+pub struct FormationScheduler {
+    /// Run time (seconds) up to which formation events have been fired.
+    /// Events with `at_min × 60` in `(last_checked_secs, now]` fire next.
+    pub last_checked_secs: f32,
+}
+
+impl Default for FormationScheduler {
+    fn default() -> Self {
+        // Negative so an event scheduled at minute 0 still fires.
+        Self {
+            last_checked_secs: -1.0,
+        }
     }
 }
 
+/// Controls light-source prop spawn timing.
+#[derive(Resource, Debug, Default)]
+pub struct LightSourceSpawner {
+    /// Accumulated time since the last light-source spawn.
+    pub spawn_timer: f32,
+}
+
+/// Remaining orologion freeze time.
+///
+/// While [`remaining`](EnemyFreeze::remaining) is positive, enemy movement
+/// and attack systems are skipped via the [`enemies_active`] run condition.
+#[derive(Resource, Debug, Default)]
+pub struct EnemyFreeze {
+    /// Seconds until enemies resume (0.0 = not frozen).
+    pub remaining: f32,
+}
+
+impl EnemyFreeze {
+    pub fn is_active(&self) -> bool {
+        self.remaining > 0.0
+    }
+}
+
+/// Run condition: `true` unless an orologion freeze is in effect.
+///
+/// Treats a missing [`EnemyFreeze`] resource as "not frozen" so enemy
+/// systems still run in tests and tools that do not insert it.
+pub fn enemies_active(freeze: Option<Res<EnemyFreeze>>) -> bool {
+    freeze.is_none_or(|f| !f.is_active())
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
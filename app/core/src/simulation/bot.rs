@@ -0,0 +1,274 @@
+use std::collections::HashMap;
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::types::{PassiveItemType, UpgradeChoice, WeaponState, WeaponType};
+
+/// Direction components smaller than `sin(22.5°)` round to zero, so a unit
+/// vector quantises to the nearest of the eight keyboard directions.
+const AXIS_DEADZONE: f32 = 0.383;
+
+/// Seconds per lap for [`CircleKite`].
+const DEFAULT_KITE_PERIOD: f32 = 8.0;
+
+/// Distance within which [`Evade`] reacts to enemies (pixels).
+const DEFAULT_EVADE_RADIUS: f32 = 250.0;
+
+// ---------------------------------------------------------------------------
+// Policy trait
+// ---------------------------------------------------------------------------
+
+/// What a [`BotPolicy`] can see when deciding.
+pub struct BotContext<'a> {
+    /// Simulated seconds since the run started.
+    pub elapsed_time: f32,
+    pub player_position: Vec2,
+    /// `current_hp / max_hp`.
+    pub hp_fraction: f32,
+    /// World positions of every live enemy.
+    pub enemies: &'a [Vec2],
+    pub weapons: &'a [WeaponState],
+    /// Damage dealt so far this run, per weapon.
+    pub damage_by_weapon: &'a HashMap<WeaponType, f32>,
+}
+
+/// Decision-making for a simulated player.
+///
+/// Implementations choose a movement direction every gameplay frame and a
+/// card on every level-up.  The default card choice is
+/// [`highest_damage_choice`].
+pub trait BotPolicy: Send + Sync + 'static {
+    /// Short identifier written to the report.
+    fn name(&self) -> &str;
+
+    /// Movement for this frame; each axis is `-1`, `0`, or `1`.
+    fn movement(&mut self, ctx: &BotContext) -> IVec2;
+
+    /// Index into `choices` of the card to take, or `None` to skip.
+    fn pick_upgrade(&mut self, choices: &[UpgradeChoice], ctx: &BotContext) -> Option<usize> {
+        highest_damage_choice(choices, ctx)
+    }
+}
+
+/// Returns the built-in policy called `name` (`kite`, `evade`, or `idle`).
+pub fn policy_from_name(name: &str) -> Option<Box<dyn BotPolicy>> {
+    match name {
+        "kite" => Some(Box::new(CircleKite::default())),
+        "evade" => Some(Box::new(Evade::default())),
+        "idle" => Some(Box::new(Idle)),
+        _ => None,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Built-in policies
+// ---------------------------------------------------------------------------
+
+/// Runs in a wide circle, completing one lap every `period` seconds.
+///
+/// Approximates how most players kite: constantly moving so the horde trails
+/// behind while gems along the path are collected.
+pub struct CircleKite {
+    pub period: f32,
+}
+
+impl Default for CircleKite {
+    fn default() -> Self {
+        Self {
+            period: DEFAULT_KITE_PERIOD,
+        }
+    }
+}
+
+impl BotPolicy for CircleKite {
+    fn name(&self) -> &str {
+        "kite"
+    }
+
+    fn movement(&mut self, ctx: &BotContext) -> IVec2 {
+        let angle = ctx.elapsed_time / self.period.max(f32::EPSILON) * TAU;
+        quantize_direction(Vec2::from_angle(angle))
+    }
+}
+
+/// Moves away from nearby enemies, weighting closer ones more heavily.
+///
+/// Falls back to [`CircleKite`] when nothing is within `radius`, so the bot
+/// keeps collecting gems instead of standing still.
+pub struct Evade {
+    pub radius: f32,
+    pub idle: CircleKite,
+}
+
+impl Default for Evade {
+    fn default() -> Self {
+        Self {
+            radius: DEFAULT_EVADE_RADIUS,
+            idle: CircleKite::default(),
+        }
+    }
+}
+
+impl BotPolicy for Evade {
+    fn name(&self) -> &str {
+        "evade"
+    }
+
+    fn movement(&mut self, ctx: &BotContext) -> IVec2 {
+        let radius_sq = self.radius * self.radius;
+        let away: Vec2 = ctx
+            .enemies
+            .iter()
+            .map(|&pos| ctx.player_position - pos)
+            .filter(|offset| offset.length_squared() < radius_sq)
+            .map(|offset| offset / offset.length_squared().max(1.0))
+            .sum();
+        if away == Vec2::ZERO {
+            return self.idle.movement(ctx);
+        }
+        quantize_direction(away)
+    }
+}
+
+/// Never moves.  Useful as a worst-case baseline for weapon damage.
+pub struct Idle;
+
+impl BotPolicy for Idle {
+    fn name(&self) -> &str {
+        "idle"
+    }
+
+    fn movement(&mut self, _ctx: &BotContext) -> IVec2 {
+        IVec2::ZERO
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Snaps `direction` to the nearest of the eight keyboard directions.
+pub fn quantize_direction(direction: Vec2) -> IVec2 {
+    let dir = direction.normalize_or_zero();
+    let axis = |v: f32| {
+        if v > AXIS_DEADZONE {
+            1
+        } else if v < -AXIS_DEADZONE {
+            -1
+        } else {
+            0
+        }
+    };
+    IVec2::new(axis(dir.x), axis(dir.y))
+}
+
+/// Picks the card expected to add the most damage.
+///
+/// Preference order:
+/// 1. Upgrading the owned weapon that has dealt the most damage so far.
+/// 2. A new weapon.
+/// 3. Spinach (the damage passive), new or upgraded.
+/// 4. Any other passive.
+pub fn highest_damage_choice(choices: &[UpgradeChoice], ctx: &BotContext) -> Option<usize> {
+    let score = |choice: &UpgradeChoice| -> (u8, f32) {
+        match *choice {
+            UpgradeChoice::WeaponUpgrade(weapon) => {
+                (3, ctx.damage_by_weapon.get(&weapon).copied().unwrap_or(0.0))
+            }
+            UpgradeChoice::NewWeapon(_) => (2, 0.0),
+            UpgradeChoice::PassiveItem(PassiveItemType::Spinach)
+            | UpgradeChoice::PassiveUpgrade(PassiveItemType::Spinach) => (1, 0.0),
+            UpgradeChoice::PassiveItem(_) | UpgradeChoice::PassiveUpgrade(_) => (0, 0.0),
+        }
+    };
+    choices
+        .iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| {
+            let (tier_a, dmg_a) = score(a);
+            let (tier_b, dmg_b) = score(b);
+            tier_a.cmp(&tier_b).then(dmg_a.total_cmp(&dmg_b))
+        })
+        .map(|(i, _)| i)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctx<'a>(
+        enemies: &'a [Vec2],
+        damage_by_weapon: &'a HashMap<WeaponType, f32>,
+    ) -> BotContext<'a> {
+        BotContext {
+            elapsed_time: 0.0,
+            player_position: Vec2::ZERO,
+            hp_fraction: 1.0,
+            enemies,
+            weapons: &[],
+            damage_by_weapon,
+        }
+    }
+
+    #[test]
+    fn quantize_snaps_to_eight_directions() {
+        assert_eq!(quantize_direction(Vec2::X), IVec2::X);
+        assert_eq!(quantize_direction(Vec2::new(1.0, 1.0)), IVec2::new(1, 1));
+        assert_eq!(quantize_direction(Vec2::new(-1.0, 0.1)), IVec2::NEG_X);
+        assert_eq!(quantize_direction(Vec2::ZERO), IVec2::ZERO);
+    }
+
+    #[test]
+    fn circle_kite_changes_heading_over_a_lap() {
+        let damage = HashMap::new();
+        let mut bot = CircleKite { period: 4.0 };
+        let mut c = ctx(&[], &damage);
+        let start = bot.movement(&c);
+        c.elapsed_time = 2.0;
+        assert_eq!(bot.movement(&c), -start);
+    }
+
+    #[test]
+    fn evade_moves_away_from_nearest_enemy() {
+        let damage = HashMap::new();
+        let enemies = [Vec2::new(50.0, 0.0)];
+        let mut bot = Evade::default();
+        assert_eq!(bot.movement(&ctx(&enemies, &damage)), IVec2::NEG_X);
+    }
+
+    #[test]
+    fn highest_damage_prefers_best_weapon_upgrade() {
+        let damage = HashMap::from([(WeaponType::Whip, 100.0), (WeaponType::Knife, 500.0)]);
+        let choices = [
+            UpgradeChoice::PassiveItem(PassiveItemType::Spinach),
+            UpgradeChoice::WeaponUpgrade(WeaponType::Whip),
+            UpgradeChoice::NewWeapon(WeaponType::Garlic),
+            UpgradeChoice::WeaponUpgrade(WeaponType::Knife),
+        ];
+        assert_eq!(highest_damage_choice(&choices, &ctx(&[], &damage)), Some(3));
+    }
+
+    #[test]
+    fn highest_damage_prefers_spinach_among_passives() {
+        let damage = HashMap::new();
+        let choices = [
+            UpgradeChoice::PassiveItem(PassiveItemType::Wings),
+            UpgradeChoice::PassiveUpgrade(PassiveItemType::Spinach),
+        ];
+        assert_eq!(highest_damage_choice(&choices, &ctx(&[], &damage)), Some(1));
+        assert_eq!(highest_damage_choice(&[], &ctx(&[], &damage)), None);
+    }
+
+    #[test]
+    fn policies_are_found_by_name() {
+        for name in ["kite", "evade", "idle"] {
+            assert_eq!(policy_from_name(name).unwrap().name(), name);
+        }
+        assert!(policy_from_name("unknown").is_none());
+    }
+}
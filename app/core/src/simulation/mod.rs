@@ -0,0 +1,788 @@
+//! Headless run simulator for balance testing.
+//!
+//! Builds [`GameCorePlugin`] + [`GameConfigPlugin`] on [`MinimalPlugins`]
+//! (no window, renderer, audio, or UI), loads the RON configs from disk, and
+//! plays a run with a [`BotPolicy`] standing in for the player.  Time advances
+//! by a fixed step via [`TimeUpdateStrategy::ManualDuration`] and the app is
+//! updated in a tight loop, so a 30-minute run finishes in seconds.
+//!
+//! The run is sampled every [`SimSettings::sample_interval`] simulated
+//! seconds into a [`SimReport`] (level curve, kills, HP, inventory), which
+//! the `vs-sim` binary writes out as JSON.  [`run_sweep`] repeats this over
+//! a character × stage × seed matrix for the `vs-sweep` binary.
+//!
+//! ```ignore
+//! let report = run_simulation(&SimSettings::default(), Box::new(CircleKite::default()))?;
+//! println!("{}", serde_json::to_string_pretty(&report)?);
+//! ```
+
+pub mod bot;
+pub mod report;
+pub mod sweep;
+
+pub use bot::*;
+pub use report::*;
+pub use sweep::*;
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use bevy::asset::AssetPlugin;
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::text::FontLoader;
+use bevy::time::TimeUpdateStrategy;
+
+use crate::{
+    GameCorePlugin,
+    components::{Enemy, PassiveInventory, Player, PlayerStats, WeaponInventory},
+    config::{ConfigLoadErrors, GameConfigPlugin, GameParams},
+    events::DamageEnemyEvent,
+    resources::{
+        GameData, LevelUpChoices, MetaProgress, MoveInput, PendingUpgradeIndex, ReplayRecorder,
+        SeedOverride, SelectedCharacter, SelectedStage,
+    },
+    states::AppState,
+    types::{CharacterType, MetaUpgradeType, StageType, WeaponType},
+};
+
+/// Asset directory used when none is given, relative to the `vs-core`
+/// manifest (Bevy resolves it against `CARGO_MANIFEST_DIR` under `cargo run`).
+pub const DEFAULT_SIM_ASSETS_DIR: &str = "../vampire-survivors/assets";
+
+/// Default fixed simulation step (60 updates per simulated second).
+const DEFAULT_SIM_TIMESTEP: Duration = Duration::from_nanos(16_666_667);
+
+/// Default number of simulated minutes (a full stage).
+const DEFAULT_SIM_MINUTES: f32 = 30.0;
+
+/// Default simulated minutes the boss fight may last once the boss is up.
+const DEFAULT_BOSS_FIGHT_MINUTES: f32 = 5.0;
+
+/// Default interval between report samples, in simulated seconds.
+const DEFAULT_SAMPLE_INTERVAL: f32 = 10.0;
+
+/// Real time allowed for the RON configs to load before giving up.
+const LOADING_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Updates allowed per simulated timestep the run can last, leaving room
+/// for frames spent outside [`AppState::Playing`] (level-up picks).
+const RUN_UPDATE_MARGIN: f32 = 2.0;
+
+// ---------------------------------------------------------------------------
+// Settings
+// ---------------------------------------------------------------------------
+
+/// Parameters of one simulated run.
+#[derive(Debug, Clone)]
+pub struct SimSettings {
+    pub seed: u64,
+    pub character: CharacterType,
+    pub stage: StageType,
+    /// Permanent upgrades the simulated player owns.
+    pub meta_upgrades: Vec<MetaUpgradeType>,
+    /// Simulated minutes after which the run is stopped, unless the boss
+    /// has spawned by then.
+    pub minutes: f32,
+    /// Simulated minutes the boss fight may last, counted from the boss
+    /// spawn, before the run is stopped.
+    pub boss_fight_minutes: f32,
+    pub timestep: Duration,
+    /// Simulated seconds between report samples.
+    pub sample_interval: f32,
+    /// Directory holding `config/*.ron` (and the UI font the loader waits on).
+    pub assets_dir: String,
+}
+
+impl Default for SimSettings {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            character: CharacterType::DefaultCharacter,
+            stage: StageType::default(),
+            meta_upgrades: Vec::new(),
+            minutes: DEFAULT_SIM_MINUTES,
+            boss_fight_minutes: DEFAULT_BOSS_FIGHT_MINUTES,
+            timestep: DEFAULT_SIM_TIMESTEP,
+            sample_interval: DEFAULT_SAMPLE_INTERVAL,
+            assets_dir: DEFAULT_SIM_ASSETS_DIR.to_string(),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Resources
+// ---------------------------------------------------------------------------
+
+/// The policy playing the simulated run.
+#[derive(Resource)]
+pub struct SimBot(pub Box<dyn BotPolicy>);
+
+/// Run parameters the simulation systems need at runtime.
+#[derive(Resource, Debug, Clone)]
+struct SimRun(SimSettings);
+
+/// Report data accumulated while the simulated run plays.
+#[derive(Resource, Debug, Default)]
+pub struct SimRecorder {
+    pub samples: Vec<SimSample>,
+    pub level_ups: Vec<LevelUpPick>,
+    pub damage_by_weapon: HashMap<WeaponType, f32>,
+    /// Simulated time at which the next sample is due.
+    next_sample_at: f32,
+    /// Simulated time at which the boss spawned.
+    boss_spawned_at: Option<f32>,
+    /// Set once the run ends in game over or victory.
+    pub outcome: Option<SimOutcome>,
+}
+
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+/// Drives a headless run: starts it from the title screen, feeds the bot's
+/// movement and card picks, and records the [`SimRecorder`] samples.
+///
+/// Added by [`build_simulation_app`]; expects [`SimBot`] to be inserted.
+pub struct SimulationPlugin {
+    pub settings: SimSettings,
+}
+
+impl Plugin for SimulationPlugin {
+    fn build(&self, app: &mut App) {
+        use crate::systems::enemies::boss_spawn::check_boss_spawn;
+        use crate::systems::player::{despawn_game_session, player_movement, read_movement_input};
+        use crate::systems::replay::record_replay_frame;
+        use crate::systems::xp::choices::generate_level_up_choices;
+
+        app.insert_resource(SimRun(self.settings.clone()))
+            .init_resource::<SimRecorder>()
+            .insert_resource(ReplayRecorder {
+                disabled: true,
+                ..default()
+            })
+            .insert_resource(TimeUpdateStrategy::ManualDuration(self.settings.timestep))
+            // Headless stand-in for the keyboard so `read_movement_input`
+            // still runs; the bot overwrites its result every frame.
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_systems(OnEnter(AppState::Title), start_simulated_run)
+            .add_systems(
+                OnEnter(AppState::LevelUp),
+                pick_bot_upgrade.after(generate_level_up_choices),
+            )
+            .add_systems(
+                OnEnter(AppState::GameOver),
+                finish_simulated_run(SimOutcome::Died).before(despawn_game_session),
+            )
+            .add_systems(
+                OnEnter(AppState::Victory),
+                finish_simulated_run(SimOutcome::Victory).before(despawn_game_session),
+            )
+            .add_systems(
+                Update,
+                (
+                    // Before the recorder so a recorded run captures the
+                    // bot's movement.
+                    drive_bot
+                        .after(read_movement_input)
+                        .before(record_replay_frame)
+                        .before(player_movement),
+                    track_weapon_damage,
+                    sample_run.after(follow_player_with_camera),
+                    enforce_time_limit.after(check_boss_spawn),
+                )
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
+
+/// Builds a headless app that simulates one run with `bot`.
+pub fn build_simulation_app(settings: &SimSettings, bot: Box<dyn BotPolicy>) -> App {
+    let mut app = build_headless_app(&settings.assets_dir);
+    app.add_plugins(SimulationPlugin {
+        settings: settings.clone(),
+    })
+    .insert_resource(SimBot(bot));
+    app
+}
+
+/// Builds the game without window, renderer, audio, or UI, loading configs
+/// from `assets_dir`, with a camera that follows the player.
+///
+/// Only the asset types gameplay code touches (configs, font, meshes,
+/// materials) are registered; nothing is rendered.
+fn build_headless_app(assets_dir: &str) -> App {
+    use crate::systems::player::player_movement;
+
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        StatesPlugin,
+        AssetPlugin {
+            file_path: assets_dir.to_string(),
+            watch_for_changes_override: Some(false),
+            ..default()
+        },
+    ))
+    .init_asset::<Font>()
+    .init_asset_loader::<FontLoader>()
+    .init_asset::<Mesh>()
+    .init_asset::<ColorMaterial>()
+    .add_plugins(GameConfigPlugin)
+    .add_plugins(GameCorePlugin)
+    .add_systems(Startup, spawn_sim_camera)
+    .add_systems(
+        Update,
+        follow_player_with_camera
+            .after(player_movement)
+            .run_if(in_state(AppState::Playing)),
+    );
+    app
+}
+
+/// Simulates one run and returns its report.
+///
+/// Fails when the configs cannot be loaded from `settings.assets_dir`, when
+/// one of them is invalid, or when the run does not finish within twice
+/// the updates its time limit and boss fight allow.
+pub fn run_simulation(
+    settings: &SimSettings,
+    bot: Box<dyn BotPolicy>,
+) -> Result<SimReport, String> {
+    let policy = bot.name().to_string();
+    let mut app = build_simulation_app(settings, bot);
+    app.finish();
+    app.cleanup();
+
+    let started = Instant::now();
+    wait_for_configs(&mut app, settings)?;
+    let boss_spawn_time = SystemState::<GameParams>::new(app.world_mut())
+        .get(app.world())
+        .boss_spawn_time();
+    run_until_finished(&mut app, max_run_updates(settings, boss_spawn_time))?;
+
+    let elapsed_time = app.world().resource::<GameData>().elapsed_time;
+    let recorder = std::mem::take(&mut *app.world_mut().resource_mut::<SimRecorder>());
+    let mut damage_by_weapon: Vec<WeaponDamage> = recorder
+        .damage_by_weapon
+        .into_iter()
+        .map(|(weapon, damage)| WeaponDamage { weapon, damage })
+        .collect();
+    damage_by_weapon.sort_by(|a, b| b.damage.total_cmp(&a.damage));
+
+    Ok(SimReport {
+        seed: settings.seed,
+        character: settings.character,
+        stage: settings.stage,
+        policy,
+        timestep: settings.timestep.as_secs_f32(),
+        outcome: recorder.outcome.unwrap_or(SimOutcome::TimeLimit),
+        elapsed_time,
+        samples: recorder.samples,
+        level_ups: recorder.level_ups,
+        damage_by_weapon,
+        wall_time: started.elapsed().as_secs_f32(),
+    })
+}
+
+/// Updates `app` until the RON configs have loaded and the run has left
+/// [`AppState::Loading`].
+///
+/// Fails when a config is invalid or loading takes longer than
+/// [`LOADING_TIMEOUT`].
+fn wait_for_configs(app: &mut App, settings: &SimSettings) -> Result<(), String> {
+    let started = Instant::now();
+    loop {
+        app.update();
+
+        if *app.world().resource::<State<AppState>>().get() != AppState::Loading {
+            return Ok(());
+        }
+        let errors = &app.world().resource::<ConfigLoadErrors>().0;
+        if !errors.is_empty() {
+            return Err(format!(
+                "invalid configs in {}:\n{}",
+                settings.assets_dir,
+                errors.join("\n")
+            ));
+        }
+        if started.elapsed() > LOADING_TIMEOUT {
+            return Err(format!(
+                "configs did not load from {} within {}s",
+                settings.assets_dir,
+                LOADING_TIMEOUT.as_secs()
+            ));
+        }
+        // Configs load on the IO task pool; don't spin while waiting.
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
+
+/// Upper bound on the updates one run may take: the longest it can last in
+/// simulated time (the time limit or the boss spawn, plus the boss fight),
+/// in timesteps, times [`RUN_UPDATE_MARGIN`].
+fn max_run_updates(settings: &SimSettings, boss_spawn_time: f32) -> u64 {
+    let max_secs =
+        (settings.minutes * 60.0).max(boss_spawn_time) + settings.boss_fight_minutes * 60.0;
+    (max_secs / settings.timestep.as_secs_f32() * RUN_UPDATE_MARGIN).ceil() as u64
+}
+
+/// Updates `app` until the run records an outcome.
+///
+/// Fails after `max_updates` updates, so a run whose clock stopped (e.g.
+/// stuck outside [`AppState::Playing`]) cannot hang the caller.
+fn run_until_finished(app: &mut App, max_updates: u64) -> Result<(), String> {
+    for _ in 0..max_updates {
+        if app.world().resource::<SimRecorder>().outcome.is_some() {
+            return Ok(());
+        }
+        app.update();
+    }
+    if app.world().resource::<SimRecorder>().outcome.is_some() {
+        return Ok(());
+    }
+    Err(format!(
+        "run did not finish within {max_updates} updates (stuck at {:.0}s in {:?})",
+        app.world().resource::<GameData>().elapsed_time,
+        app.world().resource::<State<AppState>>().get()
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Spawns the camera enemy spawning is centred on (never rendered).
+fn spawn_sim_camera(mut commands: Commands) {
+    commands.spawn((Camera2d, Transform::default()));
+}
+
+/// Starts the simulated run as soon as the title screen is reached.
+///
+/// Mirrors `start_replay_playback`: the seed, character, stage, and
+/// permanent upgrades are applied (in an in-memory [`MetaProgress`] that is
+/// never saved) and the menus are skipped.
+fn start_simulated_run(
+    mut commands: Commands,
+    run: Res<SimRun>,
+    mut seed_override: ResMut<SeedOverride>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    let settings = &run.0;
+    seed_override.0 = Some(settings.seed);
+    commands.insert_resource(SelectedCharacter(settings.character));
+    commands.insert_resource(SelectedStage(settings.stage));
+    commands.insert_resource(MetaProgress::in_memory(settings.meta_upgrades.clone()));
+    next_state.set(AppState::Playing);
+}
+
+/// Replaces this frame's [`MoveInput`] with the bot's movement.
+fn drive_bot(
+    mut bot: ResMut<SimBot>,
+    mut input: ResMut<MoveInput>,
+    game_data: Res<GameData>,
+    recorder: Res<SimRecorder>,
+    player_q: Query<(&Transform, &PlayerStats, &WeaponInventory), With<Player>>,
+    enemy_q: Query<&Transform, With<Enemy>>,
+) {
+    let Ok((transform, stats, inventory)) = player_q.single() else {
+        return;
+    };
+    let enemies: Vec<Vec2> = enemy_q.iter().map(|t| t.translation.truncate()).collect();
+    let ctx = BotContext {
+        elapsed_time: game_data.elapsed_time,
+        player_position: transform.translation.truncate(),
+        hp_fraction: stats.current_hp / stats.max_hp.max(f32::EPSILON),
+        enemies: &enemies,
+        weapons: &inventory.weapons,
+        damage_by_weapon: &recorder.damage_by_weapon,
+    };
+    input.0 = bot.0.movement(&ctx);
+}
+
+/// Resolves the level-up overlay with the bot's card.
+fn pick_bot_upgrade(
+    mut bot: ResMut<SimBot>,
+    mut recorder: ResMut<SimRecorder>,
+    choices: Res<LevelUpChoices>,
+    game_data: Res<GameData>,
+    player_q: Query<(&Transform, &PlayerStats, &WeaponInventory), With<Player>>,
+    mut pending: ResMut<PendingUpgradeIndex>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    let (position, hp_fraction, weapons) = player_q
+        .single()
+        .map(|(t, stats, inv)| {
+            (
+                t.translation.truncate(),
+                stats.current_hp / stats.max_hp.max(f32::EPSILON),
+                inv.weapons.as_slice(),
+            )
+        })
+        .unwrap_or((Vec2::ZERO, 1.0, &[][..]));
+    let ctx = BotContext {
+        elapsed_time: game_data.elapsed_time,
+        player_position: position,
+        hp_fraction,
+        enemies: &[],
+        weapons,
+        damage_by_weapon: &recorder.damage_by_weapon,
+    };
+    let picked = bot.0.pick_upgrade(&choices.choices, &ctx);
+
+    recorder.level_ups.push(LevelUpPick {
+        elapsed_time: game_data.elapsed_time,
+        level: game_data.current_level,
+        choices: choices.choices.clone(),
+        picked,
+    });
+    pending.0 = picked;
+    next_state.set(AppState::Playing);
+}
+
+/// Keeps the camera on the player so enemies spawn around them, as in the
+/// real game.
+fn follow_player_with_camera(
+    player_q: Query<&Transform, With<Player>>,
+    mut camera_q: Query<&mut Transform, (With<Camera2d>, Without<Player>)>,
+) {
+    let (Ok(player), Ok(mut camera)) = (player_q.single(), camera_q.single_mut()) else {
+        return;
+    };
+    camera.translation.x = player.translation.x;
+    camera.translation.y = player.translation.y;
+}
+
+/// Sums damage dealt per weapon.
+fn track_weapon_damage(
+    mut events: MessageReader<DamageEnemyEvent>,
+    mut recorder: ResMut<SimRecorder>,
+) {
+    for event in events.read() {
+        *recorder
+            .damage_by_weapon
+            .entry(event.weapon_type)
+            .or_default() += event.damage;
+    }
+}
+
+/// Records a sample whenever `sample_interval` simulated seconds have passed.
+fn sample_run(world: &mut World) {
+    let elapsed = world.resource::<GameData>().elapsed_time;
+    if elapsed < world.resource::<SimRecorder>().next_sample_at {
+        return;
+    }
+    let interval = world
+        .resource::<SimRun>()
+        .0
+        .sample_interval
+        .max(f32::EPSILON);
+    world.resource_mut::<SimRecorder>().next_sample_at = elapsed + interval;
+    if let Err(e) = world.run_system_cached(take_sample) {
+        warn!("Failed to sample simulated run: {e}");
+    }
+}
+
+/// Appends a snapshot of the run to [`SimRecorder::samples`].
+fn take_sample(
+    mut recorder: ResMut<SimRecorder>,
+    game_data: Res<GameData>,
+    player_q: Query<(&PlayerStats, &WeaponInventory, &PassiveInventory), With<Player>>,
+    enemy_q: Query<(), With<Enemy>>,
+) {
+    let (hp, max_hp, weapons, passives) = match player_q.single() {
+        Ok((stats, weapons, passives)) => (
+            stats.current_hp.max(0.0),
+            stats.max_hp,
+            weapons
+                .weapons
+                .iter()
+                .map(|w| WeaponSample {
+                    weapon: w.weapon_type,
+                    level: w.level,
+                    evolved: w.evolved,
+                })
+                .collect(),
+            passives
+                .items
+                .iter()
+                .map(|p| PassiveSample {
+                    item: p.item_type,
+                    level: p.level,
+                })
+                .collect(),
+        ),
+        Err(_) => (0.0, 0.0, Vec::new(), Vec::new()),
+    };
+    recorder.samples.push(SimSample {
+        elapsed_time: game_data.elapsed_time,
+        level: game_data.current_level,
+        kill_count: game_data.kill_count,
+        gold_earned: game_data.gold_earned,
+        hp,
+        max_hp,
+        enemy_count: enemy_q.iter().count(),
+        weapons,
+        passives,
+    });
+}
+
+/// Stops the run with [`SimOutcome::TimeLimit`] once
+/// [`SimSettings::minutes`] have passed.
+///
+/// Once the boss has spawned the limit no longer applies, so a full-length
+/// run still gets to fight it; the fight is instead bounded by
+/// [`SimSettings::boss_fight_minutes`] from the boss spawn.
+fn enforce_time_limit(world: &mut World) {
+    let game_data = world.resource::<GameData>();
+    let (elapsed, boss_spawned) = (game_data.elapsed_time, game_data.boss_spawned);
+    let settings = &world.resource::<SimRun>().0;
+    let (limit, boss_fight) = (settings.minutes * 60.0, settings.boss_fight_minutes * 60.0);
+
+    let mut recorder = world.resource_mut::<SimRecorder>();
+    if recorder.outcome.is_some() {
+        return;
+    }
+    let deadline = if boss_spawned {
+        *recorder.boss_spawned_at.get_or_insert(elapsed) + boss_fight
+    } else {
+        limit
+    };
+    if elapsed < deadline {
+        return;
+    }
+
+    if let Err(e) = world.run_system_cached(take_sample) {
+        warn!("Failed to sample simulated run: {e}");
+    }
+    world.resource_mut::<SimRecorder>().outcome = Some(SimOutcome::TimeLimit);
+}
+
+/// Returns a system that takes a final sample and records how the run ended.
+///
+/// Ordered before `despawn_game_session` so the final sample still sees
+/// the player's inventory.
+fn finish_simulated_run(outcome: SimOutcome) -> impl FnMut(&mut World) + Send + Sync + 'static {
+    move |world: &mut World| {
+        if let Err(e) = world.run_system_cached(take_sample) {
+            warn!("Failed to sample simulated run: {e}");
+        }
+        world.resource_mut::<SimRecorder>().outcome = Some(outcome);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce as _;
+
+    use super::*;
+    use crate::resources::ReplayPlayback;
+    use crate::types::{EnemyType, PassiveItemType, UpgradeChoice};
+
+    fn build_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin));
+        app.init_state::<AppState>();
+        app.init_resource::<SimRecorder>();
+        app.init_resource::<PendingUpgradeIndex>();
+        app.insert_resource(SimRun(SimSettings::default()));
+        app.insert_resource(GameData::default());
+        app.insert_resource(SimBot(Box::new(Idle)));
+        app
+    }
+
+    #[test]
+    fn bot_pick_is_recorded_and_applied() {
+        let mut app = build_app();
+        app.insert_resource(LevelUpChoices {
+            choices: vec![
+                UpgradeChoice::PassiveItem(PassiveItemType::Wings),
+                UpgradeChoice::NewWeapon(WeaponType::Knife),
+            ],
+        });
+        app.world_mut().run_system_once(pick_bot_upgrade).unwrap();
+
+        assert_eq!(app.world().resource::<PendingUpgradeIndex>().0, Some(1));
+        let recorder = app.world().resource::<SimRecorder>();
+        assert_eq!(recorder.level_ups.len(), 1);
+        assert_eq!(recorder.level_ups[0].picked, Some(1));
+    }
+
+    #[test]
+    fn samples_are_taken_once_per_interval() {
+        let mut app = build_app();
+        app.world_mut().spawn((
+            Player,
+            PlayerStats::default(),
+            WeaponInventory::default(),
+            PassiveInventory::default(),
+        ));
+
+        for elapsed in [0.0, 5.0, 10.0, 12.0] {
+            app.world_mut().resource_mut::<GameData>().elapsed_time = elapsed;
+            app.world_mut().run_system_once(sample_run).unwrap();
+        }
+
+        let samples = &app.world().resource::<SimRecorder>().samples;
+        let times: Vec<f32> = samples.iter().map(|s| s.elapsed_time).collect();
+        assert_eq!(times, vec![0.0, 10.0]);
+        assert_eq!(samples[0].max_hp, PlayerStats::default().max_hp);
+    }
+
+    #[test]
+    fn finish_records_outcome_and_final_sample() {
+        let mut app = build_app();
+        app.world_mut()
+            .run_system_once(finish_simulated_run(SimOutcome::Died))
+            .unwrap();
+
+        let recorder = app.world().resource::<SimRecorder>();
+        assert_eq!(recorder.outcome, Some(SimOutcome::Died));
+        assert_eq!(recorder.samples.len(), 1);
+    }
+
+    /// A run with the default settings is not cut off when the time limit
+    /// is reached with the boss up, so defeating the boss ends it in victory.
+    #[test]
+    fn default_run_can_reach_victory() {
+        let settings = SimSettings::default();
+        let mut app = build_simulation_app(&settings, Box::new(Idle));
+        app.finish();
+        app.cleanup();
+        wait_for_configs(&mut app, &settings).expect("configs should load");
+        for _ in 0..10 {
+            if *app.world().resource::<State<AppState>>().get() == AppState::Playing {
+                break;
+            }
+            app.update();
+        }
+
+        // Skip to just before the time limit, which is also the boss spawn.
+        app.world_mut().resource_mut::<GameData>().elapsed_time = settings.minutes * 60.0 - 0.5;
+        for _ in 0..120 {
+            app.update();
+        }
+        assert!(app.world().resource::<GameData>().boss_spawned);
+        assert_eq!(app.world().resource::<SimRecorder>().outcome, None);
+
+        let boss = app
+            .world_mut()
+            .query::<(Entity, &Enemy)>()
+            .iter(app.world())
+            .find(|(_, e)| e.enemy_type == EnemyType::BossDeath)
+            .map(|(entity, _)| entity)
+            .expect("boss should be up");
+        app.world_mut().write_message(DamageEnemyEvent {
+            entity: boss,
+            damage: 1.0e9,
+            weapon_type: WeaponType::Whip,
+            knockback: Vec2::ZERO,
+            status: None,
+        });
+        for _ in 0..3 {
+            app.update();
+        }
+
+        assert_eq!(
+            app.world().resource::<SimRecorder>().outcome,
+            Some(SimOutcome::Victory)
+        );
+    }
+
+    /// A seeded run recorded with the bot and played back from its replay
+    /// (movement, time steps, and level-up picks) ends in the same state.
+    #[test]
+    fn replay_reproduces_recorded_run() {
+        let settings = SimSettings {
+            seed: 7,
+            ..default()
+        };
+        let mut recording = build_simulation_app(&settings, Box::new(CircleKite::default()));
+        recording.insert_resource(ReplayRecorder::default());
+        recording.finish();
+        recording.cleanup();
+        wait_for_configs(&mut recording, &settings).expect("configs should load");
+        // Half a simulated minute.
+        for _ in 0..1800 {
+            recording.update();
+        }
+        let expected = recording.world().resource::<GameData>().clone();
+        assert!(expected.kill_count > 0, "the run should have seen combat");
+        let replay = recording
+            .world_mut()
+            .resource_mut::<ReplayRecorder>()
+            .replay
+            .take()
+            .expect("the run should be recorded");
+
+        let mut playback = build_headless_app(&settings.assets_dir);
+        playback.insert_resource(ReplayPlayback::new(replay));
+        playback.finish();
+        playback.cleanup();
+        wait_for_configs(&mut playback, &settings).expect("configs should load");
+        while playback
+            .world()
+            .get_resource::<ReplayPlayback>()
+            .is_some_and(|p| p.peek_frame().is_some())
+        {
+            playback.update();
+        }
+
+        assert_eq!(*playback.world().resource::<GameData>(), expected);
+    }
+
+    /// A run that never records an outcome fails once the update cap is hit
+    /// instead of looping forever.
+    #[test]
+    fn stuck_run_fails_at_update_cap() {
+        let mut app = build_app();
+        let err = run_until_finished(&mut app, 10).unwrap_err();
+        assert!(err.contains("within 10 updates"), "{err}");
+
+        app.world_mut().resource_mut::<SimRecorder>().outcome = Some(SimOutcome::Died);
+        assert_eq!(run_until_finished(&mut app, 10), Ok(()));
+    }
+
+    /// The cap covers the time limit or the boss spawn, whichever is later,
+    /// plus the boss fight.
+    #[test]
+    fn update_cap_covers_the_boss_fight() {
+        let settings = SimSettings {
+            minutes: 10.0,
+            boss_fight_minutes: 5.0,
+            timestep: Duration::from_millis(500),
+            ..default()
+        };
+        // (30 + 5) min = 2100 s → 4200 steps, doubled.
+        assert_eq!(max_run_updates(&settings, 1800.0), 8400);
+        // (40 + 5) min = 2700 s → 5400 steps, doubled.
+        let settings = SimSettings {
+            minutes: 40.0,
+            ..settings
+        };
+        assert_eq!(max_run_updates(&settings, 1800.0), 10800);
+    }
+
+    /// Once the boss is up the run is bounded by the boss-fight allowance
+    /// instead of the time limit.
+    #[test]
+    fn boss_fight_is_bounded_by_its_own_limit() {
+        let mut app = build_app();
+        app.world_mut().resource_mut::<GameData>().elapsed_time = 1800.0;
+        app.world_mut().resource_mut::<GameData>().boss_spawned = true;
+        app.world_mut().run_system_once(enforce_time_limit).unwrap();
+        assert_eq!(app.world().resource::<SimRecorder>().outcome, None);
+
+        app.world_mut().resource_mut::<GameData>().elapsed_time = 1800.0 + 5.0 * 60.0;
+        app.world_mut().run_system_once(enforce_time_limit).unwrap();
+        assert_eq!(
+            app.world().resource::<SimRecorder>().outcome,
+            Some(SimOutcome::TimeLimit)
+        );
+    }
+}
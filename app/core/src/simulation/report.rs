@@ -0,0 +1,85 @@
+use serde::Serialize;
+
+use crate::types::{CharacterType, PassiveItemType, StageType, UpgradeChoice, WeaponType};
+
+/// How a simulated run ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SimOutcome {
+    /// The player's HP reached zero.
+    Died,
+    /// The final boss was defeated.
+    Victory,
+    /// The requested number of simulated minutes elapsed before the boss
+    /// spawned, or the boss fight outlasted its allowance.
+    TimeLimit,
+}
+
+/// An owned weapon at the time of a [`SimSample`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct WeaponSample {
+    pub weapon: WeaponType,
+    pub level: u8,
+    pub evolved: bool,
+}
+
+/// An owned passive item at the time of a [`SimSample`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct PassiveSample {
+    pub item: PassiveItemType,
+    pub level: u8,
+}
+
+/// Snapshot of the run taken every `sample_interval` simulated seconds.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SimSample {
+    pub elapsed_time: f32,
+    pub level: u32,
+    pub kill_count: u32,
+    pub gold_earned: u32,
+    pub hp: f32,
+    pub max_hp: f32,
+    /// Live enemies at the time of the sample.
+    pub enemy_count: usize,
+    pub weapons: Vec<WeaponSample>,
+    pub passives: Vec<PassiveSample>,
+}
+
+/// One level-up resolved by the bot.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LevelUpPick {
+    pub elapsed_time: f32,
+    /// Level reached by this level-up.
+    pub level: u32,
+    pub choices: Vec<UpgradeChoice>,
+    /// Index into `choices`, or `None` when the bot skipped the card.
+    pub picked: Option<usize>,
+}
+
+/// Total damage dealt by one weapon over the run.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct WeaponDamage {
+    pub weapon: WeaponType,
+    pub damage: f32,
+}
+
+/// JSON report produced by a headless simulated run.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SimReport {
+    pub seed: u64,
+    pub character: CharacterType,
+    pub stage: StageType,
+    /// [`super::BotPolicy::name`] of the policy that played the run.
+    pub policy: String,
+    /// Fixed simulation step in seconds.
+    pub timestep: f32,
+    pub outcome: SimOutcome,
+    /// Simulated seconds played when the run ended.
+    pub elapsed_time: f32,
+    pub samples: Vec<SimSample>,
+    pub level_ups: Vec<LevelUpPick>,
+    /// Damage per weapon, highest first.
+    pub damage_by_weapon: Vec<WeaponDamage>,
+    /// Real time the simulation took, in seconds.
+    pub wall_time: f32,
+}
@@ -0,0 +1,269 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+
+use serde::Serialize;
+
+use super::{SimOutcome, SimReport, SimSettings, policy_from_name, run_simulation};
+use crate::types::{CharacterType, StageType};
+
+/// Column names of [`sweep_to_csv`], in order.
+const SWEEP_CSV_HEADER: &str =
+    "character,stage,seed,outcome,survival_time,kill_count,level,gold_earned,boss_defeated";
+
+/// Parameters of a character × stage × seed sweep.
+#[derive(Debug, Clone)]
+pub struct SweepSettings {
+    pub characters: Vec<CharacterType>,
+    pub stages: Vec<StageType>,
+    pub seeds: Vec<u64>,
+    /// Built-in policy name (see [`policy_from_name`]) used for every run.
+    pub policy: String,
+    /// Shared settings for every run; `seed`, `character`, and `stage` are
+    /// overwritten per cell.
+    pub base: SimSettings,
+    /// Runs simulated in parallel.
+    pub jobs: usize,
+}
+
+/// One cell of the sweep table.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SweepRow {
+    pub character: CharacterType,
+    pub stage: StageType,
+    pub seed: u64,
+    pub outcome: SimOutcome,
+    /// Simulated seconds survived.
+    pub survival_time: f32,
+    pub kill_count: u32,
+    pub level: u32,
+    pub gold_earned: u32,
+    /// `true` when Boss Death was killed (the run ended in victory).
+    pub boss_defeated: bool,
+}
+
+impl From<&SimReport> for SweepRow {
+    fn from(report: &SimReport) -> Self {
+        let last = report.samples.last();
+        Self {
+            character: report.character,
+            stage: report.stage,
+            seed: report.seed,
+            outcome: report.outcome,
+            survival_time: report.elapsed_time,
+            kill_count: last.map_or(0, |s| s.kill_count),
+            level: last.map_or(1, |s| s.level),
+            gold_earned: last.map_or(0, |s| s.gold_earned),
+            boss_defeated: report.outcome == SimOutcome::Victory,
+        }
+    }
+}
+
+/// Simulates every character on every stage with every seed.
+///
+/// Rows are returned in character → stage → seed order regardless of which
+/// worker finished first, so two sweeps over the same configs diff cleanly.
+///
+/// `on_progress(completed, total, row)` is called on the calling thread
+/// each time a cell finishes, in completion order.
+pub fn run_sweep(
+    settings: &SweepSettings,
+    mut on_progress: impl FnMut(usize, usize, &SweepRow),
+) -> Result<Vec<SweepRow>, String> {
+    if policy_from_name(&settings.policy).is_none() {
+        return Err(format!("unknown policy: {}", settings.policy));
+    }
+
+    let mut cells = Vec::new();
+    for &character in &settings.characters {
+        for &stage in &settings.stages {
+            for &seed in &settings.seeds {
+                cells.push(SimSettings {
+                    seed,
+                    character,
+                    stage,
+                    ..settings.base.clone()
+                });
+            }
+        }
+    }
+
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<Option<Result<SweepRow, String>>> = vec![None; cells.len()];
+    std::thread::scope(|scope| {
+        let (tx, rx) = mpsc::channel();
+        for _ in 0..settings.jobs.clamp(1, cells.len().max(1)) {
+            let tx = tx.clone();
+            let (next, cells) = (&next, &cells);
+            scope.spawn(move || {
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(cell) = cells.get(i) else {
+                        break;
+                    };
+                    let bot = policy_from_name(&settings.policy).expect("policy checked above");
+                    let row = run_simulation(cell, bot).map(|report| SweepRow::from(&report));
+                    if tx.send((i, row)).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(tx);
+
+        for (completed, (i, row)) in rx.into_iter().enumerate() {
+            if let Ok(row) = &row {
+                on_progress(completed + 1, cells.len(), row);
+            }
+            results[i] = Some(row);
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|r| r.expect("every cell is simulated"))
+        .collect()
+}
+
+/// Formats sweep rows as CSV with a header line.
+pub fn sweep_to_csv(rows: &[SweepRow]) -> String {
+    let mut csv = String::from(SWEEP_CSV_HEADER);
+    csv.push('\n');
+    for row in rows {
+        let outcome = match row.outcome {
+            SimOutcome::Died => "died",
+            SimOutcome::Victory => "victory",
+            SimOutcome::TimeLimit => "time_limit",
+        };
+        csv.push_str(&format!(
+            "{:?},{:?},{},{},{:.1},{},{},{},{}\n",
+            row.character,
+            row.stage,
+            row.seed,
+            outcome,
+            row.survival_time,
+            row.kill_count,
+            row.level,
+            row.gold_earned,
+            row.boss_defeated
+        ));
+    }
+    csv
+}
+
+/// Parses a seed list such as `1,2,3`, `0..10` (exclusive), or a mix of both.
+pub fn parse_seed_list(spec: &str) -> Option<Vec<u64>> {
+    let mut seeds = Vec::new();
+    for part in spec.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        match part.split_once("..") {
+            Some((start, end)) => {
+                let start: u64 = start.trim().parse().ok()?;
+                let end: u64 = end.trim().parse().ok()?;
+                seeds.extend(start..end);
+            }
+            None => seeds.push(part.parse().ok()?),
+        }
+    }
+    (!seeds.is_empty()).then_some(seeds)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulation::SimSample;
+
+    fn report(outcome: SimOutcome) -> SimReport {
+        SimReport {
+            seed: 3,
+            character: CharacterType::Knight,
+            stage: StageType::DairyPlant,
+            policy: "kite".into(),
+            timestep: 1.0 / 60.0,
+            outcome,
+            elapsed_time: 754.5,
+            samples: vec![SimSample {
+                elapsed_time: 754.5,
+                level: 17,
+                kill_count: 1234,
+                gold_earned: 40,
+                hp: 0.0,
+                max_hp: 150.0,
+                enemy_count: 80,
+                weapons: vec![],
+                passives: vec![],
+            }],
+            level_ups: vec![],
+            damage_by_weapon: vec![],
+            wall_time: 2.0,
+        }
+    }
+
+    #[test]
+    fn row_summarises_final_sample() {
+        let row = SweepRow::from(&report(SimOutcome::Died));
+        assert_eq!(row.kill_count, 1234);
+        assert_eq!(row.level, 17);
+        assert_eq!(row.gold_earned, 40);
+        assert!(!row.boss_defeated);
+        assert!(SweepRow::from(&report(SimOutcome::Victory)).boss_defeated);
+    }
+
+    #[test]
+    fn csv_has_header_and_one_line_per_row() {
+        let csv = sweep_to_csv(&[SweepRow::from(&report(SimOutcome::Died))]);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], SWEEP_CSV_HEADER);
+        assert_eq!(lines[1], "Knight,DairyPlant,3,died,754.5,1234,17,40,false");
+        assert_eq!(lines.len(), 2);
+    }
+
+    #[test]
+    fn seed_list_accepts_ranges_and_lists() {
+        assert_eq!(parse_seed_list("1,2,5"), Some(vec![1, 2, 5]));
+        assert_eq!(parse_seed_list("0..3"), Some(vec![0, 1, 2]));
+        assert_eq!(parse_seed_list("7, 10..12"), Some(vec![7, 10, 11]));
+        assert_eq!(parse_seed_list("abc"), None);
+        assert_eq!(parse_seed_list(""), None);
+    }
+
+    #[test]
+    fn unknown_policy_is_rejected_before_simulating() {
+        let settings = SweepSettings {
+            characters: CharacterType::ALL.to_vec(),
+            stages: StageType::ALL.to_vec(),
+            seeds: vec![1],
+            policy: "teleport".into(),
+            base: SimSettings::default(),
+            jobs: 1,
+        };
+        assert!(run_sweep(&settings, |_, _, _| {}).is_err());
+    }
+
+    /// Progress is reported once per finished cell with a running count of
+    /// completed cells, whatever order the workers finish in.
+    #[test]
+    fn progress_counts_completed_cells() {
+        let settings = SweepSettings {
+            characters: vec![CharacterType::DefaultCharacter],
+            stages: vec![StageType::MadForest],
+            seeds: vec![1, 2, 3],
+            policy: "idle".into(),
+            base: SimSettings {
+                minutes: 0.05,
+                ..SimSettings::default()
+            },
+            jobs: 2,
+        };
+        let mut progress = Vec::new();
+        let rows = run_sweep(&settings, |completed, total, _| {
+            progress.push((completed, total));
+        })
+        .expect("sweep should run");
+
+        assert_eq!(rows.len(), 3);
+        assert_eq!(progress, [(1, 3), (2, 3), (3, 3)]);
+    }
+}
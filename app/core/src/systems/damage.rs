@@ -1,17 +1,48 @@
 //! Damage application system.
 //!
 //! [`apply_damage_to_enemies`] reads every [`DamageEnemyEvent`] queued this
-//! frame and reduces the target enemy's HP via [`Enemy::take_damage`].
+//! frame, scales it by the target's multiplier for the weapon's
+//! [`DamageType`](crate::types::DamageType) (from its spec in the
+//! [`WeaponRegistry`]; resistance or weakness from `enemy.ron`) and reduces
+//! the enemy's HP via [`Enemy::take_damage`].
+//! Armored [`Elite`]s take only `elite.armored_damage_taken` of every hit.
 //! Enemies whose HP reaches zero are despawned and an [`EnemyDiedEvent`] is
 //! emitted so downstream systems (XP gems, gold coins) can react.
+//!
+//! Surviving enemies are pushed by the event's `knockback`, scaled down by
+//! their [`Enemy::knockback_resistance`], and receive a short [`HitStun`]
+//! during which `move_enemies` leaves them in place.  [`tick_hit_stun`]
+//! counts the stun down and removes it.
+//!
+//! A hit that carries a status effect merges it into the survivor's
+//! [`StatusEffects`].  Crowd control (slow, freeze) is shortened by the same
+//! knockback resistance, so bosses shrug most of it off; burn and poison
+//! are not.
+
+use std::collections::HashMap;
 
 use bevy::prelude::*;
 
 use crate::{
-    components::Enemy,
+    components::{Elite, Enemy, HitStun, MiniBoss, StatusEffects},
+    config::EnemyParams,
     events::{DamageEnemyEvent, EnemyDiedEvent},
+    systems::weapons::registry::WeaponRegistry,
+    types::EliteModifier,
 };
 
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Knockback vector pushing a target at `target` directly away from `source`.
+///
+/// Returns `Vec2::ZERO` when the two points coincide, so a hit from exactly
+/// on top of an enemy deals damage without a push.
+pub fn knockback_from(source: Vec2, target: Vec2, strength: f32) -> Vec2 {
+    (target - source).normalize_or_zero() * strength
+}
+
 // ---------------------------------------------------------------------------
 // System
 // ---------------------------------------------------------------------------
@@ -20,31 +51,134 @@ use crate::{
 ///
 /// - If the target entity no longer exists (already despawned) the event is
 ///   silently skipped.
+/// - Damage is multiplied by [`EnemyParams::damage_multiplier`] for the
+///   enemy type and the weapon's damage type, and by
+///   `elite.armored_damage_taken` for Armored elites.
 /// - Enemies reduced to zero HP are despawned and an [`EnemyDiedEvent`] is
-///   emitted carrying the entity, world position, and enemy type for loot
-///   spawning.
+///   emitted carrying the entity, world position, enemy type, elite
+///   modifier and mini-boss archetype for loot spawning.
+/// - Survivors hit with a non-zero `knockback` are displaced by
+///   `knockback × (1 − knockback_resistance)` and stunned for
+///   `hit_stun_duration × (1 − knockback_resistance)` seconds.  A fresh hit
+///   never shortens a stun already in progress.
+/// - Survivors hit with a `status` have it merged into their
+///   [`StatusEffects`] (inserted on first use); crowd-control durations are
+///   scaled by `1 − knockback_resistance`.
 pub fn apply_damage_to_enemies(
     mut damage_events: MessageReader<DamageEnemyEvent>,
     mut died_events: MessageWriter<EnemyDiedEvent>,
-    mut enemy_q: Query<(&mut Enemy, &Transform)>,
+    mut enemy_q: Query<(
+        &mut Enemy,
+        &mut Transform,
+        Option<&mut HitStun>,
+        Option<&mut StatusEffects>,
+        Option<&Elite>,
+        Option<&MiniBoss>,
+    )>,
+    enemy_cfg: EnemyParams,
+    registry: Res<WeaponRegistry>,
     mut commands: Commands,
 ) {
+    let stun_duration = enemy_cfg.hit_stun_duration();
+    let tick_interval = enemy_cfg.status_tick_interval();
+    let armored_damage_taken = enemy_cfg.elite().armored_damage_taken.max(0.0);
+    // Effects for enemies that have no `StatusEffects` yet; inserted once at
+    // the end so several hits in one frame do not overwrite each other.
+    let mut new_effects: HashMap<Entity, StatusEffects> = HashMap::new();
+
     for event in damage_events.read() {
-        let Ok((mut enemy, transform)) = enemy_q.get_mut(event.entity) else {
+        let Ok((mut enemy, mut transform, hit_stun, status_effects, elite, mini_boss)) =
+            enemy_q.get_mut(event.entity)
+        else {
             continue;
         };
-        enemy.take_damage(event.damage);
+        let elite = elite.map(|e| e.modifier);
+        let damage_type = registry.damage_type(event.weapon_type);
+        let mut multiplier = enemy_cfg.damage_multiplier(enemy.enemy_type, damage_type);
+        if elite == Some(EliteModifier::Armored) {
+            multiplier *= armored_damage_taken;
+        }
+        enemy.take_damage(event.damage * multiplier);
         if enemy.is_dead() {
             let position = transform.translation.truncate();
             let enemy_type = enemy.enemy_type;
             let xp_value = enemy.xp_value;
+            let gold_chance = enemy.gold_chance;
             commands.entity(event.entity).despawn();
             died_events.write(EnemyDiedEvent {
                 entity: event.entity,
                 position,
                 enemy_type,
                 xp_value,
+                gold_chance,
+                elite,
+                mini_boss: mini_boss.map(|m| m.kind),
             });
+            new_effects.remove(&event.entity);
+            continue;
+        }
+
+        if let Some(mut effect) = event.status {
+            if effect.is_crowd_control() {
+                effect.remaining *= 1.0 - enemy.knockback_resistance;
+            }
+            if effect.remaining > 0.0 {
+                match status_effects {
+                    Some(mut effects) => {
+                        if effects.effects.is_empty() {
+                            effects.tick_timer = tick_interval;
+                        }
+                        effects.apply(effect);
+                    }
+                    None => new_effects
+                        .entry(event.entity)
+                        .or_insert_with(|| StatusEffects {
+                            tick_timer: tick_interval,
+                            ..default()
+                        })
+                        .apply(effect),
+                }
+            }
+        }
+
+        if event.knockback == Vec2::ZERO {
+            continue;
+        }
+        let take = 1.0 - enemy.knockback_resistance;
+        transform.translation += (event.knockback * take).extend(0.0);
+        let stun = stun_duration * take;
+        if stun <= 0.0 {
+            continue;
+        }
+        match hit_stun {
+            Some(mut hit_stun) => hit_stun.remaining = hit_stun.remaining.max(stun),
+            None => {
+                commands
+                    .entity(event.entity)
+                    .try_insert(HitStun { remaining: stun });
+            }
+        }
+    }
+
+    for (entity, effects) in new_effects {
+        commands.entity(entity).try_insert(effects);
+    }
+}
+
+/// Counts every [`HitStun`] down and removes it once expired.
+///
+/// Runs before `move_enemies` so an enemy resumes moving on the frame its
+/// stun runs out.
+pub fn tick_hit_stun(
+    time: Res<Time>,
+    mut stun_q: Query<(Entity, &mut HitStun)>,
+    mut commands: Commands,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut stun) in stun_q.iter_mut() {
+        stun.remaining -= dt;
+        if stun.remaining <= 0.0 {
+            commands.entity(entity).remove::<HitStun>();
         }
     }
 }
@@ -58,8 +192,10 @@ mod tests {
     use bevy::ecs::system::RunSystemOnce as _;
 
     use super::*;
+    use std::time::Duration;
+
     use crate::{
-        components::Enemy,
+        components::{Enemy, StatusEffect, StatusKind},
         events::{DamageEnemyEvent, EnemyDiedEvent},
         types::{EnemyType, WeaponType},
     };
@@ -69,6 +205,7 @@ mod tests {
         app.add_plugins(MinimalPlugins);
         app.add_message::<DamageEnemyEvent>();
         app.add_message::<EnemyDiedEvent>();
+        app.insert_resource(WeaponRegistry::with_builtin_weapons());
         app
     }
 
@@ -82,10 +219,16 @@ mod tests {
     }
 
     fn send_damage(app: &mut App, entity: Entity, damage: f32) {
+        send_hit(app, entity, damage, Vec2::ZERO);
+    }
+
+    fn send_hit(app: &mut App, entity: Entity, damage: f32, knockback: Vec2) {
         app.world_mut().write_message(DamageEnemyEvent {
             entity,
             damage,
             weapon_type: WeaponType::Whip,
+            knockback,
+            status: None,
         });
     }
 
@@ -209,4 +352,248 @@ mod tests {
         let hp = app.world().get::<Enemy>(entity).unwrap().current_hp;
         assert_eq!(hp, initial_hp - 5.0);
     }
+
+    fn position(app: &App, entity: Entity) -> Vec2 {
+        app.world()
+            .get::<Transform>(entity)
+            .unwrap()
+            .translation
+            .truncate()
+    }
+
+    fn spawn_ghost(app: &mut App) -> Entity {
+        app.world_mut()
+            .spawn((
+                Enemy::from_type(EnemyType::Ghost, 1.0),
+                Transform::from_xyz(0.0, 0.0, 0.0),
+            ))
+            .id()
+    }
+
+    fn send_weapon_damage(app: &mut App, entity: Entity, damage: f32, weapon_type: WeaponType) {
+        app.world_mut().write_message(DamageEnemyEvent {
+            entity,
+            damage,
+            weapon_type,
+            knockback: Vec2::ZERO,
+            status: None,
+        });
+    }
+
+    /// Ghosts resist physical damage: a Knife hit deals half.
+    #[test]
+    fn ghost_resists_physical_damage() {
+        let mut app = build_app();
+        let ghost = spawn_ghost(&mut app);
+        let initial_hp = app.world().get::<Enemy>(ghost).unwrap().current_hp;
+
+        send_weapon_damage(&mut app, ghost, 10.0, WeaponType::Knife);
+        run_apply(&mut app);
+
+        let hp = app.world().get::<Enemy>(ghost).unwrap().current_hp;
+        assert_eq!(hp, initial_hp - 5.0);
+    }
+
+    /// Ghosts are weak to holy damage: a Holy Wand hit deals 1.5×.
+    #[test]
+    fn ghost_takes_extra_holy_damage() {
+        let mut app = build_app();
+        let ghost = spawn_ghost(&mut app);
+        let initial_hp = app.world().get::<Enemy>(ghost).unwrap().current_hp;
+
+        send_weapon_damage(&mut app, ghost, 10.0, WeaponType::HolyWand);
+        run_apply(&mut app);
+
+        let hp = app.world().get::<Enemy>(ghost).unwrap().current_hp;
+        assert_eq!(hp, initial_hp - 15.0);
+    }
+
+    /// Knockback pushes a surviving enemy and stuns it.
+    #[test]
+    fn knockback_displaces_and_stuns_enemy() {
+        let mut app = build_app();
+        let entity = spawn_enemy(&mut app); // Bat: no knockback resistance
+
+        send_hit(&mut app, entity, 1.0, Vec2::new(30.0, 0.0));
+        run_apply(&mut app);
+
+        assert_eq!(position(&app, entity), Vec2::new(40.0, 20.0));
+        let stun = app.world().get::<HitStun>(entity).expect("stunned");
+        assert!(stun.remaining > 0.0);
+    }
+
+    /// Knockback resistance scales the push down; bosses barely move.
+    #[test]
+    fn knockback_resistance_scales_push() {
+        let mut app = build_app();
+        let boss = app
+            .world_mut()
+            .spawn((
+                Enemy::from_type(EnemyType::BossDeath, 1.0),
+                Transform::from_xyz(0.0, 0.0, 0.0),
+            ))
+            .id();
+
+        send_hit(&mut app, boss, 1.0, Vec2::new(100.0, 0.0));
+        run_apply(&mut app);
+
+        let moved = position(&app, boss).x;
+        assert!(moved > 0.0 && moved < 10.0, "boss moved {moved}px");
+    }
+
+    /// Hits without knockback deal damage but never stun.
+    #[test]
+    fn zero_knockback_does_not_stun() {
+        let mut app = build_app();
+        let entity = spawn_enemy(&mut app);
+
+        send_damage(&mut app, entity, 1.0);
+        run_apply(&mut app);
+
+        assert_eq!(position(&app, entity), Vec2::new(10.0, 20.0));
+        assert!(app.world().get::<HitStun>(entity).is_none());
+    }
+
+    /// A weaker follow-up hit does not shorten an ongoing stun.
+    #[test]
+    fn weaker_hit_keeps_longer_stun() {
+        let mut app = build_app();
+        let entity = spawn_enemy(&mut app);
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(HitStun { remaining: 5.0 });
+
+        send_hit(&mut app, entity, 1.0, Vec2::X);
+        run_apply(&mut app);
+
+        assert_eq!(app.world().get::<HitStun>(entity).unwrap().remaining, 5.0);
+    }
+
+    fn send_status(app: &mut App, entity: Entity, effect: StatusEffect) {
+        app.world_mut().write_message(DamageEnemyEvent {
+            entity,
+            damage: 0.0,
+            weapon_type: effect.source,
+            knockback: Vec2::ZERO,
+            status: Some(effect),
+        });
+    }
+
+    /// Status effects from the same frame are merged, not overwritten.
+    #[test]
+    fn status_effects_from_one_frame_are_merged() {
+        let mut app = build_app();
+        let entity = spawn_enemy(&mut app);
+
+        send_status(
+            &mut app,
+            entity,
+            StatusEffect::burn(5.0, 2.0, WeaponType::FireWand),
+        );
+        send_status(
+            &mut app,
+            entity,
+            StatusEffect::slow(0.3, 1.0, WeaponType::Garlic),
+        );
+        run_apply(&mut app);
+
+        let status = app.world().get::<StatusEffects>(entity).expect("inserted");
+        assert!(status.get(StatusKind::Burn).is_some());
+        assert!(status.get(StatusKind::Slow).is_some());
+    }
+
+    /// Crowd control is shortened by knockback resistance; burns are not.
+    #[test]
+    fn knockback_resistance_shortens_crowd_control_only() {
+        let mut app = build_app();
+        let boss = app
+            .world_mut()
+            .spawn((
+                Enemy::from_type(EnemyType::BossDeath, 1.0),
+                Transform::default(),
+            ))
+            .id();
+
+        send_status(
+            &mut app,
+            boss,
+            StatusEffect::freeze(2.0, WeaponType::HolyWand),
+        );
+        send_status(
+            &mut app,
+            boss,
+            StatusEffect::burn(5.0, 2.0, WeaponType::FireWand),
+        );
+        run_apply(&mut app);
+
+        let status = app.world().get::<StatusEffects>(boss).unwrap();
+        let freeze = status.get(StatusKind::Freeze).unwrap().remaining;
+        assert!((freeze - 0.1).abs() < 1e-4, "freeze lasted {freeze}s");
+        assert_eq!(status.get(StatusKind::Burn).unwrap().remaining, 2.0);
+    }
+
+    /// A lethal hit does not leave a status component behind.
+    #[test]
+    fn lethal_hit_with_status_despawns_cleanly() {
+        let mut app = build_app();
+        let entity = spawn_enemy(&mut app);
+
+        send_status(
+            &mut app,
+            entity,
+            StatusEffect::burn(5.0, 2.0, WeaponType::FireWand),
+        );
+        send_damage(&mut app, entity, 9999.0);
+        run_apply(&mut app);
+
+        assert!(app.world().get_entity(entity).is_err());
+    }
+
+    /// Armored elites take reduced damage and report their modifier on death.
+    #[test]
+    fn armored_elite_takes_reduced_damage() {
+        let mut app = build_app();
+        let entity = spawn_enemy(&mut app);
+        app.world_mut().entity_mut(entity).insert(Elite {
+            modifier: EliteModifier::Armored,
+        });
+        let max_hp = app.world().get::<Enemy>(entity).unwrap().max_hp;
+
+        send_damage(&mut app, entity, 4.0);
+        run_apply(&mut app);
+        let hp = app.world().get::<Enemy>(entity).unwrap().current_hp;
+        assert!((max_hp - hp - 2.0).abs() < 1e-4, "took {}", max_hp - hp);
+
+        send_damage(&mut app, entity, 9999.0);
+        run_apply(&mut app);
+        let events = died_events(&app);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].elite, Some(EliteModifier::Armored));
+    }
+
+    /// `tick_hit_stun` removes the stun once it runs out.
+    #[test]
+    fn hit_stun_expires() {
+        let mut app = build_app();
+        let entity = spawn_enemy(&mut app);
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(HitStun { remaining: 0.01 });
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(0.02));
+        app.world_mut().run_system_once(tick_hit_stun).unwrap();
+
+        assert!(app.world().get::<HitStun>(entity).is_none());
+    }
+
+    #[test]
+    fn knockback_from_points_away_from_source() {
+        assert_eq!(
+            knockback_from(Vec2::ZERO, Vec2::new(0.0, 5.0), 20.0),
+            Vec2::new(0.0, 20.0)
+        );
+        assert_eq!(knockback_from(Vec2::ONE, Vec2::ONE, 20.0), Vec2::ZERO);
+    }
 }
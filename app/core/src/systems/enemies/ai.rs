@@ -14,7 +14,7 @@
 use bevy::prelude::*;
 
 use crate::{
-    components::{Enemy, EnemyAI, Player},
+    components::{Enemy, EnemyAI, HitStun, Player, StatusEffects},
     config::EnemyParams,
     types::AIType,
 };
@@ -38,13 +38,20 @@ const DEFAULT_KEEP_MAX_DIST: f32 = 250.0;
 /// - `KeepDistance`: moves away when too close, toward when too far, still
 ///   when within the band.  Thresholds are sourced from [`EnemyParams`] with
 ///   compile-time constant fallbacks.
-/// - All other AI types: stationary (handled by dedicated systems).
+/// - All other AI types: stationary (handled by dedicated systems, e.g.
+///   [`move_formation_enemies`](super::formation::move_formation_enemies)).
+/// - Enemies under a [`HitStun`] from a knockback hit skip movement.
+/// - Speed is scaled by [`StatusEffects::speed_multiplier`] (slowed or
+///   frozen enemies).
 /// - `normalize_or_zero` prevents NaN when an enemy is exactly on the player.
 /// - Enemies without a player remain stationary.
 pub fn move_enemies(
     time: Res<Time>,
     player_q: Query<&Transform, With<Player>>,
-    mut enemy_q: Query<(&Enemy, &EnemyAI, &mut Transform), Without<Player>>,
+    mut enemy_q: Query<
+        (&Enemy, &EnemyAI, &mut Transform, Option<&StatusEffects>),
+        (Without<Player>, Without<HitStun>),
+    >,
     enemy_cfg: EnemyParams,
 ) {
     let Ok(player_tf) = player_q.single() else {
@@ -63,12 +70,13 @@ pub fn move_enemies(
 
     let dt = time.delta_secs();
 
-    for (enemy, ai, mut tf) in enemy_q.iter_mut() {
+    for (enemy, ai, mut tf, status) in enemy_q.iter_mut() {
         let enemy_pos = tf.translation.truncate();
+        let speed = enemy.move_speed * status.map_or(1.0, StatusEffects::speed_multiplier);
         match ai.ai_type {
             AIType::ChasePlayer => {
                 let direction = (player_pos - enemy_pos).normalize_or_zero();
-                tf.translation += (direction * enemy.move_speed * dt).extend(0.0);
+                tf.translation += (direction * speed * dt).extend(0.0);
             }
             AIType::KeepDistance => {
                 let to_player = player_pos - enemy_pos;
@@ -83,7 +91,7 @@ pub fn move_enemies(
                     // In the comfort band — hold position.
                     Vec2::ZERO
                 };
-                tf.translation += (direction * enemy.move_speed * dt).extend(0.0);
+                tf.translation += (direction * speed * dt).extend(0.0);
             }
             _ => {} // other AI types handled by dedicated systems
         }
@@ -158,6 +166,49 @@ mod tests {
         );
     }
 
+    /// A hit-stunned enemy must hold its position.
+    #[test]
+    fn stunned_enemy_does_not_move() {
+        let mut app = build_app();
+        spawn_player_at(&mut app, Vec2::new(100.0, 0.0));
+        let enemy = spawn_chase_enemy_at(&mut app, Vec2::ZERO);
+        app.world_mut()
+            .entity_mut(enemy)
+            .insert(HitStun { remaining: 0.2 });
+
+        advance_and_run(&mut app);
+
+        let x = app.world().get::<Transform>(enemy).unwrap().translation.x;
+        assert_eq!(x, 0.0, "stunned enemy should stay put");
+    }
+
+    /// A frozen enemy holds its position; a slowed one covers less ground.
+    #[test]
+    fn status_effects_scale_movement() {
+        use crate::{components::StatusEffect, types::WeaponType};
+
+        let mut app = build_app();
+        spawn_player_at(&mut app, Vec2::new(1000.0, 0.0));
+        let frozen = spawn_chase_enemy_at(&mut app, Vec2::ZERO);
+        let slowed = spawn_chase_enemy_at(&mut app, Vec2::ZERO);
+        let free = spawn_chase_enemy_at(&mut app, Vec2::ZERO);
+        let mut freeze = StatusEffects::default();
+        freeze.apply(StatusEffect::freeze(1.0, WeaponType::HolyWand));
+        let mut slow = StatusEffects::default();
+        slow.apply(StatusEffect::slow(0.5, 1.0, WeaponType::Garlic));
+        app.world_mut().entity_mut(frozen).insert(freeze);
+        app.world_mut().entity_mut(slowed).insert(slow);
+
+        advance_and_run(&mut app);
+
+        let x = |e| app.world().get::<Transform>(e).unwrap().translation.x;
+        assert_eq!(x(frozen), 0.0, "frozen enemy should stay put");
+        assert!(
+            (x(slowed) - x(free) * 0.5).abs() < 1e-4,
+            "slow halves speed"
+        );
+    }
+
     /// Each of multiple enemies must move toward the same player.
     #[test]
     fn multiple_enemies_all_move_toward_player() {
@@ -15,11 +15,15 @@
 //!   position.
 //! - [`check_boss_phase_transition`] runs after the move systems; once it
 //!   flips the phase, the corresponding move system stops and the next starts.
+//!
+//! Every move system scales speed by the boss's
+//! [`StatusEffects::speed_multiplier`]; crowd control barely lasts on the boss
+//! because its high knockback resistance shortens slows and freezes.
 
 use bevy::prelude::*;
 
 use crate::{
-    components::{CircleCollider, Enemy, EnemyAI, GameSessionEntity, Player},
+    components::{CircleCollider, Enemy, EnemyAI, GameSessionEntity, Player, StatusEffects},
     config::{EnemyConfig, EnemyParams, GameParams},
     types::{AIType, BossPhase, EnemyType},
 };
@@ -57,7 +61,10 @@ const DEFAULT_MINI_DEATH_COLLIDER: f32 = 20.0;
 pub fn move_boss_phase1(
     time: Res<Time>,
     player_q: Query<&Transform, With<Player>>,
-    mut boss_q: Query<(&Enemy, &mut Transform, &BossPhase), Without<Player>>,
+    mut boss_q: Query<
+        (&Enemy, &mut Transform, &BossPhase, Option<&StatusEffects>),
+        Without<Player>,
+    >,
 ) {
     let Ok(player_tf) = player_q.single() else {
         return;
@@ -65,13 +72,14 @@ pub fn move_boss_phase1(
     let player_pos = player_tf.translation.truncate();
     let dt = time.delta_secs();
 
-    for (enemy, mut boss_tf, phase) in boss_q.iter_mut() {
+    for (enemy, mut boss_tf, phase, status) in boss_q.iter_mut() {
         if *phase != BossPhase::Phase1 {
             continue;
         }
         let boss_pos = boss_tf.translation.truncate();
         let direction = (player_pos - boss_pos).normalize_or_zero();
-        boss_tf.translation += (direction * enemy.move_speed * dt).extend(0.0);
+        let speed = enemy.move_speed * status.map_or(1.0, StatusEffects::speed_multiplier);
+        boss_tf.translation += (direction * speed * dt).extend(0.0);
     }
 }
 
@@ -83,7 +91,10 @@ pub fn move_boss_phase1(
 pub fn move_boss_phase2(
     time: Res<Time>,
     player_q: Query<&Transform, With<Player>>,
-    mut boss_q: Query<(&Enemy, &mut Transform, &BossPhase), Without<Player>>,
+    mut boss_q: Query<
+        (&Enemy, &mut Transform, &BossPhase, Option<&StatusEffects>),
+        Without<Player>,
+    >,
     game_cfg: GameParams,
 ) {
     let Ok(player_tf) = player_q.single() else {
@@ -96,13 +107,14 @@ pub fn move_boss_phase2(
         .map(|c| c.boss_phase2_speed_multiplier)
         .unwrap_or(DEFAULT_PHASE2_SPEED_MULTIPLIER);
 
-    for (enemy, mut boss_tf, phase) in boss_q.iter_mut() {
+    for (enemy, mut boss_tf, phase, status) in boss_q.iter_mut() {
         if *phase != BossPhase::Phase2 {
             continue;
         }
         let boss_pos = boss_tf.translation.truncate();
         let direction = (player_pos - boss_pos).normalize_or_zero();
-        boss_tf.translation += (direction * enemy.move_speed * multiplier * dt).extend(0.0);
+        let speed = enemy.move_speed * status.map_or(1.0, StatusEffects::speed_multiplier);
+        boss_tf.translation += (direction * speed * multiplier * dt).extend(0.0);
     }
 }
 
@@ -114,7 +126,10 @@ pub fn move_boss_phase2(
 pub fn move_boss_phase3(
     time: Res<Time>,
     player_q: Query<&Transform, With<Player>>,
-    mut boss_q: Query<(&Enemy, &mut Transform, &BossPhase), Without<Player>>,
+    mut boss_q: Query<
+        (&Enemy, &mut Transform, &BossPhase, Option<&StatusEffects>),
+        Without<Player>,
+    >,
     game_cfg: GameParams,
 ) {
     let Ok(player_tf) = player_q.single() else {
@@ -127,13 +142,14 @@ pub fn move_boss_phase3(
         .map(|c| c.boss_phase3_speed_multiplier)
         .unwrap_or(DEFAULT_PHASE3_SPEED_MULTIPLIER);
 
-    for (enemy, mut boss_tf, phase) in boss_q.iter_mut() {
+    for (enemy, mut boss_tf, phase, status) in boss_q.iter_mut() {
         if *phase != BossPhase::Phase3 {
             continue;
         }
         let boss_pos = boss_tf.translation.truncate();
         let direction = (player_pos - boss_pos).normalize_or_zero();
-        boss_tf.translation += (direction * enemy.move_speed * multiplier * dt).extend(0.0);
+        let speed = enemy.move_speed * status.map_or(1.0, StatusEffects::speed_multiplier);
+        boss_tf.translation += (direction * speed * multiplier * dt).extend(0.0);
     }
 }
 
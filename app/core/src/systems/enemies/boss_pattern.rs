@@ -0,0 +1,553 @@
+//! Boss-pattern interpreter — runs data-driven stage bosses.
+//!
+//! A stage that names its own [`StageBoss`](crate::config::StageBoss) in
+//! `stage.ron` gets it spawned with a [`BossPatterns`] component instead of
+//! Boss Death's hand-coded phases.  Three systems drive every such boss:
+//!
+//! - [`advance_boss_pattern_phase`] — moves to the next phase once HP drops to
+//!   its `hp_threshold` (several at once after a big hit) and restarts the
+//!   pattern cycle.
+//! - [`move_pattern_bosses`] — chases the player at the phase's speed, or
+//!   follows a charge in progress.
+//! - [`run_boss_patterns`] — every `interval` seconds runs the phase's next
+//!   [`BossPattern`], looping through the list.
+//!
+//! | Pattern       | Effect                                                  |
+//! |---------------|---------------------------------------------------------|
+//! | `RadialBurst` | Bullets fired evenly in every direction                 |
+//! | `AimedVolley` | Bullets fanned toward the player                        |
+//! | `Summon`      | Regular enemies spawned in a ring around the boss       |
+//! | `Charge`      | Straight dash toward the player's position              |
+//! | `Teleport`    | Boss reappears at a set distance from the player        |
+//!
+//! Bullets are pooled [`HostileProjectile`]s, moved and collided with the
+//! player by the shared [`hostile_projectile`](super::hostile_projectile)
+//! systems.  The pattern timer is held during a charge
+//! so a dash is never interrupted by the next pattern.
+
+use bevy::prelude::*;
+use rand::RngExt;
+
+use crate::{
+    components::{BossPatterns, Enemy, HostileProjectile, Player, StatusEffects},
+    config::{BossPattern, EnemyParams},
+    resources::GameRng,
+    systems::enemies::{
+        hostile_projectile::{HostileProjectiles, HostileShot},
+        spawn::{fallback_collider_radius, spawn_enemy},
+    },
+    types::EnemyType,
+};
+
+/// Lower bound on a phase's `interval`, so a zero in `stage.ron` cannot fire
+/// a pattern every frame.
+const MIN_PATTERN_INTERVAL: f32 = 0.1;
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Advances each pattern boss to the last phase whose `hp_threshold` its HP
+/// fraction has reached.
+///
+/// Entering a phase restarts its pattern list from the top, with the first
+/// pattern one `interval` away.
+pub fn advance_boss_pattern_phase(mut boss_q: Query<(&Enemy, &mut BossPatterns)>) {
+    for (enemy, mut patterns) in boss_q.iter_mut() {
+        let hp_fraction = if enemy.max_hp > 0.0 {
+            enemy.current_hp / enemy.max_hp
+        } else {
+            0.0
+        };
+        let mut phase = patterns.phase;
+        while patterns
+            .boss
+            .phases
+            .get(phase + 1)
+            .is_some_and(|next| hp_fraction <= next.hp_threshold)
+        {
+            phase += 1;
+        }
+        if phase == patterns.phase {
+            continue;
+        }
+        patterns.phase = phase;
+        patterns.next_pattern = 0;
+        patterns.timer = patterns.boss.phases[phase].interval;
+    }
+}
+
+/// Moves each pattern boss: along its charge while one is in progress,
+/// otherwise toward the player at `move_speed × speed_multiplier` of the
+/// active phase.
+///
+/// Both are scaled by [`StatusEffects::speed_multiplier`].  No-op without a
+/// player.
+pub fn move_pattern_bosses(
+    time: Res<Time>,
+    player_q: Query<&Transform, With<Player>>,
+    mut boss_q: Query<
+        (
+            &Enemy,
+            &mut BossPatterns,
+            &mut Transform,
+            Option<&StatusEffects>,
+        ),
+        Without<Player>,
+    >,
+) {
+    let Ok(player_tf) = player_q.single() else {
+        return;
+    };
+    let player_pos = player_tf.translation.truncate();
+    let dt = time.delta_secs();
+
+    for (enemy, mut patterns, mut boss_tf, status) in boss_q.iter_mut() {
+        let status_mult = status.map_or(1.0, StatusEffects::speed_multiplier);
+        if patterns.charge_remaining > 0.0 {
+            boss_tf.translation += (patterns.charge_velocity * status_mult * dt).extend(0.0);
+            patterns.charge_remaining -= dt;
+            continue;
+        }
+        let phase_mult = patterns.current_phase().map_or(1.0, |p| p.speed_multiplier);
+        let direction = (player_pos - boss_tf.translation.truncate()).normalize_or_zero();
+        boss_tf.translation +=
+            (direction * enemy.move_speed * phase_mult * status_mult * dt).extend(0.0);
+    }
+}
+
+/// Counts each pattern boss's timer down and runs the active phase's next
+/// [`BossPattern`] when it elapses.
+///
+/// No-op without a player, and for a boss whose phase has no patterns.
+pub fn run_boss_patterns(
+    mut commands: Commands,
+    time: Res<Time>,
+    player_q: Query<&Transform, With<Player>>,
+    mut boss_q: Query<(&Enemy, &mut BossPatterns, &mut Transform), Without<Player>>,
+    enemy_cfg: EnemyParams,
+    mut game_rng: ResMut<GameRng>,
+    mut projectiles: HostileProjectiles,
+) {
+    let Ok(player_tf) = player_q.single() else {
+        return;
+    };
+    let player_pos = player_tf.translation.truncate();
+    let dt = time.delta_secs();
+
+    for (boss, mut patterns, mut boss_tf) in boss_q.iter_mut() {
+        if patterns.charge_remaining > 0.0 {
+            continue;
+        }
+        let Some(phase) = patterns.current_phase() else {
+            continue;
+        };
+        if phase.patterns.is_empty() {
+            continue;
+        }
+        let interval = phase.interval.max(MIN_PATTERN_INTERVAL);
+        let pattern = phase.patterns[patterns.next_pattern % phase.patterns.len()];
+        let pattern_count = phase.patterns.len();
+
+        patterns.timer -= dt;
+        if patterns.timer > 0.0 {
+            continue;
+        }
+        patterns.timer += interval;
+        patterns.next_pattern = (patterns.next_pattern + 1) % pattern_count;
+
+        let boss_pos = boss_tf.translation.truncate();
+        let to_player = (player_pos - boss_pos).normalize_or_zero();
+        let bullet = BulletStyle {
+            source: boss.enemy_type,
+            radius: patterns.boss.projectile_radius,
+            lifetime: patterns.boss.projectile_lifetime,
+            color: {
+                let (r, g, b) = patterns.boss.color;
+                Color::srgb(r, g, b)
+            },
+        };
+
+        match pattern {
+            BossPattern::RadialBurst {
+                count,
+                speed,
+                damage,
+            } => {
+                for i in 0..count {
+                    let angle = std::f32::consts::TAU * i as f32 / count as f32;
+                    let velocity = Vec2::from_angle(angle) * speed;
+                    fire_boss_bullet(&mut projectiles, boss_pos, velocity, damage, &bullet);
+                }
+            }
+            BossPattern::AimedVolley {
+                count,
+                spread_deg,
+                speed,
+                damage,
+            } => {
+                if to_player == Vec2::ZERO {
+                    continue; // player exactly on the boss — no direction to aim
+                }
+                for offset in volley_offsets(count, spread_deg.to_radians()) {
+                    let velocity = Vec2::from_angle(offset).rotate(to_player) * speed;
+                    fire_boss_bullet(&mut projectiles, boss_pos, velocity, damage, &bullet);
+                }
+            }
+            BossPattern::Summon {
+                enemy,
+                count,
+                radius,
+            } => {
+                let cfg_stats = enemy_cfg.get().map(|c| c.stats_for(enemy).clone());
+                let collider_radius = cfg_stats
+                    .as_ref()
+                    .map(|s| s.collider_radius)
+                    .unwrap_or_else(|| fallback_collider_radius(enemy));
+                for i in 0..count {
+                    let angle = std::f32::consts::TAU * i as f32 / count as f32;
+                    spawn_enemy(
+                        &mut commands,
+                        enemy,
+                        boss_pos + Vec2::from_angle(angle) * radius,
+                        1.0,
+                        1.0,
+                        1.0,
+                        collider_radius,
+                        cfg_stats.as_ref(),
+                    );
+                }
+            }
+            BossPattern::Charge { speed, duration } => {
+                patterns.charge_velocity = to_player * speed;
+                patterns.charge_remaining = duration.max(0.0);
+            }
+            BossPattern::Teleport { distance } => {
+                let angle = game_rng.rng().random_range(0.0..std::f32::consts::TAU);
+                let target = player_pos + Vec2::from_angle(angle) * distance;
+                boss_tf.translation.x = target.x;
+                boss_tf.translation.y = target.y;
+            }
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Source, size, lifetime and colour shared by every bullet of one boss.
+struct BulletStyle {
+    source: EnemyType,
+    radius: f32,
+    lifetime: f32,
+    color: Color,
+}
+
+/// Angle offsets (radians) of `count` bullets spread evenly across `spread`,
+/// centred on zero.  A single bullet flies straight.
+pub(super) fn volley_offsets(count: u32, spread: f32) -> impl Iterator<Item = f32> {
+    (0..count).map(move |i| {
+        if count <= 1 {
+            0.0
+        } else {
+            -spread / 2.0 + spread * i as f32 / (count - 1) as f32
+        }
+    })
+}
+
+/// Fires one boss bullet from `origin` moving with `velocity`.
+fn fire_boss_bullet(
+    projectiles: &mut HostileProjectiles,
+    origin: Vec2,
+    velocity: Vec2,
+    damage: f32,
+    style: &BulletStyle,
+) {
+    projectiles.fire(HostileShot {
+        projectile: HostileProjectile::straight(style.source, damage, velocity, style.lifetime),
+        origin,
+        radius: style.radius,
+        color: style.color,
+    });
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::system::RunSystemOnce as _;
+
+    use super::*;
+    use crate::{
+        config::{BossPhaseSpec, StageBoss},
+        resources::HostileProjectilePool,
+    };
+
+    fn build_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(GameRng::from_seed(0));
+        app.init_resource::<HostileProjectilePool>();
+        app
+    }
+
+    fn advance(app: &mut App, secs: f32) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(secs));
+    }
+
+    fn phase(hp_threshold: f32, patterns: Vec<BossPattern>) -> BossPhaseSpec {
+        BossPhaseSpec {
+            hp_threshold,
+            speed_multiplier: 1.0,
+            interval: 1.0,
+            patterns,
+        }
+    }
+
+    fn spawn_boss(app: &mut App, pos: Vec2, phases: Vec<BossPhaseSpec>) -> Entity {
+        let boss = StageBoss {
+            name: "Test Boss".to_string(),
+            base_hp: 1000.0,
+            speed: 20.0,
+            damage: 10.0,
+            collider_radius: 30.0,
+            color: (1.0, 1.0, 1.0),
+            projectile_radius: 5.0,
+            projectile_lifetime: 3.0,
+            phases,
+        };
+        app.world_mut()
+            .spawn((
+                Enemy::from_type(EnemyType::BossDeath, 1.0),
+                BossPatterns::new(boss),
+                Transform::from_xyz(pos.x, pos.y, 5.0),
+            ))
+            .id()
+    }
+
+    fn spawn_player(app: &mut App, pos: Vec2) {
+        app.world_mut()
+            .spawn((Player, Transform::from_xyz(pos.x, pos.y, 0.0)));
+    }
+
+    /// Runs the patterns after a full interval has elapsed.
+    fn fire(app: &mut App) {
+        advance(app, 1.0);
+        app.world_mut().run_system_once(run_boss_patterns).unwrap();
+    }
+
+    fn bullets(app: &mut App) -> Vec<Vec2> {
+        let mut q = app.world_mut().query::<&HostileProjectile>();
+        q.iter(app.world()).map(|b| b.velocity).collect()
+    }
+
+    fn set_hp_fraction(app: &mut App, boss: Entity, fraction: f32) {
+        let mut enemy = app.world_mut().get_mut::<Enemy>(boss).unwrap();
+        enemy.current_hp = enemy.max_hp * fraction;
+    }
+
+    #[test]
+    fn radial_burst_fires_evenly_in_all_directions() {
+        let mut app = build_app();
+        spawn_player(&mut app, Vec2::new(500.0, 0.0));
+        spawn_boss(
+            &mut app,
+            Vec2::ZERO,
+            vec![phase(
+                1.0,
+                vec![BossPattern::RadialBurst {
+                    count: 8,
+                    speed: 100.0,
+                    damage: 5.0,
+                }],
+            )],
+        );
+
+        fire(&mut app);
+
+        let velocities = bullets(&mut app);
+        assert_eq!(velocities.len(), 8);
+        let sum: Vec2 = velocities.iter().sum();
+        assert!(sum.length() < 1e-3, "burst must be symmetric, got {sum}");
+        assert!(velocities.iter().all(|v| (v.length() - 100.0).abs() < 1e-3));
+    }
+
+    #[test]
+    fn aimed_volley_is_centred_on_the_player() {
+        let mut app = build_app();
+        spawn_player(&mut app, Vec2::new(0.0, 300.0));
+        spawn_boss(
+            &mut app,
+            Vec2::ZERO,
+            vec![phase(
+                1.0,
+                vec![BossPattern::AimedVolley {
+                    count: 3,
+                    spread_deg: 30.0,
+                    speed: 200.0,
+                    damage: 5.0,
+                }],
+            )],
+        );
+
+        fire(&mut app);
+
+        let velocities = bullets(&mut app);
+        assert_eq!(velocities.len(), 3);
+        let mean = velocities.iter().sum::<Vec2>() / 3.0;
+        assert!(mean.x.abs() < 1e-3 && mean.y > 0.0, "mean heading {mean}");
+    }
+
+    #[test]
+    fn summon_spawns_regular_enemies_around_the_boss() {
+        let mut app = build_app();
+        spawn_player(&mut app, Vec2::new(500.0, 0.0));
+        spawn_boss(
+            &mut app,
+            Vec2::ZERO,
+            vec![phase(
+                1.0,
+                vec![BossPattern::Summon {
+                    enemy: EnemyType::Ghost,
+                    count: 4,
+                    radius: 80.0,
+                }],
+            )],
+        );
+
+        fire(&mut app);
+
+        let mut q = app.world_mut().query::<(&Enemy, &Transform)>();
+        let ghosts: Vec<_> = q
+            .iter(app.world())
+            .filter(|(e, _)| e.enemy_type == EnemyType::Ghost)
+            .map(|(_, t)| t.translation.truncate().length())
+            .collect();
+        assert_eq!(ghosts.len(), 4);
+        assert!(ghosts.iter().all(|d| (d - 80.0).abs() < 1e-3));
+    }
+
+    #[test]
+    fn teleport_lands_at_distance_from_player() {
+        let mut app = build_app();
+        let player_pos = Vec2::new(100.0, 100.0);
+        spawn_player(&mut app, player_pos);
+        let boss = spawn_boss(
+            &mut app,
+            Vec2::new(-900.0, 0.0),
+            vec![phase(1.0, vec![BossPattern::Teleport { distance: 250.0 }])],
+        );
+
+        fire(&mut app);
+
+        let pos = app.world().get::<Transform>(boss).unwrap().translation;
+        assert!((pos.truncate().distance(player_pos) - 250.0).abs() < 1e-2);
+        assert_eq!(pos.z, 5.0, "teleport keeps the draw layer");
+    }
+
+    #[test]
+    fn charge_dashes_and_holds_the_pattern_timer() {
+        let mut app = build_app();
+        spawn_player(&mut app, Vec2::new(1000.0, 0.0));
+        let boss = spawn_boss(
+            &mut app,
+            Vec2::ZERO,
+            vec![phase(
+                1.0,
+                vec![
+                    BossPattern::Charge {
+                        speed: 400.0,
+                        duration: 0.5,
+                    },
+                    BossPattern::Teleport { distance: 10.0 },
+                ],
+            )],
+        );
+
+        fire(&mut app);
+        advance(&mut app, 0.25);
+        app.world_mut()
+            .run_system_once(move_pattern_bosses)
+            .unwrap();
+        let x = app.world().get::<Transform>(boss).unwrap().translation.x;
+        assert!((x - 100.0).abs() < 1e-2, "charge speed, got x = {x}");
+
+        // The teleport is due but must wait for the charge to end.
+        advance(&mut app, 1.0);
+        app.world_mut().run_system_once(run_boss_patterns).unwrap();
+        let x_after = app.world().get::<Transform>(boss).unwrap().translation.x;
+        assert_eq!(x_after, x, "no pattern runs mid-charge");
+    }
+
+    #[test]
+    fn patterns_loop_through_the_list() {
+        let mut app = build_app();
+        spawn_player(&mut app, Vec2::new(500.0, 0.0));
+        let burst = |count| BossPattern::RadialBurst {
+            count,
+            speed: 100.0,
+            damage: 1.0,
+        };
+        spawn_boss(
+            &mut app,
+            Vec2::ZERO,
+            vec![phase(1.0, vec![burst(1), burst(2)])],
+        );
+
+        fire(&mut app);
+        fire(&mut app);
+        fire(&mut app);
+
+        assert_eq!(bullets(&mut app).len(), 1 + 2 + 1);
+    }
+
+    #[test]
+    fn phase_advances_on_hp_threshold_and_can_skip() {
+        let mut app = build_app();
+        let boss = spawn_boss(
+            &mut app,
+            Vec2::ZERO,
+            vec![phase(1.0, vec![]), phase(0.6, vec![]), phase(0.3, vec![])],
+        );
+
+        set_hp_fraction(&mut app, boss, 0.7);
+        app.world_mut()
+            .run_system_once(advance_boss_pattern_phase)
+            .unwrap();
+        assert_eq!(app.world().get::<BossPatterns>(boss).unwrap().phase, 0);
+
+        // One big hit skips straight past phase 2.
+        set_hp_fraction(&mut app, boss, 0.2);
+        app.world_mut()
+            .run_system_once(advance_boss_pattern_phase)
+            .unwrap();
+        let patterns = app.world().get::<BossPatterns>(boss).unwrap();
+        assert_eq!(patterns.phase, 2);
+        assert_eq!(patterns.next_pattern, 0);
+    }
+
+    #[test]
+    fn phase_speed_multiplier_scales_chase() {
+        let mut app = build_app();
+        spawn_player(&mut app, Vec2::new(1000.0, 0.0));
+        let mut fast = phase(1.0, vec![]);
+        fast.speed_multiplier = 2.0;
+        let boss = spawn_boss(&mut app, Vec2::ZERO, vec![fast]);
+
+        advance(&mut app, 1.0);
+        app.world_mut()
+            .run_system_once(move_pattern_bosses)
+            .unwrap();
+
+        let speed = app.world().get::<Enemy>(boss).unwrap().move_speed;
+        let x = app.world().get::<Transform>(boss).unwrap().translation.x;
+        assert!((x - speed * 2.0).abs() < 1e-2, "got x = {x}");
+    }
+}
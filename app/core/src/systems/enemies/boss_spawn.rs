@@ -1,4 +1,4 @@
-//! Boss spawn system — triggers Boss Death at the 30-minute mark.
+//! Boss spawn system — triggers the final boss at the 30-minute mark.
 //!
 //! [`check_boss_spawn`] runs every frame during [`AppState::Playing`].
 //! When `GameData.elapsed_time` first reaches `boss_spawn_time` (default
@@ -7,15 +7,21 @@
 //! 1. Sets `GameData.boss_spawned = true` to prevent re-entry.
 //! 2. Sets `EnemySpawner.active = false` to stop normal enemy spawning.
 //! 3. Emits a [`BossSpawnedEvent`] for UI and other listeners.
-//! 4. Spawns the Boss Death entity just off-screen above the player with
-//!    [`BossPhase::Phase1`], a large red placeholder sprite, and fixed HP
-//!    (no difficulty scaling per design spec).
+//! 4. Spawns the boss just off-screen above the player with fixed HP (no
+//!    difficulty scaling per design spec).
+//!
+//! When the selected stage names its own [`StageBoss`] (`boss` in
+//! `stage.ron`), that boss is spawned with a [`BossPatterns`] component and
+//! driven by [`super::boss_pattern`].  Otherwise Boss Death is spawned with
+//! [`BossPhase::Phase1`] and a large red placeholder sprite.  Either way the
+//! enemy type is [`EnemyType::BossDeath`] — the final-boss slot — so
+//! victory, loot and audio treat every stage boss alike.
 
 use bevy::prelude::*;
 
 use crate::{
-    components::{CircleCollider, Enemy, EnemyAI, GameSessionEntity, Player},
-    config::{GameParams, StageParams},
+    components::{BossPatterns, CircleCollider, Enemy, EnemyAI, GameSessionEntity, Player},
+    config::{EnemyParams, GameParams, StageBoss, StageParams},
     events::BossSpawnedEvent,
     resources::{EnemySpawner, GameData, SelectedStage},
     types::{AIType, BossPhase, EnemyType},
@@ -38,7 +44,8 @@ const BOSS_SPAWN_OFFSET_Y: f32 = 700.0;
 // System
 // ---------------------------------------------------------------------------
 
-/// Checks whether the 30-minute mark has been reached and spawns Boss Death.
+/// Checks whether the 30-minute mark has been reached and spawns the stage's
+/// final boss (Boss Death unless the stage names its own).
 ///
 /// This is a no-op once `game_data.boss_spawned` is `true`, so it is safe to
 /// keep registered in `Update` without any per-frame cost after the trigger.
@@ -52,6 +59,7 @@ pub fn check_boss_spawn(
     stage_params: StageParams,
     selected_stage: Option<Res<SelectedStage>>,
     player_q: Query<&Transform, With<Player>>,
+    enemy_cfg: EnemyParams,
 ) {
     // Already spawned — nothing to do.
     if game_data.boss_spawned {
@@ -82,12 +90,25 @@ pub fn check_boss_spawn(
 
     // HP is fixed at the base value — no difficulty scaling per design spec.
     // Stage boss multipliers are applied on top of the base stats.
-    let (boss_hp_mult, boss_speed_mult) = selected_stage
+    let stage_entry = selected_stage
         .as_deref()
-        .and_then(|s| stage_params.get().map(|c| c.entry_for(s.0)))
+        .and_then(|s| stage_params.get().map(|c| c.entry_for(s.0)));
+    let (boss_hp_mult, boss_speed_mult) = stage_entry
         .map(|e| (e.boss_hp_multiplier, e.boss_speed_multiplier))
         .unwrap_or((1.0, 1.0));
 
+    if let Some(stage_boss) = stage_entry.and_then(|e| e.boss.as_ref()) {
+        spawn_stage_boss(
+            &mut commands,
+            stage_boss,
+            spawn_pos,
+            boss_hp_mult,
+            boss_speed_mult,
+            &enemy_cfg,
+        );
+        return;
+    }
+
     let mut enemy = Enemy::from_type(EnemyType::BossDeath, 1.0);
     if (boss_hp_mult - 1.0).abs() > f32::EPSILON {
         enemy.max_hp *= boss_hp_mult;
@@ -119,6 +140,50 @@ pub fn check_boss_spawn(
     ));
 }
 
+/// Spawns a pattern-driven [`StageBoss`] at `position`.
+///
+/// XP, gold and resistances come from the Boss Death entry in `enemy.ron`;
+/// HP, speed, contact damage and size come from the stage's definition, with
+/// the stage's boss multipliers on top.
+fn spawn_stage_boss(
+    commands: &mut Commands,
+    boss: &StageBoss,
+    position: Vec2,
+    hp_mult: f32,
+    speed_mult: f32,
+    enemy_cfg: &EnemyParams,
+) {
+    let mut enemy = match enemy_cfg.get() {
+        Some(c) => Enemy::from_config(EnemyType::BossDeath, c.stats_for(EnemyType::BossDeath), 1.0),
+        None => Enemy::from_type(EnemyType::BossDeath, 1.0),
+    };
+    enemy.max_hp = boss.base_hp * hp_mult;
+    enemy.current_hp = enemy.max_hp;
+    enemy.move_speed = boss.speed * speed_mult;
+    enemy.damage = boss.damage;
+    let (r, g, b) = boss.color;
+
+    commands.spawn((
+        GameSessionEntity,
+        enemy,
+        BossPatterns::new(boss.clone()),
+        EnemyAI {
+            ai_type: AIType::BossMultiPhase,
+            attack_timer: 0.0,
+            attack_range: 0.0,
+        },
+        CircleCollider {
+            radius: boss.collider_radius,
+        },
+        Sprite {
+            color: Color::srgb(r, g, b),
+            custom_size: Some(Vec2::splat(boss.collider_radius * 2.0)),
+            ..default()
+        },
+        Transform::from_xyz(position.x, position.y, 5.0),
+    ));
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -322,6 +387,80 @@ mod tests {
         );
     }
 
+    /// A stage that names its own boss spawns it with patterns instead of
+    /// Boss Death's phases, scaled by the stage's boss multipliers.
+    #[test]
+    fn stage_boss_replaces_boss_death() {
+        use crate::config::{
+            BossPattern, BossPhaseSpec, StageBoss, StageConfig, StageConfigHandle, StageEntryConfig,
+        };
+        use crate::types::StageType;
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, bevy::asset::AssetPlugin::default()));
+        app.init_asset::<StageConfig>();
+        app.insert_resource(GameData::default());
+        app.insert_resource(EnemySpawner::default());
+        app.insert_resource(SelectedStage(StageType::InlaidLibrary));
+        app.add_message::<BossSpawnedEvent>();
+
+        let boss = StageBoss {
+            name: "Library Lich".to_string(),
+            base_hp: 1000.0,
+            speed: 20.0,
+            damage: 33.0,
+            collider_radius: 40.0,
+            color: (0.5, 0.3, 0.9),
+            projectile_radius: 6.0,
+            projectile_lifetime: 4.0,
+            phases: vec![BossPhaseSpec {
+                hp_threshold: 1.0,
+                speed_multiplier: 1.0,
+                interval: 2.0,
+                patterns: vec![BossPattern::Teleport { distance: 100.0 }],
+            }],
+        };
+        let entry = StageEntryConfig {
+            display_name: "Test".to_string(),
+            enemy_types: vec![EnemyType::Bat],
+            enemy_hp_multiplier: 1.0,
+            enemy_speed_multiplier: 1.0,
+            spawn_interval_multiplier: 1.0,
+            max_enemies_multiplier: 1.0,
+            boss_hp_multiplier: 2.0,
+            boss_speed_multiplier: 1.0,
+            waves: Vec::new(),
+            formations: Vec::new(),
+            mini_bosses: Vec::new(),
+            boss: Some(boss),
+        };
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<StageConfig>>()
+            .add(StageConfig {
+                mad_forest: entry.clone(),
+                inlaid_library: entry.clone(),
+                dairy_plant: entry,
+            });
+        app.world_mut().insert_resource(StageConfigHandle(handle));
+        app.world_mut().resource_mut::<GameData>().elapsed_time = DEFAULT_BOSS_SPAWN_TIME;
+
+        app.world_mut()
+            .run_system_once(check_boss_spawn)
+            .expect("check_boss_spawn should run");
+
+        let mut q = app
+            .world_mut()
+            .query::<(&Enemy, &BossPatterns, Option<&BossPhase>)>();
+        let (enemy, patterns, phase) = q.single(app.world()).expect("stage boss missing");
+        assert_eq!(enemy.enemy_type, EnemyType::BossDeath);
+        assert_eq!(enemy.max_hp, 2000.0);
+        assert_eq!(enemy.damage, 33.0);
+        assert_eq!(patterns.boss.name, "Library Lich");
+        assert!(phase.is_none(), "hand-coded phases must not drive it");
+        assert_eq!(boss_events(&app).len(), 1);
+    }
+
     /// Elapsed time exactly at threshold triggers the spawn (boundary check).
     #[test]
     fn spawn_triggers_exactly_at_threshold() {
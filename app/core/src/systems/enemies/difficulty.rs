@@ -4,26 +4,44 @@
 //! keeps [`EnemySpawner::difficulty_multiplier`] and
 //! [`EnemySpawner::spawn_interval`] in sync with the elapsed run time.
 //!
-//! ## Scaling formula
+//! ## Wave-driven scaling
+//!
+//! While a [`StageWave`] of the selected stage is active, the wave sets the
+//! pace:
+//!
+//! ```text
+//! difficulty_multiplier = lerp(difficulty_start, difficulty_end, wave progress)
+//! spawn_interval        = wave.spawn_interval × spawn_interval_multiplier
+//!                         / difficulty_multiplier
+//! ```
+//!
+//! ## Fallback scaling formula
+//!
+//! Outside every wave (or before the stage config loads):
 //!
 //! ```text
 //! difficulty_multiplier = 1.0 + floor(elapsed_secs / 60) × 0.1
-//! spawn_interval        = DEFAULT_ENEMY_SPAWN_BASE_INTERVAL / difficulty_multiplier
+//! spawn_interval        = spawn_base_interval × spawn_interval_multiplier
+//!                         / difficulty_multiplier
 //! ```
 //!
-//! Examples:
+//! Examples (multiplier 1.0):
 //! |  Time  | Multiplier | Spawn interval (base 0.5 s) |
 //! |--------|------------|----------------------------|
 //! |  0 min |    1.0×    |  0.500 s                   |
 //! |  1 min |    1.1×    |  0.455 s                   |
 //! | 10 min |    2.0×    |  0.250 s                   |
 //! | 30 min |    4.0×    |  0.125 s                   |
+//!
+//! Both multipliers are capped at `difficulty_max` from `enemy.ron`.
+//!
+//! [`StageWave`]: crate::config::StageWave
 
 use bevy::prelude::*;
 
 use crate::{
-    config::EnemyParams,
-    resources::{EnemySpawner, GameData},
+    config::{EnemyParams, StageParams},
+    resources::{EnemySpawner, GameData, SelectedStage},
 };
 
 // ---------------------------------------------------------------------------
@@ -73,18 +91,37 @@ pub fn effective_spawn_interval(difficulty: f32) -> f32 {
 /// when config is loaded; otherwise the `DEFAULT_*` fallback constants are used.
 ///
 /// Updates:
-/// - `difficulty_multiplier` — grows by 0.1 per minute, capped at `difficulty_max`
-/// - `spawn_interval`        — derived as `base_interval / difficulty_multiplier`
+/// - `difficulty_multiplier` — the active wave's ramp, or 0.1 more per
+///   minute outside every wave; capped at `difficulty_max`
+/// - `spawn_interval`        — the wave's (or `enemy.ron`'s base) interval
+///   × the stage's `spawn_interval_multiplier` / `difficulty_multiplier`
 pub fn update_difficulty(
     game_data: Res<GameData>,
     mut spawner: ResMut<EnemySpawner>,
     enemy_cfg: EnemyParams,
+    stage_params: StageParams,
+    selected_stage: Option<Res<SelectedStage>>,
 ) {
-    let base_interval = enemy_cfg.spawn_base_interval();
+    let elapsed = game_data.elapsed_time;
     let diff_max = enemy_cfg.difficulty_max();
-
-    spawner.difficulty_multiplier = difficulty_from_elapsed(game_data.elapsed_time, diff_max);
-    spawner.spawn_interval = base_interval / spawner.difficulty_multiplier.max(1.0);
+    let stage_entry = selected_stage
+        .as_deref()
+        .and_then(|s| stage_params.get().map(|c| c.entry_for(s.0)));
+    let interval_mult = stage_entry.map_or(1.0, |e| e.spawn_interval_multiplier);
+
+    let (difficulty, base_interval) = match stage_entry.and_then(|e| e.wave_at(elapsed)) {
+        Some(wave) => (
+            wave.difficulty_at(elapsed).min(diff_max),
+            wave.spawn_interval,
+        ),
+        None => (
+            difficulty_from_elapsed(elapsed, diff_max),
+            enemy_cfg.spawn_base_interval(),
+        ),
+    };
+
+    spawner.difficulty_multiplier = difficulty;
+    spawner.spawn_interval = base_interval * interval_mult / difficulty.max(1.0);
 }
 
 // ---------------------------------------------------------------------------
@@ -272,4 +309,98 @@ mod tests {
             "spawn interval at 10 min ({interval_10min}) should be shorter than at start ({interval_start})"
         );
     }
+
+    // -----------------------------------------------------------------------
+    // Stage waves
+    // -----------------------------------------------------------------------
+
+    /// An active wave sets both the difficulty ramp and the spawn interval,
+    /// scaled by the stage's `spawn_interval_multiplier`.
+    #[test]
+    fn update_difficulty_follows_active_wave() {
+        use crate::config::{
+            StageConfig, StageConfigHandle, StageEntryConfig, StageWave, WaveEnemy,
+        };
+        use crate::types::{EnemyType, StageType};
+
+        let mut app = build_app();
+        app.add_plugins(bevy::asset::AssetPlugin::default());
+        app.init_asset::<StageConfig>();
+        app.insert_resource(SelectedStage(StageType::MadForest));
+
+        let entry = StageEntryConfig {
+            display_name: "Test".to_string(),
+            enemy_types: vec![EnemyType::Bat],
+            enemy_hp_multiplier: 1.0,
+            enemy_speed_multiplier: 1.0,
+            spawn_interval_multiplier: 0.5,
+            max_enemies_multiplier: 1.0,
+            boss_hp_multiplier: 1.0,
+            boss_speed_multiplier: 1.0,
+            waves: vec![StageWave {
+                start_min: 0.0,
+                end_min: 10.0,
+                enemies: vec![WaveEnemy {
+                    enemy: EnemyType::Bat,
+                    weight: 1.0,
+                }],
+                min_count: 0,
+                burst: 1,
+                spawn_interval: 0.4,
+                difficulty_start: 1.5,
+                difficulty_end: 2.5,
+            }],
+            formations: Vec::new(),
+            mini_bosses: Vec::new(),
+            boss: None,
+        };
+        let config = StageConfig {
+            mad_forest: entry.clone(),
+            inlaid_library: entry.clone(),
+            dairy_plant: entry,
+        };
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<StageConfig>>()
+            .add(config);
+        app.world_mut().insert_resource(StageConfigHandle(handle));
+
+        // Halfway through the wave → difficulty 2.0, interval 0.4 × 0.5 / 2.0.
+        app.world_mut().resource_mut::<GameData>().elapsed_time = 300.0;
+        app.world_mut()
+            .run_system_once(update_difficulty)
+            .expect("update_difficulty should run");
+
+        let spawner = app.world().resource::<EnemySpawner>();
+        assert!(
+            (spawner.difficulty_multiplier - 2.0).abs() < EPS,
+            "expected the wave's ramp midpoint 2.0, got {}",
+            spawner.difficulty_multiplier
+        );
+        assert!(
+            (spawner.spawn_interval - 0.1).abs() < EPS,
+            "expected interval 0.1, got {}",
+            spawner.spawn_interval
+        );
+
+        // After the wave ends → the fixed per-minute ramp and the enemy.ron
+        // base interval, still scaled by the stage multiplier.
+        app.world_mut().resource_mut::<GameData>().elapsed_time = 600.0;
+        app.world_mut()
+            .run_system_once(update_difficulty)
+            .expect("update_difficulty should run");
+
+        let spawner = app.world().resource::<EnemySpawner>();
+        assert!(
+            (spawner.difficulty_multiplier - 2.0).abs() < EPS,
+            "expected the fallback 2.0 at 10 min, got {}",
+            spawner.difficulty_multiplier
+        );
+        let expected = DEFAULT_ENEMY_SPAWN_BASE_INTERVAL * 0.5 / 2.0;
+        assert!(
+            (spawner.spawn_interval - expected).abs() < EPS,
+            "expected interval {expected}, got {}",
+            spawner.spawn_interval
+        );
+    }
 }
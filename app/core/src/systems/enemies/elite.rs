@@ -0,0 +1,345 @@
+//! Elite enemy variants.
+//!
+//! [`spawn_enemies`](super::spawn::spawn_enemies) rolls [`roll_elite`] for
+//! every regular spawn; the chance starts at `elite.base_chance` (from
+//! `enemy.ron`) and rises with [`EnemySpawner::difficulty_multiplier`] up to
+//! `elite.max_chance`.  A winning roll attaches an [`Elite`] carrying a random
+//! [`EliteModifier`].
+//!
+//! - [`init_elites`] scales a fresh elite's [`Enemy`] stats (HP, XP, size and,
+//!   for Swift, speed) and tints its sprite with [`EliteModifier::tint`].
+//! - Armored damage reduction lives in
+//!   [`apply_damage_to_enemies`](crate::systems::damage::apply_damage_to_enemies)
+//!   and Vampiric healing in
+//!   [`enemy_player_collision`](crate::systems::player::collision::enemy_player_collision).
+//! - [`split_elites_on_death`] spawns smaller copies where a Splitting elite
+//!   died.
+//!
+//! The elite's modifier travels on [`EnemyDiedEvent::elite`]; the gold drop
+//! is guaranteed for elites and the multiplied XP value yields a bigger gem.
+
+use bevy::prelude::*;
+use rand::RngExt;
+
+use crate::{
+    components::{CircleCollider, Elite, Enemy},
+    config::{EliteConfig, EnemyParams, StageParams},
+    events::EnemyDiedEvent,
+    resources::{EnemySpawner, SelectedStage},
+    systems::enemies::spawn::{fallback_collider_radius, spawn_enemy},
+    types::{EliteModifier, EnemyType},
+};
+
+/// Distance (px) between the death position and each split copy.
+const SPLIT_OFFSET: f32 = 12.0;
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Rolls whether a regular spawn at `difficulty` becomes an elite, and which
+/// modifier it gets.
+///
+/// Returns `None` for a regular enemy.  Only called for types passing
+/// [`can_be_elite`].
+pub fn roll_elite(
+    rng: &mut impl RngExt,
+    difficulty: f32,
+    cfg: &EliteConfig,
+) -> Option<EliteModifier> {
+    if rng.random::<f32>() >= cfg.chance_at(difficulty) {
+        return None;
+    }
+    let index = rng.random_range(0..EliteModifier::ALL.len());
+    Some(EliteModifier::ALL[index])
+}
+
+/// `true` for enemy types that may roll an elite modifier.
+pub(crate) fn can_be_elite(enemy_type: EnemyType) -> bool {
+    !enemy_type.is_boss() && enemy_type != EnemyType::MiniDeath
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Applies elite scaling to every enemy that received an [`Elite`] this frame.
+///
+/// - Max HP × `hp_multiplier` (current HP refilled), XP × `xp_multiplier`.
+/// - Sprite and collider × `size_multiplier`.
+/// - Swift: move speed × `swift_speed_multiplier`.
+/// - Sprite colour replaced by the modifier's tint, keeping the original
+///   alpha so translucent enemies stay translucent.
+pub fn init_elites(
+    mut elite_q: Query<(&Elite, &mut Enemy, &mut Sprite, &mut CircleCollider), Added<Elite>>,
+    enemy_cfg: EnemyParams,
+) {
+    if elite_q.is_empty() {
+        return;
+    }
+    let cfg = enemy_cfg.elite();
+
+    for (elite, mut enemy, mut sprite, mut collider) in elite_q.iter_mut() {
+        enemy.max_hp *= cfg.hp_multiplier.max(0.0);
+        enemy.current_hp = enemy.max_hp;
+        enemy.xp_value = (enemy.xp_value as f32 * cfg.xp_multiplier.max(0.0)).round() as u32;
+        if elite.modifier == EliteModifier::Swift {
+            enemy.move_speed *= cfg.swift_speed_multiplier.max(0.0);
+        }
+
+        let size = cfg.size_multiplier.max(0.0);
+        collider.radius *= size;
+        sprite.custom_size = sprite.custom_size.map(|s| s * size);
+        sprite.color = elite.modifier.tint().with_alpha(sprite.color.alpha());
+    }
+}
+
+/// Spawns `split_count` regular copies where a Splitting elite died.
+///
+/// Copies use the dead elite's type at the current difficulty and the
+/// selected stage's HP and speed multipliers, with `split_hp_fraction` of
+/// that HP and `split_size_multiplier` of its normal size.  They are never
+/// elites themselves, so a split cannot chain.
+pub fn split_elites_on_death(
+    mut commands: Commands,
+    mut died_events: MessageReader<EnemyDiedEvent>,
+    spawner: Res<EnemySpawner>,
+    enemy_cfg: EnemyParams,
+    stage_params: StageParams,
+    selected_stage: Option<Res<SelectedStage>>,
+) {
+    let cfg = enemy_cfg.elite();
+    let (stage_hp_mult, stage_speed_mult) = selected_stage
+        .as_deref()
+        .and_then(|s| stage_params.get().map(|c| c.entry_for(s.0)))
+        .map(|e| (e.enemy_hp_multiplier, e.enemy_speed_multiplier))
+        .unwrap_or((1.0, 1.0));
+
+    for event in died_events.read() {
+        if event.elite != Some(EliteModifier::Splitting) || cfg.split_count == 0 {
+            continue;
+        }
+        let cfg_stats = enemy_cfg
+            .get()
+            .map(|c| c.stats_for(event.enemy_type).clone());
+        let collider_radius = cfg_stats
+            .as_ref()
+            .map(|s| s.collider_radius)
+            .unwrap_or_else(|| fallback_collider_radius(event.enemy_type))
+            * cfg.split_size_multiplier.max(0.0);
+
+        for i in 0..cfg.split_count {
+            let angle = std::f32::consts::TAU * i as f32 / cfg.split_count as f32;
+            spawn_enemy(
+                &mut commands,
+                event.enemy_type,
+                event.position + Vec2::from_angle(angle) * SPLIT_OFFSET,
+                spawner.difficulty_multiplier,
+                stage_hp_mult * cfg.split_hp_fraction.max(0.0),
+                stage_speed_mult,
+                collider_radius,
+                cfg_stats.as_ref(),
+            );
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce as _;
+
+    use super::*;
+    use crate::resources::GameRng;
+
+    fn build_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<EnemyDiedEvent>();
+        app.insert_resource(EnemySpawner::default());
+        app
+    }
+
+    fn spawn_elite(app: &mut App, modifier: EliteModifier) -> Entity {
+        app.world_mut()
+            .spawn((
+                Enemy::from_type(EnemyType::Bat, 1.0),
+                Elite { modifier },
+                CircleCollider { radius: 8.0 },
+                Sprite {
+                    color: Color::srgba(0.5, 0.1, 0.8, 0.6),
+                    custom_size: Some(Vec2::splat(16.0)),
+                    ..default()
+                },
+            ))
+            .id()
+    }
+
+    #[test]
+    fn roll_never_fires_at_zero_chance_and_always_at_full() {
+        let mut rng = GameRng::from_seed(7);
+        let never = EliteConfig {
+            base_chance: 0.0,
+            chance_per_difficulty: 0.0,
+            ..default()
+        };
+        let always = EliteConfig {
+            base_chance: 1.0,
+            max_chance: 1.0,
+            ..default()
+        };
+        for _ in 0..100 {
+            assert!(roll_elite(rng.rng(), 10.0, &never).is_none());
+            assert!(roll_elite(rng.rng(), 1.0, &always).is_some());
+        }
+    }
+
+    #[test]
+    fn elite_stats_are_scaled_and_sprite_tinted() {
+        let mut app = build_app();
+        let base = Enemy::from_type(EnemyType::Bat, 1.0);
+        let entity = spawn_elite(&mut app, EliteModifier::Swift);
+
+        app.world_mut().run_system_once(init_elites).unwrap();
+
+        let cfg = EliteConfig::default();
+        let enemy = app.world().get::<Enemy>(entity).unwrap();
+        assert_eq!(enemy.max_hp, base.max_hp * cfg.hp_multiplier);
+        assert_eq!(enemy.current_hp, enemy.max_hp);
+        assert_eq!(
+            enemy.move_speed,
+            base.move_speed * cfg.swift_speed_multiplier
+        );
+        assert!(enemy.xp_value > base.xp_value);
+        let collider = app.world().get::<CircleCollider>(entity).unwrap();
+        assert_eq!(collider.radius, 8.0 * cfg.size_multiplier);
+        let sprite = app.world().get::<Sprite>(entity).unwrap();
+        assert_eq!(
+            sprite.color,
+            EliteModifier::Swift.tint().with_alpha(0.6),
+            "tint must keep the original alpha"
+        );
+    }
+
+    #[test]
+    fn non_swift_elite_keeps_speed() {
+        let mut app = build_app();
+        let base = Enemy::from_type(EnemyType::Bat, 1.0);
+        let entity = spawn_elite(&mut app, EliteModifier::Armored);
+
+        app.world_mut().run_system_once(init_elites).unwrap();
+
+        let enemy = app.world().get::<Enemy>(entity).unwrap();
+        assert_eq!(enemy.move_speed, base.move_speed);
+    }
+
+    #[test]
+    fn splitting_elite_leaves_smaller_copies() {
+        let mut app = build_app();
+        let base = Enemy::from_type(EnemyType::Bat, 1.0);
+        for elite in [
+            Some(EliteModifier::Splitting),
+            Some(EliteModifier::Vampiric),
+            None,
+        ] {
+            app.world_mut().write_message(EnemyDiedEvent {
+                entity: Entity::PLACEHOLDER,
+                position: Vec2::new(50.0, 0.0),
+                enemy_type: EnemyType::Bat,
+                xp_value: 15,
+                gold_chance: 0.05,
+                elite,
+                mini_boss: None,
+            });
+        }
+
+        app.world_mut()
+            .run_system_once(split_elites_on_death)
+            .unwrap();
+
+        let cfg = EliteConfig::default();
+        let mut q = app
+            .world_mut()
+            .query_filtered::<(&Enemy, &Transform), Without<Elite>>();
+        let copies: Vec<_> = q.iter(app.world()).collect();
+        assert_eq!(copies.len(), cfg.split_count as usize);
+        for (enemy, tf) in copies {
+            assert_eq!(enemy.enemy_type, EnemyType::Bat);
+            assert!((enemy.max_hp - base.max_hp * cfg.split_hp_fraction).abs() < 1e-4);
+            assert!(
+                tf.translation.truncate().distance(Vec2::new(50.0, 0.0)) <= SPLIT_OFFSET + 1e-3
+            );
+        }
+    }
+
+    /// Split copies keep the stage's HP and speed multipliers, so a hard
+    /// stage's splits are as tough and as fast as its regular spawns.
+    #[test]
+    fn split_copies_use_stage_multipliers() {
+        use crate::config::{StageConfig, StageConfigHandle, StageEntryConfig};
+        use crate::types::StageType;
+
+        let mut app = build_app();
+        app.add_plugins(bevy::asset::AssetPlugin::default());
+        app.init_asset::<StageConfig>();
+        app.insert_resource(SelectedStage(StageType::DairyPlant));
+        let entry = StageEntryConfig {
+            display_name: "Test".to_string(),
+            enemy_types: vec![EnemyType::Bat],
+            enemy_hp_multiplier: 1.5,
+            enemy_speed_multiplier: 1.2,
+            spawn_interval_multiplier: 1.0,
+            max_enemies_multiplier: 1.0,
+            boss_hp_multiplier: 1.0,
+            boss_speed_multiplier: 1.0,
+            waves: Vec::new(),
+            formations: Vec::new(),
+            mini_bosses: Vec::new(),
+            boss: None,
+        };
+        let config = StageConfig {
+            mad_forest: entry.clone(),
+            inlaid_library: entry.clone(),
+            dairy_plant: entry,
+        };
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<StageConfig>>()
+            .add(config);
+        app.world_mut().insert_resource(StageConfigHandle(handle));
+        app.world_mut().write_message(EnemyDiedEvent {
+            entity: Entity::PLACEHOLDER,
+            position: Vec2::ZERO,
+            enemy_type: EnemyType::Bat,
+            xp_value: 15,
+            gold_chance: 0.05,
+            elite: Some(EliteModifier::Splitting),
+            mini_boss: None,
+        });
+
+        app.world_mut()
+            .run_system_once(split_elites_on_death)
+            .unwrap();
+
+        let base = Enemy::from_type(EnemyType::Bat, 1.0);
+        let cfg = EliteConfig::default();
+        let mut q = app.world_mut().query::<&Enemy>();
+        let copies: Vec<&Enemy> = q.iter(app.world()).collect();
+        assert_eq!(copies.len(), cfg.split_count as usize);
+        for enemy in copies {
+            assert!((enemy.max_hp - base.max_hp * 1.5 * cfg.split_hp_fraction).abs() < 1e-4);
+            assert!((enemy.move_speed - base.move_speed * 1.2).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn bosses_never_become_elites() {
+        assert!(can_be_elite(EnemyType::Bat));
+        assert!(!can_be_elite(EnemyType::BossDeath));
+        assert!(!can_be_elite(EnemyType::MiniBoss));
+        assert!(!can_be_elite(EnemyType::MiniDeath));
+    }
+}
@@ -0,0 +1,528 @@
+//! Formation spawn events — rings, swarms and walls.
+//!
+//! [`spawn_formations`] fires the selected stage's scheduled
+//! [`FormationEvent`]s (`formations` in `stage.ron`) once the run reaches
+//! their `at_min`.  Every member spawns through the regular
+//! [`spawn_enemy`] path and then has its [`EnemyAI`] overridden with
+//! [`AIType::Formation`] plus a [`FormationMotion`]:
+//!
+//! | Kind  | Spawn shape                              | Motion                             |
+//! |-------|------------------------------------------|------------------------------------|
+//! | Ring  | Circle just outside the viewport corners | Converge on the player, then chase |
+//! | Swarm | Tight cluster on a random viewport edge  | Straight line through the player   |
+//! | Wall  | Line spanning one viewport edge          | Straight sweep to the far side     |
+//!
+//! [`move_formation_enemies`] drives the override.  Sweeping enemies never
+//! turn back; they are removed by the regular distance cull once they have
+//! crossed the screen.
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use rand::RngExt;
+
+use crate::{
+    components::{Enemy, EnemyAI, FormationMotion, HitStun, Player, StatusEffects},
+    config::{EnemyParams, FormationEvent, GameParams, StageParams},
+    resources::{EnemySpawner, FormationScheduler, GameData, GameRng, SelectedStage},
+    systems::enemies::spawn::{default_enemy_ai, fallback_collider_radius, spawn_enemy},
+    types::{AIType, FormationKind},
+};
+
+// ---------------------------------------------------------------------------
+// Fallback constants (used when RON config is not yet loaded)
+// ---------------------------------------------------------------------------
+
+/// Maximum simultaneous enemies; formations are truncated to fit under it.
+const DEFAULT_ENEMY_MAX_COUNT: usize = 500;
+/// Window width used to size formations (pixels).
+const DEFAULT_WINDOW_WIDTH: f32 = 1280.0;
+/// Window height used to size formations (pixels).
+const DEFAULT_WINDOW_HEIGHT: f32 = 720.0;
+/// Extra pixels beyond the viewport edge at which formations appear.
+const DEFAULT_SPAWN_MARGIN: f32 = 60.0;
+
+/// Distance from the ring centre at which members revert to their normal AI.
+const RING_RELEASE_RADIUS: f32 = 80.0;
+/// Radius of the random cluster a swarm spawns in (pixels).
+const SWARM_SPREAD: f32 = 60.0;
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Fires every scheduled [`FormationEvent`] whose `at_min` was reached since
+/// the previous frame.
+///
+/// Skipped while [`EnemySpawner::active`] is `false` (final boss fight);
+/// events that come due during that time are dropped rather than queued.
+/// Formations are truncated so the enemy count never exceeds `max_count`.
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_formations(
+    mut commands: Commands,
+    mut scheduler: ResMut<FormationScheduler>,
+    spawner: Res<EnemySpawner>,
+    game_data: Res<GameData>,
+    player_q: Query<&Transform, With<Player>>,
+    enemy_q: Query<(), With<Enemy>>,
+    enemy_cfg: EnemyParams,
+    game_cfg: GameParams,
+    stage_params: StageParams,
+    selected_stage: Option<Res<SelectedStage>>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    let now = game_data.elapsed_time;
+    let last = scheduler.last_checked_secs;
+    // Formations that come due while spawning is disabled are skipped, not
+    // deferred.
+    if !spawner.active {
+        scheduler.last_checked_secs = now;
+        return;
+    }
+
+    // Without a stage config or a player the spawn cannot happen yet; keep
+    // `last_checked_secs` so due formations fire on the next frame that can.
+    let Some(entry) = selected_stage
+        .as_deref()
+        .and_then(|s| stage_params.get().map(|c| c.entry_for(s.0)))
+    else {
+        return;
+    };
+    let Ok(player_tf) = player_q.single() else {
+        return;
+    };
+    scheduler.last_checked_secs = now;
+
+    let due: Vec<FormationEvent> = entry
+        .formations
+        .iter()
+        .filter(|f| {
+            let at = f.at_min * 60.0;
+            at > last && at <= now
+        })
+        .copied()
+        .collect();
+    if due.is_empty() {
+        return;
+    }
+
+    let player_pos = player_tf.translation.truncate();
+
+    let (win_w, win_h) = game_cfg
+        .get()
+        .map(|c| (c.window_width as f32, c.window_height as f32))
+        .unwrap_or((DEFAULT_WINDOW_WIDTH, DEFAULT_WINDOW_HEIGHT));
+    let margin = enemy_cfg
+        .get()
+        .map(|c| c.spawn_margin)
+        .unwrap_or(DEFAULT_SPAWN_MARGIN)
+        .max(0.0);
+    let half = Vec2::new(win_w / 2.0 + margin, win_h / 2.0 + margin);
+
+    let max_count = enemy_cfg
+        .get()
+        .map(|c| c.max_count)
+        .unwrap_or(DEFAULT_ENEMY_MAX_COUNT);
+    let mut room = max_count.saturating_sub(enemy_q.iter().count());
+
+    for event in due {
+        let placements = formation_placements(game_rng.rng(), &event, player_pos, half);
+        let cfg_stats = enemy_cfg.get().map(|c| c.stats_for(event.enemy).clone());
+        let collider_radius = cfg_stats
+            .as_ref()
+            .map(|s| s.collider_radius)
+            .unwrap_or_else(|| fallback_collider_radius(event.enemy));
+
+        for (pos, motion) in placements.into_iter().take(room) {
+            let entity = spawn_enemy(
+                &mut commands,
+                event.enemy,
+                pos,
+                spawner.difficulty_multiplier,
+                entry.enemy_hp_multiplier,
+                entry.enemy_speed_multiplier * event.speed_multiplier,
+                collider_radius,
+                cfg_stats.as_ref(),
+            );
+            commands.entity(entity).insert((
+                EnemyAI {
+                    ai_type: AIType::Formation,
+                    ..default_enemy_ai(event.enemy)
+                },
+                motion,
+            ));
+            room -= 1;
+        }
+    }
+}
+
+/// Moves every enemy with [`AIType::Formation`] along its [`FormationMotion`].
+///
+/// Converging enemies that get within [`RING_RELEASE_RADIUS`] of the ring
+/// centre are handed back their normal AI.  Slows and freezes from
+/// [`StatusEffects`] scale the speed, and a [`HitStun`] from a knockback hit
+/// skips movement, as for regular enemies.
+pub fn move_formation_enemies(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut enemy_q: Query<
+        (
+            Entity,
+            &Enemy,
+            &EnemyAI,
+            &FormationMotion,
+            &mut Transform,
+            Option<&StatusEffects>,
+        ),
+        Without<HitStun>,
+    >,
+) {
+    let dt = time.delta_secs();
+    for (entity, enemy, ai, motion, mut tf, status) in enemy_q.iter_mut() {
+        if ai.ai_type != AIType::Formation {
+            continue;
+        }
+        let pos = tf.translation.truncate();
+        let direction = match *motion {
+            FormationMotion::Converge { center } => {
+                if pos.distance_squared(center) <= RING_RELEASE_RADIUS * RING_RELEASE_RADIUS {
+                    commands
+                        .entity(entity)
+                        .try_insert(default_enemy_ai(enemy.enemy_type))
+                        .try_remove::<FormationMotion>();
+                    continue;
+                }
+                (center - pos).normalize_or_zero()
+            }
+            FormationMotion::Sweep { direction } => direction,
+        };
+        let speed = enemy.move_speed * status.map_or(1.0, StatusEffects::speed_multiplier);
+        tf.translation += (direction * speed * dt).extend(0.0);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Computes the spawn position and motion of each member of `event`.
+///
+/// `half` is the half-extent of the off-screen spawn boundary around the
+/// player (viewport half-size plus spawn margin).
+pub(crate) fn formation_placements(
+    rng: &mut impl RngExt,
+    event: &FormationEvent,
+    player_pos: Vec2,
+    half: Vec2,
+) -> Vec<(Vec2, FormationMotion)> {
+    let count = event.count as usize;
+    match event.kind {
+        FormationKind::Ring => {
+            // Just outside the viewport corners so the whole ring starts hidden.
+            let radius = half.length();
+            let motion = FormationMotion::Converge { center: player_pos };
+            (0..count)
+                .map(|i| {
+                    let angle = TAU * i as f32 / count as f32;
+                    (player_pos + Vec2::from_angle(angle) * radius, motion)
+                })
+                .collect()
+        }
+        FormationKind::Swarm => {
+            let (edge, _) = random_edge(rng, half);
+            let start = player_pos + edge + along_edge_offset(rng, edge, half);
+            // Aim through the player so the swarm crosses the whole screen.
+            let direction = (player_pos - start).normalize_or_zero();
+            let motion = FormationMotion::Sweep { direction };
+            (0..count)
+                .map(|_| {
+                    let offset = Vec2::new(
+                        rng.random_range(-SWARM_SPREAD..SWARM_SPREAD),
+                        rng.random_range(-SWARM_SPREAD..SWARM_SPREAD),
+                    );
+                    (start + offset, motion)
+                })
+                .collect()
+        }
+        FormationKind::Wall => {
+            let (edge, along) = random_edge(rng, half);
+            let motion = FormationMotion::Sweep {
+                direction: -edge.normalize_or_zero(),
+            };
+            (0..count)
+                .map(|i| {
+                    // Evenly spread from one corner of the edge to the other.
+                    let t = if count > 1 {
+                        i as f32 / (count - 1) as f32 * 2.0 - 1.0
+                    } else {
+                        0.0
+                    };
+                    (player_pos + edge + along * t, motion)
+                })
+                .collect()
+        }
+    }
+}
+
+/// Picks one of the four viewport edges.
+///
+/// Returns the offset from the centre to the edge midpoint and the
+/// half-length vector running along that edge.
+fn random_edge(rng: &mut impl RngExt, half: Vec2) -> (Vec2, Vec2) {
+    match rng.random_range(0..4u8) {
+        0 => (Vec2::new(0.0, half.y), Vec2::new(half.x, 0.0)),
+        1 => (Vec2::new(0.0, -half.y), Vec2::new(half.x, 0.0)),
+        2 => (Vec2::new(-half.x, 0.0), Vec2::new(0.0, half.y)),
+        _ => (Vec2::new(half.x, 0.0), Vec2::new(0.0, half.y)),
+    }
+}
+
+/// Random offset along `edge`, within the middle half of the edge so the
+/// swarm's path crosses the visible screen.
+fn along_edge_offset(rng: &mut impl RngExt, edge: Vec2, half: Vec2) -> Vec2 {
+    let t = rng.random_range(-0.5..0.5);
+    if edge.x == 0.0 {
+        Vec2::new(half.x * t, 0.0)
+    } else {
+        Vec2::new(0.0, half.y * t)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::system::RunSystemOnce as _;
+
+    use super::*;
+    use crate::types::EnemyType;
+
+    const HALF: Vec2 = Vec2::new(700.0, 420.0);
+
+    fn event(kind: FormationKind, count: u32) -> FormationEvent {
+        FormationEvent::new(1.0, kind, EnemyType::Bat, count, 1.0)
+    }
+
+    #[test]
+    fn ring_surrounds_player_off_screen_and_converges() {
+        let mut rng = GameRng::from_seed(1);
+        let player = Vec2::new(100.0, -50.0);
+        let members =
+            formation_placements(rng.rng(), &event(FormationKind::Ring, 12), player, HALF);
+
+        assert_eq!(members.len(), 12);
+        for (pos, motion) in &members {
+            assert!((pos.distance(player) - HALF.length()).abs() < 1e-3);
+            assert_eq!(*motion, FormationMotion::Converge { center: player });
+        }
+    }
+
+    #[test]
+    fn wall_spans_one_edge_and_sweeps_inward() {
+        let mut rng = GameRng::from_seed(2);
+        let members =
+            formation_placements(rng.rng(), &event(FormationKind::Wall, 10), Vec2::ZERO, HALF);
+
+        assert_eq!(members.len(), 10);
+        let FormationMotion::Sweep { direction } = members[0].1 else {
+            panic!("wall members must sweep");
+        };
+        for (pos, motion) in &members {
+            assert_eq!(*motion, members[0].1, "whole wall moves together");
+            // Every member starts on the same edge, moving toward the centre.
+            assert!(pos.dot(direction) < 0.0);
+        }
+        let first = members.first().unwrap().0;
+        let last = members.last().unwrap().0;
+        assert!(
+            first.distance(last) >= 2.0 * HALF.y - 1e-3,
+            "wall spans the edge"
+        );
+    }
+
+    #[test]
+    fn swarm_is_a_tight_cluster_heading_through_the_player() {
+        let mut rng = GameRng::from_seed(3);
+        let members = formation_placements(
+            rng.rng(),
+            &event(FormationKind::Swarm, 20),
+            Vec2::ZERO,
+            HALF,
+        );
+
+        assert_eq!(members.len(), 20);
+        let centroid = members.iter().map(|(p, _)| *p).sum::<Vec2>() / 20.0;
+        for (pos, motion) in &members {
+            assert!(pos.distance(centroid) < SWARM_SPREAD * 2.0);
+            let FormationMotion::Sweep { direction } = *motion else {
+                panic!("swarm members must sweep");
+            };
+            assert!(direction.dot(-centroid.normalize()) > 0.9);
+        }
+    }
+
+    #[test]
+    fn converging_enemy_is_released_near_the_centre() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        let far = app
+            .world_mut()
+            .spawn((
+                Enemy::from_type(EnemyType::Skeleton, 1.0),
+                EnemyAI {
+                    ai_type: AIType::Formation,
+                    ..default_enemy_ai(EnemyType::Skeleton)
+                },
+                FormationMotion::Converge { center: Vec2::ZERO },
+                Transform::from_xyz(500.0, 0.0, 5.0),
+            ))
+            .id();
+        let near = app
+            .world_mut()
+            .spawn((
+                Enemy::from_type(EnemyType::Medusa, 1.0),
+                EnemyAI {
+                    ai_type: AIType::Formation,
+                    ..default_enemy_ai(EnemyType::Medusa)
+                },
+                FormationMotion::Converge { center: Vec2::ZERO },
+                Transform::from_xyz(10.0, 0.0, 5.0),
+            ))
+            .id();
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(0.1));
+
+        app.world_mut()
+            .run_system_once(move_formation_enemies)
+            .unwrap();
+
+        let world = app.world();
+        assert!(world.get::<Transform>(far).unwrap().translation.x < 500.0);
+        assert!(world.get::<FormationMotion>(far).is_some());
+        assert!(world.get::<FormationMotion>(near).is_none());
+        assert_eq!(
+            world.get::<EnemyAI>(near).unwrap().ai_type,
+            AIType::KeepDistance,
+            "released Medusa goes back to its own AI"
+        );
+    }
+
+    /// Knockback pushes formation members too, so they must not walk
+    /// through it while stunned.
+    #[test]
+    fn hit_stunned_formation_enemy_does_not_move() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        let stunned = app
+            .world_mut()
+            .spawn((
+                Enemy::from_type(EnemyType::Bat, 1.0),
+                EnemyAI {
+                    ai_type: AIType::Formation,
+                    ..default_enemy_ai(EnemyType::Bat)
+                },
+                FormationMotion::Sweep { direction: Vec2::X },
+                Transform::from_xyz(-500.0, 0.0, 5.0),
+                HitStun { remaining: 0.2 },
+            ))
+            .id();
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(0.1));
+
+        app.world_mut()
+            .run_system_once(move_formation_enemies)
+            .unwrap();
+
+        assert_eq!(
+            app.world().get::<Transform>(stunned).unwrap().translation.x,
+            -500.0
+        );
+    }
+
+    /// App with a stage whose only formation is an 8-bat ring at minute 1.
+    fn scheduler_app() -> App {
+        use crate::config::{StageConfig, StageConfigHandle, StageEntryConfig};
+        use crate::types::StageType;
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, bevy::asset::AssetPlugin::default()));
+        app.init_asset::<StageConfig>();
+        app.insert_resource(FormationScheduler::default());
+        app.insert_resource(EnemySpawner::default());
+        app.insert_resource(GameData::default());
+        app.insert_resource(GameRng::from_seed(0));
+        app.insert_resource(SelectedStage(StageType::MadForest));
+
+        let entry = StageEntryConfig {
+            display_name: "Test".to_string(),
+            enemy_types: vec![EnemyType::Bat],
+            enemy_hp_multiplier: 1.0,
+            enemy_speed_multiplier: 1.0,
+            spawn_interval_multiplier: 1.0,
+            max_enemies_multiplier: 1.0,
+            boss_hp_multiplier: 1.0,
+            boss_speed_multiplier: 1.0,
+            waves: Vec::new(),
+            formations: vec![event(FormationKind::Ring, 8)],
+            mini_bosses: Vec::new(),
+            boss: None,
+        };
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<StageConfig>>()
+            .add(StageConfig {
+                mad_forest: entry.clone(),
+                inlaid_library: entry.clone(),
+                dairy_plant: entry,
+            });
+        app.world_mut().insert_resource(StageConfigHandle(handle));
+        app
+    }
+
+    fn enemy_count(app: &mut App) -> usize {
+        let mut q = app.world_mut().query_filtered::<(), With<Enemy>>();
+        q.iter(app.world()).count()
+    }
+
+    #[test]
+    fn scheduler_fires_each_event_once() {
+        let mut app = scheduler_app();
+        app.world_mut().spawn((Player, Transform::default()));
+
+        // Before minute 1: nothing.
+        app.world_mut().resource_mut::<GameData>().elapsed_time = 59.0;
+        app.world_mut().run_system_once(spawn_formations).unwrap();
+        assert_eq!(enemy_count(&mut app), 0);
+
+        // Crossing minute 1 fires the ring once.
+        app.world_mut().resource_mut::<GameData>().elapsed_time = 61.0;
+        app.world_mut().run_system_once(spawn_formations).unwrap();
+        assert_eq!(enemy_count(&mut app), 8);
+
+        app.world_mut().resource_mut::<GameData>().elapsed_time = 62.0;
+        app.world_mut().run_system_once(spawn_formations).unwrap();
+        assert_eq!(enemy_count(&mut app), 8, "event must not fire twice");
+    }
+
+    /// A formation that comes due while there is no player is not lost; it
+    /// fires once the player exists.
+    #[test]
+    fn formation_due_without_player_fires_later() {
+        let mut app = scheduler_app();
+
+        app.world_mut().resource_mut::<GameData>().elapsed_time = 61.0;
+        app.world_mut().run_system_once(spawn_formations).unwrap();
+        assert_eq!(enemy_count(&mut app), 0);
+
+        app.world_mut().spawn((Player, Transform::default()));
+        app.world_mut().resource_mut::<GameData>().elapsed_time = 62.0;
+        app.world_mut().run_system_once(spawn_formations).unwrap();
+        assert_eq!(enemy_count(&mut app), 8);
+    }
+}
@@ -0,0 +1,499 @@
+//! Shared pipeline for projectiles enemies fire at the player.
+//!
+//! Every enemy shot — Medusa and Dragon attacks from
+//! [`tick_ranged_attacks`](super::ranged::tick_ranged_attacks), Boss Death's
+//! scythes and stage-boss bullets — is a [`HostileProjectile`] fired through
+//! the [`HostileProjectiles`] system param.  Three systems drive them all:
+//!
+//! - [`move_hostile_projectiles`] — applies homing, acceleration and spin,
+//!   moves each projectile along its velocity and releases it when its
+//!   lifetime runs out.
+//! - [`update_hostile_projectile_grid`] — rebuilds the
+//!   [`HostileProjectileGrid`] from live projectile positions.
+//! - [`hostile_projectile_player_collision`] — checks the projectiles in the
+//!   grid cells around the player and emits a [`PlayerDamagedEvent`] on the
+//!   first hit.
+//!
+//! ## Pooling
+//!
+//! A finished projectile is not despawned: it is hidden, tagged
+//! [`PooledProjectile`] and parked in the [`HostileProjectilePool`], and the
+//! next [`HostileProjectiles::fire`] reuses it.  Once
+//! `hostile_projectile_pool_size` (from `enemy.ron`) projectiles are parked,
+//! extras are despawned as before.
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use crate::{
+    components::{
+        CircleCollider, GameSessionEntity, HostileProjectile, InvincibilityTimer, Player,
+        PooledProjectile,
+    },
+    config::{EnemyParams, PlayerParams},
+    events::PlayerDamagedEvent,
+    resources::{HostileProjectileGrid, HostileProjectilePool},
+    systems::collision::check_circle_collision,
+};
+
+/// Invincibility duration granted after a projectile hit while
+/// `player.ron` is loading (seconds).
+const DEFAULT_PROJECTILE_INVINCIBILITY: f32 = 0.5;
+
+/// Draw layer of hostile projectiles: above the ground, below enemies.
+const PROJECTILE_Z: f32 = 4.0;
+
+// ---------------------------------------------------------------------------
+// Firing
+// ---------------------------------------------------------------------------
+
+/// Everything needed to put one [`HostileProjectile`] into the world.
+#[derive(Debug, Clone)]
+pub struct HostileShot {
+    pub projectile: HostileProjectile,
+    /// World position the projectile starts from.
+    pub origin: Vec2,
+    /// Collider radius in pixels; the sprite is a square of twice this size.
+    pub radius: f32,
+    pub color: Color,
+}
+
+/// Fires [`HostileProjectile`]s, reusing a pooled entity when one is ready.
+#[derive(SystemParam)]
+pub struct HostileProjectiles<'w, 's> {
+    commands: Commands<'w, 's>,
+    pool: ResMut<'w, HostileProjectilePool>,
+    pooled_q: Query<'w, 's, (), With<PooledProjectile>>,
+}
+
+impl HostileProjectiles<'_, '_> {
+    /// Puts `shot` into the world and returns its entity.
+    ///
+    /// A parked projectile is overwritten and shown again; otherwise a new
+    /// [`GameSessionEntity`] is spawned.  Pool entries that no longer exist
+    /// are skipped.
+    pub fn fire(&mut self, shot: HostileShot) -> Entity {
+        let HostileShot {
+            projectile,
+            origin,
+            radius,
+            color,
+        } = shot;
+        let bundle = (
+            projectile,
+            CircleCollider { radius },
+            Sprite {
+                color,
+                custom_size: Some(Vec2::splat(radius * 2.0)),
+                ..default()
+            },
+            Transform::from_translation(origin.extend(PROJECTILE_Z)),
+            Visibility::Inherited,
+        );
+
+        while let Some(entity) = self.pool.take() {
+            if self.pooled_q.contains(entity) {
+                self.commands
+                    .entity(entity)
+                    .remove::<PooledProjectile>()
+                    .insert(bundle);
+                return entity;
+            }
+        }
+        self.commands.spawn((bundle, GameSessionEntity)).id()
+    }
+}
+
+/// Takes a finished projectile out of play: parks it in the pool, or
+/// despawns it when the pool already holds `pool_size` projectiles.
+fn release(
+    commands: &mut Commands,
+    pool: &mut HostileProjectilePool,
+    entity: Entity,
+    pool_size: usize,
+) {
+    if pool.len() >= pool_size {
+        commands.entity(entity).despawn();
+        return;
+    }
+    commands
+        .entity(entity)
+        .insert((PooledProjectile, Visibility::Hidden));
+    pool.release(entity);
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Advances every live [`HostileProjectile`] by one frame.
+///
+/// - Homing turns the velocity toward the player by at most `homing × dt`
+///   radians; without a player the projectile flies straight.
+/// - Acceleration changes the speed, never below zero.
+/// - Spin rotates the sprite only.
+///
+/// Projectiles whose lifetime runs out are released.  Also makes last
+/// frame's releases available to [`HostileProjectiles::fire`].
+pub fn move_hostile_projectiles(
+    mut commands: Commands,
+    time: Res<Time>,
+    player_q: Query<&Transform, (With<Player>, Without<HostileProjectile>)>,
+    mut proj_q: Query<(Entity, &mut HostileProjectile, &mut Transform), Without<PooledProjectile>>,
+    mut pool: ResMut<HostileProjectilePool>,
+    enemy_cfg: EnemyParams,
+) {
+    pool.recycle();
+
+    let player_pos = player_q.single().ok().map(|tf| tf.translation.truncate());
+    let pool_size = enemy_cfg.hostile_projectile_pool_size();
+    let dt = time.delta_secs();
+
+    for (entity, mut proj, mut tf) in proj_q.iter_mut() {
+        if proj.homing > 0.0
+            && let Some(player_pos) = player_pos
+        {
+            let desired = player_pos - tf.translation.truncate();
+            if desired != Vec2::ZERO && proj.velocity != Vec2::ZERO {
+                let max_turn = proj.homing * dt;
+                let turn = proj.velocity.angle_to(desired).clamp(-max_turn, max_turn);
+                proj.velocity = Vec2::from_angle(turn).rotate(proj.velocity);
+            }
+        }
+        if proj.acceleration != 0.0 {
+            let speed = (proj.velocity.length() + proj.acceleration * dt).max(0.0);
+            proj.velocity = proj.velocity.normalize_or_zero() * speed;
+        }
+
+        tf.translation += (proj.velocity * dt).extend(0.0);
+        if proj.spin != 0.0 {
+            tf.rotate_z(proj.spin * dt);
+        }
+
+        proj.lifetime -= dt;
+        if proj.lifetime <= 0.0 {
+            release(&mut commands, &mut pool, entity, pool_size);
+        }
+    }
+}
+
+/// Rebuilds the [`HostileProjectileGrid`] from every live projectile.
+///
+/// Projectiles released this frame are left out: their [`PooledProjectile`]
+/// marker is still a queued command.
+pub fn update_hostile_projectile_grid(
+    mut grid: ResMut<HostileProjectileGrid>,
+    proj_q: Query<
+        (Entity, &Transform, &CircleCollider, &HostileProjectile),
+        Without<PooledProjectile>,
+    >,
+) {
+    let grid = &mut *grid;
+    grid.grid.clear();
+    grid.max_radius = 0.0;
+    for (entity, tf, collider, proj) in proj_q.iter() {
+        if proj.lifetime <= 0.0 {
+            continue;
+        }
+        grid.grid.insert(tf.translation.truncate(), entity);
+        grid.max_radius = grid.max_radius.max(collider.radius);
+    }
+}
+
+/// Checks the projectiles near the player (via [`HostileProjectileGrid`])
+/// against the player's circle collider.
+///
+/// On the first hit the projectile is released, a [`PlayerDamagedEvent`] is
+/// emitted and an [`InvincibilityTimer`] is granted.  No-op while the player
+/// is invincible.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn hostile_projectile_player_collision(
+    mut commands: Commands,
+    player_q: Query<
+        (Entity, &Transform, &CircleCollider),
+        (With<Player>, Without<InvincibilityTimer>),
+    >,
+    grid: Res<HostileProjectileGrid>,
+    proj_q: Query<(&Transform, &CircleCollider, &HostileProjectile), Without<PooledProjectile>>,
+    mut pool: ResMut<HostileProjectilePool>,
+    player_cfg: PlayerParams,
+    enemy_cfg: EnemyParams,
+    mut damage_events: MessageWriter<PlayerDamagedEvent>,
+) {
+    let Ok((player_entity, player_tf, player_collider)) = player_q.single() else {
+        return;
+    };
+    let player_pos = player_tf.translation.truncate();
+
+    let invincibility_duration = player_cfg
+        .get()
+        .map(|c| c.invincibility_time)
+        .unwrap_or(DEFAULT_PROJECTILE_INVINCIBILITY);
+
+    let candidates = grid
+        .grid
+        .get_nearby(player_pos, player_collider.radius + grid.max_radius);
+    for proj_entity in candidates {
+        let Ok((proj_tf, proj_collider, proj)) = proj_q.get(proj_entity) else {
+            continue;
+        };
+        if proj.lifetime <= 0.0
+            || !check_circle_collision(
+                player_pos,
+                player_collider.radius,
+                proj_tf.translation.truncate(),
+                proj_collider.radius,
+            )
+        {
+            continue;
+        }
+
+        // Hit: release the projectile, emit damage, and grant invincibility.
+        release(
+            &mut commands,
+            &mut pool,
+            proj_entity,
+            enemy_cfg.hostile_projectile_pool_size(),
+        );
+        damage_events.write(PlayerDamagedEvent {
+            player: player_entity,
+            damage: proj.damage,
+        });
+        commands.entity(player_entity).insert(InvincibilityTimer {
+            remaining: invincibility_duration,
+        });
+        return; // one hit per frame
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::system::RunSystemOnce as _;
+
+    use super::*;
+    use crate::types::EnemyType;
+
+    fn build_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<PlayerDamagedEvent>();
+        app.init_resource::<HostileProjectilePool>();
+        app.init_resource::<HostileProjectileGrid>();
+        app
+    }
+
+    fn spawn_player(app: &mut App, pos: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                Player,
+                Transform::from_translation(pos.extend(10.0)),
+                CircleCollider { radius: 12.0 },
+            ))
+            .id()
+    }
+
+    fn shot(origin: Vec2, velocity: Vec2, lifetime: f32) -> HostileShot {
+        HostileShot {
+            projectile: HostileProjectile::straight(EnemyType::Medusa, 12.0, velocity, lifetime),
+            origin,
+            radius: 5.0,
+            color: Color::WHITE,
+        }
+    }
+
+    fn fire(app: &mut App, shot: HostileShot) -> Entity {
+        app.world_mut()
+            .run_system_once(move |mut projectiles: HostileProjectiles| {
+                projectiles.fire(shot.clone())
+            })
+            .unwrap()
+    }
+
+    fn step(app: &mut App, secs: f32) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(secs));
+        app.world_mut()
+            .run_system_once(move_hostile_projectiles)
+            .unwrap();
+    }
+
+    fn collide(app: &mut App) -> Vec<PlayerDamagedEvent> {
+        app.world_mut()
+            .run_system_once(update_hostile_projectile_grid)
+            .unwrap();
+        app.world_mut()
+            .run_system_once(hostile_projectile_player_collision)
+            .unwrap();
+        let messages = app.world().resource::<Messages<PlayerDamagedEvent>>();
+        let mut cursor = messages.get_cursor();
+        cursor.read(messages).cloned().collect()
+    }
+
+    fn is_pooled(app: &App, entity: Entity) -> bool {
+        app.world().get::<PooledProjectile>(entity).is_some()
+    }
+
+    // -----------------------------------------------------------------------
+
+    /// A projectile moves `velocity × dt` per frame.
+    #[test]
+    fn projectile_moves_each_frame() {
+        let mut app = build_app();
+        let proj = fire(&mut app, shot(Vec2::ZERO, Vec2::new(180.0, 0.0), 5.0));
+
+        let dt = 1.0_f32 / 60.0;
+        step(&mut app, dt);
+
+        let x = app.world().get::<Transform>(proj).unwrap().translation.x;
+        assert!((x - 180.0 * dt).abs() < 1e-3, "projectile should advance");
+    }
+
+    /// An expired projectile is hidden and parked instead of despawned, and
+    /// the next shot reuses the same entity.
+    #[test]
+    fn expired_projectile_is_pooled_and_reused() {
+        let mut app = build_app();
+        let first = fire(&mut app, shot(Vec2::ZERO, Vec2::ZERO, 0.05));
+
+        step(&mut app, 0.1);
+        assert!(is_pooled(&app, first));
+        assert_eq!(
+            app.world().get::<Visibility>(first),
+            Some(&Visibility::Hidden)
+        );
+
+        // Parked projectiles become available on the following frame.
+        step(&mut app, 0.0);
+        let second = fire(&mut app, shot(Vec2::new(40.0, 0.0), Vec2::X, 5.0));
+
+        assert_eq!(second, first, "pooled entity must be reused");
+        assert!(!is_pooled(&app, second));
+        let tf = app.world().get::<Transform>(second).unwrap();
+        assert_eq!(tf.translation.truncate(), Vec2::new(40.0, 0.0));
+        assert_eq!(
+            app.world()
+                .get::<HostileProjectile>(second)
+                .unwrap()
+                .lifetime,
+            5.0
+        );
+    }
+
+    /// Once the pool is full, finished projectiles are despawned.
+    #[test]
+    fn full_pool_despawns_projectile() {
+        let mut app = build_app();
+        let proj = fire(&mut app, shot(Vec2::ZERO, Vec2::ZERO, 0.05));
+        // Fill the pool to the built-in `hostile_projectile_pool_size`.
+        {
+            let mut pool = app.world_mut().resource_mut::<HostileProjectilePool>();
+            for _ in 0..512 {
+                pool.release(Entity::PLACEHOLDER);
+            }
+        }
+
+        step(&mut app, 0.1);
+
+        assert!(app.world().get_entity(proj).is_err());
+    }
+
+    /// Homing bends the velocity toward the player without changing speed.
+    #[test]
+    fn homing_turns_toward_player() {
+        let mut app = build_app();
+        spawn_player(&mut app, Vec2::new(0.0, 500.0));
+        let mut homing = shot(Vec2::ZERO, Vec2::new(100.0, 0.0), 5.0);
+        homing.projectile.homing = 1.0;
+        let proj = fire(&mut app, homing);
+
+        step(&mut app, 0.5);
+
+        let velocity = app.world().get::<HostileProjectile>(proj).unwrap().velocity;
+        assert!(velocity.y > 0.0, "should curve toward the player");
+        assert!((velocity.length() - 100.0).abs() < 1e-3);
+        assert!(
+            (velocity.to_angle() - 0.5).abs() < 1e-3,
+            "turn capped at homing × dt"
+        );
+    }
+
+    /// Negative acceleration slows the projectile down to a stop.
+    #[test]
+    fn deceleration_stops_at_zero() {
+        let mut app = build_app();
+        let mut slowing = shot(Vec2::ZERO, Vec2::new(100.0, 0.0), 5.0);
+        slowing.projectile.acceleration = -150.0;
+        let proj = fire(&mut app, slowing);
+
+        step(&mut app, 0.5);
+        let speed = app
+            .world()
+            .get::<HostileProjectile>(proj)
+            .unwrap()
+            .velocity
+            .length();
+        assert!((speed - 25.0).abs() < 1e-3, "got {speed}");
+
+        step(&mut app, 0.5);
+        let velocity = app.world().get::<HostileProjectile>(proj).unwrap().velocity;
+        assert_eq!(velocity, Vec2::ZERO);
+    }
+
+    /// A projectile overlapping the player deals damage and is pooled.
+    #[test]
+    fn projectile_hitting_player_deals_damage() {
+        let mut app = build_app();
+        let player = spawn_player(&mut app, Vec2::ZERO);
+        let proj = fire(&mut app, shot(Vec2::new(10.0, 0.0), Vec2::ZERO, 5.0));
+
+        let events = collide(&mut app);
+
+        assert_eq!(events.len(), 1, "expected one damage event");
+        assert_eq!(events[0].player, player);
+        assert!((events[0].damage - 12.0).abs() < 1e-6);
+        assert!(is_pooled(&app, proj));
+        assert!(app.world().get::<InvincibilityTimer>(player).is_some());
+    }
+
+    /// Several projectiles on the player still deal only one hit per frame.
+    #[test]
+    fn one_hit_per_frame() {
+        let mut app = build_app();
+        spawn_player(&mut app, Vec2::ZERO);
+        for _ in 0..3 {
+            fire(&mut app, shot(Vec2::ZERO, Vec2::ZERO, 5.0));
+        }
+
+        assert_eq!(collide(&mut app).len(), 1);
+    }
+
+    /// A far-away projectile does not deal damage.
+    #[test]
+    fn projectile_far_away_no_damage() {
+        let mut app = build_app();
+        spawn_player(&mut app, Vec2::ZERO);
+        fire(&mut app, shot(Vec2::new(500.0, 0.0), Vec2::ZERO, 5.0));
+
+        assert!(collide(&mut app).is_empty());
+    }
+
+    /// An invincible player is not hit and the projectile flies on.
+    #[test]
+    fn invincible_player_is_not_hit() {
+        let mut app = build_app();
+        let player = spawn_player(&mut app, Vec2::ZERO);
+        app.world_mut()
+            .entity_mut(player)
+            .insert(InvincibilityTimer { remaining: 1.0 });
+        let proj = fire(&mut app, shot(Vec2::ZERO, Vec2::ZERO, 5.0));
+
+        assert!(collide(&mut app).is_empty());
+        assert!(!is_pooled(&app, proj));
+    }
+}
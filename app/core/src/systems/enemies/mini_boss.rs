@@ -0,0 +1,380 @@
+//! Mini-boss abilities — charging giant, summoner and ranged mage.
+//!
+//! Every [`EnemyType::MiniBoss`] carries a [`MiniBoss`] component holding
+//! its archetype from `mini_boss_archetypes` in `enemy.ron`.  Between
+//! abilities it moves with the regular enemy AI; two systems add the
+//! archetype's special move:
+//!
+//! - [`run_mini_boss_abilities`] — counts the ability timer down and uses the
+//!   archetype's [`MiniBossAbility`] when it elapses.
+//! - [`move_charging_mini_bosses`] — drives a charge in progress, then hands
+//!   the mini-boss back to [`AIType::ChasePlayer`].
+//!
+//! | Ability  | Effect                                              |
+//! |----------|-----------------------------------------------------|
+//! | `Charge` | Straight dash toward the player's position          |
+//! | `Summon` | Regular enemies spawned in a ring around the caster |
+//! | `Volley` | Projectiles fanned toward the player                |
+//!
+//! The ability timer is held during a charge so a dash is never interrupted.
+//!
+//! [`EnemyType::MiniBoss`]: crate::types::EnemyType::MiniBoss
+
+use bevy::prelude::*;
+
+use crate::{
+    components::{Enemy, EnemyAI, HostileProjectile, MiniBoss, Player, StatusEffects},
+    config::{EnemyParams, MiniBossAbility},
+    systems::enemies::{
+        boss_pattern::volley_offsets,
+        hostile_projectile::{HostileProjectiles, HostileShot},
+        spawn::{fallback_collider_radius, spawn_enemy},
+    },
+    types::{AIType, EnemyType},
+};
+
+/// Lower bound on an archetype's `ability_interval`, so a zero in
+/// `enemy.ron` cannot fire the ability every frame.
+const MIN_ABILITY_INTERVAL: f32 = 0.1;
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Counts each mini-boss's ability timer down and uses its archetype's
+/// [`MiniBossAbility`] when it elapses.
+///
+/// - `Charge`: switches the AI to [`AIType::ChargeAttack`] and starts a dash
+///   toward the player's current position.
+/// - `Summon`: spawns `count` enemies in a ring of `radius` around the
+///   mini-boss, clamped so the live enemy count stays within
+///   [`EnemyParams::max_count`].
+/// - `Volley`: fires `count` projectiles fanned across `spread_deg`, centred
+///   on the player, in the archetype's colour.
+///
+/// No-op without a player.  Aimed abilities are skipped when the player is
+/// exactly on the mini-boss.
+pub fn run_mini_boss_abilities(
+    mut commands: Commands,
+    time: Res<Time>,
+    player_q: Query<&Transform, With<Player>>,
+    mut boss_q: Query<(&Transform, &mut MiniBoss, &mut EnemyAI), Without<Player>>,
+    enemy_q: Query<(), With<Enemy>>,
+    enemy_cfg: EnemyParams,
+    mut projectiles: HostileProjectiles,
+) {
+    let Ok(player_tf) = player_q.single() else {
+        return;
+    };
+    let player_pos = player_tf.translation.truncate();
+    let dt = time.delta_secs();
+    // Spawns are deferred, so track the remaining room across summoners.
+    let mut room = enemy_cfg.max_count().saturating_sub(enemy_q.iter().count());
+
+    for (boss_tf, mut mini_boss, mut ai) in boss_q.iter_mut() {
+        if mini_boss.charge_remaining > 0.0 {
+            continue;
+        }
+        mini_boss.ability_timer -= dt;
+        if mini_boss.ability_timer > 0.0 {
+            continue;
+        }
+        mini_boss.ability_timer += mini_boss
+            .archetype
+            .ability_interval
+            .max(MIN_ABILITY_INTERVAL);
+
+        let boss_pos = boss_tf.translation.truncate();
+        let to_player = (player_pos - boss_pos).normalize_or_zero();
+
+        match mini_boss.archetype.ability {
+            MiniBossAbility::Charge { speed, duration } => {
+                if to_player == Vec2::ZERO {
+                    continue; // player exactly on the mini-boss — no direction
+                }
+                mini_boss.charge_velocity = to_player * speed;
+                mini_boss.charge_remaining = duration.max(0.0);
+                ai.ai_type = AIType::ChargeAttack;
+            }
+            MiniBossAbility::Summon {
+                enemy,
+                count,
+                radius,
+            } => {
+                let cfg_stats = enemy_cfg.get().map(|c| c.stats_for(enemy).clone());
+                let collider_radius = cfg_stats
+                    .as_ref()
+                    .map(|s| s.collider_radius)
+                    .unwrap_or_else(|| fallback_collider_radius(enemy));
+                let summoned = (count as usize).min(room);
+                room -= summoned;
+                for i in 0..summoned {
+                    let angle = std::f32::consts::TAU * i as f32 / count as f32;
+                    spawn_enemy(
+                        &mut commands,
+                        enemy,
+                        boss_pos + Vec2::from_angle(angle) * radius,
+                        1.0,
+                        1.0,
+                        1.0,
+                        collider_radius,
+                        cfg_stats.as_ref(),
+                    );
+                }
+            }
+            MiniBossAbility::Volley {
+                count,
+                spread_deg,
+                speed,
+                damage,
+                lifetime,
+                radius,
+            } => {
+                if to_player == Vec2::ZERO {
+                    continue;
+                }
+                let (r, g, b) = mini_boss.archetype.color;
+                for offset in volley_offsets(count, spread_deg.to_radians()) {
+                    let velocity = Vec2::from_angle(offset).rotate(to_player) * speed;
+                    projectiles.fire(HostileShot {
+                        projectile: HostileProjectile::straight(
+                            EnemyType::MiniBoss,
+                            damage,
+                            velocity,
+                            lifetime,
+                        ),
+                        origin: boss_pos,
+                        radius,
+                        color: Color::srgb(r, g, b),
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Moves each charging mini-boss along its dash, scaled by
+/// [`StatusEffects::speed_multiplier`].
+///
+/// When the charge runs out the mini-boss returns to
+/// [`AIType::ChasePlayer`] and the regular AI takes over again.
+pub fn move_charging_mini_bosses(
+    time: Res<Time>,
+    mut boss_q: Query<(
+        &mut MiniBoss,
+        &mut EnemyAI,
+        &mut Transform,
+        Option<&StatusEffects>,
+    )>,
+) {
+    let dt = time.delta_secs();
+
+    for (mut mini_boss, mut ai, mut boss_tf, status) in boss_q.iter_mut() {
+        if mini_boss.charge_remaining <= 0.0 {
+            continue;
+        }
+        let status_mult = status.map_or(1.0, StatusEffects::speed_multiplier);
+        boss_tf.translation += (mini_boss.charge_velocity * status_mult * dt).extend(0.0);
+        mini_boss.charge_remaining -= dt;
+        if mini_boss.charge_remaining <= 0.0 {
+            mini_boss.charge_remaining = 0.0;
+            mini_boss.charge_velocity = Vec2::ZERO;
+            ai.ai_type = AIType::ChasePlayer;
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::system::RunSystemOnce as _;
+
+    use super::*;
+    use crate::{
+        config::enemy::default_mini_boss_archetype, resources::HostileProjectilePool,
+        types::MiniBossKind,
+    };
+
+    fn build_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<HostileProjectilePool>();
+        app
+    }
+
+    fn advance(app: &mut App, secs: f32) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(secs));
+    }
+
+    fn spawn_player(app: &mut App, pos: Vec2) {
+        app.world_mut()
+            .spawn((Player, Transform::from_translation(pos.extend(10.0))));
+    }
+
+    /// Spawns a mini-boss of `kind` at the origin with its ability due now.
+    fn spawn_mini_boss(app: &mut App, kind: MiniBossKind) -> Entity {
+        let archetype = default_mini_boss_archetype(kind);
+        let mut mini_boss = MiniBoss::new(kind, archetype);
+        mini_boss.ability_timer = 0.0;
+        app.world_mut()
+            .spawn((
+                Enemy::from_type(EnemyType::MiniBoss, 1.0),
+                EnemyAI {
+                    ai_type: AIType::ChasePlayer,
+                    attack_timer: 0.0,
+                    attack_range: 20.0,
+                },
+                mini_boss,
+                Transform::from_translation(Vec3::new(0.0, 0.0, 5.0)),
+            ))
+            .id()
+    }
+
+    fn run_abilities(app: &mut App) {
+        app.world_mut()
+            .run_system_once(run_mini_boss_abilities)
+            .expect("run_mini_boss_abilities should run");
+        app.world_mut().flush();
+    }
+
+    // -----------------------------------------------------------------------
+
+    /// The ability waits for its timer.
+    #[test]
+    fn ability_waits_for_timer() {
+        let mut app = build_app();
+        spawn_player(&mut app, Vec2::new(200.0, 0.0));
+        let boss = spawn_mini_boss(&mut app, MiniBossKind::Mage);
+        app.world_mut()
+            .get_mut::<MiniBoss>(boss)
+            .unwrap()
+            .ability_timer = 1.0;
+
+        advance(&mut app, 0.5);
+        run_abilities(&mut app);
+
+        let mut q = app.world_mut().query::<&HostileProjectile>();
+        assert_eq!(q.iter(app.world()).count(), 0);
+        let timer = app.world().get::<MiniBoss>(boss).unwrap().ability_timer;
+        assert!((timer - 0.5).abs() < 1e-4);
+    }
+
+    /// A Mage fires its whole volley, centred on the player.
+    #[test]
+    fn mage_fires_volley_at_player() {
+        let mut app = build_app();
+        spawn_player(&mut app, Vec2::new(200.0, 0.0));
+        spawn_mini_boss(&mut app, MiniBossKind::Mage);
+
+        run_abilities(&mut app);
+
+        let mut q = app.world_mut().query::<&HostileProjectile>();
+        let shots: Vec<Vec2> = q.iter(app.world()).map(|p| p.velocity).collect();
+        assert_eq!(shots.len(), 5);
+        let mean = shots.iter().sum::<Vec2>() / shots.len() as f32;
+        assert!(
+            mean.x > 0.0 && mean.y.abs() < 1e-3,
+            "volley centred on player"
+        );
+    }
+
+    /// A Summoner spawns its ring of regular enemies.
+    #[test]
+    fn summoner_spawns_enemies_around_itself() {
+        let mut app = build_app();
+        spawn_player(&mut app, Vec2::new(200.0, 0.0));
+        spawn_mini_boss(&mut app, MiniBossKind::Summoner);
+
+        run_abilities(&mut app);
+
+        let mut q = app.world_mut().query::<(&Enemy, &Transform)>();
+        let bats: Vec<Vec2> = q
+            .iter(app.world())
+            .filter(|(e, _)| e.enemy_type == EnemyType::Bat)
+            .map(|(_, t)| t.translation.truncate())
+            .collect();
+        assert_eq!(bats.len(), 6);
+        for pos in bats {
+            assert!((pos.length() - 60.0).abs() < 1e-3);
+        }
+    }
+
+    /// A Summoner at the enemy cap spawns nothing; near the cap it only
+    /// fills the remaining room.
+    #[test]
+    fn summoner_respects_enemy_max_count() {
+        let mut app = build_app();
+        spawn_player(&mut app, Vec2::new(200.0, 0.0));
+        let boss = spawn_mini_boss(&mut app, MiniBossKind::Summoner);
+        // The mini-boss itself counts toward the default cap of 500.
+        for _ in 0..497 {
+            app.world_mut()
+                .spawn(Enemy::from_type(EnemyType::Zombie, 1.0));
+        }
+
+        run_abilities(&mut app);
+        app.world_mut()
+            .get_mut::<MiniBoss>(boss)
+            .unwrap()
+            .ability_timer = 0.0;
+        run_abilities(&mut app);
+
+        let mut q = app.world_mut().query::<&Enemy>();
+        let bats = q
+            .iter(app.world())
+            .filter(|e| e.enemy_type == EnemyType::Bat)
+            .count();
+        assert_eq!(bats, 2);
+        assert_eq!(q.iter(app.world()).count(), 500);
+    }
+
+    /// A Giant dashes at the player, holds its timer while charging and
+    /// returns to chasing when the charge ends.
+    #[test]
+    fn giant_charges_then_resumes_chase() {
+        let mut app = build_app();
+        spawn_player(&mut app, Vec2::new(500.0, 0.0));
+        let boss = spawn_mini_boss(&mut app, MiniBossKind::Giant);
+
+        run_abilities(&mut app);
+        assert_eq!(
+            app.world().get::<EnemyAI>(boss).unwrap().ai_type,
+            AIType::ChargeAttack
+        );
+        let timer = app.world().get::<MiniBoss>(boss).unwrap().ability_timer;
+
+        advance(&mut app, 0.5);
+        app.world_mut()
+            .run_system_once(move_charging_mini_bosses)
+            .unwrap();
+        run_abilities(&mut app);
+        let x = app.world().get::<Transform>(boss).unwrap().translation.x;
+        assert!(
+            (x - 420.0 * 0.5).abs() < 1e-2,
+            "dash at charge speed, got {x}"
+        );
+        assert_eq!(
+            app.world().get::<MiniBoss>(boss).unwrap().ability_timer,
+            timer,
+            "timer held during the charge"
+        );
+
+        advance(&mut app, 0.5);
+        app.world_mut()
+            .run_system_once(move_charging_mini_bosses)
+            .unwrap();
+        let mini_boss = app.world().get::<MiniBoss>(boss).unwrap();
+        assert_eq!(mini_boss.charge_remaining, 0.0);
+        assert_eq!(
+            app.world().get::<EnemyAI>(boss).unwrap().ai_type,
+            AIType::ChasePlayer
+        );
+    }
+}
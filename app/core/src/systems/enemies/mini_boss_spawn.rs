@@ -1,24 +1,33 @@
-//! Mini-boss spawn system — timer-based, every 3 minutes.
+//! Mini-boss spawn system — per-stage schedule with an interval fallback.
 //!
-//! [`spawn_mini_boss`] runs every frame during [`AppState::Playing`].
-//! When [`TreasureSpawner::spawn_timer`] first reaches
-//! `EnemyConfig::mini_boss_interval` (default 180 seconds, tunable via
-//! `enemy.ron`) it:
+//! [`spawn_mini_boss`] runs every frame during [`AppState::Playing`].  With
+//! a stage selected and `stage.ron` loaded it fires every
+//! [`MiniBossEvent`](crate::config::MiniBossEvent) in the stage's
+//! `mini_bosses` list once the run reaches its `at_min`.  Otherwise it falls
+//! back to one mini-boss every `EnemyConfig::mini_boss_interval` seconds
+//! (default 180, tunable via `enemy.ron`), cycling through the archetypes.
 //!
-//! 1. Resets the timer to 0.
-//! 2. Spawns a [`EnemyType::MiniBoss`] entity just off-screen using the
-//!    same random edge logic as the normal enemy spawner.
+//! Each mini-boss is a [`EnemyType::MiniBoss`] spawned just off-screen using
+//! the same random edge logic as the normal enemy spawner, then given its
+//! archetype's [`MiniBoss`] state, size, colour and AI (see
+//! [`spawn_mini_boss_of_kind`]).
 //!
 //! Spawning is skipped once the final boss has appeared (`boss_spawned`
-//! flag) so the player is not harassed by mini-bosses during the climax.
+//! flag) so the player is not harassed by mini-bosses during the climax;
+//! schedule entries that come due during that time are dropped.
+//!
+//! [`AppState::Playing`]: crate::states::AppState::Playing
 
 use bevy::prelude::*;
 
 use crate::{
-    config::{EnemyParams, GameParams},
-    resources::{GameData, TreasureSpawner},
-    systems::enemies::spawn::{DEFAULT_COLLIDER_MINI_BOSS, spawn_enemy},
-    types::EnemyType,
+    components::{EnemyAI, MiniBoss},
+    config::{EnemyParams, GameParams, MiniBossAbility, StageParams},
+    resources::{GameData, GameRng, SelectedStage, TreasureSpawner},
+    systems::enemies::spawn::{
+        DEFAULT_COLLIDER_MINI_BOSS, random_off_screen_position, spawn_enemy,
+    },
+    types::{AIType, EnemyType, MiniBossKind},
 };
 
 // ---------------------------------------------------------------------------
@@ -36,17 +45,26 @@ const DEFAULT_HALF_H: f32 = 360.0;
 /// Extra off-screen margin so the mini-boss spawns out of view (pixels).
 const DEFAULT_SPAWN_MARGIN: f32 = 60.0;
 
+/// Distance a ranged (Summoner / Mage) mini-boss tries to keep from the
+/// player, as for the Medusa.
+const RANGED_ATTACK_RANGE: f32 = 250.0;
+
 // ---------------------------------------------------------------------------
 // System
 // ---------------------------------------------------------------------------
 
-/// Ticks [`TreasureSpawner`] and spawns a [`EnemyType::MiniBoss`] every
-/// `EnemyConfig::mini_boss_interval` seconds (default 180 s, tunable via
-/// `enemy.ron`).
+/// Spawns the mini-bosses that are due this frame.
+///
+/// - Stage schedule: every `mini_bosses` entry whose `at_min × 60` falls in
+///   `(last_checked_secs, elapsed_time]` spawns once.
+/// - No schedule available: ticks [`TreasureSpawner::spawn_timer`] and
+///   spawns the next archetype of [`MiniBossKind::ALL`] every
+///   `mini_boss_interval` seconds.
 ///
 /// Reads the camera position to compute a random off-screen spawn location
 /// (same four-edge strategy used by the normal enemy spawner).  Skips
 /// spawning if the final boss has already appeared.
+#[allow(clippy::too_many_arguments)]
 pub fn spawn_mini_boss(
     mut commands: Commands,
     mut treasure_spawner: ResMut<TreasureSpawner>,
@@ -55,23 +73,50 @@ pub fn spawn_mini_boss(
     game_data: Res<GameData>,
     game_cfg: GameParams,
     enemy_cfg: EnemyParams,
+    stage_params: StageParams,
+    selected_stage: Option<Res<SelectedStage>>,
+    mut game_rng: ResMut<GameRng>,
 ) {
+    let now = game_data.elapsed_time;
+    let last = treasure_spawner.last_checked_secs;
+    treasure_spawner.last_checked_secs = now;
+
     // Stop mini-boss spawning once the final boss fight begins.
     if game_data.boss_spawned {
         return;
     }
 
-    let interval = enemy_cfg
-        .get()
-        .map(|c| c.mini_boss_interval)
-        .unwrap_or(DEFAULT_MINI_BOSS_INTERVAL)
-        .max(1.0);
-
-    treasure_spawner.spawn_timer += time.delta_secs();
-    if treasure_spawner.spawn_timer < interval {
+    let entry = selected_stage
+        .as_deref()
+        .and_then(|s| stage_params.get().map(|c| c.entry_for(s.0)));
+    let due: Vec<MiniBossKind> = match entry {
+        Some(entry) => entry
+            .mini_bosses
+            .iter()
+            .filter(|e| {
+                let at = e.at_min * 60.0;
+                at > last && at <= now
+            })
+            .map(|e| e.kind)
+            .collect(),
+        None => {
+            let interval = enemy_cfg
+                .get()
+                .map(|c| c.mini_boss_interval)
+                .unwrap_or(DEFAULT_MINI_BOSS_INTERVAL)
+                .max(1.0);
+            treasure_spawner.spawn_timer += time.delta_secs();
+            if treasure_spawner.spawn_timer < interval {
+                return;
+            }
+            treasure_spawner.spawn_timer = 0.0;
+            let next = treasure_spawner.spawned as usize % MiniBossKind::ALL.len();
+            vec![MiniBossKind::ALL[next]]
+        }
+    };
+    if due.is_empty() {
         return;
     }
-    treasure_spawner.spawn_timer = 0.0;
 
     // Compute half-viewport + margin from config, fallback to constants.
     let (win_w, win_h) = game_cfg
@@ -90,52 +135,73 @@ pub fn spawn_mini_boss(
         .single()
         .map(|t| t.translation.truncate())
         .unwrap_or(Vec2::ZERO);
-    let spawn_pos = random_off_screen_position(cam_pos, half_w, half_h);
+
+    for kind in due {
+        let spawn_pos = random_off_screen_position(game_rng.rng(), cam_pos, half_w, half_h);
+        spawn_mini_boss_of_kind(&mut commands, kind, spawn_pos, &enemy_cfg);
+        treasure_spawner.spawned += 1;
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Spawns one mini-boss of `kind` at `position` and returns its entity.
+///
+/// Stats come from the shared `mini_boss` block in `enemy.ron`, scaled by the
+/// archetype's HP, speed and size multipliers.  Mini-boss HP is not scaled by
+/// difficulty or stage (fixed challenge per spawn).  Chargers chase the
+/// player between charges; summoners and mages keep their distance.
+pub(crate) fn spawn_mini_boss_of_kind(
+    commands: &mut Commands,
+    kind: MiniBossKind,
+    position: Vec2,
+    enemy_cfg: &EnemyParams,
+) -> Entity {
+    let archetype = enemy_cfg.mini_boss_archetype(kind);
 
     // Collider radius: prefer RON config, fall back to constant.
     let radius = enemy_cfg
         .get()
         .map(|c| c.mini_boss.collider_radius)
-        .unwrap_or(DEFAULT_COLLIDER_MINI_BOSS);
+        .unwrap_or(DEFAULT_COLLIDER_MINI_BOSS)
+        * archetype.size_multiplier.max(0.1);
 
-    // Mini-boss HP is not scaled by difficulty or stage (fixed challenge per spawn).
-    spawn_enemy(
-        &mut commands,
+    let entity = spawn_enemy(
+        commands,
         EnemyType::MiniBoss,
-        spawn_pos,
-        1.0,
-        1.0,
+        position,
         1.0,
+        archetype.hp_multiplier.max(0.0),
+        archetype.speed_multiplier.max(0.0),
         radius,
         enemy_cfg.get().map(|c| &c.mini_boss),
     );
-}
-
-// ---------------------------------------------------------------------------
-// Helpers
-// ---------------------------------------------------------------------------
 
-fn random_off_screen_position(cam_pos: Vec2, half_w: f32, half_h: f32) -> Vec2 {
-    use rand::RngExt;
-    let mut rng = rand::rng();
-    match rng.random_range(0..4u8) {
-        0 => Vec2::new(
-            cam_pos.x + rng.random_range(-half_w..half_w),
-            cam_pos.y + half_h,
-        ),
-        1 => Vec2::new(
-            cam_pos.x + rng.random_range(-half_w..half_w),
-            cam_pos.y - half_h,
-        ),
-        2 => Vec2::new(
-            cam_pos.x - half_w,
-            cam_pos.y + rng.random_range(-half_h..half_h),
-        ),
-        _ => Vec2::new(
-            cam_pos.x + half_w,
-            cam_pos.y + rng.random_range(-half_h..half_h),
-        ),
-    }
+    let ai = match archetype.ability {
+        MiniBossAbility::Charge { .. } => EnemyAI {
+            ai_type: AIType::ChasePlayer,
+            attack_timer: 0.0,
+            attack_range: 20.0,
+        },
+        MiniBossAbility::Summon { .. } | MiniBossAbility::Volley { .. } => EnemyAI {
+            ai_type: AIType::KeepDistance,
+            attack_timer: 0.0,
+            attack_range: RANGED_ATTACK_RANGE,
+        },
+    };
+    let (r, g, b) = archetype.color;
+    commands.entity(entity).insert((
+        ai,
+        Sprite {
+            color: Color::srgb(r, g, b),
+            custom_size: Some(Vec2::splat(radius * 2.0)),
+            ..default()
+        },
+        MiniBoss::new(kind, archetype),
+    ));
+    entity
 }
 
 // ---------------------------------------------------------------------------
@@ -161,6 +227,7 @@ mod tests {
         app.init_state::<AppState>();
         app.insert_resource(GameData::default());
         app.insert_resource(TreasureSpawner::default());
+        app.insert_resource(GameRng::from_seed(0));
         app
     }
 
@@ -253,4 +320,121 @@ mod tests {
             "spawned enemy must be MiniBoss"
         );
     }
+
+    /// The interval fallback cycles through the archetypes in order and
+    /// applies each one's state and AI.
+    #[test]
+    fn interval_spawns_cycle_through_archetypes() {
+        let mut app = build_app();
+
+        for _ in 0..3 {
+            app.world_mut()
+                .resource_mut::<TreasureSpawner>()
+                .spawn_timer = DEFAULT_MINI_BOSS_INTERVAL;
+            app.world_mut()
+                .run_system_once(spawn_mini_boss)
+                .expect("spawn_mini_boss should run");
+        }
+        app.world_mut().flush();
+
+        let mut q = app.world_mut().query::<(&MiniBoss, &EnemyAI)>();
+        let mut spawned: Vec<(MiniBossKind, AIType)> = q
+            .iter(app.world())
+            .map(|(m, ai)| (m.kind, ai.ai_type))
+            .collect();
+        spawned.sort_by_key(|(kind, _)| *kind as u8);
+        assert_eq!(
+            spawned,
+            vec![
+                (MiniBossKind::Giant, AIType::ChasePlayer),
+                (MiniBossKind::Summoner, AIType::KeepDistance),
+                (MiniBossKind::Mage, AIType::KeepDistance),
+            ]
+        );
+    }
+
+    /// The Giant's HP and size multipliers are applied on top of the shared
+    /// mini-boss stats.
+    #[test]
+    fn giant_is_bigger_and_tougher() {
+        use crate::components::CircleCollider;
+
+        let mut app = build_app();
+        app.world_mut()
+            .resource_mut::<TreasureSpawner>()
+            .spawn_timer = DEFAULT_MINI_BOSS_INTERVAL;
+        app.world_mut()
+            .run_system_once(spawn_mini_boss)
+            .expect("spawn_mini_boss should run");
+        app.world_mut().flush();
+
+        let mut q = app
+            .world_mut()
+            .query::<(&Enemy, &CircleCollider, &Sprite)>();
+        let (enemy, collider, sprite) = q.single(app.world()).unwrap();
+        let base = Enemy::from_type(EnemyType::MiniBoss, 1.0);
+        assert!((enemy.max_hp - base.max_hp * 2.0).abs() < 1e-3);
+        assert!((collider.radius - DEFAULT_COLLIDER_MINI_BOSS * 1.5).abs() < 1e-4);
+        assert_eq!(sprite.custom_size, Some(Vec2::splat(collider.radius * 2.0)));
+    }
+
+    /// With a stage schedule loaded, each entry spawns exactly once when the
+    /// run reaches it, and the interval timer is ignored.
+    #[test]
+    fn stage_schedule_fires_each_event_once() {
+        use crate::config::{MiniBossEvent, StageConfig, StageConfigHandle, StageEntryConfig};
+        use crate::types::StageType;
+
+        let mut app = build_app();
+        app.add_plugins(bevy::asset::AssetPlugin::default());
+        app.init_asset::<StageConfig>();
+        app.insert_resource(SelectedStage(StageType::MadForest));
+
+        let entry = StageEntryConfig {
+            display_name: "Test".to_string(),
+            enemy_types: vec![EnemyType::Bat],
+            enemy_hp_multiplier: 1.0,
+            enemy_speed_multiplier: 1.0,
+            spawn_interval_multiplier: 1.0,
+            max_enemies_multiplier: 1.0,
+            boss_hp_multiplier: 1.0,
+            boss_speed_multiplier: 1.0,
+            waves: Vec::new(),
+            formations: Vec::new(),
+            mini_bosses: vec![
+                MiniBossEvent::new(1.0, MiniBossKind::Mage),
+                MiniBossEvent::new(1.0, MiniBossKind::Giant),
+            ],
+            boss: None,
+        };
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<StageConfig>>()
+            .add(StageConfig {
+                mad_forest: entry.clone(),
+                inlaid_library: entry.clone(),
+                dairy_plant: entry,
+            });
+        app.world_mut().insert_resource(StageConfigHandle(handle));
+        app.world_mut()
+            .resource_mut::<TreasureSpawner>()
+            .spawn_timer = DEFAULT_MINI_BOSS_INTERVAL;
+
+        // Before minute 1: nothing, even with the interval timer full.
+        app.world_mut().resource_mut::<GameData>().elapsed_time = 59.0;
+        app.world_mut().run_system_once(spawn_mini_boss).unwrap();
+        app.world_mut().flush();
+        assert_eq!(mini_boss_count(&mut app), 0);
+
+        // Crossing minute 1 fires both entries.
+        app.world_mut().resource_mut::<GameData>().elapsed_time = 61.0;
+        app.world_mut().run_system_once(spawn_mini_boss).unwrap();
+        app.world_mut().flush();
+        assert_eq!(mini_boss_count(&mut app), 2);
+
+        app.world_mut().resource_mut::<GameData>().elapsed_time = 62.0;
+        app.world_mut().run_system_once(spawn_mini_boss).unwrap();
+        app.world_mut().flush();
+        assert_eq!(mini_boss_count(&mut app), 2, "events must not fire twice");
+    }
 }
@@ -1,13 +1,19 @@
 pub mod ai;
 pub mod boss_ai;
+pub mod boss_pattern;
 pub mod boss_scythe;
 pub mod boss_spawn;
 pub mod cull;
 pub mod difficulty;
-pub mod dragon;
-pub mod medusa;
+pub mod elite;
+pub mod formation;
+pub mod hostile_projectile;
+pub mod mini_boss;
 pub mod mini_boss_spawn;
+pub mod ranged;
+pub mod separation;
 pub mod spawn;
+pub mod status;
 
 use bevy::prelude::*;
 
@@ -17,75 +23,145 @@ pub struct EnemiesPlugin;
 
 impl Plugin for EnemiesPlugin {
     fn build(&self, app: &mut App) {
+        use crate::resources::enemies_active;
+        use crate::systems::damage::{apply_damage_to_enemies, tick_hit_stun};
         use crate::systems::enemies::ai::move_enemies;
         use crate::systems::enemies::boss_ai::{
             check_boss_phase_transition, move_boss_phase1, move_boss_phase2, move_boss_phase3,
         };
-        use crate::systems::enemies::boss_scythe::{
-            boss_scythe_player_collision, move_boss_scythes, tick_boss_scythe_attack,
+        use crate::systems::enemies::boss_pattern::{
+            advance_boss_pattern_phase, move_pattern_bosses, run_boss_patterns,
         };
+        use crate::systems::enemies::boss_scythe::tick_boss_scythe_attack;
         use crate::systems::enemies::boss_spawn::check_boss_spawn;
         use crate::systems::enemies::cull::cull_distant_enemies;
         use crate::systems::enemies::difficulty::update_difficulty;
-        use crate::systems::enemies::dragon::{
-            dragon_fireball_player_collision, move_dragon_fireballs, tick_dragon_attack,
+        use crate::systems::enemies::elite::{init_elites, split_elites_on_death};
+        use crate::systems::enemies::formation::{move_formation_enemies, spawn_formations};
+        use crate::systems::enemies::hostile_projectile::{
+            hostile_projectile_player_collision, move_hostile_projectiles,
+            update_hostile_projectile_grid,
         };
-        use crate::systems::enemies::medusa::{
-            medusa_projectile_player_collision, move_medusa_projectiles, tick_medusa_attack,
+        use crate::systems::enemies::mini_boss::{
+            move_charging_mini_bosses, run_mini_boss_abilities,
         };
         use crate::systems::enemies::mini_boss_spawn::spawn_mini_boss;
+        use crate::systems::enemies::ranged::tick_ranged_attacks;
+        use crate::systems::enemies::separation::separate_enemies;
         use crate::systems::enemies::spawn::spawn_enemies;
+        use crate::systems::enemies::status::{tick_status_effects, tint_status_effects};
         use crate::systems::game_timer::update_game_timer;
         use crate::systems::player::collision::enemy_player_collision;
         use crate::systems::player::player_movement;
+        use crate::systems::rng::GameRngSystems;
+        use crate::systems::spatial::update_spatial_grid;
         app.add_systems(
             Update,
             (
-                move_enemies.after(player_movement),
-                move_boss_phase1.after(player_movement),
-                move_boss_phase2.after(player_movement),
-                move_boss_phase3.after(player_movement),
+                // Movement and attack timers pause while an orologion freeze
+                // is active.
+                move_enemies.after(player_movement).run_if(enemies_active),
+                move_boss_phase1
+                    .after(player_movement)
+                    .run_if(enemies_active),
+                move_boss_phase2
+                    .after(player_movement)
+                    .run_if(enemies_active),
+                move_boss_phase3
+                    .after(player_movement)
+                    .run_if(enemies_active),
                 check_boss_phase_transition
                     .after(move_boss_phase1)
                     .after(move_boss_phase2)
                     .after(move_boss_phase3),
-                tick_boss_scythe_attack.after(move_boss_phase3),
-                move_boss_scythes,
-                boss_scythe_player_collision
-                    .after(move_boss_scythes)
-                    .after(enemy_player_collision),
+                tick_boss_scythe_attack
+                    .after(move_boss_phase3)
+                    .run_if(enemies_active),
                 update_difficulty.after(update_game_timer),
                 // Boss spawn must run before spawn_enemies so that setting
                 // EnemySpawner.active = false takes effect within the same frame.
                 check_boss_spawn
                     .after(update_game_timer)
                     .before(spawn_enemies),
-                spawn_enemies.after(update_difficulty),
+                spawn_enemies
+                    .after(update_difficulty)
+                    .in_set(GameRngSystems::EnemySpawns),
+                // Formation events (nested to stay within Bevy's tuple limit).
+                (
+                    spawn_formations
+                        .after(update_difficulty)
+                        .after(check_boss_spawn)
+                        .in_set(GameRngSystems::FormationSpawns),
+                    move_formation_enemies
+                        .after(player_movement)
+                        .run_if(enemies_active),
+                ),
                 // Must run after check_boss_spawn so that boss_spawned is already
                 // set before the mini-boss timer check — prevents an extra MiniBoss
                 // spawning on the same frame the final boss appears.
-                spawn_mini_boss.after(check_boss_spawn),
+                spawn_mini_boss
+                    .after(check_boss_spawn)
+                    .in_set(GameRngSystems::MiniBossSpawns),
                 cull_distant_enemies
                     .after(move_enemies)
+                    .after(move_formation_enemies)
                     .after(spawn_enemies),
-                tick_dragon_attack.after(move_enemies),
-                move_dragon_fireballs,
-                dragon_fireball_player_collision
-                    .after(move_dragon_fireballs)
-                    .after(enemy_player_collision),
-                tick_medusa_attack.after(move_enemies),
-                move_medusa_projectiles,
+                tick_ranged_attacks
+                    .after(move_enemies)
+                    .run_if(enemies_active),
+                // Every enemy and boss projectile moves, is indexed and hits
+                // the player through one pooled pipeline.
+                move_hostile_projectiles,
+                update_hostile_projectile_grid.after(move_hostile_projectiles),
                 // Run after enemy_player_collision so that if melee damage fires
                 // first this system is ordered after it.  Note: both systems use
                 // deferred Commands, so the InvincibilityTimer inserted by one is
                 // not yet visible to the other within the same frame — same-frame
                 // double-hit remains theoretically possible but is ordered
                 // deterministically.
-                medusa_projectile_player_collision
-                    .after(move_medusa_projectiles)
+                hostile_projectile_player_collision
+                    .after(update_hostile_projectile_grid)
                     .after(enemy_player_collision),
             )
                 .run_if(in_state(AppState::Playing)),
+        )
+        .add_systems(
+            Update,
+            (
+                // Hit-stun counts down before AI movement so an enemy moves
+                // again on the frame its stun expires.
+                tick_hit_stun.before(move_enemies),
+                // Crowd separation reads the grid built from this frame's AI
+                // movement.
+                separate_enemies.after(update_spatial_grid),
+                // Status ticks feed burn / poison damage into this frame's
+                // damage pass.
+                tick_status_effects.before(apply_damage_to_enemies),
+                tint_status_effects.after(tick_status_effects),
+                // Data-driven stage bosses: move, then run the pattern due.
+                move_pattern_bosses.after(player_movement),
+                run_boss_patterns
+                    .after(move_pattern_bosses)
+                    .in_set(GameRngSystems::BossPatterns),
+                // Mini-boss charges, then the ability due this frame.
+                move_charging_mini_bosses.after(player_movement),
+                run_mini_boss_abilities.after(move_charging_mini_bosses),
+            )
+                // All are frozen by an orologion like the rest of AI.
+                .run_if(enemies_active)
+                .run_if(in_state(AppState::Playing)),
+        )
+        .add_systems(
+            Update,
+            (
+                // Elite scaling lands before the new elite's first move.
+                init_elites.after(spawn_enemies).before(move_enemies),
+                split_elites_on_death.after(apply_damage_to_enemies),
+                advance_boss_pattern_phase
+                    .after(apply_damage_to_enemies)
+                    .before(run_boss_patterns),
+            )
+                .run_if(in_state(AppState::Playing)),
         );
     }
 }
@@ -0,0 +1,210 @@
+//! Ranged enemy attacks, driven entirely by `ranged_attacks` in `enemy.ron`.
+//!
+//! [`tick_ranged_attacks`] advances `EnemyAI::attack_timer` for every enemy
+//! whose type has a [`RangedAttackConfig`] and fires a [`HostileProjectile`]
+//! at the player when the interval elapses.  Movement stays with the
+//! enemy's AI (the Medusa keeps its distance, the Dragon chases), and the
+//! shot is handled by the shared
+//! [`hostile_projectile`](super::hostile_projectile) pipeline — so making
+//! another enemy type ranged only takes a new map entry.
+//!
+//! [`RangedAttackConfig`]: crate::config::RangedAttackConfig
+
+use bevy::prelude::*;
+
+use crate::{
+    components::{Enemy, EnemyAI, HostileProjectile, Player},
+    config::EnemyParams,
+    systems::enemies::hostile_projectile::{HostileProjectiles, HostileShot},
+    types::AIType,
+};
+
+/// Shortest accepted attack interval; guards against a zero in the RON.
+const MIN_ATTACK_INTERVAL: f32 = 0.1;
+/// Shortest accepted projectile lifetime (seconds).
+const MIN_PROJECTILE_LIFETIME: f32 = 0.1;
+/// Slowest accepted launch speed (pixels/second).
+const MIN_PROJECTILE_SPEED: f32 = 1.0;
+
+// ---------------------------------------------------------------------------
+// System
+// ---------------------------------------------------------------------------
+
+/// Ticks the attack timer of every ranged enemy and fires at the player
+/// whenever its `attack_interval` elapses.
+///
+/// - Only enemies moving on their own (`ChasePlayer` / `KeepDistance`)
+///   shoot; formation members and bosses do not.
+/// - The shot aims at the player's position at fire time and deals the
+///   enemy's contact `damage`.
+/// - No-op without a player; an enemy exactly on the player holds its fire.
+pub fn tick_ranged_attacks(
+    time: Res<Time>,
+    player_q: Query<&Transform, With<Player>>,
+    mut enemy_q: Query<(&Transform, &Enemy, &mut EnemyAI), Without<Player>>,
+    enemy_cfg: EnemyParams,
+    mut projectiles: HostileProjectiles,
+) {
+    let Ok(player_tf) = player_q.single() else {
+        return;
+    };
+    let player_pos = player_tf.translation.truncate();
+    let dt = time.delta_secs();
+
+    for (enemy_tf, enemy, mut ai) in enemy_q.iter_mut() {
+        if !matches!(ai.ai_type, AIType::ChasePlayer | AIType::KeepDistance) {
+            continue;
+        }
+        let Some(attack) = enemy_cfg.ranged_attack(enemy.enemy_type) else {
+            continue;
+        };
+
+        ai.attack_timer += dt;
+        if ai.attack_timer < attack.attack_interval.max(MIN_ATTACK_INTERVAL) {
+            continue;
+        }
+        ai.attack_timer = 0.0;
+
+        let origin = enemy_tf.translation.truncate();
+        let direction = (player_pos - origin).normalize_or_zero();
+        if direction == Vec2::ZERO {
+            continue; // player is exactly on the enemy — skip
+        }
+
+        let (r, g, b) = attack.color;
+        projectiles.fire(HostileShot {
+            projectile: HostileProjectile {
+                source: enemy.enemy_type,
+                damage: enemy.damage,
+                velocity: direction * attack.projectile_speed.max(MIN_PROJECTILE_SPEED),
+                lifetime: attack.projectile_lifetime.max(MIN_PROJECTILE_LIFETIME),
+                homing: attack.homing.max(0.0),
+                acceleration: attack.acceleration,
+                spin: attack.spin,
+            },
+            origin,
+            radius: attack.projectile_radius,
+            color: Color::srgb(r, g, b),
+        });
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::system::RunSystemOnce as _;
+
+    use super::*;
+    use crate::{resources::HostileProjectilePool, types::EnemyType};
+
+    /// Built-in Medusa attack interval (mirrors `enemy.ron`).
+    const MEDUSA_INTERVAL: f32 = 2.0;
+
+    fn build_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.init_resource::<HostileProjectilePool>();
+        app
+    }
+
+    fn spawn_player(app: &mut App, pos: Vec2) {
+        app.world_mut()
+            .spawn((Player, Transform::from_translation(pos.extend(10.0))));
+    }
+
+    fn spawn_enemy(app: &mut App, enemy_type: EnemyType, ai_type: AIType, timer: f32) -> Entity {
+        app.world_mut()
+            .spawn((
+                Enemy::from_type(enemy_type, 1.0),
+                EnemyAI {
+                    ai_type,
+                    attack_timer: timer,
+                    attack_range: 200.0,
+                },
+                Transform::from_translation(Vec3::new(0.0, 0.0, 5.0)),
+            ))
+            .id()
+    }
+
+    fn run(app: &mut App, secs: f32) -> Vec<HostileProjectile> {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(secs));
+        app.world_mut()
+            .run_system_once(tick_ranged_attacks)
+            .expect("tick_ranged_attacks should run");
+        let mut q = app.world_mut().query::<&HostileProjectile>();
+        q.iter(app.world()).cloned().collect()
+    }
+
+    // -----------------------------------------------------------------------
+
+    /// A Medusa does not fire before its attack interval elapses.
+    #[test]
+    fn does_not_fire_before_interval() {
+        let mut app = build_app();
+        spawn_player(&mut app, Vec2::new(200.0, 0.0));
+        spawn_enemy(&mut app, EnemyType::Medusa, AIType::KeepDistance, 0.0);
+
+        assert!(run(&mut app, 0.5).is_empty());
+    }
+
+    /// After the interval, one aimed shot carrying the enemy's damage is fired.
+    #[test]
+    fn fires_at_player_after_interval() {
+        let mut app = build_app();
+        spawn_player(&mut app, Vec2::new(200.0, 0.0));
+        let medusa = spawn_enemy(
+            &mut app,
+            EnemyType::Medusa,
+            AIType::KeepDistance,
+            MEDUSA_INTERVAL - 0.01,
+        );
+
+        let shots = run(&mut app, 0.05);
+
+        assert_eq!(shots.len(), 1, "exactly one projectile should be fired");
+        let enemy = app.world().get::<Enemy>(medusa).unwrap();
+        assert_eq!(shots[0].source, EnemyType::Medusa);
+        assert_eq!(shots[0].damage, enemy.damage);
+        assert!(shots[0].velocity.x > 0.0 && shots[0].velocity.y.abs() < 1e-4);
+    }
+
+    /// Dragons shoot while chasing, with the built-in Dragon attack.
+    #[test]
+    fn chasing_dragon_fires() {
+        let mut app = build_app();
+        spawn_player(&mut app, Vec2::new(0.0, 300.0));
+        spawn_enemy(&mut app, EnemyType::Dragon, AIType::ChasePlayer, 10.0);
+
+        let shots = run(&mut app, 0.05);
+
+        assert_eq!(shots.len(), 1);
+        assert_eq!(shots[0].source, EnemyType::Dragon);
+    }
+
+    /// Enemy types without a ranged attack never fire.
+    #[test]
+    fn melee_enemy_never_fires() {
+        let mut app = build_app();
+        spawn_player(&mut app, Vec2::new(200.0, 0.0));
+        spawn_enemy(&mut app, EnemyType::Bat, AIType::ChasePlayer, 100.0);
+
+        assert!(run(&mut app, 0.05).is_empty());
+    }
+
+    /// A ranged enemy held in a formation does not shoot.
+    #[test]
+    fn formation_member_holds_fire() {
+        let mut app = build_app();
+        spawn_player(&mut app, Vec2::new(200.0, 0.0));
+        spawn_enemy(&mut app, EnemyType::Medusa, AIType::Formation, 100.0);
+
+        assert!(run(&mut app, 0.05).is_empty());
+    }
+}
@@ -0,0 +1,243 @@
+//! Enemy-to-enemy separation (soft collision).
+//!
+//! Enemy AI steers every enemy straight at its target with no knowledge of
+//! its neighbours, so without this pass a horde collapses into a single
+//! overlapping blob on the player.  [`separate_enemies`] runs right after
+//! [`update_spatial_grid`](crate::systems::spatial::update_spatial_grid) and
+//! pushes overlapping enemies apart:
+//!
+//! - Two enemies overlap when their centres are closer than the sum of their
+//!   [`CircleCollider`] radii.
+//! - Each frame `separation_strength` (from `enemy.ron`) of the overlap is
+//!   resolved; the push is split by radius so large enemies (bosses) barely
+//!   move while small ones are shoved aside.
+//! - [`PhaseThrough`] enemies (ghosts) are skipped entirely.
+//!
+//! Pushes are computed from the positions at the start of the pass and then
+//! applied together, so the result does not depend on iteration order.
+//! Neighbours come from [`SpatialGrid`], keeping the pass O(n) for a
+//! well-spread horde — see `benches/enemy_separation.rs`.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::{
+    components::{CircleCollider, Enemy, PhaseThrough},
+    config::EnemyParams,
+    resources::SpatialGrid,
+};
+
+/// Distance below which two centres count as coincident and get a fixed
+/// push axis instead of a normalised one.
+const COINCIDENT_EPSILON: f32 = 1e-3;
+
+// ---------------------------------------------------------------------------
+// Public helpers (pure — used by the system and the benchmark)
+// ---------------------------------------------------------------------------
+
+/// Position and collider radius of one enemy taking part in separation.
+#[derive(Debug, Clone, Copy)]
+pub struct SeparationBody {
+    pub pos: Vec2,
+    pub radius: f32,
+}
+
+/// Computes the separation push for every body in `bodies`.
+///
+/// `grid` must contain the same entities at (roughly) the same positions;
+/// grid entries missing from `bodies` (e.g. ghosts) are ignored.  Each
+/// body's total push is capped at its own radius so a dense blob unpacks
+/// over several frames instead of exploding in one.
+pub fn separation_pushes(
+    grid: &SpatialGrid,
+    bodies: &HashMap<Entity, SeparationBody>,
+    strength: f32,
+) -> Vec<(Entity, Vec2)> {
+    let max_radius = bodies.values().map(|b| b.radius).fold(0.0_f32, f32::max);
+
+    bodies
+        .iter()
+        .filter_map(|(&entity, body)| {
+            let mut push = Vec2::ZERO;
+            for other in grid.get_nearby(body.pos, body.radius + max_radius) {
+                if other == entity {
+                    continue;
+                }
+                let Some(other_body) = bodies.get(&other) else {
+                    continue;
+                };
+                let min_dist = body.radius + other_body.radius;
+                let offset = body.pos - other_body.pos;
+                let dist_sq = offset.length_squared();
+                if dist_sq >= min_dist * min_dist {
+                    continue;
+                }
+                let dist = dist_sq.sqrt();
+                let dir = if dist > COINCIDENT_EPSILON {
+                    offset / dist
+                } else if entity < other {
+                    // Stacked exactly: split along X, in opposite directions.
+                    Vec2::X
+                } else {
+                    Vec2::NEG_X
+                };
+                // The smaller body takes the larger share of the push.
+                let share = other_body.radius / min_dist;
+                push += dir * (min_dist - dist) * share * strength;
+            }
+            (push != Vec2::ZERO).then(|| (entity, push.clamp_length_max(body.radius)))
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// System
+// ---------------------------------------------------------------------------
+
+/// Pushes overlapping enemies apart using [`SpatialGrid`] neighbour queries.
+///
+/// Runs after `update_spatial_grid`, so the grid reflects this frame's AI
+/// movement.  The grid is not rebuilt afterwards; later grid users see
+/// positions at most one separation step stale.
+pub fn separate_enemies(
+    grid: Res<SpatialGrid>,
+    mut enemy_q: Query<
+        (Entity, &CircleCollider, &mut Transform),
+        (With<Enemy>, Without<PhaseThrough>),
+    >,
+    enemy_cfg: EnemyParams,
+) {
+    let strength = enemy_cfg.separation_strength();
+    if strength <= 0.0 {
+        return;
+    }
+
+    let bodies: HashMap<Entity, SeparationBody> = enemy_q
+        .iter()
+        .map(|(entity, collider, tf)| {
+            (
+                entity,
+                SeparationBody {
+                    pos: tf.translation.truncate(),
+                    radius: collider.radius,
+                },
+            )
+        })
+        .collect();
+
+    for (entity, push) in separation_pushes(&grid, &bodies, strength) {
+        if let Ok((_, _, mut tf)) = enemy_q.get_mut(entity) {
+            tf.translation += push.extend(0.0);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce as _;
+
+    use super::*;
+    use crate::{systems::spatial::update_spatial_grid, types::EnemyType};
+
+    fn build_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(SpatialGrid::default());
+        app
+    }
+
+    fn spawn_at(app: &mut App, pos: Vec2, radius: f32) -> Entity {
+        app.world_mut()
+            .spawn((
+                Enemy::from_type(EnemyType::Skeleton, 1.0),
+                CircleCollider { radius },
+                Transform::from_xyz(pos.x, pos.y, 5.0),
+            ))
+            .id()
+    }
+
+    fn run(app: &mut App) {
+        app.world_mut()
+            .run_system_once(update_spatial_grid)
+            .unwrap();
+        app.world_mut().run_system_once(separate_enemies).unwrap();
+    }
+
+    fn pos(app: &App, entity: Entity) -> Vec2 {
+        app.world()
+            .get::<Transform>(entity)
+            .unwrap()
+            .translation
+            .truncate()
+    }
+
+    #[test]
+    fn overlapping_enemies_are_pushed_apart_symmetrically() {
+        let mut app = build_app();
+        let a = spawn_at(&mut app, Vec2::new(-5.0, 0.0), 12.0);
+        let b = spawn_at(&mut app, Vec2::new(5.0, 0.0), 12.0);
+
+        run(&mut app);
+
+        let (pa, pb) = (pos(&app, a), pos(&app, b));
+        assert!(pa.x < -5.0 && pb.x > 5.0, "both must move away");
+        assert!((pa.x + pb.x).abs() < 1e-4, "equal radii → equal pushes");
+        assert_eq!(pa.y, 0.0);
+    }
+
+    #[test]
+    fn separated_enemies_do_not_move() {
+        let mut app = build_app();
+        let a = spawn_at(&mut app, Vec2::ZERO, 12.0);
+        let b = spawn_at(&mut app, Vec2::new(30.0, 0.0), 12.0);
+
+        run(&mut app);
+
+        assert_eq!(pos(&app, a), Vec2::ZERO);
+        assert_eq!(pos(&app, b), Vec2::new(30.0, 0.0));
+    }
+
+    #[test]
+    fn larger_enemy_moves_less() {
+        let mut app = build_app();
+        let small = spawn_at(&mut app, Vec2::ZERO, 8.0);
+        let big = spawn_at(&mut app, Vec2::new(20.0, 0.0), 60.0);
+
+        run(&mut app);
+
+        let small_moved = pos(&app, small).distance(Vec2::ZERO);
+        let big_moved = pos(&app, big).distance(Vec2::new(20.0, 0.0));
+        assert!(small_moved > big_moved * 2.0);
+    }
+
+    #[test]
+    fn stacked_enemies_split_in_opposite_directions() {
+        let mut app = build_app();
+        let a = spawn_at(&mut app, Vec2::ZERO, 10.0);
+        let b = spawn_at(&mut app, Vec2::ZERO, 10.0);
+
+        run(&mut app);
+
+        let (pa, pb) = (pos(&app, a), pos(&app, b));
+        assert!(pa.x != 0.0 && pb.x != 0.0);
+        assert!((pa + pb).length() < 1e-4);
+    }
+
+    #[test]
+    fn phase_through_enemies_are_ignored() {
+        let mut app = build_app();
+        let solid = spawn_at(&mut app, Vec2::ZERO, 12.0);
+        let ghost = spawn_at(&mut app, Vec2::new(5.0, 0.0), 12.0);
+        app.world_mut().entity_mut(ghost).insert(PhaseThrough);
+
+        run(&mut app);
+
+        assert_eq!(pos(&app, solid), Vec2::ZERO);
+        assert_eq!(pos(&app, ghost), Vec2::new(5.0, 0.0));
+    }
+}
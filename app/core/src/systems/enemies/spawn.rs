@@ -1,16 +1,25 @@
-//! Enemy spawn system — timer-based, off-screen.
+//! Enemy spawn system — wave-driven, off-screen.
 //!
 //! Each frame [`spawn_enemies`] reads the current [`EnemySpawner`] state
 //! (set by [`super::difficulty::update_difficulty`]) and, once the effective
-//! spawn interval elapses, picks a random position just outside the visible
-//! viewport and spawns an enemy chosen from the active spawn table.
+//! spawn interval elapses, picks random positions just outside the visible
+//! viewport and spawns enemies chosen from the active spawn table.
 //!
-//! ## Spawn table (time-gated, weighted)
+//! ## Stage waves
 //!
-//! Enemies are selected by weighted random — stronger enemies that unlock
-//! later carry lower weights so early enemies remain common throughout
-//! the run.  The weights are loaded from `enemy.ron` (`spawn_weight` field
-//! on each entry) with constant fallbacks listed below.
+//! The selected stage's wave timeline (`waves` in `stage.ron`) decides what
+//! spawns when.  While a [`StageWave`] is active its own weighted table is
+//! used, `burst` enemies appear per spawn interval, and the field is topped
+//! up to `min_count` every frame.
+//!
+//! ## Fallback spawn table (time-gated, weighted)
+//!
+//! When no wave covers the current time (or the stage config is not loaded),
+//! enemies are selected by weighted random from unlock timers, filtered by
+//! the stage's `enemy_types` — stronger enemies that unlock later carry
+//! lower weights so early enemies remain common throughout the run.  The
+//! weights are loaded from `enemy.ron` (`spawn_weight` field on each entry)
+//! with constant fallbacks listed below.
 //!
 //! | Enemy    | Unlocks at | Default weight |
 //! |----------|-----------|---------------|
@@ -21,14 +30,27 @@
 //! | Demon    | 15 min    | 0.5           |
 //! | Medusa   | 20 min    | 0.4           |
 //! | Dragon   | 25 min    | 0.3           |
+//!
+//! ## Elites
+//!
+//! Each regular spawn rolls for an elite modifier via
+//! [`roll_elite`](super::elite::roll_elite); the chance rises with
+//! [`EnemySpawner::difficulty_multiplier`].  Elites get an [`Elite`]
+//! component, and [`init_elites`](super::elite::init_elites) scales them.
+//!
+//! All random rolls draw from [`GameRng`] so a run is reproducible from its
+//! seed.
+//!
+//! [`StageWave`]: crate::config::StageWave
 
 use bevy::prelude::*;
 use rand::RngExt;
 
 use crate::{
-    components::{CircleCollider, Enemy, EnemyAI, GameSessionEntity, PhaseThrough},
-    config::{EnemyParams, EnemyStatsEntry, GameParams, StageParams},
-    resources::{EnemySpawner, GameData, SelectedStage},
+    components::{CircleCollider, Elite, Enemy, EnemyAI, GameSessionEntity, PhaseThrough},
+    config::{EnemyConfig, EnemyParams, EnemyStatsEntry, GameParams, StageParams},
+    resources::{EnemySpawner, GameData, GameRng, SelectedStage},
+    systems::enemies::elite::{can_be_elite, roll_elite},
     types::{AIType, EnemyType},
 };
 
@@ -98,13 +120,20 @@ const DEFAULT_SPAWN_MARGIN: f32 = 60.0;
 ///
 /// Reads `difficulty_multiplier` and `spawn_interval` from [`EnemySpawner`],
 /// which are kept up-to-date by
-/// [`super::difficulty::update_difficulty`] (runs earlier in the same frame).
+/// [`super::difficulty::update_difficulty`] (runs earlier in the same frame)
+/// from the active wave's `spawn_interval` and difficulty ramp.
 ///
 /// Each frame this system:
 /// 1. Returns early when [`EnemySpawner::active`] is `false`.
 /// 2. Throttles when the current enemy count reaches [`ENEMY_MAX_COUNT`].
-/// 3. Accumulates delta time; spawns once the effective interval elapses.
-/// 4. Picks a random off-screen edge position and a random enemy type.
+/// 3. Looks up the selected stage's active
+///    [`StageWave`](crate::config::StageWave).
+/// 4. Accumulates delta time; once the effective interval elapses, queues
+///    the wave's `burst` (one enemy when no wave is active).
+/// 5. Queues a top-up to the wave's `min_count` on top of that.
+/// 6. Spawns the queued enemies at random off-screen positions, picking
+///    types from the wave table or the unlock-timer fallback table, and
+///    rolls each one for an elite modifier.
 #[allow(clippy::too_many_arguments)]
 pub fn spawn_enemies(
     mut commands: Commands,
@@ -117,6 +146,7 @@ pub fn spawn_enemies(
     game_data: Res<GameData>,
     stage_params: StageParams,
     selected_stage: Option<Res<SelectedStage>>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     if !spawner.active {
         return;
@@ -127,15 +157,40 @@ pub fn spawn_enemies(
         .get()
         .map(|c| c.max_count)
         .unwrap_or(DEFAULT_ENEMY_MAX_COUNT);
-    if enemy_q.iter().count() >= max_count {
+    let current = enemy_q.iter().count();
+    if current >= max_count {
         return;
     }
 
+    let elapsed = game_data.elapsed_time;
+    let stage = selected_stage.as_deref().map(|s| s.0);
+    let stage_entry = stage.and_then(|s| stage_params.get().map(|c| c.entry_for(s)));
+    let wave = stage_entry.and_then(|e| e.wave_at(elapsed));
+
     spawner.spawn_timer += time.delta_secs();
-    if spawner.spawn_timer < spawner.spawn_interval {
+    let burst = if spawner.spawn_timer >= spawner.spawn_interval {
+        spawner.spawn_timer = 0.0;
+        wave.map_or(1, |w| w.burst as usize)
+    } else {
+        0
+    };
+    let top_up = wave.map_or(0, |w| w.min_count.saturating_sub(current));
+    let to_spawn = (burst + top_up).min(max_count - current);
+    if to_spawn == 0 {
         return;
     }
-    spawner.spawn_timer = 0.0;
+
+    let table = match wave {
+        Some(w) => w.spawn_table(),
+        None => {
+            let mut table = unlock_spawn_table(&enemy_cfg, elapsed);
+            // Apply stage filter: keep only enemy types allowed by the stage.
+            if let Some(entry) = stage_entry {
+                table.retain(|(etype, _)| entry.enemy_types.contains(etype));
+            }
+            table
+        }
+    };
 
     // Compute half-viewport dimensions with spawn margin from config.
     let (win_w, win_h) = game_cfg
@@ -150,41 +205,66 @@ pub fn spawn_enemies(
     let half_w = win_w / 2.0 + spawn_margin;
     let half_h = win_h / 2.0 + spawn_margin;
 
-    // Derive the camera-centred spawn position.
+    // Derive the camera-centred spawn area.
     let cam_pos = camera_q
         .single()
         .map(|t| t.translation.truncate())
         .unwrap_or(Vec2::ZERO);
-    let spawn_pos = random_off_screen_position(cam_pos, half_w, half_h);
 
-    // Build the active spawn table based on elapsed time.
-    let elapsed = game_data.elapsed_time;
-    let zombie_unlock = enemy_cfg
-        .get()
-        .map(|c| c.zombie_unlock_secs)
-        .unwrap_or(DEFAULT_ZOMBIE_UNLOCK_SECS)
-        .max(0.0);
-    let ghost_unlock = enemy_cfg
-        .get()
-        .map(|c| c.ghost_unlock_secs)
-        .unwrap_or(DEFAULT_GHOST_UNLOCK_SECS)
-        .max(0.0);
-    let demon_unlock = enemy_cfg
-        .get()
-        .map(|c| c.demon_unlock_secs)
-        .unwrap_or(DEFAULT_DEMON_UNLOCK_SECS)
-        .max(0.0);
-    let medusa_unlock = enemy_cfg
-        .get()
-        .map(|c| c.medusa_unlock_secs)
-        .unwrap_or(DEFAULT_MEDUSA_UNLOCK_SECS)
-        .max(0.0);
-    let dragon_unlock = enemy_cfg
-        .get()
-        .map(|c| c.dragon_unlock_secs)
-        .unwrap_or(DEFAULT_DRAGON_UNLOCK_SECS)
-        .max(0.0);
+    // Stage HP/speed multipliers (default 1.0 when config not loaded).
+    let (stage_hp_mult, stage_speed_mult) = stage_entry
+        .map(|e| (e.enemy_hp_multiplier, e.enemy_speed_multiplier))
+        .unwrap_or((1.0, 1.0));
+    let elite_cfg = enemy_cfg.elite();
+
+    for _ in 0..to_spawn {
+        let Some(enemy_type) = weighted_random(game_rng.rng(), &table) else {
+            // All entries have zero weight or stage filter removed all — skip.
+            return;
+        };
+        let spawn_pos = random_off_screen_position(game_rng.rng(), cam_pos, half_w, half_h);
+
+        // Derive all enemy stats from config when available, falling back to constants.
+        let cfg_stats = enemy_cfg.get().map(|c| c.stats_for(enemy_type).clone());
+        let collider_radius = cfg_stats
+            .as_ref()
+            .map(|s| s.collider_radius)
+            .unwrap_or_else(|| fallback_collider_radius(enemy_type));
+
+        let entity = spawn_enemy(
+            &mut commands,
+            enemy_type,
+            spawn_pos,
+            spawner.difficulty_multiplier,
+            stage_hp_mult,
+            stage_speed_mult,
+            collider_radius,
+            cfg_stats.as_ref(),
+        );
+        if !can_be_elite(enemy_type) {
+            continue;
+        }
+        if let Some(modifier) =
+            roll_elite(game_rng.rng(), spawner.difficulty_multiplier, &elite_cfg)
+        {
+            commands.entity(entity).insert(Elite { modifier });
+        }
+    }
+}
 
+// ---------------------------------------------------------------------------
+// Private helpers
+// ---------------------------------------------------------------------------
+
+/// Builds the weighted spawn table from the `enemy.ron` unlock timers.
+///
+/// Used outside any stage wave.  Each enemy type respects only its own
+/// unlock threshold; stronger enemies that unlock later carry lower weights
+/// so early enemies remain common.
+fn unlock_spawn_table(enemy_cfg: &EnemyParams, elapsed: f32) -> Vec<(EnemyType, f32)> {
+    let unlock = |secs: fn(&EnemyConfig) -> f32, fallback: f32| -> f32 {
+        enemy_cfg.get().map(secs).unwrap_or(fallback).max(0.0)
+    };
     // Helper: return the spawn_weight from config or fall back to constant.
     let weight = |etype: EnemyType, fallback: f32| -> f32 {
         enemy_cfg
@@ -194,91 +274,49 @@ pub fn spawn_enemies(
             .max(0.0)
     };
 
-    // Build the weighted spawn table from independent unlock flags so each
-    // enemy type respects only its own threshold.  Stronger enemies that
-    // unlock later carry lower weights so early enemies remain common.
-    let mut table: Vec<(EnemyType, f32)> = vec![
-        (EnemyType::Bat, weight(EnemyType::Bat, DEFAULT_WEIGHT_BAT)),
+    let gated = [
         (
-            EnemyType::Skeleton,
-            weight(EnemyType::Skeleton, DEFAULT_WEIGHT_SKELETON),
-        ),
-    ];
-    if elapsed >= zombie_unlock {
-        table.push((
             EnemyType::Zombie,
-            weight(EnemyType::Zombie, DEFAULT_WEIGHT_ZOMBIE),
-        ));
-    }
-    if elapsed >= ghost_unlock {
-        table.push((
+            unlock(|c| c.zombie_unlock_secs, DEFAULT_ZOMBIE_UNLOCK_SECS),
+            DEFAULT_WEIGHT_ZOMBIE,
+        ),
+        (
             EnemyType::Ghost,
-            weight(EnemyType::Ghost, DEFAULT_WEIGHT_GHOST),
-        ));
-    }
-    if elapsed >= demon_unlock {
-        table.push((
+            unlock(|c| c.ghost_unlock_secs, DEFAULT_GHOST_UNLOCK_SECS),
+            DEFAULT_WEIGHT_GHOST,
+        ),
+        (
             EnemyType::Demon,
-            weight(EnemyType::Demon, DEFAULT_WEIGHT_DEMON),
-        ));
-    }
-    if elapsed >= medusa_unlock {
-        table.push((
+            unlock(|c| c.demon_unlock_secs, DEFAULT_DEMON_UNLOCK_SECS),
+            DEFAULT_WEIGHT_DEMON,
+        ),
+        (
             EnemyType::Medusa,
-            weight(EnemyType::Medusa, DEFAULT_WEIGHT_MEDUSA),
-        ));
-    }
-    if elapsed >= dragon_unlock {
-        table.push((
+            unlock(|c| c.medusa_unlock_secs, DEFAULT_MEDUSA_UNLOCK_SECS),
+            DEFAULT_WEIGHT_MEDUSA,
+        ),
+        (
             EnemyType::Dragon,
-            weight(EnemyType::Dragon, DEFAULT_WEIGHT_DRAGON),
-        ));
-    }
+            unlock(|c| c.dragon_unlock_secs, DEFAULT_DRAGON_UNLOCK_SECS),
+            DEFAULT_WEIGHT_DRAGON,
+        ),
+    ];
 
-    // Apply stage filter: keep only enemy types allowed by the selected stage.
-    let stage = selected_stage.as_deref().map(|s| s.0);
-    if let Some(cfg) = stage_params.get()
-        && let Some(stage) = stage
-    {
-        let allowed = &cfg.entry_for(stage).enemy_types;
-        table.retain(|(etype, _)| allowed.contains(etype));
+    let mut table: Vec<(EnemyType, f32)> = vec![
+        (EnemyType::Bat, weight(EnemyType::Bat, DEFAULT_WEIGHT_BAT)),
+        (
+            EnemyType::Skeleton,
+            weight(EnemyType::Skeleton, DEFAULT_WEIGHT_SKELETON),
+        ),
+    ];
+    for (etype, unlock_secs, fallback_weight) in gated {
+        if elapsed >= unlock_secs {
+            table.push((etype, weight(etype, fallback_weight)));
+        }
     }
-
-    let mut rng = rand::rng();
-    let Some(enemy_type) = weighted_random(&mut rng, &table) else {
-        // All entries have zero weight or stage filter removed all — skip.
-        return;
-    };
-
-    // Stage HP/speed multipliers (default 1.0 when config not loaded).
-    let (stage_hp_mult, stage_speed_mult) = stage
-        .and_then(|s| stage_params.get().map(|c| c.entry_for(s)))
-        .map(|e| (e.enemy_hp_multiplier, e.enemy_speed_multiplier))
-        .unwrap_or((1.0, 1.0));
-
-    // Derive all enemy stats from config when available, falling back to constants.
-    let cfg_stats = enemy_cfg.get().map(|c| c.stats_for(enemy_type).clone());
-    let collider_radius = cfg_stats
-        .as_ref()
-        .map(|s| s.collider_radius)
-        .unwrap_or_else(|| fallback_collider_radius(enemy_type));
-
-    spawn_enemy(
-        &mut commands,
-        enemy_type,
-        spawn_pos,
-        spawner.difficulty_multiplier,
-        stage_hp_mult,
-        stage_speed_mult,
-        collider_radius,
-        cfg_stats.as_ref(),
-    );
+    table
 }
 
-// ---------------------------------------------------------------------------
-// Private helpers
-// ---------------------------------------------------------------------------
-
 /// Selects an [`EnemyType`] from a weighted table using a single random roll.
 ///
 /// Each entry is `(EnemyType, weight)`.  The probability of picking entry
@@ -286,7 +324,7 @@ pub fn spawn_enemies(
 /// skipped.  Returns `None` when the total weight is zero (all entries
 /// disabled) so the caller can skip the spawn rather than forcing an
 /// arbitrary selection.
-fn weighted_random(rng: &mut impl rand::RngExt, table: &[(EnemyType, f32)]) -> Option<EnemyType> {
+fn weighted_random(rng: &mut impl RngExt, table: &[(EnemyType, f32)]) -> Option<EnemyType> {
     let total: f32 = table.iter().map(|(_, w)| w).sum();
     if total <= 0.0 {
         return None;
@@ -306,10 +344,14 @@ fn weighted_random(rng: &mut impl rand::RngExt, table: &[(EnemyType, f32)]) -> O
 /// edges, centred on `cam_pos`.
 ///
 /// `half_w` and `half_h` are the half-extents of the spawn boundary (already
-/// incorporating the window size and spawn margin).
-fn random_off_screen_position(cam_pos: Vec2, half_w: f32, half_h: f32) -> Vec2 {
-    let mut rng = rand::rng();
-
+/// incorporating the window size and spawn margin).  Shared with the
+/// mini-boss spawner so both use the same edge strategy.
+pub(crate) fn random_off_screen_position(
+    rng: &mut impl RngExt,
+    cam_pos: Vec2,
+    half_w: f32,
+    half_h: f32,
+) -> Vec2 {
     match rng.random_range(0..4u8) {
         // Top edge
         0 => Vec2::new(
@@ -358,7 +400,7 @@ fn enemy_color(enemy_type: EnemyType) -> Color {
 }
 
 /// Fallback collider radius when config is not loaded.
-fn fallback_collider_radius(enemy_type: EnemyType) -> f32 {
+pub(crate) fn fallback_collider_radius(enemy_type: EnemyType) -> f32 {
     match enemy_type {
         EnemyType::Bat => DEFAULT_COLLIDER_BAT,
         EnemyType::Skeleton => DEFAULT_COLLIDER_SKELETON,
@@ -382,6 +424,8 @@ fn fallback_collider_radius(enemy_type: EnemyType) -> f32 {
 /// implement per-stage difficulty modifiers.
 /// Ghost enemies additionally receive [`PhaseThrough`].
 /// Medusa enemies use [`AIType::KeepDistance`] instead of `ChasePlayer`.
+///
+/// Returns the new entity so callers can attach extra components.
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn spawn_enemy(
     commands: &mut Commands,
@@ -392,7 +436,7 @@ pub(crate) fn spawn_enemy(
     speed_mult: f32,
     collider_radius: f32,
     cfg_stats: Option<&EnemyStatsEntry>,
-) {
+) -> Entity {
     let color = enemy_color(enemy_type);
     let mut enemy_component = match cfg_stats {
         Some(stats) => Enemy::from_config(enemy_type, stats, difficulty),
@@ -407,19 +451,7 @@ pub(crate) fn spawn_enemy(
     if (speed_mult - 1.0).abs() > f32::EPSILON {
         enemy_component.move_speed *= speed_mult;
     }
-    let ai = if enemy_type == EnemyType::Medusa {
-        EnemyAI {
-            ai_type: AIType::KeepDistance,
-            attack_timer: 0.0,
-            attack_range: 250.0,
-        }
-    } else {
-        EnemyAI {
-            ai_type: AIType::ChasePlayer,
-            attack_timer: 0.0,
-            attack_range: 20.0,
-        }
-    };
+    let ai = default_enemy_ai(enemy_type);
     let mut entity = commands.spawn((
         enemy_component,
         ai,
@@ -438,6 +470,27 @@ pub(crate) fn spawn_enemy(
     if enemy_type == EnemyType::Ghost {
         entity.insert(PhaseThrough);
     }
+    entity.id()
+}
+
+/// The [`EnemyAI`] a regular enemy of `enemy_type` spawns with.
+///
+/// Medusa keeps its distance; every other type chases the player.  Also
+/// used to restore an enemy's AI when a formation releases it.
+pub(crate) fn default_enemy_ai(enemy_type: EnemyType) -> EnemyAI {
+    if enemy_type == EnemyType::Medusa {
+        EnemyAI {
+            ai_type: AIType::KeepDistance,
+            attack_timer: 0.0,
+            attack_range: 250.0,
+        }
+    } else {
+        EnemyAI {
+            ai_type: AIType::ChasePlayer,
+            attack_timer: 0.0,
+            attack_range: 20.0,
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -465,6 +518,7 @@ mod tests {
         app.init_state::<AppState>();
         app.insert_resource(EnemySpawner::default());
         app.insert_resource(GameData::default());
+        app.insert_resource(GameRng::from_seed(0));
         app
     }
 
@@ -1159,6 +1213,29 @@ mod tests {
         );
     }
 
+    /// Two generators with the same seed pick identical enemies and spawn
+    /// positions, so a run can be reproduced from its seed.
+    #[test]
+    fn same_seed_reproduces_spawn_rolls() {
+        let table = [
+            (EnemyType::Bat, 1.0_f32),
+            (EnemyType::Skeleton, 1.0),
+            (EnemyType::Zombie, 0.8),
+        ];
+        let mut a = GameRng::from_seed(1234);
+        let mut b = GameRng::from_seed(1234);
+        for _ in 0..50 {
+            assert_eq!(
+                weighted_random(a.rng(), &table),
+                weighted_random(b.rng(), &table)
+            );
+            assert_eq!(
+                random_off_screen_position(a.rng(), Vec2::ZERO, 700.0, 420.0),
+                random_off_screen_position(b.rng(), Vec2::ZERO, 700.0, 420.0)
+            );
+        }
+    }
+
     /// Bat is more common than Dragon in a full post-25-min spawn table.
     #[test]
     fn bat_more_common_than_dragon_after_all_unlocked() {
@@ -1213,8 +1290,7 @@ mod tests {
         use std::time::Duration;
 
         use crate::config::{
-            DragonBehaviorConfig, EnemyConfig, EnemyConfigHandle, EnemyStatsEntry,
-            MedusaBehaviorConfig,
+            EliteConfig, EnemyConfig, EnemyConfigHandle, EnemyStatsEntry, MedusaBehaviorConfig,
         };
 
         // Build an app that also registers Assets<EnemyConfig>.
@@ -1228,6 +1304,7 @@ mod tests {
         app.init_state::<AppState>();
         app.insert_resource(EnemySpawner::default());
         app.insert_resource(GameData::default());
+        app.insert_resource(GameRng::from_seed(0));
         app.init_asset::<EnemyConfig>();
 
         // Helper: build a stats entry with the given spawn_weight.
@@ -1239,6 +1316,8 @@ mod tests {
             gold_chance: 0.0,
             collider_radius: 8.0,
             spawn_weight,
+            knockback_resistance: 0.0,
+            damage_multipliers: Default::default(),
         };
 
         let config = EnemyConfig {
@@ -1257,6 +1336,9 @@ mod tests {
             cull_distance: 2000.0,
             difficulty_max: 10.0,
             spawn_margin: 60.0,
+            separation_strength: 0.5,
+            hit_stun_duration: 0.15,
+            status_tick_interval: 0.5,
             // Zero all unlock thresholds so all types are eligible from the start.
             zombie_unlock_secs: 0.0,
             ghost_unlock_secs: 0.0,
@@ -1264,20 +1346,14 @@ mod tests {
             medusa_unlock_secs: 0.0,
             dragon_unlock_secs: 0.0,
             mini_boss_interval: 180.0,
+            hostile_projectile_pool_size: 512,
             medusa_behavior: MedusaBehaviorConfig {
                 keep_min_dist: 150.0,
                 keep_max_dist: 250.0,
-                attack_interval: 2.0,
-                projectile_speed: 180.0,
-                projectile_lifetime: 5.0,
-                projectile_radius: 5.0,
-            },
-            dragon_behavior: DragonBehaviorConfig {
-                attack_interval: 3.0,
-                fireball_speed: 200.0,
-                fireball_lifetime: 6.0,
-                fireball_radius: 7.0,
             },
+            ranged_attacks: Default::default(),
+            mini_boss_archetypes: Default::default(),
+            elite: EliteConfig::default(),
         };
 
         let handle = {
@@ -1345,4 +1421,114 @@ mod tests {
         assert!(w.get::<Transform>(entity).is_some(), "missing Transform");
         assert!(w.get::<Sprite>(entity).is_some(), "missing Sprite");
     }
+
+    // -----------------------------------------------------------------------
+    // Stage wave timeline
+    // -----------------------------------------------------------------------
+
+    /// Builds a Playing app whose selected stage (Mad Forest) runs `wave`
+    /// for the whole run.
+    fn build_wave_app(wave: crate::config::StageWave) -> App {
+        use crate::config::{StageConfig, StageConfigHandle, StageEntryConfig};
+        use crate::resources::SelectedStage;
+        use crate::types::StageType;
+
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            StatesPlugin,
+            bevy::asset::AssetPlugin::default(),
+        ));
+        app.init_state::<AppState>();
+        app.insert_resource(EnemySpawner::default());
+        app.insert_resource(GameData::default());
+        app.insert_resource(GameRng::from_seed(0));
+        app.insert_resource(SelectedStage(StageType::MadForest));
+        app.init_asset::<StageConfig>();
+
+        let entry = StageEntryConfig {
+            display_name: "Test".to_string(),
+            enemy_types: vec![EnemyType::Bat],
+            enemy_hp_multiplier: 1.0,
+            enemy_speed_multiplier: 1.0,
+            spawn_interval_multiplier: 1.0,
+            max_enemies_multiplier: 1.0,
+            boss_hp_multiplier: 1.0,
+            boss_speed_multiplier: 1.0,
+            waves: vec![wave],
+            formations: Vec::new(),
+            mini_bosses: Vec::new(),
+            boss: None,
+        };
+        let config = StageConfig {
+            mad_forest: entry.clone(),
+            inlaid_library: entry.clone(),
+            dairy_plant: entry,
+        };
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<StageConfig>>()
+            .add(config);
+        app.world_mut().insert_resource(StageConfigHandle(handle));
+        app
+    }
+
+    fn skeleton_wave(min_count: usize, burst: u32) -> crate::config::StageWave {
+        crate::config::StageWave {
+            start_min: 0.0,
+            end_min: 30.0,
+            enemies: vec![crate::config::WaveEnemy {
+                enemy: EnemyType::Skeleton,
+                weight: 1.0,
+            }],
+            min_count,
+            burst,
+            spawn_interval: DEFAULT_ENEMY_SPAWN_BASE_INTERVAL,
+            difficulty_start: 1.0,
+            difficulty_end: 1.0,
+        }
+    }
+
+    /// An active wave spawns its whole burst at once, using only its own
+    /// table (the stage whitelist is ignored).
+    #[test]
+    fn wave_spawns_burst_from_wave_table() {
+        use bevy::ecs::system::RunSystemOnce as _;
+
+        let mut app = build_wave_app(skeleton_wave(0, 3));
+        app.world_mut().resource_mut::<EnemySpawner>().spawn_timer =
+            DEFAULT_ENEMY_SPAWN_BASE_INTERVAL + 0.1;
+
+        app.world_mut()
+            .run_system_once(spawn_enemies)
+            .expect("spawn_enemies should run");
+
+        let mut q = app.world_mut().query::<&Enemy>();
+        let types: Vec<_> = q.iter(app.world()).map(|e| e.enemy_type).collect();
+        assert_eq!(types, vec![EnemyType::Skeleton; 3]);
+    }
+
+    /// Below `min_count` the field is topped up immediately, without waiting
+    /// for the spawn interval.
+    #[test]
+    fn wave_tops_up_to_min_count_before_interval() {
+        use bevy::ecs::system::RunSystemOnce as _;
+
+        let mut app = build_wave_app(skeleton_wave(5, 1));
+        app.world_mut()
+            .spawn(Enemy::from_type(EnemyType::Skeleton, 1.0));
+
+        app.world_mut()
+            .run_system_once(spawn_enemies)
+            .expect("spawn_enemies should run");
+
+        let mut q = app.world_mut().query_filtered::<(), With<Enemy>>();
+        assert_eq!(q.iter(app.world()).count(), 5);
+
+        // Already at min_count: nothing more until the interval elapses.
+        app.world_mut()
+            .run_system_once(spawn_enemies)
+            .expect("spawn_enemies should run");
+        assert_eq!(q.iter(app.world()).count(), 5);
+    }
 }
@@ -0,0 +1,233 @@
+//! Status-effect ticking and tinting.
+//!
+//! [`StatusEffects`] are merged onto enemies by
+//! [`apply_damage_to_enemies`](crate::systems::damage::apply_damage_to_enemies).
+//! Two systems keep them running:
+//!
+//! - [`tick_status_effects`] counts durations down, drops expired effects and,
+//!   every `status_tick_interval` seconds (from `enemy.ron`), emits a
+//!   [`DamageEnemyEvent`] per burn / poison.  Tick damage goes through the
+//!   regular damage pipeline, so resistances and death handling apply; its
+//!   weapon type is the effect's source, so a burn from the Fire Wand counts
+//!   as fire damage.
+//! - [`tint_status_effects`] shows the most visible effect as a sprite tint
+//!   ([`StatusEffects::tint`]) and restores the enemy's own colour, saved in
+//!   [`StatusTint`], once every effect has expired.
+//!
+//! Movement systems read [`StatusEffects::speed_multiplier`] directly.
+
+use bevy::prelude::*;
+
+use crate::{
+    components::{StatusEffects, StatusTint},
+    config::EnemyParams,
+    events::DamageEnemyEvent,
+};
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Advances every enemy's [`StatusEffects`] by one frame.
+///
+/// Must run before `apply_damage_to_enemies` so tick damage lands on the
+/// same frame.  Enemies with no active effect are skipped without touching
+/// the component, keeping [`tint_status_effects`]'s change filter quiet.
+pub fn tick_status_effects(
+    time: Res<Time>,
+    mut enemy_q: Query<(Entity, &mut StatusEffects)>,
+    mut damage_events: MessageWriter<DamageEnemyEvent>,
+    enemy_cfg: EnemyParams,
+) {
+    let dt = time.delta_secs();
+    let interval = enemy_cfg.status_tick_interval();
+
+    for (entity, mut status) in enemy_q.iter_mut() {
+        if status.effects.is_empty() {
+            continue;
+        }
+
+        status.tick_timer -= dt;
+        if status.tick_timer <= 0.0 {
+            status.tick_timer += interval;
+            for effect in status.effects.iter().filter(|e| e.is_damage_over_time()) {
+                damage_events.write(DamageEnemyEvent {
+                    entity,
+                    damage: effect.damage_per_second() * interval,
+                    weapon_type: effect.source,
+                    knockback: Vec2::ZERO,
+                    status: None,
+                });
+            }
+        }
+
+        for effect in status.effects.iter_mut() {
+            effect.remaining -= dt;
+        }
+        status.effects.retain(|e| e.remaining > 0.0);
+    }
+}
+
+/// Tints enemies whose [`StatusEffects`] changed this frame.
+///
+/// The first tint saves the sprite's own colour in a [`StatusTint`]; the
+/// original alpha is kept so translucent enemies (ghosts) stay translucent.
+/// When the last effect expires the saved colour is restored and the
+/// [`StatusTint`] removed.
+pub fn tint_status_effects(
+    mut enemy_q: Query<
+        (Entity, &StatusEffects, &mut Sprite, Option<&StatusTint>),
+        Changed<StatusEffects>,
+    >,
+    mut commands: Commands,
+) {
+    for (entity, status, mut sprite, saved) in enemy_q.iter_mut() {
+        match (status.tint(), saved) {
+            (Some(tint), saved) => {
+                let base_color = match saved {
+                    Some(saved) => saved.base_color,
+                    None => {
+                        let base_color = sprite.color;
+                        commands
+                            .entity(entity)
+                            .try_insert(StatusTint { base_color });
+                        base_color
+                    }
+                };
+                sprite.color = tint.with_alpha(base_color.alpha());
+            }
+            (None, Some(saved)) => {
+                sprite.color = saved.base_color;
+                commands.entity(entity).try_remove::<StatusTint>();
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::system::RunSystemOnce as _;
+
+    use super::*;
+    use crate::{
+        components::{Enemy, StatusEffect},
+        types::{EnemyType, WeaponType},
+    };
+
+    fn build_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<DamageEnemyEvent>();
+        app
+    }
+
+    fn spawn_with(app: &mut App, effect: StatusEffect) -> Entity {
+        let mut status = StatusEffects {
+            tick_timer: 0.5,
+            ..default()
+        };
+        status.apply(effect);
+        app.world_mut()
+            .spawn((
+                Enemy::from_type(EnemyType::Zombie, 1.0),
+                status,
+                Sprite {
+                    color: Color::srgba(0.2, 0.3, 0.4, 0.5),
+                    ..default()
+                },
+            ))
+            .id()
+    }
+
+    fn tick(app: &mut App, secs: f32) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(secs));
+        app.world_mut()
+            .run_system_once(tick_status_effects)
+            .unwrap();
+    }
+
+    fn damage_events(app: &App) -> Vec<DamageEnemyEvent> {
+        let messages = app.world().resource::<Messages<DamageEnemyEvent>>();
+        let mut cursor = messages.get_cursor();
+        cursor.read(messages).cloned().collect()
+    }
+
+    /// A burn emits `dps × interval` fire damage each interval.
+    #[test]
+    fn burn_ticks_damage_from_its_source() {
+        let mut app = build_app();
+        let enemy = spawn_with(
+            &mut app,
+            StatusEffect::burn(10.0, 3.0, WeaponType::FireWand),
+        );
+
+        tick(&mut app, 0.3);
+        assert!(
+            damage_events(&app).is_empty(),
+            "no tick before the interval"
+        );
+        tick(&mut app, 0.3);
+
+        let events = damage_events(&app);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].entity, enemy);
+        assert!((events[0].damage - 5.0).abs() < 1e-4);
+        assert_eq!(events[0].weapon_type, WeaponType::FireWand);
+    }
+
+    /// Crowd control never deals damage.
+    #[test]
+    fn slow_deals_no_damage() {
+        let mut app = build_app();
+        spawn_with(&mut app, StatusEffect::slow(0.5, 3.0, WeaponType::Garlic));
+
+        tick(&mut app, 0.6);
+
+        assert!(damage_events(&app).is_empty());
+    }
+
+    /// Expired effects are dropped; the component itself stays.
+    #[test]
+    fn expired_effects_are_removed() {
+        let mut app = build_app();
+        let enemy = spawn_with(&mut app, StatusEffect::freeze(0.2, WeaponType::HolyWand));
+
+        tick(&mut app, 0.3);
+
+        let status = app.world().get::<StatusEffects>(enemy).unwrap();
+        assert!(status.effects.is_empty());
+    }
+
+    /// The tint is shown while an effect is active and the original colour
+    /// comes back once it expires.
+    #[test]
+    fn tint_is_applied_and_restored() {
+        let mut app = build_app();
+        let enemy = spawn_with(&mut app, StatusEffect::freeze(0.2, WeaponType::HolyWand));
+        let base = Color::srgba(0.2, 0.3, 0.4, 0.5);
+
+        app.world_mut()
+            .run_system_once(tint_status_effects)
+            .unwrap();
+        let tinted = app.world().get::<Sprite>(enemy).unwrap().color;
+        assert_ne!(tinted, base);
+        assert_eq!(tinted.alpha(), 0.5, "alpha must be preserved");
+
+        tick(&mut app, 0.3);
+        app.world_mut()
+            .run_system_once(tint_status_effects)
+            .unwrap();
+
+        assert_eq!(app.world().get::<Sprite>(enemy).unwrap().color, base);
+        assert!(app.world().get::<StatusTint>(enemy).is_none());
+    }
+}
@@ -45,6 +45,9 @@ mod tests {
             position: Vec2::ZERO,
             enemy_type: EnemyType::Bat,
             xp_value: 3,
+            gold_chance: 0.0,
+            elite: None,
+            mini_boss: None,
         });
     }
 
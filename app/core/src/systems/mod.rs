@@ -10,8 +10,11 @@ pub mod game_over;
 pub mod game_timer;
 pub mod kill_count;
 pub mod persistence;
+pub mod pickups;
 pub mod player;
 pub mod projectiles;
+pub mod replay;
+pub mod rng;
 pub mod spatial;
 pub mod victory;
 pub mod weapons;
@@ -0,0 +1,199 @@
+//! Floor pickup drops.
+//!
+//! [`drop_floor_pickups`] rolls `enemy_drop_chance × luck` for every
+//! [`EnemyDiedEvent`]; on success one pickup is chosen from the weighted
+//! `drop_table` in `pickup.ron` by [`roll_pickup`] and spawned with
+//! [`spawn_floor_pickup`].  Broken light sources reuse both helpers.
+
+use bevy::prelude::*;
+use rand::RngExt;
+
+use crate::{
+    components::{FloorPickup, GameSessionEntity, Player, PlayerStats},
+    config::PickupParams,
+    events::EnemyDiedEvent,
+    resources::GameRng,
+    types::PickupType,
+};
+
+/// Offset from the death position so a pickup does not hide under the XP gem
+/// (and gold coin) dropped by the same enemy.
+const PICKUP_DROP_OFFSET: Vec2 = Vec2::new(-10.0, 0.0);
+
+// ---------------------------------------------------------------------------
+// System
+// ---------------------------------------------------------------------------
+
+/// Rolls a floor-pickup drop for every [`EnemyDiedEvent`] received this frame.
+///
+/// - Drop chance is `enemy_drop_chance × luck`, clamped to `0.0..=1.0`
+///   (luck defaults to `1.0` when no player exists).
+/// - The pickup is chosen from the weighted `drop_table`; nothing drops when
+///   every weight is zero.
+///
+/// Must run after
+/// [`apply_damage_to_enemies`](crate::systems::damage::apply_damage_to_enemies)
+/// so this frame's deaths are already written.
+pub fn drop_floor_pickups(
+    mut commands: Commands,
+    mut died_events: MessageReader<EnemyDiedEvent>,
+    player_q: Query<&PlayerStats, With<Player>>,
+    pickup_cfg: PickupParams,
+    mut game_rng: ResMut<GameRng>,
+) {
+    let luck = player_q.single().map(|s| s.luck).unwrap_or(1.0);
+    let chance = (pickup_cfg.enemy_drop_chance() * luck).clamp(0.0, 1.0);
+    let table = pickup_cfg.drop_table();
+    let radius = pickup_cfg.pickup_radius();
+    let rng = game_rng.rng();
+
+    for event in died_events.read() {
+        if chance <= 0.0 || rng.random::<f32>() >= chance {
+            continue;
+        }
+        if let Some(kind) = roll_pickup(rng, &table) {
+            spawn_floor_pickup(
+                &mut commands,
+                kind,
+                event.position + PICKUP_DROP_OFFSET,
+                radius,
+            );
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Selects a [`PickupType`] from a weighted `(pickup, weight)` table.
+///
+/// Entries with weight ≤ 0 never win.  Returns `None` when the total weight
+/// is zero so callers can skip the drop.
+pub(crate) fn roll_pickup(
+    rng: &mut impl RngExt,
+    table: &[(PickupType, f32)],
+) -> Option<PickupType> {
+    let total: f32 = table.iter().map(|&(_, w)| w.max(0.0)).sum();
+    if total <= 0.0 {
+        return None;
+    }
+    let roll = rng.random_range(0.0..total);
+    let mut cumulative = 0.0_f32;
+    for &(kind, weight) in table {
+        cumulative += weight.max(0.0);
+        if roll < cumulative {
+            return Some(kind);
+        }
+    }
+    table
+        .iter()
+        .rev()
+        .find(|&&(_, w)| w > 0.0)
+        .map(|&(kind, _)| kind) // floating-point rounding fallback
+}
+
+/// Spawns a [`FloorPickup`] of `kind` at `position`.
+///
+/// Tagged with [`GameSessionEntity`] so pickups are cleaned up when the run
+/// ends.
+pub(crate) fn spawn_floor_pickup(
+    commands: &mut Commands,
+    kind: PickupType,
+    position: Vec2,
+    radius: f32,
+) {
+    commands.spawn((
+        GameSessionEntity,
+        FloorPickup { kind },
+        // Placeholder square sprite, one colour per pickup type.
+        Sprite {
+            color: kind.color(),
+            custom_size: Some(Vec2::splat(radius * 2.0)),
+            ..default()
+        },
+        // Same layer as XP gems: above ground, below enemies.
+        Transform::from_xyz(position.x, position.y, 0.5),
+    ));
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce as _;
+    use rand::{SeedableRng, rngs::StdRng};
+
+    use super::*;
+    use crate::types::EnemyType;
+
+    fn build_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<EnemyDiedEvent>();
+        app.insert_resource(GameRng::from_seed(0));
+        app
+    }
+
+    fn send_died(app: &mut App) {
+        app.world_mut().write_message(EnemyDiedEvent {
+            entity: Entity::PLACEHOLDER,
+            position: Vec2::new(40.0, 40.0),
+            enemy_type: EnemyType::Skeleton,
+            xp_value: 5,
+            gold_chance: 0.0,
+            elite: None,
+            mini_boss: None,
+        });
+    }
+
+    fn pickup_count(app: &mut App) -> usize {
+        let mut q = app.world_mut().query::<&FloorPickup>();
+        q.iter(app.world()).count()
+    }
+
+    #[test]
+    fn roll_pickup_respects_weights() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let table = [(PickupType::Rosary, 0.0), (PickupType::Vacuum, 1.0)];
+        for _ in 0..100 {
+            assert_eq!(roll_pickup(&mut rng, &table), Some(PickupType::Vacuum));
+        }
+        assert_eq!(roll_pickup(&mut rng, &[(PickupType::Rosary, 0.0)]), None);
+        assert_eq!(roll_pickup(&mut rng, &[]), None);
+    }
+
+    #[test]
+    fn high_luck_guarantees_a_drop() {
+        let mut app = build_app();
+        app.world_mut().spawn((
+            Player,
+            PlayerStats {
+                luck: 1000.0,
+                ..default()
+            },
+        ));
+        send_died(&mut app);
+        app.world_mut().run_system_once(drop_floor_pickups).unwrap();
+        assert_eq!(pickup_count(&mut app), 1);
+    }
+
+    #[test]
+    fn zero_luck_never_drops() {
+        let mut app = build_app();
+        app.world_mut().spawn((
+            Player,
+            PlayerStats {
+                luck: 0.0,
+                ..default()
+            },
+        ));
+        for _ in 0..50 {
+            send_died(&mut app);
+        }
+        app.world_mut().run_system_once(drop_floor_pickups).unwrap();
+        assert_eq!(pickup_count(&mut app), 0);
+    }
+}
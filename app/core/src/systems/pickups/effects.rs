@@ -0,0 +1,372 @@
+//! Floor pickup collection and effects.
+//!
+//! [`collect_floor_pickups`] despawns every [`FloorPickup`] overlapping the
+//! player and writes a [`PickupCollectedEvent`].  One system per
+//! [`PickupType`] reads the event and applies its effect:
+//!
+//! | Pickup         | System                  | Effect |
+//! |----------------|-------------------------|--------|
+//! | `FloorChicken` | [`apply_floor_chicken`] | Heals `chicken_heal` HP |
+//! | `Vacuum`       | [`apply_vacuum`]        | Attracts every XP gem on the map |
+//! | `Rosary`       | [`apply_rosary`]        | Kills every non-boss enemy on screen |
+//! | `Orologion`    | [`apply_orologion`]     | Freezes enemy AI for `orologion_duration` s |
+//!
+//! The orologion freeze is stored in [`EnemyFreeze`]; enemy movement and
+//! attack systems are gated on
+//! [`enemies_active`](crate::resources::enemies_active).
+
+use bevy::prelude::*;
+
+use crate::{
+    components::{
+        AttractedToPlayer, CircleCollider, Elite, Enemy, ExperienceGem, FloorPickup, Player,
+        PlayerStats,
+    },
+    config::{GameParams, PickupParams},
+    events::{EnemyDiedEvent, PickupCollectedEvent},
+    resources::EnemyFreeze,
+    types::PickupType,
+};
+
+// ---------------------------------------------------------------------------
+// Collection
+// ---------------------------------------------------------------------------
+
+/// Collects every [`FloorPickup`] overlapping the player collider.
+///
+/// Proximity check: `player.radius + pickup_radius`.  Floor pickups are rare,
+/// so an O(n) scan is used (same as treasure chests).
+pub fn collect_floor_pickups(
+    mut commands: Commands,
+    mut collected: MessageWriter<PickupCollectedEvent>,
+    player_q: Query<(&Transform, &CircleCollider), With<Player>>,
+    pickup_q: Query<(Entity, &Transform, &FloorPickup)>,
+    pickup_cfg: PickupParams,
+) {
+    let Ok((player_tf, player_col)) = player_q.single() else {
+        return;
+    };
+    let player_pos = player_tf.translation.truncate();
+    let threshold = player_col.radius + pickup_cfg.pickup_radius();
+
+    for (entity, tf, pickup) in &pickup_q {
+        let position = tf.translation.truncate();
+        if position.distance(player_pos) > threshold {
+            continue;
+        }
+        commands.entity(entity).despawn();
+        collected.write(PickupCollectedEvent {
+            kind: pickup.kind,
+            position,
+        });
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Effects
+// ---------------------------------------------------------------------------
+
+/// Floor chicken: restores `chicken_heal` HP, clamped to max HP.
+pub fn apply_floor_chicken(
+    mut collected: MessageReader<PickupCollectedEvent>,
+    mut player_q: Query<&mut PlayerStats, With<Player>>,
+    pickup_cfg: PickupParams,
+) {
+    let count = collected
+        .read()
+        .filter(|e| e.kind == PickupType::FloorChicken)
+        .count();
+    if count == 0 {
+        return;
+    }
+    let Ok(mut stats) = player_q.single_mut() else {
+        return;
+    };
+    let heal = pickup_cfg.chicken_heal() * count as f32;
+    stats.current_hp = (stats.current_hp + heal).min(stats.max_hp);
+}
+
+/// Vacuum: starts attraction on every XP gem on the map, regardless of
+/// distance, at `vacuum_speed_multiplier ×` the player's gem attraction speed.
+///
+/// Gems that are already attracted keep their current speed.
+pub fn apply_vacuum(
+    mut commands: Commands,
+    mut collected: MessageReader<PickupCollectedEvent>,
+    player_q: Query<&PlayerStats, With<Player>>,
+    gem_q: Query<Entity, (With<ExperienceGem>, Without<AttractedToPlayer>)>,
+    pickup_cfg: PickupParams,
+) {
+    if !collected_kind(&mut collected, PickupType::Vacuum) {
+        return;
+    }
+    let Ok(stats) = player_q.single() else {
+        return;
+    };
+    let speed = stats.gem_attraction_speed * pickup_cfg.vacuum_speed_multiplier();
+    for gem in &gem_q {
+        commands.entity(gem).insert(AttractedToPlayer { speed });
+    }
+}
+
+/// Rosary: kills every non-boss enemy inside the viewport.
+///
+/// Enemies are despawned directly and an [`EnemyDiedEvent`] is written for
+/// each so XP gems, gold, and the kill counter react as for a weapon kill.
+/// Bosses ([`EnemyType::is_boss`](crate::types::EnemyType::is_boss)) are
+/// unaffected.
+pub fn apply_rosary(
+    mut commands: Commands,
+    mut collected: MessageReader<PickupCollectedEvent>,
+    mut died_events: MessageWriter<EnemyDiedEvent>,
+    camera_q: Query<&Transform, With<Camera2d>>,
+    enemy_q: Query<(Entity, &Enemy, &Transform, Option<&Elite>)>,
+    game_cfg: GameParams,
+) {
+    if !collected_kind(&mut collected, PickupType::Rosary) {
+        return;
+    }
+    let cam_pos = camera_q
+        .single()
+        .map(|t| t.translation.truncate())
+        .unwrap_or(Vec2::ZERO);
+    let half = game_cfg.window_size() / 2.0;
+
+    for (entity, enemy, tf, elite) in &enemy_q {
+        if enemy.enemy_type.is_boss() || enemy.is_dead() {
+            continue;
+        }
+        let position = tf.translation.truncate();
+        let offset = (position - cam_pos).abs();
+        if offset.x > half.x || offset.y > half.y {
+            continue;
+        }
+        commands.entity(entity).despawn();
+        died_events.write(EnemyDiedEvent {
+            entity,
+            position,
+            enemy_type: enemy.enemy_type,
+            xp_value: enemy.xp_value,
+            gold_chance: enemy.gold_chance,
+            elite: elite.map(|e| e.modifier),
+            mini_boss: None,
+        });
+    }
+}
+
+/// Orologion: freezes enemy AI for `orologion_duration` seconds.
+///
+/// A second orologion refreshes the freeze to the full duration rather than
+/// stacking.
+pub fn apply_orologion(
+    mut collected: MessageReader<PickupCollectedEvent>,
+    mut freeze: ResMut<EnemyFreeze>,
+    pickup_cfg: PickupParams,
+) {
+    if collected_kind(&mut collected, PickupType::Orologion) {
+        freeze.remaining = freeze.remaining.max(pickup_cfg.orologion_duration());
+    }
+}
+
+/// Whether any pickup collected this frame is `kind`.
+///
+/// Reads every message rather than stopping at the first match, so none is
+/// left in the reader to fire the effect again next frame.
+fn collected_kind(collected: &mut MessageReader<PickupCollectedEvent>, kind: PickupType) -> bool {
+    let mut found = false;
+    for event in collected.read() {
+        found |= event.kind == kind;
+    }
+    found
+}
+
+/// Counts down [`EnemyFreeze::remaining`].
+pub fn tick_enemy_freeze(mut freeze: ResMut<EnemyFreeze>, time: Res<Time>) {
+    if freeze.is_active() {
+        freeze.remaining = (freeze.remaining - time.delta_secs()).max(0.0);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce as _;
+
+    use super::*;
+    use crate::types::{EnemyType, GemTier};
+
+    fn build_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<PickupCollectedEvent>();
+        app.add_message::<EnemyDiedEvent>();
+        app.insert_resource(EnemyFreeze::default());
+        app
+    }
+
+    fn collect(app: &mut App, kind: PickupType) {
+        app.world_mut().write_message(PickupCollectedEvent {
+            kind,
+            position: Vec2::ZERO,
+        });
+    }
+
+    fn spawn_player(app: &mut App, current_hp: f32) -> Entity {
+        app.world_mut()
+            .spawn((
+                Player,
+                PlayerStats {
+                    current_hp,
+                    max_hp: 100.0,
+                    ..default()
+                },
+                CircleCollider { radius: 12.0 },
+                Transform::default(),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn touching_a_pickup_collects_it() {
+        let mut app = build_app();
+        spawn_player(&mut app, 100.0);
+        let near = app
+            .world_mut()
+            .spawn((
+                FloorPickup {
+                    kind: PickupType::Rosary,
+                },
+                Transform::from_xyz(15.0, 0.0, 0.5),
+            ))
+            .id();
+        let far = app
+            .world_mut()
+            .spawn((
+                FloorPickup {
+                    kind: PickupType::Vacuum,
+                },
+                Transform::from_xyz(200.0, 0.0, 0.5),
+            ))
+            .id();
+
+        app.world_mut()
+            .run_system_once(collect_floor_pickups)
+            .unwrap();
+
+        assert!(app.world().get_entity(near).is_err());
+        assert!(app.world().get_entity(far).is_ok());
+        let messages = app.world().resource::<Messages<PickupCollectedEvent>>();
+        let kinds: Vec<_> = messages
+            .get_cursor()
+            .read(messages)
+            .map(|e| e.kind)
+            .collect();
+        assert_eq!(kinds, vec![PickupType::Rosary]);
+    }
+
+    #[test]
+    fn chicken_heals_up_to_max_hp() {
+        let mut app = build_app();
+        let player = spawn_player(&mut app, 50.0);
+        collect(&mut app, PickupType::FloorChicken);
+        app.world_mut()
+            .run_system_once(apply_floor_chicken)
+            .unwrap();
+        assert_eq!(
+            app.world().get::<PlayerStats>(player).unwrap().current_hp,
+            80.0
+        );
+
+        collect(&mut app, PickupType::FloorChicken);
+        collect(&mut app, PickupType::FloorChicken);
+        app.world_mut()
+            .run_system_once(apply_floor_chicken)
+            .unwrap();
+        assert_eq!(
+            app.world().get::<PlayerStats>(player).unwrap().current_hp,
+            100.0
+        );
+    }
+
+    #[test]
+    fn vacuum_attracts_every_gem_on_the_map() {
+        let mut app = build_app();
+        spawn_player(&mut app, 100.0);
+        let gem = app
+            .world_mut()
+            .spawn((
+                ExperienceGem {
+                    value: 1,
+                    tier: GemTier::Small,
+                },
+                Transform::from_xyz(5000.0, 0.0, 0.5),
+            ))
+            .id();
+        collect(&mut app, PickupType::Vacuum);
+
+        app.world_mut().run_system_once(apply_vacuum).unwrap();
+
+        assert!(app.world().get::<AttractedToPlayer>(gem).is_some());
+    }
+
+    #[test]
+    fn rosary_kills_on_screen_non_bosses_only() {
+        let mut app = build_app();
+        app.world_mut().spawn((Camera2d, Transform::default()));
+        let mut spawn = |enemy_type, x| {
+            app.world_mut()
+                .spawn((
+                    Enemy::from_type(enemy_type, 1.0),
+                    Transform::from_xyz(x, 0.0, 1.0),
+                ))
+                .id()
+        };
+        let bat = spawn(EnemyType::Bat, 100.0);
+        let boss = spawn(EnemyType::BossDeath, 100.0);
+        let off_screen = spawn(EnemyType::Bat, 5000.0);
+        collect(&mut app, PickupType::Rosary);
+
+        app.world_mut().run_system_once(apply_rosary).unwrap();
+
+        assert!(app.world().get_entity(bat).is_err());
+        assert!(app.world().get_entity(boss).is_ok());
+        assert!(app.world().get_entity(off_screen).is_ok());
+        let messages = app.world().resource::<Messages<EnemyDiedEvent>>();
+        assert_eq!(messages.get_cursor().read(messages).count(), 1);
+    }
+
+    #[test]
+    fn orologion_freezes_without_stacking() {
+        let mut app = build_app();
+        collect(&mut app, PickupType::Orologion);
+        app.world_mut().run_system_once(apply_orologion).unwrap();
+        let first = app.world().resource::<EnemyFreeze>().remaining;
+        assert!(first > 0.0);
+
+        collect(&mut app, PickupType::Orologion);
+        app.world_mut().run_system_once(apply_orologion).unwrap();
+        assert_eq!(app.world().resource::<EnemyFreeze>().remaining, first);
+    }
+
+    /// Every message is consumed in the frame it arrives, so a second
+    /// pickup in the same frame does not fire again on the next frame.
+    #[test]
+    fn effects_consume_all_messages_in_one_frame() {
+        let mut app = build_app();
+        let orologion = app.world_mut().register_system(apply_orologion);
+        collect(&mut app, PickupType::Orologion);
+        collect(&mut app, PickupType::Orologion);
+        app.world_mut().run_system(orologion).unwrap();
+        assert!(app.world().resource::<EnemyFreeze>().is_active());
+
+        app.world_mut().resource_mut::<EnemyFreeze>().remaining = 0.0;
+        app.world_mut().run_system(orologion).unwrap();
+        assert!(
+            !app.world().resource::<EnemyFreeze>().is_active(),
+            "no leftover message may refresh the freeze"
+        );
+    }
+}
@@ -0,0 +1,276 @@
+//! Breakable light-source props.
+//!
+//! [`spawn_light_sources`] places a [`LightSource`] just outside the viewport
+//! every `light_source_interval` seconds, up to `light_source_max` at once.
+//! [`break_light_sources`] shatters any the player or a weapon hits and drops
+//! one pickup rolled from the `pickup.ron` drop table.
+
+use bevy::prelude::*;
+
+use super::drop::{roll_pickup, spawn_floor_pickup};
+use crate::{
+    components::{AuraWeapon, CircleCollider, GameSessionEntity, LightSource, Player, Projectile},
+    config::{GameParams, PickupParams},
+    resources::{GameRng, LightSourceSpawner},
+    systems::{enemies::spawn::random_off_screen_position, weapons::santa_water::SantaWaterZone},
+};
+
+/// Placeholder colour for light-source props (warm brazier orange).
+const LIGHT_SOURCE_COLOR: Color = Color::srgb(1.0, 0.6, 0.2);
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Ticks [`LightSourceSpawner`] and spawns a light source at the viewport
+/// edge once `light_source_interval` elapses.
+///
+/// Light sources farther than one viewport diagonal from the camera are
+/// removed first, so props the player has left behind do not hold the
+/// `light_source_max` cap forever.
+#[allow(clippy::too_many_arguments)]
+pub fn spawn_light_sources(
+    mut commands: Commands,
+    mut spawner: ResMut<LightSourceSpawner>,
+    time: Res<Time>,
+    camera_q: Query<&Transform, With<Camera2d>>,
+    light_q: Query<(Entity, &Transform), With<LightSource>>,
+    game_cfg: GameParams,
+    pickup_cfg: PickupParams,
+    mut game_rng: ResMut<GameRng>,
+) {
+    spawner.spawn_timer += time.delta_secs();
+    if spawner.spawn_timer < pickup_cfg.light_source_interval() {
+        return;
+    }
+    spawner.spawn_timer = 0.0;
+
+    let cam_pos = camera_q
+        .single()
+        .map(|t| t.translation.truncate())
+        .unwrap_or(Vec2::ZERO);
+    let viewport = game_cfg.window_size();
+    let keep_dist_sq = viewport.length_squared();
+
+    let mut remaining = 0;
+    for (entity, tf) in &light_q {
+        if tf.translation.truncate().distance_squared(cam_pos) > keep_dist_sq {
+            commands.entity(entity).despawn();
+        } else {
+            remaining += 1;
+        }
+    }
+    if remaining >= pickup_cfg.light_source_max() {
+        return;
+    }
+
+    let radius = pickup_cfg.light_source_radius();
+    let half = viewport / 2.0 + Vec2::splat(radius);
+    let pos = random_off_screen_position(game_rng.rng(), cam_pos, half.x, half.y);
+    commands.spawn((
+        GameSessionEntity,
+        LightSource,
+        // Tall placeholder rectangle, like a brazier.
+        Sprite {
+            color: LIGHT_SOURCE_COLOR,
+            custom_size: Some(Vec2::new(radius * 1.5, radius * 2.5)),
+            ..default()
+        },
+        // Below pickups so a dropped item is drawn on top of the prop.
+        Transform::from_xyz(pos.x, pos.y, 0.4),
+    ));
+}
+
+/// Breaks every [`LightSource`] the player or a weapon overlaps and drops a
+/// pickup in its place.
+///
+/// Weapon hits are projectiles ([`Projectile`]), Santa Water pools and the
+/// Garlic aura ([`AuraWeapon`]).  Weapons that only strike enemies directly
+/// (Whip, Bible, Lightning Ring) leave props alone.
+///
+/// Light sources are sparse (a handful at most), so an O(n) scan is used —
+/// the same approach as treasure chests.
+#[allow(clippy::too_many_arguments)]
+pub fn break_light_sources(
+    mut commands: Commands,
+    player_q: Query<(&Transform, &CircleCollider), With<Player>>,
+    projectile_q: Query<(&Transform, &CircleCollider), With<Projectile>>,
+    zone_q: Query<(&Transform, &SantaWaterZone)>,
+    aura_q: Query<(&GlobalTransform, &AuraWeapon)>,
+    light_q: Query<(Entity, &Transform), With<LightSource>>,
+    pickup_cfg: PickupParams,
+    mut game_rng: ResMut<GameRng>,
+) {
+    if light_q.is_empty() {
+        return;
+    }
+    // Every circle that breaks a prop it touches, as (centre, radius).
+    let hitters: Vec<(Vec2, f32)> = player_q
+        .iter()
+        .chain(projectile_q.iter())
+        .map(|(tf, col)| (tf.translation.truncate(), col.radius))
+        .chain(
+            zone_q
+                .iter()
+                .map(|(tf, zone)| (tf.translation.truncate(), zone.radius)),
+        )
+        .chain(
+            aura_q
+                .iter()
+                .map(|(gtf, aura)| (gtf.translation().truncate(), aura.radius)),
+        )
+        .collect();
+    let light_radius = pickup_cfg.light_source_radius();
+    let table = pickup_cfg.drop_table();
+    let pickup_radius = pickup_cfg.pickup_radius();
+
+    for (entity, tf) in &light_q {
+        let pos = tf.translation.truncate();
+        let hit = hitters
+            .iter()
+            .any(|&(center, radius)| pos.distance(center) <= radius + light_radius);
+        if !hit {
+            continue;
+        }
+        commands.entity(entity).despawn();
+        if let Some(kind) = roll_pickup(game_rng.rng(), &table) {
+            spawn_floor_pickup(&mut commands, kind, pos, pickup_radius);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce as _;
+
+    use super::*;
+    use crate::components::FloorPickup;
+
+    fn build_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(GameRng::from_seed(7));
+        app.insert_resource(LightSourceSpawner::default());
+        app
+    }
+
+    fn count<C: Component>(app: &mut App) -> usize {
+        let mut q = app.world_mut().query_filtered::<(), With<C>>();
+        q.iter(app.world()).count()
+    }
+
+    #[test]
+    fn touching_a_light_source_breaks_it_and_drops_a_pickup() {
+        let mut app = build_app();
+        app.world_mut().spawn((
+            Player,
+            CircleCollider { radius: 12.0 },
+            Transform::default(),
+        ));
+        app.world_mut()
+            .spawn((LightSource, Transform::from_xyz(5.0, 0.0, 0.4)));
+        app.world_mut()
+            .spawn((LightSource, Transform::from_xyz(500.0, 0.0, 0.4)));
+
+        app.world_mut()
+            .run_system_once(break_light_sources)
+            .unwrap();
+
+        assert_eq!(count::<LightSource>(&mut app), 1, "far prop must survive");
+        assert_eq!(count::<FloorPickup>(&mut app), 1);
+    }
+
+    /// Projectiles and damage zones break props out of the player's reach.
+    #[test]
+    fn weapon_hits_break_light_sources() {
+        use std::collections::HashMap;
+
+        use crate::types::WeaponType;
+
+        let mut app = build_app();
+        app.world_mut().spawn((
+            Player,
+            CircleCollider { radius: 12.0 },
+            Transform::default(),
+        ));
+        app.world_mut().spawn((
+            Projectile {
+                damage: 10.0,
+                piercing: 0,
+                hit_enemies: Vec::new(),
+                lifetime: 1.0,
+                weapon_type: WeaponType::Knife,
+                knockback: 0.0,
+            },
+            CircleCollider { radius: 6.0 },
+            Transform::from_xyz(300.0, 0.0, 5.0),
+        ));
+        app.world_mut().spawn((
+            SantaWaterZone {
+                damage: 10.0,
+                radius: 40.0,
+                remaining: 1.0,
+                weapon_type: WeaponType::SantaWater,
+                hit_cooldown: HashMap::new(),
+            },
+            Transform::from_xyz(0.0, 300.0, 1.0),
+        ));
+        app.world_mut()
+            .spawn((LightSource, Transform::from_xyz(305.0, 0.0, 0.4)));
+        app.world_mut()
+            .spawn((LightSource, Transform::from_xyz(0.0, 330.0, 0.4)));
+        app.world_mut()
+            .spawn((LightSource, Transform::from_xyz(-300.0, 0.0, 0.4)));
+
+        app.world_mut()
+            .run_system_once(break_light_sources)
+            .unwrap();
+
+        assert_eq!(
+            count::<LightSource>(&mut app),
+            1,
+            "only the untouched prop survives"
+        );
+        assert_eq!(count::<FloorPickup>(&mut app), 2);
+    }
+
+    #[test]
+    fn spawner_respects_light_source_cap() {
+        let mut app = build_app();
+        for i in 0..10 {
+            app.world_mut()
+                .spawn((LightSource, Transform::from_xyz(i as f32, 0.0, 0.4)));
+        }
+        app.world_mut()
+            .resource_mut::<LightSourceSpawner>()
+            .spawn_timer = 1000.0;
+
+        app.world_mut()
+            .run_system_once(spawn_light_sources)
+            .unwrap();
+
+        assert_eq!(count::<LightSource>(&mut app), 10, "cap reached: no spawn");
+        assert_eq!(
+            app.world().resource::<LightSourceSpawner>().spawn_timer,
+            0.0
+        );
+    }
+
+    #[test]
+    fn spawner_adds_light_source_after_interval() {
+        let mut app = build_app();
+        app.world_mut()
+            .resource_mut::<LightSourceSpawner>()
+            .spawn_timer = 1000.0;
+
+        app.world_mut()
+            .run_system_once(spawn_light_sources)
+            .unwrap();
+
+        assert_eq!(count::<LightSource>(&mut app), 1);
+    }
+}
@@ -0,0 +1,59 @@
+//! Floor pickups: healing food, vacuum, rosary, and orologion.
+//!
+//! | Module           | Contents |
+//! |------------------|----------|
+//! | [`drop`]         | Enemy-death drops, weighted drop-table roll, pickup spawning |
+//! | [`light_source`] | Breakable light-source props that drop a pickup when broken |
+//! | [`effects`]      | Player contact → [`PickupCollectedEvent`] → per-pickup effect systems |
+//!
+//! Drop rates and effect strengths come from `pickup.ron`
+//! ([`PickupParams`](crate::config::PickupParams)).
+//!
+//! [`PickupCollectedEvent`]: crate::events::PickupCollectedEvent
+
+pub mod drop;
+pub mod effects;
+pub mod light_source;
+
+use bevy::prelude::*;
+
+use crate::states::AppState;
+
+pub struct PickupPlugin;
+
+impl Plugin for PickupPlugin {
+    fn build(&self, app: &mut App) {
+        use crate::systems::damage::apply_damage_to_enemies;
+        use crate::systems::pickups::drop::drop_floor_pickups;
+        use crate::systems::pickups::effects::{
+            apply_floor_chicken, apply_orologion, apply_rosary, apply_vacuum,
+            collect_floor_pickups, tick_enemy_freeze,
+        };
+        use crate::systems::pickups::light_source::{break_light_sources, spawn_light_sources};
+        use crate::systems::player::player_movement;
+        use crate::systems::rng::GameRngSystems;
+        app.add_systems(
+            Update,
+            (
+                drop_floor_pickups
+                    .after(apply_damage_to_enemies)
+                    .in_set(GameRngSystems::FloorPickupDrops),
+                spawn_light_sources.in_set(GameRngSystems::LightSourceSpawns),
+                break_light_sources
+                    .after(player_movement)
+                    .in_set(GameRngSystems::LightSourceBreaks),
+                collect_floor_pickups.after(player_movement),
+                apply_floor_chicken.after(collect_floor_pickups),
+                apply_vacuum.after(collect_floor_pickups),
+                // After damage so enemies killed by weapons this frame are
+                // already despawned and not reported dead twice.
+                apply_rosary
+                    .after(collect_floor_pickups)
+                    .after(apply_damage_to_enemies),
+                apply_orologion.after(collect_floor_pickups),
+                tick_enemy_freeze.before(apply_orologion),
+            )
+                .run_if(in_state(AppState::Playing)),
+        );
+    }
+}
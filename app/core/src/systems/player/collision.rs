@@ -4,7 +4,8 @@
 //!
 //! - [`enemy_player_collision`] — each frame, checks whether any enemy
 //!   overlaps the player.  If the player is not currently invincible, emits a
-//!   [`PlayerDamagedEvent`] and inserts an [`InvincibilityTimer`].
+//!   [`PlayerDamagedEvent`] and inserts an [`InvincibilityTimer`].  A
+//!   Vampiric [`Elite`] heals itself on the hit.
 //! - [`apply_damage_to_player`] — reads [`PlayerDamagedEvent`] and reduces
 //!   [`PlayerStats::current_hp`], clamped to zero.
 //! - [`tick_invincibility`] — decrements [`InvincibilityTimer`] each frame and
@@ -13,11 +14,12 @@
 use bevy::prelude::*;
 
 use crate::{
-    components::{CircleCollider, Enemy, InvincibilityTimer, Player, PlayerStats},
-    config::PlayerParams,
+    components::{CircleCollider, Elite, Enemy, InvincibilityTimer, Player, PlayerStats},
+    config::{EnemyParams, PlayerParams},
     events::PlayerDamagedEvent,
     resources::SpatialGrid,
     systems::collision::check_circle_collision,
+    types::EliteModifier,
 };
 
 // ---------------------------------------------------------------------------
@@ -46,16 +48,18 @@ const MAX_ENEMY_COLLIDER_RADIUS: f32 = 60.0;
 /// skipped entirely (the query filter returns no entity).  On the first
 /// overlapping enemy found, a [`PlayerDamagedEvent`] is emitted and an
 /// [`InvincibilityTimer`] is inserted so the player cannot be hit again until
-/// the timer expires.
+/// the timer expires.  A Vampiric [`Elite`] that lands the hit restores
+/// `elite.vampiric_heal_fraction` of its max HP.
 /// Query filter for a non-invincible player entity.
 type VulnerablePlayer = (With<Player>, Without<InvincibilityTimer>);
 
 pub fn enemy_player_collision(
     mut commands: Commands,
     player_q: Query<(Entity, &Transform, &CircleCollider), VulnerablePlayer>,
-    enemy_q: Query<(&Transform, &CircleCollider, &Enemy)>,
+    mut enemy_q: Query<(&Transform, &CircleCollider, &mut Enemy, Option<&Elite>)>,
     spatial_grid: Res<SpatialGrid>,
     player_cfg: PlayerParams,
+    enemy_cfg: EnemyParams,
     mut damage_events: MessageWriter<PlayerDamagedEvent>,
 ) {
     let Ok((player_entity, player_tf, player_collider)) = player_q.single() else {
@@ -73,7 +77,7 @@ pub fn enemy_player_collision(
     let candidates = spatial_grid.get_nearby(player_pos, query_radius);
 
     for candidate in candidates {
-        let Ok((enemy_tf, enemy_collider, enemy)) = enemy_q.get(candidate) else {
+        let Ok((enemy_tf, enemy_collider, mut enemy, elite)) = enemy_q.get_mut(candidate) else {
             continue;
         };
 
@@ -95,6 +99,10 @@ pub fn enemy_player_collision(
         commands.entity(player_entity).insert(InvincibilityTimer {
             remaining: invincibility_duration,
         });
+        if elite.is_some_and(|e| e.modifier == EliteModifier::Vampiric) {
+            let heal = enemy.max_hp * enemy_cfg.elite().vampiric_heal_fraction.max(0.0);
+            enemy.current_hp = (enemy.current_hp + heal).min(enemy.max_hp);
+        }
         return; // only one hit per frame
     }
 }
@@ -223,6 +231,29 @@ mod tests {
         assert!(events[0].damage > 0.0, "damage should be positive");
     }
 
+    /// A Vampiric elite heals on contact; a regular enemy does not.
+    #[test]
+    fn vampiric_elite_heals_on_contact() {
+        let mut app = build_app();
+        spawn_player(&mut app, Vec2::ZERO, 12.0);
+        let enemy = spawn_enemy(&mut app, Vec2::new(5.0, 0.0), 10.0);
+        let max_hp = {
+            let mut entity = app.world_mut().entity_mut(enemy);
+            entity.insert(Elite {
+                modifier: EliteModifier::Vampiric,
+            });
+            let mut stats = entity.get_mut::<Enemy>().unwrap();
+            stats.current_hp = 1.0;
+            stats.max_hp
+        };
+
+        update_grid(&mut app);
+        run_collision(&mut app);
+
+        let hp = app.world().get::<Enemy>(enemy).unwrap().current_hp;
+        assert!((hp - (1.0 + max_hp * 0.2)).abs() < 1e-4, "hp is {hp}");
+    }
+
     /// After contact, the player receives an InvincibilityTimer.
     #[test]
     fn contact_inserts_invincibility_timer() {
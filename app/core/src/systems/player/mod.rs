@@ -19,7 +19,8 @@ impl Plugin for PlayerPlugin {
             .add_systems(
                 Update,
                 (
-                    player_movement,
+                    read_movement_input,
+                    player_movement.after(read_movement_input),
                     regen_hp.after(apply_damage_to_player),
                     tick_invincibility.before(enemy_player_collision),
                     enemy_player_collision.after(update_spatial_grid),
@@ -36,7 +37,7 @@ use crate::{
         PlayerFacingDirection, PlayerStats, PlayerWhipSide, WeaponInventory,
     },
     config::{CharacterParams, GameParams, PlayerParams},
-    resources::{MetaProgress, SelectedCharacter},
+    resources::{MetaProgress, MoveInput, SelectedCharacter},
     types::{MetaUpgradeType, WeaponState, WhipSide},
 };
 
@@ -223,37 +224,47 @@ pub(crate) fn apply_meta_upgrades(
 // Movement
 // ---------------------------------------------------------------------------
 
-/// Moves the player based on WASD / arrow-key input.
+/// Samples WASD / arrow keys into [`MoveInput`] for this frame.
 ///
-/// - Input from all four cardinal directions is summed then normalised so that
-///   diagonal movement is not faster than axis-aligned movement.
+/// Opposite keys cancel out.  Runs before [`player_movement`]; during replay
+/// playback the replay systems overwrite the sampled value with the recorded
+/// one before movement is applied.
+pub fn read_movement_input(keys: Res<ButtonInput<KeyCode>>, mut input: ResMut<MoveInput>) {
+    let mut direction = IVec2::ZERO;
+
+    if keys.pressed(KeyCode::KeyW) || keys.pressed(KeyCode::ArrowUp) {
+        direction.y += 1;
+    }
+    if keys.pressed(KeyCode::KeyS) || keys.pressed(KeyCode::ArrowDown) {
+        direction.y -= 1;
+    }
+    if keys.pressed(KeyCode::KeyA) || keys.pressed(KeyCode::ArrowLeft) {
+        direction.x -= 1;
+    }
+    if keys.pressed(KeyCode::KeyD) || keys.pressed(KeyCode::ArrowRight) {
+        direction.x += 1;
+    }
+
+    input.0 = direction;
+}
+
+/// Moves the player according to this frame's [`MoveInput`].
+///
+/// - The input direction is normalised so that diagonal movement is not
+///   faster than axis-aligned movement.
 /// - Movement is frame-rate independent: distance = speed × Δt.
 /// - [`PlayerFacingDirection`] is updated whenever the player moves, so that
 ///   directional weapons (e.g. Knife) always have a valid aim vector.
 pub fn player_movement(
     time: Res<Time>,
-    keys: Res<ButtonInput<KeyCode>>,
+    input: Res<MoveInput>,
     mut query: Query<(&mut Transform, &PlayerStats, &mut PlayerFacingDirection), With<Player>>,
 ) {
     let Ok((mut transform, stats, mut facing)) = query.single_mut() else {
         return;
     };
 
-    let mut direction = Vec2::ZERO;
-
-    if keys.pressed(KeyCode::KeyW) || keys.pressed(KeyCode::ArrowUp) {
-        direction.y += 1.0;
-    }
-    if keys.pressed(KeyCode::KeyS) || keys.pressed(KeyCode::ArrowDown) {
-        direction.y -= 1.0;
-    }
-    if keys.pressed(KeyCode::KeyA) || keys.pressed(KeyCode::ArrowLeft) {
-        direction.x -= 1.0;
-    }
-    if keys.pressed(KeyCode::KeyD) || keys.pressed(KeyCode::ArrowRight) {
-        direction.x += 1.0;
-    }
-
+    let direction = input.0.as_vec2();
     if direction != Vec2::ZERO {
         let normalized = direction.normalize();
         facing.0 = normalized;
@@ -411,6 +422,10 @@ mod tests {
         let mut input = ButtonInput::<KeyCode>::default();
         input.press(KeyCode::ArrowRight);
         app.insert_resource(input);
+        app.init_resource::<MoveInput>();
+        app.world_mut()
+            .run_system_once(read_movement_input)
+            .expect("read_movement_input system should run");
 
         // Advance Time manually BEFORE running the system so that delta_secs() > 0.
         // We bypass app.update() to avoid the TimePlugin resetting the delta.
@@ -588,6 +603,10 @@ mod tests {
         ));
 
         app.insert_resource(ButtonInput::<KeyCode>::default());
+        app.init_resource::<MoveInput>();
+        app.world_mut()
+            .run_system_once(read_movement_input)
+            .expect("read_movement_input system should run");
 
         app.world_mut()
             .resource_mut::<Time>()
@@ -15,14 +15,25 @@
 //!   records the struck enemy in `hit_enemies` to prevent a double-hit on the
 //!   same target.  The projectile is despawned only when `piercing` reaches 0
 //!   and the next enemy is hit.
+//!
+//! # Knockback
+//!
+//! Hit enemies are pushed `projectile.knockback` pixels along the
+//! projectile's travel direction, or directly away from the projectile when
+//! it is stationary.
+//!
+//! # Status effects
+//!
+//! Projectiles carrying an [`InflictsStatus`] pass its effect along with
+//! every hit (e.g. the Holy Wand freeze).
 
 use bevy::prelude::*;
 
 use crate::{
-    components::{CircleCollider, Enemy, Projectile},
+    components::{CircleCollider, Enemy, InflictsStatus, Projectile, ProjectileVelocity},
     events::DamageEnemyEvent,
     resources::SpatialGrid,
-    systems::collision::check_circle_collision,
+    systems::{collision::check_circle_collision, damage::knockback_from},
 };
 
 // ---------------------------------------------------------------------------
@@ -51,14 +62,24 @@ const MAX_ENEMY_COLLIDER_RADIUS: f32 = 60.0;
 ///    - despawns the projectile (`piercing == 0`), or
 ///    - records the enemy in `hit_enemies` and decrements `piercing`.
 pub fn projectile_enemy_collision(
-    mut projectile_q: Query<(Entity, &mut Projectile, &Transform, &CircleCollider)>,
+    mut projectile_q: Query<(
+        Entity,
+        &mut Projectile,
+        &Transform,
+        &CircleCollider,
+        Option<&ProjectileVelocity>,
+        Option<&InflictsStatus>,
+    )>,
     enemy_q: Query<(&Transform, &CircleCollider), With<Enemy>>,
     spatial_grid: Res<SpatialGrid>,
     mut damage_events: MessageWriter<DamageEnemyEvent>,
     mut commands: Commands,
 ) {
-    for (proj_entity, mut projectile, proj_tf, proj_collider) in projectile_q.iter_mut() {
+    for (proj_entity, mut projectile, proj_tf, proj_collider, velocity, inflicts) in
+        projectile_q.iter_mut()
+    {
         let proj_pos = proj_tf.translation.truncate();
+        let travel_dir = velocity.map_or(Vec2::ZERO, |v| v.0.normalize_or_zero());
         let query_radius = proj_collider.radius + MAX_ENEMY_COLLIDER_RADIUS;
 
         let candidates = spatial_grid.get_nearby(proj_pos, query_radius);
@@ -85,10 +106,17 @@ pub fn projectile_enemy_collision(
             }
 
             // Hit confirmed — emit damage event.
+            let knockback = if travel_dir == Vec2::ZERO {
+                knockback_from(proj_pos, enemy_pos, projectile.knockback)
+            } else {
+                travel_dir * projectile.knockback
+            };
             damage_events.write(DamageEnemyEvent {
                 entity: candidate,
                 damage: projectile.damage,
                 weapon_type: projectile.weapon_type,
+                knockback,
+                status: inflicts.map(|i| i.0),
             });
 
             if projectile.piercing == 0 {
@@ -116,7 +144,9 @@ mod tests {
 
     use super::*;
     use crate::{
-        components::{CircleCollider, Enemy, Projectile, ProjectileVelocity},
+        components::{
+            CircleCollider, Enemy, InflictsStatus, Projectile, ProjectileVelocity, StatusEffect,
+        },
         events::DamageEnemyEvent,
         resources::SpatialGrid,
         types::{EnemyType, WeaponType},
@@ -143,6 +173,7 @@ mod tests {
                     hit_enemies: Vec::new(),
                     lifetime: 5.0,
                     weapon_type: WeaponType::MagicWand,
+                    knockback: 0.0,
                 },
                 ProjectileVelocity(Vec2::ZERO),
                 Transform::from_xyz(pos.x, pos.y, 5.0),
@@ -201,6 +232,44 @@ mod tests {
         assert_eq!(events[0].damage, 10.0);
     }
 
+    /// Knockback follows the projectile's travel direction.
+    #[test]
+    fn hit_knockback_follows_travel_direction() {
+        let mut app = build_app();
+        spawn_enemy(&mut app, Vec2::new(5.0, 0.0), 10.0);
+        let proj = spawn_projectile(&mut app, Vec2::ZERO, 8.0, 0);
+        app.world_mut()
+            .get_mut::<Projectile>(proj)
+            .unwrap()
+            .knockback = 12.0;
+        app.world_mut()
+            .entity_mut(proj)
+            .insert(ProjectileVelocity(Vec2::new(0.0, 300.0)));
+
+        update_grid(&mut app);
+        run_collision(&mut app);
+
+        let events = damage_events(&app);
+        assert_eq!(events[0].knockback, Vec2::new(0.0, 12.0));
+    }
+
+    /// A projectile's `InflictsStatus` effect rides along with its hits.
+    #[test]
+    fn hit_carries_inflicted_status() {
+        let mut app = build_app();
+        spawn_enemy(&mut app, Vec2::new(5.0, 0.0), 10.0);
+        let proj = spawn_projectile(&mut app, Vec2::ZERO, 8.0, 0);
+        let freeze = StatusEffect::freeze(0.5, WeaponType::HolyWand);
+        app.world_mut()
+            .entity_mut(proj)
+            .insert(InflictsStatus(freeze));
+
+        update_grid(&mut app);
+        run_collision(&mut app);
+
+        assert_eq!(damage_events(&app)[0].status, Some(freeze));
+    }
+
     /// A non-piercing projectile (piercing == 0) is despawned after the first hit.
     #[test]
     fn non_piercing_projectile_despawns_on_hit() {
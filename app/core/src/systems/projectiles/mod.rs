@@ -27,16 +27,13 @@ impl Plugin for ProjectilesPlugin {
     fn build(&self, app: &mut App) {
         use crate::systems::projectiles::collision::projectile_enemy_collision;
         use crate::systems::spatial::update_spatial_grid;
-        use crate::systems::weapons::cross::update_cross;
         app.add_systems(
             Update,
             (
                 move_projectiles,
-                update_cross.after(move_projectiles),
                 projectile_enemy_collision
                     .after(update_spatial_grid)
-                    .after(move_projectiles)
-                    .after(update_cross),
+                    .after(move_projectiles),
                 despawn_expired_projectiles,
             )
                 .run_if(in_state(AppState::Playing)),
@@ -93,6 +90,7 @@ pub fn despawn_expired_projectiles(
 /// - `position` — World-space spawn point (pixels).
 /// - `velocity` — Direction × speed in pixels/second.
 /// - `damage` — Base damage per hit (before `damage_multiplier`).
+/// - `knockback` — Push distance (pixels) applied to each enemy hit.
 /// - `lifetime` — Maximum life in seconds; despawned when it reaches 0.
 /// - `piercing` — Extra enemies this projectile penetrates past the first
 ///   (`0` = normal single-hit behaviour).
@@ -107,6 +105,7 @@ pub fn spawn_projectile(
     position: Vec2,
     velocity: Vec2,
     damage: f32,
+    knockback: f32,
     lifetime: f32,
     piercing: u32,
     collider_radius: f32,
@@ -121,6 +120,7 @@ pub fn spawn_projectile(
                 hit_enemies: Vec::new(),
                 lifetime,
                 weapon_type,
+                knockback,
             },
             ProjectileVelocity(velocity),
             CircleCollider {
@@ -188,6 +188,7 @@ mod tests {
                     hit_enemies: Vec::new(),
                     lifetime,
                     weapon_type: WeaponType::MagicWand,
+                    knockback: 0.0,
                 },
                 ProjectileVelocity(velocity),
                 Transform::from_xyz(0.0, 0.0, 5.0),
@@ -328,6 +329,7 @@ mod tests {
                 Vec2::new(10.0, 20.0),
                 Vec2::new(300.0, 0.0),
                 15.0, // damage
+                0.0,  // knockback
                 5.0,  // lifetime
                 1,    // piercing
                 5.0,  // collider_radius
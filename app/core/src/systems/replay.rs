@@ -0,0 +1,495 @@
+//! Input recording and deterministic replay of full runs.
+//!
+//! Every live run is recorded into [`ReplayRecorder`]: the [`GameRng`] seed,
+//! the selected character / stage / permanent upgrades, the [`MoveInput`] and
+//! time step of every gameplay frame, and each level-up card choice.  When the
+//! run ends in game over or victory the replay and the final [`GameData`]
+//! summary are written to `save/replays/`; abandoned runs are discarded.
+//!
+//! When a [`ReplayPlayback`] resource is present (see the `--replay <path>`
+//! flag), the recorded run is started straight from the title screen and:
+//!
+//! 1. Every gameplay frame's movement is taken from the replay instead of the
+//!    keyboard.
+//! 2. Time advances by exactly the recorded step each frame via
+//!    [`TimeUpdateStrategy::ManualDuration`], independent of render speed.
+//! 3. Level-up overlays are resolved with the recorded card automatically.
+//! 4. On game over / victory the final [`GameData`] is compared with the
+//!    recorded summary and any desync is logged.
+//!
+//! Playback ends there, or when the title screen is reached again; the
+//! player's own [`MetaProgress`] and [`SeedOverride`] are then restored.
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+
+use crate::{
+    resources::{
+        GameData, GameRng, MetaProgress, MoveInput, PendingUpgradeIndex, Replay, ReplayPlayback,
+        ReplayRecorder, ReplaySummary, SeedOverride, SelectedCharacter, SelectedStage,
+    },
+    states::AppState,
+};
+
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        use crate::systems::persistence::{save_meta_on_game_over, save_meta_on_victory};
+        use crate::systems::player::{player_movement, read_movement_input};
+        use crate::systems::xp::choices::generate_level_up_choices;
+
+        let playing_back = resource_exists::<ReplayPlayback>;
+
+        app.init_resource::<ReplayRecorder>()
+            // --- Recording ---------------------------------------------------
+            .add_systems(
+                OnEnter(AppState::Playing),
+                start_replay_recording.run_if(not(playing_back)),
+            )
+            .add_systems(
+                OnTransition {
+                    exited: AppState::LevelUp,
+                    entered: AppState::Playing,
+                },
+                record_upgrade_choice,
+            )
+            .add_systems(OnEnter(AppState::GameOver), finish_replay_recording)
+            .add_systems(OnEnter(AppState::Victory), finish_replay_recording)
+            .add_systems(OnEnter(AppState::Title), discard_replay_recording)
+            // --- Playback ----------------------------------------------------
+            .add_systems(
+                OnEnter(AppState::Title),
+                start_replay_playback.run_if(playing_back),
+            )
+            .add_systems(
+                OnEnter(AppState::LevelUp),
+                pick_replay_upgrade
+                    .after(generate_level_up_choices)
+                    .run_if(playing_back),
+            )
+            .add_systems(
+                OnEnter(AppState::GameOver),
+                finish_replay_playback
+                    .after(save_meta_on_game_over)
+                    .run_if(playing_back),
+            )
+            .add_systems(
+                OnEnter(AppState::Victory),
+                finish_replay_playback
+                    .after(save_meta_on_victory)
+                    .run_if(playing_back),
+            )
+            // --- Per-frame ---------------------------------------------------
+            // The recorded input replaces the keyboard sample before the
+            // recorder captures it and before the player moves.
+            .add_systems(
+                Update,
+                (
+                    feed_replay_frame
+                        .after(read_movement_input)
+                        .run_if(playing_back),
+                    record_replay_frame
+                        .after(read_movement_input)
+                        .after(feed_replay_frame),
+                )
+                    .before(player_movement)
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Recording
+// ---------------------------------------------------------------------------
+
+/// Starts recording when a new run begins.
+///
+/// Runs on every entry to [`AppState::Playing`] but only acts when no
+/// recording is in progress, so returning from `LevelUp` / `Paused` keeps
+/// the current one.  [`GameRng`] has already been re-seeded for the run by
+/// `reset_per_run_resources` (an `OnTransition` system, which runs first).
+pub fn start_replay_recording(
+    mut recorder: ResMut<ReplayRecorder>,
+    game_rng: Res<GameRng>,
+    character: Res<SelectedCharacter>,
+    stage: Res<SelectedStage>,
+    meta: Res<MetaProgress>,
+) {
+    if recorder.disabled || recorder.replay.is_some() {
+        return;
+    }
+    recorder.replay = Some(Replay::new(
+        game_rng.seed(),
+        character.0,
+        stage.0,
+        meta.purchased_upgrades.clone(),
+    ));
+}
+
+/// Appends this frame's movement and time step to the active recording.
+pub fn record_replay_frame(
+    mut recorder: ResMut<ReplayRecorder>,
+    input: Res<MoveInput>,
+    time: Res<Time>,
+) {
+    if let Some(replay) = recorder.replay.as_mut() {
+        replay.push_frame(*input, time.delta());
+    }
+}
+
+/// Records the card picked on the level-up overlay.
+///
+/// Runs on the `LevelUp → Playing` transition, before
+/// `apply_selected_upgrade` consumes [`PendingUpgradeIndex`] in
+/// `OnEnter(Playing)`.
+pub fn record_upgrade_choice(
+    mut recorder: ResMut<ReplayRecorder>,
+    pending: Res<PendingUpgradeIndex>,
+) {
+    if let Some(replay) = recorder.replay.as_mut() {
+        replay.upgrade_choices.push(pending.0);
+    }
+}
+
+/// Stores the final run summary and writes the replay to `save/replays/`.
+pub fn finish_replay_recording(mut recorder: ResMut<ReplayRecorder>, game_data: Res<GameData>) {
+    let Some(mut replay) = recorder.replay.take() else {
+        return;
+    };
+    replay.summary = Some(ReplaySummary::from(&*game_data));
+    if let Some(path) = replay.save() {
+        info!(
+            "Replay saved to {} ({} frames)",
+            path.display(),
+            replay.frame_count()
+        );
+    }
+}
+
+/// Drops an unfinished recording when the player quits to the title.
+pub fn discard_replay_recording(mut recorder: ResMut<ReplayRecorder>) {
+    recorder.replay = None;
+}
+
+// ---------------------------------------------------------------------------
+// Playback
+// ---------------------------------------------------------------------------
+
+/// Starts the recorded run the first time the title screen is reached.
+///
+/// Applies the recorded seed, character, stage, and permanent upgrades (in
+/// an in-memory [`MetaProgress`] that is never saved), locks the time step to
+/// the first recorded frame, and jumps straight to [`AppState::Playing`].
+/// Reaching the title again (the replayed run was quit) ends playback.
+pub fn start_replay_playback(
+    mut commands: Commands,
+    mut playback: ResMut<ReplayPlayback>,
+    meta: Res<MetaProgress>,
+    mut seed_override: ResMut<SeedOverride>,
+    mut time_strategy: ResMut<TimeUpdateStrategy>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if playback.started {
+        info!("Replay abandoned");
+        end_replay_playback(
+            &mut commands,
+            &mut playback,
+            &mut seed_override,
+            &mut time_strategy,
+        );
+        return;
+    }
+    playback.started = true;
+    playback.saved_meta = Some(meta.clone());
+    playback.saved_seed = *seed_override;
+
+    let replay = &playback.replay;
+    seed_override.0 = Some(replay.seed);
+    commands.insert_resource(SelectedCharacter(replay.character));
+    commands.insert_resource(SelectedStage(replay.stage));
+    commands.insert_resource(MetaProgress::in_memory(replay.meta_upgrades.clone()));
+    if let Some((_, delta)) = playback.peek_frame() {
+        *time_strategy = TimeUpdateStrategy::ManualDuration(delta);
+    }
+    info!(
+        "Replaying run: seed {}, {:?} on {:?}, {} frames",
+        replay.seed,
+        replay.character,
+        replay.stage,
+        replay.frame_count()
+    );
+    next_state.set(AppState::Playing);
+}
+
+/// Replaces this frame's [`MoveInput`] with the recorded one and schedules
+/// the recorded time step of the next frame.
+///
+/// Once the recording is exhausted the player stops moving and time returns
+/// to wall-clock updates.
+pub fn feed_replay_frame(
+    mut playback: ResMut<ReplayPlayback>,
+    mut input: ResMut<MoveInput>,
+    mut time_strategy: ResMut<TimeUpdateStrategy>,
+) {
+    match playback.next_frame() {
+        Some((recorded, _)) => *input = recorded,
+        None => *input = MoveInput::default(),
+    }
+    *time_strategy = match playback.peek_frame() {
+        Some((_, delta)) => TimeUpdateStrategy::ManualDuration(delta),
+        None => TimeUpdateStrategy::Automatic,
+    };
+}
+
+/// Resolves the level-up overlay with the recorded card.
+///
+/// Leaves the overlay open for manual input if the recording has no more
+/// choices (e.g. the replay was truncated).
+pub fn pick_replay_upgrade(
+    mut playback: ResMut<ReplayPlayback>,
+    mut pending: ResMut<PendingUpgradeIndex>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    let Some(choice) = playback.next_upgrade_choice() else {
+        warn!("Replay has no recorded choice for this level-up");
+        return;
+    };
+    pending.0 = choice;
+    next_state.set(AppState::Playing);
+}
+
+/// Compares the replayed run with the recorded summary and ends playback.
+///
+/// Runs after the run's gold has been accrued to the in-memory
+/// [`MetaProgress`], so the restored one is left untouched.
+pub fn finish_replay_playback(
+    mut commands: Commands,
+    mut playback: ResMut<ReplayPlayback>,
+    game_data: Res<GameData>,
+    mut seed_override: ResMut<SeedOverride>,
+    mut time_strategy: ResMut<TimeUpdateStrategy>,
+) {
+    let actual = ReplaySummary::from(&*game_data);
+    match playback.replay.summary {
+        Some(expected) if expected == actual => {
+            info!("Replay reproduced the recorded run: {actual:?}");
+        }
+        Some(expected) => {
+            warn!("Replay desynced: expected {expected:?}, got {actual:?}");
+        }
+        None => info!("Replay finished (no recorded summary): {actual:?}"),
+    }
+    end_replay_playback(
+        &mut commands,
+        &mut playback,
+        &mut seed_override,
+        &mut time_strategy,
+    );
+}
+
+/// Puts back the player's [`MetaProgress`] and [`SeedOverride`], returns
+/// time to wall-clock updates, and removes [`ReplayPlayback`].
+fn end_replay_playback(
+    commands: &mut Commands,
+    playback: &mut ReplayPlayback,
+    seed_override: &mut SeedOverride,
+    time_strategy: &mut TimeUpdateStrategy,
+) {
+    if let Some(meta) = playback.saved_meta.take() {
+        commands.insert_resource(meta);
+    }
+    *seed_override = playback.saved_seed;
+    *time_strategy = TimeUpdateStrategy::Automatic;
+    commands.remove_resource::<ReplayPlayback>();
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::system::RunSystemOnce as _;
+    use bevy::state::app::StatesPlugin;
+
+    use super::*;
+    use crate::types::{CharacterType, StageType};
+
+    fn build_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin));
+        app.init_state::<AppState>();
+        app.init_resource::<ReplayRecorder>();
+        app.init_resource::<MoveInput>();
+        app.init_resource::<PendingUpgradeIndex>();
+        app.insert_resource(GameData::default());
+        app.insert_resource(GameRng::from_seed(99));
+        app.insert_resource(SelectedCharacter(CharacterType::Magician));
+        app.insert_resource(SelectedStage(StageType::InlaidLibrary));
+        app.insert_resource(MetaProgress::default());
+        app
+    }
+
+    fn recorded_replay() -> Replay {
+        let mut replay = Replay::new(5, CharacterType::Thief, StageType::DairyPlant, vec![]);
+        replay.push_frame(MoveInput(IVec2::new(1, -1)), Duration::from_millis(16));
+        replay.push_frame(MoveInput(IVec2::ZERO), Duration::from_millis(20));
+        replay.upgrade_choices = vec![Some(2)];
+        replay
+    }
+
+    #[test]
+    fn recording_captures_run_parameters_and_frames() {
+        let mut app = build_app();
+        app.world_mut()
+            .run_system_once(start_replay_recording)
+            .unwrap();
+
+        app.world_mut().resource_mut::<MoveInput>().0 = IVec2::X;
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_millis(16));
+        app.world_mut()
+            .run_system_once(record_replay_frame)
+            .unwrap();
+        app.world_mut()
+            .run_system_once(record_replay_frame)
+            .unwrap();
+
+        app.world_mut().resource_mut::<PendingUpgradeIndex>().0 = Some(1);
+        app.world_mut()
+            .run_system_once(record_upgrade_choice)
+            .unwrap();
+
+        let recorder = app.world().resource::<ReplayRecorder>();
+        let replay = recorder
+            .replay
+            .as_ref()
+            .expect("recording should be active");
+        assert_eq!(replay.seed, 99);
+        assert_eq!(replay.character, CharacterType::Magician);
+        assert_eq!(replay.stage, StageType::InlaidLibrary);
+        assert_eq!(replay.frame_count(), 2);
+        assert_eq!(replay.frames.len(), 1, "identical frames must merge");
+        assert_eq!(replay.upgrade_choices, vec![Some(1)]);
+    }
+
+    #[test]
+    fn recording_is_not_restarted_mid_run() {
+        let mut app = build_app();
+        app.world_mut()
+            .run_system_once(start_replay_recording)
+            .unwrap();
+        app.world_mut()
+            .run_system_once(record_replay_frame)
+            .unwrap();
+        app.world_mut()
+            .run_system_once(start_replay_recording)
+            .unwrap();
+
+        let recorder = app.world().resource::<ReplayRecorder>();
+        assert_eq!(recorder.replay.as_ref().unwrap().frame_count(), 1);
+    }
+
+    #[test]
+    fn playback_applies_run_parameters_and_starts_run() {
+        let mut app = build_app();
+        app.init_resource::<SeedOverride>();
+        app.insert_resource(ReplayPlayback::new(recorded_replay()));
+
+        app.world_mut()
+            .run_system_once(start_replay_playback)
+            .unwrap();
+        app.update();
+
+        assert_eq!(app.world().resource::<SeedOverride>().0, Some(5));
+        assert_eq!(
+            app.world().resource::<SelectedCharacter>().0,
+            CharacterType::Thief
+        );
+        assert_eq!(
+            app.world().resource::<SelectedStage>().0,
+            StageType::DairyPlant
+        );
+        assert_eq!(
+            *app.world().resource::<State<AppState>>(),
+            AppState::Playing
+        );
+    }
+
+    #[test]
+    fn finishing_playback_restores_meta_progress_and_seed() {
+        let mut app = build_app();
+        app.insert_resource(SeedOverride(Some(42)));
+        app.world_mut().resource_mut::<MetaProgress>().total_gold = 300;
+        app.insert_resource(ReplayPlayback::new(recorded_replay()));
+
+        app.world_mut()
+            .run_system_once(start_replay_playback)
+            .unwrap();
+        assert!(app.world().resource::<MetaProgress>().load_failed);
+        app.world_mut()
+            .run_system_once(finish_replay_playback)
+            .unwrap();
+
+        let meta = app.world().resource::<MetaProgress>();
+        assert_eq!(meta.total_gold, 300);
+        assert!(!meta.load_failed);
+        assert_eq!(app.world().resource::<SeedOverride>().0, Some(42));
+        assert!(!app.world().contains_resource::<ReplayPlayback>());
+    }
+
+    #[test]
+    fn returning_to_title_ends_playback() {
+        let mut app = build_app();
+        app.init_resource::<SeedOverride>();
+        app.insert_resource(ReplayPlayback::new(recorded_replay()));
+
+        app.world_mut()
+            .run_system_once(start_replay_playback)
+            .unwrap();
+        app.world_mut()
+            .run_system_once(start_replay_playback)
+            .unwrap();
+
+        assert!(!app.world().resource::<MetaProgress>().load_failed);
+        assert_eq!(app.world().resource::<SeedOverride>().0, None);
+        assert!(!app.world().contains_resource::<ReplayPlayback>());
+    }
+
+    #[test]
+    fn feed_replaces_input_and_schedules_next_time_step() {
+        let mut app = build_app();
+        app.insert_resource(ReplayPlayback::new(recorded_replay()));
+
+        app.world_mut().run_system_once(feed_replay_frame).unwrap();
+        assert_eq!(app.world().resource::<MoveInput>().0, IVec2::new(1, -1));
+        assert!(matches!(
+            app.world().resource::<TimeUpdateStrategy>(),
+            TimeUpdateStrategy::ManualDuration(d) if *d == Duration::from_millis(20)
+        ));
+
+        app.world_mut().run_system_once(feed_replay_frame).unwrap();
+        app.world_mut().run_system_once(feed_replay_frame).unwrap();
+        assert_eq!(app.world().resource::<MoveInput>().0, IVec2::ZERO);
+        assert!(matches!(
+            app.world().resource::<TimeUpdateStrategy>(),
+            TimeUpdateStrategy::Automatic
+        ));
+    }
+
+    #[test]
+    fn level_up_uses_recorded_choice() {
+        let mut app = build_app();
+        app.insert_resource(ReplayPlayback::new(recorded_replay()));
+
+        app.world_mut()
+            .run_system_once(pick_replay_upgrade)
+            .unwrap();
+
+        assert_eq!(app.world().resource::<PendingUpgradeIndex>().0, Some(2));
+    }
+}
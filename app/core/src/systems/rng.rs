@@ -0,0 +1,73 @@
+//! Fixed order of the gameplay systems that draw from [`GameRng`].
+//!
+//! A seeded run only replays identically when [`GameRng`] hands out its
+//! values in the same order every frame.  Bevy is free to run systems with
+//! no ordering between them in any order (and on any thread), so every
+//! `Update` system taking `ResMut<GameRng>` joins one of the
+//! [`GameRngSystems`] sets, which run one after another:
+//!
+//! 1. Spawns — regular enemies (including their elite rolls), formations,
+//!    mini-bosses, light sources.
+//! 2. Weapon fire — one [`GameRngSystems::Weapon`] set per weapon, chained in
+//!    [`WeaponRegistry`](crate::systems::weapons::registry::WeaponRegistry)
+//!    order by `WeaponsPlugin`, all inside [`WeaponSystems::Fire`].
+//! 3. Stage-boss attack patterns.
+//! 4. Drops — gold, floor pickups, light-source breaks, treasure chests.
+//!
+//! `reset_per_run_resources` and `generate_level_up_choices` run on state
+//! transitions, alone in their schedules, and need no set.
+//!
+//! [`GameRng`]: crate::resources::GameRng
+
+use bevy::prelude::*;
+
+use crate::{systems::weapons::WeaponSystems, types::WeaponType};
+
+/// Sets of the `Update` systems that draw from
+/// [`GameRng`](crate::resources::GameRng), in draw order.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameRngSystems {
+    /// `spawn_enemies`, which also rolls elites.
+    EnemySpawns,
+    FormationSpawns,
+    MiniBossSpawns,
+    LightSourceSpawns,
+    /// The fire system(s) of one weapon.
+    Weapon(WeaponType),
+    BossPatterns,
+    GoldDrops,
+    FloorPickupDrops,
+    LightSourceBreaks,
+    TreasureChests,
+}
+
+/// Chains the [`GameRngSystems`] sets around [`WeaponSystems::Fire`].
+pub struct GameRngPlugin;
+
+impl Plugin for GameRngPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(
+            Update,
+            (
+                GameRngSystems::EnemySpawns,
+                GameRngSystems::FormationSpawns,
+                GameRngSystems::MiniBossSpawns,
+                GameRngSystems::LightSourceSpawns,
+            )
+                .chain()
+                .before(WeaponSystems::Fire),
+        )
+        .configure_sets(
+            Update,
+            (
+                GameRngSystems::BossPatterns,
+                GameRngSystems::GoldDrops,
+                GameRngSystems::FloorPickupDrops,
+                GameRngSystems::LightSourceBreaks,
+                GameRngSystems::TreasureChests,
+            )
+                .chain()
+                .after(WeaponSystems::Fire),
+        );
+    }
+}
@@ -76,6 +76,9 @@ mod tests {
             position: Vec2::ZERO,
             enemy_type,
             xp_value: 0,
+            gold_chance: 0.0,
+            elite: None,
+            mini_boss: None,
         });
     }
 
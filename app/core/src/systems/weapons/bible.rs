@@ -48,30 +48,31 @@ use std::f32::consts::TAU;
 use bevy::prelude::*;
 
 use crate::{
-    components::{Enemy, GameSessionEntity, OrbitWeapon, Player, PlayerStats},
-    config::weapon::bible::BibleParams,
+    components::{Enemy, GameSessionEntity, OrbitWeapon, Player, PlayerStats, WeaponInventory},
+    config::{
+        register_weapon_config,
+        validate::CheckConfigFile,
+        weapon::bible::{
+            BibleConfig, BibleConfigHandle, BibleConfigLoader, BibleParams, DEFAULT_COUNT_BY_LEVEL,
+            DEFAULT_DAMAGE_BY_LEVEL, DEFAULT_ORBIT_RADIUS_BY_LEVEL, DEFAULT_ORBIT_SPEED_BY_LEVEL,
+        },
+    },
     events::{DamageEnemyEvent, WeaponFiredEvent},
     resources::SpatialGrid,
-    types::WeaponType,
+    systems::{
+        damage::knockback_from,
+        weapons::{
+            WeaponSystems,
+            registry::{LocalizedText, WeaponSpec},
+        },
+    },
+    types::{DamageType, WeaponSound, WeaponType},
 };
 
 // ---------------------------------------------------------------------------
 // Fallback constants (used while RON config is still loading)
 // ---------------------------------------------------------------------------
 
-/// Damage per hit at each weapon level (index 0 = level 1).
-const DEFAULT_BIBLE_DAMAGE_BY_LEVEL: [f32; 8] = [20.0, 25.0, 30.0, 35.0, 40.0, 50.0, 60.0, 80.0];
-
-/// Orbit radius in pixels at each weapon level.
-const DEFAULT_BIBLE_ORBIT_RADIUS_BY_LEVEL: [f32; 8] =
-    [80.0, 80.0, 80.0, 90.0, 90.0, 100.0, 100.0, 110.0];
-
-/// Angular velocity (rad/s) at each weapon level.
-const DEFAULT_BIBLE_ORBIT_SPEED_BY_LEVEL: [f32; 8] = [2.0, 2.0, 2.3, 2.3, 2.5, 2.5, 2.8, 3.0];
-
-/// Number of orbiting bodies at each weapon level.
-const DEFAULT_BIBLE_COUNT_BY_LEVEL: [u32; 8] = [1, 1, 2, 2, 3, 3, 3, 3];
-
 /// UnholyVespers orbit speed multiplier relative to Bible base speed (2× faster).
 const DEFAULT_UNHOLY_VESPERS_SPEED_MULT: f32 = 2.0;
 /// UnholyVespers orbit radius multiplier relative to Bible base radius (1.5× wider).
@@ -83,6 +84,9 @@ const DEFAULT_BIBLE_HIT_COOLDOWN: f32 = 1.5;
 /// Fallback orb collision radius while RON config is still loading.
 const DEFAULT_BIBLE_ORB_RADIUS: f32 = 12.0;
 
+/// Fallback knockback distance per orb hit while RON config is still loading.
+const DEFAULT_BIBLE_KNOCKBACK: f32 = 25.0;
+
 /// Visual radius of each orb circle mesh (pixels).
 const BIBLE_ORB_VISUAL_RADIUS: f32 = 12.0;
 
@@ -146,32 +150,10 @@ pub fn fire_bible(
         let level = event.level.clamp(1, 8) as usize;
 
         // --- Per-level stats (with config fallback) ---
-        let base_damage = cfg
-            .and_then(|c| c.damage_by_level.get(level - 1).copied())
-            .unwrap_or(DEFAULT_BIBLE_DAMAGE_BY_LEVEL[level - 1]);
-        let base_radius = cfg
-            .and_then(|c| c.orbit_radius_by_level.get(level - 1).copied())
-            .unwrap_or(DEFAULT_BIBLE_ORBIT_RADIUS_BY_LEVEL[level - 1]);
-        let base_speed = cfg
-            .and_then(|c| c.orbit_speed_by_level.get(level - 1).copied())
-            .unwrap_or(DEFAULT_BIBLE_ORBIT_SPEED_BY_LEVEL[level - 1]);
         let count_needed = cfg
             .and_then(|c| c.count_by_level.get(level - 1).copied())
-            .unwrap_or(DEFAULT_BIBLE_COUNT_BY_LEVEL[level - 1]);
-
-        let damage = base_damage * stats.damage_multiplier;
-        let speed_mult = if is_unholy {
-            DEFAULT_UNHOLY_VESPERS_SPEED_MULT
-        } else {
-            1.0
-        };
-        let radius_mult = if is_unholy {
-            DEFAULT_UNHOLY_VESPERS_RADIUS_MULT
-        } else {
-            1.0
-        };
-        let speed = base_speed * speed_mult;
-        let radius = base_radius * stats.area_multiplier * radius_mult;
+            .unwrap_or(DEFAULT_COUNT_BY_LEVEL[level - 1]);
+        let (damage, radius, speed) = orb_stats(cfg, event.weapon_type, event.level, stats);
 
         // --- Collect existing orbs for this player ---
         let existing: Vec<Entity> = orb_q
@@ -221,6 +203,79 @@ pub fn fire_bible(
     }
 }
 
+/// Re-derives damage, orbit radius and orbit speed on every [`BibleOrb`] when
+/// `bible.ron` is hot-reloaded, keeping the current orbit angles.
+///
+/// The level comes from the owning player's [`WeaponInventory`].  A changed
+/// orb count takes effect on the next [`fire_bible`].  Runs in all states so
+/// edits made while paused are not missed.
+pub fn hot_reload_bible_orbs(
+    mut events: MessageReader<AssetEvent<BibleConfig>>,
+    player_q: Query<(&PlayerStats, &WeaponInventory), With<Player>>,
+    mut orb_q: Query<(&BibleOrb, &mut OrbitWeapon)>,
+    bible_cfg: BibleParams,
+) {
+    let modified = events
+        .read()
+        .filter(|e| matches!(e, AssetEvent::Modified { .. }))
+        .count();
+    if modified == 0 {
+        return;
+    }
+
+    let cfg = bible_cfg.get();
+    for (orb, mut orb_weapon) in orb_q.iter_mut() {
+        let Ok((stats, inventory)) = player_q.get(orb.player) else {
+            continue;
+        };
+        let Some(weapon) = inventory
+            .weapons
+            .iter()
+            .find(|w| w.weapon_type == orb.weapon_type)
+        else {
+            continue;
+        };
+        let (damage, radius, speed) = orb_stats(cfg, weapon.weapon_type, weapon.level, stats);
+        orb_weapon.damage = damage;
+        orb_weapon.orbit_radius = radius;
+        orb_weapon.orbit_speed = speed;
+    }
+}
+
+/// Orb damage, orbit radius and orbit speed for `weapon_type` at `level`,
+/// after the player's damage and area multipliers (with config fallback).
+fn orb_stats(
+    cfg: Option<&BibleConfig>,
+    weapon_type: WeaponType,
+    level: u8,
+    stats: &PlayerStats,
+) -> (f32, f32, f32) {
+    let level = level.clamp(1, 8) as usize;
+    let base_damage = cfg
+        .and_then(|c| c.damage_by_level.get(level - 1).copied())
+        .unwrap_or(DEFAULT_DAMAGE_BY_LEVEL[level - 1]);
+    let base_radius = cfg
+        .and_then(|c| c.orbit_radius_by_level.get(level - 1).copied())
+        .unwrap_or(DEFAULT_ORBIT_RADIUS_BY_LEVEL[level - 1]);
+    let base_speed = cfg
+        .and_then(|c| c.orbit_speed_by_level.get(level - 1).copied())
+        .unwrap_or(DEFAULT_ORBIT_SPEED_BY_LEVEL[level - 1]);
+
+    let (speed_mult, radius_mult) = if weapon_type == WeaponType::UnholyVespers {
+        (
+            DEFAULT_UNHOLY_VESPERS_SPEED_MULT,
+            DEFAULT_UNHOLY_VESPERS_RADIUS_MULT,
+        )
+    } else {
+        (1.0, 1.0)
+    };
+    (
+        base_damage * stats.damage_multiplier,
+        base_radius * stats.area_multiplier * radius_mult,
+        base_speed * speed_mult,
+    )
+}
+
 /// Advances all [`BibleOrb`] orbital positions each frame and deals damage to
 /// overlapping enemies.
 ///
@@ -250,6 +305,7 @@ pub fn orbit_bible(
     let hit_cooldown_secs = cfg
         .map(|c| c.hit_cooldown_secs)
         .unwrap_or(DEFAULT_BIBLE_HIT_COOLDOWN);
+    let knockback = cfg.map(|c| c.knockback).unwrap_or(DEFAULT_BIBLE_KNOCKBACK);
 
     for (orb, mut orb_weapon, mut transform) in orb_q.iter_mut() {
         // --- Advance orbital angle ---
@@ -290,12 +346,14 @@ pub fn orbit_bible(
             let Ok(enemy_tf) = enemy_q.get(enemy_entity) else {
                 continue;
             };
-            let dist = (enemy_tf.translation.truncate() - orb_world_pos).length();
-            if dist <= orb_collision_radius {
+            let enemy_pos = enemy_tf.translation.truncate();
+            if (enemy_pos - orb_world_pos).length() <= orb_collision_radius {
                 damage_events.write(DamageEnemyEvent {
                     entity: enemy_entity,
                     damage: orb_weapon.damage,
                     weapon_type: orb.weapon_type,
+                    knockback: knockback_from(orb_world_pos, enemy_pos, knockback),
+                    status: None,
                 });
                 orb_weapon
                     .hit_cooldown
@@ -326,6 +384,80 @@ pub fn spawn_bible_visual(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+/// Bible: tomes orbiting the player.
+///
+/// The Bible orbits continuously; its cooldown only gates topping up the orb
+/// count after a level-up.
+pub const BIBLE: WeaponSpec = WeaponSpec {
+    weapon_type: WeaponType::Bible,
+    evolved: false,
+    name: LocalizedText {
+        en: "Bible",
+        ja: "聖書",
+    },
+    description: LocalizedText {
+        en: "Orbiting projectile that circles the player.",
+        ja: "プレイヤーの周りを旋回する飛翔体。",
+    },
+    abbrev: "Bi",
+    damage_type: DamageType::Magic,
+    fire_sound: None,
+    config_path: "config/weapons/bible.ron",
+    config_check: <BibleConfigLoader as CheckConfigFile>::check,
+    cooldown_by_level: [1.0; 8],
+    damage_by_level: DEFAULT_DAMAGE_BY_LEVEL,
+    count_by_level: DEFAULT_COUNT_BY_LEVEL,
+};
+
+/// Unholy Vespers (Bible + Spellbinder): faster, wider orbit.
+pub const UNHOLY_VESPERS: WeaponSpec = WeaponSpec {
+    weapon_type: WeaponType::UnholyVespers,
+    evolved: true,
+    name: LocalizedText {
+        en: "Unholy Vespers",
+        ja: "邪悪な晩課",
+    },
+    description: LocalizedText {
+        en: "Evolved Bible — infinite orbiting blades.",
+        ja: "進化形聖書 — 無限に旋回する刃。",
+    },
+    abbrev: "UV",
+    damage_type: DamageType::Magic,
+    fire_sound: None,
+    config_path: "config/weapons/bible.ron",
+    config_check: <BibleConfigLoader as CheckConfigFile>::check,
+    cooldown_by_level: [0.8; 8],
+    damage_by_level: [80.0; 8],
+    count_by_level: [3; 8],
+};
+
+/// Registers the Bible and Unholy Vespers: `bible.ron`, the orbit systems,
+/// the orb visual and the orb hot-reload.
+pub struct BiblePlugin;
+
+impl Plugin for BiblePlugin {
+    fn build(&self, app: &mut App) {
+        use crate::systems::spatial::update_spatial_grid;
+        register_weapon_config(app, BIBLE.config_path, BibleConfigLoader, BibleConfigHandle);
+        app.add_systems(
+            Update,
+            (
+                (
+                    fire_bible,
+                    orbit_bible.after(fire_bible).after(update_spatial_grid),
+                )
+                    .in_set(WeaponSystems::Fire),
+                spawn_bible_visual.in_set(WeaponSystems::Effects),
+                hot_reload_bible_orbs.before(orbit_bible),
+            ),
+        );
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -553,7 +685,7 @@ mod tests {
             .next()
             .expect("orb should exist")
             .orbit_speed;
-        let expected = DEFAULT_BIBLE_ORBIT_SPEED_BY_LEVEL[0] * DEFAULT_UNHOLY_VESPERS_SPEED_MULT;
+        let expected = DEFAULT_ORBIT_SPEED_BY_LEVEL[0] * DEFAULT_UNHOLY_VESPERS_SPEED_MULT;
         assert!(
             (speed - expected).abs() < 1e-5,
             "UnholyVespers speed should be {expected}, got {speed}"
@@ -575,7 +707,7 @@ mod tests {
             .next()
             .expect("orb should exist")
             .orbit_radius;
-        let expected = DEFAULT_BIBLE_ORBIT_RADIUS_BY_LEVEL[0] * DEFAULT_UNHOLY_VESPERS_RADIUS_MULT;
+        let expected = DEFAULT_ORBIT_RADIUS_BY_LEVEL[0] * DEFAULT_UNHOLY_VESPERS_RADIUS_MULT;
         assert!(
             (radius - expected).abs() < 1e-5,
             "UnholyVespers radius should be {expected}, got {radius}"
@@ -772,16 +904,16 @@ mod tests {
     /// Damage increases with level.
     #[test]
     fn bible_damage_increases_with_level() {
-        let lv1 = DEFAULT_BIBLE_DAMAGE_BY_LEVEL[0];
-        let lv8 = DEFAULT_BIBLE_DAMAGE_BY_LEVEL[7];
+        let lv1 = DEFAULT_DAMAGE_BY_LEVEL[0];
+        let lv8 = DEFAULT_DAMAGE_BY_LEVEL[7];
         assert!(lv8 > lv1, "Lv8 damage ({lv8}) should exceed Lv1 ({lv1})");
     }
 
     /// Orbit radius increases with level.
     #[test]
     fn bible_radius_increases_with_level() {
-        let lv1 = DEFAULT_BIBLE_ORBIT_RADIUS_BY_LEVEL[0];
-        let lv8 = DEFAULT_BIBLE_ORBIT_RADIUS_BY_LEVEL[7];
+        let lv1 = DEFAULT_ORBIT_RADIUS_BY_LEVEL[0];
+        let lv8 = DEFAULT_ORBIT_RADIUS_BY_LEVEL[7];
         assert!(lv8 > lv1, "Lv8 radius ({lv8}) should exceed Lv1 ({lv1})");
     }
 
@@ -810,4 +942,66 @@ mod tests {
             "area_multiplier=2 should double the orbit radius, got {orb_radius}"
         );
     }
+
+    /// Reloading `bible.ron` re-derives live orbs from the player's current
+    /// weapon level without resetting their orbit angle.
+    #[test]
+    fn config_reload_rederives_orbs() {
+        let mut app = build_app();
+        app.add_message::<AssetEvent<BibleConfig>>();
+        let player = spawn_player(&mut app);
+        fire_once(&mut app, player, WeaponType::Bible, 1);
+
+        let orb = app
+            .world_mut()
+            .query_filtered::<Entity, With<BibleOrb>>()
+            .single(app.world())
+            .expect("one orb");
+        app.world_mut()
+            .get_mut::<OrbitWeapon>(orb)
+            .unwrap()
+            .orbit_angle = 1.0;
+        app.world_mut()
+            .get_mut::<WeaponInventory>(player)
+            .unwrap()
+            .weapons[0]
+            .level = 8;
+        app.world_mut()
+            .write_message(AssetEvent::<BibleConfig>::Modified {
+                id: AssetId::default(),
+            });
+        app.world_mut()
+            .run_system_once(hot_reload_bible_orbs)
+            .expect("hot_reload_bible_orbs should run");
+
+        let orb_weapon = app.world().get::<OrbitWeapon>(orb).unwrap();
+        assert_eq!(orb_weapon.damage, DEFAULT_DAMAGE_BY_LEVEL[7]);
+        assert_eq!(orb_weapon.orbit_radius, DEFAULT_ORBIT_RADIUS_BY_LEVEL[7]);
+        assert_eq!(orb_weapon.orbit_speed, DEFAULT_ORBIT_SPEED_BY_LEVEL[7]);
+        assert_eq!(orb_weapon.orbit_angle, 1.0, "orbit angle is kept");
+    }
+
+    /// The Bible spec's damage and orb count match the tables in `bible.ron`.
+    #[test]
+    fn bible_spec_matches_ron_config() {
+        use crate::config::weapon::bible::{BibleConfig, BibleConfigPartial};
+        let partial: BibleConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(include_str!(
+                "../../../../vampire-survivors/assets/config/weapons/bible.ron"
+            ))
+            .expect("bible.ron should parse");
+        let cfg = BibleConfig::from(partial);
+        for (i, &expected) in cfg.damage_by_level.iter().enumerate() {
+            assert_eq!(
+                BIBLE.base_damage(i as u8 + 1),
+                expected,
+                "Bible lv{}",
+                i + 1
+            );
+        }
+        for (i, &expected) in cfg.count_by_level.iter().enumerate() {
+            assert_eq!(BIBLE.base_count(i as u8 + 1), expected, "Bible lv{}", i + 1);
+        }
+    }
 }
@@ -3,13 +3,14 @@
 //! [`tick_weapon_cooldowns`] runs every frame in [`AppState::Playing`] and
 //! decrements the `cooldown_timer` of every weapon in the player's inventory.
 //! When a timer reaches zero the system emits a [`WeaponFiredEvent`]; weapon-
-//! specific fire systems (tasks 4.4 / 4.5) consume that event to spawn the
-//! correct projectiles or effects.
+//! specific fire systems (in [`WeaponSystems::Fire`]) consume that event to
+//! spawn the correct projectiles or effects.
 //!
 //! ## Cooldown reduction
 //!
 //! The timer is decremented by raw `delta_secs` each frame, but the reset
-//! value after firing uses the player's `cooldown_reduction` stat:
+//! value after firing uses the per-level cooldown from the weapon's
+//! [`WeaponSpec`] and the player's `cooldown_reduction` stat:
 //!
 //! ```text
 //! reset = base_cooldown × (1 − cooldown_reduction).clamp(0.1, 1.0)
@@ -17,12 +18,16 @@
 //!
 //! Using `+=` (additive reset) instead of `=` keeps the fire rate accurate
 //! even when a single frame is longer than the cooldown (fast-forward robustness).
+//!
+//! [`WeaponSystems::Fire`]: super::WeaponSystems::Fire
+//! [`WeaponSpec`]: super::registry::WeaponSpec
 
 use bevy::prelude::*;
 
 use crate::{
     components::{Player, PlayerStats, WeaponInventory},
     events::WeaponFiredEvent,
+    systems::weapons::registry::WeaponRegistry,
 };
 
 // ---------------------------------------------------------------------------
@@ -33,20 +38,26 @@ use crate::{
 ///
 /// - Requires the player entity to carry [`WeaponInventory`] and [`PlayerStats`].
 /// - If the player is absent the system is a no-op.
+/// - Weapons missing from the [`WeaponRegistry`] are skipped — without a
+///   registered plugin nothing would consume their fire events.
 /// - The cooldown timer is reset additively so that frames slower than the
 ///   cooldown do not silently swallow extra fire ticks.
 pub fn tick_weapon_cooldowns(
     time: Res<Time>,
     mut player_q: Query<(Entity, &mut WeaponInventory, &PlayerStats), With<Player>>,
+    registry: Res<WeaponRegistry>,
     mut fired_events: MessageWriter<WeaponFiredEvent>,
 ) {
     let delta = time.delta_secs();
 
     for (player_entity, mut inventory, stats) in player_q.iter_mut() {
         for weapon in inventory.weapons.iter_mut() {
+            let Some(spec) = registry.get(weapon.weapon_type) else {
+                continue;
+            };
             weapon.cooldown_timer -= delta;
 
-            let reset = weapon.effective_cooldown(stats.cooldown_reduction);
+            let reset = spec.effective_cooldown(weapon.level, stats.cooldown_reduction);
             while weapon.cooldown_timer <= 0.0 {
                 fired_events.write(WeaponFiredEvent {
                     player: player_entity,
@@ -79,9 +90,18 @@ mod tests {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
         app.add_message::<WeaponFiredEvent>();
+        app.insert_resource(WeaponRegistry::with_builtin_weapons());
         app
     }
 
+    /// Level-1 base cooldown of `weapon_type` from the built-in registry.
+    fn base_cooldown(weapon_type: WeaponType) -> f32 {
+        WeaponRegistry::with_builtin_weapons()
+            .get(weapon_type)
+            .expect("built-in weapon should be registered")
+            .base_cooldown(1)
+    }
+
     fn advance_and_run(app: &mut App, delta_secs: f32) {
         use std::time::Duration;
         app.world_mut()
@@ -130,7 +150,7 @@ mod tests {
         let mut app = build_app();
 
         let mut weapon = crate::types::WeaponState::new(WeaponType::MagicWand);
-        let base = weapon.base_cooldown();
+        let base = base_cooldown(WeaponType::MagicWand);
         // Set timer to base cooldown so it won't fire this tick.
         weapon.cooldown_timer = base;
 
@@ -186,7 +206,7 @@ mod tests {
             .query_filtered::<&WeaponInventory, With<Player>>();
         let inv = q.single(app.world()).expect("player should exist");
         let timer = inv.weapons[0].cooldown_timer;
-        let base = crate::types::WeaponState::new(WeaponType::MagicWand).base_cooldown();
+        let base = base_cooldown(WeaponType::MagicWand);
 
         assert!(
             timer > 0.0,
@@ -204,7 +224,7 @@ mod tests {
         let mut app = build_app();
 
         let weapon = crate::types::WeaponState::new(WeaponType::Whip);
-        let base = weapon.base_cooldown();
+        let base = base_cooldown(WeaponType::Whip);
 
         let mut stats = PlayerStats::default();
         stats.cooldown_reduction = 0.5;
@@ -261,6 +281,18 @@ mod tests {
         );
     }
 
+    /// A weapon without a registered spec never fires.
+    #[test]
+    fn unregistered_weapon_is_skipped() {
+        let mut app = build_app();
+        app.insert_resource(WeaponRegistry::default());
+        spawn_player_with_weapon(&mut app, WeaponType::Whip);
+
+        advance_and_run(&mut app, 1.0 / 60.0);
+
+        assert_eq!(fired_count(&app), 0);
+    }
+
     /// No player entity → system is a no-op.
     #[test]
     fn no_player_no_event() {
@@ -1,5 +1,9 @@
 //! Cross weapon — boomerang projectile that reverses at max range.
 //!
+//! Its evolution HeavenSword (Cross Lv8 + Clover) is fired by
+//! [`fire_heaven_sword`]: stronger, longer-ranged boomerangs thrown in every
+//! direction at once, sharing the same [`CrossBoomerang`] return logic.
+//!
 //! The Cross fires one or more projectiles aimed at the direction the player
 //! is currently facing.  Each projectile travels outward until it reaches
 //! [`CrossBoomerang::max_range`] pixels from its spawn point, then reverses
@@ -30,34 +34,46 @@
 //!
 //! [`move_projectiles`]: crate::systems::projectiles::move_projectiles
 
+use std::f32::consts::TAU;
+
 use bevy::prelude::*;
 
 use crate::{
     components::{Player, PlayerFacingDirection, PlayerStats, Projectile, ProjectileVelocity},
-    config::weapon::cross::CrossParams,
+    config::{
+        register_weapon_config,
+        validate::CheckConfigFile,
+        weapon::cross::{
+            CrossConfigHandle, CrossConfigLoader, CrossParams, DEFAULT_COUNT_BY_LEVEL,
+            DEFAULT_DAMAGE_BY_LEVEL, DEFAULT_HEAVEN_SWORD_COUNT, DEFAULT_HEAVEN_SWORD_DAMAGE,
+            DEFAULT_MAX_RANGE_BY_LEVEL, DEFAULT_SPEED_BY_LEVEL,
+        },
+    },
     events::WeaponFiredEvent,
-    systems::projectiles::spawn_projectile,
-    types::WeaponType,
+    systems::{
+        projectiles::spawn_projectile,
+        weapons::{
+            WeaponSystems,
+            registry::{LocalizedText, WeaponSpec},
+        },
+    },
+    types::{DamageType, WeaponSound, WeaponType},
 };
 
 // ---------------------------------------------------------------------------
 // Fallback constants (used while RON config is still loading)
 // ---------------------------------------------------------------------------
 
-/// Damage per hit at each weapon level while RON config is loading.
-const DEFAULT_CROSS_DAMAGE_BY_LEVEL: [f32; 8] = [50.0, 60.0, 70.0, 80.0, 90.0, 110.0, 130.0, 160.0];
-/// Projectile speed (px/s) at each weapon level while RON config is loading.
-const DEFAULT_CROSS_SPEED_BY_LEVEL: [f32; 8] =
-    [300.0, 320.0, 340.0, 360.0, 380.0, 400.0, 430.0, 460.0];
-/// Maximum outbound range (px) at each weapon level while RON config is loading.
-const DEFAULT_CROSS_MAX_RANGE_BY_LEVEL: [f32; 8] =
-    [150.0, 160.0, 175.0, 190.0, 205.0, 220.0, 235.0, 250.0];
-/// Number of projectiles per activation at each weapon level while RON config is loading.
-const DEFAULT_CROSS_COUNT_BY_LEVEL: [u32; 8] = [1, 1, 1, 1, 2, 2, 2, 2];
+/// HeavenSword projectile speed (px/s) while RON config is loading.
+const DEFAULT_HEAVEN_SWORD_SPEED: f32 = 500.0;
+/// HeavenSword outbound range (px) while RON config is loading.
+const DEFAULT_HEAVEN_SWORD_MAX_RANGE: f32 = 320.0;
 /// Angular gap between projectiles in the fan (degrees).
 const DEFAULT_CROSS_SPREAD_ANGLE_DEG: f32 = 30.0;
 /// Circle collider radius for hit detection (pixels).
 const DEFAULT_CROSS_COLLIDER_RADIUS: f32 = 8.0;
+/// Knockback distance per hit for Cross and HeavenSword (pixels).
+const DEFAULT_CROSS_KNOCKBACK: f32 = 15.0;
 
 /// Piercing value for cross projectiles.
 ///
@@ -69,7 +85,8 @@ const CROSS_PIERCING: u32 = u32::MAX;
 // Component
 // ---------------------------------------------------------------------------
 
-/// Marker component attached to every projectile spawned by [`fire_cross`].
+/// Marker component attached to every projectile spawned by [`fire_cross`]
+/// and [`fire_heaven_sword`].
 ///
 /// [`update_cross`] reads this each frame to decide when to reverse the
 /// projectile's velocity.
@@ -113,6 +130,7 @@ pub fn fire_cross(
     let collider_r = cfg
         .map(|c| c.collider_radius)
         .unwrap_or(DEFAULT_CROSS_COLLIDER_RADIUS);
+    let knockback = cfg.map(|c| c.knockback).unwrap_or(DEFAULT_CROSS_KNOCKBACK);
 
     for event in fired_events.read() {
         if event.weapon_type != WeaponType::Cross {
@@ -128,21 +146,21 @@ pub fn fire_cross(
 
         let damage = cfg
             .and_then(|c| c.damage_by_level.get(level - 1).copied())
-            .unwrap_or(DEFAULT_CROSS_DAMAGE_BY_LEVEL[level - 1])
+            .unwrap_or(DEFAULT_DAMAGE_BY_LEVEL[level - 1])
             * stats.damage_multiplier;
 
         let speed = cfg
             .and_then(|c| c.speed_by_level.get(level - 1).copied())
-            .unwrap_or(DEFAULT_CROSS_SPEED_BY_LEVEL[level - 1])
+            .unwrap_or(DEFAULT_SPEED_BY_LEVEL[level - 1])
             * stats.projectile_speed_mult;
 
         let max_range = cfg
             .and_then(|c| c.max_range_by_level.get(level - 1).copied())
-            .unwrap_or(DEFAULT_CROSS_MAX_RANGE_BY_LEVEL[level - 1]);
+            .unwrap_or(DEFAULT_MAX_RANGE_BY_LEVEL[level - 1]);
 
         let count = cfg
             .and_then(|c| c.count_by_level.get(level - 1).copied())
-            .unwrap_or(DEFAULT_CROSS_COUNT_BY_LEVEL[level - 1])
+            .unwrap_or(DEFAULT_COUNT_BY_LEVEL[level - 1])
             + stats.extra_projectiles;
 
         // Lifetime = round-trip time with a small buffer so the projectile
@@ -163,6 +181,78 @@ pub fn fire_cross(
                 player_pos,
                 velocity,
                 damage,
+                knockback,
+                lifetime,
+                CROSS_PIERCING,
+                collider_r,
+                event.weapon_type,
+            );
+            commands.entity(entity).insert(CrossBoomerang {
+                spawn_pos: player_pos,
+                max_range,
+                returning: false,
+            });
+        }
+    }
+}
+
+/// Fires HeavenSword boomerangs when a [`WeaponFiredEvent`] for
+/// [`WeaponType::HeavenSword`] arrives.
+///
+/// `heaven_sword_count + extra_projectiles` boomerangs are spread evenly over
+/// a full circle, the first aimed at [`PlayerFacingDirection`].  Damage, speed
+/// and range are fixed (the evolution is always at max level) and scaled by
+/// the same player multipliers as [`fire_cross`].
+pub fn fire_heaven_sword(
+    mut fired_events: MessageReader<WeaponFiredEvent>,
+    mut commands: Commands,
+    player_q: Query<(&Transform, &PlayerStats, &PlayerFacingDirection), With<Player>>,
+    cross_cfg: CrossParams,
+) {
+    let cfg = cross_cfg.get();
+    let base_damage = cfg
+        .map(|c| c.heaven_sword_damage)
+        .unwrap_or(DEFAULT_HEAVEN_SWORD_DAMAGE);
+    let base_speed = cfg
+        .map(|c| c.heaven_sword_speed)
+        .unwrap_or(DEFAULT_HEAVEN_SWORD_SPEED);
+    let max_range = cfg
+        .map(|c| c.heaven_sword_max_range)
+        .unwrap_or(DEFAULT_HEAVEN_SWORD_MAX_RANGE);
+    let base_count = cfg
+        .map(|c| c.heaven_sword_count)
+        .unwrap_or(DEFAULT_HEAVEN_SWORD_COUNT);
+    let collider_r = cfg
+        .map(|c| c.collider_radius)
+        .unwrap_or(DEFAULT_CROSS_COLLIDER_RADIUS);
+    let knockback = cfg.map(|c| c.knockback).unwrap_or(DEFAULT_CROSS_KNOCKBACK);
+
+    for event in fired_events.read() {
+        if event.weapon_type != WeaponType::HeavenSword {
+            continue;
+        }
+
+        let Ok((player_tf, stats, facing)) = player_q.get(event.player) else {
+            continue;
+        };
+
+        let player_pos = player_tf.translation.truncate();
+        let damage = base_damage * stats.damage_multiplier;
+        let speed = base_speed * stats.projectile_speed_mult;
+        let count = (base_count + stats.extra_projectiles).max(1);
+        let lifetime = 2.0 * max_range / speed + 0.5;
+        let base_dir = facing.0.normalize_or(Vec2::X);
+
+        for i in 0..count {
+            let angle = TAU * i as f32 / count as f32;
+            let velocity = rotate_vec2(base_dir, angle) * speed;
+
+            let entity = spawn_projectile(
+                &mut commands,
+                player_pos,
+                velocity,
+                damage,
+                knockback,
                 lifetime,
                 CROSS_PIERCING,
                 collider_r,
@@ -218,6 +308,77 @@ fn rotate_vec2(v: Vec2, angle: f32) -> Vec2 {
     Vec2::new(v.x * cos - v.y * sin, v.x * sin + v.y * cos)
 }
 
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+/// Cross: boomerang thrown in the facing direction.
+pub const CROSS: WeaponSpec = WeaponSpec {
+    weapon_type: WeaponType::Cross,
+    evolved: false,
+    name: LocalizedText {
+        en: "Cross",
+        ja: "クロス",
+    },
+    description: LocalizedText {
+        en: "Boomerang that flies out and returns.",
+        ja: "飛んで戻ってくるブーメラン。",
+    },
+    abbrev: "Cr",
+    damage_type: DamageType::Holy,
+    fire_sound: Some(WeaponSound::ProjectileLarge),
+    config_path: "config/weapons/cross.ron",
+    config_check: <CrossConfigLoader as CheckConfigFile>::check,
+    cooldown_by_level: [1.5, 1.3, 1.3, 1.2, 1.1, 1.0, 0.9, 0.8],
+    damage_by_level: DEFAULT_DAMAGE_BY_LEVEL,
+    count_by_level: DEFAULT_COUNT_BY_LEVEL,
+};
+
+/// Heaven Sword (Cross + Clover): boomerangs thrown in every direction.
+pub const HEAVEN_SWORD: WeaponSpec = WeaponSpec {
+    weapon_type: WeaponType::HeavenSword,
+    evolved: true,
+    name: LocalizedText {
+        en: "Heaven Sword",
+        ja: "天の剣",
+    },
+    description: LocalizedText {
+        en: "Evolved Cross — swords thrown in every direction.",
+        ja: "進化形クロス — 全方位に飛ぶ聖剣。",
+    },
+    abbrev: "HS",
+    damage_type: DamageType::Holy,
+    fire_sound: Some(WeaponSound::ProjectileLarge),
+    config_path: "config/weapons/cross.ron",
+    config_check: <CrossConfigLoader as CheckConfigFile>::check,
+    cooldown_by_level: [0.7; 8],
+    damage_by_level: [DEFAULT_HEAVEN_SWORD_DAMAGE; 8],
+    count_by_level: [DEFAULT_HEAVEN_SWORD_COUNT; 8],
+};
+
+/// Registers the Cross and Heaven Sword: `cross.ron`, their fire systems and
+/// the boomerang return.
+pub struct CrossPlugin;
+
+impl Plugin for CrossPlugin {
+    fn build(&self, app: &mut App) {
+        use crate::systems::projectiles::{
+            collision::projectile_enemy_collision, move_projectiles,
+        };
+        register_weapon_config(app, CROSS.config_path, CrossConfigLoader, CrossConfigHandle);
+        app.add_systems(
+            Update,
+            (
+                (fire_cross, fire_heaven_sword).in_set(WeaponSystems::Fire),
+                update_cross
+                    .after(move_projectiles)
+                    .before(projectile_enemy_collision)
+                    .in_set(WeaponSystems::Effects),
+            ),
+        );
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -232,6 +393,7 @@ mod tests {
     use crate::{
         components::{Projectile, ProjectileVelocity, WeaponInventory},
         events::WeaponFiredEvent,
+        systems::weapons::registry::WeaponRegistry,
         types::{WeaponState, WeaponType},
     };
 
@@ -243,6 +405,7 @@ mod tests {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
         app.add_message::<WeaponFiredEvent>();
+        app.insert_resource(WeaponRegistry::with_builtin_weapons());
         app
     }
 
@@ -303,6 +466,7 @@ mod tests {
                     hit_enemies: Vec::new(),
                     lifetime: 5.0,
                     weapon_type: WeaponType::Cross,
+                    knockback: 0.0,
                 },
                 ProjectileVelocity(velocity),
                 CrossBoomerang {
@@ -481,6 +645,68 @@ mod tests {
         );
     }
 
+    // -----------------------------------------------------------------------
+    // fire_heaven_sword tests
+    // -----------------------------------------------------------------------
+
+    /// HeavenSword throws boomerangs in every direction.
+    #[test]
+    fn fire_heaven_sword_spreads_boomerangs_over_full_circle() {
+        let mut app = build_app();
+        let player = spawn_player(&mut app);
+
+        app.world_mut().write_message(WeaponFiredEvent {
+            player,
+            weapon_type: WeaponType::HeavenSword,
+            level: 8,
+        });
+        app.world_mut()
+            .run_system_once(fire_heaven_sword)
+            .expect("fire_heaven_sword should run");
+        app.world_mut().flush();
+
+        let velocities = app
+            .world_mut()
+            .query::<(&ProjectileVelocity, &CrossBoomerang)>()
+            .iter(app.world())
+            .map(|(v, _)| v.0)
+            .collect::<Vec<_>>();
+        assert_eq!(velocities.len(), DEFAULT_HEAVEN_SWORD_COUNT as usize);
+        assert!(velocities.iter().any(|v| v.x > 0.0), "one flies right");
+        assert!(velocities.iter().any(|v| v.x < 0.0), "one flies left");
+        let sum: Vec2 = velocities.iter().sum();
+        assert!(sum.length() < 1e-2, "evenly spread, got sum = {sum:?}");
+    }
+
+    /// Each fire system only handles its own weapon type.
+    #[test]
+    fn cross_and_heaven_sword_ignore_each_other() {
+        let mut app = build_app();
+        let player = spawn_player(&mut app);
+        for weapon_type in [WeaponType::Cross, WeaponType::HeavenSword] {
+            app.world_mut().write_message(WeaponFiredEvent {
+                player,
+                weapon_type,
+                level: 8,
+            });
+        }
+
+        app.world_mut()
+            .run_system_once(fire_cross)
+            .expect("fire_cross should run");
+        app.world_mut().flush();
+        assert_eq!(projectile_count(&mut app), 2, "Lv8 Cross fires 2");
+
+        app.world_mut()
+            .run_system_once(fire_heaven_sword)
+            .expect("fire_heaven_sword should run");
+        app.world_mut().flush();
+        assert_eq!(
+            projectile_count(&mut app),
+            2 + DEFAULT_HEAVEN_SWORD_COUNT as usize
+        );
+    }
+
     // -----------------------------------------------------------------------
     // update_cross tests
     // -----------------------------------------------------------------------
@@ -646,4 +872,28 @@ mod tests {
         assert!((rotated.x).abs() < 1e-6, "x should be ~0");
         assert!((rotated.y - 1.0).abs() < 1e-6, "y should be ~1");
     }
+
+    /// The Cross spec's damage and count match the tables in `cross.ron`.
+    #[test]
+    fn cross_spec_matches_ron_config() {
+        use crate::config::weapon::cross::{CrossConfig, CrossConfigPartial};
+        let partial: CrossConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(include_str!(
+                "../../../../vampire-survivors/assets/config/weapons/cross.ron"
+            ))
+            .expect("cross.ron should parse");
+        let cfg = CrossConfig::from(partial);
+        for (i, &expected) in cfg.damage_by_level.iter().enumerate() {
+            assert_eq!(
+                CROSS.base_damage(i as u8 + 1),
+                expected,
+                "Cross lv{}",
+                i + 1
+            );
+        }
+        for (i, &expected) in cfg.count_by_level.iter().enumerate() {
+            assert_eq!(CROSS.base_count(i as u8 + 1), expected, "Cross lv{}", i + 1);
+        }
+    }
 }
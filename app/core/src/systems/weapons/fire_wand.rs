@@ -31,10 +31,19 @@
 //! the first contact it:
 //! 1. Emits a [`DamageEnemyEvent`] for the struck enemy (full damage).
 //! 2. Queries the [`SpatialGrid`] and emits [`DamageEnemyEvent`] for every
-//!    other enemy within the AoE radius (reduced damage).
+//!    other enemy within the AoE radius (reduced damage).  Both hits push
+//!    their target away from the impact by the configured `knockback` and
+//!    set it on fire with a [`StatusEffect::burn`].
 //! 3. Spawns a short-lived [`FireballExplosionEffect`] visual at the hit point.
 //! 4. Despawns the fireball entity.
 //!
+//! ## Hellfire
+//!
+//! The evolution Hellfire (Fire Wand Lv8 + Spinach) is fired by
+//! [`fire_hellfire`]: a fan of larger fireballs aimed at the same highest-HP
+//! target, each with its own bigger explosion.  Hellfire fireballs reuse
+//! [`FireballProjectile`], so movement, explosion and expiry are shared.
+//!
 //! [`move_fireballs`]: self::move_fireballs
 
 use bevy::prelude::*;
@@ -42,27 +51,34 @@ use bevy::prelude::*;
 use crate::{
     components::{
         CircleCollider, Enemy, GameSessionEntity, Player, PlayerStats, ProjectileVelocity,
+        StatusEffect,
+    },
+    config::{
+        register_weapon_config,
+        validate::CheckConfigFile,
+        weapon::fire_wand::{
+            DEFAULT_AOE_DAMAGE_BY_LEVEL, DEFAULT_AOE_RADIUS_BY_LEVEL, DEFAULT_DAMAGE_BY_LEVEL,
+            DEFAULT_HELLFIRE_COUNT, DEFAULT_HELLFIRE_DAMAGE, FireWandConfigHandle,
+            FireWandConfigLoader, FireWandParams,
+        },
     },
-    config::weapon::fire_wand::FireWandParams,
     events::{DamageEnemyEvent, WeaponFiredEvent},
     resources::SpatialGrid,
-    systems::collision::check_circle_collision,
-    types::WeaponType,
+    systems::{
+        collision::check_circle_collision,
+        damage::knockback_from,
+        weapons::{
+            WeaponSystems,
+            registry::{LocalizedText, WeaponSpec},
+        },
+    },
+    types::{DamageType, WeaponSound, WeaponType},
 };
 
 // ---------------------------------------------------------------------------
 // Fallback constants (used while RON config is still loading)
 // ---------------------------------------------------------------------------
 
-/// Direct hit damage per level (index 0 = level 1).
-const DEFAULT_FIRE_WAND_DAMAGE_BY_LEVEL: [f32; 8] =
-    [80.0, 100.0, 120.0, 150.0, 180.0, 220.0, 270.0, 330.0];
-/// AoE explosion damage per level.
-const DEFAULT_FIRE_WAND_AOE_DAMAGE_BY_LEVEL: [f32; 8] =
-    [40.0, 50.0, 60.0, 75.0, 90.0, 110.0, 135.0, 165.0];
-/// AoE explosion radius (pixels) per level.
-const DEFAULT_FIRE_WAND_AOE_RADIUS_BY_LEVEL: [f32; 8] =
-    [80.0, 90.0, 100.0, 110.0, 120.0, 130.0, 140.0, 150.0];
 /// Fireball travel speed in pixels/second.
 const DEFAULT_FIRE_WAND_SPEED: f32 = 250.0;
 /// Fireball lifetime in seconds before automatic despawn.
@@ -75,14 +91,33 @@ const DEFAULT_FIRE_WAND_EXPLOSION_DURATION: f32 = 0.3;
 const DEFAULT_FIRE_WAND_EXPLOSION_COLOR: (f32, f32, f32, f32) = (1.0, 0.4, 0.1, 0.8);
 /// Z-depth of the explosion visual.
 const DEFAULT_FIRE_WAND_EXPLOSION_Z: f32 = 7.0;
+/// Hellfire AoE explosion damage while RON config is loading.
+const DEFAULT_HELLFIRE_AOE_DAMAGE: f32 = 200.0;
+/// Hellfire explosion radius (pixels) while RON config is loading.
+const DEFAULT_HELLFIRE_AOE_RADIUS: f32 = 200.0;
+/// Angular gap between Hellfire fireballs (degrees) while RON config is loading.
+const DEFAULT_HELLFIRE_SPREAD_ANGLE_DEG: f32 = 20.0;
+/// Hellfire fireball collider radius while RON config is loading.
+const DEFAULT_HELLFIRE_COLLIDER_RADIUS: f32 = 20.0;
+/// Knockback distance for direct and AoE hits, away from the impact (pixels).
+const DEFAULT_FIRE_WAND_KNOCKBACK: f32 = 35.0;
+/// Burn damage per second inflicted by every fireball hit.
+const DEFAULT_FIRE_WAND_BURN_DPS: f32 = 15.0;
+/// Seconds the burn lasts.
+const DEFAULT_FIRE_WAND_BURN_DURATION: f32 = 3.0;
 /// Fireball sprite z-depth.
 const FIRE_WAND_PROJECTILE_Z: f32 = 5.5;
+/// Fire Wand fireball colour (orange-red placeholder).
+const FIREBALL_COLOR: Color = Color::srgb(1.0, 0.4, 0.1);
+/// Hellfire fireball colour (deep red placeholder).
+const HELLFIRE_COLOR: Color = Color::srgb(0.9, 0.1, 0.05);
 
 // ---------------------------------------------------------------------------
 // Components
 // ---------------------------------------------------------------------------
 
-/// Carried by every fireball entity spawned by [`fire_fire_wand`].
+/// Carried by every fireball entity spawned by [`fire_fire_wand`] and
+/// [`fire_hellfire`].
 ///
 /// Because fireballs explode on impact rather than piercing, they do not use
 /// the standard [`Projectile`](crate::components::Projectile) component.
@@ -98,6 +133,8 @@ pub struct FireballProjectile {
     pub aoe_radius: f32,
     /// Remaining lifetime in seconds; the fireball auto-despawns at zero.
     pub lifetime: f32,
+    /// Weapon credited with the explosion damage (`FireWand` or `Hellfire`).
+    pub weapon_type: WeaponType,
 }
 
 /// Short-lived explosion visual spawned by [`fireball_enemy_collision`].
@@ -152,63 +189,158 @@ pub fn fire_fire_wand(
 
         let damage = cfg
             .and_then(|c| c.damage_by_level.get(level - 1).copied())
-            .unwrap_or(DEFAULT_FIRE_WAND_DAMAGE_BY_LEVEL[level - 1])
+            .unwrap_or(DEFAULT_DAMAGE_BY_LEVEL[level - 1])
             * stats.damage_multiplier;
 
         let aoe_damage = cfg
             .and_then(|c| c.aoe_damage_by_level.get(level - 1).copied())
-            .unwrap_or(DEFAULT_FIRE_WAND_AOE_DAMAGE_BY_LEVEL[level - 1])
+            .unwrap_or(DEFAULT_AOE_DAMAGE_BY_LEVEL[level - 1])
             * stats.damage_multiplier;
 
         let aoe_radius = cfg
             .and_then(|c| c.aoe_radius_by_level.get(level - 1).copied())
-            .unwrap_or(DEFAULT_FIRE_WAND_AOE_RADIUS_BY_LEVEL[level - 1])
+            .unwrap_or(DEFAULT_AOE_RADIUS_BY_LEVEL[level - 1])
             * stats.area_multiplier;
 
-        // Target the enemy with the highest current HP; skip any enemy
-        // exactly on the player so direction is always non-zero.
-        let target = enemy_q
-            .iter()
-            .filter(|(tf, _)| tf.translation.truncate().distance_squared(player_pos) > f32::EPSILON)
-            .max_by(|(_, a), (_, b)| {
-                a.current_hp
-                    .partial_cmp(&b.current_hp)
-                    .unwrap_or(std::cmp::Ordering::Equal)
-            });
-
-        let Some((target_tf, _)) = target else {
+        let Some(dir) = highest_hp_direction(&enemy_q, player_pos) else {
             continue; // no targetable enemies
         };
 
-        let target_pos = target_tf.translation.truncate();
-        let dir = (target_pos - player_pos).normalize_or_zero();
-        if dir == Vec2::ZERO {
-            continue;
-        }
-
-        let velocity = dir * speed * stats.projectile_speed_mult;
-
-        commands.spawn((
-            GameSessionEntity,
+        spawn_fireball(
+            &mut commands,
+            player_pos,
+            dir * speed * stats.projectile_speed_mult,
             FireballProjectile {
                 damage,
                 aoe_damage,
                 aoe_radius,
                 lifetime,
+                weapon_type: event.weapon_type,
             },
-            ProjectileVelocity(velocity),
-            CircleCollider { radius: collider_r },
-            // Orange-red fireball placeholder sprite.
-            Sprite {
-                color: Color::srgb(1.0, 0.4, 0.1),
-                custom_size: Some(Vec2::splat(collider_r * 2.0)),
-                ..default()
-            },
-            Transform::from_xyz(player_pos.x, player_pos.y, FIRE_WAND_PROJECTILE_Z),
-        ));
+            collider_r,
+            FIREBALL_COLOR,
+        );
+    }
+}
+
+/// Fires a fan of Hellfire fireballs when a [`WeaponFiredEvent`] for
+/// [`WeaponType::Hellfire`] arrives.
+///
+/// `hellfire_count + extra_projectiles` fireballs are spread
+/// `hellfire_spread_angle_deg` apart, centred on the highest-HP enemy.
+/// Damage and explosion size are fixed (the evolution is always at max level)
+/// and scaled by the same player multipliers as [`fire_fire_wand`].
+pub fn fire_hellfire(
+    mut fired_events: MessageReader<WeaponFiredEvent>,
+    mut commands: Commands,
+    player_q: Query<(&Transform, &PlayerStats), With<Player>>,
+    enemy_q: Query<(&Transform, &Enemy)>,
+    fire_wand_cfg: FireWandParams,
+) {
+    let cfg = fire_wand_cfg.get();
+    let speed = cfg.map(|c| c.speed).unwrap_or(DEFAULT_FIRE_WAND_SPEED);
+    let lifetime = cfg
+        .map(|c| c.lifetime)
+        .unwrap_or(DEFAULT_FIRE_WAND_LIFETIME);
+    let base_damage = cfg
+        .map(|c| c.hellfire_damage)
+        .unwrap_or(DEFAULT_HELLFIRE_DAMAGE);
+    let base_aoe_damage = cfg
+        .map(|c| c.hellfire_aoe_damage)
+        .unwrap_or(DEFAULT_HELLFIRE_AOE_DAMAGE);
+    let base_aoe_radius = cfg
+        .map(|c| c.hellfire_aoe_radius)
+        .unwrap_or(DEFAULT_HELLFIRE_AOE_RADIUS);
+    let base_count = cfg
+        .map(|c| c.hellfire_count)
+        .unwrap_or(DEFAULT_HELLFIRE_COUNT);
+    let spread_rad = cfg
+        .map(|c| c.hellfire_spread_angle_deg)
+        .unwrap_or(DEFAULT_HELLFIRE_SPREAD_ANGLE_DEG)
+        .to_radians();
+    let collider_r = cfg
+        .map(|c| c.hellfire_collider_radius)
+        .unwrap_or(DEFAULT_HELLFIRE_COLLIDER_RADIUS);
+
+    for event in fired_events.read() {
+        if event.weapon_type != WeaponType::Hellfire {
+            continue;
+        }
+
+        let Ok((player_tf, stats)) = player_q.get(event.player) else {
+            continue;
+        };
+
+        let player_pos = player_tf.translation.truncate();
+        let Some(dir) = highest_hp_direction(&enemy_q, player_pos) else {
+            continue; // no targetable enemies
+        };
+
+        let count = (base_count + stats.extra_projectiles).max(1);
+        let half_span = (count as f32 - 1.0) * 0.5 * spread_rad;
+        for i in 0..count {
+            let angle = i as f32 * spread_rad - half_span;
+            let velocity =
+                Vec2::from_angle(angle).rotate(dir) * speed * stats.projectile_speed_mult;
+            spawn_fireball(
+                &mut commands,
+                player_pos,
+                velocity,
+                FireballProjectile {
+                    damage: base_damage * stats.damage_multiplier,
+                    aoe_damage: base_aoe_damage * stats.damage_multiplier,
+                    aoe_radius: base_aoe_radius * stats.area_multiplier,
+                    lifetime,
+                    weapon_type: event.weapon_type,
+                },
+                collider_r,
+                HELLFIRE_COLOR,
+            );
+        }
     }
 }
 
+/// Returns the unit direction from `player_pos` to the enemy with the highest
+/// current HP, or `None` when no enemy is targetable.
+///
+/// Enemies exactly on the player are skipped so the direction is never zero.
+fn highest_hp_direction(enemy_q: &Query<(&Transform, &Enemy)>, player_pos: Vec2) -> Option<Vec2> {
+    let (target_tf, _) = enemy_q
+        .iter()
+        .filter(|(tf, _)| tf.translation.truncate().distance_squared(player_pos) > f32::EPSILON)
+        .max_by(|(_, a), (_, b)| {
+            a.current_hp
+                .partial_cmp(&b.current_hp)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })?;
+    let dir = (target_tf.translation.truncate() - player_pos).normalize_or_zero();
+    (dir != Vec2::ZERO).then_some(dir)
+}
+
+/// Spawns one fireball entity at `position`.
+fn spawn_fireball(
+    commands: &mut Commands,
+    position: Vec2,
+    velocity: Vec2,
+    fireball: FireballProjectile,
+    collider_r: f32,
+    color: Color,
+) {
+    commands.spawn((
+        GameSessionEntity,
+        fireball,
+        ProjectileVelocity(velocity),
+        CircleCollider { radius: collider_r },
+        // Placeholder fireball sprite.
+        Sprite {
+            color,
+            custom_size: Some(Vec2::splat(collider_r * 2.0)),
+            ..default()
+        },
+        Transform::from_xyz(position.x, position.y, FIRE_WAND_PROJECTILE_Z),
+    ));
+}
+
 /// Advances every [`FireballProjectile`] entity along its velocity.
 ///
 /// Fireballs do not use the standard `move_projectiles` system because they
@@ -231,6 +363,7 @@ pub fn move_fireballs(
 /// 3. On the first hit:
 ///    a. Emits a [`DamageEnemyEvent`] for the struck enemy (full damage).
 ///    b. Emits [`DamageEnemyEvent`] for all other enemies within `aoe_radius` (AoE damage).
+///    Every hit carries a burn built from `burn_dps` / `burn_duration`.
 ///    c. Spawns a [`FireballExplosionEffect`] visual at the impact point.
 ///    d. Despawns the fireball entity.
 ///
@@ -254,6 +387,15 @@ pub fn fireball_enemy_collision(
     let explosion_z = cfg
         .map(|c| c.explosion_z)
         .unwrap_or(DEFAULT_FIRE_WAND_EXPLOSION_Z);
+    let knockback = cfg
+        .map(|c| c.knockback)
+        .unwrap_or(DEFAULT_FIRE_WAND_KNOCKBACK);
+    let burn_dps = cfg
+        .map(|c| c.burn_dps)
+        .unwrap_or(DEFAULT_FIRE_WAND_BURN_DPS);
+    let burn_duration = cfg
+        .map(|c| c.burn_duration)
+        .unwrap_or(DEFAULT_FIRE_WAND_BURN_DURATION);
 
     // Compute the maximum enemy collider radius from live data so the spatial
     // query never under-shoots for unusually large enemies.
@@ -284,11 +426,15 @@ pub fn fireball_enemy_collision(
             continue;
         };
 
+        let burn = StatusEffect::burn(burn_dps, burn_duration, projectile.weapon_type);
+
         // Emit direct-hit damage.
         damage_events.write(DamageEnemyEvent {
             entity: hit_entity,
             damage: projectile.damage,
-            weapon_type: WeaponType::FireWand,
+            weapon_type: projectile.weapon_type,
+            knockback: knockback_from(pos, explosion_center, knockback),
+            status: Some(burn),
         });
 
         // AoE: damage all other enemies within the explosion radius.
@@ -311,7 +457,9 @@ pub fn fireball_enemy_collision(
                 damage_events.write(DamageEnemyEvent {
                     entity: candidate,
                     damage: projectile.aoe_damage,
-                    weapon_type: WeaponType::FireWand,
+                    weapon_type: projectile.weapon_type,
+                    knockback: knockback_from(explosion_center, enemy_pos, knockback),
+                    status: Some(burn),
                 });
             }
         }
@@ -367,6 +515,86 @@ pub fn despawn_expired_fireballs(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+/// Fire Wand: exploding fireball aimed at the toughest enemy.
+pub const FIRE_WAND: WeaponSpec = WeaponSpec {
+    weapon_type: WeaponType::FireWand,
+    evolved: false,
+    name: LocalizedText {
+        en: "Fire Wand",
+        ja: "炎の杖",
+    },
+    description: LocalizedText {
+        en: "Fireball targeting the highest-HP enemy.",
+        ja: "最大HPの敵を狙う火の玉。",
+    },
+    abbrev: "FW",
+    damage_type: DamageType::Fire,
+    fire_sound: Some(WeaponSound::ProjectileLarge),
+    config_path: "config/weapons/fire_wand.ron",
+    config_check: <FireWandConfigLoader as CheckConfigFile>::check,
+    cooldown_by_level: [3.0, 2.7, 2.5, 2.3, 2.1, 2.0, 1.8, 1.5],
+    damage_by_level: DEFAULT_DAMAGE_BY_LEVEL,
+    count_by_level: [1; 8],
+};
+
+/// Hellfire (Fire Wand + Spinach): a fan of larger fireballs.
+pub const HELLFIRE: WeaponSpec = WeaponSpec {
+    weapon_type: WeaponType::Hellfire,
+    evolved: true,
+    name: LocalizedText {
+        en: "Hellfire",
+        ja: "地獄の業火",
+    },
+    description: LocalizedText {
+        en: "Evolved Fire Wand — a volley of huge fireballs.",
+        ja: "進化形炎の杖 — 巨大な火球の連射。",
+    },
+    abbrev: "HF",
+    damage_type: DamageType::Fire,
+    fire_sound: Some(WeaponSound::ProjectileLarge),
+    config_path: "config/weapons/fire_wand.ron",
+    config_check: <FireWandConfigLoader as CheckConfigFile>::check,
+    cooldown_by_level: [1.2; 8],
+    damage_by_level: [DEFAULT_HELLFIRE_DAMAGE; 8],
+    count_by_level: [DEFAULT_HELLFIRE_COUNT; 8],
+};
+
+/// Registers the Fire Wand and Hellfire: `fire_wand.ron`, the fireball
+/// flight and explosion systems.
+pub struct FireWandPlugin;
+
+impl Plugin for FireWandPlugin {
+    fn build(&self, app: &mut App) {
+        use crate::systems::spatial::update_spatial_grid;
+        register_weapon_config(
+            app,
+            FIRE_WAND.config_path,
+            FireWandConfigLoader,
+            FireWandConfigHandle,
+        );
+        app.add_systems(
+            Update,
+            (
+                (
+                    fire_fire_wand,
+                    fire_hellfire,
+                    move_fireballs,
+                    fireball_enemy_collision
+                        .after(move_fireballs)
+                        .after(update_spatial_grid),
+                )
+                    .in_set(WeaponSystems::Fire),
+                (despawn_explosion_effects, despawn_expired_fireballs)
+                    .in_set(WeaponSystems::Effects),
+            ),
+        );
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -379,7 +607,7 @@ mod tests {
 
     use super::*;
     use crate::{
-        components::{Enemy, WeaponInventory},
+        components::{Enemy, StatusKind, WeaponInventory},
         events::{DamageEnemyEvent, WeaponFiredEvent},
         resources::SpatialGrid,
         types::{EnemyType, WeaponState, WeaponType},
@@ -589,6 +817,60 @@ mod tests {
         );
     }
 
+    // -----------------------------------------------------------------------
+    // fire_hellfire tests
+    // -----------------------------------------------------------------------
+
+    /// Hellfire fires a fan of fireballs centred on the highest-HP enemy.
+    #[test]
+    fn fire_hellfire_fires_fan_toward_target() {
+        let mut app = build_app();
+        let player = spawn_player(&mut app);
+        spawn_enemy_with_hp(&mut app, Vec2::new(200.0, 0.0), 50.0);
+
+        app.world_mut().write_message(WeaponFiredEvent {
+            player,
+            weapon_type: WeaponType::Hellfire,
+            level: 8,
+        });
+        app.world_mut()
+            .run_system_once(fire_hellfire)
+            .expect("fire_hellfire should run");
+        app.world_mut().flush();
+
+        let fireballs = app
+            .world_mut()
+            .query::<(&FireballProjectile, &ProjectileVelocity)>()
+            .iter(app.world())
+            .map(|(f, v)| (f.weapon_type, v.0))
+            .collect::<Vec<_>>();
+        assert_eq!(fireballs.len(), DEFAULT_HELLFIRE_COUNT as usize);
+        for (weapon_type, vel) in fireballs {
+            assert_eq!(weapon_type, WeaponType::Hellfire);
+            assert!(vel.x > 0.0, "every fireball heads toward the target");
+        }
+    }
+
+    /// Hellfire events are ignored by `fire_fire_wand`.
+    #[test]
+    fn fire_fire_wand_ignores_hellfire() {
+        let mut app = build_app();
+        let player = spawn_player(&mut app);
+        spawn_enemy_with_hp(&mut app, Vec2::new(100.0, 0.0), 50.0);
+
+        app.world_mut().write_message(WeaponFiredEvent {
+            player,
+            weapon_type: WeaponType::Hellfire,
+            level: 8,
+        });
+        app.world_mut()
+            .run_system_once(fire_fire_wand)
+            .expect("fire_fire_wand should run");
+        app.world_mut().flush();
+
+        assert_eq!(fireball_count(&mut app), 0);
+    }
+
     // -----------------------------------------------------------------------
     // move_fireballs tests
     // -----------------------------------------------------------------------
@@ -606,6 +888,7 @@ mod tests {
                     aoe_damage: 40.0,
                     aoe_radius: 80.0,
                     lifetime: 3.0,
+                    weapon_type: WeaponType::FireWand,
                 },
                 ProjectileVelocity(Vec2::new(250.0, 0.0)),
                 Transform::from_xyz(0.0, 0.0, 5.5),
@@ -641,6 +924,7 @@ mod tests {
                     aoe_damage: 40.0,
                     aoe_radius: 80.0,
                     lifetime: 3.0,
+                    weapon_type: WeaponType::FireWand,
                 },
                 CircleCollider { radius: 12.0 },
                 Transform::from_xyz(pos.x, pos.y, 5.5),
@@ -672,6 +956,28 @@ mod tests {
         );
     }
 
+    /// Both the direct hit and the splash set their target on fire.
+    #[test]
+    fn fireball_collision_inflicts_burn() {
+        let mut app = build_app();
+
+        spawn_enemy_with_hp(&mut app, Vec2::new(5.0, 0.0), 50.0);
+        spawn_enemy_with_hp(&mut app, Vec2::new(30.0, 0.0), 30.0);
+        spawn_fireball_at(&mut app, Vec2::ZERO);
+
+        update_grid(&mut app);
+        run_collision(&mut app);
+
+        let events = damage_events(&app);
+        assert_eq!(events.len(), 2);
+        for event in &events {
+            let burn = event.status.expect("fireball hits should burn");
+            assert_eq!(burn.kind, StatusKind::Burn);
+            assert_eq!(burn.magnitude, DEFAULT_FIRE_WAND_BURN_DPS);
+            assert_eq!(burn.remaining, DEFAULT_FIRE_WAND_BURN_DURATION);
+        }
+    }
+
     /// AoE enemies (not the directly hit one) receive AoE damage.
     #[test]
     fn fireball_collision_deals_aoe_damage_to_nearby_enemies() {
@@ -813,6 +1119,7 @@ mod tests {
                 aoe_damage: 40.0,
                 aoe_radius: 80.0,
                 lifetime: 0.001, // nearly expired
+                weapon_type: WeaponType::FireWand,
             })
             .id();
 
@@ -840,6 +1147,7 @@ mod tests {
                 aoe_damage: 40.0,
                 aoe_radius: 80.0,
                 lifetime: 99.0,
+                weapon_type: WeaponType::FireWand,
             })
             .id();
 
@@ -902,4 +1210,22 @@ mod tests {
             "active explosion effect should not be despawned"
         );
     }
+
+    /// The Fire Wand spec's damage matches the per-level table in
+    /// `fire_wand.ron`.
+    #[test]
+    fn fire_wand_spec_matches_ron_config() {
+        use crate::config::weapon::fire_wand::{FireWandConfig, FireWandConfigPartial};
+        let partial: FireWandConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(include_str!(
+                "../../../../vampire-survivors/assets/config/weapons/fire_wand.ron"
+            ))
+            .expect("fire_wand.ron should parse");
+        let cfg = FireWandConfig::from(partial);
+        for (i, &expected) in cfg.damage_by_level.iter().enumerate() {
+            let level = i as u8 + 1;
+            assert_eq!(FIRE_WAND.base_damage(level), expected, "FireWand lv{level}");
+        }
+    }
 }
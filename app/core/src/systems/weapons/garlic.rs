@@ -34,34 +34,55 @@
 //! The effective radius is `base_radius × player.area_multiplier`, matching
 //! the behaviour of other area weapons (e.g. Whip).
 //!
+//! ## Slow
+//!
+//! Every aura tick also slows the enemies it hits by `slow_fraction` for
+//! `slow_duration` seconds, so enemies wading through the aura stay in it
+//! longer.  SoulEater applies the same slow.
+//!
 //! ## Tick interval
 //!
 //! The aura fires every time its weapon cooldown expires (driven by
-//! `tick_weapon_cooldowns`), using the base-cooldown table in
-//! `types/weapon.rs`.
+//! `tick_weapon_cooldowns`), using the cooldown table in [`GARLIC`].
 
 use std::collections::HashSet;
 
 use bevy::prelude::*;
 
 use crate::{
-    components::{AuraWeapon, Enemy, GameSessionEntity, Player, PlayerStats},
-    config::weapon::garlic::GarlicParams,
+    components::{
+        AuraWeapon, Enemy, GameSessionEntity, Player, PlayerStats, StatusEffect, WeaponInventory,
+    },
+    config::{
+        register_weapon_config,
+        validate::CheckConfigFile,
+        weapon::garlic::{
+            DEFAULT_DAMAGE_BY_LEVEL, DEFAULT_RADIUS_BY_LEVEL, GarlicConfig, GarlicConfigHandle,
+            GarlicConfigLoader, GarlicParams,
+        },
+    },
     events::{DamageEnemyEvent, WeaponFiredEvent},
     resources::SpatialGrid,
-    types::WeaponType,
+    systems::{
+        damage::knockback_from,
+        weapons::{
+            WeaponSystems,
+            registry::{LocalizedText, WeaponSpec},
+        },
+    },
+    types::{DamageType, WeaponSound, WeaponType},
 };
 
 // ---------------------------------------------------------------------------
 // Fallback constants (used while RON config is still loading)
 // ---------------------------------------------------------------------------
 
-/// Damage per aura tick at each weapon level (index 0 = level 1).
-const DEFAULT_GARLIC_DAMAGE_BY_LEVEL: [f32; 8] = [5.0, 5.0, 8.0, 8.0, 10.0, 12.0, 15.0, 20.0];
-
-/// Aura radius in pixels at each weapon level (index 0 = level 1).
-const DEFAULT_GARLIC_RADIUS_BY_LEVEL: [f32; 8] =
-    [80.0, 90.0, 90.0, 100.0, 110.0, 120.0, 130.0, 150.0];
+/// Knockback distance per aura tick, away from the player (pixels).
+const DEFAULT_GARLIC_KNOCKBACK: f32 = 12.0;
+/// Fraction of move speed removed from enemies hit by the aura.
+const DEFAULT_GARLIC_SLOW_FRACTION: f32 = 0.3;
+/// Seconds the slow lingers after each aura tick.
+const DEFAULT_GARLIC_SLOW_DURATION: f32 = 0.6;
 /// SoulEater multiplies the base Garlic damage by this factor.
 const DEFAULT_SOUL_EATER_DAMAGE_MULT: f32 = 3.0;
 /// HP restored to the player per enemy hit by SoulEater's aura.
@@ -95,8 +116,8 @@ pub struct GarlicAura {
 ///   [`spawn_garlic_visual`] on the following frame).
 /// - On every subsequent activation the [`AuraWeapon`] stats are updated;
 ///   [`update_garlic_visual`] then syncs the visual radius automatically.
-/// - A [`DamageEnemyEvent`] is emitted for each enemy within the
-///   area-scaled radius (found via [`SpatialGrid`]).
+/// - A [`DamageEnemyEvent`] carrying a [`StatusEffect::slow`] is emitted for
+///   each enemy within the area-scaled radius (found via [`SpatialGrid`]).
 /// - A [`HashSet`] guards against duplicate spawns when multiple
 ///   same-type events arrive in the same system run (commands are deferred,
 ///   so the freshly-queued entity is not yet visible to `aura_q`).
@@ -115,6 +136,13 @@ pub fn fire_garlic(
     mut commands: Commands,
 ) {
     let cfg = garlic_cfg.get();
+    let knockback = cfg.map(|c| c.knockback).unwrap_or(DEFAULT_GARLIC_KNOCKBACK);
+    let slow_fraction = cfg
+        .map(|c| c.slow_fraction)
+        .unwrap_or(DEFAULT_GARLIC_SLOW_FRACTION);
+    let slow_duration = cfg
+        .map(|c| c.slow_duration)
+        .unwrap_or(DEFAULT_GARLIC_SLOW_DURATION);
     // Track players for which a spawn was already queued this run, so that
     // two same-frame Garlic events cannot produce duplicate aura entities.
     let mut spawn_scheduled_for: HashSet<Entity> = HashSet::new();
@@ -130,23 +158,7 @@ pub fn fire_garlic(
         };
 
         let player_pos = player_tf.translation.truncate();
-        let level = event.level.clamp(1, 8) as usize;
-
-        // --- Per-level stats (with config fallback) ---
-        let base_damage = cfg
-            .and_then(|c| c.damage_by_level.get(level - 1).copied())
-            .unwrap_or(DEFAULT_GARLIC_DAMAGE_BY_LEVEL[level - 1]);
-        let base_radius = cfg
-            .and_then(|c| c.radius_by_level.get(level - 1).copied())
-            .unwrap_or(DEFAULT_GARLIC_RADIUS_BY_LEVEL[level - 1]);
-
-        let damage_mult = if is_soul_eater {
-            DEFAULT_SOUL_EATER_DAMAGE_MULT
-        } else {
-            1.0
-        };
-        let damage = base_damage * stats.damage_multiplier * damage_mult;
-        let radius = base_radius * stats.area_multiplier;
+        let (damage, radius) = aura_stats(cfg, event.weapon_type, event.level, &stats);
 
         // --- Spawn or update the persistent GarlicAura entity ---
         // Find any existing aura belonging to this player.
@@ -186,17 +198,20 @@ pub fn fire_garlic(
         }
 
         // --- Deal damage to all enemies within the aura radius ---
+        let slow = StatusEffect::slow(slow_fraction, slow_duration, event.weapon_type);
         let mut hits = 0u32;
         for enemy_entity in spatial_grid.get_nearby(player_pos, radius) {
             let Ok(enemy_tf) = enemy_q.get(enemy_entity) else {
                 continue;
             };
-            let dist = (enemy_tf.translation.truncate() - player_pos).length();
-            if dist <= radius {
+            let enemy_pos = enemy_tf.translation.truncate();
+            if (enemy_pos - player_pos).length() <= radius {
                 damage_events.write(DamageEnemyEvent {
                     entity: enemy_entity,
                     damage,
                     weapon_type: event.weapon_type,
+                    knockback: knockback_from(player_pos, enemy_pos, knockback),
+                    status: Some(slow),
                 });
                 hits += 1;
             }
@@ -210,6 +225,71 @@ pub fn fire_garlic(
     }
 }
 
+/// Re-derives every [`GarlicAura`]'s damage and radius when `garlic.ron` is
+/// hot-reloaded, so edits show before the next aura tick.
+///
+/// The level comes from the owning player's [`WeaponInventory`];
+/// [`update_garlic_visual`] then resizes the circle.  Runs in all states so
+/// edits made while paused are not missed.
+pub fn hot_reload_garlic_auras(
+    mut events: MessageReader<AssetEvent<GarlicConfig>>,
+    player_q: Query<(&PlayerStats, &WeaponInventory), With<Player>>,
+    mut aura_q: Query<(&GarlicAura, &mut AuraWeapon)>,
+    garlic_cfg: GarlicParams,
+) {
+    let modified = events
+        .read()
+        .filter(|e| matches!(e, AssetEvent::Modified { .. }))
+        .count();
+    if modified == 0 {
+        return;
+    }
+
+    let cfg = garlic_cfg.get();
+    for (aura, mut aura_weapon) in aura_q.iter_mut() {
+        let Ok((stats, inventory)) = player_q.get(aura.player) else {
+            continue;
+        };
+        let Some(weapon) = inventory
+            .weapons
+            .iter()
+            .find(|w| matches!(w.weapon_type, WeaponType::Garlic | WeaponType::SoulEater))
+        else {
+            continue;
+        };
+        let (damage, radius) = aura_stats(cfg, weapon.weapon_type, weapon.level, stats);
+        aura_weapon.damage = damage;
+        aura_weapon.radius = radius;
+    }
+}
+
+/// Aura damage and radius for `weapon_type` at `level`, after the player's
+/// damage and area multipliers (with config fallback).
+fn aura_stats(
+    cfg: Option<&GarlicConfig>,
+    weapon_type: WeaponType,
+    level: u8,
+    stats: &PlayerStats,
+) -> (f32, f32) {
+    let level = level.clamp(1, 8) as usize;
+    let base_damage = cfg
+        .and_then(|c| c.damage_by_level.get(level - 1).copied())
+        .unwrap_or(DEFAULT_DAMAGE_BY_LEVEL[level - 1]);
+    let base_radius = cfg
+        .and_then(|c| c.radius_by_level.get(level - 1).copied())
+        .unwrap_or(DEFAULT_RADIUS_BY_LEVEL[level - 1]);
+
+    let damage_mult = if weapon_type == WeaponType::SoulEater {
+        DEFAULT_SOUL_EATER_DAMAGE_MULT
+    } else {
+        1.0
+    };
+    (
+        base_damage * stats.damage_multiplier * damage_mult,
+        base_radius * stats.area_multiplier,
+    )
+}
+
 /// Adds a circle visual to newly spawned [`GarlicAura`] entities.
 ///
 /// Runs whenever `Added<GarlicAura>` is detected (the frame after the
@@ -245,6 +325,80 @@ pub fn update_garlic_visual(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+/// Garlic: damaging, slowing aura around the player.
+pub const GARLIC: WeaponSpec = WeaponSpec {
+    weapon_type: WeaponType::Garlic,
+    evolved: false,
+    name: LocalizedText {
+        en: "Garlic",
+        ja: "ニンニク",
+    },
+    description: LocalizedText {
+        en: "Continuous damage aura around player.",
+        ja: "プレイヤー周囲に継続ダメージのオーラ。",
+    },
+    abbrev: "Ga",
+    damage_type: DamageType::Holy,
+    fire_sound: Some(WeaponSound::AuraTick),
+    config_path: "config/weapons/garlic.ron",
+    config_check: <GarlicConfigLoader as CheckConfigFile>::check,
+    cooldown_by_level: [0.5, 0.5, 0.5, 0.45, 0.4, 0.4, 0.35, 0.3],
+    damage_by_level: DEFAULT_DAMAGE_BY_LEVEL,
+    count_by_level: [1; 8],
+};
+
+/// Soul Eater (Garlic + Pummarola): stronger aura that heals on every tick.
+pub const SOUL_EATER: WeaponSpec = WeaponSpec {
+    weapon_type: WeaponType::SoulEater,
+    evolved: true,
+    name: LocalizedText {
+        en: "Soul Eater",
+        ja: "魂喰い",
+    },
+    description: LocalizedText {
+        en: "Evolved Garlic — drains life from enemies.",
+        ja: "進化形ニンニク — 敵の命を吸収。",
+    },
+    abbrev: "SE",
+    damage_type: DamageType::Holy,
+    fire_sound: Some(WeaponSound::AuraTick),
+    config_path: "config/weapons/garlic.ron",
+    config_check: <GarlicConfigLoader as CheckConfigFile>::check,
+    cooldown_by_level: [0.5; 8],
+    damage_by_level: [20.0; 8],
+    count_by_level: [1; 8],
+};
+
+/// Registers the Garlic and Soul Eater: `garlic.ron`, the aura tick, its
+/// visual and its hot-reload.
+pub struct GarlicPlugin;
+
+impl Plugin for GarlicPlugin {
+    fn build(&self, app: &mut App) {
+        use crate::systems::spatial::update_spatial_grid;
+        register_weapon_config(
+            app,
+            GARLIC.config_path,
+            GarlicConfigLoader,
+            GarlicConfigHandle,
+        );
+        app.add_systems(
+            Update,
+            (
+                fire_garlic
+                    .after(update_spatial_grid)
+                    .in_set(WeaponSystems::Fire),
+                (spawn_garlic_visual, update_garlic_visual).in_set(WeaponSystems::Effects),
+                hot_reload_garlic_auras.before(update_garlic_visual),
+            ),
+        );
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -260,6 +414,7 @@ mod tests {
         components::WeaponInventory,
         events::WeaponFiredEvent,
         resources::SpatialGrid,
+        systems::weapons::registry::WeaponRegistry,
         types::{WeaponState, WeaponType},
     };
 
@@ -273,6 +428,7 @@ mod tests {
         app.add_message::<WeaponFiredEvent>();
         app.add_message::<DamageEnemyEvent>();
         app.insert_resource(SpatialGrid::default());
+        app.insert_resource(WeaponRegistry::with_builtin_weapons());
         app
     }
 
@@ -363,6 +519,23 @@ mod tests {
         assert_eq!(events[0].weapon_type, WeaponType::Garlic);
     }
 
+    /// Aura hits slow the enemy by the configured fraction.
+    #[test]
+    fn garlic_hit_slows_enemy() {
+        let mut app = build_app();
+        let player = spawn_player(&mut app);
+        spawn_enemy(&mut app, Vec2::new(50.0, 0.0));
+
+        fire_once(&mut app, player, WeaponType::Garlic, 1);
+
+        let slow = damage_events(&app)[0]
+            .status
+            .expect("garlic hits should slow");
+        assert_eq!(slow.kind, crate::components::StatusKind::Slow);
+        assert_eq!(slow.magnitude, DEFAULT_GARLIC_SLOW_FRACTION);
+        assert_eq!(slow.remaining, DEFAULT_GARLIC_SLOW_DURATION);
+    }
+
     /// Enemy outside aura radius is not hit.
     #[test]
     fn garlic_does_not_hit_enemy_out_of_range() {
@@ -450,16 +623,16 @@ mod tests {
     /// Level 3 deals more damage per tick than level 1.
     #[test]
     fn garlic_damage_increases_with_level() {
-        let lv1 = DEFAULT_GARLIC_DAMAGE_BY_LEVEL[0];
-        let lv3 = DEFAULT_GARLIC_DAMAGE_BY_LEVEL[2];
+        let lv1 = DEFAULT_DAMAGE_BY_LEVEL[0];
+        let lv3 = DEFAULT_DAMAGE_BY_LEVEL[2];
         assert!(lv3 > lv1, "Lv3 damage ({lv3}) should exceed Lv1 ({lv1})");
     }
 
     /// Level 8 has a larger radius than level 1.
     #[test]
     fn garlic_radius_increases_with_level() {
-        let lv1 = DEFAULT_GARLIC_RADIUS_BY_LEVEL[0];
-        let lv8 = DEFAULT_GARLIC_RADIUS_BY_LEVEL[7];
+        let lv1 = DEFAULT_RADIUS_BY_LEVEL[0];
+        let lv8 = DEFAULT_RADIUS_BY_LEVEL[7];
         assert!(lv8 > lv1, "Lv8 radius ({lv8}) should exceed Lv1 ({lv1})");
     }
 
@@ -513,7 +686,7 @@ mod tests {
 
         let events = damage_events(&app);
         assert_eq!(events.len(), 1);
-        let expected = DEFAULT_GARLIC_DAMAGE_BY_LEVEL[0] * DEFAULT_SOUL_EATER_DAMAGE_MULT;
+        let expected = DEFAULT_DAMAGE_BY_LEVEL[0] * DEFAULT_SOUL_EATER_DAMAGE_MULT;
         assert!(
             (events[0].damage - expected).abs() < 1e-4,
             "SoulEater Lv1 damage should be {expected}, got {}",
@@ -595,4 +768,56 @@ mod tests {
             "all three enemies in range should be hit"
         );
     }
+
+    /// Reloading `garlic.ron` re-derives a live aura from the player's
+    /// current weapon level.
+    #[test]
+    fn config_reload_rederives_aura_radius() {
+        let mut app = build_app();
+        app.add_message::<AssetEvent<GarlicConfig>>();
+        let player = spawn_player(&mut app);
+        fire_once(&mut app, player, WeaponType::Garlic, 1);
+
+        app.world_mut()
+            .get_mut::<WeaponInventory>(player)
+            .unwrap()
+            .weapons[0]
+            .level = 8;
+        app.world_mut()
+            .write_message(AssetEvent::<GarlicConfig>::Modified {
+                id: AssetId::default(),
+            });
+        app.world_mut()
+            .run_system_once(hot_reload_garlic_auras)
+            .expect("hot_reload_garlic_auras should run");
+
+        let radius = app
+            .world_mut()
+            .query_filtered::<&AuraWeapon, With<GarlicAura>>()
+            .single(app.world())
+            .expect("one aura")
+            .radius;
+        assert_eq!(radius, DEFAULT_RADIUS_BY_LEVEL[7]);
+    }
+
+    /// The Garlic spec's damage matches the per-level table in `garlic.ron`.
+    #[test]
+    fn garlic_spec_matches_ron_config() {
+        use crate::config::weapon::garlic::{GarlicConfig, GarlicConfigPartial};
+        let partial: GarlicConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(include_str!(
+                "../../../../vampire-survivors/assets/config/weapons/garlic.ron"
+            ))
+            .expect("garlic.ron should parse");
+        let cfg = GarlicConfig::from(partial);
+        for (i, &expected) in cfg.damage_by_level.iter().enumerate() {
+            assert_eq!(
+                GARLIC.base_damage(i as u8 + 1),
+                expected,
+                "Garlic lv{}",
+                i + 1
+            );
+        }
+    }
 }
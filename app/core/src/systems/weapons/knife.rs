@@ -34,10 +34,23 @@ use bevy::prelude::*;
 
 use crate::{
     components::{Player, PlayerFacingDirection, PlayerStats},
-    config::weapon::knife::KnifeParams,
+    config::{
+        register_weapon_config,
+        validate::CheckConfigFile,
+        weapon::knife::{
+            DEFAULT_BASE_DAMAGE, DEFAULT_COUNT_BY_LEVEL, DEFAULT_DAMAGE_PER_TWO_LEVELS,
+            KnifeConfigHandle, KnifeConfigLoader, KnifeParams,
+        },
+    },
     events::WeaponFiredEvent,
-    systems::projectiles::spawn_projectile,
-    types::WeaponType,
+    systems::{
+        projectiles::spawn_projectile,
+        weapons::{
+            WeaponSystems,
+            registry::{LocalizedText, MAX_WEAPON_LEVEL, WeaponSpec},
+        },
+    },
+    types::{DamageType, WeaponSound, WeaponType},
 };
 
 // ---------------------------------------------------------------------------
@@ -48,19 +61,14 @@ use crate::{
 const DEFAULT_KNIFE_BASE_SPEED: f32 = 600.0;
 /// Speed added every two weapon levels.
 const DEFAULT_KNIFE_SPEED_PER_TWO_LEVELS: f32 = 100.0;
-/// Base damage at weapon level 1.
-const DEFAULT_KNIFE_BASE_DAMAGE: f32 = 15.0;
-/// Damage added every two weapon levels.
-const DEFAULT_KNIFE_DAMAGE_PER_TWO_LEVELS: f32 = 5.0;
 /// Projectile lifetime in seconds.
 const DEFAULT_KNIFE_LIFETIME: f32 = 5.0;
 /// Circle collider radius for hit detection (pixels).
 const DEFAULT_KNIFE_COLLIDER_RADIUS: f32 = 6.0;
 /// Angular gap between adjacent knives in a fan (degrees).
 const DEFAULT_KNIFE_SPREAD_ANGLE_DEG: f32 = 15.0;
-
-/// Fallback projectile count per level while RON config is still loading.
-const DEFAULT_KNIFE_COUNT_BY_LEVEL: [u32; 8] = [1, 1, 2, 2, 3, 3, 4, 5];
+/// Knockback distance per hit (pixels).
+const DEFAULT_KNIFE_KNOCKBACK: f32 = 8.0;
 
 /// Piercing value used for knife projectiles.
 /// `u32::MAX` ensures knives pass through every enemy for the duration of their
@@ -91,13 +99,12 @@ pub fn fire_knife(
     let speed_per_two = cfg
         .map(|c| c.speed_per_two_levels)
         .unwrap_or(DEFAULT_KNIFE_SPEED_PER_TWO_LEVELS);
-    let base_damage = cfg
-        .map(|c| c.base_damage)
-        .unwrap_or(DEFAULT_KNIFE_BASE_DAMAGE);
+    let base_damage = cfg.map(|c| c.base_damage).unwrap_or(DEFAULT_BASE_DAMAGE);
     let dmg_per_two = cfg
         .map(|c| c.damage_per_two_levels)
-        .unwrap_or(DEFAULT_KNIFE_DAMAGE_PER_TWO_LEVELS);
+        .unwrap_or(DEFAULT_DAMAGE_PER_TWO_LEVELS);
     let lifetime = cfg.map(|c| c.lifetime).unwrap_or(DEFAULT_KNIFE_LIFETIME);
+    let knockback = cfg.map(|c| c.knockback).unwrap_or(DEFAULT_KNIFE_KNOCKBACK);
     let collider_r = cfg
         .map(|c| c.collider_radius)
         .unwrap_or(DEFAULT_KNIFE_COLLIDER_RADIUS);
@@ -129,7 +136,7 @@ pub fn fire_knife(
         // Base count from the level table, plus player's extra_projectiles bonus.
         let mut count = cfg
             .and_then(|c| c.count_by_level.get(level - 1).copied())
-            .unwrap_or(DEFAULT_KNIFE_COUNT_BY_LEVEL[level - 1])
+            .unwrap_or(DEFAULT_COUNT_BY_LEVEL[level - 1])
             + stats.extra_projectiles;
         // ThousandEdge fires twice as many knives as the base Knife at the same level.
         if is_thousand_edge {
@@ -154,6 +161,7 @@ pub fn fire_knife(
                 player_pos,
                 velocity,
                 damage,
+                knockback,
                 lifetime,
                 KNIFE_PIERCING,
                 collider_r,
@@ -170,6 +178,78 @@ fn rotate_vec2(v: Vec2, angle: f32) -> Vec2 {
     Vec2::new(v.x * cos - v.y * sin, v.x * sin + v.y * cos)
 }
 
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+/// Knife damage table: `base + damage_per_two_levels × floor((level − 1) / 2)`.
+const fn knife_damage_by_level() -> [f32; MAX_WEAPON_LEVEL] {
+    let mut table = [0.0; MAX_WEAPON_LEVEL];
+    let mut i = 0;
+    while i < MAX_WEAPON_LEVEL {
+        table[i] = DEFAULT_BASE_DAMAGE + DEFAULT_DAMAGE_PER_TWO_LEVELS * (i / 2) as f32;
+        i += 1;
+    }
+    table
+}
+
+/// Knife: piercing blades thrown in the facing direction.
+pub const KNIFE: WeaponSpec = WeaponSpec {
+    weapon_type: WeaponType::Knife,
+    evolved: false,
+    name: LocalizedText {
+        en: "Knife",
+        ja: "ナイフ",
+    },
+    description: LocalizedText {
+        en: "Fast piercing shot in movement direction.",
+        ja: "移動方向へ高速貫通弾。",
+    },
+    abbrev: "Kn",
+    damage_type: DamageType::Physical,
+    fire_sound: Some(WeaponSound::ProjectileSmall),
+    config_path: "config/weapons/knife.ron",
+    config_check: <KnifeConfigLoader as CheckConfigFile>::check,
+    cooldown_by_level: [0.3, 0.25, 0.25, 0.2, 0.2, 0.18, 0.18, 0.15],
+    damage_by_level: knife_damage_by_level(),
+    count_by_level: DEFAULT_COUNT_BY_LEVEL,
+};
+
+/// Thousand Edge (Knife + Bracer): a near-continuous stream of blades.
+///
+/// [`fire_knife`] doubles the Lv8 count for the evolved form, so the count
+/// here is `5 × 2`.
+pub const THOUSAND_EDGE: WeaponSpec = WeaponSpec {
+    weapon_type: WeaponType::ThousandEdge,
+    evolved: true,
+    name: LocalizedText {
+        en: "Thousand Edge",
+        ja: "千の刃",
+    },
+    description: LocalizedText {
+        en: "Evolved Knife — endless blade flurry.",
+        ja: "進化形ナイフ — 終わりなき刃の嵐。",
+    },
+    abbrev: "TE",
+    damage_type: DamageType::Physical,
+    fire_sound: Some(WeaponSound::ProjectileSmall),
+    config_path: "config/weapons/knife.ron",
+    config_check: <KnifeConfigLoader as CheckConfigFile>::check,
+    cooldown_by_level: [0.12; 8],
+    damage_by_level: [30.0; 8],
+    count_by_level: [10; 8],
+};
+
+/// Registers the Knife and Thousand Edge: `knife.ron` and the fire system.
+pub struct KnifePlugin;
+
+impl Plugin for KnifePlugin {
+    fn build(&self, app: &mut App) {
+        register_weapon_config(app, KNIFE.config_path, KnifeConfigLoader, KnifeConfigHandle);
+        app.add_systems(Update, fire_knife.in_set(WeaponSystems::Fire));
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -184,6 +264,7 @@ mod tests {
     use crate::{
         components::{Projectile, ProjectileVelocity, WeaponInventory},
         events::WeaponFiredEvent,
+        systems::weapons::registry::WeaponRegistry,
         types::{WeaponState, WeaponType},
     };
 
@@ -195,6 +276,7 @@ mod tests {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
         app.add_message::<WeaponFiredEvent>();
+        app.insert_resource(WeaponRegistry::with_builtin_weapons());
         app
     }
 
@@ -427,8 +509,8 @@ mod tests {
     /// Damage scales every two levels.
     #[test]
     fn damage_scales_every_two_levels() {
-        let step = DEFAULT_KNIFE_DAMAGE_PER_TWO_LEVELS;
-        let base = DEFAULT_KNIFE_BASE_DAMAGE;
+        let step = DEFAULT_DAMAGE_PER_TWO_LEVELS;
+        let base = DEFAULT_BASE_DAMAGE;
         // Level 1 and 2 have same damage.
         let lv1_dmg = base; // step 0
         let lv2_dmg = base; // step 0
@@ -457,4 +539,27 @@ mod tests {
         assert!((rotated.x).abs() < 1e-6, "x should be ~0");
         assert!((rotated.y - 1.0).abs() < 1e-6, "y should be ~1");
     }
+
+    /// The Knife spec's damage and count match `knife.ron` at every level.
+    #[test]
+    fn knife_spec_matches_ron_config() {
+        use crate::config::weapon::knife::{KnifeConfig, KnifeConfigPartial};
+        let partial: KnifeConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(include_str!(
+                "../../../../vampire-survivors/assets/config/weapons/knife.ron"
+            ))
+            .expect("knife.ron should parse");
+        let cfg = KnifeConfig::from(partial);
+        for level in 1..=8u8 {
+            let step = ((level as usize - 1) / 2) as f32;
+            let expected = cfg.base_damage + cfg.damage_per_two_levels * step;
+            assert_eq!(KNIFE.base_damage(level), expected, "Knife lv{level}");
+            assert_eq!(
+                KNIFE.base_count(level),
+                cfg.count_by_level[level as usize - 1],
+                "Knife lv{level}"
+            );
+        }
+    }
 }
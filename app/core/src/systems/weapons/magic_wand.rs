@@ -11,6 +11,11 @@
 //! here because targeting is global (nearest enemy on the entire map, not
 //! bounded by a fixed range) and occurs only on weapon fire (infrequently).
 //!
+//! ## HolyWand freeze
+//!
+//! HolyWand projectiles carry an [`InflictsStatus`] freeze, so every enemy
+//! they pierce stops moving for `holy_wand_freeze_duration` seconds.
+//!
 //! ## Damage formula
 //!
 //! ```text
@@ -20,11 +25,24 @@
 use bevy::prelude::*;
 
 use crate::{
-    components::{Enemy, Player, PlayerStats},
-    config::weapon::magic_wand::MagicWandParams,
+    components::{Enemy, InflictsStatus, Player, PlayerStats, StatusEffect},
+    config::{
+        register_weapon_config,
+        validate::CheckConfigFile,
+        weapon::magic_wand::{
+            DEFAULT_BASE_DAMAGE, DEFAULT_DAMAGE_PER_LEVEL, MagicWandConfigHandle,
+            MagicWandConfigLoader, MagicWandParams,
+        },
+    },
     events::WeaponFiredEvent,
-    systems::projectiles::spawn_projectile,
-    types::WeaponType,
+    systems::{
+        projectiles::spawn_projectile,
+        weapons::{
+            WeaponSystems,
+            registry::{LocalizedText, WeaponSpec, linear_by_level},
+        },
+    },
+    types::{DamageType, WeaponSound, WeaponType},
 };
 
 use std::f32::consts::TAU;
@@ -35,10 +53,6 @@ use std::f32::consts::TAU;
 
 /// Projectile travel speed in pixels/second.
 const DEFAULT_MAGIC_WAND_SPEED: f32 = 600.0;
-/// Base damage at weapon level 1.
-const DEFAULT_MAGIC_WAND_BASE_DAMAGE: f32 = 20.0;
-/// Additional damage per weapon level above 1.
-const DEFAULT_MAGIC_WAND_DAMAGE_PER_LEVEL: f32 = 10.0;
 /// Projectile lifetime in seconds.
 const DEFAULT_MAGIC_WAND_LIFETIME: f32 = 5.0;
 /// HolyWand fires in this many evenly-spaced directions (full circle).
@@ -47,6 +61,10 @@ const DEFAULT_HOLY_WAND_DIRECTION_COUNT: u32 = 8;
 const DEFAULT_HOLY_WAND_PIERCING: u32 = u32::MAX;
 /// Circle collider radius for hit detection (pixels).
 const DEFAULT_MAGIC_WAND_COLLIDER_RADIUS: f32 = 8.0;
+/// Knockback distance per hit (pixels).
+const DEFAULT_MAGIC_WAND_KNOCKBACK: f32 = 10.0;
+/// Seconds each HolyWand hit freezes the enemy.
+const DEFAULT_HOLY_WAND_FREEZE_DURATION: f32 = 0.4;
 
 // ---------------------------------------------------------------------------
 // Systems
@@ -66,12 +84,10 @@ pub fn fire_magic_wand(
 ) {
     let cfg = magic_wand_cfg.get();
     let speed = cfg.map(|c| c.speed).unwrap_or(DEFAULT_MAGIC_WAND_SPEED);
-    let base_damage = cfg
-        .map(|c| c.base_damage)
-        .unwrap_or(DEFAULT_MAGIC_WAND_BASE_DAMAGE);
+    let base_damage = cfg.map(|c| c.base_damage).unwrap_or(DEFAULT_BASE_DAMAGE);
     let dmg_per_level = cfg
         .map(|c| c.damage_per_level)
-        .unwrap_or(DEFAULT_MAGIC_WAND_DAMAGE_PER_LEVEL);
+        .unwrap_or(DEFAULT_DAMAGE_PER_LEVEL);
     let lifetime = cfg
         .map(|c| c.lifetime)
         .unwrap_or(DEFAULT_MAGIC_WAND_LIFETIME);
@@ -84,6 +100,12 @@ pub fn fire_magic_wand(
     let holy_piercing = cfg
         .map(|c| c.holy_wand_piercing)
         .unwrap_or(DEFAULT_HOLY_WAND_PIERCING);
+    let knockback = cfg
+        .map(|c| c.knockback)
+        .unwrap_or(DEFAULT_MAGIC_WAND_KNOCKBACK);
+    let freeze_duration = cfg
+        .map(|c| c.holy_wand_freeze_duration)
+        .unwrap_or(DEFAULT_HOLY_WAND_FREEZE_DURATION);
 
     for event in fired_events.read() {
         if event.weapon_type != WeaponType::MagicWand && event.weapon_type != WeaponType::HolyWand {
@@ -99,20 +121,24 @@ pub fn fire_magic_wand(
         let damage = (base_damage + dmg_per_level * (level - 1.0)) * stats.damage_multiplier;
 
         if event.weapon_type == WeaponType::HolyWand {
-            // HolyWand fires in all directions simultaneously with infinite pierce.
+            // HolyWand fires in all directions simultaneously with infinite
+            // pierce, freezing everything it passes through.
+            let freeze = StatusEffect::freeze(freeze_duration, event.weapon_type);
             for i in 0..holy_direction_count {
                 let angle = TAU * i as f32 / holy_direction_count as f32;
                 let dir = Vec2::new(angle.cos(), angle.sin());
-                spawn_projectile(
+                let projectile = spawn_projectile(
                     &mut commands,
                     player_pos,
                     dir * speed,
                     damage,
+                    knockback,
                     lifetime,
                     holy_piercing,
                     collider_r,
                     event.weapon_type,
                 );
+                commands.entity(projectile).insert(InflictsStatus(freeze));
             }
         } else {
             // MagicWand: fire single homing projectile toward nearest enemy.
@@ -140,6 +166,7 @@ pub fn fire_magic_wand(
                 player_pos,
                 dir * speed,
                 damage,
+                knockback,
                 lifetime,
                 0, // piercing = 0 (single hit)
                 collider_r,
@@ -149,6 +176,71 @@ pub fn fire_magic_wand(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+/// Magic Wand: bolt aimed at the nearest enemy.
+pub const MAGIC_WAND: WeaponSpec = WeaponSpec {
+    weapon_type: WeaponType::MagicWand,
+    evolved: false,
+    name: LocalizedText {
+        en: "Magic Wand",
+        ja: "魔法の杖",
+    },
+    description: LocalizedText {
+        en: "Homing projectile toward nearest enemy.",
+        ja: "最寄りの敵に向かうホーミング弾。",
+    },
+    abbrev: "MW",
+    damage_type: DamageType::Magic,
+    fire_sound: Some(WeaponSound::ProjectileSmall),
+    config_path: "config/weapons/magic_wand.ron",
+    config_check: <MagicWandConfigLoader as CheckConfigFile>::check,
+    cooldown_by_level: [0.5, 0.5, 0.5, 0.4, 0.4, 0.35, 0.35, 0.3],
+    damage_by_level: linear_by_level(DEFAULT_BASE_DAMAGE, DEFAULT_DAMAGE_PER_LEVEL),
+    count_by_level: [1; 8],
+};
+
+/// Holy Wand (Magic Wand + Empty Tome): piercing, freezing bolts in every
+/// direction.
+pub const HOLY_WAND: WeaponSpec = WeaponSpec {
+    weapon_type: WeaponType::HolyWand,
+    evolved: true,
+    name: LocalizedText {
+        en: "Holy Wand",
+        ja: "聖なる杖",
+    },
+    description: LocalizedText {
+        en: "Evolved Magic Wand — rapid homing bolts.",
+        ja: "進化形魔法の杖 — 高速ホーミング弾。",
+    },
+    abbrev: "HW",
+    damage_type: DamageType::Holy,
+    fire_sound: Some(WeaponSound::ProjectileSmall),
+    config_path: "config/weapons/magic_wand.ron",
+    config_check: <MagicWandConfigLoader as CheckConfigFile>::check,
+    cooldown_by_level: [0.25; 8],
+    damage_by_level: [90.0; 8],
+    count_by_level: [1; 8],
+};
+
+/// Registers the Magic Wand and Holy Wand: `magic_wand.ron` and the fire
+/// system.
+pub struct MagicWandPlugin;
+
+impl Plugin for MagicWandPlugin {
+    fn build(&self, app: &mut App) {
+        register_weapon_config(
+            app,
+            MAGIC_WAND.config_path,
+            MagicWandConfigLoader,
+            MagicWandConfigHandle,
+        );
+        app.add_systems(Update, fire_magic_wand.in_set(WeaponSystems::Fire));
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -163,6 +255,7 @@ mod tests {
     use crate::{
         components::{Projectile, ProjectileVelocity, WeaponInventory},
         events::WeaponFiredEvent,
+        systems::weapons::registry::WeaponRegistry,
         types::{EnemyType, WeaponState, WeaponType},
     };
 
@@ -172,14 +265,14 @@ mod tests {
 
     /// Test helper: compute Magic Wand damage using DEFAULT_* fallback values.
     fn magic_wand_damage_for_level(level: u8) -> f32 {
-        DEFAULT_MAGIC_WAND_BASE_DAMAGE
-            + DEFAULT_MAGIC_WAND_DAMAGE_PER_LEVEL * (level.clamp(1, 8) as f32 - 1.0)
+        DEFAULT_BASE_DAMAGE + DEFAULT_DAMAGE_PER_LEVEL * (level.clamp(1, 8) as f32 - 1.0)
     }
 
     fn build_app() -> App {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
         app.add_message::<WeaponFiredEvent>();
+        app.insert_resource(WeaponRegistry::with_builtin_weapons());
         app
     }
 
@@ -394,6 +487,13 @@ mod tests {
             DEFAULT_HOLY_WAND_DIRECTION_COUNT as usize,
             "HolyWand should fire {DEFAULT_HOLY_WAND_DIRECTION_COUNT} projectiles"
         );
+        let frozen = app
+            .world_mut()
+            .query::<&InflictsStatus>()
+            .iter(app.world())
+            .filter(|s| s.0.kind == crate::components::StatusKind::Freeze)
+            .count();
+        assert_eq!(frozen, projs.len(), "every HolyWand shot should freeze");
     }
 
     /// Enemy exactly on the player is skipped; a farther enemy is targeted instead.
@@ -435,4 +535,31 @@ mod tests {
             "no shot should fire when the only enemy is on the player"
         );
     }
+
+    /// The Magic Wand spec's damage matches the `magic_wand.ron` formula at
+    /// every level.
+    #[test]
+    fn magic_wand_spec_matches_ron_config() {
+        use crate::config::weapon::magic_wand::{MagicWandConfig, MagicWandConfigPartial};
+        let partial: MagicWandConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(include_str!(
+                "../../../../vampire-survivors/assets/config/weapons/magic_wand.ron"
+            ))
+            .expect("magic_wand.ron should parse");
+        let cfg = MagicWandConfig::from(partial);
+        for level in 1..=8u8 {
+            let expected = cfg.base_damage + cfg.damage_per_level * (level as f32 - 1.0);
+            assert_eq!(
+                MAGIC_WAND.base_damage(level),
+                expected,
+                "MagicWand lv{level}"
+            );
+            assert_eq!(
+                magic_wand_damage_for_level(level),
+                expected,
+                "MagicWand lv{level}"
+            );
+        }
+    }
 }
@@ -5,6 +5,9 @@ pub mod fire_wand;
 pub mod garlic;
 pub mod knife;
 pub mod magic_wand;
+pub mod registry;
+pub mod runetracer;
+pub mod santa_water;
 pub mod thunder_ring;
 pub mod whip;
 
@@ -12,6 +15,24 @@ use bevy::prelude::*;
 
 use crate::states::AppState;
 
+/// System sets weapon plugins add their systems to.
+///
+/// Both sets only run in [`AppState::Playing`].
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WeaponSystems {
+    /// Fire systems and anything else that writes damage this frame.  Runs
+    /// after the cooldown tick and before damage is applied.
+    Fire,
+    /// Visuals, expiry and other per-frame upkeep with no ordering against
+    /// damage.
+    Effects,
+}
+
+/// Shared weapon systems plus every built-in weapon plugin.
+///
+/// The [`registry::WeaponRegistry`] is filled from
+/// [`registry::builtin_specs`]; each weapon plugin registers its own config
+/// and systems.  See [`registry`].
 pub struct WeaponsPlugin;
 
 impl Plugin for WeaponsPlugin {
@@ -19,64 +40,69 @@ impl Plugin for WeaponsPlugin {
         use crate::systems::damage::apply_damage_to_enemies;
         use crate::systems::player::player_movement;
         use crate::systems::projectiles::collision::projectile_enemy_collision;
-        use crate::systems::spatial::update_spatial_grid;
-        use crate::systems::weapons::bible::{fire_bible, orbit_bible, spawn_bible_visual};
+        use crate::systems::rng::GameRngSystems;
+        use crate::systems::weapons::bible::BiblePlugin;
         use crate::systems::weapons::cooldown::tick_weapon_cooldowns;
-        use crate::systems::weapons::cross::fire_cross;
-        use crate::systems::weapons::fire_wand::{
-            despawn_expired_fireballs, despawn_explosion_effects, fire_fire_wand,
-            fireball_enemy_collision, move_fireballs,
-        };
-        use crate::systems::weapons::garlic::{
-            fire_garlic, spawn_garlic_visual, update_garlic_visual,
-        };
-        use crate::systems::weapons::knife::fire_knife;
-        use crate::systems::weapons::magic_wand::fire_magic_wand;
-        use crate::systems::weapons::thunder_ring::{despawn_thunder_effects, fire_thunder_ring};
-        use crate::systems::weapons::whip::{despawn_whip_effects, fire_whip};
-        app.add_systems(
-            Update,
-            (
-                tick_weapon_cooldowns.after(player_movement),
-                fire_magic_wand.after(tick_weapon_cooldowns),
-                fire_knife.after(tick_weapon_cooldowns),
-                fire_bible.after(tick_weapon_cooldowns),
-                orbit_bible.after(fire_bible).after(update_spatial_grid),
-                fire_garlic
+        use crate::systems::weapons::cross::CrossPlugin;
+        use crate::systems::weapons::fire_wand::FireWandPlugin;
+        use crate::systems::weapons::garlic::GarlicPlugin;
+        use crate::systems::weapons::knife::KnifePlugin;
+        use crate::systems::weapons::magic_wand::MagicWandPlugin;
+        use crate::systems::weapons::registry::WeaponRegistry;
+        use crate::systems::weapons::runetracer::RunetracerPlugin;
+        use crate::systems::weapons::santa_water::SantaWaterPlugin;
+        use crate::systems::weapons::thunder_ring::ThunderRingPlugin;
+        use crate::systems::weapons::whip::WhipPlugin;
+        app.insert_resource(WeaponRegistry::with_builtin_weapons())
+            .configure_sets(
+                Update,
+                WeaponSystems::Fire
                     .after(tick_weapon_cooldowns)
-                    .after(update_spatial_grid),
-                fire_thunder_ring.after(tick_weapon_cooldowns),
-                fire_cross.after(tick_weapon_cooldowns),
-                fire_fire_wand.after(tick_weapon_cooldowns),
-                fire_whip
-                    .after(tick_weapon_cooldowns)
-                    .after(update_spatial_grid),
-                move_fireballs,
-                fireball_enemy_collision
-                    .after(move_fireballs)
-                    .after(update_spatial_grid),
-                spawn_bible_visual,
-                spawn_garlic_visual,
-                update_garlic_visual,
-                despawn_whip_effects,
-                despawn_thunder_effects,
-                despawn_explosion_effects,
-                despawn_expired_fireballs,
+                    .run_if(in_state(AppState::Playing)),
+            )
+            .configure_sets(
+                Update,
+                WeaponSystems::Effects.run_if(in_state(AppState::Playing)),
             )
-                .run_if(in_state(AppState::Playing)),
-        )
-        .add_systems(
-            Update,
-            apply_damage_to_enemies
-                .after(fire_whip)
-                .after(fire_garlic)
-                .after(fire_magic_wand)
-                .after(fire_knife)
-                .after(orbit_bible)
-                .after(fire_thunder_ring)
-                .after(projectile_enemy_collision)
-                .after(fireball_enemy_collision)
-                .run_if(in_state(AppState::Playing)),
-        );
+            .add_systems(
+                Update,
+                tick_weapon_cooldowns
+                    .after(player_movement)
+                    .run_if(in_state(AppState::Playing)),
+            )
+            .add_systems(
+                Update,
+                apply_damage_to_enemies
+                    .after(WeaponSystems::Fire)
+                    .after(projectile_enemy_collision)
+                    .run_if(in_state(AppState::Playing)),
+            )
+            .add_plugins((
+                WhipPlugin,
+                MagicWandPlugin,
+                KnifePlugin,
+                GarlicPlugin,
+                BiblePlugin,
+                ThunderRingPlugin,
+                CrossPlugin,
+                FireWandPlugin,
+                RunetracerPlugin,
+                SantaWaterPlugin,
+            ));
+
+        // Weapons drawing from GameRng fire one after another, in
+        // registration order, so seeded runs replay identically.
+        let rng_sets: Vec<GameRngSystems> = app
+            .world()
+            .resource::<WeaponRegistry>()
+            .iter()
+            .map(|spec| GameRngSystems::Weapon(spec.weapon_type))
+            .collect();
+        for &set in &rng_sets {
+            app.configure_sets(Update, set.in_set(WeaponSystems::Fire));
+        }
+        for pair in rng_sets.windows(2) {
+            app.configure_sets(Update, pair[1].after(pair[0]));
+        }
     }
 }
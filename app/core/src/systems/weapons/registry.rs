@@ -0,0 +1,419 @@
+//! Weapon registry — the one place a weapon plugs into the game.
+//!
+//! Every weapon module defines a [`WeaponSpec`] for the base weapon and its
+//! evolution, listed in [`builtin_specs`], and a plugin (e.g. [`WhipPlugin`])
+//! that, in its `build`:
+//!
+//! 1. loads the RON config at its spec's `config_path` via
+//!    [`register_weapon_config`](crate::config::register_weapon_config), which
+//!    also makes the Loading screen wait for it;
+//! 2. adds its fire systems to [`WeaponSystems::Fire`] and its visuals to
+//!    [`WeaponSystems::Effects`].
+//!
+//! `WeaponsPlugin` fills the [`WeaponRegistry`] from [`builtin_specs`].
+//! Shared systems then read the [`WeaponRegistry`] instead of matching on
+//! [`WeaponType`]: [`tick_weapon_cooldowns`] takes the per-level cooldown
+//! from the spec, [`generate_level_up_choices`] offers every registered base
+//! weapon, [`apply_damage_to_enemies`] scales hits by the spec's damage
+//! type, the audio crate plays its fire sound, and the UI shows its name,
+//! description and slot label.
+//!
+//! [`WhipPlugin`]: super::whip::WhipPlugin
+//! [`WeaponSystems::Fire`]: super::WeaponSystems::Fire
+//! [`WeaponSystems::Effects`]: super::WeaponSystems::Effects
+//! [`tick_weapon_cooldowns`]: super::cooldown::tick_weapon_cooldowns
+//! [`generate_level_up_choices`]: crate::systems::xp::choices::generate_level_up_choices
+//! [`apply_damage_to_enemies`]: crate::systems::damage::apply_damage_to_enemies
+
+use bevy::prelude::*;
+
+use crate::{
+    config::validate::FileCheck,
+    resources::Language,
+    types::{DamageType, WeaponSound, WeaponType},
+};
+
+/// Highest weapon level covered by the per-level tables.
+pub const MAX_WEAPON_LEVEL: usize = 8;
+
+// ---------------------------------------------------------------------------
+// Spec
+// ---------------------------------------------------------------------------
+
+/// A display string in every UI [`Language`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LocalizedText {
+    pub en: &'static str,
+    pub ja: &'static str,
+}
+
+impl LocalizedText {
+    /// The string for `lang`.
+    pub fn get(&self, lang: Language) -> &'static str {
+        match lang {
+            Language::English => self.en,
+            Language::Japanese => self.ja,
+        }
+    }
+}
+
+/// Static description of one weapon: per-level base stats and display
+/// metadata.
+///
+/// Tables are indexed by `level − 1`; levels outside 1–8 are clamped.  Evolved
+/// forms use the same value at every level.
+#[derive(Debug, Clone, Copy)]
+pub struct WeaponSpec {
+    pub weapon_type: WeaponType,
+    /// `true` for evolved forms, which are never offered as new weapons.
+    pub evolved: bool,
+    /// Display name (level-up cards, character select, evolution banner).
+    pub name: LocalizedText,
+    /// One-line effect description shown on level-up cards.
+    pub description: LocalizedText,
+    /// Short label shown inside a HUD weapon slot (at most 4 characters).
+    pub abbrev: &'static str,
+    /// Damage type of every hit; enemies scale it by their resistance from
+    /// `enemy.ron`.
+    pub damage_type: DamageType,
+    /// Sound played on each activation, or `None` for silent weapons.
+    pub fire_sound: Option<WeaponSound>,
+    /// RON config the weapon's plugin loads, relative to the assets
+    /// directory.  An evolution shares its base weapon's file.
+    pub config_path: &'static str,
+    /// Parses and validates `config_path` the way the weapon's loader does,
+    /// for the headless config validator.
+    pub config_check: FileCheck,
+    /// Seconds between activations, before the player's cooldown reduction.
+    pub cooldown_by_level: [f32; MAX_WEAPON_LEVEL],
+    /// Damage per hit, before the player's damage multiplier.
+    pub damage_by_level: [f32; MAX_WEAPON_LEVEL],
+    /// Projectiles (or activations) per cycle, before `extra_projectiles`.
+    pub count_by_level: [u32; MAX_WEAPON_LEVEL],
+}
+
+impl WeaponSpec {
+    /// Base cooldown in seconds at `level`.
+    pub fn base_cooldown(&self, level: u8) -> f32 {
+        self.cooldown_by_level[level_index(level)]
+    }
+
+    /// Base damage per hit at `level`.
+    pub fn base_damage(&self, level: u8) -> f32 {
+        self.damage_by_level[level_index(level)]
+    }
+
+    /// Base projectile count at `level`.
+    pub fn base_count(&self, level: u8) -> u32 {
+        self.count_by_level[level_index(level)]
+    }
+
+    /// Cooldown at `level` after the player's cooldown reduction.
+    ///
+    /// `cooldown_reduction` is the fraction removed (e.g. `0.3` = 30% shorter).
+    /// It is clamped to `[0.0, 0.9]` so the effective cooldown is always at
+    /// least 10 % of the base value, preventing degenerate sub-frame intervals.
+    pub fn effective_cooldown(&self, level: u8, cooldown_reduction: f32) -> f32 {
+        let factor = (1.0 - cooldown_reduction.clamp(0.0, 0.9)).max(0.1);
+        self.base_cooldown(level) * factor
+    }
+}
+
+/// Table index for `level`, clamped to 1–8.
+fn level_index(level: u8) -> usize {
+    (level as usize).clamp(1, MAX_WEAPON_LEVEL) - 1
+}
+
+/// Per-level table following `base + per_level × (level − 1)`.
+pub const fn linear_by_level(base: f32, per_level: f32) -> [f32; MAX_WEAPON_LEVEL] {
+    let mut table = [0.0; MAX_WEAPON_LEVEL];
+    let mut i = 0;
+    while i < MAX_WEAPON_LEVEL {
+        table[i] = base + per_level * i as f32;
+        i += 1;
+    }
+    table
+}
+
+// ---------------------------------------------------------------------------
+// Registry
+// ---------------------------------------------------------------------------
+
+/// Every registered weapon spec, in registration order.
+#[derive(Resource, Debug, Default)]
+pub struct WeaponRegistry {
+    specs: Vec<WeaponSpec>,
+}
+
+impl WeaponRegistry {
+    /// Adds `spec`, replacing an earlier spec for the same weapon type.
+    pub fn register(&mut self, spec: WeaponSpec) {
+        match self
+            .specs
+            .iter_mut()
+            .find(|s| s.weapon_type == spec.weapon_type)
+        {
+            Some(existing) => *existing = spec,
+            None => self.specs.push(spec),
+        }
+    }
+
+    /// The spec for `weapon_type`, or `None` when no plugin registered it.
+    pub fn get(&self, weapon_type: WeaponType) -> Option<&WeaponSpec> {
+        self.specs.iter().find(|s| s.weapon_type == weapon_type)
+    }
+
+    /// All registered specs, in registration order.
+    pub fn iter(&self) -> impl Iterator<Item = &WeaponSpec> {
+        self.specs.iter()
+    }
+
+    /// Registered base (non-evolved) weapons, in registration order.
+    pub fn base_weapons(&self) -> impl Iterator<Item = WeaponType> + '_ {
+        self.specs
+            .iter()
+            .filter(|s| !s.evolved)
+            .map(|s| s.weapon_type)
+    }
+
+    /// Display name of `weapon_type` in `lang`, or `"?"` when it is not
+    /// registered.
+    pub fn name(&self, weapon_type: WeaponType, lang: Language) -> &'static str {
+        self.get(weapon_type).map_or("?", |s| s.name.get(lang))
+    }
+
+    /// Effect description of `weapon_type` in `lang`, or `"?"` when it is
+    /// not registered.
+    pub fn description(&self, weapon_type: WeaponType, lang: Language) -> &'static str {
+        self.get(weapon_type)
+            .map_or("?", |s| s.description.get(lang))
+    }
+
+    /// Damage type of `weapon_type`; unregistered weapons deal
+    /// [`DamageType::Physical`].
+    pub fn damage_type(&self, weapon_type: WeaponType) -> DamageType {
+        self.get(weapon_type)
+            .map_or(DamageType::Physical, |s| s.damage_type)
+    }
+
+    /// Firing sound of `weapon_type`, or `None` when it is silent or not
+    /// registered.
+    pub fn fire_sound(&self, weapon_type: WeaponType) -> Option<WeaponSound> {
+        self.get(weapon_type).and_then(|s| s.fire_sound)
+    }
+
+    /// HUD slot label of `weapon_type`, or `"?"` when it is not registered.
+    pub fn abbrev(&self, weapon_type: WeaponType) -> &'static str {
+        self.get(weapon_type).map_or("?", |s| s.abbrev)
+    }
+}
+
+impl WeaponRegistry {
+    /// Registry holding every spec in [`builtin_specs`].
+    pub fn with_builtin_weapons() -> Self {
+        let mut registry = Self::default();
+        for &spec in builtin_specs() {
+            registry.register(spec);
+        }
+        registry
+    }
+}
+
+/// Every built-in weapon, each base form followed by its evolution.
+///
+/// This is the registry order, which level-up offers and the
+/// [`GameRng`](crate::resources::GameRng) draw order of fire systems follow.
+pub fn builtin_specs() -> &'static [WeaponSpec] {
+    use super::{
+        bible::{BIBLE, UNHOLY_VESPERS},
+        cross::{CROSS, HEAVEN_SWORD},
+        fire_wand::{FIRE_WAND, HELLFIRE},
+        garlic::{GARLIC, SOUL_EATER},
+        knife::{KNIFE, THOUSAND_EDGE},
+        magic_wand::{HOLY_WAND, MAGIC_WAND},
+        runetracer::{NO_FUTURE, RUNETRACER},
+        santa_water::SANTA_WATER,
+        thunder_ring::{LIGHTNING_RING, THUNDER_RING},
+        whip::{BLOODY_TEAR, WHIP},
+    };
+    const SPECS: &[WeaponSpec] = &[
+        WHIP,
+        BLOODY_TEAR,
+        MAGIC_WAND,
+        HOLY_WAND,
+        KNIFE,
+        THOUSAND_EDGE,
+        GARLIC,
+        SOUL_EATER,
+        BIBLE,
+        UNHOLY_VESPERS,
+        THUNDER_RING,
+        LIGHTNING_RING,
+        CROSS,
+        HEAVEN_SWORD,
+        FIRE_WAND,
+        HELLFIRE,
+        RUNETRACER,
+        NO_FUTURE,
+        SANTA_WATER,
+    ];
+    SPECS
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn linear_table_steps_by_per_level() {
+        assert_eq!(
+            linear_by_level(20.0, 10.0),
+            [20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 90.0]
+        );
+    }
+
+    /// Out-of-range levels clamp instead of panicking or acting as max level.
+    #[test]
+    fn stats_clamp_level() {
+        let registry = WeaponRegistry::with_builtin_weapons();
+        let knife = registry.get(WeaponType::Knife).unwrap();
+        assert_eq!(knife.base_cooldown(0), knife.base_cooldown(1));
+        assert_eq!(knife.base_damage(0), knife.base_damage(1));
+        assert_eq!(knife.base_count(0), knife.base_count(1));
+        assert_eq!(knife.base_count(200), knife.base_count(8));
+    }
+
+    #[test]
+    fn effective_cooldown_caps_reduction_at_ninety_percent() {
+        let registry = WeaponRegistry::with_builtin_weapons();
+        let whip = registry.get(WeaponType::Whip).unwrap();
+        let base = whip.base_cooldown(1);
+        assert!((whip.effective_cooldown(1, 0.3) - base * 0.7).abs() < 1e-6);
+        assert!((whip.effective_cooldown(1, 5.0) - base * 0.1).abs() < 1e-6);
+        assert_eq!(whip.effective_cooldown(1, -1.0), base);
+    }
+
+    /// Registering a weapon twice keeps one entry, with the latest spec.
+    #[test]
+    fn re_registering_replaces_spec() {
+        let mut registry = WeaponRegistry::with_builtin_weapons();
+        let count = registry.iter().count();
+        let mut whip = *registry.get(WeaponType::Whip).unwrap();
+        whip.name.en = "Lash";
+        registry.register(whip);
+
+        assert_eq!(registry.iter().count(), count);
+        assert_eq!(registry.name(WeaponType::Whip, Language::English), "Lash");
+    }
+
+    /// Ten base weapons and nine evolutions, with unique short labels.
+    #[test]
+    fn builtin_weapons_are_complete() {
+        let registry = WeaponRegistry::with_builtin_weapons();
+        assert_eq!(registry.base_weapons().count(), 10);
+        assert_eq!(registry.iter().filter(|s| s.evolved).count(), 9);
+
+        let mut labels = HashSet::new();
+        for spec in registry.iter() {
+            for text in [spec.name, spec.description] {
+                assert!(
+                    !text.en.is_empty() && !text.ja.is_empty(),
+                    "{:?} needs a name and description in every language",
+                    spec.weapon_type
+                );
+            }
+            assert!(
+                spec.config_path.starts_with("config/weapons/"),
+                "{:?} config path: {}",
+                spec.weapon_type,
+                spec.config_path
+            );
+            assert!(
+                !spec.abbrev.is_empty() && spec.abbrev.len() <= 4,
+                "{:?} label should be short: '{}'",
+                spec.weapon_type,
+                spec.abbrev
+            );
+            assert!(
+                labels.insert(spec.abbrev),
+                "duplicate label {}",
+                spec.abbrev
+            );
+        }
+    }
+
+    /// Damage and count never decrease with level for any base weapon.
+    #[test]
+    fn base_weapon_stats_never_decrease_with_level() {
+        let registry = WeaponRegistry::with_builtin_weapons();
+        for spec in registry.iter().filter(|s| !s.evolved) {
+            for level in 2..=8u8 {
+                assert!(
+                    spec.base_damage(level) >= spec.base_damage(level - 1),
+                    "{:?} lv{level} damage decreased",
+                    spec.weapon_type
+                );
+                assert!(
+                    spec.base_count(level) >= spec.base_count(level - 1),
+                    "{:?} lv{level} count decreased",
+                    spec.weapon_type
+                );
+                assert!(
+                    spec.base_cooldown(level) <= spec.base_cooldown(level - 1),
+                    "{:?} lv{level} cooldown increased",
+                    spec.weapon_type
+                );
+            }
+        }
+    }
+
+    /// Evolutions keep their base weapon's damage type or upgrade it.
+    #[test]
+    fn evolutions_keep_or_upgrade_damage_type() {
+        let registry = WeaponRegistry::with_builtin_weapons();
+        let damage_type = |wt| registry.damage_type(wt);
+        assert_eq!(damage_type(WeaponType::Whip), DamageType::Physical);
+        assert_eq!(damage_type(WeaponType::BloodyTear), DamageType::Physical);
+        assert_eq!(damage_type(WeaponType::MagicWand), DamageType::Magic);
+        // HolyWand upgrades the Magic Wand's arcane bolts to holy damage.
+        assert_eq!(damage_type(WeaponType::HolyWand), DamageType::Holy);
+        assert_eq!(damage_type(WeaponType::Hellfire), DamageType::Fire);
+        assert_eq!(
+            damage_type(WeaponType::LightningRing),
+            DamageType::Lightning
+        );
+    }
+
+    /// Evolved weapons sound like their base weapon; the Bible family is
+    /// silent because its books orbit persistently.
+    #[test]
+    fn evolutions_share_base_fire_sound() {
+        let registry = WeaponRegistry::with_builtin_weapons();
+        for (base, evolved) in [
+            (WeaponType::Whip, WeaponType::BloodyTear),
+            (WeaponType::Knife, WeaponType::ThousandEdge),
+            (WeaponType::Garlic, WeaponType::SoulEater),
+            (WeaponType::FireWand, WeaponType::Hellfire),
+        ] {
+            assert!(registry.fire_sound(base).is_some());
+            assert_eq!(registry.fire_sound(base), registry.fire_sound(evolved));
+        }
+        assert_eq!(registry.fire_sound(WeaponType::Bible), None);
+    }
+
+    #[test]
+    fn unregistered_weapon_has_placeholder_metadata() {
+        let registry = WeaponRegistry::default();
+        assert!(registry.get(WeaponType::Whip).is_none());
+        assert_eq!(registry.name(WeaponType::Whip, Language::English), "?");
+        assert_eq!(registry.abbrev(WeaponType::Whip), "?");
+        assert_eq!(registry.damage_type(WeaponType::Whip), DamageType::Physical);
+        assert_eq!(registry.fire_sound(WeaponType::Whip), None);
+    }
+}
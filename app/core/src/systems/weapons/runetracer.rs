@@ -0,0 +1,656 @@
+//! Runetracer weapon — projectile that bounces off the edges of the screen.
+//!
+//! Its evolution NoFuture (Runetracer Lv8 + Wings) is fired by
+//! [`fire_no_future`]: more, faster and stronger bouncers that last longer,
+//! sharing the same [`RunetracerBounce`] reflection logic.
+//!
+//! Each activation fires one or more projectiles in random directions from
+//! the player.  A projectile never leaves the viewport: whenever it reaches a
+//! screen edge while heading outward, [`bounce_runetracers`] reflects it back
+//! in.  It keeps bouncing until its lifetime runs out.
+//!
+//! ## Level progression
+//!
+//! | Level | Damage | Count | Speed (px/s) | Duration (s) |
+//! |-------|--------|-------|--------------|--------------|
+//! | 1     | 10     | 1     | 250          | 2.25         |
+//! | 2     | 15     | 1     | 250          | 2.5          |
+//! | 3     | 15     | 1     | 275          | 2.75         |
+//! | 4     | 20     | 2     | 275          | 3.0          |
+//! | 5     | 25     | 2     | 300          | 3.25         |
+//! | 6     | 25     | 2     | 300          | 3.5          |
+//! | 7     | 30     | 3     | 325          | 3.75         |
+//! | 8     | 35     | 3     | 350          | 4.0          |
+//!
+//! Duration is the projectile's lifetime and is scaled by
+//! [`PlayerStats::duration_multiplier`], so Spellbinder keeps runetracers
+//! bouncing longer.
+//!
+//! ## Bounce behaviour
+//!
+//! The bounds are the camera viewport (camera position ± half the window
+//! size from `game.ron`), shrunk by the projectile's collider radius so it
+//! turns around while still fully on screen.  [`bounce_runetracers`] runs
+//! after [`move_projectiles`]; when a projectile has crossed an edge it flips
+//! the matching velocity component, clamps the position back inside and
+//! clears `hit_enemies` so enemies can be struck again on the rebound.
+//!
+//! [`move_projectiles`]: crate::systems::projectiles::move_projectiles
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use rand::RngExt;
+
+use crate::{
+    components::{CircleCollider, Player, PlayerStats, Projectile, ProjectileVelocity},
+    config::{
+        GameParams, register_weapon_config,
+        validate::CheckConfigFile,
+        weapon::runetracer::{
+            DEFAULT_COUNT_BY_LEVEL, DEFAULT_DAMAGE_BY_LEVEL, DEFAULT_DURATION_BY_LEVEL,
+            DEFAULT_NO_FUTURE_COUNT, DEFAULT_NO_FUTURE_DAMAGE, DEFAULT_SPEED_BY_LEVEL,
+            RunetracerConfigHandle, RunetracerConfigLoader, RunetracerParams,
+        },
+    },
+    events::WeaponFiredEvent,
+    resources::GameRng,
+    systems::{
+        projectiles::spawn_projectile,
+        rng::GameRngSystems,
+        weapons::{
+            WeaponSystems,
+            registry::{LocalizedText, WeaponSpec},
+        },
+    },
+    types::{DamageType, WeaponSound, WeaponType},
+};
+
+// ---------------------------------------------------------------------------
+// Fallback constants (used while RON config is still loading)
+// ---------------------------------------------------------------------------
+
+/// NoFuture projectile speed (px/s) while RON config is loading.
+const DEFAULT_NO_FUTURE_SPEED: f32 = 400.0;
+/// NoFuture bounce duration (s) while RON config is loading.
+const DEFAULT_NO_FUTURE_DURATION: f32 = 4.5;
+/// Circle collider radius for hit detection (pixels).
+const DEFAULT_RUNETRACER_COLLIDER_RADIUS: f32 = 7.0;
+/// Knockback distance per hit for Runetracer and NoFuture (pixels).
+const DEFAULT_RUNETRACER_KNOCKBACK: f32 = 5.0;
+
+/// Piercing value for runetracer projectiles.
+///
+/// `u32::MAX` lets the projectile pass through every enemy it touches; it
+/// only disappears when its duration runs out.
+const RUNETRACER_PIERCING: u32 = u32::MAX;
+
+// ---------------------------------------------------------------------------
+// Component
+// ---------------------------------------------------------------------------
+
+/// Marker component attached to every projectile spawned by
+/// [`fire_runetracer`] and [`fire_no_future`].
+///
+/// [`bounce_runetracers`] reflects these projectiles off the viewport edges.
+#[derive(Component, Debug)]
+pub struct RunetracerBounce;
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Fires Runetracer projectiles when a [`WeaponFiredEvent`] arrives.
+///
+/// Each projectile leaves the player in a random direction (from
+/// [`GameRng`], so seeded runs are reproducible) and carries
+/// [`RunetracerBounce`].  Its lifetime is the level's duration ×
+/// [`PlayerStats::duration_multiplier`].
+///
+/// [`PlayerStats::extra_projectiles`] adds to the base count so the Duplicator
+/// passive increases the number of runetracers fired.
+pub fn fire_runetracer(
+    mut fired_events: MessageReader<WeaponFiredEvent>,
+    mut commands: Commands,
+    player_q: Query<(&Transform, &PlayerStats), With<Player>>,
+    runetracer_cfg: RunetracerParams,
+    mut game_rng: ResMut<GameRng>,
+) {
+    let cfg = runetracer_cfg.get();
+    let collider_r = cfg
+        .map(|c| c.collider_radius)
+        .unwrap_or(DEFAULT_RUNETRACER_COLLIDER_RADIUS);
+    let knockback = cfg
+        .map(|c| c.knockback)
+        .unwrap_or(DEFAULT_RUNETRACER_KNOCKBACK);
+
+    for event in fired_events.read() {
+        if event.weapon_type != WeaponType::Runetracer {
+            continue;
+        }
+
+        let Ok((player_tf, stats)) = player_q.get(event.player) else {
+            continue;
+        };
+
+        let player_pos = player_tf.translation.truncate();
+        let level = event.level.clamp(1, 8) as usize;
+
+        let damage = cfg
+            .and_then(|c| c.damage_by_level.get(level - 1).copied())
+            .unwrap_or(DEFAULT_DAMAGE_BY_LEVEL[level - 1])
+            * stats.damage_multiplier;
+
+        let speed = cfg
+            .and_then(|c| c.speed_by_level.get(level - 1).copied())
+            .unwrap_or(DEFAULT_SPEED_BY_LEVEL[level - 1])
+            * stats.projectile_speed_mult;
+
+        let lifetime = cfg
+            .and_then(|c| c.duration_by_level.get(level - 1).copied())
+            .unwrap_or(DEFAULT_DURATION_BY_LEVEL[level - 1])
+            * stats.duration_multiplier;
+
+        let count = cfg
+            .and_then(|c| c.count_by_level.get(level - 1).copied())
+            .unwrap_or(DEFAULT_COUNT_BY_LEVEL[level - 1])
+            + stats.extra_projectiles;
+
+        for _ in 0..count {
+            let angle = game_rng.rng().random_range(0.0..TAU);
+            let entity = spawn_projectile(
+                &mut commands,
+                player_pos,
+                Vec2::from_angle(angle) * speed,
+                damage,
+                knockback,
+                lifetime,
+                RUNETRACER_PIERCING,
+                collider_r,
+                event.weapon_type,
+            );
+            commands.entity(entity).insert(RunetracerBounce);
+        }
+    }
+}
+
+/// Fires NoFuture projectiles when a [`WeaponFiredEvent`] for
+/// [`WeaponType::NoFuture`] arrives.
+///
+/// `no_future_count + extra_projectiles` bouncers leave the player in random
+/// directions.  Damage, speed and duration are fixed (the evolution is always
+/// at max level) and scaled by the same player multipliers as
+/// [`fire_runetracer`].
+pub fn fire_no_future(
+    mut fired_events: MessageReader<WeaponFiredEvent>,
+    mut commands: Commands,
+    player_q: Query<(&Transform, &PlayerStats), With<Player>>,
+    runetracer_cfg: RunetracerParams,
+    mut game_rng: ResMut<GameRng>,
+) {
+    let cfg = runetracer_cfg.get();
+    let base_damage = cfg
+        .map(|c| c.no_future_damage)
+        .unwrap_or(DEFAULT_NO_FUTURE_DAMAGE);
+    let base_speed = cfg
+        .map(|c| c.no_future_speed)
+        .unwrap_or(DEFAULT_NO_FUTURE_SPEED);
+    let base_duration = cfg
+        .map(|c| c.no_future_duration)
+        .unwrap_or(DEFAULT_NO_FUTURE_DURATION);
+    let base_count = cfg
+        .map(|c| c.no_future_count)
+        .unwrap_or(DEFAULT_NO_FUTURE_COUNT);
+    let collider_r = cfg
+        .map(|c| c.collider_radius)
+        .unwrap_or(DEFAULT_RUNETRACER_COLLIDER_RADIUS);
+    let knockback = cfg
+        .map(|c| c.knockback)
+        .unwrap_or(DEFAULT_RUNETRACER_KNOCKBACK);
+
+    for event in fired_events.read() {
+        if event.weapon_type != WeaponType::NoFuture {
+            continue;
+        }
+
+        let Ok((player_tf, stats)) = player_q.get(event.player) else {
+            continue;
+        };
+
+        let player_pos = player_tf.translation.truncate();
+        let damage = base_damage * stats.damage_multiplier;
+        let speed = base_speed * stats.projectile_speed_mult;
+        let lifetime = base_duration * stats.duration_multiplier;
+        let count = (base_count + stats.extra_projectiles).max(1);
+
+        for _ in 0..count {
+            let angle = game_rng.rng().random_range(0.0..TAU);
+            let entity = spawn_projectile(
+                &mut commands,
+                player_pos,
+                Vec2::from_angle(angle) * speed,
+                damage,
+                knockback,
+                lifetime,
+                RUNETRACER_PIERCING,
+                collider_r,
+                event.weapon_type,
+            );
+            commands.entity(entity).insert(RunetracerBounce);
+        }
+    }
+}
+
+/// Reflects each [`RunetracerBounce`] projectile off the viewport edges.
+///
+/// The viewport is the world-space area the [`Camera2d`]'s orthographic
+/// [`Projection`] shows, inset by the projectile's [`CircleCollider`]
+/// radius, so it follows window resizes and zoom.  Until the camera knows
+/// its viewport size (headless runs) the camera position ± half of
+/// [`GameParams::window_size`] stands in.  For each axis on which the
+/// projectile is outside that box **and** still moving outward, the
+/// velocity component is negated and the position clamped back onto the
+/// edge.  `hit_enemies` is cleared on every bounce so the rebound can strike
+/// the same enemies again.
+///
+/// This system must run **after** [`move_projectiles`] so it acts on the
+/// position that was just written this frame.
+///
+/// [`move_projectiles`]: crate::systems::projectiles::move_projectiles
+pub fn bounce_runetracers(
+    camera_q: Query<
+        (&Transform, &Camera, &Projection),
+        (With<Camera2d>, Without<RunetracerBounce>),
+    >,
+    game_cfg: GameParams,
+    mut runetracer_q: Query<
+        (
+            &mut Transform,
+            &mut ProjectileVelocity,
+            &mut Projectile,
+            &CircleCollider,
+        ),
+        With<RunetracerBounce>,
+    >,
+) {
+    let fallback = Rect::from_center_half_size(Vec2::ZERO, game_cfg.window_size() / 2.0);
+    let view = match camera_q.single() {
+        Ok((tf, camera, projection)) => {
+            let area = visible_area(camera, projection).unwrap_or(fallback);
+            let cam_pos = tf.translation.truncate();
+            Rect::from_corners(cam_pos + area.min, cam_pos + area.max)
+        }
+        Err(_) => fallback,
+    };
+
+    for (mut transform, mut velocity, mut projectile, collider) in runetracer_q.iter_mut() {
+        let inset = (view.half_size() - Vec2::splat(collider.radius)).max(Vec2::ZERO);
+        let min = view.center() - inset;
+        let max = view.center() + inset;
+        let mut pos = transform.translation.truncate();
+        let mut bounced = false;
+
+        if (pos.x < min.x && velocity.0.x < 0.0) || (pos.x > max.x && velocity.0.x > 0.0) {
+            velocity.0.x = -velocity.0.x;
+            pos.x = pos.x.clamp(min.x, max.x);
+            bounced = true;
+        }
+        if (pos.y < min.y && velocity.0.y < 0.0) || (pos.y > max.y && velocity.0.y > 0.0) {
+            velocity.0.y = -velocity.0.y;
+            pos.y = pos.y.clamp(min.y, max.y);
+            bounced = true;
+        }
+
+        if bounced {
+            transform.translation.x = pos.x;
+            transform.translation.y = pos.y;
+            projectile.hit_enemies.clear();
+        }
+    }
+}
+
+/// World-space area `camera` shows, relative to its position, or `None`
+/// while its viewport size is still unknown.
+fn visible_area(camera: &Camera, projection: &Projection) -> Option<Rect> {
+    camera.logical_viewport_size()?;
+    match projection {
+        Projection::Orthographic(ortho) => Some(ortho.area),
+        _ => None,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+/// Runetracer: projectile that bounces around the screen.
+pub const RUNETRACER: WeaponSpec = WeaponSpec {
+    weapon_type: WeaponType::Runetracer,
+    evolved: false,
+    name: LocalizedText {
+        en: "Runetracer",
+        ja: "ルーントレーサー",
+    },
+    description: LocalizedText {
+        en: "Projectile that bounces off screen edges.",
+        ja: "画面の端で跳ね返る光弾。",
+    },
+    abbrev: "Rt",
+    damage_type: DamageType::Magic,
+    fire_sound: Some(WeaponSound::ProjectileSmall),
+    config_path: "config/weapons/runetracer.ron",
+    config_check: <RunetracerConfigLoader as CheckConfigFile>::check,
+    cooldown_by_level: [3.0, 3.0, 2.8, 2.8, 2.6, 2.6, 2.4, 2.2],
+    damage_by_level: DEFAULT_DAMAGE_BY_LEVEL,
+    count_by_level: DEFAULT_COUNT_BY_LEVEL,
+};
+
+/// NO FUTURE (Runetracer + Wings): more, stronger and longer-lived bouncers.
+pub const NO_FUTURE: WeaponSpec = WeaponSpec {
+    weapon_type: WeaponType::NoFuture,
+    evolved: true,
+    name: LocalizedText {
+        en: "NO FUTURE",
+        ja: "ノーフューチャー",
+    },
+    description: LocalizedText {
+        en: "Evolved Runetracer — a swarm of ricocheting bolts.",
+        ja: "進化形ルーントレーサー — 画面を跳ね回る光弾の群れ。",
+    },
+    abbrev: "NF",
+    damage_type: DamageType::Magic,
+    fire_sound: Some(WeaponSound::ProjectileSmall),
+    config_path: "config/weapons/runetracer.ron",
+    config_check: <RunetracerConfigLoader as CheckConfigFile>::check,
+    cooldown_by_level: [2.0; 8],
+    damage_by_level: [DEFAULT_NO_FUTURE_DAMAGE; 8],
+    count_by_level: [DEFAULT_NO_FUTURE_COUNT; 8],
+};
+
+/// Registers the Runetracer and NO FUTURE: `runetracer.ron`, their fire
+/// systems and the screen-edge bounce.
+pub struct RunetracerPlugin;
+
+impl Plugin for RunetracerPlugin {
+    fn build(&self, app: &mut App) {
+        use crate::systems::projectiles::{
+            collision::projectile_enemy_collision, move_projectiles,
+        };
+        register_weapon_config(
+            app,
+            RUNETRACER.config_path,
+            RunetracerConfigLoader,
+            RunetracerConfigHandle,
+        );
+        app.add_systems(
+            Update,
+            (
+                (
+                    fire_runetracer.in_set(GameRngSystems::Weapon(WeaponType::Runetracer)),
+                    fire_no_future.in_set(GameRngSystems::Weapon(WeaponType::NoFuture)),
+                )
+                    .in_set(WeaponSystems::Fire),
+                bounce_runetracers
+                    .after(move_projectiles)
+                    .before(projectile_enemy_collision)
+                    .in_set(WeaponSystems::Effects),
+            ),
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce as _;
+
+    use super::*;
+
+    /// Half of the built-in 1280×720 viewport (no `game.ron` in tests).
+    const HALF_WIDTH: f32 = 640.0;
+    const HALF_HEIGHT: f32 = 360.0;
+
+    // -----------------------------------------------------------------------
+    // Helpers
+    // -----------------------------------------------------------------------
+
+    fn build_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<WeaponFiredEvent>();
+        app.insert_resource(GameRng::from_seed(0));
+        app.world_mut().spawn((Camera2d, Transform::default()));
+        app
+    }
+
+    fn spawn_player(app: &mut App, stats: PlayerStats) -> Entity {
+        app.world_mut()
+            .spawn((Player, stats, Transform::from_xyz(0.0, 0.0, 10.0)))
+            .id()
+    }
+
+    fn fire(app: &mut App, player: Entity, weapon_type: WeaponType, level: u8) {
+        app.world_mut().write_message(WeaponFiredEvent {
+            player,
+            weapon_type,
+            level,
+        });
+        app.world_mut()
+            .run_system_once(fire_runetracer)
+            .expect("fire_runetracer should run");
+        app.world_mut()
+            .run_system_once(fire_no_future)
+            .expect("fire_no_future should run");
+        app.world_mut().flush();
+    }
+
+    /// `(weapon_type, damage, piercing, lifetime, velocity)` of every
+    /// runetracer projectile.
+    fn projectiles(app: &mut App) -> Vec<(WeaponType, f32, u32, f32, Vec2)> {
+        app.world_mut()
+            .query_filtered::<(&Projectile, &ProjectileVelocity), With<RunetracerBounce>>()
+            .iter(app.world())
+            .map(|(p, v)| (p.weapon_type, p.damage, p.piercing, p.lifetime, v.0))
+            .collect()
+    }
+
+    fn spawn_tracer(app: &mut App, pos: Vec2, velocity: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                Projectile {
+                    damage: 10.0,
+                    piercing: u32::MAX,
+                    hit_enemies: vec![Entity::PLACEHOLDER],
+                    lifetime: 3.0,
+                    weapon_type: WeaponType::Runetracer,
+                    knockback: 0.0,
+                },
+                ProjectileVelocity(velocity),
+                CircleCollider { radius: 5.0 },
+                RunetracerBounce,
+                Transform::from_xyz(pos.x, pos.y, 5.0),
+            ))
+            .id()
+    }
+
+    fn bounce(app: &mut App) {
+        app.world_mut()
+            .run_system_once(bounce_runetracers)
+            .expect("bounce_runetracers should run");
+    }
+
+    // -----------------------------------------------------------------------
+    // fire tests
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn level_1_fires_one_bouncing_projectile_at_full_speed() {
+        let mut app = build_app();
+        let player = spawn_player(&mut app, PlayerStats::default());
+        fire(&mut app, player, WeaponType::Runetracer, 1);
+
+        let fired = projectiles(&mut app);
+        assert_eq!(fired.len(), 1);
+        let (weapon_type, _, piercing, _, velocity) = fired[0];
+        assert_eq!(weapon_type, WeaponType::Runetracer);
+        assert_eq!(piercing, RUNETRACER_PIERCING);
+        assert!((velocity.length() - DEFAULT_SPEED_BY_LEVEL[0]).abs() < 1e-3);
+    }
+
+    #[test]
+    fn count_follows_level_table_plus_extra_projectiles() {
+        let mut app = build_app();
+        let player = spawn_player(
+            &mut app,
+            PlayerStats {
+                extra_projectiles: 1,
+                ..default()
+            },
+        );
+        fire(&mut app, player, WeaponType::Runetracer, 7);
+        assert_eq!(projectiles(&mut app).len(), 4, "3 at level 7 + 1 extra");
+    }
+
+    #[test]
+    fn lifetime_scales_with_duration_multiplier() {
+        let mut app = build_app();
+        let player = spawn_player(
+            &mut app,
+            PlayerStats {
+                duration_multiplier: 1.5,
+                ..default()
+            },
+        );
+        fire(&mut app, player, WeaponType::Runetracer, 4);
+
+        let fired = projectiles(&mut app);
+        let expected = DEFAULT_DURATION_BY_LEVEL[3] * 1.5;
+        assert!(
+            fired
+                .iter()
+                .all(|&(.., lifetime, _)| (lifetime - expected).abs() < 1e-4)
+        );
+    }
+
+    #[test]
+    fn no_future_fires_evolved_projectiles() {
+        let mut app = build_app();
+        let player = spawn_player(&mut app, PlayerStats::default());
+        fire(&mut app, player, WeaponType::NoFuture, 8);
+
+        let fired = projectiles(&mut app);
+        assert_eq!(fired.len(), DEFAULT_NO_FUTURE_COUNT as usize);
+        assert!(fired.iter().all(|&(weapon_type, damage, ..)| {
+            weapon_type == WeaponType::NoFuture && damage == DEFAULT_NO_FUTURE_DAMAGE
+        }));
+    }
+
+    #[test]
+    fn other_weapons_are_ignored() {
+        let mut app = build_app();
+        let player = spawn_player(&mut app, PlayerStats::default());
+        fire(&mut app, player, WeaponType::Knife, 1);
+        assert!(projectiles(&mut app).is_empty());
+    }
+
+    // -----------------------------------------------------------------------
+    // bounce_runetracers tests
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn projectile_past_right_edge_reflects_and_is_clamped() {
+        let mut app = build_app();
+        let entity = spawn_tracer(
+            &mut app,
+            Vec2::new(HALF_WIDTH + 3.0, 0.0),
+            Vec2::new(200.0, 50.0),
+        );
+        bounce(&mut app);
+
+        let world = app.world();
+        assert_eq!(
+            world.get::<ProjectileVelocity>(entity).unwrap().0,
+            Vec2::new(-200.0, 50.0)
+        );
+        assert_eq!(
+            world.get::<Transform>(entity).unwrap().translation.x,
+            HALF_WIDTH - 5.0,
+            "clamped to the edge minus the collider radius"
+        );
+        assert!(
+            world
+                .get::<Projectile>(entity)
+                .unwrap()
+                .hit_enemies
+                .is_empty(),
+            "a bounce lets the projectile hit the same enemies again"
+        );
+    }
+
+    #[test]
+    fn corner_reflects_both_axes() {
+        let mut app = build_app();
+        let entity = spawn_tracer(
+            &mut app,
+            Vec2::new(-HALF_WIDTH, -HALF_HEIGHT),
+            Vec2::new(-100.0, -100.0),
+        );
+        bounce(&mut app);
+        assert_eq!(
+            app.world().get::<ProjectileVelocity>(entity).unwrap().0,
+            Vec2::new(100.0, 100.0)
+        );
+    }
+
+    /// A projectile outside the bounds but already heading back in is left
+    /// alone, so it cannot get stuck flipping every frame.
+    #[test]
+    fn projectile_heading_inward_is_not_reflected() {
+        let mut app = build_app();
+        let entity = spawn_tracer(
+            &mut app,
+            Vec2::new(HALF_WIDTH + 3.0, 0.0),
+            Vec2::new(-200.0, 0.0),
+        );
+        bounce(&mut app);
+
+        let world = app.world();
+        assert_eq!(
+            world.get::<ProjectileVelocity>(entity).unwrap().0,
+            Vec2::new(-200.0, 0.0)
+        );
+        assert_eq!(
+            world.get::<Projectile>(entity).unwrap().hit_enemies.len(),
+            1
+        );
+    }
+
+    /// The bounds follow the camera, not the world origin.
+    #[test]
+    fn bounds_follow_the_camera() {
+        let mut app = build_app();
+        let mut cam = app
+            .world_mut()
+            .query_filtered::<&mut Transform, With<Camera2d>>();
+        cam.single_mut(app.world_mut()).unwrap().translation.x = 1000.0;
+
+        let inside = spawn_tracer(&mut app, Vec2::new(1100.0, 0.0), Vec2::new(200.0, 0.0));
+        let outside = spawn_tracer(&mut app, Vec2::new(100.0, 0.0), Vec2::new(-200.0, 0.0));
+        bounce(&mut app);
+
+        let world = app.world();
+        assert_eq!(
+            world.get::<ProjectileVelocity>(inside).unwrap().0.x,
+            200.0,
+            "on screen relative to the camera → no bounce"
+        );
+        assert_eq!(
+            world.get::<ProjectileVelocity>(outside).unwrap().0.x,
+            200.0,
+            "off the camera's left edge → reflected"
+        );
+    }
+}
@@ -0,0 +1,623 @@
+//! Santa Water weapon — flasks that leave damaging pools on the ground.
+//!
+//! Each activation drops one or more flasks next to random enemies within
+//! [`SantaWaterConfig::target_range`] of the player (or at random spots around
+//! the player when no enemy is in range).  Every flask leaves a
+//! [`SantaWaterZone`]: a stationary pool that damages each enemy standing in
+//! it, then dries up after its duration.
+//!
+//! ## Level progression
+//!
+//! | Level | Damage/tick | Flasks | Radius (px) | Duration (s) |
+//! |-------|-------------|--------|-------------|--------------|
+//! | 1     | 10          | 1      | 40          | 2.0          |
+//! | 2     | 10          | 1      | 44          | 2.0          |
+//! | 3     | 15          | 2      | 48          | 2.25         |
+//! | 4     | 15          | 2      | 52          | 2.5          |
+//! | 5     | 20          | 2      | 56          | 2.5          |
+//! | 6     | 20          | 3      | 60          | 2.75         |
+//! | 7     | 25          | 3      | 64          | 3.0          |
+//! | 8     | 30          | 4      | 70          | 3.0          |
+//!
+//! The radius is scaled by [`PlayerStats::area_multiplier`] and the duration
+//! by [`PlayerStats::duration_multiplier`] when the flask lands; later stat
+//! changes only affect new pools.
+//!
+//! ## Damage ticks
+//!
+//! [`tick_santa_water_zones`] runs every frame.  Like the Bible's
+//! [`OrbitWeapon::hit_cooldown`](crate::components::OrbitWeapon::hit_cooldown),
+//! each pool keeps a per-enemy timer map: an enemy inside the pool is hit
+//! through [`DamageEnemyEvent`] and then ignored by that pool for
+//! [`SantaWaterConfig::hit_cooldown_secs`].  Overlapping pools each tick
+//! independently.
+//!
+//! ## Visual
+//!
+//! [`spawn_santa_water_visual`] gives every pool the same unit circle mesh
+//! and the same opaque material, scaled to the pool radius, at a fixed z
+//! below pickups.  Because identical opaque circles on one layer do not
+//! blend, overlapping pools read as one merged puddle.
+
+use std::collections::HashMap;
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+use rand::RngExt;
+
+use crate::{
+    components::{Enemy, GameSessionEntity, Player, PlayerStats},
+    config::{
+        register_weapon_config,
+        validate::CheckConfigFile,
+        weapon::santa_water::{
+            DEFAULT_COUNT_BY_LEVEL, DEFAULT_DAMAGE_BY_LEVEL, DEFAULT_DURATION_BY_LEVEL,
+            DEFAULT_RADIUS_BY_LEVEL, SantaWaterConfig, SantaWaterConfigHandle,
+            SantaWaterConfigLoader, SantaWaterParams,
+        },
+    },
+    events::{DamageEnemyEvent, WeaponFiredEvent},
+    resources::{GameRng, SpatialGrid},
+    systems::{
+        damage::knockback_from,
+        rng::GameRngSystems,
+        weapons::{
+            WeaponSystems,
+            registry::{LocalizedText, WeaponSpec},
+        },
+    },
+    types::{DamageType, WeaponSound, WeaponType},
+};
+
+// ---------------------------------------------------------------------------
+// Fallback constants (used while RON config is still loading)
+// ---------------------------------------------------------------------------
+
+/// Seconds before a pool can hit the same enemy again.
+const DEFAULT_SANTA_WATER_HIT_COOLDOWN: f32 = 0.5;
+/// Enemies within this distance of the player (px) can be targeted.
+const DEFAULT_SANTA_WATER_TARGET_RANGE: f32 = 350.0;
+/// Maximum random offset (px) of a flask from its target.
+const DEFAULT_SANTA_WATER_SCATTER_RADIUS: f32 = 40.0;
+/// Knockback distance per tick, away from the pool centre (pixels).
+const DEFAULT_SANTA_WATER_KNOCKBACK: f32 = 4.0;
+
+/// Render layer of every pool: above the ground, below light sources
+/// (z = 0.4) and pickups (z = 0.5).
+const SANTA_WATER_Z: f32 = 0.3;
+/// Pool colour (holy-water blue).  Opaque so overlapping pools merge.
+const SANTA_WATER_COLOR: Color = Color::srgb(0.35, 0.6, 1.0);
+
+// ---------------------------------------------------------------------------
+// Component
+// ---------------------------------------------------------------------------
+
+/// A Santa Water pool on the ground.
+///
+/// Spawned by [`fire_santa_water`]; its [`Transform`] scale is the pool
+/// radius (the visual is a unit circle).
+#[derive(Component, Debug)]
+pub struct SantaWaterZone {
+    /// Damage per tick to each enemy inside the pool.
+    pub damage: f32,
+    /// Pool radius in pixels (area multiplier already applied).
+    pub radius: f32,
+    /// Seconds until the pool dries up and is despawned.
+    pub remaining: f32,
+    /// Source weapon, reported on every [`DamageEnemyEvent`].
+    pub weapon_type: WeaponType,
+    /// Per-enemy hit cooldown (seconds) to prevent damage every frame.
+    pub hit_cooldown: HashMap<Entity, f32>,
+}
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Drops Santa Water flasks when a [`WeaponFiredEvent`] arrives.
+///
+/// `count + extra_projectiles` flasks are dropped.  Each picks a random enemy
+/// within `target_range` of the player (found via [`SpatialGrid`]; the same
+/// enemy may be picked twice) and lands up to `scatter_radius` pixels from
+/// it.  With no enemy in range, flasks land at random points within
+/// `target_range` of the player instead.  Randomness comes from [`GameRng`]
+/// so seeded runs are reproducible.
+///
+/// Must run after [`super::spatial::update_spatial_grid`] so the grid
+/// reflects the current frame's enemy positions.
+pub fn fire_santa_water(
+    mut fired_events: MessageReader<WeaponFiredEvent>,
+    mut commands: Commands,
+    player_q: Query<(&Transform, &PlayerStats), With<Player>>,
+    enemy_q: Query<&Transform, With<Enemy>>,
+    spatial_grid: Res<SpatialGrid>,
+    santa_water_cfg: SantaWaterParams,
+    mut game_rng: ResMut<GameRng>,
+) {
+    let cfg = santa_water_cfg.get();
+    let target_range = cfg
+        .map(|c| c.target_range)
+        .unwrap_or(DEFAULT_SANTA_WATER_TARGET_RANGE);
+    let scatter_radius = cfg
+        .map(|c| c.scatter_radius)
+        .unwrap_or(DEFAULT_SANTA_WATER_SCATTER_RADIUS);
+
+    for event in fired_events.read() {
+        if event.weapon_type != WeaponType::SantaWater {
+            continue;
+        }
+
+        let Ok((player_tf, stats)) = player_q.get(event.player) else {
+            continue;
+        };
+
+        let player_pos = player_tf.translation.truncate();
+        let (damage, radius, duration, count) = santa_water_stats(cfg, event.level, stats);
+
+        let target_range_sq = target_range * target_range;
+        let targets: Vec<Vec2> = spatial_grid
+            .get_nearby(player_pos, target_range)
+            .into_iter()
+            .filter_map(|e| enemy_q.get(e).ok())
+            .map(|tf| tf.translation.truncate())
+            .filter(|pos| pos.distance_squared(player_pos) <= target_range_sq)
+            .collect();
+
+        let rng = game_rng.rng();
+        for _ in 0..count {
+            let center = if targets.is_empty() {
+                player_pos + random_offset(rng, target_range)
+            } else {
+                targets[rng.random_range(0..targets.len())] + random_offset(rng, scatter_radius)
+            };
+            commands.spawn((
+                GameSessionEntity,
+                SantaWaterZone {
+                    damage,
+                    radius,
+                    remaining: duration,
+                    weapon_type: event.weapon_type,
+                    hit_cooldown: HashMap::new(),
+                },
+                Transform::from_xyz(center.x, center.y, SANTA_WATER_Z)
+                    .with_scale(Vec3::splat(radius)),
+            ));
+        }
+    }
+}
+
+/// Returns `(damage, radius, duration, count)` for one activation at
+/// `level`, with the player's damage, area, duration and projectile bonuses
+/// applied.
+fn santa_water_stats(
+    cfg: Option<&SantaWaterConfig>,
+    level: u8,
+    stats: &PlayerStats,
+) -> (f32, f32, f32, u32) {
+    let idx = level.clamp(1, 8) as usize - 1;
+    let damage = cfg
+        .and_then(|c| c.damage_by_level.get(idx).copied())
+        .unwrap_or(DEFAULT_DAMAGE_BY_LEVEL[idx]);
+    let radius = cfg
+        .and_then(|c| c.radius_by_level.get(idx).copied())
+        .unwrap_or(DEFAULT_RADIUS_BY_LEVEL[idx]);
+    let duration = cfg
+        .and_then(|c| c.duration_by_level.get(idx).copied())
+        .unwrap_or(DEFAULT_DURATION_BY_LEVEL[idx]);
+    let count = cfg
+        .and_then(|c| c.count_by_level.get(idx).copied())
+        .unwrap_or(DEFAULT_COUNT_BY_LEVEL[idx]);
+    (
+        damage * stats.damage_multiplier,
+        radius * stats.area_multiplier,
+        duration * stats.duration_multiplier,
+        count + stats.extra_projectiles,
+    )
+}
+
+/// Uniformly random point in a disc of `radius` around the origin.
+fn random_offset(rng: &mut impl RngExt, radius: f32) -> Vec2 {
+    let angle = rng.random_range(0.0..TAU);
+    let distance = radius * rng.random_range(0.0f32..1.0).sqrt();
+    Vec2::from_angle(angle) * distance
+}
+
+/// Ticks every [`SantaWaterZone`]: damages enemies inside it and despawns it
+/// once its duration runs out.
+///
+/// For each pool, every frame:
+/// 1. Counts `remaining` down and despawns the pool when it reaches zero.
+/// 2. Ticks down and purges the per-enemy hit cooldowns.
+/// 3. Uses [`SpatialGrid`] to find enemies within `radius` that are not on
+///    cooldown, emits a [`DamageEnemyEvent`] for each (knocking it away from
+///    the pool centre) and puts it on cooldown for `hit_cooldown_secs`.
+pub fn tick_santa_water_zones(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut zone_q: Query<(Entity, &Transform, &mut SantaWaterZone)>,
+    enemy_q: Query<&Transform, With<Enemy>>,
+    spatial_grid: Res<SpatialGrid>,
+    santa_water_cfg: SantaWaterParams,
+    mut damage_events: MessageWriter<DamageEnemyEvent>,
+) {
+    let dt = time.delta_secs();
+    let cfg = santa_water_cfg.get();
+    let hit_cooldown_secs = cfg
+        .map(|c| c.hit_cooldown_secs)
+        .unwrap_or(DEFAULT_SANTA_WATER_HIT_COOLDOWN);
+    let knockback = cfg
+        .map(|c| c.knockback)
+        .unwrap_or(DEFAULT_SANTA_WATER_KNOCKBACK);
+
+    for (entity, transform, mut zone) in zone_q.iter_mut() {
+        zone.remaining -= dt;
+        if zone.remaining <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        zone.hit_cooldown.retain(|_, cd| {
+            *cd -= dt;
+            *cd > 0.0
+        });
+
+        let center = transform.translation.truncate();
+        for enemy_entity in spatial_grid.get_nearby(center, zone.radius) {
+            if zone.hit_cooldown.contains_key(&enemy_entity) {
+                continue;
+            }
+            let Ok(enemy_tf) = enemy_q.get(enemy_entity) else {
+                continue;
+            };
+            let enemy_pos = enemy_tf.translation.truncate();
+            if enemy_pos.distance(center) <= zone.radius {
+                damage_events.write(DamageEnemyEvent {
+                    entity: enemy_entity,
+                    damage: zone.damage,
+                    weapon_type: zone.weapon_type,
+                    knockback: knockback_from(center, enemy_pos, knockback),
+                    status: None,
+                });
+                zone.hit_cooldown.insert(enemy_entity, hit_cooldown_secs);
+            }
+        }
+    }
+}
+
+/// Adds the pool visual to newly spawned [`SantaWaterZone`] entities.
+///
+/// Every pool shares one unit [`Circle`] mesh and one opaque material
+/// (created on first use), scaled by the zone's `Transform::scale`, so
+/// overlapping pools merge into a single puddle instead of stacking
+/// translucent circles.
+pub fn spawn_santa_water_visual(
+    mut commands: Commands,
+    query: Query<Entity, Added<SantaWaterZone>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut shared: Local<Option<(Handle<Mesh>, Handle<ColorMaterial>)>>,
+) {
+    if query.is_empty() {
+        return;
+    }
+    let (mesh, material) = shared.get_or_insert_with(|| {
+        (
+            meshes.add(Circle::new(1.0)),
+            materials.add(ColorMaterial::from_color(SANTA_WATER_COLOR)),
+        )
+    });
+    for entity in query.iter() {
+        commands
+            .entity(entity)
+            .try_insert((Mesh2d(mesh.clone()), MeshMaterial2d(material.clone())));
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+/// Santa Water: flasks that leave damaging pools near enemies.
+pub const SANTA_WATER: WeaponSpec = WeaponSpec {
+    weapon_type: WeaponType::SantaWater,
+    evolved: false,
+    name: LocalizedText {
+        en: "Santa Water",
+        ja: "聖水",
+    },
+    description: LocalizedText {
+        en: "Flasks leave damaging pools near enemies.",
+        ja: "敵の近くに聖水の水たまりを作る。",
+    },
+    abbrev: "SW",
+    damage_type: DamageType::Holy,
+    fire_sound: Some(WeaponSound::ProjectileLarge),
+    config_path: "config/weapons/santa_water.ron",
+    config_check: <SantaWaterConfigLoader as CheckConfigFile>::check,
+    cooldown_by_level: [4.5, 4.5, 4.2, 4.2, 4.0, 3.8, 3.6, 3.5],
+    damage_by_level: DEFAULT_DAMAGE_BY_LEVEL,
+    count_by_level: DEFAULT_COUNT_BY_LEVEL,
+};
+
+/// Registers Santa Water: `santa_water.ron`, the flask drop, the pool tick
+/// and the pool visual.
+pub struct SantaWaterPlugin;
+
+impl Plugin for SantaWaterPlugin {
+    fn build(&self, app: &mut App) {
+        use crate::systems::spatial::update_spatial_grid;
+        register_weapon_config(
+            app,
+            SANTA_WATER.config_path,
+            SantaWaterConfigLoader,
+            SantaWaterConfigHandle,
+        );
+        app.add_systems(
+            Update,
+            (
+                (
+                    fire_santa_water.in_set(GameRngSystems::Weapon(WeaponType::SantaWater)),
+                    tick_santa_water_zones,
+                )
+                    .after(update_spatial_grid)
+                    .in_set(WeaponSystems::Fire),
+                spawn_santa_water_visual.in_set(WeaponSystems::Effects),
+            ),
+        );
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::ecs::system::RunSystemOnce as _;
+
+    use super::*;
+    use crate::types::EnemyType;
+
+    // -----------------------------------------------------------------------
+    // Helpers
+    // -----------------------------------------------------------------------
+
+    fn build_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<WeaponFiredEvent>();
+        app.add_message::<DamageEnemyEvent>();
+        app.insert_resource(SpatialGrid::default());
+        app.insert_resource(GameRng::from_seed(0));
+        app
+    }
+
+    fn advance(app: &mut App, secs: f32) {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f32(secs));
+    }
+
+    fn spawn_player(app: &mut App, stats: PlayerStats) -> Entity {
+        app.world_mut()
+            .spawn((Player, stats, Transform::from_xyz(0.0, 0.0, 10.0)))
+            .id()
+    }
+
+    fn spawn_enemy(app: &mut App, pos: Vec2) -> Entity {
+        app.world_mut()
+            .spawn((
+                Enemy::from_type(EnemyType::Bat, 1.0),
+                Transform::from_xyz(pos.x, pos.y, 1.0),
+            ))
+            .id()
+    }
+
+    fn spawn_zone(app: &mut App, pos: Vec2, radius: f32, remaining: f32) -> Entity {
+        app.world_mut()
+            .spawn((
+                SantaWaterZone {
+                    damage: 10.0,
+                    radius,
+                    remaining,
+                    weapon_type: WeaponType::SantaWater,
+                    hit_cooldown: HashMap::new(),
+                },
+                Transform::from_xyz(pos.x, pos.y, SANTA_WATER_Z).with_scale(Vec3::splat(radius)),
+            ))
+            .id()
+    }
+
+    fn fire(app: &mut App, player: Entity, level: u8) {
+        app.world_mut()
+            .run_system_once(crate::systems::spatial::update_spatial_grid)
+            .unwrap();
+        app.world_mut().write_message(WeaponFiredEvent {
+            player,
+            weapon_type: WeaponType::SantaWater,
+            level,
+        });
+        app.world_mut()
+            .run_system_once(fire_santa_water)
+            .expect("fire_santa_water should run");
+        app.world_mut().flush();
+    }
+
+    fn tick(app: &mut App, secs: f32) {
+        advance(app, secs);
+        app.world_mut()
+            .run_system_once(crate::systems::spatial::update_spatial_grid)
+            .unwrap();
+        app.world_mut()
+            .run_system_once(tick_santa_water_zones)
+            .expect("tick_santa_water_zones should run");
+        app.world_mut().flush();
+    }
+
+    fn zones(app: &mut App) -> Vec<(Vec2, f32, f32)> {
+        app.world_mut()
+            .query::<(&Transform, &SantaWaterZone)>()
+            .iter(app.world())
+            .map(|(tf, z)| (tf.translation.truncate(), z.radius, z.remaining))
+            .collect()
+    }
+
+    fn damage_events(app: &App) -> Vec<DamageEnemyEvent> {
+        let messages = app.world().resource::<Messages<DamageEnemyEvent>>();
+        messages.get_cursor().read(messages).cloned().collect()
+    }
+
+    // -----------------------------------------------------------------------
+    // fire_santa_water tests
+    // -----------------------------------------------------------------------
+
+    /// Flasks land within `scatter_radius` of an enemy in range.
+    #[test]
+    fn flasks_land_near_enemies() {
+        let mut app = build_app();
+        let player = spawn_player(&mut app, PlayerStats::default());
+        let enemy_pos = Vec2::new(200.0, 50.0);
+        spawn_enemy(&mut app, enemy_pos);
+        fire(&mut app, player, 3);
+
+        let zones = zones(&mut app);
+        assert_eq!(zones.len(), 2, "level 3 drops 2 flasks");
+        for (pos, ..) in zones {
+            assert!(pos.distance(enemy_pos) <= DEFAULT_SANTA_WATER_SCATTER_RADIUS + 1e-3);
+        }
+    }
+
+    /// With no enemy in range, flasks still land near the player.
+    #[test]
+    fn flasks_land_near_player_without_targets() {
+        let mut app = build_app();
+        let player = spawn_player(&mut app, PlayerStats::default());
+        spawn_enemy(&mut app, Vec2::new(5000.0, 0.0));
+        fire(&mut app, player, 1);
+
+        let zones = zones(&mut app);
+        assert_eq!(zones.len(), 1);
+        assert!(zones[0].0.length() <= DEFAULT_SANTA_WATER_TARGET_RANGE + 1e-3);
+    }
+
+    /// Pool radius follows `area_multiplier`, lifetime follows
+    /// `duration_multiplier` and the count includes `extra_projectiles`.
+    #[test]
+    fn pools_scale_with_player_stats() {
+        let mut app = build_app();
+        let player = spawn_player(
+            &mut app,
+            PlayerStats {
+                area_multiplier: 1.5,
+                duration_multiplier: 2.0,
+                extra_projectiles: 1,
+                ..default()
+            },
+        );
+        fire(&mut app, player, 1);
+
+        let zones = zones(&mut app);
+        assert_eq!(zones.len(), 2, "1 flask at level 1 + 1 extra");
+        for (_, radius, remaining) in zones {
+            assert_eq!(radius, DEFAULT_RADIUS_BY_LEVEL[0] * 1.5);
+            assert_eq!(remaining, DEFAULT_DURATION_BY_LEVEL[0] * 2.0);
+        }
+    }
+
+    /// Other weapons' events drop nothing.
+    #[test]
+    fn other_weapons_are_ignored() {
+        let mut app = build_app();
+        let player = spawn_player(&mut app, PlayerStats::default());
+        app.world_mut().write_message(WeaponFiredEvent {
+            player,
+            weapon_type: WeaponType::Garlic,
+            level: 1,
+        });
+        app.world_mut().run_system_once(fire_santa_water).unwrap();
+        app.world_mut().flush();
+        assert!(zones(&mut app).is_empty());
+    }
+
+    // -----------------------------------------------------------------------
+    // tick_santa_water_zones tests
+    // -----------------------------------------------------------------------
+
+    /// An enemy inside a pool is hit once, then again only after the hit
+    /// cooldown expires; an enemy outside is never hit.
+    #[test]
+    fn pool_damages_enemies_inside_on_a_per_enemy_cooldown() {
+        let mut app = build_app();
+        spawn_zone(&mut app, Vec2::ZERO, 50.0, 10.0);
+        let inside = spawn_enemy(&mut app, Vec2::new(30.0, 0.0));
+        spawn_enemy(&mut app, Vec2::new(80.0, 0.0));
+
+        tick(&mut app, 1.0 / 60.0);
+        tick(&mut app, 1.0 / 60.0);
+        let events = damage_events(&app);
+        assert_eq!(events.len(), 1, "second frame is within the cooldown");
+        assert_eq!(events[0].entity, inside);
+        assert_eq!(events[0].weapon_type, WeaponType::SantaWater);
+        assert!(events[0].knockback.x > 0.0, "pushed away from the centre");
+
+        tick(&mut app, DEFAULT_SANTA_WATER_HIT_COOLDOWN);
+        assert_eq!(damage_events(&app).len(), 2, "hit again after the cooldown");
+    }
+
+    /// Overlapping pools tick independently.
+    #[test]
+    fn overlapping_pools_each_damage() {
+        let mut app = build_app();
+        spawn_zone(&mut app, Vec2::ZERO, 50.0, 10.0);
+        spawn_zone(&mut app, Vec2::new(40.0, 0.0), 50.0, 10.0);
+        spawn_enemy(&mut app, Vec2::new(20.0, 0.0));
+
+        tick(&mut app, 1.0 / 60.0);
+        assert_eq!(damage_events(&app).len(), 2);
+    }
+
+    /// A pool is despawned once its duration runs out.
+    #[test]
+    fn pool_dries_up_after_duration() {
+        let mut app = build_app();
+        let zone = spawn_zone(&mut app, Vec2::ZERO, 50.0, 0.5);
+
+        tick(&mut app, 0.25);
+        assert!(app.world().get_entity(zone).is_ok());
+        tick(&mut app, 0.3);
+        assert!(app.world().get_entity(zone).is_err());
+    }
+
+    // -----------------------------------------------------------------------
+    // spawn_santa_water_visual tests
+    // -----------------------------------------------------------------------
+
+    /// Every pool shares one mesh and one material so overlaps merge.
+    #[test]
+    fn pools_share_one_material() {
+        let mut app = build_app();
+        app.init_resource::<Assets<Mesh>>();
+        app.init_resource::<Assets<ColorMaterial>>();
+        // A registered system keeps its `Local` between runs.
+        let visual = app.world_mut().register_system(spawn_santa_water_visual);
+        let a = spawn_zone(&mut app, Vec2::ZERO, 50.0, 10.0);
+        app.world_mut().run_system(visual).unwrap();
+        let b = spawn_zone(&mut app, Vec2::new(30.0, 0.0), 60.0, 10.0);
+        app.world_mut().run_system(visual).unwrap();
+
+        let world = app.world();
+        let material = |e: Entity| {
+            world
+                .get::<MeshMaterial2d<ColorMaterial>>(e)
+                .unwrap()
+                .0
+                .id()
+        };
+        let mesh = |e: Entity| world.get::<Mesh2d>(e).unwrap().0.id();
+        assert_eq!(material(a), material(b));
+        assert_eq!(mesh(a), mesh(b));
+        assert_eq!(world.resource::<Assets<ColorMaterial>>().len(), 1);
+    }
+}
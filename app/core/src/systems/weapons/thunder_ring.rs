@@ -37,23 +37,31 @@ use bevy::prelude::*;
 
 use crate::{
     components::{Enemy, GameSessionEntity, Player, PlayerStats},
-    config::weapon::thunder_ring::ThunderRingParams,
+    config::{
+        register_weapon_config,
+        validate::CheckConfigFile,
+        weapon::thunder_ring::{
+            DEFAULT_COUNT_BY_LEVEL, DEFAULT_DAMAGE_BY_LEVEL, ThunderRingConfigHandle,
+            ThunderRingConfigLoader, ThunderRingParams,
+        },
+    },
     events::{DamageEnemyEvent, WeaponFiredEvent},
-    resources::SpatialGrid,
-    types::WeaponType,
+    resources::{GameRng, SpatialGrid},
+    systems::{
+        damage::knockback_from,
+        rng::GameRngSystems,
+        weapons::{
+            WeaponSystems,
+            registry::{LocalizedText, WeaponSpec},
+        },
+    },
+    types::{DamageType, WeaponSound, WeaponType},
 };
 
 // ---------------------------------------------------------------------------
 // Fallback constants (used while RON config is still loading)
 // ---------------------------------------------------------------------------
 
-/// Damage per strike at each weapon level (index 0 = level 1).
-const DEFAULT_THUNDER_RING_DAMAGE_BY_LEVEL: [f32; 8] =
-    [40.0, 50.0, 60.0, 60.0, 70.0, 80.0, 90.0, 100.0];
-
-/// Number of strikes per activation at each weapon level.
-const DEFAULT_THUNDER_RING_COUNT_BY_LEVEL: [u32; 8] = [1, 1, 2, 2, 3, 3, 3, 4];
-
 /// Fallback visual effect duration while RON config is still loading.
 const DEFAULT_THUNDER_RING_EFFECT_DURATION: f32 = 0.2;
 
@@ -70,6 +78,10 @@ const DEFAULT_THUNDER_RING_STRIKE_Z: f32 = 6.0;
 /// Approximates the visible screen radius so only on-screen enemies are struck.
 const DEFAULT_THUNDER_RING_TARGET_RANGE: f32 = 800.0;
 
+/// Fallback knockback distance per strike, away from the player (pixels).
+/// Zero: lightning damages without pushing.
+const DEFAULT_THUNDER_RING_KNOCKBACK: f32 = 0.0;
+
 // ---------------------------------------------------------------------------
 // Component
 // ---------------------------------------------------------------------------
@@ -101,6 +113,7 @@ pub struct ThunderStrikeEffect {
 /// Every event is processed independently — multiple events in the same frame
 /// (e.g. catch-up ticks from [`super::weapon_cooldown::tick_weapon_cooldowns`]
 /// after a frame hitch) each trigger a full activation.
+#[allow(clippy::too_many_arguments)]
 pub fn fire_thunder_ring(
     mut fired_events: MessageReader<WeaponFiredEvent>,
     player_q: Query<(&Transform, &PlayerStats), With<Player>>,
@@ -109,6 +122,7 @@ pub fn fire_thunder_ring(
     thunder_cfg: ThunderRingParams,
     mut damage_events: MessageWriter<DamageEnemyEvent>,
     mut commands: Commands,
+    mut game_rng: ResMut<GameRng>,
 ) {
     let cfg = thunder_cfg.get();
 
@@ -127,10 +141,10 @@ pub fn fire_thunder_ring(
 
         let base_damage = cfg
             .and_then(|c| c.damage_by_level.get(level - 1).copied())
-            .unwrap_or(DEFAULT_THUNDER_RING_DAMAGE_BY_LEVEL[level - 1]);
+            .unwrap_or(DEFAULT_DAMAGE_BY_LEVEL[level - 1]);
         let base_count = cfg
             .and_then(|c| c.count_by_level.get(level - 1).copied())
-            .unwrap_or(DEFAULT_THUNDER_RING_COUNT_BY_LEVEL[level - 1]);
+            .unwrap_or(DEFAULT_COUNT_BY_LEVEL[level - 1]);
         let effect_duration = cfg
             .map(|c| c.effect_duration)
             .unwrap_or(DEFAULT_THUNDER_RING_EFFECT_DURATION);
@@ -149,6 +163,9 @@ pub fn fire_thunder_ring(
         let target_range = cfg
             .map(|c| c.target_range)
             .unwrap_or(DEFAULT_THUNDER_RING_TARGET_RANGE);
+        let knockback = cfg
+            .map(|c| c.knockback)
+            .unwrap_or(DEFAULT_THUNDER_RING_KNOCKBACK);
 
         // Collect enemies within target_range using SpatialGrid so that only
         // on-screen (or near-screen) enemies are considered as targets.
@@ -174,7 +191,7 @@ pub fn fire_thunder_ring(
 
         // Fisher-Yates partial shuffle: move `pick_count` random entries to
         // the front of `candidates` so they can be taken as unique targets.
-        let mut rng = rand::rng();
+        let rng = game_rng.rng();
         for i in 0..pick_count {
             let j = i + rng.random_range(0..(candidates.len() - i));
             candidates.swap(i, j);
@@ -185,6 +202,8 @@ pub fn fire_thunder_ring(
                 entity: *enemy_entity,
                 damage,
                 weapon_type: event.weapon_type,
+                knockback: knockback_from(player_pos, *enemy_pos, knockback),
+                status: None,
             });
             let (r, g, b, a) = visual_color;
             commands.spawn((
@@ -218,6 +237,78 @@ pub fn despawn_thunder_effects(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+/// Thunder Ring: lightning strikes on random nearby enemies.
+pub const THUNDER_RING: WeaponSpec = WeaponSpec {
+    weapon_type: WeaponType::ThunderRing,
+    evolved: false,
+    name: LocalizedText {
+        en: "Thunder Ring",
+        ja: "サンダーリング",
+    },
+    description: LocalizedText {
+        en: "Random lightning strikes across the screen.",
+        ja: "画面全体にランダムな落雷。",
+    },
+    abbrev: "TR",
+    damage_type: DamageType::Lightning,
+    fire_sound: Some(WeaponSound::Thunder),
+    config_path: "config/weapons/thunder_ring.ron",
+    config_check: <ThunderRingConfigLoader as CheckConfigFile>::check,
+    cooldown_by_level: [2.0, 1.7, 1.7, 1.5, 1.5, 1.3, 1.3, 1.0],
+    damage_by_level: DEFAULT_DAMAGE_BY_LEVEL,
+    count_by_level: DEFAULT_COUNT_BY_LEVEL,
+};
+
+/// Lightning Ring (Thunder Ring + Duplicator): more, stronger strikes.
+pub const LIGHTNING_RING: WeaponSpec = WeaponSpec {
+    weapon_type: WeaponType::LightningRing,
+    evolved: true,
+    name: LocalizedText {
+        en: "Lightning Ring",
+        ja: "雷のリング",
+    },
+    description: LocalizedText {
+        en: "Evolved Thunder Ring — storm of lightning.",
+        ja: "進化形サンダーリング — 雷の嵐。",
+    },
+    abbrev: "LR",
+    damage_type: DamageType::Lightning,
+    fire_sound: Some(WeaponSound::Thunder),
+    config_path: "config/weapons/thunder_ring.ron",
+    config_check: <ThunderRingConfigLoader as CheckConfigFile>::check,
+    cooldown_by_level: [0.7; 8],
+    damage_by_level: [100.0; 8],
+    count_by_level: [4; 8],
+};
+
+/// Registers the Thunder Ring and Lightning Ring: `thunder_ring.ron`, the
+/// strike system and its flash effect.
+pub struct ThunderRingPlugin;
+
+impl Plugin for ThunderRingPlugin {
+    fn build(&self, app: &mut App) {
+        register_weapon_config(
+            app,
+            THUNDER_RING.config_path,
+            ThunderRingConfigLoader,
+            ThunderRingConfigHandle,
+        );
+        app.add_systems(
+            Update,
+            (
+                fire_thunder_ring
+                    .in_set(WeaponSystems::Fire)
+                    .in_set(GameRngSystems::Weapon(WeaponType::ThunderRing)),
+                despawn_thunder_effects.in_set(WeaponSystems::Effects),
+            ),
+        );
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -247,6 +338,7 @@ mod tests {
         app.add_message::<WeaponFiredEvent>();
         app.add_message::<DamageEnemyEvent>();
         app.insert_resource(SpatialGrid::default());
+        app.insert_resource(GameRng::from_seed(0));
         app
     }
 
@@ -471,16 +563,16 @@ mod tests {
     /// Damage increases with level.
     #[test]
     fn thunder_ring_damage_increases_with_level() {
-        let lv1 = DEFAULT_THUNDER_RING_DAMAGE_BY_LEVEL[0];
-        let lv8 = DEFAULT_THUNDER_RING_DAMAGE_BY_LEVEL[7];
+        let lv1 = DEFAULT_DAMAGE_BY_LEVEL[0];
+        let lv8 = DEFAULT_DAMAGE_BY_LEVEL[7];
         assert!(lv8 > lv1, "Lv8 damage ({lv8}) should exceed Lv1 ({lv1})");
     }
 
     /// Strike count increases at higher levels.
     #[test]
     fn thunder_ring_count_increases_with_level() {
-        let lv1 = DEFAULT_THUNDER_RING_COUNT_BY_LEVEL[0];
-        let lv8 = DEFAULT_THUNDER_RING_COUNT_BY_LEVEL[7];
+        let lv1 = DEFAULT_COUNT_BY_LEVEL[0];
+        let lv8 = DEFAULT_COUNT_BY_LEVEL[7];
         assert!(lv8 > lv1, "Lv8 count ({lv8}) should exceed Lv1 ({lv1})");
     }
 
@@ -601,4 +693,34 @@ mod tests {
             "effect should be despawned after its lifetime expires"
         );
     }
+
+    /// The Thunder Ring spec's damage and strike count match the tables in
+    /// `thunder_ring.ron`.
+    #[test]
+    fn thunder_ring_spec_matches_ron_config() {
+        use crate::config::weapon::thunder_ring::{ThunderRingConfig, ThunderRingConfigPartial};
+        let partial: ThunderRingConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(include_str!(
+                "../../../../vampire-survivors/assets/config/weapons/thunder_ring.ron"
+            ))
+            .expect("thunder_ring.ron should parse");
+        let cfg = ThunderRingConfig::from(partial);
+        for (i, &expected) in cfg.damage_by_level.iter().enumerate() {
+            let level = i as u8 + 1;
+            assert_eq!(
+                THUNDER_RING.base_damage(level),
+                expected,
+                "ThunderRing lv{level}"
+            );
+        }
+        for (i, &expected) in cfg.count_by_level.iter().enumerate() {
+            let level = i as u8 + 1;
+            assert_eq!(
+                THUNDER_RING.base_count(level),
+                expected,
+                "ThunderRing lv{level}"
+            );
+        }
+    }
 }
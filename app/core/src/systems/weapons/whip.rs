@@ -22,10 +22,21 @@ use bevy::prelude::*;
 
 use crate::{
     components::{Enemy, GameSessionEntity, Player, PlayerStats, PlayerWhipSide},
-    config::weapon::whip::WhipParams,
+    config::{
+        register_weapon_config,
+        validate::CheckConfigFile,
+        weapon::whip::{
+            DEFAULT_BASE_DAMAGE, DEFAULT_DAMAGE_PER_LEVEL, WhipConfigHandle, WhipConfigLoader,
+            WhipParams,
+        },
+    },
     events::{DamageEnemyEvent, WeaponFiredEvent},
     resources::SpatialGrid,
-    types::{WeaponType, WhipSide},
+    systems::weapons::{
+        WeaponSystems,
+        registry::{LocalizedText, WeaponSpec, linear_by_level},
+    },
+    types::{DamageType, WeaponSound, WeaponType, WhipSide},
 };
 
 // ---------------------------------------------------------------------------
@@ -34,14 +45,12 @@ use crate::{
 
 /// Reach of the Whip in pixels (before area_multiplier is applied).
 const DEFAULT_WHIP_RANGE: f32 = 160.0;
-/// Base damage of the Whip at level 1.
-const DEFAULT_WHIP_BASE_DAMAGE: f32 = 20.0;
-/// Additional damage per weapon level above 1.
-const DEFAULT_WHIP_DAMAGE_PER_LEVEL: f32 = 10.0;
 /// How long the swing visual stays on screen (seconds).
 const DEFAULT_WHIP_EFFECT_DURATION: f32 = 0.15;
 /// Vertical spread factor: enemy passes when `rel.y.abs() < range * factor`.
 const DEFAULT_WHIP_SPREAD_FACTOR: f32 = 0.6;
+/// Horizontal knockback distance per hit (pixels).
+const DEFAULT_WHIP_KNOCKBACK: f32 = 30.0;
 /// BloodyTear range multiplier relative to base Whip range (2× larger hitbox).
 const DEFAULT_BLOODY_TEAR_RANGE_MULT: f32 = 2.0;
 /// HP restored to the player per enemy hit by BloodyTear.
@@ -87,18 +96,17 @@ pub fn fire_whip(
 ) {
     let cfg = whip_cfg.get();
     let range_base = cfg.map(|c| c.range).unwrap_or(DEFAULT_WHIP_RANGE);
-    let base_damage = cfg
-        .map(|c| c.base_damage)
-        .unwrap_or(DEFAULT_WHIP_BASE_DAMAGE);
+    let base_damage = cfg.map(|c| c.base_damage).unwrap_or(DEFAULT_BASE_DAMAGE);
     let dmg_per_level = cfg
         .map(|c| c.damage_per_level)
-        .unwrap_or(DEFAULT_WHIP_DAMAGE_PER_LEVEL);
+        .unwrap_or(DEFAULT_DAMAGE_PER_LEVEL);
     let effect_dur = cfg
         .map(|c| c.effect_duration)
         .unwrap_or(DEFAULT_WHIP_EFFECT_DURATION);
     let spread = cfg
         .map(|c| c.spread_factor)
         .unwrap_or(DEFAULT_WHIP_SPREAD_FACTOR);
+    let knockback = cfg.map(|c| c.knockback).unwrap_or(DEFAULT_WHIP_KNOCKBACK);
 
     for event in fired_events.read() {
         if event.weapon_type != WeaponType::Whip && event.weapon_type != WeaponType::BloodyTear {
@@ -138,6 +146,9 @@ pub fn fire_whip(
                     entity: enemy_entity,
                     damage,
                     weapon_type: event.weapon_type,
+                    // Lashes push enemies further along the swing side.
+                    knockback: Vec2::new(direction * knockback, 0.0),
+                    status: None,
                 });
                 hits += 1;
             }
@@ -184,6 +195,73 @@ pub fn despawn_whip_effects(
     }
 }
 
+// ---------------------------------------------------------------------------
+// Plugin
+// ---------------------------------------------------------------------------
+
+/// Whip: alternating fan-shaped lash.
+pub const WHIP: WeaponSpec = WeaponSpec {
+    weapon_type: WeaponType::Whip,
+    evolved: false,
+    name: LocalizedText {
+        en: "Whip",
+        ja: "ムチ",
+    },
+    description: LocalizedText {
+        en: "Fan-shaped swing, alternating sides.",
+        ja: "左右交互に扇状の斬撃。",
+    },
+    abbrev: "Wh",
+    damage_type: DamageType::Physical,
+    fire_sound: Some(WeaponSound::Whip),
+    config_path: "config/weapons/whip.ron",
+    config_check: <WhipConfigLoader as CheckConfigFile>::check,
+    cooldown_by_level: [1.0, 1.0, 1.0, 0.8, 0.8, 0.7, 0.7, 0.6],
+    damage_by_level: linear_by_level(DEFAULT_BASE_DAMAGE, DEFAULT_DAMAGE_PER_LEVEL),
+    count_by_level: [1; 8],
+};
+
+/// Bloody Tear (Whip + Hollow Heart): double reach, heals on every hit.
+pub const BLOODY_TEAR: WeaponSpec = WeaponSpec {
+    weapon_type: WeaponType::BloodyTear,
+    evolved: true,
+    name: LocalizedText {
+        en: "Bloody Tear",
+        ja: "血の涙",
+    },
+    description: LocalizedText {
+        en: "Evolved Whip — massive area slash.",
+        ja: "進化形ムチ — 広範囲の大斬撃。",
+    },
+    abbrev: "BT",
+    damage_type: DamageType::Physical,
+    fire_sound: Some(WeaponSound::Whip),
+    config_path: "config/weapons/whip.ron",
+    config_check: <WhipConfigLoader as CheckConfigFile>::check,
+    cooldown_by_level: [0.5; 8],
+    damage_by_level: [90.0; 8],
+    count_by_level: [1; 8],
+};
+
+/// Registers the Whip and Bloody Tear: `whip.ron` and the swing systems.
+pub struct WhipPlugin;
+
+impl Plugin for WhipPlugin {
+    fn build(&self, app: &mut App) {
+        use crate::systems::spatial::update_spatial_grid;
+        register_weapon_config(app, WHIP.config_path, WhipConfigLoader, WhipConfigHandle);
+        app.add_systems(
+            Update,
+            (
+                fire_whip
+                    .after(update_spatial_grid)
+                    .in_set(WeaponSystems::Fire),
+                despawn_whip_effects.in_set(WeaponSystems::Effects),
+            ),
+        );
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -199,6 +277,7 @@ mod tests {
         components::WeaponInventory,
         events::WeaponFiredEvent,
         resources::SpatialGrid,
+        systems::weapons::registry::WeaponRegistry,
         types::{WeaponState, WeaponType, WhipSide},
     };
 
@@ -208,7 +287,7 @@ mod tests {
 
     /// Test helper: compute Whip damage using DEFAULT_* fallback values.
     fn whip_damage_for_level(level: u8) -> f32 {
-        DEFAULT_WHIP_BASE_DAMAGE + DEFAULT_WHIP_DAMAGE_PER_LEVEL * (level.clamp(1, 8) as f32 - 1.0)
+        DEFAULT_BASE_DAMAGE + DEFAULT_DAMAGE_PER_LEVEL * (level.clamp(1, 8) as f32 - 1.0)
     }
 
     fn build_app() -> App {
@@ -217,6 +296,7 @@ mod tests {
         app.add_message::<WeaponFiredEvent>();
         app.add_message::<DamageEnemyEvent>();
         app.insert_resource(SpatialGrid::default());
+        app.insert_resource(WeaponRegistry::with_builtin_weapons());
         app
     }
 
@@ -561,4 +641,23 @@ mod tests {
             stats.current_hp
         );
     }
+
+    /// The Whip spec's damage matches the `whip.ron` formula at every level,
+    /// so HUD and level-up stats agree with what the swing deals.
+    #[test]
+    fn whip_spec_matches_ron_config() {
+        use crate::config::weapon::whip::{WhipConfig, WhipConfigPartial};
+        let partial: WhipConfigPartial = ron::Options::default()
+            .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+            .from_str(include_str!(
+                "../../../../vampire-survivors/assets/config/weapons/whip.ron"
+            ))
+            .expect("whip.ron should parse");
+        let cfg = WhipConfig::from(partial);
+        for level in 1..=8u8 {
+            let expected = cfg.base_damage + cfg.damage_per_level * (level as f32 - 1.0);
+            assert_eq!(WHIP.base_damage(level), expected, "Whip lv{level}");
+            assert_eq!(whip_damage_for_level(level), expected, "Whip lv{level}");
+        }
+    }
 }
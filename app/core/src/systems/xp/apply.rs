@@ -994,6 +994,38 @@ mod tests {
         );
     }
 
+    /// Reloading `passive.ron` re-runs the recalculation for the live player.
+    #[test]
+    fn passive_config_reload_recalculates_stats() {
+        use crate::config::{PassiveConfig, hot_reload_passive_config};
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<AssetEvent<PassiveConfig>>();
+        app.add_systems(
+            bevy::app::Update,
+            (hot_reload_passive_config, recalculate_player_stats).chain(),
+        );
+        let entity = spawn_player_with_base(&mut app);
+        app.update();
+
+        app.world_mut()
+            .get_mut::<PlayerStats>(entity)
+            .unwrap()
+            .damage_multiplier = 999.0;
+        app.world_mut()
+            .write_message(AssetEvent::<PassiveConfig>::Modified {
+                id: AssetId::default(),
+            });
+        app.update();
+
+        let dm = app
+            .world()
+            .get::<PlayerStats>(entity)
+            .unwrap()
+            .damage_multiplier;
+        assert_eq!(dm, 1.0, "stats should be rebuilt from the base values");
+    }
+
     /// After adding a passive to the inventory, recalculate updates stats.
     #[test]
     fn recalculate_updates_stats_after_passive_added() {
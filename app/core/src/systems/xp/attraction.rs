@@ -3,24 +3,29 @@
 //! Two systems handle the full gem-collection flow:
 //!
 //! - [`attract_gems_to_player`] — each frame, scans all un-attracted
-//!   [`ExperienceGem`] entities.  Any gem within the player's
-//!   [`PlayerStats::pickup_radius`] receives an [`AttractedToPlayer`]
+//!   [`ExperienceGem`] and [`GoldCoin`] entities.  Any pickup within the
+//!   player's [`PlayerStats::pickup_radius`] receives an [`AttractedToPlayer`]
 //!   component that drives movement toward the player.
 //!
 //! - [`move_attracted_gems`] — advances each attracted gem along its vector
 //!   toward the player.  When the gem is close enough it is absorbed:
-//!   its value is added to [`GameData::current_xp`] and the entity is
-//!   despawned.
+//!   its value is added to [`GameData::current_xp`], a
+//!   [`GemCollectedEvent`] is written, and the entity is despawned.
 
 use bevy::prelude::*;
 
 use crate::{
-    components::{AttractedToPlayer, ExperienceGem, Player, PlayerStats},
+    components::{AttractedToPlayer, ExperienceGem, GoldCoin, Player, PlayerStats},
+    events::GemCollectedEvent,
     resources::GameData,
 };
 
-/// Query filter for gems that have not yet started moving toward the player.
-type UnattractedGem = (With<ExperienceGem>, Without<AttractedToPlayer>);
+/// Query filter for gems and gold coins that have not yet started moving
+/// toward the player.
+type UnattractedPickup = (
+    Or<(With<ExperienceGem>, With<GoldCoin>)>,
+    Without<AttractedToPlayer>,
+);
 
 /// Query filter for gems that are currently attracted to the player.
 ///
@@ -36,17 +41,20 @@ type AttractedGem = (
 // Systems
 // ---------------------------------------------------------------------------
 
-/// Checks every un-attracted [`ExperienceGem`] and starts magnetic attraction
-/// for those within the player's [`PlayerStats::pickup_radius`].
+/// Checks every un-attracted [`ExperienceGem`] and [`GoldCoin`] and starts
+/// magnetic attraction for those within the player's
+/// [`PlayerStats::pickup_radius`].
 ///
-/// Inserts [`AttractedToPlayer`] on qualifying gems so that
-/// [`move_attracted_gems`] can move them each frame.  The query filter
+/// Inserts [`AttractedToPlayer`] on qualifying pickups so that
+/// [`move_attracted_gems`] and
+/// [`move_attracted_coins`](crate::systems::xp::gold::move_attracted_coins)
+/// can move them each frame.  The query filter
 /// `Without<AttractedToPlayer>` prevents re-inserting the component on gems
 /// that are already moving.
 pub fn attract_gems_to_player(
     mut commands: Commands,
     player_q: Query<(&Transform, &PlayerStats), With<Player>>,
-    gem_q: Query<(Entity, &Transform), UnattractedGem>,
+    gem_q: Query<(Entity, &Transform), UnattractedPickup>,
 ) {
     let Ok((player_tf, player_stats)) = player_q.single() else {
         return;
@@ -71,10 +79,12 @@ pub fn attract_gems_to_player(
 /// On each frame the gem is translated along the normalised direction vector
 /// toward the player by `speed × delta_secs` pixels.  When the remaining
 /// distance is within `gem_absorption_radius` (from `player.ron`) the gem is
-/// despawned and its [`ExperienceGem::value`] is added to [`GameData::current_xp`].
+/// despawned, its [`ExperienceGem::value`] is added to [`GameData::current_xp`],
+/// and a [`GemCollectedEvent`] is written.
 pub fn move_attracted_gems(
     mut commands: Commands,
     mut game_data: ResMut<GameData>,
+    mut collected: MessageWriter<GemCollectedEvent>,
     player_q: Query<(&Transform, &PlayerStats), With<Player>>,
     mut gem_q: Query<(Entity, &mut Transform, &ExperienceGem, &AttractedToPlayer), AttractedGem>,
     time: Res<Time>,
@@ -99,6 +109,10 @@ pub fn move_attracted_gems(
             let whole = game_data.xp_fractional_accumulator as u32;
             game_data.current_xp += whole;
             game_data.xp_fractional_accumulator -= whole as f32;
+            collected.write(GemCollectedEvent {
+                position: gem_pos,
+                value: gem.value,
+            });
             commands.entity(gem_entity).despawn();
         } else {
             // Move toward the player, clamped so we never overshoot.
@@ -131,6 +145,7 @@ mod tests {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
         app.insert_resource(GameData::default());
+        app.add_message::<GemCollectedEvent>();
         app
     }
 
@@ -307,6 +322,32 @@ mod tests {
         );
     }
 
+    /// Each absorbed gem writes one GemCollectedEvent carrying its raw value.
+    #[test]
+    fn gem_absorbed_writes_collected_event() {
+        let mut app = build_app();
+        spawn_player(&mut app, Vec2::ZERO);
+        app.world_mut().spawn((
+            ExperienceGem {
+                value: 5,
+                tier: GemTier::from_value(5),
+            },
+            Transform::from_xyz(2.0, 0.0, 0.5),
+            AttractedToPlayer {
+                speed: PlayerStats::default().gem_attraction_speed,
+            },
+        ));
+
+        app.world_mut()
+            .run_system_once(move_attracted_gems)
+            .unwrap();
+
+        let messages = app.world().resource::<Messages<GemCollectedEvent>>();
+        let mut cursor = messages.get_cursor();
+        let values: Vec<u32> = cursor.read(messages).map(|e| e.value).collect();
+        assert_eq!(values, vec![5], "one event per absorbed gem");
+    }
+
     /// Multiple gems absorbed in one frame accumulate XP.
     #[test]
     fn multiple_gems_absorbed_accumulate_xp() {
@@ -10,7 +10,7 @@
 //! | Source | Condition |
 //! |--------|-----------|
 //! | [`UpgradeChoice::WeaponUpgrade`] | Weapon owned, `level < max_weapon_level`, not evolved |
-//! | [`UpgradeChoice::NewWeapon`] | Base weapon not owned, weapon slot available |
+//! | [`UpgradeChoice::NewWeapon`] | Registered base weapon not owned, weapon slot available |
 //! | [`UpgradeChoice::PassiveUpgrade`] | Passive owned, `level < max_passive_level` |
 //! | [`UpgradeChoice::PassiveItem`] | Passive not owned, passive slot available |
 //!
@@ -25,7 +25,8 @@ use rand::RngExt;
 use crate::{
     components::{PassiveInventory, Player, PlayerStats, WeaponInventory},
     config::GameParams,
-    resources::LevelUpChoices,
+    resources::{GameRng, LevelUpChoices},
+    systems::weapons::registry::WeaponRegistry,
     types::{PassiveItemType, UpgradeChoice, WeaponType},
 };
 
@@ -33,18 +34,6 @@ use crate::{
 // Static item lists
 // ---------------------------------------------------------------------------
 
-/// All base (non-evolved) weapon types eligible to appear as new-weapon choices.
-const BASE_WEAPONS: [WeaponType; 8] = [
-    WeaponType::Whip,
-    WeaponType::MagicWand,
-    WeaponType::Knife,
-    WeaponType::Garlic,
-    WeaponType::Bible,
-    WeaponType::ThunderRing,
-    WeaponType::Cross,
-    WeaponType::FireWand,
-];
-
 /// All passive item types eligible to appear as new-passive choices.
 const ALL_PASSIVES: [PassiveItemType; 9] = [
     PassiveItemType::Spinach,
@@ -69,6 +58,9 @@ const ALL_PASSIVES: [PassiveItemType; 9] = [
 /// one extra card is added.  Both fall back to `config::game::DEFAULT_*`
 /// constants when the config asset is not yet loaded.
 ///
+/// New-weapon choices come from the base weapons in the [`WeaponRegistry`],
+/// so a weapon plugin's weapon is offered as soon as it is registered.
+///
 /// Runs on [`OnEnter(AppState::LevelUp)`](crate::states::AppState::LevelUp).
 /// When fewer valid choices exist than the configured count (e.g. all items are
 /// maxed), all remaining options are returned.
@@ -76,6 +68,8 @@ pub fn generate_level_up_choices(
     player_q: Query<(&WeaponInventory, &PassiveInventory, &PlayerStats), With<Player>>,
     mut level_up_choices: ResMut<LevelUpChoices>,
     game_cfg: GameParams,
+    registry: Res<WeaponRegistry>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     let Ok((weapon_inv, passive_inv, stats)) = player_q.single() else {
         level_up_choices.choices.clear();
@@ -106,7 +100,7 @@ pub fn generate_level_up_choices(
     if weapon_inv.weapons.len() < max_weapons {
         let owned_weapons: HashSet<WeaponType> =
             weapon_inv.weapons.iter().map(|w| w.weapon_type).collect();
-        for &weapon_type in &BASE_WEAPONS {
+        for weapon_type in registry.base_weapons() {
             if !owned_weapons.contains(&weapon_type) {
                 pool.push(UpgradeChoice::NewWeapon(weapon_type));
             }
@@ -132,7 +126,7 @@ pub fn generate_level_up_choices(
     }
 
     // Shuffle the pool and take up to choice_count choices.
-    fisher_yates_shuffle(&mut pool);
+    fisher_yates_shuffle(game_rng.rng(), &mut pool);
     pool.truncate(choice_count);
     level_up_choices.choices = pool;
 }
@@ -167,9 +161,8 @@ pub(crate) fn build_owned_upgrade_pool(
     pool
 }
 
-/// In-place Fisher-Yates shuffle using the thread-local RNG.
-fn fisher_yates_shuffle<T>(items: &mut [T]) {
-    let mut rng = rand::rng();
+/// In-place Fisher-Yates shuffle driven by `rng` (normally [`GameRng`]).
+fn fisher_yates_shuffle<T>(rng: &mut impl RngExt, items: &mut [T]) {
     for i in (1..items.len()).rev() {
         let j = rng.random_range(0..i + 1);
         items.swap(i, j);
@@ -199,9 +192,18 @@ mod tests {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
         app.insert_resource(LevelUpChoices::default());
+        app.insert_resource(GameRng::from_seed(0));
+        app.insert_resource(WeaponRegistry::with_builtin_weapons());
         app
     }
 
+    /// Base weapons of the built-in registry, in registration order.
+    fn base_weapons() -> Vec<WeaponType> {
+        WeaponRegistry::with_builtin_weapons()
+            .base_weapons()
+            .collect()
+    }
+
     fn spawn_player(app: &mut App, weapons: Vec<WeaponState>, passives: Vec<PassiveState>) {
         spawn_player_with_luck(app, weapons, passives, 1.0);
     }
@@ -271,9 +273,9 @@ mod tests {
     #[test]
     fn returns_all_when_pool_smaller_than_choice_count() {
         let mut app = build_app();
-        // All 8 weapons owned at max level, all 9 passives owned at max level
+        // All base weapons owned at max level, all 9 passives owned at max level
         // → pool is empty; no choices generated.
-        let weapons: Vec<WeaponState> = BASE_WEAPONS
+        let weapons: Vec<WeaponState> = base_weapons()
             .iter()
             .map(|&wt| {
                 let mut ws = WeaponState::new(wt);
@@ -311,7 +313,7 @@ mod tests {
             })
             .collect();
         // Fill weapon slots so no NewWeapon choices appear.
-        let mut weapons: Vec<WeaponState> = BASE_WEAPONS
+        let mut weapons: Vec<WeaponState> = base_weapons()
             .iter()
             .map(|&wt| WeaponState::new(wt))
             .collect();
@@ -346,7 +348,7 @@ mod tests {
             .collect();
         // Fill remaining weapon slots so pool stays small.
         let mut weapons = vec![whip];
-        for &wt in BASE_WEAPONS.iter().skip(1) {
+        for &wt in base_weapons().iter().skip(1) {
             let mut ws = WeaponState::new(wt);
             ws.level = DEFAULT_MAX_WEAPON_LEVEL;
             weapons.push(ws);
@@ -375,7 +377,7 @@ mod tests {
             .collect();
         // Fill remaining weapon slots.
         let mut weapons = vec![whip];
-        for &wt in BASE_WEAPONS.iter().skip(1) {
+        for &wt in base_weapons().iter().skip(1) {
             let mut ws = WeaponState::new(wt);
             ws.level = DEFAULT_MAX_WEAPON_LEVEL;
             weapons.push(ws);
@@ -421,12 +423,38 @@ mod tests {
         );
     }
 
+    /// Only weapons in the registry are offered as new weapons.
+    #[test]
+    fn new_weapon_choices_come_from_registry() {
+        let mut app = build_app();
+        let builtin = WeaponRegistry::with_builtin_weapons();
+        let mut registry = WeaponRegistry::default();
+        registry.register(*builtin.get(WeaponType::Garlic).unwrap());
+        registry.register(*builtin.get(WeaponType::SoulEater).unwrap());
+        app.insert_resource(registry);
+        let passives: Vec<PassiveState> = ALL_PASSIVES
+            .iter()
+            .map(|&pt| PassiveState {
+                item_type: pt,
+                level: DEFAULT_MAX_PASSIVE_LEVEL,
+            })
+            .collect();
+        spawn_player(&mut app, vec![], passives);
+        run(&mut app);
+
+        assert_eq!(
+            choices(&app),
+            vec![UpgradeChoice::NewWeapon(WeaponType::Garlic)],
+            "evolved forms and unregistered weapons are never offered"
+        );
+    }
+
     /// No NewWeapon choices when weapon slots are full.
     #[test]
     fn no_new_weapon_when_slots_full() {
         let mut app = build_app();
         // 6 weapons (full) at max level; all passives maxed.
-        let weapons: Vec<WeaponState> = BASE_WEAPONS
+        let weapons: Vec<WeaponState> = base_weapons()
             .iter()
             .take(DEFAULT_MAX_WEAPONS)
             .map(|&wt| {
@@ -461,7 +489,7 @@ mod tests {
         let mut app = build_app();
         // All weapons at max level → no weapon choices in pool.
         // No passives owned → all 9 unowned; slot available.
-        let weapons: Vec<WeaponState> = BASE_WEAPONS
+        let weapons: Vec<WeaponState> = base_weapons()
             .iter()
             .take(DEFAULT_MAX_WEAPONS)
             .map(|&wt| {
@@ -491,7 +519,7 @@ mod tests {
     fn owned_passive_below_max_is_upgradeable() {
         let mut app = build_app();
         // All weapons at max; only Spinach at level 1 (upgradeable).
-        let weapons: Vec<WeaponState> = BASE_WEAPONS
+        let weapons: Vec<WeaponState> = base_weapons()
             .iter()
             .take(DEFAULT_MAX_WEAPONS)
             .map(|&wt| {
@@ -528,7 +556,7 @@ mod tests {
     #[test]
     fn max_level_passive_excluded() {
         let mut app = build_app();
-        let weapons: Vec<WeaponState> = BASE_WEAPONS
+        let weapons: Vec<WeaponState> = base_weapons()
             .iter()
             .take(DEFAULT_MAX_WEAPONS)
             .map(|&wt| {
@@ -561,7 +589,7 @@ mod tests {
     #[test]
     fn no_new_passive_when_slots_full() {
         let mut app = build_app();
-        let weapons: Vec<WeaponState> = BASE_WEAPONS
+        let weapons: Vec<WeaponState> = base_weapons()
             .iter()
             .take(DEFAULT_MAX_WEAPONS)
             .map(|&wt| {
@@ -596,7 +624,7 @@ mod tests {
     #[test]
     fn fisher_yates_preserves_length() {
         let mut v: Vec<i32> = (0..10).collect();
-        fisher_yates_shuffle(&mut v);
+        fisher_yates_shuffle(GameRng::from_seed(0).rng(), &mut v);
         assert_eq!(v.len(), 10);
     }
 
@@ -631,6 +659,7 @@ mod tests {
         let mut app = App::new();
         app.add_plugins((MinimalPlugins, bevy::asset::AssetPlugin::default()));
         app.insert_resource(LevelUpChoices::default());
+        app.insert_resource(GameRng::from_seed(0));
         app.init_asset::<GameConfig>();
 
         // Insert a config with a high threshold (2.0) so a player with luck=1.5
@@ -724,7 +753,7 @@ mod tests {
     fn fisher_yates_preserves_elements() {
         let original: Vec<i32> = (0..10).collect();
         let mut v = original.clone();
-        fisher_yates_shuffle(&mut v);
+        fisher_yates_shuffle(GameRng::from_seed(0).rng(), &mut v);
         let mut expected = original;
         let mut actual = v;
         expected.sort_unstable();
@@ -5,7 +5,8 @@
 //!
 //! [`drop_treasure_on_mini_boss_death`] listens for the same event and, when
 //! the dead enemy is [`EnemyType::MiniBoss`], spawns a treasure chest at the
-//! death position via [`spawn_treasure`].
+//! death position via [`spawn_treasure`].  The chest holds the
+//! `chest_items` of the mini-boss archetype that was killed.
 //!
 //! The gem value is taken directly from [`EnemyDiedEvent::xp_value`], which
 //! is populated by [`apply_damage_to_enemies`] from the enemy's
@@ -16,7 +17,7 @@ use bevy::prelude::*;
 
 use crate::{
     components::{ExperienceGem, GameSessionEntity},
-    config::GameParams,
+    config::{EnemyParams, GameParams},
     events::EnemyDiedEvent,
     systems::xp::treasure::spawn_treasure,
     types::{EnemyType, GemTier},
@@ -64,16 +65,22 @@ pub fn spawn_xp_gems(mut commands: Commands, mut died_events: MessageReader<Enem
 /// Reads every [`EnemyDiedEvent`] this frame; ignores all non-`MiniBoss`
 /// enemies.  The chest radius is sourced from `GameConfig::treasure_radius`
 /// with a constant fallback so the drop works even before `game.ron` loads.
+/// The item count comes from the archetype's `chest_items` in `enemy.ron`;
+/// a mini-boss without an archetype drops a single-item chest.
 pub fn drop_treasure_on_mini_boss_death(
     mut commands: Commands,
     mut died_events: MessageReader<EnemyDiedEvent>,
     game_cfg: GameParams,
+    enemy_cfg: EnemyParams,
 ) {
     let radius = game_cfg.treasure_radius();
 
     for event in died_events.read() {
         if event.enemy_type == EnemyType::MiniBoss {
-            spawn_treasure(&mut commands, event.position, radius);
+            let items = event
+                .mini_boss
+                .map_or(1, |kind| enemy_cfg.mini_boss_archetype(kind).chest_items);
+            spawn_treasure(&mut commands, event.position, radius, items);
         }
     }
 }
@@ -103,6 +110,9 @@ mod tests {
             position,
             enemy_type,
             xp_value,
+            gold_chance: 0.0,
+            elite: None,
+            mini_boss: None,
         });
     }
 
@@ -224,6 +234,37 @@ mod tests {
         );
     }
 
+    /// The chest tier follows the killed archetype: Giant 1, Summoner 3,
+    /// Mage 5 items.
+    #[test]
+    fn mini_boss_chest_tier_follows_archetype() {
+        use crate::{components::Treasure, types::MiniBossKind};
+
+        let mut app = build_app();
+        for kind in MiniBossKind::ALL {
+            let entity = app.world_mut().spawn_empty().id();
+            app.world_mut().write_message(EnemyDiedEvent {
+                entity,
+                position: Vec2::ZERO,
+                enemy_type: EnemyType::MiniBoss,
+                xp_value: 30,
+                gold_chance: 0.0,
+                elite: None,
+                mini_boss: Some(kind),
+            });
+        }
+
+        app.world_mut()
+            .run_system_once(drop_treasure_on_mini_boss_death)
+            .expect("drop_treasure_on_mini_boss_death should run");
+        app.world_mut().flush();
+
+        let mut q = app.world_mut().query::<&Treasure>();
+        let mut items: Vec<u32> = q.iter(app.world()).map(|t| t.items).collect();
+        items.sort_unstable();
+        assert_eq!(items, vec![1, 3, 5]);
+    }
+
     /// Non-MiniBoss death must NOT spawn a treasure chest.
     #[test]
     fn non_mini_boss_death_does_not_spawn_treasure() {
@@ -11,6 +11,12 @@
 //!
 //! ## Evolution table
 //!
+//! Recipes are data-driven: each row of `evolution.ron`
+//! ([`EvolutionParams::table`](crate::config::EvolutionParams::table)) names
+//! a base weapon, its required passive, and the evolved form.  Every function
+//! here takes that table as a slice, so adding an evolution needs no code
+//! change beyond the evolved weapon's own behaviour.  Built-in table:
+//!
 //! | Base weapon  | Required passive | Evolved form    |
 //! |--------------|------------------|-----------------|
 //! | Whip         | HollowHeart      | BloodyTear      |
@@ -19,11 +25,13 @@
 //! | Garlic       | Pummarola        | SoulEater       |
 //! | Bible        | Spellbinder      | UnholyVespers   |
 //! | ThunderRing  | Duplicator       | LightningRing   |
-//! | Cross        | —                | (no evolution)  |
-//! | FireWand     | —                | (no evolution)  |
+//! | Cross        | Clover           | HeavenSword     |
+//! | FireWand     | Spinach          | Hellfire        |
+//! | Runetracer   | Wings            | NoFuture        |
 
 use crate::{
     components::{PassiveInventory, WeaponInventory},
+    config::EvolutionEntry,
     types::{PassiveItemType, WeaponState, WeaponType},
 };
 
@@ -31,38 +39,19 @@ use crate::{
 // Pure query functions
 // ---------------------------------------------------------------------------
 
-/// Returns the passive item required to evolve `weapon`, or `None` if that
-/// weapon has no evolution path.
-pub fn get_evolution_requirement(weapon: WeaponType) -> Option<PassiveItemType> {
-    match weapon {
-        WeaponType::Whip => Some(PassiveItemType::HollowHeart),
-        WeaponType::MagicWand => Some(PassiveItemType::EmptyTome),
-        WeaponType::Knife => Some(PassiveItemType::Bracer),
-        WeaponType::Garlic => Some(PassiveItemType::Pummarola),
-        WeaponType::Bible => Some(PassiveItemType::Spellbinder),
-        WeaponType::ThunderRing => Some(PassiveItemType::Duplicator),
-        // Cross and FireWand have no evolution in the current scope.
-        _ => None,
-    }
+/// Returns the passive item required to evolve `weapon`, or `None` if the
+/// table has no evolution path for it.
+pub fn get_evolution_requirement(
+    table: &[EvolutionEntry],
+    weapon: WeaponType,
+) -> Option<PassiveItemType> {
+    table.iter().find(|e| e.weapon == weapon).map(|e| e.passive)
 }
 
-/// Returns the evolved form of `weapon`.
-///
-/// # Panics
-///
-/// Panics in **all** builds if `weapon` has no evolution path.
-/// Callers must only call this after [`get_evolution_requirement`] returns
-/// `Some`.
-pub fn get_evolved_weapon(weapon: WeaponType) -> WeaponType {
-    match weapon {
-        WeaponType::Whip => WeaponType::BloodyTear,
-        WeaponType::MagicWand => WeaponType::HolyWand,
-        WeaponType::Knife => WeaponType::ThousandEdge,
-        WeaponType::Garlic => WeaponType::SoulEater,
-        WeaponType::Bible => WeaponType::UnholyVespers,
-        WeaponType::ThunderRing => WeaponType::LightningRing,
-        other => panic!("{other:?} has no evolved form"),
-    }
+/// Returns the evolved form of `weapon`, or `None` if the table has no
+/// evolution path for it.
+pub fn get_evolved_weapon(table: &[EvolutionEntry], weapon: WeaponType) -> Option<WeaponType> {
+    table.iter().find(|e| e.weapon == weapon).map(|e| e.evolved)
 }
 
 /// Checks whether a single weapon can evolve given the current passive
@@ -73,10 +62,12 @@ pub fn get_evolved_weapon(weapon: WeaponType) -> WeaponType {
 /// - the required passive item is owned (at any level)
 ///
 /// `required_level` is typically `GameConfig::max_weapon_level` (default 8).
-/// Passing it as a parameter keeps the function pure and config-driven.
+/// Passing it and the table as parameters keeps the function pure and
+/// config-driven.
 ///
 /// Returns `None` otherwise.
 pub fn can_evolve_weapon(
+    table: &[EvolutionEntry],
     weapon_state: &WeaponState,
     passive_inventory: &PassiveInventory,
     required_level: u8,
@@ -84,32 +75,37 @@ pub fn can_evolve_weapon(
     if weapon_state.level != required_level || weapon_state.evolved {
         return None;
     }
-    let required = get_evolution_requirement(weapon_state.weapon_type)?;
+    let entry = table
+        .iter()
+        .find(|e| e.weapon == weapon_state.weapon_type)?;
     let owned = passive_inventory
         .items
         .iter()
-        .any(|p| p.item_type == required);
-    if owned {
-        Some(get_evolved_weapon(weapon_state.weapon_type))
-    } else {
-        None
-    }
+        .any(|p| p.item_type == entry.passive);
+    owned.then_some(entry.evolved)
 }
 
-/// Scans the full weapon inventory and returns the first weapon that can
-/// evolve, or `None` if none qualify.
+/// Scans the full weapon inventory and returns the evolved form of the
+/// first weapon that can evolve, or `None` if none qualify.
+///
+/// Evolved forms listed in `skip` are passed over, so a caller applying
+/// several evolutions before the inventory updates never picks the same
+/// weapon twice.
 ///
 /// This is the entry point used by the treasure-opening system to decide
 /// whether to trigger an evolution instead of a generic reward.
 pub fn find_evolution(
+    table: &[EvolutionEntry],
     weapon_inv: &WeaponInventory,
     passive_inv: &PassiveInventory,
     required_level: u8,
+    skip: &[WeaponType],
 ) -> Option<WeaponType> {
     weapon_inv
         .weapons
         .iter()
-        .find_map(|ws| can_evolve_weapon(ws, passive_inv, required_level))
+        .filter_map(|ws| can_evolve_weapon(table, ws, passive_inv, required_level))
+        .find(|evolved| !skip.contains(evolved))
 }
 
 // ---------------------------------------------------------------------------
@@ -119,8 +115,11 @@ pub fn find_evolution(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::DEFAULT_EVOLUTION_TABLE;
     use crate::types::{PassiveState, WeaponState};
 
+    const TABLE: &[EvolutionEntry] = &DEFAULT_EVOLUTION_TABLE;
+
     fn make_passive_inv(items: &[PassiveItemType]) -> PassiveInventory {
         PassiveInventory {
             items: items
@@ -151,7 +150,7 @@ mod tests {
     #[test]
     fn evolution_requirement_whip_needs_hollow_heart() {
         assert_eq!(
-            get_evolution_requirement(WeaponType::Whip),
+            get_evolution_requirement(TABLE, WeaponType::Whip),
             Some(PassiveItemType::HollowHeart)
         );
     }
@@ -159,7 +158,7 @@ mod tests {
     #[test]
     fn evolution_requirement_magic_wand_needs_empty_tome() {
         assert_eq!(
-            get_evolution_requirement(WeaponType::MagicWand),
+            get_evolution_requirement(TABLE, WeaponType::MagicWand),
             Some(PassiveItemType::EmptyTome)
         );
     }
@@ -167,7 +166,7 @@ mod tests {
     #[test]
     fn evolution_requirement_knife_needs_bracer() {
         assert_eq!(
-            get_evolution_requirement(WeaponType::Knife),
+            get_evolution_requirement(TABLE, WeaponType::Knife),
             Some(PassiveItemType::Bracer)
         );
     }
@@ -175,7 +174,7 @@ mod tests {
     #[test]
     fn evolution_requirement_garlic_needs_pummarola() {
         assert_eq!(
-            get_evolution_requirement(WeaponType::Garlic),
+            get_evolution_requirement(TABLE, WeaponType::Garlic),
             Some(PassiveItemType::Pummarola)
         );
     }
@@ -183,7 +182,7 @@ mod tests {
     #[test]
     fn evolution_requirement_bible_needs_spellbinder() {
         assert_eq!(
-            get_evolution_requirement(WeaponType::Bible),
+            get_evolution_requirement(TABLE, WeaponType::Bible),
             Some(PassiveItemType::Spellbinder)
         );
     }
@@ -191,46 +190,89 @@ mod tests {
     #[test]
     fn evolution_requirement_thunder_ring_needs_duplicator() {
         assert_eq!(
-            get_evolution_requirement(WeaponType::ThunderRing),
+            get_evolution_requirement(TABLE, WeaponType::ThunderRing),
             Some(PassiveItemType::Duplicator)
         );
     }
 
     #[test]
-    fn evolution_requirement_cross_has_none() {
-        assert_eq!(get_evolution_requirement(WeaponType::Cross), None);
+    fn evolution_requirement_cross_needs_clover() {
+        assert_eq!(
+            get_evolution_requirement(TABLE, WeaponType::Cross),
+            Some(PassiveItemType::Clover)
+        );
     }
 
     #[test]
-    fn evolution_requirement_fire_wand_has_none() {
-        assert_eq!(get_evolution_requirement(WeaponType::FireWand), None);
+    fn evolution_requirement_fire_wand_needs_spinach() {
+        assert_eq!(
+            get_evolution_requirement(TABLE, WeaponType::FireWand),
+            Some(PassiveItemType::Spinach)
+        );
+    }
+
+    #[test]
+    fn evolution_requirement_runetracer_needs_wings() {
+        assert_eq!(
+            get_evolution_requirement(TABLE, WeaponType::Runetracer),
+            Some(PassiveItemType::Wings)
+        );
+    }
+
+    #[test]
+    fn evolution_requirement_evolved_weapon_has_none() {
+        assert_eq!(
+            get_evolution_requirement(TABLE, WeaponType::HeavenSword),
+            None
+        );
     }
 
     // --- get_evolved_weapon ---
 
     #[test]
-    fn get_evolved_weapon_all_six_paths() {
-        assert_eq!(get_evolved_weapon(WeaponType::Whip), WeaponType::BloodyTear);
+    fn get_evolved_weapon_all_paths() {
+        assert_eq!(
+            get_evolved_weapon(TABLE, WeaponType::Whip),
+            Some(WeaponType::BloodyTear)
+        );
+        assert_eq!(
+            get_evolved_weapon(TABLE, WeaponType::MagicWand),
+            Some(WeaponType::HolyWand)
+        );
+        assert_eq!(
+            get_evolved_weapon(TABLE, WeaponType::Knife),
+            Some(WeaponType::ThousandEdge)
+        );
         assert_eq!(
-            get_evolved_weapon(WeaponType::MagicWand),
-            WeaponType::HolyWand
+            get_evolved_weapon(TABLE, WeaponType::Garlic),
+            Some(WeaponType::SoulEater)
         );
         assert_eq!(
-            get_evolved_weapon(WeaponType::Knife),
-            WeaponType::ThousandEdge
+            get_evolved_weapon(TABLE, WeaponType::Bible),
+            Some(WeaponType::UnholyVespers)
         );
         assert_eq!(
-            get_evolved_weapon(WeaponType::Garlic),
-            WeaponType::SoulEater
+            get_evolved_weapon(TABLE, WeaponType::ThunderRing),
+            Some(WeaponType::LightningRing)
         );
         assert_eq!(
-            get_evolved_weapon(WeaponType::Bible),
-            WeaponType::UnholyVespers
+            get_evolved_weapon(TABLE, WeaponType::Cross),
+            Some(WeaponType::HeavenSword)
         );
         assert_eq!(
-            get_evolved_weapon(WeaponType::ThunderRing),
-            WeaponType::LightningRing
+            get_evolved_weapon(TABLE, WeaponType::FireWand),
+            Some(WeaponType::Hellfire)
         );
+        assert_eq!(
+            get_evolved_weapon(TABLE, WeaponType::Runetracer),
+            Some(WeaponType::NoFuture)
+        );
+    }
+
+    #[test]
+    fn get_evolved_weapon_returns_none_without_path() {
+        assert_eq!(get_evolved_weapon(TABLE, WeaponType::BloodyTear), None);
+        assert_eq!(get_evolved_weapon(&[], WeaponType::Whip), None);
     }
 
     // --- can_evolve_weapon ---
@@ -240,7 +282,7 @@ mod tests {
         let ws = lv8(WeaponType::Whip);
         let inv = make_passive_inv(&[PassiveItemType::HollowHeart]);
         assert_eq!(
-            can_evolve_weapon(&ws, &inv, 8),
+            can_evolve_weapon(TABLE, &ws, &inv, 8),
             Some(WeaponType::BloodyTear)
         );
     }
@@ -250,7 +292,7 @@ mod tests {
         let mut ws = lv8(WeaponType::Whip);
         ws.level = 7;
         let inv = make_passive_inv(&[PassiveItemType::HollowHeart]);
-        assert_eq!(can_evolve_weapon(&ws, &inv, 8), None);
+        assert_eq!(can_evolve_weapon(TABLE, &ws, &inv, 8), None);
     }
 
     #[test]
@@ -258,35 +300,69 @@ mod tests {
         let mut ws = lv8(WeaponType::Whip);
         ws.evolved = true;
         let inv = make_passive_inv(&[PassiveItemType::HollowHeart]);
-        assert_eq!(can_evolve_weapon(&ws, &inv, 8), None);
+        assert_eq!(can_evolve_weapon(TABLE, &ws, &inv, 8), None);
     }
 
     #[test]
     fn can_evolve_returns_none_when_passive_missing() {
         let ws = lv8(WeaponType::Whip);
         let inv = make_passive_inv(&[]); // no passives
-        assert_eq!(can_evolve_weapon(&ws, &inv, 8), None);
+        assert_eq!(can_evolve_weapon(TABLE, &ws, &inv, 8), None);
     }
 
     #[test]
     fn can_evolve_returns_none_when_wrong_passive_owned() {
         let ws = lv8(WeaponType::Whip);
         let inv = make_passive_inv(&[PassiveItemType::EmptyTome]); // wrong passive
-        assert_eq!(can_evolve_weapon(&ws, &inv, 8), None);
+        assert_eq!(can_evolve_weapon(TABLE, &ws, &inv, 8), None);
     }
 
     #[test]
-    fn can_evolve_returns_none_for_no_evolution_weapon() {
+    fn can_evolve_returns_none_for_weapon_missing_from_table() {
         let ws = lv8(WeaponType::Cross);
-        let inv = make_passive_inv(&[
+        let inv = make_passive_inv(&[PassiveItemType::Clover]);
+        let table = [EvolutionEntry::new(
+            WeaponType::Whip,
             PassiveItemType::HollowHeart,
-            PassiveItemType::EmptyTome,
-            PassiveItemType::Bracer,
-            PassiveItemType::Pummarola,
-            PassiveItemType::Spellbinder,
-            PassiveItemType::Duplicator,
-        ]); // all passives present, still no evolution
-        assert_eq!(can_evolve_weapon(&ws, &inv, 8), None);
+            WeaponType::BloodyTear,
+        )];
+        assert_eq!(can_evolve_weapon(&table, &ws, &inv, 8), None);
+    }
+
+    #[test]
+    fn can_evolve_cross_into_heaven_sword_with_clover() {
+        let ws = lv8(WeaponType::Cross);
+        let inv = make_passive_inv(&[PassiveItemType::Clover]);
+        assert_eq!(
+            can_evolve_weapon(TABLE, &ws, &inv, 8),
+            Some(WeaponType::HeavenSword)
+        );
+    }
+
+    #[test]
+    fn can_evolve_fire_wand_into_hellfire_with_spinach() {
+        let ws = lv8(WeaponType::FireWand);
+        let inv = make_passive_inv(&[PassiveItemType::Spinach]);
+        assert_eq!(
+            can_evolve_weapon(TABLE, &ws, &inv, 8),
+            Some(WeaponType::Hellfire)
+        );
+    }
+
+    /// A custom table row is honoured without any code change.
+    #[test]
+    fn can_evolve_uses_custom_table_row() {
+        let ws = lv8(WeaponType::Whip);
+        let inv = make_passive_inv(&[PassiveItemType::Wings]);
+        let table = [EvolutionEntry::new(
+            WeaponType::Whip,
+            PassiveItemType::Wings,
+            WeaponType::HeavenSword,
+        )];
+        assert_eq!(
+            can_evolve_weapon(&table, &ws, &inv, 8),
+            Some(WeaponType::HeavenSword)
+        );
     }
 
     // --- find_evolution ---
@@ -297,7 +373,7 @@ mod tests {
         let passive = make_passive_inv(&[PassiveItemType::HollowHeart, PassiveItemType::Bracer]);
         // Whip is first in the list and eligible → returns BloodyTear
         assert_eq!(
-            find_evolution(&inv, &passive, 8),
+            find_evolution(TABLE, &inv, &passive, 8, &[]),
             Some(WeaponType::BloodyTear)
         );
     }
@@ -309,22 +385,42 @@ mod tests {
         let inv = make_weapon_inv(vec![ws_knife, lv8(WeaponType::MagicWand)]);
         let passive = make_passive_inv(&[PassiveItemType::EmptyTome]);
         assert_eq!(
-            find_evolution(&inv, &passive, 8),
+            find_evolution(TABLE, &inv, &passive, 8, &[]),
             Some(WeaponType::HolyWand)
         );
     }
 
+    #[test]
+    fn find_evolution_passes_over_skipped_forms() {
+        let inv = make_weapon_inv(vec![lv8(WeaponType::Whip), lv8(WeaponType::Knife)]);
+        let passive = make_passive_inv(&[PassiveItemType::HollowHeart, PassiveItemType::Bracer]);
+        assert_eq!(
+            find_evolution(TABLE, &inv, &passive, 8, &[WeaponType::BloodyTear]),
+            Some(WeaponType::ThousandEdge)
+        );
+        assert_eq!(
+            find_evolution(
+                TABLE,
+                &inv,
+                &passive,
+                8,
+                &[WeaponType::BloodyTear, WeaponType::ThousandEdge]
+            ),
+            None
+        );
+    }
+
     #[test]
     fn find_evolution_returns_none_when_nothing_qualifies() {
         let inv = make_weapon_inv(vec![lv8(WeaponType::Whip)]);
         let passive = make_passive_inv(&[]); // no passive
-        assert_eq!(find_evolution(&inv, &passive, 8), None);
+        assert_eq!(find_evolution(TABLE, &inv, &passive, 8, &[]), None);
     }
 
     #[test]
     fn find_evolution_returns_none_for_empty_inventory() {
         let inv = make_weapon_inv(vec![]);
         let passive = make_passive_inv(&[PassiveItemType::HollowHeart]);
-        assert_eq!(find_evolution(&inv, &passive, 8), None);
+        assert_eq!(find_evolution(TABLE, &inv, &passive, 8, &[]), None);
     }
 }
@@ -0,0 +1,275 @@
+//! Gold coin drops and pickup.
+//!
+//! [`drop_gold_coins`] listens for [`EnemyDiedEvent`] and rolls the dead
+//! enemy's `gold_chance` (from `enemy.ron`, carried on the event) scaled by
+//! the player's [`PlayerStats::luck`].  On success a [`GoldCoin`] of a rolled
+//! [`CoinTier`] is spawned at the death position.  Elite enemies always
+//! drop a coin.
+//!
+//! Coins are pulled in by the same
+//! [`attract_gems_to_player`](super::attraction::attract_gems_to_player)
+//! pass as XP gems; [`move_attracted_coins`] moves them and credits
+//! [`GameData::gold_earned`] on pickup, which is added to the persistent
+//! gold total when the run ends.
+
+use bevy::prelude::*;
+use rand::RngExt;
+
+use crate::{
+    components::{AttractedToPlayer, GameSessionEntity, GoldCoin, Player, PlayerStats},
+    config::GameParams,
+    events::EnemyDiedEvent,
+    resources::{GameData, GameRng},
+    types::CoinTier,
+};
+
+/// Offset from the death position so a coin does not hide under the XP gem
+/// dropped by the same enemy.
+const COIN_DROP_OFFSET: Vec2 = Vec2::new(10.0, 0.0);
+
+/// Query filter for coins that are currently attracted to the player.
+///
+/// `Without<Player>` makes the `Transform` access disjoint from the player
+/// query in [`move_attracted_coins`].
+type AttractedCoin = (With<AttractedToPlayer>, Without<Player>);
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Rolls a gold drop for every [`EnemyDiedEvent`] received this frame.
+///
+/// - Drop chance is `event.gold_chance × luck`, clamped to `0.0..=1.0`
+///   (luck defaults to `1.0` when no player exists).  Elites
+///   (`event.elite` set) drop a coin without a roll.
+/// - The coin tier is rolled from `gold_bag_chance` / `gold_rich_bag_chance`
+///   and its value read from `game.ron` via [`GameParams::gold_value`].
+/// - Tagged with [`GameSessionEntity`] so coins are cleaned up when the run
+///   ends.
+///
+/// Must run after
+/// [`apply_damage_to_enemies`](crate::systems::damage::apply_damage_to_enemies)
+/// so this frame's deaths are already written.
+pub fn drop_gold_coins(
+    mut commands: Commands,
+    mut died_events: MessageReader<EnemyDiedEvent>,
+    player_q: Query<&PlayerStats, With<Player>>,
+    game_cfg: GameParams,
+    mut game_rng: ResMut<GameRng>,
+) {
+    let luck = player_q.single().map(|s| s.luck).unwrap_or(1.0);
+    let bag_chance = game_cfg.gold_bag_chance();
+    let rich_bag_chance = game_cfg.gold_rich_bag_chance();
+    let rng = game_rng.rng();
+
+    for event in died_events.read() {
+        let chance = (event.gold_chance * luck).clamp(0.0, 1.0);
+        if event.elite.is_none() && (chance <= 0.0 || rng.random::<f32>() >= chance) {
+            continue;
+        }
+        let tier = CoinTier::from_roll(rng.random(), bag_chance, rich_bag_chance);
+        let position = event.position + COIN_DROP_OFFSET;
+        commands.spawn((
+            GameSessionEntity,
+            GoldCoin {
+                value: game_cfg.gold_value(tier),
+                tier,
+            },
+            // Tiered color/size placeholder sprite, like XP gems.
+            Sprite {
+                color: tier.color(),
+                custom_size: Some(Vec2::splat(tier.radius() * 2.0)),
+                ..default()
+            },
+            // Same layer as XP gems: above ground, below enemies.
+            Transform::from_xyz(position.x, position.y, 0.5),
+        ));
+    }
+}
+
+/// Moves every attracted [`GoldCoin`] toward the player and collects it on
+/// arrival, adding its value to [`GameData::gold_earned`].
+///
+/// Mirrors [`move_attracted_gems`](super::attraction::move_attracted_gems):
+/// coins travel at their [`AttractedToPlayer::speed`] and are absorbed
+/// within `gem_absorption_radius`.
+pub fn move_attracted_coins(
+    mut commands: Commands,
+    mut game_data: ResMut<GameData>,
+    player_q: Query<(&Transform, &PlayerStats), With<Player>>,
+    mut coin_q: Query<(Entity, &mut Transform, &GoldCoin, &AttractedToPlayer), AttractedCoin>,
+    time: Res<Time>,
+) {
+    let Ok((player_tf, player_stats)) = player_q.single() else {
+        return;
+    };
+
+    let absorption_radius = player_stats.gem_absorption_radius;
+    let player_pos = player_tf.translation.truncate();
+    let delta = time.delta_secs();
+
+    for (coin_entity, mut coin_tf, coin, attracted) in coin_q.iter_mut() {
+        let coin_pos = coin_tf.translation.truncate();
+        let to_player = player_pos - coin_pos;
+        let distance = to_player.length();
+
+        if distance <= absorption_radius {
+            game_data.gold_earned += coin.value;
+            commands.entity(coin_entity).despawn();
+        } else {
+            let direction = to_player / distance;
+            let move_dist = (attracted.speed * delta).min(distance - absorption_radius);
+            coin_tf.translation += (direction * move_dist).extend(0.0);
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce as _;
+
+    use super::*;
+    use crate::{systems::xp::attraction::attract_gems_to_player, types::EnemyType};
+
+    fn build_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.add_message::<EnemyDiedEvent>();
+        app.insert_resource(GameData::default());
+        app.insert_resource(GameRng::from_seed(0));
+        app
+    }
+
+    fn send_died(app: &mut App, gold_chance: f32) {
+        app.world_mut().write_message(EnemyDiedEvent {
+            entity: Entity::PLACEHOLDER,
+            position: Vec2::new(100.0, -50.0),
+            enemy_type: EnemyType::Bat,
+            xp_value: 3,
+            gold_chance,
+            elite: None,
+            mini_boss: None,
+        });
+    }
+
+    fn coins(app: &mut App) -> Vec<(u32, CoinTier, Vec3)> {
+        let mut q = app.world_mut().query::<(&GoldCoin, &Transform)>();
+        q.iter(app.world())
+            .map(|(c, t)| (c.value, c.tier, t.translation))
+            .collect()
+    }
+
+    fn spawn_player(app: &mut App, luck: f32) {
+        app.world_mut().spawn((
+            Player,
+            PlayerStats { luck, ..default() },
+            Transform::default(),
+        ));
+    }
+
+    #[test]
+    fn certain_drop_spawns_coin_near_death_position() {
+        let mut app = build_app();
+        send_died(&mut app, 1.0);
+        app.world_mut().run_system_once(drop_gold_coins).unwrap();
+
+        let cs = coins(&mut app);
+        assert_eq!(cs.len(), 1);
+        let (value, tier, pos) = cs[0];
+        assert!(value >= 1, "{tier:?} coin must be worth at least 1 gold");
+        assert_eq!(pos.truncate(), Vec2::new(100.0, -50.0) + COIN_DROP_OFFSET);
+    }
+
+    #[test]
+    fn zero_chance_never_drops() {
+        let mut app = build_app();
+        spawn_player(&mut app, 10.0);
+        for _ in 0..50 {
+            send_died(&mut app, 0.0);
+        }
+        app.world_mut().run_system_once(drop_gold_coins).unwrap();
+        assert!(coins(&mut app).is_empty(), "gold_chance 0 must never drop");
+    }
+
+    #[test]
+    fn elite_always_drops() {
+        let mut app = build_app();
+        for _ in 0..10 {
+            app.world_mut().write_message(EnemyDiedEvent {
+                entity: Entity::PLACEHOLDER,
+                position: Vec2::ZERO,
+                enemy_type: EnemyType::Bat,
+                xp_value: 15,
+                gold_chance: 0.0,
+                elite: Some(crate::types::EliteModifier::Swift),
+                mini_boss: None,
+            });
+        }
+        app.world_mut().run_system_once(drop_gold_coins).unwrap();
+        assert_eq!(coins(&mut app).len(), 10);
+    }
+
+    #[test]
+    fn luck_scales_drop_chance() {
+        // 0.25 × luck 4.0 = certain drop.
+        let mut app = build_app();
+        spawn_player(&mut app, 4.0);
+        for _ in 0..20 {
+            send_died(&mut app, 0.25);
+        }
+        app.world_mut().run_system_once(drop_gold_coins).unwrap();
+        assert_eq!(coins(&mut app).len(), 20);
+    }
+
+    #[test]
+    fn coins_are_attracted_like_gems() {
+        let mut app = build_app();
+        spawn_player(&mut app, 1.0);
+        let coin = app
+            .world_mut()
+            .spawn((
+                GoldCoin {
+                    value: 1,
+                    tier: CoinTier::Coin,
+                },
+                Transform::from_xyz(30.0, 0.0, 0.5),
+            ))
+            .id();
+
+        app.world_mut()
+            .run_system_once(attract_gems_to_player)
+            .unwrap();
+        assert!(app.world().get::<AttractedToPlayer>(coin).is_some());
+    }
+
+    #[test]
+    fn collected_coin_credits_gold_earned() {
+        let mut app = build_app();
+        spawn_player(&mut app, 1.0);
+        let coin = app
+            .world_mut()
+            .spawn((
+                GoldCoin {
+                    value: 10,
+                    tier: CoinTier::Bag,
+                },
+                AttractedToPlayer { speed: 300.0 },
+                Transform::from_xyz(1.0, 0.0, 0.5),
+            ))
+            .id();
+
+        app.world_mut()
+            .run_system_once(move_attracted_coins)
+            .unwrap();
+
+        assert_eq!(app.world().resource::<GameData>().gold_earned, 10);
+        assert!(
+            app.world().get_entity(coin).is_err(),
+            "coin must be despawned"
+        );
+    }
+}
@@ -3,6 +3,7 @@ pub mod attraction;
 pub mod choices;
 pub mod drop;
 pub mod evolution;
+pub mod gold;
 pub mod level_up;
 pub mod treasure;
 
@@ -17,10 +18,12 @@ impl Plugin for XpPlugin {
         use crate::systems::damage::apply_damage_to_enemies;
         use crate::systems::player::player_movement;
         use crate::systems::player::spawn_player;
+        use crate::systems::rng::GameRngSystems;
         use crate::systems::xp::apply::{apply_selected_upgrade, recalculate_player_stats};
         use crate::systems::xp::attraction::{attract_gems_to_player, move_attracted_gems};
         use crate::systems::xp::choices::generate_level_up_choices;
         use crate::systems::xp::drop::{drop_treasure_on_mini_boss_death, spawn_xp_gems};
+        use crate::systems::xp::gold::{drop_gold_coins, move_attracted_coins};
         use crate::systems::xp::level_up::check_level_up;
         use crate::systems::xp::treasure::{
             animate_treasure_spawn_flash, apply_evolution, open_treasure_chests,
@@ -38,10 +41,14 @@ impl Plugin for XpPlugin {
                     recalculate_player_stats,
                     spawn_xp_gems.after(apply_damage_to_enemies),
                     drop_treasure_on_mini_boss_death.after(apply_damage_to_enemies),
+                    drop_gold_coins
+                        .after(apply_damage_to_enemies)
+                        .in_set(GameRngSystems::GoldDrops),
                     attract_gems_to_player.after(player_movement),
                     move_attracted_gems.after(attract_gems_to_player),
+                    move_attracted_coins.after(attract_gems_to_player),
                     check_level_up.after(move_attracted_gems),
-                    open_treasure_chests,
+                    open_treasure_chests.in_set(GameRngSystems::TreasureChests),
                     spawn_treasure_glow,
                     animate_treasure_spawn_flash,
                     update_treasure_glow,
@@ -1,16 +1,21 @@
 //! Treasure chest collection and reward system.
 //!
 //! When the player touches a treasure chest ([`Treasure`] component), the
-//! chest is despawned and a reward is applied:
+//! chest is despawned and [`Treasure::items`] rewards are applied (1, 3 or 5
+//! depending on the mini-boss that dropped it).  Each item is:
 //!
 //! 1. **If any weapon can evolve** (at max level + required passive owned),
-//!    the first eligible weapon evolves immediately.
+//!    the first eligible weapon not already evolved by this chest evolves.
 //! 2. Otherwise a random reward is chosen from three options:
 //!    - **Upgrade** — if any owned weapon or passive is below max level, one
 //!      is upgraded.  This option is skipped when no upgrades are available.
 //!    - **HP recovery** — restores `treasure_hp_recovery_pct × max_hp`.
 //!    - **Gold** — adds `treasure_gold_reward` to [`GameData`].
 //!
+//! A five-item chest can therefore evolve up to five weapons at once.  The
+//! evolutions land via deferred triggers, so the chest tracks the forms it
+//! has already queued instead of re-reading the inventory.
+//!
 //! When gold is the reward, `treasure_gold_reward` is added to
 //! [`GameData::gold_earned`] (run total).  At run end
 //! (`OnEnter(GameOver)` / `OnEnter(Victory)`), the full `gold_earned` value is
@@ -31,10 +36,10 @@ use crate::{
         CircleCollider, GameSessionEntity, PassiveInventory, Player, PlayerStats, Treasure,
         TreasureGlow, TreasureSpawnFlash, WeaponInventory,
     },
-    config::{GameParams, PassiveConfig, PassiveParams},
+    config::{EvolutionParams, GameParams, PassiveConfig, PassiveParams},
     events::TreasureOpenedEvent,
     materials::GlowMaterial,
-    resources::GameData,
+    resources::{GameData, GameRng},
     types::{UpgradeChoice, WeaponType},
 };
 
@@ -47,16 +52,20 @@ use super::evolution::find_evolution;
 /// stay in sync without a config round-trip for a colour value).
 const CHEST_COLOR: Color = Color::srgb(1.0, 0.85, 0.1);
 
+/// Largest number of rewards one chest can hold.
+const MAX_CHEST_ITEMS: u32 = 5;
+
 // ---------------------------------------------------------------------------
 // System
 // ---------------------------------------------------------------------------
 
-/// Detects player–treasure overlaps, applies the reward, and despawns the
-/// chest.
+/// Detects player–treasure overlaps, applies the chest's rewards, and
+/// despawns the chest.
 ///
-/// Evolution takes priority: if at least one weapon in the player's inventory
-/// is at `max_weapon_level` **and** the player owns the required passive, that
-/// weapon is replaced by its evolved form.
+/// For every item evolution takes priority: if a weapon in the player's
+/// inventory is at `max_weapon_level` **and** the player owns the required
+/// passive, and this chest has not already evolved it, that weapon is
+/// replaced by its evolved form.
 ///
 /// Otherwise a random reward is chosen from three options (see module docs).
 ///
@@ -75,6 +84,7 @@ pub fn open_treasure_chests(
     mut opened_events: MessageWriter<TreasureOpenedEvent>,
     game_cfg: GameParams,
     passive_cfg: PassiveParams,
+    evolution_cfg: EvolutionParams,
     mut player_q: Query<
         (
             &Transform,
@@ -85,7 +95,8 @@ pub fn open_treasure_chests(
         ),
         With<Player>,
     >,
-    treasure_q: Query<(Entity, &Transform), With<Treasure>>,
+    treasure_q: Query<(Entity, &Transform, &Treasure)>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     let Ok((player_tf, player_col, mut weapon_inv, mut passive_inv, mut stats)) =
         player_q.single_mut()
@@ -99,7 +110,7 @@ pub fn open_treasure_chests(
     let max_passive_level = game_cfg.max_passive_level();
     let hp_recovery_pct = game_cfg.treasure_hp_recovery_pct();
 
-    for (treasure_entity, treasure_tf) in &treasure_q {
+    for (treasure_entity, treasure_tf, treasure) in &treasure_q {
         let dist = player_tf
             .translation
             .truncate()
@@ -120,28 +131,43 @@ pub fn open_treasure_chests(
         // Despawn the chest immediately.
         commands.entity(treasure_entity).despawn();
 
-        // Apply reward: evolution takes priority over random reward.
-        if let Some(evolved) = find_evolution(&weapon_inv, &passive_inv, max_weapon_level) {
-            // Mutation happens in apply_evolution (an observer) which runs
-            // after this system.  Emitting a trigger keeps this system
-            // read-only on the inventory, avoiding borrow conflicts.
-            commands.trigger(WeaponEvolvedTrigger {
-                evolved_type: evolved,
-            });
-        } else {
-            apply_non_evolution_reward(
-                &mut weapon_inv,
-                &mut passive_inv,
-                &mut stats,
-                &mut game_data,
-                &RewardContext {
-                    passive_cfg: passive_cfg.get(),
-                    hp_recovery_pct,
-                    gold_reward,
-                },
+        let ctx = RewardContext {
+            passive_cfg: passive_cfg.get(),
+            hp_recovery_pct,
+            gold_reward,
+        };
+        // Forms queued by this chest; the inventory only changes once the
+        // triggers run, so without this every item would pick the same weapon.
+        let mut queued_evolutions: Vec<WeaponType> = Vec::new();
+        for _ in 0..treasure.items.clamp(1, MAX_CHEST_ITEMS) {
+            // Evolution takes priority over a random reward.
+            let evolved = find_evolution(
+                evolution_cfg.table(),
+                &weapon_inv,
+                &passive_inv,
                 max_weapon_level,
-                max_passive_level,
+                &queued_evolutions,
             );
+            if let Some(evolved) = evolved {
+                queued_evolutions.push(evolved);
+                // Mutation happens in apply_evolution (an observer) which runs
+                // after this system.  Emitting a trigger keeps this system
+                // read-only on the inventory, avoiding borrow conflicts.
+                commands.trigger(WeaponEvolvedTrigger {
+                    evolved_type: evolved,
+                });
+            } else {
+                apply_non_evolution_reward(
+                    game_rng.rng(),
+                    &mut weapon_inv,
+                    &mut passive_inv,
+                    &mut stats,
+                    &mut game_data,
+                    &ctx,
+                    max_weapon_level,
+                    max_passive_level,
+                );
+            }
         }
 
         // Process at most one chest per frame to avoid stale-inventory decisions.
@@ -169,8 +195,10 @@ pub(crate) enum Reward {
 /// - [`Reward::HpRecovery`]
 /// - [`Reward::Gold`]
 ///
-/// All eligible options have equal probability.
+/// All eligible options have equal probability.  Rolls are drawn from `rng`
+/// (normally [`GameRng`]) so rewards are reproducible from the run seed.
 pub(crate) fn pick_reward(
+    rng: &mut impl RngExt,
     weapon_inv: &WeaponInventory,
     passive_inv: &PassiveInventory,
     max_weapon_level: u8,
@@ -179,8 +207,6 @@ pub(crate) fn pick_reward(
     let upgrade_pool =
         build_owned_upgrade_pool(weapon_inv, passive_inv, max_weapon_level, max_passive_level);
 
-    let mut rng = rand::rng();
-
     let mut options: Vec<Reward> = vec![Reward::HpRecovery, Reward::Gold];
     if !upgrade_pool.is_empty() {
         let idx = rng.random_range(0..upgrade_pool.len());
@@ -249,7 +275,9 @@ pub(crate) fn apply_reward(
 ///
 /// Returns the [`Reward`] that was chosen so the caller can perform
 /// any additional bookkeeping.
+#[allow(clippy::too_many_arguments)]
 fn apply_non_evolution_reward(
+    rng: &mut impl RngExt,
     weapon_inv: &mut WeaponInventory,
     passive_inv: &mut PassiveInventory,
     stats: &mut PlayerStats,
@@ -258,7 +286,13 @@ fn apply_non_evolution_reward(
     max_weapon_level: u8,
     max_passive_level: u8,
 ) -> Reward {
-    let reward = pick_reward(weapon_inv, passive_inv, max_weapon_level, max_passive_level);
+    let reward = pick_reward(
+        rng,
+        weapon_inv,
+        passive_inv,
+        max_weapon_level,
+        max_passive_level,
+    );
     apply_reward(reward, weapon_inv, passive_inv, stats, game_data, ctx);
     reward
 }
@@ -275,12 +309,15 @@ pub struct WeaponEvolvedTrigger {
 /// Reacts to [`WeaponEvolvedTrigger`] and replaces the base weapon entry in
 /// [`WeaponInventory`] with the evolved form.
 ///
+/// The base weapon is found through the `evolution.ron` table, so any recipe
+/// listed there is applied without code changes.
+///
 /// Registered as a global observer on `App`.
 pub fn apply_evolution(
     trigger: On<WeaponEvolvedTrigger>,
     mut player_q: Query<(&mut WeaponInventory, &PassiveInventory), With<Player>>,
+    evolution_cfg: EvolutionParams,
 ) {
-    use super::evolution::{get_evolution_requirement, get_evolved_weapon};
     use crate::types::WeaponState;
 
     let evolved_type = trigger.event().evolved_type;
@@ -293,11 +330,18 @@ pub fn apply_evolution(
         if ws.evolved {
             continue;
         }
-        let Some(required) = get_evolution_requirement(ws.weapon_type) else {
+        let recipe = evolution_cfg
+            .table()
+            .iter()
+            .find(|e| e.weapon == ws.weapon_type && e.evolved == evolved_type);
+        let Some(recipe) = recipe else {
             continue;
         };
-        let has_passive = passive_inv.items.iter().any(|p| p.item_type == required);
-        if has_passive && get_evolved_weapon(ws.weapon_type) == evolved_type {
+        let has_passive = passive_inv
+            .items
+            .iter()
+            .any(|p| p.item_type == recipe.passive);
+        if has_passive {
             *ws = WeaponState {
                 weapon_type: evolved_type,
                 level: ws.level, // preserve the earned level (always max for evolution)
@@ -317,14 +361,15 @@ pub fn apply_evolution(
 ///
 /// `radius` is the chest's collision radius in pixels; callers should pass
 /// `game_cfg.treasure_radius()` (falls back to `config::game::DEFAULT_TREASURE_RADIUS`
-/// when config is not yet loaded).
+/// when config is not yet loaded).  `items` is the number of rewards the
+/// chest grants when opened.
 ///
 /// The chest uses a yellow square placeholder sprite (replace with pixel-art
 /// chest sprite in Phase 17).  z = 6.0 places it above enemies (z = 5.0) so
 /// it is always visible.
 ///
 /// Called by the spawner system when a treasure drop is triggered.
-pub fn spawn_treasure(commands: &mut Commands, position: Vec2, radius: f32) {
+pub fn spawn_treasure(commands: &mut Commands, position: Vec2, radius: f32, items: u32) {
     commands.spawn((
         // Yellow square placeholder; Phase 17 will replace with a real sprite.
         // Starts white and fades to yellow via TreasureSpawnFlash.
@@ -335,7 +380,7 @@ pub fn spawn_treasure(commands: &mut Commands, position: Vec2, radius: f32) {
         },
         Transform::from_xyz(position.x, position.y, 6.0),
         CircleCollider { radius },
-        Treasure,
+        Treasure { items },
         TreasureSpawnFlash { elapsed: 0.0 },
         GameSessionEntity,
     ));
@@ -461,6 +506,7 @@ mod tests {
             .init_state::<AppState>()
             .insert_resource(GameData::default())
             .insert_resource(MetaProgress::default())
+            .insert_resource(GameRng::from_seed(0))
             .add_message::<TreasureOpenedEvent>()
             .add_observer(apply_evolution)
             .add_systems(
@@ -497,7 +543,7 @@ mod tests {
 
     fn spawn_treasure_at(app: &mut App, pos: Vec2) -> Entity {
         app.world_mut()
-            .spawn((Treasure, Transform::from_xyz(pos.x, pos.y, 0.0)))
+            .spawn((Treasure::default(), Transform::from_xyz(pos.x, pos.y, 0.0)))
             .id()
     }
 
@@ -575,6 +621,29 @@ mod tests {
         panic!("gold reward never triggered in 100 chest openings");
     }
 
+    /// A multi-item chest applies one reward per item: a five-item chest can
+    /// award gold several times in one opening, which a one-item chest never
+    /// does.
+    #[test]
+    fn five_item_chest_applies_several_rewards() {
+        let mut app = build_app();
+        spawn_player_at(&mut app, Vec2::ZERO);
+
+        for _ in 0..100 {
+            let before = app.world().resource::<GameData>().gold_earned;
+            app.world_mut()
+                .spawn((Treasure { items: 5 }, Transform::from_xyz(0.0, 0.0, 0.0)));
+            app.update();
+
+            let awarded = app.world().resource::<GameData>().gold_earned - before;
+            assert!(awarded <= 5 * DEFAULT_TREASURE_GOLD);
+            if awarded > DEFAULT_TREASURE_GOLD {
+                return;
+            }
+        }
+        panic!("no five-item chest awarded gold twice in 100 openings");
+    }
+
     /// When an evolvable weapon is present, the evolution triggers and no gold
     /// is awarded.
     #[test]
@@ -626,6 +695,54 @@ mod tests {
         assert!(inv.weapons[0].evolved, "evolved flag should be set");
     }
 
+    /// Every item of a multi-item chest can evolve a different weapon; the
+    /// items left over become regular rewards.
+    #[test]
+    fn multi_item_chest_evolves_every_eligible_weapon() {
+        let mut app = build_app();
+
+        let mut whip = WeaponState::new(WeaponType::Whip);
+        whip.level = 8;
+        let mut knife = WeaponState::new(WeaponType::Knife);
+        knife.level = 8;
+        let passive = PassiveInventory {
+            items: [PassiveItemType::HollowHeart, PassiveItemType::Bracer]
+                .into_iter()
+                .map(|item_type| PassiveState {
+                    item_type,
+                    level: 1,
+                })
+                .collect(),
+        };
+        let mut stats = PlayerStats::default();
+        stats.max_hp = 100.0;
+        stats.current_hp = 100.0;
+        app.world_mut().spawn((
+            Player,
+            WeaponInventory {
+                weapons: vec![whip, knife],
+            },
+            passive,
+            stats,
+            Transform::from_xyz(0.0, 0.0, 0.0),
+            CircleCollider {
+                radius: DEFAULT_TREASURE_RADIUS,
+            },
+        ));
+        app.world_mut()
+            .spawn((Treasure { items: 3 }, Transform::from_xyz(0.0, 0.0, 0.0)));
+
+        app.update();
+
+        let mut q = app
+            .world_mut()
+            .query_filtered::<&WeaponInventory, With<Player>>();
+        let inv = q.single(app.world()).expect("player should exist");
+        let types: Vec<WeaponType> = inv.weapons.iter().map(|w| w.weapon_type).collect();
+        assert_eq!(types, [WeaponType::BloodyTear, WeaponType::ThousandEdge]);
+        assert!(inv.weapons.iter().all(|w| w.evolved));
+    }
+
     /// Opening a chest emits exactly one [`TreasureOpenedEvent`] at the chest
     /// position.
     #[test]
@@ -680,7 +797,7 @@ mod tests {
         let radius = DEFAULT_TREASURE_RADIUS;
         app.world_mut()
             .run_system_once(move |mut commands: Commands| {
-                spawn_treasure(&mut commands, Vec2::ZERO, radius);
+                spawn_treasure(&mut commands, Vec2::ZERO, radius, 1);
             })
             .unwrap();
 
@@ -722,6 +839,7 @@ mod tests {
                     &mut commands,
                     Vec2::new(10.0, 20.0),
                     DEFAULT_TREASURE_RADIUS,
+                    1,
                 );
             })
             .unwrap();
@@ -15,6 +15,16 @@ pub enum CharacterType {
     Knight,
 }
 
+impl CharacterType {
+    /// Every playable character, in character-select order.
+    pub const ALL: [Self; 4] = [
+        Self::DefaultCharacter,
+        Self::Magician,
+        Self::Thief,
+        Self::Knight,
+    ];
+}
+
 /// Base statistics for a playable character.
 ///
 /// Loaded from `assets/config/character.ron` at runtime via [`CharacterConfig`].
@@ -0,0 +1,66 @@
+use bevy::prelude::Color;
+
+/// Size of a gold drop, rolled when an enemy drops gold.
+///
+/// The gold each tier is worth comes from `game.ron`
+/// (`gold_coin_value`, `gold_bag_value`, `gold_rich_bag_value`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoinTier {
+    #[default]
+    Coin,
+    Bag,
+    RichBag,
+}
+
+impl CoinTier {
+    /// Picks a tier from a uniform roll in `0.0..1.0`.
+    ///
+    /// The lowest `rich_bag_chance` of the range yields a rich bag, the next
+    /// `bag_chance` a bag, and the rest a plain coin.
+    pub fn from_roll(roll: f32, bag_chance: f32, rich_bag_chance: f32) -> Self {
+        let rich = rich_bag_chance.max(0.0);
+        if roll < rich {
+            Self::RichBag
+        } else if roll < rich + bag_chance.max(0.0) {
+            Self::Bag
+        } else {
+            Self::Coin
+        }
+    }
+
+    pub fn color(self) -> Color {
+        match self {
+            Self::Coin => Color::srgb(1.0, 0.85, 0.2),
+            Self::Bag => Color::srgb(0.9, 0.65, 0.15),
+            Self::RichBag => Color::srgb(1.0, 0.95, 0.6),
+        }
+    }
+
+    pub fn radius(self) -> f32 {
+        match self {
+            Self::Coin => 5.0,
+            Self::Bag => 7.0,
+            Self::RichBag => 10.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_roll_boundaries() {
+        assert_eq!(CoinTier::from_roll(0.0, 0.1, 0.02), CoinTier::RichBag);
+        assert_eq!(CoinTier::from_roll(0.019, 0.1, 0.02), CoinTier::RichBag);
+        assert_eq!(CoinTier::from_roll(0.02, 0.1, 0.02), CoinTier::Bag);
+        assert_eq!(CoinTier::from_roll(0.119, 0.1, 0.02), CoinTier::Bag);
+        assert_eq!(CoinTier::from_roll(0.12, 0.1, 0.02), CoinTier::Coin);
+        assert_eq!(CoinTier::from_roll(0.99, 0.1, 0.02), CoinTier::Coin);
+    }
+
+    #[test]
+    fn zero_chances_always_yield_plain_coins() {
+        assert_eq!(CoinTier::from_roll(0.0, 0.0, 0.0), CoinTier::Coin);
+    }
+}
@@ -1,3 +1,4 @@
+use bevy::prelude::Color;
 use serde::{Deserialize, Serialize};
 
 /// All enemy types, ordered by earliest appearance.
@@ -7,8 +8,9 @@ pub enum EnemyType {
     Bat,
     /// Appears from 0 min. Basic melee enemy.
     Skeleton,
-    /// Spawns every 3 minutes until the final boss appears. High HP mini-boss
-    /// that drops a treasure chest on defeat.
+    /// Spawns on the stage's mini-boss schedule until the final boss
+    /// appears.  High HP; its [`MiniBossKind`] sets its behaviour and the
+    /// size of the treasure chest it drops on defeat.
     MiniBoss,
     /// Appears from 5 min. Slow but tanky.
     Zombie,
@@ -26,6 +28,70 @@ pub enum EnemyType {
     MiniDeath,
 }
 
+impl EnemyType {
+    /// `true` for the final boss and the treasure-carrying mini-boss.
+    ///
+    /// Screen-clearing effects such as the rosary leave these alive.
+    pub fn is_boss(self) -> bool {
+        matches!(self, EnemyType::BossDeath | EnemyType::MiniBoss)
+    }
+}
+
+/// Modifier carried by an elite enemy (see
+/// [`Elite`](crate::components::Elite)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EliteModifier {
+    /// Moves faster than its kin.
+    Swift,
+    /// Takes reduced damage from every source.
+    Armored,
+    /// Splits into smaller copies of itself on death.
+    Splitting,
+    /// Heals itself whenever it damages the player.
+    Vampiric,
+}
+
+impl EliteModifier {
+    /// Every modifier, in roll order.
+    pub const ALL: [EliteModifier; 4] = [
+        EliteModifier::Swift,
+        EliteModifier::Armored,
+        EliteModifier::Splitting,
+        EliteModifier::Vampiric,
+    ];
+
+    /// Sprite tint marking an elite with this modifier.
+    pub fn tint(self) -> Color {
+        match self {
+            EliteModifier::Swift => Color::srgb(1.0, 0.9, 0.2),
+            EliteModifier::Armored => Color::srgb(0.55, 0.6, 0.7),
+            EliteModifier::Splitting => Color::srgb(0.3, 0.95, 0.6),
+            EliteModifier::Vampiric => Color::srgb(0.75, 0.0, 0.2),
+        }
+    }
+}
+
+/// Archetype of a [`EnemyType::MiniBoss`] (see
+/// [`MiniBoss`](crate::components::MiniBoss)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MiniBossKind {
+    /// Slow, oversized brute that periodically charges at the player.
+    Giant,
+    /// Keeps its distance and calls in packs of regular enemies.
+    Summoner,
+    /// Keeps its distance and fires aimed projectile volleys.
+    Mage,
+}
+
+impl MiniBossKind {
+    /// Every archetype, in the order the fallback timer cycles through them.
+    pub const ALL: [MiniBossKind; 3] = [
+        MiniBossKind::Giant,
+        MiniBossKind::Summoner,
+        MiniBossKind::Mage,
+    ];
+}
+
 /// Enemy AI behavior mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum AIType {
@@ -37,6 +103,20 @@ pub enum AIType {
     ChargeAttack,
     /// Multi-phase boss behavior.
     BossMultiPhase,
+    /// Follows the [`FormationMotion`](crate::components::FormationMotion)
+    /// override given by a formation event until released.
+    Formation,
+}
+
+/// Shape of a scheduled formation spawn event (see `formations` in `stage.ron`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FormationKind {
+    /// Closing ring of enemies around the player.
+    Ring,
+    /// Tight, fast cluster crossing the screen in a straight line.
+    Swarm,
+    /// Line of enemies sweeping across the screen from one side.
+    Wall,
 }
 
 /// Boss fight phases.
@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 use crate::types::{PassiveItemType, WeaponType};
 
 /// Content revealed when a treasure chest is opened.
@@ -14,7 +16,7 @@ pub enum TreasureContent {
 }
 
 /// One option shown on the level-up card selection screen.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum UpgradeChoice {
     /// Acquire a new weapon.
     NewWeapon(WeaponType),
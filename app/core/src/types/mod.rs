@@ -1,13 +1,17 @@
 pub mod character;
+pub mod coin_tier;
 pub mod enemy;
 pub mod game;
 pub mod gem_tier;
+pub mod pickup;
 pub mod stage;
 pub mod weapon;
 
 pub use character::*;
+pub use coin_tier::CoinTier;
 pub use enemy::*;
 pub use game::*;
 pub use gem_tier::GemTier;
+pub use pickup::PickupType;
 pub use stage::StageType;
 pub use weapon::*;
@@ -0,0 +1,28 @@
+use bevy::prelude::Color;
+use serde::{Deserialize, Serialize};
+
+/// Items that can lie on the floor and take effect when the player touches
+/// them.  Which ones drop, and how often, is set by `pickup.ron`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PickupType {
+    /// Floor chicken: restores a fixed amount of HP.
+    FloorChicken,
+    /// Vacuum: pulls every XP gem on the map toward the player.
+    Vacuum,
+    /// Rosary: kills every non-boss enemy on screen.
+    Rosary,
+    /// Orologion: freezes enemy AI for a few seconds.
+    Orologion,
+}
+
+impl PickupType {
+    /// Placeholder sprite colour until real art is added.
+    pub fn color(self) -> Color {
+        match self {
+            Self::FloorChicken => Color::srgb(0.95, 0.55, 0.35),
+            Self::Vacuum => Color::srgb(0.3, 0.6, 1.0),
+            Self::Rosary => Color::srgb(1.0, 1.0, 1.0),
+            Self::Orologion => Color::srgb(0.5, 0.9, 1.0),
+        }
+    }
+}
@@ -1,5 +1,7 @@
+use serde::{Deserialize, Serialize};
+
 /// All available stages, each with distinct enemy pools and difficulty modifiers.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum StageType {
     /// Mad Forest — basic enemies (Bat, Skeleton). Baseline difficulty.
     #[default]
@@ -10,6 +12,11 @@ pub enum StageType {
     DairyPlant,
 }
 
+impl StageType {
+    /// Every stage, in stage-select order.
+    pub const ALL: [Self; 3] = [Self::MadForest, Self::InlaidLibrary, Self::DairyPlant];
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -7,7 +7,10 @@
 
 use bevy::prelude::*;
 use vs_core::config::{CharacterConfig, CharacterParams, GameConfig, GameParams};
-use vs_core::resources::{GameSettings, MetaProgress, PendingUpgradeIndex, SelectedStage};
+use vs_core::resources::{
+    GameRng, GameSettings, MetaProgress, PendingUpgradeIndex, SeedOverride, SelectedStage,
+    VolumeChannel,
+};
 use vs_core::states::AppState;
 use vs_core::types::{
     CharacterType, MetaUpgradeType, StageType, get_character_stats, upgrade_cost,
@@ -53,6 +56,15 @@ pub enum ButtonAction {
     GoToTitle,
     /// Toggle the UI language between Japanese and English.
     ToggleLanguage,
+    /// Lower the given volume level by one step.
+    VolumeDown(VolumeChannel),
+    /// Raise the given volume level by one step.
+    VolumeUp(VolumeChannel),
+    /// Toggle [`GameSettings::muted`].
+    ToggleMute,
+    /// Toggle [`SeedOverride`] between a random seed per run and reusing the
+    /// seed of the most recent run.
+    ToggleFixedSeed,
     /// Confirm the upgrade card at the given index and resume gameplay.
     ///
     /// The index refers to the slot in [`vs_core::resources::LevelUpChoices`]
@@ -101,6 +113,8 @@ pub fn handle_button_interaction(
     mut selected_stage: Option<ResMut<SelectedStage>>,
     char_params: CharacterParams,
     game_params: GameParams,
+    mut seed_override: Option<ResMut<SeedOverride>>,
+    game_rng: Option<Res<GameRng>>,
 ) {
     let color_normal = btn_cfg.color_normal();
     let color_hover = btn_cfg.color_hover();
@@ -119,6 +133,8 @@ pub fn handle_button_interaction(
                     &mut selected_stage,
                     char_params.get(),
                     game_params.get(),
+                    &mut seed_override,
+                    game_rng.as_deref().map(GameRng::seed),
                 );
             }
             Interaction::Hovered => {
@@ -141,6 +157,8 @@ fn apply_action(
     selected_stage: &mut Option<ResMut<SelectedStage>>,
     char_cfg: Option<&CharacterConfig>,
     game_cfg: Option<&GameConfig>,
+    seed_override: &mut Option<ResMut<SeedOverride>>,
+    last_seed: Option<u64>,
 ) {
     match action {
         ButtonAction::StartGame => {
@@ -171,6 +189,29 @@ fn apply_action(
                 s.language = s.language.next();
             }
         }
+        ButtonAction::VolumeDown(channel) => {
+            if let Some(s) = settings {
+                s.step_volume(channel, -1);
+            }
+        }
+        ButtonAction::VolumeUp(channel) => {
+            if let Some(s) = settings {
+                s.step_volume(channel, 1);
+            }
+        }
+        ButtonAction::ToggleMute => {
+            if let Some(s) = settings {
+                s.muted = !s.muted;
+            }
+        }
+        ButtonAction::ToggleFixedSeed => {
+            if let Some(o) = seed_override {
+                o.0 = match o.0 {
+                    Some(_) => None,
+                    None => last_seed,
+                };
+            }
+        }
         ButtonAction::SelectUpgrade(index) => {
             if let Some(p) = pending {
                 p.0 = Some(index);
@@ -250,6 +291,8 @@ mod tests {
                 &mut None,
                 None,
                 None,
+                &mut None,
+                None,
             );
         }
         app.update();
@@ -284,6 +327,8 @@ mod tests {
                 &mut None,
                 None,
                 None,
+                &mut None,
+                None,
             );
         }
         app.update();
@@ -310,6 +355,8 @@ mod tests {
                 &mut None,
                 None,
                 None,
+                &mut None,
+                None,
             );
         }
         app.update();
@@ -320,6 +367,87 @@ mod tests {
         );
     }
 
+    #[test]
+    fn apply_action_volume_steppers_update_settings() {
+        use bevy::ecs::system::RunSystemOnce as _;
+        use bevy::state::app::StatesPlugin;
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin));
+        app.init_state::<AppState>();
+        app.insert_resource(GameSettings::default());
+
+        app.world_mut()
+            .run_system_once(
+                |mut next_state: ResMut<NextState<AppState>>, settings: ResMut<GameSettings>| {
+                    let mut settings = Some(settings);
+                    for action in [
+                        ButtonAction::VolumeDown(VolumeChannel::Sfx),
+                        ButtonAction::VolumeDown(VolumeChannel::Sfx),
+                        ButtonAction::ToggleMute,
+                    ] {
+                        apply_action(
+                            action,
+                            &mut next_state,
+                            &mut None,
+                            &mut settings,
+                            &mut None,
+                            &mut None,
+                            None,
+                            None,
+                            &mut None,
+                            None,
+                        );
+                    }
+                },
+            )
+            .unwrap();
+
+        let settings = app.world().resource::<GameSettings>();
+        assert!((settings.sfx_volume - 0.8).abs() < 1e-6);
+        assert_eq!(settings.bgm_volume, 1.0);
+        assert!(settings.muted);
+    }
+
+    #[test]
+    fn apply_action_toggle_fixed_seed_reuses_last_seed() {
+        use bevy::ecs::system::RunSystemOnce as _;
+        use bevy::state::app::StatesPlugin;
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin));
+        app.init_state::<AppState>();
+        app.init_resource::<SeedOverride>();
+
+        let toggle = |app: &mut App| {
+            app.world_mut()
+                .run_system_once(
+                    |mut next_state: ResMut<NextState<AppState>>,
+                     seed_override: ResMut<SeedOverride>| {
+                        apply_action(
+                            ButtonAction::ToggleFixedSeed,
+                            &mut next_state,
+                            &mut None,
+                            &mut None,
+                            &mut None,
+                            &mut None,
+                            None,
+                            None,
+                            &mut Some(seed_override),
+                            Some(4242),
+                        );
+                    },
+                )
+                .unwrap();
+        };
+
+        toggle(&mut app);
+        assert_eq!(
+            *app.world().resource::<SeedOverride>(),
+            SeedOverride(Some(4242))
+        );
+        toggle(&mut app);
+        assert_eq!(*app.world().resource::<SeedOverride>(), SeedOverride(None));
+    }
+
     #[test]
     fn menu_button_is_clone() {
         let original = MenuButton {
@@ -2,8 +2,9 @@
 //!
 //! Spawns a label + track + fill as child sprites of the boss entity, so the
 //! bar moves with the boss automatically.  The bar is created the first frame a
-//! [`BossPhase`] entity appears and is cleaned up automatically when the boss
-//! entity is despawned.
+//! boss appears — Boss Death ([`BossPhase`]) or a stage's own boss
+//! ([`BossPatterns`]) — and is cleaned up automatically when the boss entity is
+//! despawned.  A stage boss is labelled with its own name.
 //!
 //! ```text
 //!         DEATH          ← Text2d label (world space)
@@ -18,7 +19,7 @@
 //! | [`update_boss_hp_bar_world`] | Every frame while `Playing`   | Updates fill width to match HP %     |
 
 use bevy::prelude::*;
-use vs_core::components::Enemy;
+use vs_core::components::{BossPatterns, Enemy};
 use vs_core::types::BossPhase;
 
 use crate::config::hud::gameplay::BossHpBarHudConfig;
@@ -45,6 +46,9 @@ const Z_FILL: f32 = 1.5;
 /// Z offset of the label text (above both).
 const Z_LABEL: f32 = 2.0;
 
+/// Filter matching either kind of boss that shows an HP bar.
+type BossFilter = Or<(With<BossPhase>, With<BossPatterns>)>;
+
 // ---------------------------------------------------------------------------
 // Marker components
 // ---------------------------------------------------------------------------
@@ -86,17 +90,21 @@ pub struct BossHpBarFill {
 /// Spawns the HP bar (track + fill + label) as children of each new boss entity.
 ///
 /// Runs every `Playing` frame, but is a no-op after the first frame because
-/// [`BossHpBarAttached`] is inserted immediately on spawn.
+/// [`BossHpBarAttached`] is inserted immediately on spawn.  A stage boss shows
+/// its upper-cased name instead of the configured `label_text`.
 pub fn maybe_spawn_boss_hp_bar(
     mut commands: Commands,
-    boss_q: Query<Entity, (With<BossPhase>, Without<BossHpBarAttached>)>,
+    boss_q: Query<(Entity, Option<&BossPatterns>), (BossFilter, Without<BossHpBarAttached>)>,
     cfg: crate::config::hud::gameplay::BossHpBarHudParams<'_>,
 ) {
-    for boss_entity in boss_q.iter() {
+    for (boss_entity, patterns) in boss_q.iter() {
         let c = cfg.get();
-        let label_text = c
-            .map(|c| c.label_text.as_str())
-            .unwrap_or(DEFAULT_LABEL_TEXT);
+        let label_text = match patterns {
+            Some(patterns) => patterns.boss.name.to_uppercase(),
+            None => c
+                .map(|c| c.label_text.clone())
+                .unwrap_or_else(|| DEFAULT_LABEL_TEXT.to_string()),
+        };
         let bar_width = c.map(|c| c.bar_width).unwrap_or(DEFAULT_BAR_WIDTH);
         let bar_height = c.map(|c| c.bar_height).unwrap_or(DEFAULT_BAR_HEIGHT);
         let label_font_size = c
@@ -166,7 +174,7 @@ pub fn maybe_spawn_boss_hp_bar(
 /// The fill is left-aligned within the track: `Transform.translation.x` is
 /// shifted so the left edge stays fixed while the right edge shrinks.
 pub fn update_boss_hp_bar_world(
-    boss_q: Query<&Enemy, With<BossPhase>>,
+    boss_q: Query<&Enemy, BossFilter>,
     mut fill_q: Query<(&BossHpBarFill, &mut Sprite, &mut Transform)>,
 ) {
     let Ok(enemy) = boss_q.single() else {
@@ -366,6 +374,43 @@ mod tests {
         assert!((sprite.custom_size.unwrap().x - 160.0).abs() < 1e-5);
     }
 
+    /// A stage boss gets a bar labelled with its own name.
+    #[test]
+    fn stage_boss_bar_uses_boss_name() {
+        use vs_core::config::StageBoss;
+
+        let mut app = build_app();
+        let boss = StageBoss {
+            name: "Library Lich".to_string(),
+            base_hp: 1000.0,
+            speed: 20.0,
+            damage: 10.0,
+            collider_radius: 30.0,
+            color: (1.0, 1.0, 1.0),
+            projectile_radius: 5.0,
+            projectile_lifetime: 3.0,
+            phases: Vec::new(),
+        };
+        let entity = app
+            .world_mut()
+            .spawn((
+                vs_core::components::Enemy::from_type(EnemyType::BossDeath, 1.0),
+                BossPatterns::new(boss),
+            ))
+            .id();
+
+        app.world_mut()
+            .run_system_once(maybe_spawn_boss_hp_bar)
+            .unwrap();
+
+        assert!(app.world().get::<BossHpBarAttached>(entity).is_some());
+        let mut q = app
+            .world_mut()
+            .query_filtered::<&Text2d, With<BossHpBarLabel>>();
+        let label = q.single(app.world()).unwrap();
+        assert_eq!(label.0, "LIBRARY LICH");
+    }
+
     /// No boss → update_boss_hp_bar_world returns early without panicking.
     #[test]
     fn no_boss_does_not_panic() {
@@ -20,9 +20,10 @@
 
 use bevy::prelude::*;
 use bevy::state::state_scoped::DespawnOnExit;
+use vs_core::resources::Language;
 use vs_core::states::AppState;
+use vs_core::systems::weapons::registry::WeaponRegistry;
 use vs_core::systems::xp::treasure::WeaponEvolvedTrigger;
-use vs_core::types::WeaponType;
 
 use crate::config::hud::gameplay::evolution_notification::EvolutionNotificationHudConfigHandle;
 use crate::config::{EvolutionNotificationHudConfig, EvolutionNotificationHudParams};
@@ -65,30 +66,6 @@ pub struct EvolutionNotification {
     pub text_color: Color,
 }
 
-// ---------------------------------------------------------------------------
-// Display name helper
-// ---------------------------------------------------------------------------
-
-/// Returns a human-readable display name for the given [`WeaponType`].
-pub fn weapon_display_name(weapon: WeaponType) -> &'static str {
-    match weapon {
-        WeaponType::Whip => "Whip",
-        WeaponType::MagicWand => "Magic Wand",
-        WeaponType::Knife => "Knife",
-        WeaponType::Garlic => "Garlic",
-        WeaponType::Bible => "Bible",
-        WeaponType::ThunderRing => "Thunder Ring",
-        WeaponType::Cross => "Cross",
-        WeaponType::FireWand => "Fire Wand",
-        WeaponType::BloodyTear => "Bloody Tear",
-        WeaponType::HolyWand => "Holy Wand",
-        WeaponType::ThousandEdge => "Thousand Edge",
-        WeaponType::SoulEater => "Soul Eater",
-        WeaponType::UnholyVespers => "Unholy Vespers",
-        WeaponType::LightningRing => "Lightning Ring",
-    }
-}
-
 // ---------------------------------------------------------------------------
 // Observer
 // ---------------------------------------------------------------------------
@@ -101,6 +78,7 @@ pub fn weapon_display_name(weapon: WeaponType) -> &'static str {
 pub fn on_weapon_evolved(
     trigger: On<WeaponEvolvedTrigger>,
     mut commands: Commands,
+    registry: Res<WeaponRegistry>,
     cfg: EvolutionNotificationHudParams,
 ) {
     let cfg = cfg.get();
@@ -110,7 +88,8 @@ pub fn on_weapon_evolved(
     let top_percent = cfg.map_or(DEFAULT_TOP_PERCENT, |c| c.top_percent);
     let text_color = cfg.map_or(DEFAULT_TEXT_COLOR, |c| Color::from(&c.text_color));
 
-    let name = weapon_display_name(trigger.event().evolved_type);
+    // The banner caption is English-only, so the name matches it.
+    let name = registry.name(trigger.event().evolved_type, Language::English);
     let text = format!("{name}\nEvolved!");
 
     // Spawn the text as a separate entity so its TextColor can be mutated
@@ -254,52 +233,6 @@ mod tests {
 
     use super::*;
 
-    // -----------------------------------------------------------------------
-    // weapon_display_name
-    // -----------------------------------------------------------------------
-
-    /// All evolved weapon types return non-empty display names.
-    #[test]
-    fn evolved_weapons_have_display_names() {
-        let evolved = [
-            WeaponType::BloodyTear,
-            WeaponType::HolyWand,
-            WeaponType::ThousandEdge,
-            WeaponType::SoulEater,
-            WeaponType::UnholyVespers,
-            WeaponType::LightningRing,
-        ];
-        for wt in evolved {
-            let name = weapon_display_name(wt);
-            assert!(
-                !name.is_empty(),
-                "{wt:?} should have a non-empty display name"
-            );
-        }
-    }
-
-    /// All base weapon types also return non-empty display names.
-    #[test]
-    fn base_weapons_have_display_names() {
-        let base = [
-            WeaponType::Whip,
-            WeaponType::MagicWand,
-            WeaponType::Knife,
-            WeaponType::Garlic,
-            WeaponType::Bible,
-            WeaponType::ThunderRing,
-            WeaponType::Cross,
-            WeaponType::FireWand,
-        ];
-        for wt in base {
-            let name = weapon_display_name(wt);
-            assert!(
-                !name.is_empty(),
-                "{wt:?} should have a non-empty display name"
-            );
-        }
-    }
-
     // -----------------------------------------------------------------------
     // Helpers
     // -----------------------------------------------------------------------
@@ -330,6 +263,39 @@ mod tests {
             .id()
     }
 
+    /// Texts of every spawned `Text` entity.
+    fn texts(app: &mut App) -> Vec<String> {
+        app.world_mut()
+            .query::<&Text>()
+            .iter(app.world())
+            .map(|t| t.0.clone())
+            .collect()
+    }
+
+    // -----------------------------------------------------------------------
+    // on_weapon_evolved
+    // -----------------------------------------------------------------------
+
+    /// The banner shows the evolved weapon's registered name.
+    #[test]
+    fn banner_uses_registered_weapon_name() {
+        use vs_core::systems::weapons::whip::BLOODY_TEAR;
+        use vs_core::types::WeaponType;
+
+        let mut app = build_app();
+        let mut registry = WeaponRegistry::default();
+        registry.register(BLOODY_TEAR);
+        app.insert_resource(registry);
+        app.add_observer(on_weapon_evolved);
+
+        app.world_mut().trigger(WeaponEvolvedTrigger {
+            evolved_type: WeaponType::BloodyTear,
+        });
+        app.world_mut().flush();
+
+        assert_eq!(texts(&mut app), vec!["Bloody Tear\nEvolved!".to_string()]);
+    }
+
     // -----------------------------------------------------------------------
     // update_evolution_notification
     // -----------------------------------------------------------------------
@@ -10,7 +10,7 @@
 
 use bevy::prelude::*;
 use vs_core::components::{Player, WeaponInventory};
-use vs_core::types::WeaponType;
+use vs_core::systems::weapons::registry::WeaponRegistry;
 
 use crate::config::hud::gameplay::WeaponSlotsHudConfig;
 use crate::config::hud::gameplay::weapon_slots::WeaponSlotsHudConfigHandle;
@@ -56,30 +56,6 @@ pub struct HudWeaponSlotLabel {
 #[derive(Component, Debug)]
 pub struct HudWeaponSlotsRow;
 
-// ---------------------------------------------------------------------------
-// Display helpers
-// ---------------------------------------------------------------------------
-
-/// Returns a short abbreviation for a weapon type, shown inside the slot.
-pub fn weapon_abbr(wt: WeaponType) -> &'static str {
-    match wt {
-        WeaponType::Whip => "Wh",
-        WeaponType::MagicWand => "MW",
-        WeaponType::Knife => "Kn",
-        WeaponType::Garlic => "Ga",
-        WeaponType::Bible => "Bi",
-        WeaponType::ThunderRing => "TR",
-        WeaponType::Cross => "Cr",
-        WeaponType::FireWand => "FW",
-        WeaponType::BloodyTear => "BT",
-        WeaponType::HolyWand => "HW",
-        WeaponType::ThousandEdge => "TE",
-        WeaponType::SoulEater => "SE",
-        WeaponType::UnholyVespers => "UV",
-        WeaponType::LightningRing => "LR",
-    }
-}
-
 // ---------------------------------------------------------------------------
 // Spawn
 // ---------------------------------------------------------------------------
@@ -159,6 +135,7 @@ pub fn update_weapon_slots(
     player_q: Query<&WeaponInventory, With<Player>>,
     mut slot_q: Query<(&HudWeaponSlot, &mut BackgroundColor)>,
     mut label_q: Query<(&HudWeaponSlotLabel, &mut Text, &mut Visibility)>,
+    registry: Res<WeaponRegistry>,
     cfg: crate::config::hud::gameplay::WeaponSlotsHudParams<'_>,
 ) {
     let Ok(inv) = player_q.single() else {
@@ -184,7 +161,7 @@ pub fn update_weapon_slots(
 
     for (label, mut text, mut vis) in label_q.iter_mut() {
         if let Some(weapon_state) = inv.weapons.get(label.index) {
-            *text = Text::new(weapon_abbr(weapon_state.weapon_type));
+            *text = Text::new(registry.abbrev(weapon_state.weapon_type));
             *vis = Visibility::Visible;
         } else {
             *text = Text::new("");
@@ -258,11 +235,17 @@ mod tests {
     use bevy::ecs::system::RunSystemOnce as _;
 
     use super::*;
-    use vs_core::types::WeaponState;
+    use vs_core::systems::weapons::{knife::KNIFE, runetracer::RUNETRACER, whip::WHIP};
+    use vs_core::types::{WeaponState, WeaponType};
 
     fn build_app() -> App {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins);
+        let mut registry = WeaponRegistry::default();
+        registry.register(WHIP);
+        registry.register(KNIFE);
+        registry.register(RUNETRACER);
+        app.insert_resource(registry);
         app
     }
 
@@ -363,7 +346,23 @@ mod tests {
         let vis = app.world().get::<Visibility>(label_entity).unwrap();
         assert_eq!(*vis, Visibility::Visible);
         let text = app.world().get::<Text>(label_entity).unwrap();
-        assert_eq!(text.0.as_str(), weapon_abbr(WeaponType::Whip));
+        assert_eq!(text.0.as_str(), WHIP.abbrev);
+    }
+
+    /// A Runetracer in the second slot shows its own registry abbreviation,
+    /// not the first slot's.
+    #[test]
+    fn runetracer_slot_shows_its_label() {
+        let mut app = build_app();
+        let slots = spawn_slots(&mut app);
+        spawn_player_with_weapons(&mut app, &[WeaponType::Whip, WeaponType::Runetracer]);
+
+        app.world_mut()
+            .run_system_once(update_weapon_slots)
+            .unwrap();
+
+        let text = app.world().get::<Text>(slots[1].1).unwrap();
+        assert_eq!(text.0.as_str(), RUNETRACER.abbrev);
     }
 
     /// The label for an empty slot is hidden.
@@ -382,34 +381,18 @@ mod tests {
         assert_eq!(*vis, Visibility::Hidden);
     }
 
+    /// A weapon missing from the registry shows a placeholder label.
     #[test]
-    fn weapon_abbr_all_types_covered() {
-        let types = [
-            WeaponType::Whip,
-            WeaponType::MagicWand,
-            WeaponType::Knife,
-            WeaponType::Garlic,
-            WeaponType::Bible,
-            WeaponType::ThunderRing,
-            WeaponType::Cross,
-            WeaponType::FireWand,
-            WeaponType::BloodyTear,
-            WeaponType::HolyWand,
-            WeaponType::ThousandEdge,
-            WeaponType::SoulEater,
-            WeaponType::UnholyVespers,
-            WeaponType::LightningRing,
-        ];
-        for wt in types {
-            let abbr = weapon_abbr(wt);
-            assert!(
-                !abbr.is_empty(),
-                "abbreviation for {wt:?} must not be empty"
-            );
-            assert!(
-                abbr.len() <= 4,
-                "abbreviation for {wt:?} should be short: '{abbr}'"
-            );
-        }
+    fn unregistered_weapon_shows_placeholder_label() {
+        let mut app = build_app();
+        let slots = spawn_slots(&mut app);
+        spawn_player_with_weapons(&mut app, &[WeaponType::Garlic]);
+
+        app.world_mut()
+            .run_system_once(update_weapon_slots)
+            .unwrap();
+
+        let text = app.world().get::<Text>(slots[0].1).unwrap();
+        assert_eq!(text.0.as_str(), "?");
     }
 }
@@ -1,13 +1,15 @@
 //! Upgrade card HUD widget.
 //!
 //! Spawns individual upgrade selection cards and handles their interaction
-//! colors.  Display text helpers (weapon/passive names and descriptions) also
-//! live here so they can be reused from other screens in the future (e.g. the
-//! meta-progression shop).
+//! colors.  Display text helpers (passive names and descriptions, and the
+//! per-choice lookups that read weapon text from the
+//! [`WeaponRegistry`]) also live here so they can be reused from other
+//! screens in the future (e.g. the meta-progression shop).
 
 use bevy::prelude::*;
 use vs_core::resources::Language;
-use vs_core::types::{PassiveItemType, UpgradeChoice, WeaponType};
+use vs_core::systems::weapons::registry::WeaponRegistry;
+use vs_core::types::{PassiveItemType, UpgradeChoice};
 
 use crate::components::{ButtonAction, MenuButton};
 use crate::config::hud::upgrade_card::{UpgradeCardHudConfig, UpgradeCardHudParams};
@@ -67,9 +69,15 @@ pub fn choice_subtitle(choice: &UpgradeChoice, lang: Language) -> &'static str {
 }
 
 /// Returns the item name for a choice in the given language.
-pub fn choice_name(choice: &UpgradeChoice, lang: Language) -> &'static str {
+///
+/// Weapon names come from their spec in `registry`.
+pub fn choice_name(
+    choice: &UpgradeChoice,
+    registry: &WeaponRegistry,
+    lang: Language,
+) -> &'static str {
     match choice {
-        UpgradeChoice::NewWeapon(wt) | UpgradeChoice::WeaponUpgrade(wt) => weapon_name(*wt, lang),
+        UpgradeChoice::NewWeapon(wt) | UpgradeChoice::WeaponUpgrade(wt) => registry.name(*wt, lang),
         UpgradeChoice::PassiveItem(pt) | UpgradeChoice::PassiveUpgrade(pt) => {
             passive_name(*pt, lang)
         }
@@ -77,10 +85,16 @@ pub fn choice_name(choice: &UpgradeChoice, lang: Language) -> &'static str {
 }
 
 /// Returns the one-line effect description for a choice in the given language.
-pub fn choice_description(choice: &UpgradeChoice, lang: Language) -> &'static str {
+///
+/// Weapon descriptions come from their spec in `registry`.
+pub fn choice_description(
+    choice: &UpgradeChoice,
+    registry: &WeaponRegistry,
+    lang: Language,
+) -> &'static str {
     match choice {
         UpgradeChoice::NewWeapon(wt) | UpgradeChoice::WeaponUpgrade(wt) => {
-            weapon_description(*wt, lang)
+            registry.description(*wt, lang)
         }
         UpgradeChoice::PassiveItem(pt) | UpgradeChoice::PassiveUpgrade(pt) => {
             passive_description(*pt, lang)
@@ -88,78 +102,6 @@ pub fn choice_description(choice: &UpgradeChoice, lang: Language) -> &'static st
     }
 }
 
-fn weapon_name(wt: WeaponType, lang: Language) -> &'static str {
-    match (wt, lang) {
-        (WeaponType::Whip, Language::Japanese) => "ムチ",
-        (WeaponType::Whip, Language::English) => "Whip",
-        (WeaponType::MagicWand, Language::Japanese) => "魔法の杖",
-        (WeaponType::MagicWand, Language::English) => "Magic Wand",
-        (WeaponType::Knife, Language::Japanese) => "ナイフ",
-        (WeaponType::Knife, Language::English) => "Knife",
-        (WeaponType::Garlic, Language::Japanese) => "ニンニク",
-        (WeaponType::Garlic, Language::English) => "Garlic",
-        (WeaponType::Bible, Language::Japanese) => "聖書",
-        (WeaponType::Bible, Language::English) => "Bible",
-        (WeaponType::ThunderRing, Language::Japanese) => "サンダーリング",
-        (WeaponType::ThunderRing, Language::English) => "Thunder Ring",
-        (WeaponType::Cross, Language::Japanese) => "クロス",
-        (WeaponType::Cross, Language::English) => "Cross",
-        (WeaponType::FireWand, Language::Japanese) => "炎の杖",
-        (WeaponType::FireWand, Language::English) => "Fire Wand",
-        (WeaponType::BloodyTear, Language::Japanese) => "血の涙",
-        (WeaponType::BloodyTear, Language::English) => "Bloody Tear",
-        (WeaponType::HolyWand, Language::Japanese) => "聖なる杖",
-        (WeaponType::HolyWand, Language::English) => "Holy Wand",
-        (WeaponType::ThousandEdge, Language::Japanese) => "千の刃",
-        (WeaponType::ThousandEdge, Language::English) => "Thousand Edge",
-        (WeaponType::SoulEater, Language::Japanese) => "魂喰い",
-        (WeaponType::SoulEater, Language::English) => "Soul Eater",
-        (WeaponType::UnholyVespers, Language::Japanese) => "邪悪な晩課",
-        (WeaponType::UnholyVespers, Language::English) => "Unholy Vespers",
-        (WeaponType::LightningRing, Language::Japanese) => "雷のリング",
-        (WeaponType::LightningRing, Language::English) => "Lightning Ring",
-    }
-}
-
-fn weapon_description(wt: WeaponType, lang: Language) -> &'static str {
-    match (wt, lang) {
-        (WeaponType::Whip, Language::Japanese) => "左右交互に扇状の斬撃。",
-        (WeaponType::Whip, Language::English) => "Fan-shaped swing, alternating sides.",
-        (WeaponType::MagicWand, Language::Japanese) => "最寄りの敵に向かうホーミング弾。",
-        (WeaponType::MagicWand, Language::English) => "Homing projectile toward nearest enemy.",
-        (WeaponType::Knife, Language::Japanese) => "移動方向へ高速貫通弾。",
-        (WeaponType::Knife, Language::English) => "Fast piercing shot in movement direction.",
-        (WeaponType::Garlic, Language::Japanese) => "プレイヤー周囲に継続ダメージのオーラ。",
-        (WeaponType::Garlic, Language::English) => "Continuous damage aura around player.",
-        (WeaponType::Bible, Language::Japanese) => "プレイヤーの周りを旋回する飛翔体。",
-        (WeaponType::Bible, Language::English) => "Orbiting projectile that circles the player.",
-        (WeaponType::ThunderRing, Language::Japanese) => "画面全体にランダムな落雷。",
-        (WeaponType::ThunderRing, Language::English) => {
-            "Random lightning strikes across the screen."
-        }
-        (WeaponType::Cross, Language::Japanese) => "飛んで戻ってくるブーメラン。",
-        (WeaponType::Cross, Language::English) => "Boomerang that flies out and returns.",
-        (WeaponType::FireWand, Language::Japanese) => "最大HPの敵を狙う火の玉。",
-        (WeaponType::FireWand, Language::English) => "Fireball targeting the highest-HP enemy.",
-        (WeaponType::BloodyTear, Language::Japanese) => "進化形ムチ — 広範囲の大斬撃。",
-        (WeaponType::BloodyTear, Language::English) => "Evolved Whip — massive area slash.",
-        (WeaponType::HolyWand, Language::Japanese) => "進化形魔法の杖 — 高速ホーミング弾。",
-        (WeaponType::HolyWand, Language::English) => "Evolved Magic Wand — rapid homing bolts.",
-        (WeaponType::ThousandEdge, Language::Japanese) => "進化形ナイフ — 終わりなき刃の嵐。",
-        (WeaponType::ThousandEdge, Language::English) => "Evolved Knife — endless blade flurry.",
-        (WeaponType::SoulEater, Language::Japanese) => "進化形ニンニク — 敵の命を吸収。",
-        (WeaponType::SoulEater, Language::English) => "Evolved Garlic — drains life from enemies.",
-        (WeaponType::UnholyVespers, Language::Japanese) => "進化形聖書 — 無限に旋回する刃。",
-        (WeaponType::UnholyVespers, Language::English) => {
-            "Evolved Bible — infinite orbiting blades."
-        }
-        (WeaponType::LightningRing, Language::Japanese) => "進化形サンダーリング — 雷の嵐。",
-        (WeaponType::LightningRing, Language::English) => {
-            "Evolved Thunder Ring — storm of lightning."
-        }
-    }
-}
-
 fn passive_name(pt: PassiveItemType, lang: Language) -> &'static str {
     match (pt, lang) {
         (PassiveItemType::Spinach, Language::Japanese) => "ほうれん草",
@@ -236,6 +178,7 @@ fn passive_description(pt: PassiveItemType, lang: Language) -> &'static str {
 ///
 /// - `index`  — zero-based position in the choices list (stored in [`UpgradeCardHud`]).
 /// - `choice` — the upgrade to display.
+/// - `registry` — source of weapon names and descriptions.
 /// - `cfg`    — layout/color config; pass `card_params.get()`. Falls back to
 ///   `DEFAULT_*` constants when the asset is not yet loaded.
 ///
@@ -245,6 +188,7 @@ pub fn spawn_upgrade_card(
     parent: &mut ChildSpawnerCommands,
     index: usize,
     choice: &UpgradeChoice,
+    registry: &WeaponRegistry,
     cfg: Option<&UpgradeCardHudConfig>,
     font: Handle<Font>,
     lang: Language,
@@ -333,7 +277,7 @@ pub fn spawn_upgrade_card(
 
             // Item name.
             card.spawn((
-                Text::new(choice_name(choice, lang)),
+                Text::new(choice_name(choice, registry, lang)),
                 TextFont {
                     font: font.clone(),
                     font_size: font_size_name,
@@ -344,7 +288,7 @@ pub fn spawn_upgrade_card(
 
             // Effect description.
             card.spawn((
-                Text::new(choice_description(choice, lang)),
+                Text::new(choice_description(choice, registry, lang)),
                 TextFont {
                     font: font.clone(),
                     font_size: font_size_desc,
@@ -428,36 +372,25 @@ pub fn hot_reload_upgrade_card_hud(mut events: MessageReader<AssetEvent<UpgradeC
 
 #[cfg(test)]
 mod tests {
+    use vs_core::types::WeaponType;
+
     use super::*;
 
     #[test]
-    fn weapon_name_covers_all_variants() {
-        let weapons = [
-            WeaponType::Whip,
-            WeaponType::MagicWand,
-            WeaponType::Knife,
-            WeaponType::Garlic,
-            WeaponType::Bible,
-            WeaponType::ThunderRing,
-            WeaponType::Cross,
-            WeaponType::FireWand,
-            WeaponType::BloodyTear,
-            WeaponType::HolyWand,
-            WeaponType::ThousandEdge,
-            WeaponType::SoulEater,
-            WeaponType::UnholyVespers,
-            WeaponType::LightningRing,
-        ];
-        for wt in weapons {
-            for lang in [Language::English, Language::Japanese] {
-                assert!(
-                    !weapon_name(wt, lang).is_empty(),
-                    "weapon_name({wt:?}, {lang:?}) must not be empty"
-                );
-                assert!(
-                    !weapon_description(wt, lang).is_empty(),
-                    "weapon_description({wt:?}, {lang:?}) must not be empty"
-                );
+    fn weapon_choices_read_registry_text() {
+        let registry = WeaponRegistry::with_builtin_weapons();
+        for spec in registry.iter() {
+            for choice in [
+                UpgradeChoice::NewWeapon(spec.weapon_type),
+                UpgradeChoice::WeaponUpgrade(spec.weapon_type),
+            ] {
+                for lang in [Language::English, Language::Japanese] {
+                    assert_eq!(choice_name(&choice, &registry, lang), spec.name.get(lang));
+                    assert_eq!(
+                        choice_description(&choice, &registry, lang),
+                        spec.description.get(lang)
+                    );
+                }
             }
         }
     }
@@ -536,6 +469,7 @@ mod tests {
                 parent,
                 0,
                 &choice,
+                &WeaponRegistry::with_builtin_weapons(),
                 None,
                 Handle::default(),
                 Language::English,
@@ -595,6 +529,7 @@ mod tests {
                     parent,
                     0,
                     choice,
+                    &WeaponRegistry::with_builtin_weapons(),
                     None,
                     Handle::default(),
                     Language::English,
@@ -707,6 +642,7 @@ mod tests {
                 parent,
                 0,
                 &choice,
+                &WeaponRegistry::with_builtin_weapons(),
                 Some(&cfg),
                 Handle::default(),
                 Language::English,
@@ -68,6 +68,22 @@ pub fn t(key: &'static str, lang: Language) -> &'static str {
         ("lang_japanese", Language::English) => "Japanese",
         ("lang_english", Language::Japanese) => "English",
         ("lang_english", Language::English) => "English",
+        ("label_master_volume", Language::Japanese) => "全体音量",
+        ("label_master_volume", Language::English) => "Master Volume",
+        ("label_bgm_volume", Language::Japanese) => "BGM音量",
+        ("label_bgm_volume", Language::English) => "Music Volume",
+        ("label_sfx_volume", Language::Japanese) => "効果音量",
+        ("label_sfx_volume", Language::English) => "SFX Volume",
+        ("label_mute", Language::Japanese) => "ミュート",
+        ("label_mute", Language::English) => "Mute",
+        ("label_on", Language::Japanese) => "オン",
+        ("label_on", Language::English) => "ON",
+        ("label_off", Language::Japanese) => "オフ",
+        ("label_off", Language::English) => "OFF",
+        ("label_seed", Language::Japanese) => "シード",
+        ("label_seed", Language::English) => "Seed",
+        ("label_seed_random", Language::Japanese) => "ランダム",
+        ("label_seed_random", Language::English) => "Random",
         ("btn_back", Language::Japanese) => "もどる",
         ("btn_back", Language::English) => "Back",
 
@@ -88,24 +104,6 @@ pub fn t(key: &'static str, lang: Language) -> &'static str {
         ("label_speed", Language::English) => "Speed",
         ("label_weapon", Language::Japanese) => "初期武器",
         ("label_weapon", Language::English) => "Starting Weapon",
-        // ── Weapon names (for character-select detail panel) ─────────────
-        ("weapon_whip", Language::Japanese) => "ムチ",
-        ("weapon_whip", Language::English) => "Whip",
-        ("weapon_magic_wand", Language::Japanese) => "マジックワンド",
-        ("weapon_magic_wand", Language::English) => "Magic Wand",
-        ("weapon_knife", Language::Japanese) => "ナイフ",
-        ("weapon_knife", Language::English) => "Knife",
-        ("weapon_garlic", Language::Japanese) => "ガーリック",
-        ("weapon_garlic", Language::English) => "Garlic",
-        ("weapon_bible", Language::Japanese) => "聖書",
-        ("weapon_bible", Language::English) => "Bible",
-        ("weapon_thunder_ring", Language::Japanese) => "サンダーリング",
-        ("weapon_thunder_ring", Language::English) => "Thunder Ring",
-        ("weapon_cross", Language::Japanese) => "クロス",
-        ("weapon_cross", Language::English) => "Cross",
-        ("weapon_fire_wand", Language::Japanese) => "ファイアワンド",
-        ("weapon_fire_wand", Language::English) => "Fire Wand",
-
         // ── Stage select screen ───────────────────────────────────────────
         ("stage_select_title", Language::Japanese) => "ステージ選択",
         ("stage_select_title", Language::English) => "Select Stage",
@@ -183,6 +181,18 @@ pub fn t(key: &'static str, lang: Language) -> &'static str {
         ("stat_enemies_defeated", Language::English) => "Enemies Defeated:",
         ("stat_gold_earned", Language::Japanese) => "獲得ゴールド:",
         ("stat_gold_earned", Language::English) => "Gold Earned:",
+        ("stat_seed", Language::Japanese) => "シード:",
+        ("stat_seed", Language::English) => "Seed:",
+
+        // ── Config error screen ───────────────────────────────────────────
+        ("config_error_title", Language::Japanese) => "設定ファイルエラー",
+        ("config_error_title", Language::English) => "CONFIG ERROR",
+        ("config_error_hint", Language::Japanese) => {
+            "ファイルを修正して保存すると自動で再読み込みします"
+        }
+        ("config_error_hint", Language::English) => {
+            "Fix the file and save it; it reloads automatically"
+        }
 
         // ── Fallback ──────────────────────────────────────────────────────
         _ => key,
@@ -269,6 +279,14 @@ mod tests {
             "label_language",
             "lang_japanese",
             "lang_english",
+            "label_master_volume",
+            "label_bgm_volume",
+            "label_sfx_volume",
+            "label_mute",
+            "label_on",
+            "label_off",
+            "label_seed",
+            "label_seed_random",
             "btn_back",
             "character_select_title",
             "btn_play",
@@ -278,14 +296,6 @@ mod tests {
             "label_hp",
             "label_speed",
             "label_weapon",
-            "weapon_whip",
-            "weapon_magic_wand",
-            "weapon_knife",
-            "weapon_garlic",
-            "weapon_bible",
-            "weapon_thunder_ring",
-            "weapon_cross",
-            "weapon_fire_wand",
             "level_up_title",
             "meta_shop_title",
             "pause_title",
@@ -296,6 +306,7 @@ mod tests {
             "stat_level_reached",
             "stat_enemies_defeated",
             "stat_gold_earned",
+            "stat_seed",
         ];
         for key in &keys {
             assert_ne!(t(key, Language::Japanese), *key, "JP: {key}");
@@ -11,6 +11,7 @@
 //! - [`styles`]: `DEFAULT_*` color, font-size, and layout fallback constants
 
 use bevy::prelude::*;
+use vs_core::config::ConfigLoadErrors;
 use vs_core::states::AppState;
 use vs_core::systems::kill_count::track_kill_count;
 use vs_core::systems::xp::choices::generate_level_up_choices;
@@ -37,6 +38,14 @@ impl Plugin for GameUIPlugin {
             .add_plugins(config::UiConfigPlugin)
             // Camera is permanent — needed for title / menu rendering too.
             .add_systems(Startup, (camera::setup_camera, fonts::preload_fonts))
+            // Config error screen — listed while a core config fails to load.
+            .add_systems(
+                Update,
+                screens::config_error::update_config_error_screen.run_if(
+                    in_state(AppState::Loading)
+                        .and(resource_exists_and_changed::<ConfigLoadErrors>),
+                ),
+            )
             // Title screen
             .add_systems(OnEnter(AppState::Title), screens::title::setup_title_screen)
             .add_systems(
@@ -91,6 +100,8 @@ impl Plugin for GameUIPlugin {
                 Update,
                 (
                     screens::settings::update_settings_display,
+                    screens::settings::update_audio_settings_display,
+                    screens::settings::update_seed_display,
                     i18n::update_translatable_texts,
                 )
                     .run_if(in_state(AppState::Settings)),
@@ -22,7 +22,8 @@ use bevy::state::state_scoped::DespawnOnExit;
 use vs_core::config::CharacterParams;
 use vs_core::resources::{GameSettings, MetaProgress, SelectedCharacter};
 use vs_core::states::AppState;
-use vs_core::types::{CharacterType, WeaponType};
+use vs_core::systems::weapons::registry::WeaponRegistry;
+use vs_core::types::CharacterType;
 
 use crate::components::ButtonAction;
 use crate::config::{
@@ -86,39 +87,16 @@ fn card_base_color(
     }
 }
 
-/// Returns the i18n key for a given [`WeaponType`] name.
-fn weapon_name_key(wt: WeaponType) -> &'static str {
-    match wt {
-        WeaponType::Whip => "weapon_whip",
-        WeaponType::MagicWand => "weapon_magic_wand",
-        WeaponType::Knife => "weapon_knife",
-        WeaponType::Garlic => "weapon_garlic",
-        WeaponType::Bible => "weapon_bible",
-        WeaponType::ThunderRing => "weapon_thunder_ring",
-        WeaponType::Cross => "weapon_cross",
-        WeaponType::FireWand => "weapon_fire_wand",
-        // Evolved weapons are never used as starting weapons; map each to its
-        // base weapon's i18n key so the detail panel shows a recognisable name
-        // if the RON config ever assigns one.  Listed explicitly so adding a
-        // new WeaponType causes a compile error here.
-        WeaponType::BloodyTear => "weapon_whip",
-        WeaponType::HolyWand => "weapon_magic_wand",
-        WeaponType::ThousandEdge => "weapon_knife",
-        WeaponType::SoulEater => "weapon_garlic",
-        WeaponType::UnholyVespers => "weapon_bible",
-        WeaponType::LightningRing => "weapon_thunder_ring",
-    }
-}
-
 /// Builds the formatted multi-line detail string for the given character.
 ///
-/// For unlocked characters the detail shows HP, move speed, starting weapon,
-/// and the description line.  For locked characters it shows the lock badge
+/// For unlocked characters the detail shows HP, move speed, starting weapon
+/// (named by its spec in `registry`), and the description line.  For locked characters it shows the lock badge
 /// and the gold cost required to purchase the character in the gold shop.
 fn build_detail_text(
     stats: &vs_core::types::CharacterBaseStats,
     is_unlocked: bool,
     lang: vs_core::resources::Language,
+    registry: &WeaponRegistry,
 ) -> String {
     if is_unlocked {
         format!(
@@ -129,7 +107,7 @@ fn build_detail_text(
             t("label_speed", lang),
             stats.move_speed as u32,
             t("label_weapon", lang),
-            t(weapon_name_key(stats.starting_weapon), lang),
+            registry.name(stats.starting_weapon, lang),
             stats.description,
         )
     } else {
@@ -160,6 +138,7 @@ pub fn setup_character_select_screen(
     settings: Option<Res<GameSettings>>,
     meta: Option<Res<MetaProgress>>,
     selected: Option<Res<SelectedCharacter>>,
+    registry: Res<WeaponRegistry>,
 ) {
     let lang = settings.as_deref().map(|s| s.language).unwrap_or_default();
     let font: Handle<Font> = asset_server
@@ -195,12 +174,7 @@ pub fn setup_character_select_screen(
         .map(|m| m.unlocked_characters.clone())
         .unwrap_or_else(|| vec![CharacterType::DefaultCharacter]);
 
-    let all_chars = [
-        CharacterType::DefaultCharacter,
-        CharacterType::Magician,
-        CharacterType::Thief,
-        CharacterType::Knight,
-    ];
+    let all_chars = CharacterType::ALL;
 
     commands
         .spawn((
@@ -294,7 +268,7 @@ pub fn setup_character_select_screen(
             // ── Detail panel ──────────────────────────────────────────────
             let init_stats = char_params.stats_for(current_selected);
             let init_unlocked = unlocked.contains(&current_selected);
-            let detail_content = build_detail_text(&init_stats, init_unlocked, lang);
+            let detail_content = build_detail_text(&init_stats, init_unlocked, lang, &registry);
             let init_detail_text_color = if init_unlocked {
                 detail_text_color
             } else {
@@ -358,10 +332,12 @@ pub fn setup_character_select_screen(
 /// string and recompute each card's background.  Running unconditionally keeps
 /// card hover state consistent with the latest selection at minimal cost (only
 /// four card entities per screen).
+#[allow(clippy::too_many_arguments)]
 pub fn update_character_select(
     selected: Option<Res<SelectedCharacter>>,
     meta: Option<Res<MetaProgress>>,
     settings: Option<Res<GameSettings>>,
+    registry: Res<WeaponRegistry>,
     char_params: CharacterParams,
     cs_params: CharacterSelectScreenParams,
     mut detail_q: Query<(&mut Text, &mut TextColor), With<CharacterDetailText>>,
@@ -390,7 +366,7 @@ pub fn update_character_select(
     let is_unlocked = meta.unlocked_characters.contains(&char_type);
 
     // Rebuild detail panel text.
-    let content = build_detail_text(&stats, is_unlocked, lang);
+    let content = build_detail_text(&stats, is_unlocked, lang, &registry);
     let text_color = if is_unlocked {
         detail_text_color
     } else {
@@ -471,6 +447,7 @@ mod tests {
         app.insert_resource(SelectedCharacter::default());
         app.insert_resource(MetaProgress::default());
         app.insert_resource(GameSettings::default());
+        app.insert_resource(WeaponRegistry::with_builtin_weapons());
         app
     }
 
@@ -720,6 +697,16 @@ mod tests {
             "Knight HP 150 must appear in detail; got: {:?}",
             text.0
         );
+        let weapon = vs_core::types::get_character_stats(CharacterType::Knight).starting_weapon;
+        let weapon_name = app
+            .world()
+            .resource::<WeaponRegistry>()
+            .name(weapon, vs_core::resources::Language::English);
+        assert!(
+            text.0.contains(weapon_name),
+            "starting weapon {weapon_name} must appear in detail; got: {:?}",
+            text.0
+        );
     }
 
     #[test]
@@ -0,0 +1,219 @@
+//! Config error screen — shown while a core RON config fails to load.
+//!
+//! The game stays in [`AppState::Loading`] while [`ConfigLoadErrors`] is
+//! non-empty.  This screen lists every failure (file, field and reason) and
+//! tells the player that saving a fixed file reloads it; once every config
+//! loads, the list empties, the screen disappears and the game moves on to
+//! the title.  All entities are tagged with
+//! [`DespawnOnExit`]`(`[`AppState::Loading`]`)`.
+//!
+//! Systems:
+//! - [`update_config_error_screen`]: rebuilds the screen whenever
+//!   [`ConfigLoadErrors`] changes
+
+use bevy::prelude::*;
+use bevy::state::state_scoped::DespawnOnExit;
+use vs_core::config::ConfigLoadErrors;
+use vs_core::resources::GameSettings;
+use vs_core::states::AppState;
+
+use crate::config::{ScreenHeadingHudParams, UiStyleParams};
+use crate::hud::screen_heading::spawn_screen_heading;
+use crate::i18n::{font_for_lang, t};
+
+// ---------------------------------------------------------------------------
+// Fallback constants
+// ---------------------------------------------------------------------------
+
+/// Heading color (alarm red).
+const DEFAULT_HEADING_COLOR: Color = Color::srgb(1.0, 0.35, 0.30);
+/// Error line color (light gray).
+const DEFAULT_ERROR_COLOR: Color = Color::srgb(0.85, 0.85, 0.85);
+/// Hint line color (muted yellow).
+const DEFAULT_HINT_COLOR: Color = Color::srgb(0.90, 0.80, 0.40);
+
+const DEFAULT_ERROR_FONT_SIZE: f32 = 16.0;
+const DEFAULT_HINT_FONT_SIZE: f32 = 18.0;
+const DEFAULT_ROW_GAP: f32 = 8.0;
+const DEFAULT_LIST_MARGIN_TOP: f32 = 24.0;
+/// Error list width as a percentage of the window, so long lines wrap.
+const DEFAULT_LIST_WIDTH_PCT: f32 = 90.0;
+
+// ---------------------------------------------------------------------------
+// Marker components
+// ---------------------------------------------------------------------------
+
+/// Marks the root node of the config error screen.
+#[derive(Component)]
+pub struct ConfigErrorScreen;
+
+// ---------------------------------------------------------------------------
+// Systems
+// ---------------------------------------------------------------------------
+
+/// Replaces the config error screen with one listing the current
+/// [`ConfigLoadErrors`], or removes it when the list is empty.
+///
+/// Run in [`AppState::Loading`] when [`ConfigLoadErrors`] changes.
+pub fn update_config_error_screen(
+    mut commands: Commands,
+    errors: Res<ConfigLoadErrors>,
+    screens: Query<Entity, With<ConfigErrorScreen>>,
+    ui_style: UiStyleParams,
+    heading_cfg: ScreenHeadingHudParams,
+    asset_server: Option<Res<AssetServer>>,
+    settings: Option<Res<GameSettings>>,
+) {
+    for entity in &screens {
+        commands.entity(entity).despawn();
+    }
+    if errors.0.is_empty() {
+        return;
+    }
+
+    let lang = settings.as_deref().map(|s| s.language).unwrap_or_default();
+    let font: Handle<Font> = asset_server
+        .map(|s| s.load(font_for_lang(lang)))
+        .unwrap_or_default();
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(ui_style.bg_color()),
+            DespawnOnExit(AppState::Loading),
+            ConfigErrorScreen,
+        ))
+        .with_children(|parent| {
+            spawn_screen_heading(
+                parent,
+                t("config_error_title", lang),
+                DEFAULT_HEADING_COLOR,
+                heading_cfg.get(),
+                font.clone(),
+            );
+
+            parent
+                .spawn(Node {
+                    width: Val::Percent(DEFAULT_LIST_WIDTH_PCT),
+                    flex_direction: FlexDirection::Column,
+                    margin: UiRect::top(Val::Px(DEFAULT_LIST_MARGIN_TOP)),
+                    row_gap: Val::Px(DEFAULT_ROW_GAP),
+                    ..default()
+                })
+                .with_children(|list| {
+                    for error in &errors.0 {
+                        list.spawn((
+                            Text::new(error.clone()),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: DEFAULT_ERROR_FONT_SIZE,
+                                ..default()
+                            },
+                            TextColor(DEFAULT_ERROR_COLOR),
+                        ));
+                    }
+                    list.spawn((
+                        Text::new(t("config_error_hint", lang)),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: DEFAULT_HINT_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(DEFAULT_HINT_COLOR),
+                    ));
+                });
+        });
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use bevy::state::app::StatesPlugin;
+
+    use super::*;
+
+    fn build_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin));
+        app.init_state::<AppState>();
+        app.init_resource::<ConfigLoadErrors>();
+        app.add_systems(
+            Update,
+            update_config_error_screen.run_if(
+                in_state(AppState::Loading).and(resource_exists_and_changed::<ConfigLoadErrors>),
+            ),
+        );
+        app
+    }
+
+    fn screen_count(app: &mut App) -> usize {
+        let mut q = app
+            .world_mut()
+            .query_filtered::<Entity, With<ConfigErrorScreen>>();
+        q.iter(app.world()).count()
+    }
+
+    fn texts(app: &mut App) -> Vec<String> {
+        let mut q = app.world_mut().query::<&Text>();
+        q.iter(app.world()).map(|t| t.0.clone()).collect()
+    }
+
+    #[test]
+    fn no_screen_without_errors() {
+        let mut app = build_app();
+        app.update();
+        assert_eq!(screen_count(&mut app), 0);
+    }
+
+    #[test]
+    fn errors_are_listed_and_screen_clears_when_fixed() {
+        let mut app = build_app();
+        app.update();
+
+        app.world_mut().resource_mut::<ConfigLoadErrors>().0 =
+            vec!["config/stage.ron: mad_forest.enemy_types: must not be empty".to_string()];
+        app.update();
+        assert_eq!(screen_count(&mut app), 1);
+        assert!(
+            texts(&mut app)
+                .iter()
+                .any(|t| t.contains("mad_forest.enemy_types"))
+        );
+
+        // A second failure rebuilds the screen rather than stacking another.
+        app.world_mut()
+            .resource_mut::<ConfigLoadErrors>()
+            .0
+            .push("config/game.ron: bad".to_string());
+        app.update();
+        assert_eq!(screen_count(&mut app), 1);
+
+        app.world_mut().resource_mut::<ConfigLoadErrors>().0.clear();
+        app.update();
+        assert_eq!(screen_count(&mut app), 0);
+    }
+
+    #[test]
+    fn screen_despawns_when_leaving_loading() {
+        let mut app = build_app();
+        app.world_mut().resource_mut::<ConfigLoadErrors>().0 = vec!["bad".to_string()];
+        app.update();
+        assert_eq!(screen_count(&mut app), 1);
+
+        app.world_mut()
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::Title);
+        app.update();
+        assert_eq!(screen_count(&mut app), 0);
+    }
+}
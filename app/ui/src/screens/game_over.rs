@@ -1,8 +1,8 @@
 //! Game-over screen — shown when the player's HP reaches zero.
 //!
 //! Spawns a full-screen layout containing a "GAME OVER" heading, run
-//! statistics (survival time, level reached, enemies defeated, gold earned),
-//! and two buttons: "もう一度" (retry → CharacterSelect) and "タイトルへ"
+//! statistics (survival time, level reached, enemies defeated, gold earned,
+//! run seed), and two buttons: "もう一度" (retry → CharacterSelect) and "タイトルへ"
 //! (title).  All entities are tagged with
 //! [`DespawnOnExit`]`(`[`AppState::GameOver`]`)` so Bevy automatically
 //! despawns them when the state transitions away.
@@ -14,7 +14,7 @@
 
 use bevy::prelude::*;
 use bevy::state::state_scoped::DespawnOnExit;
-use vs_core::resources::{GameData, GameSettings};
+use vs_core::resources::{GameData, GameRng, GameSettings};
 use vs_core::states::AppState;
 
 use crate::components::ButtonAction;
@@ -41,7 +41,8 @@ pub struct GameOverScreenBg;
 /// Spawns the game-over screen UI when entering [`AppState::GameOver`].
 ///
 /// Reads [`GameData`] to display run statistics (survival time, level
-/// reached, enemies defeated, gold earned).  Visual tunables are loaded from
+/// reached, enemies defeated, gold earned) and [`GameRng`] for the run seed
+/// so bug reports can quote it.  Visual tunables are loaded from
 /// `config/ui/screen/game_over.ron` via [`GameOverScreenParams`]; the private
 /// Fallback values are provided by the typed accessor methods on each Params type.
 #[allow(clippy::too_many_arguments)]
@@ -54,6 +55,7 @@ pub fn setup_game_over_screen(
     game_data: Res<GameData>,
     asset_server: Option<Res<AssetServer>>,
     settings: Option<Res<GameSettings>>,
+    game_rng: Option<Res<GameRng>>,
 ) {
     let lang = settings.as_deref().map(|s| s.language).unwrap_or_default();
     let font: Handle<Font> = asset_server
@@ -71,6 +73,7 @@ pub fn setup_game_over_screen(
     let level = game_data.current_level;
     let kills = game_data.kill_count;
     let gold = game_data.gold_earned;
+    let seed = game_rng.as_deref().map(GameRng::seed);
 
     commands
         .spawn((
@@ -106,12 +109,16 @@ pub fn setup_game_over_screen(
                     ..default()
                 })
                 .with_children(|stats| {
-                    for line in [
+                    let mut lines = vec![
                         format!("{} {clear_time}", t("stat_clear_time", lang)),
                         format!("{} {level}", t("stat_level_reached", lang)),
                         format!("{} {kills}", t("stat_enemies_defeated", lang)),
                         format!("{} {gold}", t("stat_gold_earned", lang)),
-                    ] {
+                    ];
+                    if let Some(seed) = seed {
+                        lines.push(format!("{} {seed}", t("stat_seed", lang)));
+                    }
+                    for line in lines {
                         stats.spawn((
                             Text::new(line),
                             TextFont {
@@ -341,4 +348,22 @@ mod tests {
             "gold 999 should appear; got: {texts:?}"
         );
     }
+
+    #[test]
+    fn stats_show_run_seed() {
+        use vs_core::resources::Language;
+
+        let mut app = build_app();
+        app.insert_resource(GameRng::from_seed(4242));
+        app.add_systems(OnEnter(AppState::GameOver), setup_game_over_screen);
+        enter_game_over(&mut app);
+
+        let mut q = app.world_mut().query::<&Text>();
+        let texts: Vec<String> = q.iter(app.world()).map(|t| t.0.clone()).collect();
+        let expected = format!("{} 4242", t("stat_seed", Language::Japanese));
+        assert!(
+            texts.iter().any(|t| t.as_str() == expected),
+            "seed row '{expected}' should appear; got: {texts:?}"
+        );
+    }
 }
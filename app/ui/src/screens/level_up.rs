@@ -11,6 +11,7 @@ use bevy::prelude::*;
 use bevy::state::state_scoped::DespawnOnExit;
 use vs_core::resources::{GameData, GameSettings, LevelUpChoices};
 use vs_core::states::AppState;
+use vs_core::systems::weapons::registry::WeaponRegistry;
 
 use crate::config::{LevelUpScreenParams, ScreenHeadingHudParams, UpgradeCardHudParams};
 use crate::hud::screen_heading::spawn_screen_heading;
@@ -43,6 +44,7 @@ pub fn setup_level_up_screen(
     mut commands: Commands,
     choices: Res<LevelUpChoices>,
     game_data: Res<GameData>,
+    registry: Res<WeaponRegistry>,
     mut next_state: ResMut<NextState<AppState>>,
     screen_cfg: LevelUpScreenParams,
     heading_cfg: ScreenHeadingHudParams,
@@ -111,7 +113,15 @@ pub fn setup_level_up_screen(
             ))
             .with_children(|row| {
                 for (i, choice) in choices.choices.iter().enumerate() {
-                    spawn_upgrade_card(row, i, choice, card_cfg.get(), font.clone(), lang);
+                    spawn_upgrade_card(
+                        row,
+                        i,
+                        choice,
+                        &registry,
+                        card_cfg.get(),
+                        font.clone(),
+                        lang,
+                    );
                 }
             });
         });
@@ -136,6 +146,7 @@ mod tests {
         app.init_state::<AppState>();
         app.insert_resource(LevelUpChoices::default());
         app.insert_resource(GameData::default());
+        app.insert_resource(WeaponRegistry::with_builtin_weapons());
         app
     }
 
@@ -1,6 +1,7 @@
 //! Screen implementations for each application state.
 
 pub mod character_select;
+pub mod config_error;
 pub mod game_over;
 pub mod level_up;
 pub mod meta_shop;
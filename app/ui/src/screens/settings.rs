@@ -1,7 +1,7 @@
 //! Settings screen.
 //!
-//! Displays configurable options; currently only language selection.
-//! Returns to the Title screen via the "Back" button.
+//! Displays configurable options: language, master / BGM / SFX volume, mute,
+//! and the run seed.  Returns to the Title screen via the "Back" button.
 //!
 //! All entities are tagged with [`DespawnOnExit`]`(AppState::Settings)` so
 //! Bevy cleans them up automatically on state exit.
@@ -12,19 +12,31 @@
 //!         Settings / 設定
 //!
 //!  Language / 言語:  [ Japanese / 日本語 ↔ English ]
+//!  Master / 全体音量:  [ - ]  100%  [ + ]
+//!  BGM / BGM音量:      [ - ]  100%  [ + ]
+//!  SFX / 効果音量:     [ - ]  100%  [ + ]
+//!  Mute / ミュート:    [ OFF ]
+//!  Seed / シード:      [ Random ]
 //!
 //!         [ Back / もどる ]
 //! ```
 //!
 //! The language button cycles between Japanese and English via
-//! [`ButtonAction::ToggleLanguage`].  [`update_settings_display`] refreshes
-//! the button label whenever [`GameSettings`] changes, and
-//! [`crate::i18n::update_translatable_texts`] refreshes all other labeled
-//! nodes at the same time.
+//! [`ButtonAction::ToggleLanguage`]; the volume steppers and mute button use
+//! [`ButtonAction::VolumeDown`] / [`ButtonAction::VolumeUp`] /
+//! [`ButtonAction::ToggleMute`].  [`update_settings_display`] and
+//! [`update_audio_settings_display`] refresh the labels whenever
+//! [`GameSettings`] changes, and [`crate::i18n::update_translatable_texts`]
+//! refreshes all other labeled nodes at the same time.  vs-audio applies the
+//! new volumes to its channels on the same frame.
+//!
+//! The seed button uses [`ButtonAction::ToggleFixedSeed`] to switch between a
+//! fresh random seed per run and replaying the most recent run's seed;
+//! [`update_seed_display`] keeps its label in sync with [`SeedOverride`].
 
 use bevy::prelude::*;
 use bevy::state::state_scoped::DespawnOnExit;
-use vs_core::resources::GameSettings;
+use vs_core::resources::{GameSettings, Language, SeedOverride, VolumeChannel};
 use vs_core::states::AppState;
 
 use crate::components::ButtonAction;
@@ -41,6 +53,12 @@ const DEFAULT_ROW_GAP: f32 = 24.0;
 const DEFAULT_LABEL_FONT_SIZE: f32 = 20.0;
 const DEFAULT_LABEL_COLOR: Color = Color::srgb(0.95, 0.90, 0.85);
 const DEFAULT_ROW_COLUMN_GAP: f32 = 16.0;
+/// Width of the `[ - ]` / `[ + ]` stepper buttons.
+const DEFAULT_STEPPER_WIDTH: f32 = 48.0;
+/// Width reserved for the percentage text between the steppers.
+const DEFAULT_VOLUME_VALUE_WIDTH: f32 = 72.0;
+/// Width of the row labels so steppers line up in a column.
+const DEFAULT_ROW_LABEL_WIDTH: f32 = 200.0;
 
 // ---------------------------------------------------------------------------
 // Marker components
@@ -60,6 +78,60 @@ pub struct LanguageButtonLabel;
 #[derive(Component, Debug)]
 pub struct LanguageLabelText;
 
+/// Marks a settings row label other than the language row; holds its i18n key.
+///
+/// [`update_audio_settings_display`] re-renders it as `"{label}:"` on
+/// language change.
+#[derive(Component, Debug)]
+pub struct SettingsRowLabel(pub &'static str);
+
+/// Marks the percentage text of a volume stepper row.
+#[derive(Component, Debug)]
+pub struct VolumeValueText(pub VolumeChannel);
+
+/// Marks the mute toggle button label ("ON" / "OFF").
+#[derive(Component, Debug)]
+pub struct MuteButtonLabel;
+
+/// Marks the seed toggle button label ("Random" or the fixed seed).
+#[derive(Component, Debug)]
+pub struct SeedButtonLabel;
+
+// ---------------------------------------------------------------------------
+// Text helpers
+// ---------------------------------------------------------------------------
+
+/// i18n key of the row label for `channel`.
+fn volume_label_key(channel: VolumeChannel) -> &'static str {
+    match channel {
+        VolumeChannel::Master => "label_master_volume",
+        VolumeChannel::Bgm => "label_bgm_volume",
+        VolumeChannel::Sfx => "label_sfx_volume",
+    }
+}
+
+/// Formats a linear volume as a whole percentage, e.g. `0.8` → `"80%"`.
+fn volume_percent_text(volume: f32) -> String {
+    format!("{}%", (volume * 100.0).round() as u32)
+}
+
+/// Localised label of the mute toggle for the current state.
+fn mute_text(muted: bool, lang: Language) -> &'static str {
+    if muted {
+        t("label_on", lang)
+    } else {
+        t("label_off", lang)
+    }
+}
+
+/// Label of the seed toggle: the fixed seed, or "Random" when none is set.
+fn seed_text(seed_override: SeedOverride, lang: Language) -> String {
+    match seed_override.0 {
+        Some(seed) => seed.to_string(),
+        None => t("label_seed_random", lang).to_string(),
+    }
+}
+
 // ---------------------------------------------------------------------------
 // System: spawn
 // ---------------------------------------------------------------------------
@@ -72,8 +144,10 @@ pub fn setup_settings_screen(
     heading_cfg: ScreenHeadingHudParams,
     btn_cfg: MenuButtonHudParams,
     asset_server: Option<Res<AssetServer>>,
+    seed_override: Option<Res<SeedOverride>>,
 ) {
     let lang = settings.language;
+    let seed_override = seed_override.as_deref().copied().unwrap_or_default();
     let font: Handle<Font> = asset_server
         .map(|s| s.load(font_for_lang(lang)))
         .unwrap_or_default();
@@ -178,6 +252,177 @@ pub fn setup_settings_screen(
                     });
                 });
 
+            // Volume rows: label + [ - ] value [ + ].
+            for channel in VolumeChannel::ALL {
+                let label_key = volume_label_key(channel);
+                parent
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(DEFAULT_ROW_COLUMN_GAP),
+                        ..default()
+                    })
+                    .with_children(|row| {
+                        row.spawn((
+                            Text::new(format!("{}:", t(label_key, lang))),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: DEFAULT_LABEL_FONT_SIZE,
+                                ..default()
+                            },
+                            TextColor(DEFAULT_LABEL_COLOR),
+                            Node {
+                                width: Val::Px(DEFAULT_ROW_LABEL_WIDTH),
+                                ..default()
+                            },
+                            SettingsRowLabel(label_key),
+                        ));
+                        spawn_stepper_button(
+                            row,
+                            "-",
+                            ButtonAction::VolumeDown(channel),
+                            btn_height * 0.75,
+                            btn_normal,
+                            btn_text_color,
+                            btn_font_size * 0.6,
+                            font.clone(),
+                        );
+                        row.spawn((
+                            Text::new(volume_percent_text(settings.volume(channel))),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: DEFAULT_LABEL_FONT_SIZE,
+                                ..default()
+                            },
+                            TextColor(DEFAULT_LABEL_COLOR),
+                            TextLayout::new(Justify::Center, LineBreak::NoWrap),
+                            Node {
+                                width: Val::Px(DEFAULT_VOLUME_VALUE_WIDTH),
+                                ..default()
+                            },
+                            VolumeValueText(channel),
+                        ));
+                        spawn_stepper_button(
+                            row,
+                            "+",
+                            ButtonAction::VolumeUp(channel),
+                            btn_height * 0.75,
+                            btn_normal,
+                            btn_text_color,
+                            btn_font_size * 0.6,
+                            font.clone(),
+                        );
+                    });
+            }
+
+            // Mute row: label + ON/OFF toggle.
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(DEFAULT_ROW_COLUMN_GAP),
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new(format!("{}:", t("label_mute", lang))),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: DEFAULT_LABEL_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(DEFAULT_LABEL_COLOR),
+                        Node {
+                            width: Val::Px(DEFAULT_ROW_LABEL_WIDTH),
+                            ..default()
+                        },
+                        SettingsRowLabel("label_mute"),
+                    ));
+                    row.spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(btn_width * 0.5),
+                            height: Val::Px(btn_height * 0.75),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(btn_normal),
+                        crate::components::MenuButton {
+                            action: ButtonAction::ToggleMute,
+                        },
+                        crate::hud::menu_button::LargeMenuButtonHud,
+                    ))
+                    .with_children(|btn| {
+                        btn.spawn((
+                            Text::new(mute_text(settings.muted, lang)),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: btn_font_size * 0.6,
+                                ..default()
+                            },
+                            TextColor(btn_text_color),
+                            TextLayout::new_with_linebreak(LineBreak::NoWrap),
+                            crate::hud::menu_button::LargeMenuButtonLabelHud,
+                            MuteButtonLabel,
+                        ));
+                    });
+                });
+
+            // Seed row: label + Random / fixed-seed toggle.
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(DEFAULT_ROW_COLUMN_GAP),
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new(format!("{}:", t("label_seed", lang))),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: DEFAULT_LABEL_FONT_SIZE,
+                            ..default()
+                        },
+                        TextColor(DEFAULT_LABEL_COLOR),
+                        Node {
+                            width: Val::Px(DEFAULT_ROW_LABEL_WIDTH),
+                            ..default()
+                        },
+                        SettingsRowLabel("label_seed"),
+                    ));
+                    row.spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(btn_width),
+                            height: Val::Px(btn_height * 0.75),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(btn_normal),
+                        crate::components::MenuButton {
+                            action: ButtonAction::ToggleFixedSeed,
+                        },
+                        crate::hud::menu_button::LargeMenuButtonHud,
+                    ))
+                    .with_children(|btn| {
+                        btn.spawn((
+                            Text::new(seed_text(seed_override, lang)),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: btn_font_size * 0.6,
+                                ..default()
+                            },
+                            TextColor(btn_text_color),
+                            TextLayout::new_with_linebreak(LineBreak::NoWrap),
+                            crate::hud::menu_button::LargeMenuButtonLabelHud,
+                            SeedButtonLabel,
+                        ));
+                    });
+                });
+
             // Back button — TranslatableText enables live language updates.
             spawn_large_menu_button(
                 parent,
@@ -190,6 +435,46 @@ pub fn setup_settings_screen(
         });
 }
 
+/// Spawns a small square `[ - ]` / `[ + ]` stepper button inside `row`.
+#[allow(clippy::too_many_arguments)]
+fn spawn_stepper_button(
+    row: &mut ChildSpawnerCommands,
+    label: &str,
+    action: ButtonAction,
+    height: f32,
+    color: Color,
+    text_color: Color,
+    font_size: f32,
+    font: Handle<Font>,
+) {
+    row.spawn((
+        Button,
+        Node {
+            width: Val::Px(DEFAULT_STEPPER_WIDTH),
+            height: Val::Px(height),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(color),
+        crate::components::MenuButton { action },
+        crate::hud::menu_button::LargeMenuButtonHud,
+    ))
+    .with_children(|btn| {
+        btn.spawn((
+            Text::new(label),
+            TextFont {
+                font,
+                font_size,
+                ..default()
+            },
+            TextColor(text_color),
+            TextLayout::new_with_linebreak(LineBreak::NoWrap),
+            crate::hud::menu_button::LargeMenuButtonLabelHud,
+        ));
+    });
+}
+
 // ---------------------------------------------------------------------------
 // System: update display
 // ---------------------------------------------------------------------------
@@ -233,6 +518,63 @@ pub fn update_settings_display(
     }
 }
 
+/// Query for every text node whose content depends on the audio settings
+/// (volume percentages, mute toggle label, and their row labels).
+type AudioSettingsTextQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        &'static mut Text,
+        &'static mut TextFont,
+        AnyOf<(
+            &'static VolumeValueText,
+            &'static MuteButtonLabel,
+            &'static SettingsRowLabel,
+        )>,
+    ),
+>;
+
+/// Refreshes volume percentages, the mute label, and the audio row labels
+/// whenever [`GameSettings`] changes.
+pub fn update_audio_settings_display(
+    settings: Res<GameSettings>,
+    asset_server: Option<Res<AssetServer>>,
+    mut text_q: AudioSettingsTextQuery,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    let lang = settings.language;
+    let new_font: Handle<Font> = asset_server
+        .map(|s| s.load(font_for_lang(lang)))
+        .unwrap_or_default();
+    for (mut text, mut text_font, (volume, mute, row_label)) in text_q.iter_mut() {
+        if let Some(VolumeValueText(channel)) = volume {
+            *text = Text::new(volume_percent_text(settings.volume(*channel)));
+        } else if mute.is_some() {
+            *text = Text::new(mute_text(settings.muted, lang));
+        } else if let Some(SettingsRowLabel(key)) = row_label {
+            *text = Text::new(format!("{}:", t(key, lang)));
+        }
+        text_font.font = new_font.clone();
+    }
+}
+
+/// Refreshes the seed toggle label whenever [`SeedOverride`] or the UI
+/// language changes.
+pub fn update_seed_display(
+    settings: Res<GameSettings>,
+    seed_override: Res<SeedOverride>,
+    mut label_q: Query<&mut Text, With<SeedButtonLabel>>,
+) {
+    if !settings.is_changed() && !seed_override.is_changed() {
+        return;
+    }
+    for mut text in label_q.iter_mut() {
+        *text = Text::new(seed_text(*seed_override, settings.language));
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -300,6 +642,79 @@ mod tests {
         );
     }
 
+    #[test]
+    fn has_volume_steppers_and_mute_toggle() {
+        let mut app = build_app();
+        app.add_systems(OnEnter(AppState::Settings), setup_settings_screen);
+        enter_settings(&mut app);
+
+        let mut q = app.world_mut().query::<&MenuButton>();
+        let actions: Vec<ButtonAction> = q.iter(app.world()).map(|b| b.action).collect();
+        for channel in VolumeChannel::ALL {
+            assert!(actions.contains(&ButtonAction::VolumeDown(channel)));
+            assert!(actions.contains(&ButtonAction::VolumeUp(channel)));
+        }
+        assert!(actions.contains(&ButtonAction::ToggleMute));
+        assert!(actions.contains(&ButtonAction::ToggleFixedSeed));
+
+        let mut q = app.world_mut().query::<&VolumeValueText>();
+        assert_eq!(q.iter(app.world()).count(), VolumeChannel::ALL.len());
+    }
+
+    #[test]
+    fn update_audio_settings_display_refreshes_volume_and_mute() {
+        let mut app = build_app();
+        let value = app
+            .world_mut()
+            .spawn((
+                Text::new("100%"),
+                TextFont::default(),
+                VolumeValueText(VolumeChannel::Bgm),
+            ))
+            .id();
+        let mute = app
+            .world_mut()
+            .spawn((Text::new("OFF"), TextFont::default(), MuteButtonLabel))
+            .id();
+
+        {
+            let mut settings = app.world_mut().resource_mut::<GameSettings>();
+            settings.language = Language::English;
+            settings.step_volume(VolumeChannel::Bgm, -3);
+            settings.muted = true;
+        }
+        app.world_mut()
+            .run_system_once(update_audio_settings_display)
+            .unwrap();
+
+        assert_eq!(app.world().get::<Text>(value).unwrap().0, "70%");
+        assert_eq!(app.world().get::<Text>(mute).unwrap().0, "ON");
+    }
+
+    #[test]
+    fn update_seed_display_shows_fixed_seed() {
+        let mut app = build_app();
+        app.init_resource::<SeedOverride>();
+        let label = app
+            .world_mut()
+            .spawn((Text::new("Random"), SeedButtonLabel))
+            .id();
+
+        app.world_mut().resource_mut::<SeedOverride>().0 = Some(31337);
+        app.world_mut()
+            .run_system_once(update_seed_display)
+            .unwrap();
+
+        assert_eq!(app.world().get::<Text>(label).unwrap().0, "31337");
+    }
+
+    #[test]
+    fn volume_percent_text_rounds() {
+        assert_eq!(volume_percent_text(1.0), "100%");
+        assert_eq!(volume_percent_text(0.3), "30%");
+        assert_eq!(volume_percent_text(0.0), "0%");
+    }
+
     #[test]
     fn language_label_shows_japanese_by_default() {
         let mut app = build_app();
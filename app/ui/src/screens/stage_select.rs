@@ -174,11 +174,7 @@ pub fn setup_stage_select_screen(
         .map(|s| s.0)
         .unwrap_or(StageType::MadForest);
 
-    let all_stages = [
-        StageType::MadForest,
-        StageType::InlaidLibrary,
-        StageType::DairyPlant,
-    ];
+    let all_stages = StageType::ALL;
 
     commands
         .spawn((
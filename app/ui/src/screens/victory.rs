@@ -1,7 +1,7 @@
 //! Victory screen — shown when the player defeats Boss Death.
 //!
 //! Spawns a full-screen layout containing a "YOU WIN!" heading, run statistics
-//! (clear time, level reached, enemies defeated, gold earned), and a button to
+//! (clear time, level reached, enemies defeated, gold earned, run seed), and a button to
 //! return to the title.  All entities are tagged with [`DespawnOnExit`]`(`[`AppState::Victory`]`)`
 //! so Bevy automatically despawns them when the state transitions away.
 //!
@@ -12,7 +12,7 @@
 
 use bevy::prelude::*;
 use bevy::state::state_scoped::DespawnOnExit;
-use vs_core::resources::{GameData, GameSettings};
+use vs_core::resources::{GameData, GameRng, GameSettings};
 use vs_core::states::AppState;
 
 use crate::components::ButtonAction;
@@ -39,7 +39,8 @@ pub struct VictoryScreenBg;
 /// Spawns the victory screen UI when entering [`AppState::Victory`].
 ///
 /// Reads [`GameData`] to display the run statistics (clear time, level
-/// reached, enemies defeated, gold earned).  Visual tunables are loaded from
+/// reached, enemies defeated, gold earned) and [`GameRng`] for the run seed
+/// so bug reports can quote it.  Visual tunables are loaded from
 /// `config/ui/screen/victory.ron` via [`VictoryScreenParams`]; the private
 /// Fallback values are provided by the typed accessor methods on each Params type.
 #[allow(clippy::too_many_arguments)]
@@ -52,6 +53,7 @@ pub fn setup_victory_screen(
     game_data: Res<GameData>,
     asset_server: Option<Res<AssetServer>>,
     settings: Option<Res<GameSettings>>,
+    game_rng: Option<Res<GameRng>>,
 ) {
     let lang = settings.as_deref().map(|s| s.language).unwrap_or_default();
     let font: Handle<Font> = asset_server
@@ -69,6 +71,7 @@ pub fn setup_victory_screen(
     let level = game_data.current_level;
     let kills = game_data.kill_count;
     let gold = game_data.gold_earned;
+    let seed = game_rng.as_deref().map(GameRng::seed);
 
     commands
         .spawn((
@@ -104,12 +107,16 @@ pub fn setup_victory_screen(
                     ..default()
                 })
                 .with_children(|stats| {
-                    for line in [
+                    let mut lines = vec![
                         format!("{} {clear_time}", t("stat_clear_time", lang)),
                         format!("{} {level}", t("stat_level_reached", lang)),
                         format!("{} {kills}", t("stat_enemies_defeated", lang)),
                         format!("{} {gold}", t("stat_gold_earned", lang)),
-                    ] {
+                    ];
+                    if let Some(seed) = seed {
+                        lines.push(format!("{} {seed}", t("stat_seed", lang)));
+                    }
+                    for line in lines {
                         stats.spawn((
                             Text::new(line),
                             TextFont {
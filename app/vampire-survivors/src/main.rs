@@ -2,29 +2,49 @@ use bevy::prelude::*;
 use vs_assets::GameAssetsPlugin;
 use vs_audio::GameAudioPlugin;
 use vs_core::GameCorePlugin;
+use vs_core::cli::{flag_value, parse_flag};
 use vs_core::config::GameConfigPlugin;
+use vs_core::resources::{Replay, ReplayPlayback, SeedOverride};
 use vs_ui::GameUIPlugin;
 
 fn main() {
-    App::new()
-        .add_plugins(DefaultPlugins.set(WindowPlugin {
-            primary_window: Some(Window {
-                title: "Vampire Survivors Clone".into(),
-                resolution: (1280, 720).into(),
-                resizable: false,
-                ..default()
-            }),
+    let mut app = App::new();
+    app.add_plugins(DefaultPlugins.set(WindowPlugin {
+        primary_window: Some(Window {
+            title: "Vampire Survivors Clone".into(),
+            resolution: (1280, 720).into(),
+            resizable: false,
             ..default()
-        }))
-        // Load assets first (other plugins may reference them)
-        .add_plugins(GameAssetsPlugin)
-        // Config: loads RON files, hot-reload, Loading → Title transition
-        .add_plugins(GameConfigPlugin)
-        // Core game logic (ECS, systems)
-        .add_plugins(GameCorePlugin)
-        // UI and camera (depends on GameCorePlugin for AppState + Player)
-        .add_plugins(GameUIPlugin)
-        // Audio (receives core events for BGM/SFX switching)
-        .add_plugins(GameAudioPlugin)
-        .run();
+        }),
+        ..default()
+    }))
+    // Load assets first (other plugins may reference them)
+    .add_plugins(GameAssetsPlugin)
+    // Config: loads RON files, hot-reload, Loading → Title transition
+    .add_plugins(GameConfigPlugin)
+    // Core game logic (ECS, systems)
+    .add_plugins(GameCorePlugin)
+    // UI and camera (depends on GameCorePlugin for AppState + Player)
+    .add_plugins(GameUIPlugin)
+    // Audio (receives core events for BGM/SFX switching)
+    .add_plugins(GameAudioPlugin);
+    // `--seed <u64>` pins every run to the given seed (for bug reproduction).
+    // An unparsable seed is logged and runs stay random.
+    let seed = parse_flag(std::env::args().skip(1), "--seed").unwrap_or_else(|e| {
+        error!("{e}; using random seeds");
+        None
+    });
+    app.insert_resource(SeedOverride(seed));
+    // `--replay <path>` plays a recorded run back instead of live input.  A
+    // replay that cannot be loaded is logged (the log plugin is up by now)
+    // and the game starts normally.
+    if let Some(path) = flag_value(std::env::args().skip(1), "--replay") {
+        match Replay::load_from(path.as_ref()) {
+            Ok(replay) => {
+                app.insert_resource(ReplayPlayback::new(replay));
+            }
+            Err(e) => error!("Failed to load replay {path}: {e}; starting normally"),
+        }
+    }
+    app.run();
 }